@@ -1,6 +1,7 @@
 use super::super::{
     EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree, CHIPLETS_OFFSET,
 };
+use crate::constraints::degrees::ConstraintDegreeGroup;
 use crate::utils::{are_equal, binary_not, is_binary};
 use alloc::vec::Vec;
 
@@ -8,6 +9,9 @@ mod bitwise;
 mod hasher;
 mod memory;
 
+#[cfg(test)]
+mod tests;
+
 // CONSTANTS
 // ================================================================================================
 /// The number of constraints on the management of the Chiplets module. This does not include
@@ -35,18 +39,29 @@ pub fn get_periodic_column_values() -> Vec<Vec<Felt>> {
 
 /// Builds the transition constraint degrees for the chiplets module and all chiplet components.
 pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
-    let mut degrees: Vec<TransitionConstraintDegree> = CONSTRAINT_DEGREES
-        .iter()
-        .map(|&degree| TransitionConstraintDegree::new(degree))
-        .collect();
-
-    degrees.append(&mut hasher::get_transition_constraint_degrees());
-
-    degrees.append(&mut bitwise::get_transition_constraint_degrees());
-
-    degrees.append(&mut memory::get_transition_constraint_degrees());
+    get_transition_constraint_degree_groups()
+        .into_iter()
+        .flat_map(|(_, degrees)| degrees)
+        .collect()
+}
 
-    degrees
+/// Returns the transition constraint degrees for the chiplets module grouped by the chiplet
+/// component which defines them. The constraints on the management of the Chiplets module are
+/// grouped under "chiplets.selectors". The groups are returned in the order in which the
+/// constraints are evaluated.
+pub fn get_transition_constraint_degree_groups() -> Vec<ConstraintDegreeGroup> {
+    vec![
+        (
+            "chiplets.selectors",
+            CONSTRAINT_DEGREES
+                .iter()
+                .map(|&degree| TransitionConstraintDegree::new(degree))
+                .collect(),
+        ),
+        ("chiplets.hasher", hasher::get_transition_constraint_degrees()),
+        ("chiplets.bitwise", bitwise::get_transition_constraint_degrees()),
+        ("chiplets.memory", memory::get_transition_constraint_degrees()),
+    ]
 }
 
 /// Returns the number of transition constraints for the chiplets.
@@ -0,0 +1,86 @@
+use super::{enforce_selectors, EvaluationFrameExt, NUM_CONSTRAINTS};
+use crate::{Felt, CHIPLETS_OFFSET, ONE, TRACE_WIDTH, ZERO};
+use winter_air::EvaluationFrame;
+
+// UNIT TESTS
+// ================================================================================================
+
+/// Evaluates the selector constraints and flags over a frame with valid selector values.
+#[test]
+fn selectors_valid_transition() {
+    // the last row of the bitwise chiplet followed by the first row of the memory chiplet
+    let frame = get_selectors_frame([ONE, ZERO, ZERO], [ONE, ONE, ZERO]);
+    assert_eq!(get_constraint_evaluation(&frame), [ZERO; NUM_CONSTRAINTS]);
+
+    let frame = &frame;
+    assert_eq!(frame.hasher_flag(), ZERO);
+    assert_eq!(frame.bitwise_flag(), ZERO);
+    assert_eq!(frame.memory_flag(true), ZERO);
+    assert_eq!(frame.memory_flag(false), ZERO);
+
+    // two rows of the memory chiplet
+    let frame = get_selectors_frame([ONE, ONE, ZERO], [ONE, ONE, ZERO]);
+    assert_eq!(get_constraint_evaluation(&frame), [ZERO; NUM_CONSTRAINTS]);
+
+    let frame = &frame;
+    assert_eq!(frame.hasher_flag(), ZERO);
+    assert_eq!(frame.bitwise_flag(), ZERO);
+    assert_eq!(frame.memory_flag(true), ONE);
+    assert_eq!(frame.memory_flag(false), ONE);
+}
+
+/// Evaluates the selector constraints and flags over non-binary selector values and compares the
+/// results against values computed by hand from the constraint polynomials.
+#[test]
+fn selectors_test_vectors() {
+    // s = [2, 3, 5], s' = [7, 11, 13]
+    let current = [2, 3, 5].map(Felt::new);
+    let next = [7, 11, 13].map(Felt::new);
+    let frame = get_selectors_frame(current, next);
+
+    let expected = [
+        // s0^2 - s0 = 4 - 2
+        Felt::new(2),
+        // s0 (s1^2 - s1) = 2 (9 - 3)
+        Felt::new(12),
+        // s0 s1 (s2^2 - s2) = 2 * 3 (25 - 5)
+        Felt::new(120),
+        // s0 (s0 - s0') = 2 (2 - 7)
+        -Felt::new(10),
+        // s0 s1 (s1 - s1') = 2 * 3 (3 - 11)
+        -Felt::new(48),
+        // s0 s1 s2 (s2 - s2') = 2 * 3 * 5 (5 - 13)
+        -Felt::new(240),
+    ];
+    assert_eq!(get_constraint_evaluation(&frame), expected);
+
+    let frame = &frame;
+    // 1 - s0
+    assert_eq!(frame.hasher_flag(), -ONE);
+    // s0 (1 - s1') = 2 (1 - 11)
+    assert_eq!(frame.bitwise_flag(), -Felt::new(20));
+    // s0 s1 (1 - s2) = 2 * 3 (1 - 5)
+    assert_eq!(frame.memory_flag(true), -Felt::new(24));
+    // s0 s1 (1 - s2') = 2 * 3 (1 - 13)
+    assert_eq!(frame.memory_flag(false), -Felt::new(72));
+}
+
+// TEST HELPERS
+// ================================================================================================
+
+/// Returns an evaluation frame with the provided values of the chiplet selector columns in the
+/// current and the next rows, and all other columns set to ZERO.
+fn get_selectors_frame(current: [Felt; 3], next: [Felt; 3]) -> EvaluationFrame<Felt> {
+    let mut current_row = vec![ZERO; TRACE_WIDTH];
+    current_row[CHIPLETS_OFFSET..CHIPLETS_OFFSET + 3].copy_from_slice(&current);
+    let mut next_row = vec![ZERO; TRACE_WIDTH];
+    next_row[CHIPLETS_OFFSET..CHIPLETS_OFFSET + 3].copy_from_slice(&next);
+    EvaluationFrame::<Felt>::from_rows(current_row, next_row)
+}
+
+/// Returns the result of the selector constraint evaluations on the provided frame.
+fn get_constraint_evaluation(frame: &EvaluationFrame<Felt>) -> [Felt; NUM_CONSTRAINTS] {
+    let mut result = [ZERO; NUM_CONSTRAINTS];
+    enforce_selectors(frame, &mut result);
+    result
+}
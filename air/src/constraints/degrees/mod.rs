@@ -0,0 +1,95 @@
+use super::{chiplets, range, stack};
+use crate::TransitionConstraintDegree;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum degree of transition constraints supported by the VM.
+///
+/// Evaluating a constraint of degree `d` requires a blowup factor of at least `d - 1` (rounded up
+/// to the next power of two). Since proofs at 96-bit security level are generated with the blowup
+/// factor of 8, constraints of degree up to 9 can be supported.
+pub const MAX_CONSTRAINT_DEGREE: usize = 9;
+
+/// The trace length used to derive constraint degrees from their descriptors. This must be
+/// greater than the length of any periodic column used by the constraints.
+const DEGREE_TRACE_LEN: usize = 1 << 16;
+
+/// Transition constraint degrees defined by a single component of the VM, labeled by the name of
+/// the component (e.g., "stack.field_ops").
+pub type ConstraintDegreeGroup = (&'static str, Vec<TransitionConstraintDegree>);
+
+// CONSTRAINT DEGREE REPORT
+// ================================================================================================
+
+/// Describes the degree of a single transition constraint of the VM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintDegreeInfo {
+    /// Name of the component defining the constraint (e.g., "stack.field_ops").
+    pub component: &'static str,
+    /// Index of the constraint within the constraints of its component.
+    pub index: usize,
+    /// Degree of the constraint, where each periodic column contributes one to the degree.
+    pub degree: usize,
+}
+
+impl ConstraintDegreeInfo {
+    /// Returns true if the degree of this constraint is greater than [MAX_CONSTRAINT_DEGREE].
+    pub fn exceeds_budget(&self) -> bool {
+        self.degree > MAX_CONSTRAINT_DEGREE
+    }
+}
+
+impl fmt::Display for ConstraintDegreeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}]: degree {} of {}",
+            self.component, self.index, self.degree, MAX_CONSTRAINT_DEGREE
+        )
+    }
+}
+
+/// Returns a report describing the degree of every transition constraint of the VM.
+///
+/// Main trace constraints are listed first, followed by the auxiliary trace constraints; within
+/// each trace segment, the constraints are listed in the order in which they are evaluated.
+pub fn get_constraint_degree_report() -> Vec<ConstraintDegreeInfo> {
+    let mut groups = vec![("system", vec![TransitionConstraintDegree::new(1)])];
+    groups.extend(stack::get_transition_constraint_degree_groups());
+    groups.push(("range", range::get_transition_constraint_degrees()));
+    groups.extend(chiplets::get_transition_constraint_degree_groups());
+    groups.push(("range.aux", range::get_aux_transition_constraint_degrees()));
+
+    groups
+        .into_iter()
+        .flat_map(|(component, degrees)| {
+            degrees
+                .into_iter()
+                .enumerate()
+                .map(move |(index, degree)| ConstraintDegreeInfo {
+                    component,
+                    index,
+                    degree: get_degree(&degree),
+                })
+        })
+        .collect()
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the degree of a constraint described by the provided descriptor.
+///
+/// The descriptor does not expose its base degree and periodic columns directly, and thus the
+/// degree is derived from the evaluation degree of the constraint over a sufficiently long trace:
+/// each periodic column with cycle length `c` adds `n - n / c` to the evaluation degree, which
+/// rounds up to one additional degree.
+fn get_degree(degree: &TransitionConstraintDegree) -> usize {
+    degree.get_evaluation_degree(DEGREE_TRACE_LEN).div_ceil(DEGREE_TRACE_LEN - 1)
+}
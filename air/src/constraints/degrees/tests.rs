@@ -0,0 +1,91 @@
+use super::{
+    chiplets, get_constraint_degree_report, get_degree, range, stack, ConstraintDegreeInfo,
+    MAX_CONSTRAINT_DEGREE,
+};
+use crate::{ProvingOptions, TransitionConstraintDegree};
+use alloc::{string::ToString, vec::Vec};
+
+// UNIT TESTS
+// ================================================================================================
+
+/// Fails when any of the transition constraints of the VM exceeds the degree budget.
+#[test]
+fn constraint_degrees_within_budget() {
+    let exceeding = get_constraint_degree_report()
+        .into_iter()
+        .filter(ConstraintDegreeInfo::exceeds_budget)
+        .map(|info| info.to_string())
+        .collect::<Vec<_>>();
+
+    assert!(exceeding.is_empty(), "constraints exceed the degree budget: {exceeding:?}");
+}
+
+/// Makes sure the report covers exactly the constraints registered with the AIR.
+#[test]
+fn constraint_degree_report_matches_air() {
+    let mut main_degrees = vec![TransitionConstraintDegree::new(1)];
+    main_degrees.append(&mut stack::get_transition_constraint_degrees());
+    main_degrees.append(&mut range::get_transition_constraint_degrees());
+    main_degrees.append(&mut chiplets::get_transition_constraint_degrees());
+    let aux_degrees = range::get_aux_transition_constraint_degrees();
+
+    let num_main_constraints = 1
+        + stack::get_transition_constraint_count()
+        + range::get_transition_constraint_count()
+        + chiplets::get_transition_constraint_count();
+    assert_eq!(num_main_constraints, main_degrees.len());
+
+    let expected = main_degrees.iter().chain(aux_degrees.iter()).map(get_degree);
+    let report = get_constraint_degree_report();
+    assert_eq!(report.len(), main_degrees.len() + aux_degrees.len());
+    assert!(report.iter().map(|info| info.degree).eq(expected));
+
+    // the first and the last constraints of a few components
+    assert_eq!(report[0], degree_info("system", 0, 1));
+    let general = report.iter().rfind(|info| info.component == "stack.general");
+    assert_eq!(general, Some(&degree_info("stack.general", 16, 9)));
+    let selectors = report.iter().find(|info| info.component == "chiplets.selectors");
+    assert_eq!(selectors, Some(&degree_info("chiplets.selectors", 0, 2)));
+    assert_eq!(report.last().map(|info| info.component), Some("range.aux"));
+}
+
+/// Makes sure constraints of the maximum degree can be evaluated with all standard proof options.
+#[test]
+fn max_constraint_degree_fits_blowup_factor() {
+    let min_blowup_factor =
+        TransitionConstraintDegree::new(MAX_CONSTRAINT_DEGREE).min_blowup_factor();
+    for options in [
+        ProvingOptions::REGULAR_96_BITS,
+        ProvingOptions::REGULAR_128_BITS,
+        ProvingOptions::RECURSIVE_96_BITS,
+        ProvingOptions::RECURSIVE_128_BITS,
+    ] {
+        assert!(options.blowup_factor() >= min_blowup_factor);
+    }
+
+    // a constraint of a higher degree would require a larger blowup factor
+    let min_blowup_factor =
+        TransitionConstraintDegree::new(MAX_CONSTRAINT_DEGREE + 1).min_blowup_factor();
+    assert!(ProvingOptions::REGULAR_96_BITS.blowup_factor() < min_blowup_factor);
+}
+
+#[test]
+fn constraint_degree_with_periodic_columns() {
+    assert_eq!(get_degree(&TransitionConstraintDegree::new(1)), 1);
+    assert_eq!(get_degree(&TransitionConstraintDegree::new(9)), 9);
+    assert_eq!(get_degree(&TransitionConstraintDegree::with_cycles(1, vec![2])), 2);
+    assert_eq!(get_degree(&TransitionConstraintDegree::with_cycles(3, vec![8])), 4);
+    assert_eq!(get_degree(&TransitionConstraintDegree::with_cycles(7, vec![32])), 8);
+    assert_eq!(get_degree(&TransitionConstraintDegree::with_cycles(2, vec![8, 32])), 4);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn degree_info(component: &'static str, index: usize, degree: usize) -> ConstraintDegreeInfo {
+    ConstraintDegreeInfo {
+        component,
+        index,
+        degree,
+    }
+}
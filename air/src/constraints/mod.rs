@@ -6,6 +6,7 @@ use crate::trace::{
 use crate::utils::binary_not;
 
 pub mod chiplets;
+pub mod degrees;
 pub mod range;
 pub mod stack;
 
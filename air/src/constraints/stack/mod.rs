@@ -3,6 +3,7 @@ use super::super::{
     TransitionConstraintDegree, CLK_COL_IDX, DECODER_TRACE_OFFSET, FMP_COL_IDX, ONE,
     STACK_AUX_TRACE_OFFSET, STACK_TRACE_OFFSET, ZERO,
 };
+use crate::constraints::degrees::ConstraintDegreeGroup;
 use crate::decoder::{IS_CALL_FLAG_COL_IDX, IS_SYSCALL_FLAG_COL_IDX, USER_OP_HELPERS_OFFSET};
 use crate::utils::{are_equal, is_binary};
 use alloc::vec::Vec;
@@ -56,26 +57,34 @@ pub const NUM_AUX_ASSERTIONS: usize = 2;
 
 /// Build the transition constraint degrees for the stack module and all the stack operations.
 pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
-    let mut degrees = overflow::get_transition_constraint_degrees();
-    // system operations constraints degrees.
-    degrees.append(&mut system_ops::get_transition_constraint_degrees());
-    // field operations constraints degrees.
-    degrees.append(&mut field_ops::get_transition_constraint_degrees());
-    // stack manipulation operations constraints degrees.
-    degrees.append(&mut stack_manipulation::get_transition_constraint_degrees());
-    // u32 operations constraints degrees.
-    degrees.append(&mut u32_ops::get_transition_constraint_degrees());
-    // input/output operations constraints degrees.
-    degrees.append(&mut io_ops::get_transition_constraint_degrees());
-    // Add the degrees of general constraints.
-    degrees.append(
-        &mut CONSTRAINT_DEGREES
-            .iter()
-            .map(|&degree| TransitionConstraintDegree::new(degree))
-            .collect(),
-    );
+    get_transition_constraint_degree_groups()
+        .into_iter()
+        .flat_map(|(_, degrees)| degrees)
+        .collect()
+}
 
-    degrees
+/// Returns the transition constraint degrees for the stack module grouped by the set of stack
+/// operations which define them. The groups are returned in the order in which the constraints
+/// are evaluated.
+pub fn get_transition_constraint_degree_groups() -> Vec<ConstraintDegreeGroup> {
+    vec![
+        ("stack.overflow", overflow::get_transition_constraint_degrees()),
+        ("stack.system_ops", system_ops::get_transition_constraint_degrees()),
+        ("stack.field_ops", field_ops::get_transition_constraint_degrees()),
+        (
+            "stack.stack_manipulation",
+            stack_manipulation::get_transition_constraint_degrees(),
+        ),
+        ("stack.u32_ops", u32_ops::get_transition_constraint_degrees()),
+        ("stack.io_ops", io_ops::get_transition_constraint_degrees()),
+        (
+            "stack.general",
+            CONSTRAINT_DEGREES
+                .iter()
+                .map(|&degree| TransitionConstraintDegree::new(degree))
+                .collect(),
+        ),
+    ]
 }
 
 /// Returns the number of transition constraints for the stack operations.
@@ -4,9 +4,11 @@ use super::{
     generate_evaluation_frame, OpFlags, DECODER_TRACE_OFFSET, DEGREE_4_OPCODE_ENDS,
     DEGREE_4_OPCODE_STARTS, DEGREE_6_OPCODE_ENDS, DEGREE_6_OPCODE_STARTS, DEGREE_7_OPCODE_ENDS,
     DEGREE_7_OPCODE_STARTS, NUM_DEGREE_4_OPS, NUM_DEGREE_5_OPS, NUM_DEGREE_6_OPS, NUM_DEGREE_7_OPS,
+    OP_BITS_EXTRA_COLS_RANGE, OP_BITS_RANGE, TRACE_WIDTH,
 };
 use crate::trace::decoder::IS_LOOP_FLAG_COL_IDX;
-use vm_core::{Operation, ONE, ZERO};
+use vm_core::{Felt, Operation, ONE, ZERO};
+use winter_air::EvaluationFrame;
 
 /// Asserts the op flag to ONE for degree 7 operation which is being executed in the current
 /// frame; assert all the other operation flags to ZERO as they are not present in the current
@@ -592,3 +594,46 @@ fn composite_flags() {
     assert_eq!(op_flags.control_flow(), ONE);
     assert_eq!(op_flags.top_binary(), ZERO);
 }
+
+/// Evaluates op flags over non-binary op bits and compares the results against values computed by
+/// hand from the flag polynomials. Unlike the tests above, this guards the exact form of the flag
+/// polynomials rather than only their values over valid op bits.
+#[test]
+fn op_flags_test_vectors() {
+    // op bits are set to b_i = i + 2, and the degree reduction columns to e_0 = 10 and e_1 = 11.
+    let mut current = vec![ZERO; TRACE_WIDTH];
+    for i in 0..7 {
+        current[DECODER_TRACE_OFFSET + OP_BITS_RANGE.start + i] = Felt::new(i as u64 + 2);
+    }
+    current[DECODER_TRACE_OFFSET + OP_BITS_EXTRA_COLS_RANGE.start] = Felt::new(10);
+    current[DECODER_TRACE_OFFSET + OP_BITS_EXTRA_COLS_RANGE.start + 1] = Felt::new(11);
+    let frame = EvaluationFrame::<Felt>::from_rows(current, vec![ZERO; TRACE_WIDTH]);
+    let op_flags = OpFlags::new(&frame);
+
+    // degree 7: NOOP = (1 - b6)(1 - b5)(1 - b4)(1 - b3)(1 - b2)(1 - b1)(1 - b0)
+    //                = (-7)(-6)(-5)(-4)(-3)(-2)(-1)
+    assert_eq!(op_flags.noop(), -Felt::new(5040));
+
+    // degree 6: U32ADD = b6(1 - b5)(1 - b4)(1 - b3)(1 - b2)(1 - b1) = 8(-6)(-5)(-4)(-3)(-2)
+    assert_eq!(op_flags.u32add(), -Felt::new(5760));
+    // degree 6: U32SPLIT = b6(1 - b5)(1 - b4)b3(1 - b2)(1 - b1) = 8(-6)(-5)(5)(-3)(-2)
+    assert_eq!(op_flags.u32split(), Felt::new(7200));
+    // degree 6: U32ADD3 = b6(1 - b5)(1 - b4)b3b2(1 - b1) = 8(-6)(-5)(5)(4)(-2)
+    assert_eq!(op_flags.u32add3(), -Felt::new(9600));
+
+    // degree 5: HPERM = e0(1 - b3)(1 - b2)(1 - b1)(1 - b0) = 10(-4)(-3)(-2)(-1)
+    assert_eq!(op_flags.hperm(), Felt::new(240));
+    // degree 5: MPVERIFY = e0(1 - b3)(1 - b2)(1 - b1)b0 = 10(-4)(-3)(-2)(2)
+    assert_eq!(op_flags.mpverify(), -Felt::new(480));
+
+    // degree 4: PUSH = e1(1 - b4)(1 - b3)b2 = 11(-5)(-4)(4)
+    assert_eq!(op_flags.push(), Felt::new(880));
+    // degree 4: END = e1 b4(1 - b3)(1 - b2) = 11(6)(-4)(-3)
+    assert_eq!(op_flags.end(), Felt::new(792));
+
+    // u32 range check flag: b6(1 - b5)(1 - b4) = 8(-6)(-5)
+    assert_eq!(op_flags.u32_rc_op(), Felt::new(240));
+
+    // right shift: (1 - b6)b5b4 + PUSH + U32SPLIT = (-7)(7)(6) + 880 + 7200
+    assert_eq!(op_flags.right_shift(), Felt::new(7786));
+}
@@ -19,8 +19,8 @@ use winter_air::{
 use winter_prover::matrix::ColMatrix;
 
 mod constraints;
-pub use constraints::stack;
 use constraints::{chiplets, range};
+pub use constraints::{degrees, stack};
 
 pub mod trace;
 use trace::*;
@@ -36,8 +36,8 @@ use utils::TransitionConstraintRange;
 // ================================================================================================
 
 pub use errors::ExecutionOptionsError;
-pub use options::{ExecutionOptions, ProvingOptions};
-pub use proof::{ExecutionProof, HashFunction};
+pub use options::{CancellationToken, ExecutionOptions, ProvingOptions};
+pub use proof::{BusArgument, BusArguments, BusRelation, ExecutionProof, HashFunction};
 pub use vm_core::{
     utils::{DeserializationError, ToElements},
     Felt, FieldElement, StarkField,
@@ -1,6 +1,9 @@
 use super::{
     trace::MIN_TRACE_LEN, ExecutionOptionsError, FieldExtension, HashFunction, WinterProofOptions,
 };
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+use vm_core::chiplets::hasher::Digest;
 
 // PROVING OPTIONS
 // ================================================================================================
@@ -146,12 +149,33 @@ impl From<ProvingOptions> for WinterProofOptions {
 ///
 /// - `max_cycles` specifies the maximum number of cycles a program is allowed to execute.
 /// - `expected_cycles` specifies the number of cycles a program is expected to execute.
+/// - `overflow_eviction_threshold` specifies the number of stack overflow table rows the VM keeps
+///   in memory before evicting the rest into the host (disabled by default).
+/// - `cycle_interval` specifies the number of cycles between consecutive invocations of the host's
+///   cycle interval handler (disabled by default).
+/// - `enable_advice_commitment` specifies whether the VM should compute a commitment to all
+///   values consumed from the advice stack during execution (disabled by default).
+/// - `enable_memory_commitment` specifies whether the VM should compute a commitment to the
+///   initial state of the memory (disabled by default).
+/// - `skip_unused_chiplets` specifies whether the VM should omit trace rows of chiplets which were
+///   not used during execution (disabled by default).
+/// - `enable_local_canaries` specifies whether the VM should surround frames of procedure locals
+///   with canary words (disabled by default).
+/// - `expected_program_hash` specifies the hash which the executed program must have (not set by
+///   default).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ExecutionOptions {
     max_cycles: u32,
     expected_cycles: u32,
     enable_tracing: bool,
     enable_debugging: bool,
+    enable_advice_commitment: bool,
+    enable_memory_commitment: bool,
+    overflow_eviction_threshold: Option<u32>,
+    cycle_interval: Option<u32>,
+    skip_unused_chiplets: bool,
+    enable_local_canaries: bool,
+    expected_program_hash: Option<Digest>,
 }
 
 impl Default for ExecutionOptions {
@@ -161,6 +185,13 @@ impl Default for ExecutionOptions {
             expected_cycles: MIN_TRACE_LEN as u32,
             enable_tracing: false,
             enable_debugging: false,
+            enable_advice_commitment: false,
+            enable_memory_commitment: false,
+            overflow_eviction_threshold: None,
+            cycle_interval: None,
+            skip_unused_chiplets: false,
+            enable_local_canaries: false,
+            expected_program_hash: None,
         }
     }
 }
@@ -194,6 +225,13 @@ impl ExecutionOptions {
             expected_cycles,
             enable_tracing,
             enable_debugging: false,
+            enable_advice_commitment: false,
+            enable_memory_commitment: false,
+            overflow_eviction_threshold: None,
+            cycle_interval: None,
+            skip_unused_chiplets: false,
+            enable_local_canaries: false,
+            expected_program_hash: None,
         })
     }
 
@@ -214,6 +252,103 @@ impl ExecutionOptions {
         self
     }
 
+    /// Enables computation of a commitment to the advice inputs consumed during execution.
+    ///
+    /// When enabled, the VM records all values popped from the advice stack (e.g., via `adv_push`,
+    /// `adv_loadw`, and `adv_pipe` instructions) in the order in which they were popped, and
+    /// exposes an RPO hash of the resulting sequence via the execution trace. This includes values
+    /// moved onto the advice stack from the advice map, preceded by the key of the map entry they
+    /// were read from. Merkle paths read from the advice provider are not included since they are
+    /// already bound to the Merkle roots on the operand stack.
+    ///
+    /// The commitment is not included in proofs of such executions, since the AIR constraints do
+    /// not tie the consumed advice to the execution trace.
+    pub fn with_advice_commitment(mut self) -> Self {
+        self.enable_advice_commitment = true;
+        self
+    }
+
+    /// Enables computation of a commitment to the initial state of the memory.
+    ///
+    /// When enabled, the VM computes an RPO hash of the words placed into memory before execution
+    /// starts (see `MemoryInputs::commitment()` in the processor), and exposes it via the
+    /// execution trace. The commitment is computed even if no words were placed into memory.
+    ///
+    /// The commitment is not included in proofs of such executions, since the initial memory
+    /// writes are not constrained by the AIR.
+    pub fn with_memory_commitment(mut self) -> Self {
+        self.enable_memory_commitment = true;
+        self
+    }
+
+    /// Enables eviction of stack overflow table rows into the host.
+    ///
+    /// Whenever the number of overflow table rows held in memory exceeds `threshold`, the deepest
+    /// half of them is handed over to the host as a single chunk together with a commitment to
+    /// it. Evicted chunks are requested back from the host (and checked against the commitment)
+    /// once all rows held in memory have been removed from the table. This bounds the memory used
+    /// by the overflow table during deep recursion, and does not affect the execution trace.
+    ///
+    /// Threshold values smaller than 2 are rounded up to 2. Eviction is not performed in debug
+    /// mode, since the full state of the overflow table is recorded at every cycle in that mode.
+    pub fn with_overflow_eviction(mut self, threshold: u32) -> Self {
+        self.overflow_eviction_threshold = Some(threshold.max(2));
+        self
+    }
+
+    /// Enables periodic invocation of the host's cycle interval handler.
+    ///
+    /// The handler is invoked with the current state of the process after every `interval`
+    /// cycles, and can be used to implement gas metering, progress reporting, or cooperative
+    /// cancellation of execution (by returning an error from the handler).
+    ///
+    /// Interval value of 0 is rounded up to 1.
+    pub fn with_cycle_interval(mut self, interval: u32) -> Self {
+        self.cycle_interval = Some(interval.max(1));
+        self
+    }
+
+    /// Enables omission of trace rows of chiplets which were not used during execution.
+    ///
+    /// By default, the kernel ROM chiplet contains at least one row for every procedure of the
+    /// kernel, even if the program never invokes kernel procedures via `syscall`. When this option
+    /// is enabled, the kernel ROM section of the chiplets trace is left empty for such programs,
+    /// which may reduce the length of the execution trace. Sections of other chiplets are always
+    /// sized according to their usage, and thus, are already empty if the chiplets are not used.
+    ///
+    /// This does not affect the width of the execution trace since all chiplets share the same
+    /// set of trace columns.
+    pub fn with_unused_chiplets_skipped(mut self) -> Self {
+        self.skip_unused_chiplets = true;
+        self
+    }
+
+    /// Enables canary words around frames of procedure locals.
+    ///
+    /// When enabled, the VM leaves a gap of one word between consecutive frames of procedure
+    /// locals and fills it with a canary value when the frame is allocated. The canaries around a
+    /// frame are checked when the frame is released at procedure exit, so that a write outside of
+    /// the frame (e.g., due to a wrong `locaddr` computation) results in an error instead of
+    /// silently corrupting locals of another procedure.
+    ///
+    /// This is a debugging aid only: since the layout of procedure locals differs from the one
+    /// assumed by the AIR, the resulting execution trace cannot be proven.
+    pub fn with_local_canaries(mut self) -> Self {
+        self.enable_local_canaries = true;
+        self
+    }
+
+    /// Sets the hash which the executed program is expected to have.
+    ///
+    /// When set, execution (and proof generation) of a program with a different hash fails with
+    /// `ExecutionError::ProgramHashMismatch` before the first cycle is executed. This guards
+    /// against executing a stale build of a program, which would otherwise be discovered only when
+    /// the resulting proof is verified against the expected program.
+    pub fn with_expected_program_hash(mut self, hash: Digest) -> Self {
+        self.expected_program_hash = Some(hash);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -240,4 +375,87 @@ impl ExecutionOptions {
     pub fn enable_debugging(&self) -> bool {
         self.enable_debugging
     }
+
+    /// Returns a flag indicating whether the VM should compute a commitment to the advice inputs
+    /// consumed during execution.
+    pub fn enable_advice_commitment(&self) -> bool {
+        self.enable_advice_commitment
+    }
+
+    /// Returns a flag indicating whether the VM should compute a commitment to the initial state
+    /// of the memory.
+    pub fn enable_memory_commitment(&self) -> bool {
+        self.enable_memory_commitment
+    }
+
+    /// Returns the number of stack overflow table rows the VM keeps in memory before evicting the
+    /// rest into the host, or None if eviction is disabled.
+    pub fn overflow_eviction_threshold(&self) -> Option<u32> {
+        self.overflow_eviction_threshold
+    }
+
+    /// Returns the number of cycles between consecutive invocations of the host's cycle interval
+    /// handler, or None if the handler is not invoked.
+    pub fn cycle_interval(&self) -> Option<u32> {
+        self.cycle_interval
+    }
+
+    /// Returns a flag indicating whether the VM should omit trace rows of chiplets which were
+    /// not used during execution.
+    pub fn skip_unused_chiplets(&self) -> bool {
+        self.skip_unused_chiplets
+    }
+
+    /// Returns a flag indicating whether the VM should surround frames of procedure locals with
+    /// canary words.
+    pub fn enable_local_canaries(&self) -> bool {
+        self.enable_local_canaries
+    }
+
+    /// Returns the hash which the executed program is expected to have, or None if the hash of the
+    /// program is not checked.
+    pub fn expected_program_hash(&self) -> Option<Digest> {
+        self.expected_program_hash
+    }
 }
+
+// CANCELLATION TOKEN
+// ================================================================================================
+
+/// A token which can be used to cancel execution and proving of a program from another thread.
+///
+/// The token is passed to the VM separately from [ExecutionOptions] (e.g., via
+/// `processor::execute_with_token()` or `prover::prove_with_token()`), so that the options remain
+/// a plain `Copy` value.
+///
+/// The token is cheap to clone, and all clones share the same state: cancelling one of them
+/// cancels all of them. Cancellation is cooperative: the VM checks the token at code block
+/// boundaries and before STARK proof generation starts, and returns an error once the token has
+/// been cancelled. Thus, all resources allocated for the job are released in the usual way.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Returns a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels all jobs associated with this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CancellationToken {}
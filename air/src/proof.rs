@@ -1,7 +1,10 @@
 use alloc::vec::Vec;
+use core::fmt;
 use vm_core::{
     crypto::hash::{Blake3_192, Blake3_256, Hasher, Rpo256},
-    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+    },
 };
 use winter_air::proof::StarkProof;
 
@@ -16,6 +19,7 @@ use winter_air::proof::StarkProof;
 pub struct ExecutionProof {
     pub proof: StarkProof,
     pub hash_fn: HashFunction,
+    pub bus_arguments: BusArguments,
 }
 
 impl ExecutionProof {
@@ -24,8 +28,20 @@ impl ExecutionProof {
 
     /// Creates a new instance of [ExecutionProof] from the specified STARK proof and hash
     /// function.
+    ///
+    /// All bus relations of the proof are assumed to be enforced via auxiliary trace columns.
     pub const fn new(proof: StarkProof, hash_fn: HashFunction) -> Self {
-        Self { proof, hash_fn }
+        Self {
+            proof,
+            hash_fn,
+            bus_arguments: BusArguments::AUX_COLUMNS,
+        }
+    }
+
+    /// Sets the arguments used to enforce bus relations in this proof.
+    pub const fn with_bus_arguments(mut self, bus_arguments: BusArguments) -> Self {
+        self.bus_arguments = bus_arguments;
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -41,6 +57,11 @@ impl ExecutionProof {
         self.hash_fn
     }
 
+    /// Returns the arguments used to enforce bus relations in this proof.
+    pub const fn bus_arguments(&self) -> BusArguments {
+        self.bus_arguments
+    }
+
     /// Returns conjectured security level of this proof in bits.
     pub fn security_level(&self) -> u32 {
         match self.hash_fn {
@@ -54,22 +75,41 @@ impl ExecutionProof {
     // --------------------------------------------------------------------------------------------
 
     /// Serializes this proof into a vector of bytes.
+    ///
+    /// The first byte encodes the hash function. If any bus relation of the proof is enforced via
+    /// the virtual bus, the highest bit of the first byte is set and the bus arguments
+    /// are encoded in the second byte. Thus, proofs which use auxiliary columns only are encoded
+    /// in the same way as before bus arguments were introduced.
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = self.proof.to_bytes();
-        assert!(!bytes.is_empty(), "invalid STARK proof");
-        // TODO: ideally we should write hash function into the proof first to avoid reallocations
-        bytes.insert(0, self.hash_fn as u8);
+        let mut bytes = Vec::new();
+        self.write_header_into(&mut bytes);
+        self.proof.write_into(&mut bytes);
         bytes
     }
 
     /// Reads the source bytes, parsing a new proof instance.
     pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
-        if source.len() < 2 {
-            return Err(DeserializationError::UnexpectedEOF);
+        let mut source = SliceReader::new(source);
+        let header = ProofHeader::read_from(&mut source)?;
+        let proof = StarkProof::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(header.into_proof(proof))
+    }
+
+    /// Writes the hash function and the bus arguments of this proof into the target, as read by
+    /// [ProofHeader::read_from()].
+    fn write_header_into<W: ByteWriter>(&self, target: &mut W) {
+        let mut header = self.hash_fn as u8;
+        if self.bus_arguments != BusArguments::AUX_COLUMNS {
+            header |= BusArguments::FLAG;
+        }
+        target.write_u8(header);
+
+        if self.bus_arguments != BusArguments::AUX_COLUMNS {
+            target.write_u8(self.bus_arguments.0);
         }
-        let hash_fn = HashFunction::try_from(source[0])?;
-        let proof = StarkProof::from_bytes(&source[1..])?;
-        Ok(Self::new(proof, hash_fn))
     }
 
     // DESTRUCTOR
@@ -128,6 +168,129 @@ impl TryFrom<u8> for HashFunction {
     }
 }
 
+// BUS ARGUMENTS
+// ================================================================================================
+
+/// A relation between components of the VM which is enforced via a bus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum BusRelation {
+    /// The block stack table of the decoder.
+    BlockStack = 0,
+    /// The block hash table of the decoder.
+    BlockHash = 1,
+    /// The op group table of the decoder.
+    OpGroup = 2,
+    /// The overflow table of the stack.
+    StackOverflow = 3,
+    /// The lookups into the range checker.
+    RangeChecker = 4,
+    /// The sibling table of the hasher chiplet.
+    HasherSiblings = 5,
+    /// The requests to and responses from the chiplets.
+    Chiplets = 6,
+}
+
+impl BusRelation {
+    /// All bus relations of the VM.
+    pub const ALL: [Self; 7] = [
+        Self::BlockStack,
+        Self::BlockHash,
+        Self::OpGroup,
+        Self::StackOverflow,
+        Self::RangeChecker,
+        Self::HasherSiblings,
+        Self::Chiplets,
+    ];
+}
+
+impl fmt::Display for BusRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlockStack => write!(f, "block stack table"),
+            Self::BlockHash => write!(f, "block hash table"),
+            Self::OpGroup => write!(f, "op group table"),
+            Self::StackOverflow => write!(f, "stack overflow table"),
+            Self::RangeChecker => write!(f, "range checker bus"),
+            Self::HasherSiblings => write!(f, "hasher sibling table"),
+            Self::Chiplets => write!(f, "chiplets bus"),
+        }
+    }
+}
+
+/// An argument used to prove that a bus relation holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BusArgument {
+    /// The relation is enforced via a running product or LogUp column of the auxiliary trace.
+    AuxColumn,
+    /// The relation is enforced via the GKR-based virtual bus.
+    ///
+    /// Reserved: the prover does not implement the virtual bus yet, so proofs generated by this
+    /// version never use this argument, and the verifier rejects proofs which do.
+    VirtualBus,
+}
+
+/// The arguments used to enforce each bus relation in a proof.
+///
+/// Bus relations are being migrated from auxiliary trace columns to the GKR-based virtual bus one
+/// relation at a time. During the migration, a proof records which argument was used for every
+/// relation, so that the verifier can check each relation accordingly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BusArguments(u8);
+
+impl BusArguments {
+    /// All bus relations are enforced via auxiliary trace columns.
+    pub const AUX_COLUMNS: Self = Self(0);
+
+    /// Bit set in the first byte of a serialized proof when the bus arguments are encoded in the
+    /// following byte; this bit is never set in the encoding of a [HashFunction].
+    const FLAG: u8 = 0x80;
+
+    /// Sets the argument used to enforce the specified relation.
+    pub const fn with(self, relation: BusRelation, argument: BusArgument) -> Self {
+        let mask = 1 << relation as u8;
+        match argument {
+            BusArgument::AuxColumn => Self(self.0 & !mask),
+            BusArgument::VirtualBus => Self(self.0 | mask),
+        }
+    }
+
+    /// Returns the argument used to enforce the specified relation.
+    pub const fn get(&self, relation: BusRelation) -> BusArgument {
+        if self.0 & (1 << relation as u8) == 0 {
+            BusArgument::AuxColumn
+        } else {
+            BusArgument::VirtualBus
+        }
+    }
+
+    /// Returns an iterator over the relations enforced via the virtual bus.
+    pub fn virtual_bus_relations(&self) -> impl Iterator<Item = BusRelation> + '_ {
+        BusRelation::ALL
+            .into_iter()
+            .filter(|relation| self.get(*relation) == BusArgument::VirtualBus)
+    }
+}
+
+impl Default for BusArguments {
+    fn default() -> Self {
+        Self::AUX_COLUMNS
+    }
+}
+
+impl TryFrom<u8> for BusArguments {
+    type Error = DeserializationError;
+
+    fn try_from(repr: u8) -> Result<Self, Self::Error> {
+        if repr >> BusRelation::ALL.len() != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "the bus arguments representation {repr} is not valid!"
+            )));
+        }
+        Ok(Self(repr))
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -146,15 +309,45 @@ impl Deserializable for HashFunction {
 impl Serializable for ExecutionProof {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.proof.write_into(target);
-        self.hash_fn.write_into(target);
+        self.write_header_into(target);
     }
 }
 
 impl Deserializable for ExecutionProof {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let proof = StarkProof::read_from(source)?;
-        let hash_fn = HashFunction::read_from(source)?;
+        let header = ProofHeader::read_from(source)?;
+        Ok(header.into_proof(proof))
+    }
+}
+
+/// The hash function and the bus arguments of a proof, as encoded by
+/// [ExecutionProof::to_bytes()].
+struct ProofHeader {
+    hash_fn: HashFunction,
+    bus_arguments: BusArguments,
+}
 
-        Ok(ExecutionProof { proof, hash_fn })
+impl ProofHeader {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = source.read_u8()?;
+        let hash_fn = HashFunction::try_from(header & !BusArguments::FLAG)?;
+        let bus_arguments = if header & BusArguments::FLAG == 0 {
+            BusArguments::AUX_COLUMNS
+        } else {
+            BusArguments::try_from(source.read_u8()?)?
+        };
+        Ok(Self {
+            hash_fn,
+            bus_arguments,
+        })
+    }
+
+    fn into_proof(self, proof: StarkProof) -> ExecutionProof {
+        ExecutionProof {
+            proof,
+            hash_fn: self.hash_fn,
+            bus_arguments: self.bus_arguments,
+        }
     }
 }
@@ -43,12 +43,13 @@ impl AssemblyContext {
     ///
     /// If [ProgramAst] is provided, the context will contain info about the procedures imported
     /// by the program, and thus, will be able to determine names of imported procedures for error
-    /// reporting purposes.
+    /// reporting purposes. It will also allocate the memory locals declared for the program body.
     pub fn for_program(program: Option<&ProgramAst>) -> Self {
         let program_imports =
             program.map(|p| p.import_info().get_imported_procedures()).unwrap_or_default();
+        let num_locals = program.map(|p| p.num_locals()).unwrap_or_default();
         Self {
-            module_stack: vec![ModuleContext::for_program(program_imports)],
+            module_stack: vec![ModuleContext::for_program(program_imports, num_locals)],
             is_kernel: false,
             kernel: None,
             allow_phantom_calls: false,
@@ -76,6 +77,11 @@ impl AssemblyContext {
         self.is_kernel
     }
 
+    /// Returns true if phantom calls are allowed in this context.
+    pub fn allows_phantom_calls(&self) -> bool {
+        self.allow_phantom_calls
+    }
+
     /// Returns the number of memory locals allocated for the procedure currently being compiled.
     pub fn num_proc_locals(&self) -> u16 {
         self.current_proc_context().expect("no procedures").num_locals
@@ -267,6 +273,22 @@ impl AssemblyContext {
         }
     }
 
+    /// Registers a dependency of the module currently being compiled on the procedure with the
+    /// specified ID and MAST root.
+    ///
+    /// This is a no-op if no modules are being compiled.
+    pub(crate) fn register_dependency(&mut self, proc_id: ProcedureId, mast_root: RpoDigest) {
+        if let Some(module_context) = self.module_stack.last_mut() {
+            module_context.dependencies.insert(proc_id, mast_root);
+        }
+    }
+
+    /// Removes and returns the dependencies registered for the module currently being compiled.
+    pub(crate) fn take_module_dependencies(&mut self) -> BTreeMap<ProcedureId, RpoDigest> {
+        let module_context = self.module_stack.last_mut().expect("no modules");
+        core::mem::take(&mut module_context.dependencies)
+    }
+
     // CONTEXT FINALIZERS
     // --------------------------------------------------------------------------------------------
 
@@ -285,6 +307,11 @@ impl AssemblyContext {
     ///
     /// This method is invoked at the end of the compilation of an executable program.
     ///
+    /// If `eliminate_dead_code` is true, the table contains only the procedures reachable from
+    /// the main procedure of the program. Otherwise, it also contains all local procedures of the
+    /// program together with the procedures they invoke, so that local procedures can be used
+    /// as dynamic call targets.
+    ///
     /// # Panics
     /// Panics if:
     /// - There is not exactly one module left on the module stack.
@@ -296,13 +323,14 @@ impl AssemblyContext {
     pub fn into_cb_table(
         mut self,
         proc_cache: &ProcedureCache,
+        eliminate_dead_code: bool,
     ) -> Result<CodeBlockTable, AssemblyError> {
         // get the last module off the module stack
         assert_eq!(self.module_stack.len(), 1, "module stack must contain exactly one module");
         let mut main_module_context = self.module_stack.pop().unwrap();
-        // complete compilation of the executable module; this appends the callset of the main
-        // procedure to the callset of the executable module
-        main_module_context.complete_executable();
+        // complete compilation of the executable module; this sets the callset of the executable
+        // module to the set of procedures which need to be included in the code block table
+        main_module_context.complete_executable(eliminate_dead_code);
 
         // build the code block table based on the callset of the executable module; called
         // procedures can be either in the specified procedure cache (for procedures imported from
@@ -329,6 +357,11 @@ impl AssemblyContext {
         self.module_stack.last().and_then(|m| m.proc_stack.last())
     }
 
+    /// Returns the path of the module currently being compiled.
+    pub(crate) fn current_module_path(&self) -> &LibraryPath {
+        &self.module_stack.last().expect("no modules").path
+    }
+
     /// Returns the name of the current procedure, or the reserved name for the main block.
     pub(crate) fn current_context_name(&self) -> &str {
         self.current_proc_context()
@@ -355,6 +388,8 @@ struct ModuleContext {
     callset: CallSet,
     /// A map containing id and names of all imported procedures in the module.
     proc_map: BTreeMap<ProcedureId, ProcedureName>,
+    /// IDs and MAST roots of external procedures invoked or re-exported from this module.
+    dependencies: BTreeMap<ProcedureId, RpoDigest>,
 }
 
 impl ModuleContext {
@@ -364,16 +399,17 @@ impl ModuleContext {
     /// Returns a new [ModuleContext] instantiated for compiling an executable module.
     ///
     /// Procedure in the returned module context is initialized with procedure context for the
-    /// "main" procedure.
-    pub fn for_program(proc_map: BTreeMap<ProcedureId, ProcedureName>) -> Self {
+    /// "main" procedure with the specified number of locals.
+    pub fn for_program(proc_map: BTreeMap<ProcedureId, ProcedureName>, num_locals: u16) -> Self {
         let name = ProcedureName::main();
-        let main_proc_context = ProcedureContext::new(name, false, 0);
+        let main_proc_context = ProcedureContext::new(name, false, num_locals);
         Self {
             proc_stack: vec![main_proc_context],
             compiled_procs: Vec::new(),
             path: LibraryPath::exec_path(),
             callset: CallSet::default(),
             proc_map,
+            dependencies: BTreeMap::new(),
         }
     }
 
@@ -390,6 +426,7 @@ impl ModuleContext {
             path: module_path.clone(),
             callset: CallSet::default(),
             proc_map,
+            dependencies: BTreeMap::new(),
         }
     }
 
@@ -514,18 +551,30 @@ impl ModuleContext {
     /// Executable modules are not completed the same way library modules are. Thus, at the end of
     /// compiling a program, the executable module will have the main procedure left on the
     /// procedure stack. To complete the module we need to pop the main procedure off the stack and
-    /// append its callset to the callset of the module context.
+    /// set the callset of the module context to the set of procedures invoked by the program.
+    ///
+    /// If `eliminate_dead_code` is true, the callset of the module is set to the callset of the
+    /// main procedure. Since callsets of procedures include callsets of all procedures they
+    /// invoke, this drops all procedures which are not reachable from the main procedure.
+    /// Otherwise, all local procedures of the module are added to the callset of the module.
     ///
     /// # Panics
     /// - If this module is not an executable module.
     /// - If there is not exactly one procedure left on the procedure stack.
     /// - If the procedure left on the procedure stack is not main procedure.
-    pub fn complete_executable(&mut self) {
+    pub fn complete_executable(&mut self, eliminate_dead_code: bool) {
         assert!(self.is_executable(), "module not executable");
         assert_eq!(self.proc_stack.len(), 1, "procedure stack must contain exactly one procedure");
         let main_proc_context = self.proc_stack.pop().unwrap();
         assert!(main_proc_context.is_main(), "not main procedure");
-        self.callset.append(&main_proc_context.callset);
+        if eliminate_dead_code {
+            self.callset = main_proc_context.callset;
+        } else {
+            for proc in self.compiled_procs.iter() {
+                self.callset.insert(proc.mast_root());
+            }
+            self.callset.append(&main_proc_context.callset);
+        }
     }
 }
 
@@ -0,0 +1,207 @@
+use super::{CodeBody, Instruction, Node};
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+// DEAD STORE ELIMINATION
+// ================================================================================================
+
+/// Returns a copy of the provided procedure body with all writes to procedure locals which are
+/// never read afterwards removed.
+///
+/// A `loc_storew.i` instruction is removed if none of the elements of local `i` can be read
+/// before they are overwritten or the procedure returns. A `loc_store.i` instruction writes only
+/// the first element of local `i`, and since it also removes the stored value from the stack, a
+/// dead `loc_store.i` instruction is replaced with `drop`.
+///
+/// Liveness of locals is tracked through `if.true`, `while.true`, `repeat`, and `@if` blocks.
+/// Bodies of procedures which take addresses of their locals via `locaddr` are returned unchanged,
+/// since accesses to locals via such addresses cannot be tracked. Debug, event, and advice injection
+/// instructions are assumed to read all locals of the procedure.
+pub fn eliminate_dead_local_stores(body: &CodeBody, num_locals: u16) -> CodeBody {
+    if num_locals == 0 || takes_local_address(body) {
+        return body.clone();
+    }
+
+    let eliminator = DeadStoreEliminator { num_locals };
+    eliminator.rewrite_body(body, &mut LiveLocals::default())
+}
+
+// DEAD STORE ELIMINATOR
+// ================================================================================================
+
+/// A set of procedure locals whose current values may still be read.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct LiveLocals {
+    /// Indexes of locals whose first element may be read.
+    first: BTreeSet<u16>,
+    /// Indexes of locals whose remaining three elements may be read.
+    rest: BTreeSet<u16>,
+}
+
+impl LiveLocals {
+    /// Adds all locals of the other set to this set.
+    fn extend(&mut self, other: &Self) {
+        self.first.extend(other.first.iter().copied());
+        self.rest.extend(other.rest.iter().copied());
+    }
+}
+
+/// Removes writes to procedure locals which are never read by walking procedure bodies backwards
+/// and tracking the set of locals which may be read later on.
+struct DeadStoreEliminator {
+    num_locals: u16,
+}
+
+impl DeadStoreEliminator {
+    /// Rewrites the provided body given the set of locals which may be read after the body is
+    /// executed, and updates this set to contain locals which may be read when the body starts
+    /// executing.
+    fn rewrite_body(&self, body: &CodeBody, live: &mut LiveLocals) -> CodeBody {
+        let nodes = body.nodes();
+        let locations = body.source_locations();
+
+        let mut new_nodes = Vec::with_capacity(nodes.len());
+        let mut new_locations = Vec::with_capacity(locations.len());
+        for (i, node) in nodes.iter().enumerate().rev() {
+            if let Some(node) = self.rewrite_node(node, live) {
+                new_nodes.push(node);
+                new_locations.extend(locations.get(i).copied());
+            }
+        }
+        new_nodes.reverse();
+        new_locations.reverse();
+
+        // keep the location of the `end` token of the body, if any
+        new_locations.extend(locations.iter().skip(nodes.len()).copied());
+
+        CodeBody::new(new_nodes).with_source_locations(new_locations)
+    }
+
+    /// Rewrites a single node, returning None if the node should be removed.
+    fn rewrite_node(&self, node: &Node, live: &mut LiveLocals) -> Option<Node> {
+        match node {
+            Node::Instruction(instruction) => self.rewrite_instruction(instruction, live),
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                let mut false_live = live.clone();
+                let true_case = self.rewrite_body(true_case, live);
+                let false_case = self.rewrite_body(false_case, &mut false_live);
+                live.extend(&false_live);
+                Some(Node::IfElse {
+                    true_case,
+                    false_case,
+                })
+            }
+            Node::Repeat { times, body } => {
+                let body = self.rewrite_loop_body(body, live);
+                Some(Node::Repeat {
+                    times: *times,
+                    body,
+                })
+            }
+            Node::While { body } => {
+                let body = self.rewrite_loop_body(body, live);
+                Some(Node::While { body })
+            }
+            Node::IfFeature {
+                feature,
+                true_case,
+                false_case,
+            } => {
+                let mut false_live = live.clone();
+                let true_case = self.rewrite_body(true_case, live);
+                let false_case = self.rewrite_body(false_case, &mut false_live);
+                live.extend(&false_live);
+                Some(Node::IfFeature {
+                    feature: feature.clone(),
+                    true_case,
+                    false_case,
+                })
+            }
+        }
+    }
+
+    /// Rewrites a single instruction, returning None if the instruction should be removed.
+    fn rewrite_instruction(
+        &self,
+        instruction: &Instruction,
+        live: &mut LiveLocals,
+    ) -> Option<Node> {
+        match instruction {
+            Instruction::LocLoad(idx) => {
+                live.first.insert(*idx);
+            }
+            Instruction::LocLoadW(idx) => {
+                live.first.insert(*idx);
+                live.rest.insert(*idx);
+            }
+            // out-of-bounds indexes are left for the assembler to report
+            Instruction::LocStore(idx) if *idx < self.num_locals => {
+                let first_live = live.first.remove(idx);
+                if !first_live {
+                    return Some(Node::Instruction(Instruction::Drop));
+                }
+            }
+            Instruction::LocStoreW(idx) if *idx < self.num_locals => {
+                let first_live = live.first.remove(idx);
+                let rest_live = live.rest.remove(idx);
+                if !first_live && !rest_live {
+                    return None;
+                }
+            }
+            Instruction::Debug(_)
+            | Instruction::Emit(_)
+            | Instruction::Trace(_)
+            | Instruction::AdvInject(_) => {
+                live.first.extend(0..self.num_locals);
+                live.rest.extend(0..self.num_locals);
+            }
+            _ => (),
+        }
+        Some(Node::Instruction(instruction.clone()))
+    }
+
+    /// Rewrites the body of a loop given the set of locals which may be read after the loop
+    /// exits, and updates this set to contain locals which may be read when the loop is entered.
+    ///
+    /// Since the body of a loop may be executed many times, locals read by the body are live at
+    /// the end of the body as well. Thus, the set of locals live at the start of the body is
+    /// computed iteratively until it stops changing.
+    fn rewrite_loop_body(&self, body: &CodeBody, live: &mut LiveLocals) -> CodeBody {
+        let mut loop_live = live.clone();
+        loop {
+            let mut body_live = loop_live.clone();
+            self.rewrite_body(body, &mut body_live);
+            body_live.extend(live);
+            if body_live == loop_live {
+                break;
+            }
+            loop_live = body_live;
+        }
+
+        *live = loop_live.clone();
+        self.rewrite_body(body, &mut loop_live)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns true if the provided body contains a `locaddr` instruction.
+fn takes_local_address(body: &CodeBody) -> bool {
+    body.nodes().iter().any(|node| match node {
+        Node::Instruction(instruction) => matches!(instruction, Instruction::Locaddr(_)),
+        Node::IfElse {
+            true_case,
+            false_case,
+        }
+        | Node::IfFeature {
+            true_case,
+            false_case,
+            ..
+        } => takes_local_address(true_case) || takes_local_address(false_case),
+        Node::Repeat { body, .. } | Node::While { body } => takes_local_address(body),
+    })
+}
@@ -0,0 +1,288 @@
+use super::{Assembler, AssemblyError, CodeBlock, Library, Operation, Program, RpoDigest};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::fmt::Write;
+use vm_core::{
+    code_blocks::{Dyn, Span},
+    utils::to_hex,
+    Decorator,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of spaces by which the body of a block is indented relative to the block.
+const INDENT: usize = 4;
+
+// DISASSEMBLER
+// ================================================================================================
+
+/// Converts compiled programs back into structured pseudo-assembly.
+///
+/// The output follows the syntax of Miden assembly: control flow blocks are printed as
+/// `if.true`, `while.true`, `call`, etc., and operations of span blocks are printed one per line.
+/// However, the output cannot always be compiled back into the same program, since the MAST does
+/// not preserve the original instructions (e.g., a single `u32checked_add` instruction is printed
+/// as the sequence of VM operations it was compiled into).
+///
+/// Procedures are identified by their MAST roots. If the MAST root of a procedure invoked via
+/// `call` or `syscall`, or of a subtree inlined via `exec`, matches a procedure exported from one
+/// of the libraries provided to the disassembler, the procedure is referred to by its name;
+/// otherwise, it is referred to by its MAST root. Decorators attached to span blocks and
+/// immediate values of operations are printed as comments.
+pub struct Disassembler {
+    assembler: Assembler,
+    procedures: BTreeMap<RpoDigest, String>,
+}
+
+impl Default for Disassembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disassembler {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [Disassembler] which does not know any procedures.
+    pub fn new() -> Self {
+        Self {
+            assembler: Assembler::default(),
+            procedures: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the procedures exported from the provided library to the procedures known to this
+    /// disassembler.
+    ///
+    /// Dependencies of the library must be added to the disassembler before the library itself.
+    ///
+    /// # Errors
+    /// Returns an error if the library cannot be compiled.
+    pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
+    where
+        L: Library,
+    {
+        self.assembler = self.assembler.with_library(library)?;
+        for (name, mast_root) in self.assembler.library_procedures()? {
+            self.procedures.entry(mast_root).or_insert(name);
+        }
+        Ok(self)
+    }
+
+    /// Names the procedure with the specified MAST root (e.g., a kernel procedure).
+    pub fn with_procedure<S: Into<String>>(mut self, name: S, mast_root: RpoDigest) -> Self {
+        self.procedures.insert(mast_root, name.into());
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the name of the procedure with the specified MAST root, if known.
+    pub fn procedure_name(&self, mast_root: &RpoDigest) -> Option<&str> {
+        self.procedures.get(mast_root).map(String::as_str)
+    }
+
+    // DISASSEMBLY
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns pseudo-assembly of the provided program.
+    ///
+    /// Procedures invoked via `call` instructions whose code is in the code block table of the
+    /// program are printed as `proc` sections before the `begin` block of the program.
+    pub fn disassemble(&self, program: &Program) -> String {
+        let mut writer = DisassemblyWriter {
+            disassembler: self,
+            output: String::new(),
+            callees: Vec::new(),
+        };
+        writer.block(INDENT, program.root(), true);
+        let root = core::mem::take(&mut writer.output);
+
+        if !program.kernel().is_empty() {
+            writer.comment(0, "kernel procedures:");
+            for proc_hash in program.kernel().proc_hashes() {
+                writer.comment(INDENT, &self.proc_label(proc_hash));
+            }
+            writer.output.push('\n');
+        }
+
+        // print the bodies of all called procedures available in the code block table, including
+        // procedures called from other procedures
+        let mut printed = BTreeSet::new();
+        let mut callee_idx = 0;
+        while let Some(&mast_root) = writer.callees.get(callee_idx) {
+            callee_idx += 1;
+            if !printed.insert(mast_root) {
+                continue;
+            }
+            if let Some(block) = program.cb_table().get(mast_root) {
+                writer.line(0, &format!("proc.{}", self.proc_label(&mast_root)));
+                writer.block(INDENT, block, true);
+                writer.line(0, "end");
+                writer.output.push('\n');
+            }
+        }
+
+        writer.line(0, "begin");
+        writer.output.push_str(&root);
+        writer.line(0, "end");
+        writer.output
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the name of the procedure with the specified MAST root, or the hex encoding of
+    /// the MAST root if the procedure is not known.
+    fn proc_label(&self, mast_root: &RpoDigest) -> String {
+        match self.procedures.get(mast_root) {
+            Some(name) => name.clone(),
+            None => hex_digest(mast_root),
+        }
+    }
+}
+
+/// Returns pseudo-assembly of the provided program without naming any procedures.
+///
+/// This is equivalent to `Disassembler::new().disassemble(program)`.
+pub fn disassemble(program: &Program) -> String {
+    Disassembler::new().disassemble(program)
+}
+
+// DISASSEMBLY WRITER
+// ================================================================================================
+
+/// Accumulates the pseudo-assembly of a program.
+struct DisassemblyWriter<'a> {
+    disassembler: &'a Disassembler,
+    output: String,
+    /// MAST roots of procedures invoked via `call`, in the order in which they were encountered.
+    callees: Vec<RpoDigest>,
+}
+
+impl DisassemblyWriter<'_> {
+    /// Writes the provided block at the specified indentation.
+    ///
+    /// If the block is a known procedure which was inlined into its parent (i.e., `is_proc_root`
+    /// is false), its body is delimited by comments with the name of the procedure.
+    fn block(&mut self, indent: usize, block: &CodeBlock, is_proc_root: bool) {
+        let proc_name = match is_proc_root {
+            true => None,
+            false => self.disassembler.procedure_name(&block.hash()).map(String::from),
+        };
+        if let Some(name) = proc_name.as_ref() {
+            self.comment(indent, &format!("exec.{name}"));
+        }
+
+        match block {
+            CodeBlock::Span(span) => self.span(indent, span),
+            CodeBlock::Join(join) => {
+                self.block(indent, join.first(), false);
+                self.block(indent, join.second(), false);
+            }
+            CodeBlock::Split(split) => {
+                self.line(indent, "if.true");
+                self.block(indent + INDENT, split.on_true(), false);
+                self.line(indent, "else");
+                self.block(indent + INDENT, split.on_false(), false);
+                self.line(indent, "end");
+            }
+            CodeBlock::Loop(block) => {
+                self.line(indent, "while.true");
+                self.block(indent + INDENT, block.body(), false);
+                self.line(indent, "end");
+            }
+            CodeBlock::Call(call) if call.fn_hash() == Dyn::dyn_hash() => {
+                self.line(indent, "dyncall")
+            }
+            CodeBlock::Call(call) => {
+                let label = self.disassembler.proc_label(&call.fn_hash());
+                if call.is_syscall() {
+                    self.line(indent, &format!("syscall.{label}"));
+                } else {
+                    self.line(indent, &format!("call.{label}"));
+                    self.callees.push(call.fn_hash());
+                }
+            }
+            CodeBlock::Dyn(_) => self.line(indent, "dynexec"),
+            CodeBlock::Proxy(proxy) => {
+                let hash = hex_digest(&proxy.hash());
+                self.comment(indent, &format!("proxy {hash} (code not available)"));
+            }
+        }
+
+        if let Some(name) = proc_name {
+            self.comment(indent, &format!("end exec.{name}"));
+        }
+    }
+
+    /// Writes the operations of the provided span block, together with its decorators.
+    fn span(&mut self, indent: usize, span: &Span) {
+        let ops: Vec<Operation> =
+            span.op_batches().iter().flat_map(|batch| batch.ops().iter().copied()).collect();
+        let mut decorators = span.decorators().iter().peekable();
+        for (op_idx, op) in ops.iter().enumerate() {
+            while let Some((_, decorator)) = decorators.next_if(|(idx, _)| *idx == op_idx) {
+                self.decorator(indent, decorator);
+            }
+            self.operation(indent, op);
+        }
+        for (_, decorator) in decorators {
+            self.decorator(indent, decorator);
+        }
+    }
+
+    /// Writes the provided operation; immediate values are repeated in a comment in hex form.
+    fn operation(&mut self, indent: usize, op: &Operation) {
+        match op {
+            Operation::Push(value) => {
+                let value = value.as_int();
+                self.line(indent, &format!("push.{value:<20} # immediate {value:#018x}"))
+            }
+            Operation::Assert(err_code) => {
+                self.line(indent, &format!("assert.err={err_code:<10} # error code"))
+            }
+            Operation::U32assert2(err_code) => {
+                self.line(indent, &format!("u32assert2.err={err_code:<10} # error code"))
+            }
+            op => self.line(indent, &format!("{op}")),
+        }
+    }
+
+    /// Writes the provided decorator as a comment.
+    fn decorator(&mut self, indent: usize, decorator: &Decorator) {
+        let text = match decorator {
+            Decorator::Advice(injector) => format!("adv.{injector}"),
+            Decorator::AsmOp(assembly_op) => format!("asm: {}", assembly_op.op()),
+            Decorator::Debug(options) => format!("debug.{options}"),
+            Decorator::Event(event_id) => format!("emit.{event_id}"),
+            Decorator::MemProtect => String::from("mem_protect"),
+            Decorator::Trace(trace_id) => format!("trace.{trace_id}"),
+        };
+        self.comment(indent, &text);
+    }
+
+    /// Writes a comment at the specified indentation.
+    fn comment(&mut self, indent: usize, text: &str) {
+        self.line(indent, &format!("# {text}"));
+    }
+
+    /// Writes a line at the specified indentation.
+    fn line(&mut self, indent: usize, text: &str) {
+        let _ = writeln!(self.output, "{:indent$}{}", "", text.trim_end());
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the hex encoding of the provided digest prefixed with `0x`.
+fn hex_digest(digest: &RpoDigest) -> String {
+    format!("0x{}", to_hex(&digest.as_bytes()).expect("failed to encode digest"))
+}
@@ -1,6 +1,6 @@
 use super::{
     Assembler, AssemblyContext, AssemblyError, CodeBlock, Felt, Instruction, Operation,
-    ProcedureId, RpoDigest, SpanBuilder, ONE, ZERO,
+    ProcedureId, ProcedureName, RpoDigest, SpanBuilder, ONE, ZERO,
 };
 use crate::utils::bound_into_included_u64;
 use core::ops::RangeBounds;
@@ -257,6 +257,10 @@ impl Assembler {
             Instruction::AdvLoadW => span.add_op(AdvPopW),
 
             Instruction::MemStream => span.add_op(MStream),
+            Instruction::MemProtect => {
+                span.push_decorator(Decorator::MemProtect);
+                span.add_ops([Drop, Drop])
+            }
 
             Instruction::Locaddr(v) => env_ops::locaddr(span, *v, ctx),
             Instruction::MemLoad => mem_ops::mem_read(span, ctx, None, false, true),
@@ -288,11 +292,15 @@ impl Assembler {
             Instruction::RCombBase => span.add_op(RCombBase),
 
             // ----- exec/call instructions -------------------------------------------------------
-            Instruction::ExecLocal(idx) => self.exec_local(*idx, ctx),
-            Instruction::ExecImported(id) => self.exec_imported(id, ctx),
-            Instruction::CallLocal(idx) => self.call_local(*idx, ctx),
+            Instruction::ExecLocal(idx) => self.exec_local(*idx, None, ctx),
+            Instruction::ExecImported(id) => self.exec_imported(id, None, ctx),
+            Instruction::ExecLocalPinned(idx, root) => self.exec_local(*idx, Some(root), ctx),
+            Instruction::ExecImportedPinned(id, root) => self.exec_imported(id, Some(root), ctx),
+            Instruction::CallLocal(idx) => self.call_local(*idx, None, ctx),
             Instruction::CallMastRoot(root) => self.call_mast_root(root, ctx),
-            Instruction::CallImported(id) => self.call_imported(id, ctx),
+            Instruction::CallImported(id) => self.call_imported(id, None, ctx),
+            Instruction::CallLocalPinned(idx, root) => self.call_local(*idx, Some(root), ctx),
+            Instruction::CallImportedPinned(id, root) => self.call_imported(id, Some(root), ctx),
             Instruction::SysCall(id) => self.syscall(id, ctx),
             Instruction::DynExec => self.dynexec(),
             Instruction::DynCall => self.dyncall(),
@@ -337,6 +345,60 @@ impl Assembler {
     }
 }
 
+// CYCLE ESTIMATION
+// ================================================================================================
+
+impl Instruction {
+    /// Returns the number of VM cycles required to execute this instruction, or None if the cost
+    /// of the instruction cannot be determined without knowing the rest of the program.
+    ///
+    /// Most instructions are lowered into a fixed sequence of VM operations, each of which takes
+    /// one cycle to execute; for such instructions the returned value is the length of this
+    /// sequence. Decorators (e.g., `emit`, `trace`, `debug`) do not add any operations, and thus,
+    /// have zero cost.
+    ///
+    /// The estimate is not exact in the following cases:
+    /// - Instructions accessing procedure locals are estimated as if the enclosing procedure had
+    ///   the maximum number of locals. Accessing the last one or two locals of a procedure may
+    ///   take one cycle less than the returned value.
+    /// - The cost of padding operation batches with NOOPs and of the control flow operations
+    ///   which delimit code blocks is not included.
+    ///
+    /// None is returned for `exec`, `call`, `syscall`, `dynexec`, and `dyncall` instructions
+    /// since their cost depends on the body of the invoked procedure.
+    pub fn estimated_cycles(&self) -> Option<u32> {
+        match self {
+            Self::ExecLocal(_)
+            | Self::ExecImported(_)
+            | Self::ExecLocalPinned(..)
+            | Self::ExecImportedPinned(..)
+            | Self::CallLocal(_)
+            | Self::CallMastRoot(_)
+            | Self::CallImported(_)
+            | Self::CallLocalPinned(..)
+            | Self::CallImportedPinned(..)
+            | Self::SysCall(_)
+            | Self::DynExec
+            | Self::DynCall => None,
+            // the root of the referenced procedure is pushed onto the stack one element at a time
+            Self::ProcRefLocal(_) | Self::ProcRefImported(_) => {
+                Some(Operation::Push(ZERO).cycle_cost() * 4)
+            }
+            // caller is valid only in kernel context which is not available here
+            Self::Caller => Some(Operation::Caller.cycle_cost()),
+            _ => {
+                let assembler = Assembler::default();
+                let mut ctx = AssemblyContext::for_program(None);
+                let proc_name = ProcedureName::try_from("estimate").ok()?;
+                ctx.begin_proc(&proc_name, false, u16::MAX).ok()?;
+                let mut span = SpanBuilder::default();
+                assembler.compile_instruction(self, &mut span, &mut ctx).ok()?;
+                Some(span.ops().iter().map(Operation::cycle_cost).sum())
+            }
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -11,12 +11,14 @@ impl Assembler {
     pub(super) fn exec_local(
         &self,
         proc_idx: u16,
+        pinned_root: Option<&RpoDigest>,
         context: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
         // register an "inlined" call to the procedure at the specified index in the module
         // currently being complied; this updates the callset of the procedure currently being
         // compiled
         let proc = context.register_local_call(proc_idx, true)?;
+        check_pinned_root(proc.mast_root(), pinned_root)?;
 
         // TODO: if the procedure consists of a single SPAN block, we could just append all
         // operations from that SPAN block to the span builder instead of returning a code block
@@ -28,6 +30,7 @@ impl Assembler {
     pub(super) fn exec_imported(
         &self,
         proc_id: &ProcedureId,
+        pinned_root: Option<&RpoDigest>,
         context: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
         // make sure the procedure is in procedure cache
@@ -36,6 +39,7 @@ impl Assembler {
         // get the procedure from the assembler
         let proc_cache = self.proc_cache.borrow();
         let proc = proc_cache.get_by_id(proc_id).expect("procedure not in cache");
+        check_pinned_root(proc.mast_root(), pinned_root)?;
 
         // register an "inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
@@ -51,12 +55,14 @@ impl Assembler {
     pub(super) fn call_local(
         &self,
         index: u16,
+        pinned_root: Option<&RpoDigest>,
         context: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
         // register a "non-inlined" call to the procedure at the specified index in the module
         // currently being complied; this updates the callset of the procedure currently being
         // compiled
         let proc = context.register_local_call(index, false)?;
+        check_pinned_root(proc.mast_root(), pinned_root)?;
 
         // create a new CALL block for the procedure call and return
         Ok(Some(CodeBlock::new_call(proc.mast_root())))
@@ -85,6 +91,7 @@ impl Assembler {
     pub(super) fn call_imported(
         &self,
         proc_id: &ProcedureId,
+        pinned_root: Option<&RpoDigest>,
         context: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
         // make sure the procedure is in procedure cache
@@ -93,6 +100,7 @@ impl Assembler {
         // get the procedure from the assembler
         let proc_cache = self.proc_cache.borrow();
         let proc = proc_cache.get_by_id(proc_id).expect("procedure not in cache");
+        check_pinned_root(proc.mast_root(), pinned_root)?;
 
         // register a "non-inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
@@ -123,6 +131,7 @@ impl Assembler {
         // register a "non-inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
         context.register_external_call(proc, false)?;
+        context.register_dependency(*proc_id, proc.mast_root());
 
         // create a new SYSCALL block for the procedure call and return
         Ok(Some(CodeBlock::new_syscall(proc.mast_root())))
@@ -177,3 +186,20 @@ impl Assembler {
         span.add_ops(ops)
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an error if the MAST root of an invoked procedure differs from the root pinned for it
+/// in the source code, if any.
+fn check_pinned_root(
+    mast_root: RpoDigest,
+    pinned_root: Option<&RpoDigest>,
+) -> Result<(), AssemblyError> {
+    match pinned_root {
+        Some(&pinned_root) if pinned_root != mast_root => {
+            Err(AssemblyError::pinned_proc_root_mismatch(pinned_root, mast_root))
+        }
+        _ => Ok(()),
+    }
+}
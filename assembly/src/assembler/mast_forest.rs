@@ -0,0 +1,278 @@
+use super::{AssemblyError, CodeBlock, DecoratorList, Operation, RpoDigest};
+use alloc::vec::Vec;
+use core::fmt;
+
+// MAST NODE ID
+// ================================================================================================
+
+/// Identifier of a node in a [MastForest].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MastNodeId(u32);
+
+impl MastNodeId {
+    /// Returns the index of the node in its forest.
+    pub fn as_usize(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl fmt::Display for MastNodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// MAST FOREST
+// ================================================================================================
+
+/// A set of MAST nodes from which programs can be compiled without going through Miden assembly
+/// source code.
+///
+/// The forest is intended for compilers which target Miden VM (e.g., from Wasm or from a DSL):
+/// instead of printing Miden assembly and parsing it again, such compilers can build the MAST of
+/// a program directly via the typed builder methods of the forest, and then compile it into a
+/// [Program](super::Program) via [Assembler::compile_mast_forest()](super::Assembler).
+///
+/// Every builder method returns the identifier of the added node. Child nodes must be added to
+/// the forest before their parents, and thus, a forest never contains cycles. A node can be
+/// referenced by any number of parent nodes.
+///
+/// The forest is self-contained: procedures invoked via `call` must be nodes of the same forest,
+/// while procedures invoked via `syscall` are identified by their MAST roots and must be exported
+/// from the kernel of the assembler which compiles the forest.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MastForest {
+    nodes: Vec<MastNode>,
+}
+
+/// A node of a [MastForest]; child nodes are referenced by their identifiers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MastNode {
+    Span(Vec<Operation>, DecoratorList),
+    Join(MastNodeId, MastNodeId),
+    Split(MastNodeId, MastNodeId),
+    Loop(MastNodeId),
+    Call(MastNodeId),
+    SysCall(RpoDigest),
+    Dyn,
+    DynCall,
+}
+
+impl MastForest {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [MastForest].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // NODE BUILDERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds a span node executing the provided sequence of operations.
+    ///
+    /// # Errors
+    /// Returns an error if the sequence of operations is empty or contains control flow
+    /// operations.
+    pub fn add_span(&mut self, operations: Vec<Operation>) -> Result<MastNodeId, AssemblyError> {
+        self.add_span_with_decorators(operations, DecoratorList::new())
+    }
+
+    /// Adds a span node executing the provided sequence of operations, with decorators attached
+    /// to the operations at the specified indexes.
+    ///
+    /// # Errors
+    /// Returns an error if the sequence of operations is empty or contains control flow
+    /// operations.
+    pub fn add_span_with_decorators(
+        &mut self,
+        operations: Vec<Operation>,
+        decorators: DecoratorList,
+    ) -> Result<MastNodeId, AssemblyError> {
+        if operations.is_empty() {
+            return Err(AssemblyError::empty_span_node());
+        }
+        if let Some(op) = operations.iter().find(|op| op.is_control_op()) {
+            return Err(AssemblyError::control_op_in_span_node(op));
+        }
+        Ok(self.add_node(MastNode::Span(operations, decorators)))
+    }
+
+    /// Adds a join node which executes the `first` node followed by the `second` node.
+    ///
+    /// # Errors
+    /// Returns an error if any of the child nodes is not in this forest.
+    pub fn add_join(
+        &mut self,
+        first: MastNodeId,
+        second: MastNodeId,
+    ) -> Result<MastNodeId, AssemblyError> {
+        self.check_node(first)?;
+        self.check_node(second)?;
+        Ok(self.add_node(MastNode::Join(first, second)))
+    }
+
+    /// Adds a split node which pops a value from the stack and executes the `on_true` node if the
+    /// value is ONE, or the `on_false` node if the value is ZERO.
+    ///
+    /// # Errors
+    /// Returns an error if any of the child nodes is not in this forest.
+    pub fn add_split(
+        &mut self,
+        on_true: MastNodeId,
+        on_false: MastNodeId,
+    ) -> Result<MastNodeId, AssemblyError> {
+        self.check_node(on_true)?;
+        self.check_node(on_false)?;
+        Ok(self.add_node(MastNode::Split(on_true, on_false)))
+    }
+
+    /// Adds a loop node which executes the `body` node while the value on the top of the stack is
+    /// ONE.
+    ///
+    /// # Errors
+    /// Returns an error if the body node is not in this forest.
+    pub fn add_loop(&mut self, body: MastNodeId) -> Result<MastNodeId, AssemblyError> {
+        self.check_node(body)?;
+        Ok(self.add_node(MastNode::Loop(body)))
+    }
+
+    /// Adds a call node which executes the `callee` node as a procedure in a new execution
+    /// context.
+    ///
+    /// # Errors
+    /// Returns an error if the callee node is not in this forest.
+    pub fn add_call(&mut self, callee: MastNodeId) -> Result<MastNodeId, AssemblyError> {
+        self.check_node(callee)?;
+        Ok(self.add_node(MastNode::Call(callee)))
+    }
+
+    /// Adds a syscall node which executes the kernel procedure with the specified MAST root.
+    ///
+    /// Whether the procedure is exported from the kernel is checked when the forest is compiled.
+    pub fn add_syscall(&mut self, mast_root: RpoDigest) -> MastNodeId {
+        self.add_node(MastNode::SysCall(mast_root))
+    }
+
+    /// Adds a dyn node which executes the code block with the MAST root on the top of the stack.
+    pub fn add_dyn(&mut self) -> MastNodeId {
+        self.add_node(MastNode::Dyn)
+    }
+
+    /// Adds a dyncall node which calls the procedure with the MAST root on the top of the stack.
+    pub fn add_dyncall(&mut self) -> MastNodeId {
+        self.add_node(MastNode::DynCall)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of nodes in this forest.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if this forest does not contain any nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns true if the node with the specified identifier is in this forest.
+    pub fn contains(&self, node_id: MastNodeId) -> bool {
+        node_id.as_usize() < self.nodes.len()
+    }
+
+    // CODE BLOCK BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds code blocks for the `root` node and for all nodes reachable from it, and returns
+    /// the code block of the root node together with the code blocks of all called procedures
+    /// and the MAST roots of all procedures invoked via `syscall`.
+    ///
+    /// # Errors
+    /// Returns an error if the root node is not in this forest.
+    pub(super) fn build_blocks(
+        &self,
+        root: MastNodeId,
+    ) -> Result<(CodeBlock, Vec<CodeBlock>, Vec<RpoDigest>), AssemblyError> {
+        self.check_node(root)?;
+
+        // find all nodes reachable from the root node
+        let mut is_reachable = vec![false; root.as_usize() + 1];
+        is_reachable[root.as_usize()] = true;
+        for index in (0..=root.as_usize()).rev() {
+            if !is_reachable[index] {
+                continue;
+            }
+            for child in self.nodes[index].children() {
+                is_reachable[child.as_usize()] = true;
+            }
+        }
+
+        // children have smaller identifiers than their parents, and thus, blocks can be built
+        // in the order of node identifiers
+        let mut blocks: Vec<Option<CodeBlock>> = vec![None; root.as_usize() + 1];
+        let mut callees = Vec::new();
+        let mut syscalls = Vec::new();
+        for index in (0..=root.as_usize()).filter(|&index| is_reachable[index]) {
+            let block = |id: &MastNodeId| blocks[id.as_usize()].clone().expect("block not built");
+            let code_block = match &self.nodes[index] {
+                MastNode::Span(operations, decorators) => {
+                    CodeBlock::new_span_with_decorators(operations.clone(), decorators.clone())
+                }
+                MastNode::Join(first, second) => CodeBlock::new_join([block(first), block(second)]),
+                MastNode::Split(on_true, on_false) => {
+                    CodeBlock::new_split(block(on_true), block(on_false))
+                }
+                MastNode::Loop(body) => CodeBlock::new_loop(block(body)),
+                MastNode::Call(callee) => {
+                    let callee = block(callee);
+                    let code_block = CodeBlock::new_call(callee.hash());
+                    callees.push(callee);
+                    code_block
+                }
+                MastNode::SysCall(mast_root) => {
+                    syscalls.push(*mast_root);
+                    CodeBlock::new_syscall(*mast_root)
+                }
+                MastNode::Dyn => CodeBlock::new_dyn(),
+                MastNode::DynCall => CodeBlock::new_dyncall(),
+            };
+            blocks[index] = Some(code_block);
+        }
+
+        let root = blocks[root.as_usize()].take().expect("root block not built");
+        Ok((root, callees, syscalls))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided node to this forest and returns its identifier.
+    fn add_node(&mut self, node: MastNode) -> MastNodeId {
+        let node_id = MastNodeId(self.nodes.len() as u32);
+        self.nodes.push(node);
+        node_id
+    }
+
+    /// Returns an error if the node with the specified identifier is not in this forest.
+    fn check_node(&self, node_id: MastNodeId) -> Result<(), AssemblyError> {
+        if self.contains(node_id) {
+            Ok(())
+        } else {
+            Err(AssemblyError::mast_node_not_found(node_id))
+        }
+    }
+}
+
+impl MastNode {
+    /// Returns the identifiers of the child nodes of this node.
+    fn children(&self) -> Vec<MastNodeId> {
+        match self {
+            Self::Join(first, second) | Self::Split(first, second) => vec![*first, *second],
+            Self::Loop(child) | Self::Call(child) => vec![*child],
+            Self::Span(..) | Self::SysCall(_) | Self::Dyn | Self::DynCall => Vec::new(),
+        }
+    }
+}
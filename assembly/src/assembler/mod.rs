@@ -1,13 +1,22 @@
 use super::{
-    ast::{instrument, Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
-    crypto::hash::RpoDigest,
-    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, Library, LibraryError,
+    ast::{
+        event, instrument, AstSerdeOptions, CodeBody, ImportedConstants, Instruction,
+        InstructionAliases, Level, ModuleAst, Node, ProcedureAst, ProgramAst, SourceLocation,
+    },
+    crypto::hash::{Rpo256, RpoDigest},
+    AssemblyError, ByteReader, ByteWriter, CallSet, CodeBlock, CodeBlockTable, Deserializable,
+    DeserializationError, Diagnostic, Felt, Kernel, Library, LibraryError, LibraryMetadata,
     LibraryPath, Module, NamedProcedure, Operation, Procedure, ProcedureId, ProcedureName, Program,
-    ONE, ZERO,
+    Serializable, Version, ONE, ZERO,
 };
-use alloc::collections::BTreeMap;
+use alloc::borrow::Cow;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
 use alloc::vec::Vec;
-use core::{borrow::Borrow, cell::RefCell};
+use core::{
+    borrow::Borrow,
+    cell::{Ref, RefCell},
+};
 use vm_core::{Decorator, DecoratorList};
 
 mod instruction;
@@ -21,9 +30,37 @@ use span_builder::SpanBuilder;
 mod context;
 pub use context::AssemblyContext;
 
+mod dead_stores;
+
+mod disassembler;
+pub use disassembler::{disassemble, Disassembler};
+
+mod mast_forest;
+pub use mast_forest::{MastForest, MastNodeId};
+
+mod op_frequency;
+pub use op_frequency::{OpFrequencyConfig, OpFrequencyReport};
+
+mod peephole;
+pub use peephole::OptimizationLevel;
+
+mod stack_cleanup;
+pub use stack_cleanup::OptimizationStats;
+
+mod stack_depth;
+pub use stack_depth::{StackDepthAnalyzer, StackEffect};
+
 mod procedure_cache;
 use procedure_cache::ProcedureCache;
 
+mod module_cache;
+use module_cache::CachedModule;
+pub use module_cache::ModuleCache;
+
+mod source_map;
+use source_map::BlockSource;
+pub use source_map::{SourceMap, SourceSpan};
+
 #[cfg(test)]
 mod tests;
 
@@ -41,6 +78,16 @@ pub struct Assembler {
     module_provider: ModuleProvider,
     proc_cache: RefCell<ProcedureCache>,
     in_debug_mode: bool,
+    eliminate_dead_stores: bool,
+    retain_dead_code: bool,
+    optimization_level: OptimizationLevel,
+    optimization_stats: RefCell<OptimizationStats>,
+    advice_map: RefCell<BTreeMap<RpoDigest, Vec<Felt>>>,
+    features: BTreeSet<String>,
+    instruction_aliases: InstructionAliases,
+    stack_depth_analyzer: Option<StackDepthAnalyzer>,
+    source_map: Option<RefCell<SourceMap>>,
+    module_cache: Option<RefCell<ModuleCache>>,
 }
 
 impl Assembler {
@@ -53,6 +100,108 @@ impl Assembler {
         self
     }
 
+    /// Enables or disables elimination of writes to procedure locals which are never read.
+    ///
+    /// When enabled, `loc_store` and `loc_storew` instructions whose values are never read before
+    /// being overwritten or before the procedure returns are removed from procedure bodies during
+    /// compilation. This is disabled by default because it changes MAST roots of the affected
+    /// procedures.
+    pub fn with_dead_store_elimination(mut self, enabled: bool) -> Self {
+        self.eliminate_dead_stores = enabled;
+        self
+    }
+
+    /// Enables or disables elimination of procedures which are not reachable from the entry point
+    /// of a program.
+    ///
+    /// When enabled, the code block table of a compiled program contains only the procedures
+    /// which are invoked (via `call`, `syscall`, or `procref` instructions) from the main
+    /// procedure of the program, either directly or via other procedures. When disabled, the
+    /// table also contains all local procedures of the program together with the procedures they
+    /// invoke, which allows using local procedures as targets of dynamic calls even if they are
+    /// not referenced from the program. This is enabled by default.
+    pub fn with_dead_code_elimination(mut self, enabled: bool) -> Self {
+        self.retain_dead_code = !enabled;
+        self
+    }
+
+    /// Sets the level of optimizations applied to compiled procedures.
+    ///
+    /// See [OptimizationLevel] for the description of the available optimizations. Optimizations
+    /// are disabled by default because they change MAST roots of the affected procedures.
+    pub fn with_optimization_level(mut self, optimization_level: OptimizationLevel) -> Self {
+        self.optimization_level = optimization_level;
+        self
+    }
+
+    /// Enables the specified feature flags.
+    ///
+    /// Bodies of `@if <flag>` blocks are compiled only if the flag is enabled, while bodies of
+    /// the corresponding `@else` blocks are compiled only if it is not. This applies to the
+    /// procedures of the libraries provided to the assembler as well.
+    pub fn with_features<I, S>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.features.extend(features.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the table of alternative mnemonics which can be used to refer to instructions in the
+    /// sources of programs and kernels compiled by this assembler.
+    ///
+    /// By default, deprecated mnemonics of u32 instructions are accepted and a warning is logged
+    /// for every instruction referred to via such a mnemonic. Setting an empty table (i.e.,
+    /// [InstructionAliases::none()]) makes the assembler reject deprecated mnemonics. Library
+    /// modules are parsed when the libraries are loaded and thus are not affected by this table.
+    pub fn with_instruction_aliases(mut self, instruction_aliases: InstructionAliases) -> Self {
+        self.instruction_aliases = instruction_aliases;
+        self
+    }
+
+    /// Sets the version of the instruction set in which the sources of programs and kernels
+    /// compiled by this assembler are written.
+    ///
+    /// Deprecated mnemonics of instructions renamed after the specified version are accepted,
+    /// while mnemonics which had already been renamed in that version are rejected. This replaces
+    /// the table of aliases set via [Assembler::with_instruction_aliases()].
+    pub fn with_instruction_set_version(self, version: Version) -> Self {
+        self.with_instruction_aliases(InstructionAliases::for_version(version))
+    }
+
+    /// Sets the analyzer used to check how procedures change the depth of the stack.
+    ///
+    /// When set, the bodies and local procedures of compiled programs and the procedures of the
+    /// kernel are analyzed before they are compiled, and a warning is logged for every problem
+    /// found by the analyzer. See [StackDepthAnalyzer] for the description of the checks.
+    pub fn with_stack_depth_analysis(mut self, analyzer: StackDepthAnalyzer) -> Self {
+        self.stack_depth_analyzer = Some(analyzer);
+        self
+    }
+
+    /// Enables or disables recording of source maps.
+    ///
+    /// When enabled, the assembler records the ranges of source code from which MAST nodes and
+    /// operation batches are compiled, and source maps of compiled programs can be retrieved via
+    /// [Assembler::source_map()]. Since procedures of the kernel are compiled when the kernel is
+    /// set, source maps should be enabled before the kernel is set.
+    pub fn with_source_maps(mut self, enabled: bool) -> Self {
+        self.source_map = enabled.then(Default::default);
+        self
+    }
+
+    /// Sets the cache of compiled library modules for this assembler.
+    ///
+    /// When a module cache is set, library modules compiled by the assembler are added to the
+    /// cache, and modules which are already in the cache are loaded from it instead of being
+    /// compiled again. The cache can be retrieved via [Assembler::into_module_cache()] and reused
+    /// by other assemblers. Modules are not cached if source maps are enabled.
+    pub fn with_module_cache(mut self, module_cache: ModuleCache) -> Self {
+        self.module_cache = Some(RefCell::new(module_cache));
+        self
+    }
+
     /// Adds the library to provide modules for the compilation.
     pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
     where
@@ -79,7 +228,12 @@ impl Assembler {
     /// # Panics
     /// Panics if the assembler has already been used to compile programs.
     pub fn with_kernel(self, kernel_source: &str) -> Result<Self, AssemblyError> {
-        let kernel_ast = ModuleAst::parse(kernel_source)?;
+        let imported_constants = self.module_provider.imported_constants();
+        let kernel_ast = ModuleAst::parse_with_aliases(
+            kernel_source,
+            &imported_constants,
+            &self.instruction_aliases,
+        )?;
         self.with_kernel_module(kernel_ast)
     }
 
@@ -88,6 +242,10 @@ impl Assembler {
     /// # Errors
     /// Returns an error if compiling kernel source results in an error.
     pub fn with_kernel_module(mut self, module: ModuleAst) -> Result<Self, AssemblyError> {
+        if let Some(analyzer) = self.stack_depth_analyzer.as_ref() {
+            analyzer.analyze_module(&module).iter().for_each(log_warning);
+        }
+
         // compile the kernel; this adds all exported kernel procedures to the procedure cache
         let mut context = AssemblyContext::for_module(true);
         let kernel = Module::kernel(module);
@@ -108,6 +266,55 @@ impl Assembler {
         self.in_debug_mode
     }
 
+    /// Returns true if the specified feature flag is enabled for this assembler.
+    pub fn is_feature_enabled(&self, feature: &str) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// Returns true if this assembler removes writes to procedure locals which are never read.
+    pub fn eliminates_dead_stores(&self) -> bool {
+        self.eliminate_dead_stores
+    }
+
+    /// Returns true if this assembler drops procedures which are not reachable from the entry
+    /// point of a program.
+    pub fn eliminates_dead_code(&self) -> bool {
+        !self.retain_dead_code
+    }
+
+    /// Returns the level of optimizations applied to compiled procedures.
+    pub fn optimization_level(&self) -> OptimizationLevel {
+        self.optimization_level
+    }
+
+    /// Returns the numbers of operations removed by the optimizer from the procedures compiled by
+    /// this assembler.
+    ///
+    /// Procedures loaded from the module cache are not included in the statistics.
+    pub fn optimization_stats(&self) -> Ref<'_, OptimizationStats> {
+        self.optimization_stats.borrow()
+    }
+
+    /// Returns the values of all data segments declared in the programs and modules compiled by
+    /// this assembler, keyed by the commitments to the values.
+    ///
+    /// Procedures generated for data segments load the values from the advice map, and thus, this
+    /// map must be provided to the VM when executing the compiled programs, e.g., via
+    /// `AdviceInputs::default().with_map(assembler.advice_map().clone())`.
+    pub fn advice_map(&self) -> Ref<'_, BTreeMap<RpoDigest, Vec<Felt>>> {
+        self.advice_map.borrow()
+    }
+
+    /// Returns the build metadata of the library providing the module or procedure with the
+    /// specified fully-qualified path, e.g., `std::math::u64` or `std::math::u64::checked_add`
+    /// (i.e., the same path which would be used in a `procref` instruction).
+    ///
+    /// Returns None if no library with the namespace of the path was added to this assembler, or
+    /// if the library does not carry any metadata.
+    pub fn library_metadata(&self, path: &str) -> Option<&LibraryMetadata> {
+        self.module_provider.get_metadata(path)
+    }
+
     /// Returns a reference to the kernel for this assembler.
     ///
     /// If the assembler was instantiated without a kernel, the internal kernel will be empty.
@@ -115,6 +322,28 @@ impl Assembler {
         &self.kernel
     }
 
+    /// Returns a reference to the module cache of this assembler, or None if the module cache has
+    /// not been set.
+    pub fn module_cache(&self) -> Option<Ref<'_, ModuleCache>> {
+        self.module_cache.as_ref().map(|module_cache| module_cache.borrow())
+    }
+
+    /// Consumes this assembler and returns its module cache, or None if the module cache has not
+    /// been set.
+    pub fn into_module_cache(self) -> Option<ModuleCache> {
+        self.module_cache.map(|module_cache| module_cache.into_inner())
+    }
+
+    /// Returns the source map of the provided program, or None if source maps are not enabled
+    /// for this assembler.
+    ///
+    /// The source map describes only the code compiled by this assembler from sources (or ASTs)
+    /// which contain source locations; for example, procedures of libraries serialized without
+    /// source locations are not included in the map.
+    pub fn source_map(&self, program: &Program) -> Option<SourceMap> {
+        self.source_map.as_ref().map(|source_map| source_map.borrow().extract(program))
+    }
+
     // PROGRAM COMPILER
     // --------------------------------------------------------------------------------------------
 
@@ -129,7 +358,9 @@ impl Assembler {
     {
         // parse the program into an AST
         let source = source.as_ref();
-        let program = ProgramAst::parse(source)?;
+        let imported_constants = self.module_provider.imported_constants();
+        let program =
+            ProgramAst::parse_with_aliases(source, &imported_constants, &self.instruction_aliases)?;
 
         // compile the program and return
         self.compile_ast(&program)
@@ -142,17 +373,58 @@ impl Assembler {
     /// Returns an error if the compilation of the specified program fails.
     #[instrument("compile_ast", skip_all)]
     pub fn compile_ast(&self, program: &ProgramAst) -> Result<Program, AssemblyError> {
+        if let Some(analyzer) = self.stack_depth_analyzer.as_ref() {
+            analyzer.analyze_program(program).iter().for_each(log_warning);
+        }
+
         // compile the program
         let mut context = AssemblyContext::for_program(Some(program));
         let program_root = self.compile_in_context(program, &mut context)?;
 
         // convert the context into a call block table for the program
-        let cb_table = context.into_cb_table(&self.proc_cache.borrow())?;
+        let cb_table =
+            context.into_cb_table(&self.proc_cache.borrow(), self.eliminates_dead_code())?;
 
         // build and return the program
         Ok(Program::with_kernel(program_root, self.kernel.clone(), cb_table))
     }
 
+    /// Compiles the MAST of a program described by the provided [MastForest] into a [Program].
+    /// The `entrypoint` node becomes the root of the program.
+    ///
+    /// This bypasses parsing and compilation of Miden assembly entirely: the nodes are compiled
+    /// into code blocks as is, and procedures called from the program are added to the code block
+    /// table of the program. Thus, none of the optimizations or analyses of the assembler are
+    /// applied to the forest.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The entrypoint node is not in the forest.
+    /// - A procedure invoked via `syscall` is not exported from the kernel of this assembler.
+    pub fn compile_mast_forest(
+        &self,
+        forest: &MastForest,
+        entrypoint: MastNodeId,
+    ) -> Result<Program, AssemblyError> {
+        let (program_root, callees, syscalls) = forest.build_blocks(entrypoint)?;
+
+        // kernel procedures invoked via syscall must be in the code block table as well
+        let proc_cache = self.proc_cache.borrow();
+        let mut cb_table = CodeBlockTable::default();
+        for mast_root in syscalls {
+            let proc = proc_cache
+                .get_by_hash(&mast_root)
+                .filter(|_| self.kernel.contains_proc(mast_root))
+                .ok_or(AssemblyError::syscall_target_not_in_kernel(mast_root))?;
+            cb_table.insert(proc.code().clone());
+        }
+        for callee in callees {
+            cb_table.insert(callee);
+        }
+
+        Ok(Program::with_kernel(program_root, self.kernel.clone(), cb_table))
+    }
+
     /// Compiles the provided [ProgramAst] into a program and returns the program root
     /// ([CodeBlock]). Mutates the provided context by adding all of the call targets of
     /// the program to the [CallSet].
@@ -172,6 +444,9 @@ impl Assembler {
             return Err(AssemblyError::InvalidProgramAssemblyContext);
         }
 
+        // register data segments declared in the program
+        self.register_data_segments(program.procedures());
+
         // compile all local procedures; this will add the procedures to the specified context
         for proc_ast in program.procedures() {
             if proc_ast.is_export {
@@ -180,12 +455,90 @@ impl Assembler {
             self.compile_procedure(proc_ast, context)?;
         }
 
-        // compile the program body
-        let program_root = self.compile_body(program.body().nodes().iter(), context, None)?;
+        // compile the program body; similarly to procedures, locals of the program body are
+        // allocated by incrementing fmp before the body is executed and deallocated afterwards
+        let num_locals = program.num_locals();
+        let body = if self.eliminate_dead_stores {
+            Cow::Owned(dead_stores::eliminate_dead_local_stores(program.body(), num_locals))
+        } else {
+            Cow::Borrowed(program.body())
+        };
+        let body = self.optimize_body(body, context);
+        let wrapper = (num_locals > 0).then(|| {
+            let num_locals = Felt::from(num_locals);
+            BodyWrapper {
+                prologue: vec![Operation::Push(num_locals), Operation::FmpUpdate],
+                epilogue: vec![Operation::Push(-num_locals), Operation::FmpUpdate],
+            }
+        });
+        let (program_root, _) = self.compile_body(&body, context, wrapper)?;
 
         Ok(program_root)
     }
 
+    /// Compiles all library modules available to this assembler which have not been compiled
+    /// yet, and adds them to the module cache.
+    ///
+    /// This can be used to move the compilation of libraries out of the compilation of programs.
+    /// Modules which do not export any procedures are not compiled.
+    ///
+    /// # Errors
+    /// Returns an error if the module cache has not been set, or if compilation of any of the
+    /// modules fails.
+    pub fn warm_module_cache(&self) -> Result<(), AssemblyError> {
+        if self.module_cache.is_none() {
+            return Err(AssemblyError::ModuleCacheNotSet);
+        }
+
+        let mut context = AssemblyContext::for_module(false);
+        for module in self.module_provider.modules() {
+            let exported_proc = module
+                .ast
+                .reexported_procs()
+                .iter()
+                .map(|proc| proc.name())
+                .chain(
+                    module.ast.procs().iter().filter(|proc| proc.is_export).map(|proc| &proc.name),
+                )
+                .next();
+            if let Some(proc_name) = exported_proc {
+                let proc_id = ProcedureId::from_name(proc_name, &module.path);
+                self.ensure_procedure_is_in_cache(&proc_id, &mut context)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the fully-qualified names and MAST roots of all procedures exported from the
+    /// library modules available to this assembler, compiling the modules as needed.
+    ///
+    /// # Errors
+    /// Returns an error if compilation of any of the modules fails.
+    pub fn library_procedures(&self) -> Result<Vec<(String, RpoDigest)>, AssemblyError> {
+        let mut context = AssemblyContext::for_module(false);
+        let mut procedures = Vec::new();
+        for module in self.module_provider.modules() {
+            let exported_procs =
+                module.ast.reexported_procs().iter().map(|proc| proc.name()).chain(
+                    module.ast.procs().iter().filter(|proc| proc.is_export).map(|proc| &proc.name),
+                );
+            for proc_name in exported_procs {
+                let proc_id = ProcedureId::from_name(proc_name, &module.path);
+                self.ensure_procedure_is_in_cache(&proc_id, &mut context)?;
+                let mast_root = self
+                    .proc_cache
+                    .borrow()
+                    .get_by_id(&proc_id)
+                    .expect("procedure not in cache")
+                    .mast_root();
+                procedures.push((format!("{}::{proc_name}", module.path), mast_root));
+            }
+        }
+
+        Ok(procedures)
+    }
+
     // MODULE COMPILER
     // --------------------------------------------------------------------------------------------
 
@@ -209,6 +562,22 @@ impl Assembler {
         let mut proc_roots = Vec::new();
         context.begin_module(path.unwrap_or(&LibraryPath::anon_path()), module)?;
 
+        // data segments are registered even if the module is loaded from the cache, since the
+        // procedures generated for them read the data from the advice map
+        self.register_data_segments(module.procs());
+
+        // try to load the module from the module cache; if the module is not in the cache, we
+        // collect the results of its compilation to add them to the cache later on
+        let cache_key = path.and_then(|path| self.module_cache_key(module, path, context));
+        let mut cached_module = None;
+        if let (Some(path), Some(cache_key)) = (path, cache_key.as_ref()) {
+            if let Some(proc_roots) = self.load_cached_module(cache_key, context)? {
+                context.complete_module()?;
+                return Ok(proc_roots);
+            }
+            cached_module = Some(CachedModule::new(path.clone()));
+        }
+
         // process all re-exported procedures
         for reexporteed_proc in module.reexported_procs().iter() {
             // make sure the re-exported procedure is loaded into the procedure cache
@@ -222,6 +591,9 @@ impl Assembler {
             let proc_mast_root = if let Some(path) = path {
                 let proc_name = reexporteed_proc.name();
                 let alias_proc_id = ProcedureId::from_name(proc_name, path);
+                if let Some(cached_module) = cached_module.as_mut() {
+                    cached_module.proc_aliases.push((alias_proc_id, ref_proc_id));
+                }
                 self.proc_cache
                     .try_borrow_mut()
                     .map_err(|_| AssemblyError::InvalidCacheLock)?
@@ -245,6 +617,7 @@ impl Assembler {
         for proc_ast in module.procs().iter() {
             self.compile_procedure(proc_ast, context)?;
         }
+        let dependencies = context.take_module_dependencies();
         let (module_procs, module_callset) = context.complete_module()?;
 
         // add the compiled procedures to the assembler's cache. the procedures are added to the
@@ -260,6 +633,9 @@ impl Assembler {
             if proc.is_export() || module_callset.contains(&proc.mast_root()) {
                 // build the procedure ID if this module has the library path
                 let proc_id = build_procedure_id(path, &proc, proc_index);
+                if let Some(cached_module) = cached_module.as_mut() {
+                    cached_module.procs.push((proc.clone(), proc_id));
+                }
 
                 // this is safe because we fail if the cache is borrowed.
                 self.proc_cache
@@ -269,9 +645,99 @@ impl Assembler {
             }
         }
 
+        // add the compiled module to the module cache
+        if let (Some(cache_key), Some(mut cached_module)) = (cache_key, cached_module) {
+            cached_module.dependencies = dependencies;
+            cached_module.proc_roots = proc_roots.clone();
+            if let Some(module_cache) = self.module_cache.as_ref() {
+                module_cache.borrow_mut().insert(cache_key, cached_module);
+            }
+        }
+
         Ok(proc_roots)
     }
 
+    // MODULE CACHE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the key of the specified module in the module cache, or None if the module should
+    /// not be cached.
+    ///
+    /// The key is computed from the module path, the module AST, and compilation options which
+    /// affect the compiled code. Modules are not cached if the module cache has not been set, if
+    /// source maps are enabled, or if the module is a kernel module.
+    fn module_cache_key(
+        &self,
+        module: &ModuleAst,
+        path: &LibraryPath,
+        context: &AssemblyContext,
+    ) -> Option<RpoDigest> {
+        if self.module_cache.is_none() || self.source_map.is_some() || context.is_kernel() {
+            return None;
+        }
+
+        let mut bytes = Vec::new();
+        path.write_into(&mut bytes);
+        module.write_into(&mut bytes, AstSerdeOptions::new(true));
+        bytes.write_bool(self.in_debug_mode);
+        bytes.write_bool(self.eliminate_dead_stores);
+        bytes.write_u8(self.optimization_level as u8);
+        bytes.write_bool(context.allows_phantom_calls());
+        bytes.write_usize(self.features.len());
+        for feature in self.features.iter() {
+            bytes.write_usize(feature.len());
+            bytes.write_bytes(feature.as_bytes());
+        }
+
+        Some(Rpo256::hash(&bytes))
+    }
+
+    /// Loads the module with the specified key from the module cache into the procedure cache,
+    /// and returns MAST roots of all procedures exported from the module.
+    ///
+    /// Returns None if the module is not in the module cache, or if any of the procedures the
+    /// module depends on is not available or has a MAST root different from the one the module
+    /// was compiled with.
+    fn load_cached_module(
+        &self,
+        cache_key: &RpoDigest,
+        context: &mut AssemblyContext,
+    ) -> Result<Option<Vec<RpoDigest>>, AssemblyError> {
+        let Some(module_cache) = self.module_cache.as_ref() else {
+            return Ok(None);
+        };
+        let dependencies = match module_cache.borrow().get(cache_key) {
+            Some(cached_module) => cached_module.dependencies.clone(),
+            None => return Ok(None),
+        };
+
+        // make sure the dependencies did not change since the module was compiled; this may
+        // compile modules containing the dependencies, and thus, the module cache must not be
+        // borrowed here
+        for (proc_id, mast_root) in dependencies.iter() {
+            if self.ensure_procedure_is_in_cache(proc_id, context).is_err() {
+                return Ok(None);
+            }
+            let proc_cache = self.proc_cache.borrow();
+            if proc_cache.get_by_id(proc_id).map(|proc| proc.mast_root()) != Some(*mast_root) {
+                return Ok(None);
+            }
+        }
+
+        let module_cache = module_cache.borrow();
+        let cached_module = module_cache.get(cache_key).expect("module not in cache");
+        let mut proc_cache =
+            self.proc_cache.try_borrow_mut().map_err(|_| AssemblyError::InvalidCacheLock)?;
+        for (alias_proc_id, ref_proc_id) in cached_module.proc_aliases.iter() {
+            proc_cache.insert_proc_alias(*alias_proc_id, *ref_proc_id)?;
+        }
+        for (proc, proc_id) in cached_module.procs.iter() {
+            proc_cache.insert(proc.clone(), *proc_id)?;
+        }
+
+        Ok(Some(cached_module.proc_roots.clone()))
+    }
+
     // PROCEDURE COMPILER
     // --------------------------------------------------------------------------------------------
 
@@ -282,6 +748,12 @@ impl Assembler {
         context: &mut AssemblyContext,
     ) -> Result<(), AssemblyError> {
         context.begin_proc(&proc.name, proc.is_export, proc.num_locals)?;
+        let body = if self.eliminate_dead_stores {
+            Cow::Owned(dead_stores::eliminate_dead_local_stores(&proc.body, proc.num_locals))
+        } else {
+            Cow::Borrowed(&proc.body)
+        };
+        let body = self.optimize_body(body, context);
         let code = if proc.num_locals > 0 {
             // for procedures with locals, we need to update fmp register before and after the
             // procedure body is executed. specifically:
@@ -292,9 +764,9 @@ impl Assembler {
                 prologue: vec![Operation::Push(num_locals), Operation::FmpUpdate],
                 epilogue: vec![Operation::Push(-num_locals), Operation::FmpUpdate],
             };
-            self.compile_body(proc.body.nodes().iter(), context, Some(wrapper))?
+            self.compile_body(&body, context, Some(wrapper))?.0
         } else {
-            self.compile_body(proc.body.nodes().iter(), context, None)?
+            self.compile_body(&body, context, None)?.0
         };
 
         context.complete_proc(code);
@@ -302,80 +774,187 @@ impl Assembler {
         Ok(())
     }
 
+    /// Adds the values of data segments declared via the provided procedures to the advice map of
+    /// this assembler.
+    fn register_data_segments(&self, procs: &[ProcedureAst]) {
+        let mut advice_map = self.advice_map.borrow_mut();
+        for data in procs.iter().filter_map(|proc| proc.data.as_ref()) {
+            advice_map.insert(data.commitment(), data.values().to_vec());
+        }
+    }
+
+    /// Removes dead stack operations from the provided body if peephole optimizations are enabled,
+    /// and records the number of removed operations for the procedure currently being compiled.
+    fn optimize_body<'a>(
+        &self,
+        body: Cow<'a, CodeBody>,
+        context: &AssemblyContext,
+    ) -> Cow<'a, CodeBody> {
+        if self.optimization_level < OptimizationLevel::Peephole {
+            return body;
+        }
+
+        let (optimized, num_removed) = stack_cleanup::remove_dead_stack_ops(&body);
+        if num_removed == 0 {
+            return body;
+        }
+        let proc_name =
+            format!("{}::{}", context.current_module_path(), context.current_context_name());
+        self.optimization_stats.borrow_mut().record(proc_name, num_removed);
+        Cow::Owned(optimized)
+    }
+
     // CODE BODY COMPILER
     // --------------------------------------------------------------------------------------------
 
-    /// TODO: add comments
-    fn compile_body<A, N>(
+    /// Compiles the provided code body into a code block, optionally wrapping the body with the
+    /// operations of the provided `wrapper`.
+    ///
+    /// Returns the compiled block together with its source. If source maps are enabled, sources
+    /// of all blocks built from the body are recorded in the source map of this assembler.
+    fn compile_body(
         &self,
-        body: A,
+        body: &CodeBody,
         context: &mut AssemblyContext,
         wrapper: Option<BodyWrapper>,
-    ) -> Result<CodeBlock, AssemblyError>
-    where
-        A: Iterator<Item = N>,
-        N: Borrow<Node>,
-    {
-        let mut blocks: Vec<CodeBlock> = Vec::new();
-        let mut span = SpanBuilder::new(wrapper);
-
-        for node in body {
-            match node.borrow() {
-                Node::Instruction(inner) => {
-                    if let Some(block) = self.compile_instruction(inner, &mut span, context)? {
-                        span.extract_span_into(&mut blocks);
-                        blocks.push(block);
-                    }
-                }
-
-                Node::IfElse {
-                    true_case,
-                    false_case,
-                } => {
-                    span.extract_span_into(&mut blocks);
-
-                    let true_case = self.compile_body(true_case.nodes().iter(), context, None)?;
+    ) -> Result<(CodeBlock, BlockSource), AssemblyError> {
+        let mut blocks = Vec::new();
+        let mut span = SpanBuilder::new(wrapper, self.optimization_level);
+
+        // operations of the wrapper prologue are assigned the location of the body start
+        let locations = self.source_locations(body);
+        if let Some(&location) = locations.first() {
+            span.locate_ops(location);
+        }
 
-                    // else is an exception because it is optional; hence, will have to be replaced
-                    // by noop span
-                    let false_case = if !false_case.nodes().is_empty() {
-                        self.compile_body(false_case.nodes().iter(), context, None)?
-                    } else {
-                        CodeBlock::new_span(vec![Operation::Noop])
-                    };
+        for (idx, node) in body.nodes().iter().enumerate() {
+            let location = locations.get(idx).copied();
+            self.compile_node(node, location, &mut span, &mut blocks, context)?;
+        }
 
-                    let block = CodeBlock::new_split(true_case, false_case);
+        span.extract_final_span_into(&mut blocks, locations.last().copied());
+        Ok(if blocks.is_empty() {
+            (CodeBlock::new_span(vec![Operation::Noop]), BlockSource::Unknown)
+        } else {
+            combine_located_blocks(blocks, |block, source| {
+                self.record_source(block, source, context)
+            })
+        })
+    }
 
-                    blocks.push(block);
+    /// Compiles a single node, appending the resulting operations to the provided span and the
+    /// resulting code blocks to the provided list of blocks.
+    ///
+    /// Nodes of the branch selected by a conditional assembly block are compiled as if they were
+    /// a part of the enclosing body.
+    fn compile_node(
+        &self,
+        node: &Node,
+        location: Option<SourceLocation>,
+        span: &mut SpanBuilder,
+        blocks: &mut Vec<(CodeBlock, BlockSource)>,
+        context: &mut AssemblyContext,
+    ) -> Result<(), AssemblyError> {
+        match node {
+            Node::Instruction(inner) => {
+                let block = self.compile_instruction(inner, span, context)?;
+                if let Some(location) = location {
+                    span.locate_ops(location);
+                }
+                if let Some(block) = block {
+                    span.extract_span_into(blocks);
+                    blocks.push((block, range_source(location, location)));
                 }
+            }
+
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                span.extract_span_into(blocks);
+
+                let (true_case_block, _) = self.compile_body(true_case, context, None)?;
+
+                // else is an exception because it is optional; hence, will have to be replaced
+                // by noop span
+                let false_case_block = if !false_case.nodes().is_empty() {
+                    self.compile_body(false_case, context, None)?.0
+                } else {
+                    CodeBlock::new_span(vec![Operation::Noop])
+                };
+
+                let block = CodeBlock::new_split(true_case_block, false_case_block);
+
+                // the block spans from the `if.true` token to the `end` token which is the last
+                // location of either branch
+                let end = self
+                    .source_locations(false_case)
+                    .last()
+                    .or(self.source_locations(true_case).last())
+                    .copied();
+                blocks.push((block, range_source(location, end)));
+            }
 
-                Node::Repeat { times, body } => {
-                    span.extract_span_into(&mut blocks);
+            Node::Repeat { times, body } => {
+                span.extract_span_into(blocks);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
+                let (block, source) = self.compile_body(body, context, None)?;
 
-                    for _ in 0..*times {
-                        blocks.push(block.clone());
-                    }
+                for _ in 0..*times {
+                    blocks.push((block.clone(), source.clone()));
                 }
+            }
+
+            Node::While { body } => {
+                span.extract_span_into(blocks);
 
-                Node::While { body } => {
-                    span.extract_span_into(&mut blocks);
+                let (block, _) = self.compile_body(body, context, None)?;
+                let block = CodeBlock::new_loop(block);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
-                    let block = CodeBlock::new_loop(block);
+                let end = self.source_locations(body).last().copied();
+                blocks.push((block, range_source(location, end)));
+            }
 
-                    blocks.push(block);
+            Node::IfFeature {
+                feature,
+                true_case,
+                false_case,
+            } => {
+                let selected = if self.features.contains(feature) {
+                    true_case
+                } else {
+                    false_case
+                };
+                let locations = self.source_locations(selected);
+                for (idx, node) in selected.nodes().iter().enumerate() {
+                    let location = locations.get(idx).copied();
+                    self.compile_node(node, location, span, blocks, context)?;
                 }
             }
         }
 
-        span.extract_final_span_into(&mut blocks);
-        Ok(if blocks.is_empty() {
-            CodeBlock::new_span(vec![Operation::Noop])
+        Ok(())
+    }
+
+    // SOURCE MAPS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns source locations of the provided code body if source maps are enabled for this
+    /// assembler; otherwise, returns an empty slice.
+    fn source_locations<'a>(&self, body: &'a CodeBody) -> &'a [SourceLocation] {
+        if self.source_map.is_some() {
+            body.source_locations()
         } else {
-            combine_blocks(blocks)
-        })
+            &[]
+        }
+    }
+
+    /// Records the source of the provided block in the source map of this assembler, if source
+    /// maps are enabled.
+    fn record_source(&self, block: &CodeBlock, source: &BlockSource, context: &AssemblyContext) {
+        if let Some(source_map) = self.source_map.as_ref() {
+            source_map.borrow_mut().record(block, source, context.current_module_path());
+        }
     }
 
     // PROCEDURE CACHE
@@ -410,6 +989,15 @@ impl Assembler {
             }
         }
 
+        // record the procedure as a dependency of the module currently being compiled
+        let mast_root = self
+            .proc_cache
+            .borrow()
+            .get_by_id(proc_id)
+            .expect("procedure not in cache")
+            .mast_root();
+        context.register_dependency(*proc_id, mast_root);
+
         Ok(())
     }
 
@@ -423,7 +1011,7 @@ impl Assembler {
         &self,
         context: AssemblyContext,
     ) -> Result<CodeBlockTable, AssemblyError> {
-        context.into_cb_table(&self.proc_cache.borrow())
+        context.into_cb_table(&self.proc_cache.borrow(), self.eliminates_dead_code())
     }
 }
 
@@ -440,16 +1028,32 @@ struct BodyWrapper {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
+#[cfg(test)]
+fn combine_blocks(blocks: Vec<CodeBlock>) -> CodeBlock {
+    let blocks = blocks.into_iter().map(|block| (block, BlockSource::Unknown)).collect();
+    combine_located_blocks(blocks, |_, _| ()).0
+}
+
+/// Combines the provided blocks into a single block, and returns it together with its source.
+///
+/// Sources of the combined block and of all blocks built in the process (including the provided
+/// blocks which are not merged with other blocks) are passed to the `record` callback.
+fn combine_located_blocks<F>(
+    mut blocks: Vec<(CodeBlock, BlockSource)>,
+    mut record: F,
+) -> (CodeBlock, BlockSource)
+where
+    F: FnMut(&CodeBlock, &BlockSource),
+{
     debug_assert!(!blocks.is_empty(), "cannot combine empty block list");
     // merge consecutive Span blocks.
-    let mut merged_blocks: Vec<CodeBlock> = Vec::with_capacity(blocks.len());
+    let mut merged_blocks: Vec<(CodeBlock, BlockSource)> = Vec::with_capacity(blocks.len());
     // Keep track of all the consecutive Span blocks and are merged together when
     // there is a discontinuity.
-    let mut contiguous_spans: Vec<CodeBlock> = Vec::new();
+    let mut contiguous_spans: Vec<(CodeBlock, BlockSource)> = Vec::new();
 
     blocks.drain(0..).for_each(|block| {
-        if block.is_span() {
+        if block.0.is_span() {
             contiguous_spans.push(block);
         } else {
             if !contiguous_spans.is_empty() {
@@ -461,6 +1065,7 @@ fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
     if !contiguous_spans.is_empty() {
         merged_blocks.push(combine_spans(&mut contiguous_spans));
     }
+    merged_blocks.iter().for_each(|(block, source)| record(block, source));
 
     // build a binary tree of blocks joining them using JOIN blocks
     let mut blocks = merged_blocks;
@@ -472,7 +1077,10 @@ fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
 
         let mut source_block_iter = source_blocks.drain(0..);
         while let (Some(left), Some(right)) = (source_block_iter.next(), source_block_iter.next()) {
-            blocks.push(CodeBlock::new_join([left, right]));
+            let source = left.1.join(&right.1);
+            let block = CodeBlock::new_join([left.0, right.0]);
+            record(&block, &source);
+            blocks.push((block, source));
         }
 
         if let Some(block) = last_block {
@@ -484,30 +1092,44 @@ fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
     blocks.remove(0)
 }
 
-/// Combines a vector of SPAN blocks into a single SPAN block.
+/// Combines a vector of SPAN blocks into a single SPAN block, and returns it together with its
+/// source.
 ///
 /// # Panics
 /// Panics if any of the provided blocks is not a SPAN block.
-fn combine_spans(spans: &mut Vec<CodeBlock>) -> CodeBlock {
+fn combine_spans(spans: &mut Vec<(CodeBlock, BlockSource)>) -> (CodeBlock, BlockSource) {
     if spans.len() == 1 {
         return spans.remove(0);
     }
 
     let mut ops = Vec::<Operation>::new();
     let mut decorators = DecoratorList::new();
-    spans.drain(0..).for_each(|block| {
+    let mut sources = Vec::with_capacity(spans.len());
+    spans.drain(0..).for_each(|(block, source)| {
         if let CodeBlock::Span(span) = block {
             for decorator in span.decorators() {
                 decorators.push((decorator.0 + ops.len(), decorator.1.clone()));
             }
+            let num_ops = ops.len();
             for batch in span.op_batches() {
                 ops.extend_from_slice(batch.ops());
             }
+            sources.push((source, ops.len() - num_ops));
         } else {
             panic!("CodeBlock was expected to be a Span Block, got {block:?}.");
         }
     });
-    CodeBlock::new_span_with_decorators(ops, decorators)
+    let source = BlockSource::concat(sources);
+    (CodeBlock::new_span_with_decorators(ops, decorators), source)
+}
+
+/// Returns the source of a block compiled from the specified range of source code, or an unknown
+/// source if the range is not known.
+fn range_source(start: Option<SourceLocation>, end: Option<SourceLocation>) -> BlockSource {
+    match (start, end) {
+        (Some(start), Some(end)) => BlockSource::Range(start, end),
+        _ => BlockSource::Unknown,
+    }
 }
 
 /// Builds a procedure ID based on the provided parameters.
@@ -528,3 +1150,8 @@ fn build_procedure_id(
     }
     proc_id
 }
+
+/// Logs the provided diagnostic as a warning.
+fn log_warning(diagnostic: &Diagnostic) {
+    event!(Level::WARN, "{}", diagnostic);
+}
@@ -0,0 +1,108 @@
+use super::{BTreeMap, LibraryPath, NamedProcedure, ProcedureId, RpoDigest};
+use alloc::vec::Vec;
+
+// MODULE CACHE
+// ================================================================================================
+
+/// A cache of compiled library modules which can be shared between assemblers.
+///
+/// Modules are keyed by a digest of the module path, the module AST, and the compilation options
+/// of the assembler (i.e., debug mode, dead store elimination, optimization level, and enabled
+/// features). Thus, an assembler instantiated with a cache from another assembler loads unchanged
+/// library modules from the cache instead of compiling them again, while modules which were
+/// changed, or which are compiled with different options, are compiled as usual.
+///
+/// A cached module is reused only if all procedures it depends on (i.e., imported procedures and
+/// kernel procedures) have the same MAST roots as at the time the module was compiled; otherwise,
+/// the module is compiled again and the cache entry is replaced.
+#[derive(Debug, Default, Clone)]
+pub struct ModuleCache {
+    modules: BTreeMap<RpoDigest, CachedModule>,
+}
+
+impl ModuleCache {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [ModuleCache].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of compiled modules in this cache.
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Returns true if this cache does not contain any compiled modules.
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Returns true if this cache contains a compiled module with the specified path.
+    pub fn contains_module(&self, path: &LibraryPath) -> bool {
+        self.modules.values().any(|module| &module.path == path)
+    }
+
+    /// Returns the cached module with the specified key.
+    pub(super) fn get(&self, key: &RpoDigest) -> Option<&CachedModule> {
+        self.modules.get(key)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Removes all compiled modules with the specified path from this cache, and returns true if
+    /// any modules were removed.
+    pub fn evict(&mut self, path: &LibraryPath) -> bool {
+        let num_modules = self.modules.len();
+        self.modules.retain(|_, module| &module.path != path);
+        self.modules.len() != num_modules
+    }
+
+    /// Removes all compiled modules from this cache.
+    pub fn clear(&mut self) {
+        self.modules.clear();
+    }
+
+    /// Inserts the provided module into this cache under the specified key, replacing the module
+    /// previously cached under the same key, if any.
+    pub(super) fn insert(&mut self, key: RpoDigest, module: CachedModule) {
+        self.modules.insert(key, module);
+    }
+}
+
+// CACHED MODULE
+// ================================================================================================
+
+/// Contains the results of compiling a single library module.
+#[derive(Debug, Clone)]
+pub(super) struct CachedModule {
+    /// Path of the module.
+    pub path: LibraryPath,
+    /// IDs and MAST roots of procedures which the module depends on.
+    pub dependencies: BTreeMap<ProcedureId, RpoDigest>,
+    /// Compiled procedures which are added to the procedure cache of the assembler together with
+    /// their procedure IDs.
+    pub procs: Vec<(NamedProcedure, Option<ProcedureId>)>,
+    /// Aliases of re-exported procedures together with IDs of the procedures they refer to.
+    pub proc_aliases: Vec<(ProcedureId, ProcedureId)>,
+    /// MAST roots of all procedures exported from the module.
+    pub proc_roots: Vec<RpoDigest>,
+}
+
+impl CachedModule {
+    /// Returns a new [CachedModule] with the specified path and without any procedures.
+    pub fn new(path: LibraryPath) -> Self {
+        Self {
+            path,
+            dependencies: BTreeMap::new(),
+            procs: Vec::new(),
+            proc_aliases: Vec::new(),
+            proc_roots: Vec::new(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use super::{Library, LibraryError, Module, ProcedureId};
+use super::{ImportedConstants, Library, LibraryError, LibraryMetadata, Module, ProcedureId};
 use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 // MODULE PROVIDER
@@ -12,18 +13,45 @@ pub struct ModuleProvider {
     modules: Vec<Module>,
     /// Map from procedure id to the index of a module in which the procedure is defined.
     procedures: BTreeMap<ProcedureId, usize>,
+    /// Map from library namespace to the build metadata of the library.
+    metadata: BTreeMap<String, LibraryMetadata>,
 }
 
 impl ModuleProvider {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns an iterator over all modules of this provider.
+    pub fn modules(&self) -> impl Iterator<Item = &Module> {
+        self.modules.iter()
+    }
+
     /// Fetch a module that contains the provided procedure id.
     pub fn get_module(&self, id: &ProcedureId) -> Option<&Module> {
         // this will panic only if there is a bug in `Self::add_module`.
         self.procedures.get(id).map(|i| &self.modules[*i])
     }
 
+    /// Returns the build metadata of the library which provided the module or procedure with the
+    /// specified fully-qualified path (e.g., `std::math::u64` or `std::math::u64::checked_add`).
+    ///
+    /// Returns None if no library with the namespace of the path was added to this provider, or if
+    /// the library does not carry any metadata.
+    pub fn get_metadata(&self, path: &str) -> Option<&LibraryMetadata> {
+        let namespace = path.split("::").next()?;
+        self.metadata.get(namespace)
+    }
+
+    /// Returns the constants exported by the modules of this provider, which can be referenced
+    /// from constant expressions of the modules importing them.
+    pub fn imported_constants(&self) -> ImportedConstants<'_> {
+        let mut constants = ImportedConstants::default();
+        for module in self.modules.iter() {
+            constants.add_module(&module.path, &module.ast);
+        }
+        constants
+    }
+
     // MODULE AND LIBRARY MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -64,6 +92,11 @@ impl ModuleProvider {
         library.modules().try_for_each(|module| {
             module.check_namespace(namespace)?;
             self.add_module(module.clone())
-        })
+        })?;
+
+        if !library.metadata().is_empty() {
+            self.metadata.insert(namespace.to_string(), library.metadata().clone());
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,218 @@
+use super::{CodeBlock, Program, RpoDigest};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use vm_core::code_blocks::Dyn;
+
+// OPERATION FREQUENCY REPORT
+// ================================================================================================
+
+/// Options which control how operations and MAST nodes of a program are counted by
+/// [OpFrequencyReport].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpFrequencyConfig {
+    /// If true, MAST nodes with the same MAST root are counted only once, even if they appear in
+    /// several places of the program (e.g., the body of a procedure inlined via `exec` from
+    /// several call sites). This approximates the amount of distinct code in the program. If
+    /// false, every occurrence of a node is counted.
+    pub deduplicate_nodes: bool,
+    /// If true, the bodies of procedures invoked via `call` and `syscall` are counted as well,
+    /// provided they are present in the code block table of the program. The body of every such
+    /// procedure is counted once, regardless of the number of call sites.
+    pub include_callees: bool,
+}
+
+impl Default for OpFrequencyConfig {
+    fn default() -> Self {
+        Self {
+            deduplicate_nodes: false,
+            include_callees: true,
+        }
+    }
+}
+
+/// Histogram of the VM operations and MAST node kinds a compiled program consists of.
+///
+/// The report is computed statically from the MAST of a program, and thus, describes the code
+/// generated by the assembler rather than the operations executed at runtime (e.g., the body of a
+/// loop is counted once regardless of the number of iterations). Operations are grouped by their
+/// opcodes, i.e., immediate values of operations are ignored. NOOPs inserted to pad operation
+/// batches of span blocks are counted together with other NOOPs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpFrequencyReport {
+    ops: BTreeMap<String, usize>,
+    nodes: BTreeMap<&'static str, usize>,
+}
+
+impl OpFrequencyReport {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the report for the provided program, with the nodes of the program counted
+    /// according to the provided config.
+    pub fn new(program: &Program, config: OpFrequencyConfig) -> Self {
+        let mut counter = OpCounter {
+            config,
+            report: Self::default(),
+            visited: BTreeSet::new(),
+            callees: Vec::new(),
+        };
+        counter.block(program.root());
+
+        // count the bodies of the procedures invoked via `call` and `syscall`, including the
+        // procedures invoked from other procedures
+        let mut counted = BTreeSet::new();
+        while let Some(mast_root) = counter.callees.pop() {
+            if !counted.insert(mast_root) {
+                continue;
+            }
+            if let Some(block) = program.cb_table().get(mast_root) {
+                counter.block(block);
+            }
+        }
+
+        counter.report
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of occurrences of the operation with the specified name (e.g., `add`
+    /// or `push`), or 0 if the operation does not occur in the program.
+    pub fn op_count(&self, op_name: &str) -> usize {
+        self.ops.get(op_name).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of MAST nodes of the specified kind, or 0 if the program has no such
+    /// nodes.
+    ///
+    /// Node kinds are `span`, `join`, `split`, `loop`, `call`, `syscall`, `dyn`, `dyncall`, and
+    /// `proxy`.
+    pub fn node_count(&self, node_kind: &str) -> usize {
+        self.nodes.get(node_kind).copied().unwrap_or(0)
+    }
+
+    /// Returns an iterator over the names of the operations occurring in the program and their
+    /// numbers of occurrences, ordered from the most to the least frequent operation.
+    pub fn ops(&self) -> impl Iterator<Item = (&str, usize)> {
+        sorted_by_count(self.ops.iter().map(|(name, count)| (name.as_str(), *count)))
+    }
+
+    /// Returns an iterator over the kinds of MAST nodes occurring in the program and their
+    /// numbers of occurrences, ordered from the most to the least frequent kind.
+    pub fn nodes(&self) -> impl Iterator<Item = (&str, usize)> {
+        sorted_by_count(self.nodes.iter().map(|(kind, count)| (*kind, *count)))
+    }
+
+    /// Returns the total number of operations in the program.
+    pub fn num_ops(&self) -> usize {
+        self.ops.values().sum()
+    }
+
+    /// Returns the total number of MAST nodes in the program.
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.values().sum()
+    }
+}
+
+impl fmt::Display for OpFrequencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "MAST nodes ({} total):", self.num_nodes())?;
+        write_histogram(f, self.nodes(), self.num_nodes())?;
+        writeln!(f, "operations ({} total):", self.num_ops())?;
+        write_histogram(f, self.ops(), self.num_ops())
+    }
+}
+
+// OPERATION COUNTER
+// ================================================================================================
+
+/// Walks the MAST of a program and accumulates the counts of its operations and nodes.
+struct OpCounter {
+    config: OpFrequencyConfig,
+    report: OpFrequencyReport,
+    /// MAST roots of the nodes counted so far; used only if nodes are deduplicated.
+    visited: BTreeSet<RpoDigest>,
+    /// MAST roots of procedures invoked via `call` and `syscall`.
+    callees: Vec<RpoDigest>,
+}
+
+impl OpCounter {
+    /// Counts the provided block and all of its children.
+    fn block(&mut self, block: &CodeBlock) {
+        if self.config.deduplicate_nodes && !self.visited.insert(block.hash()) {
+            return;
+        }
+
+        let kind = match block {
+            CodeBlock::Span(span) => {
+                for op in span.op_batches().iter().flat_map(|batch| batch.ops()) {
+                    *self.report.ops.entry(op_name(&op.to_string())).or_default() += 1;
+                }
+                "span"
+            }
+            CodeBlock::Join(join) => {
+                self.block(join.first());
+                self.block(join.second());
+                "join"
+            }
+            CodeBlock::Split(split) => {
+                self.block(split.on_true());
+                self.block(split.on_false());
+                "split"
+            }
+            CodeBlock::Loop(block) => {
+                self.block(block.body());
+                "loop"
+            }
+            CodeBlock::Call(call) if call.fn_hash() == Dyn::dyn_hash() => "dyncall",
+            CodeBlock::Call(call) => {
+                if self.config.include_callees {
+                    self.callees.push(call.fn_hash());
+                }
+                if call.is_syscall() {
+                    "syscall"
+                } else {
+                    "call"
+                }
+            }
+            CodeBlock::Dyn(_) => "dyn",
+            CodeBlock::Proxy(_) => "proxy",
+        };
+        *self.report.nodes.entry(kind).or_default() += 1;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the name of an operation without its immediate value, e.g., `push` for `push(5)`.
+fn op_name(op: &str) -> String {
+    op.split('(').next().unwrap_or(op).trim().to_string()
+}
+
+/// Returns the provided entries ordered by count (descending) and then by name.
+fn sorted_by_count<'a>(
+    entries: impl Iterator<Item = (&'a str, usize)>,
+) -> impl Iterator<Item = (&'a str, usize)> {
+    let mut entries: Vec<_> = entries.collect();
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    entries.into_iter()
+}
+
+/// Writes one line per entry with the count of the entry and its share of the total.
+fn write_histogram<'a>(
+    f: &mut fmt::Formatter<'_>,
+    entries: impl Iterator<Item = (&'a str, usize)>,
+    total: usize,
+) -> fmt::Result {
+    for (name, count) in entries {
+        // share of the total in tenths of a percent
+        let share = count * 1000 / total.max(1);
+        writeln!(f, "    {name:<12} {count:>8} {:>4}.{}%", share / 10, share % 10)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,271 @@
+use super::{Decorator, DecoratorList, Felt, Operation, SourceLocation, ONE, ZERO};
+use alloc::vec::Vec;
+
+// OPTIMIZATION LEVEL
+// ================================================================================================
+
+/// Specifies which optimizations the assembler applies to the operations of SPAN blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptimizationLevel {
+    /// Operations are emitted exactly as produced by the compiled instructions.
+    #[default]
+    None,
+    /// Instructions whose results are immediately dropped (e.g., `dup.1 drop` or `padw dropw`,
+    /// which are commonly created by expansion of macros and constants) are removed from
+    /// procedure bodies before they are compiled; since this happens before instructions are
+    /// translated into operations, such sequences are removed in debug mode as well. The numbers
+    /// of removed instructions can be retrieved via [crate::Assembler::optimization_stats()].
+    ///
+    /// Additionally, redundant operation sequences are removed or replaced with cheaper ones
+    /// before SPAN blocks are built. Specifically:
+    /// - Operations which push a value onto the stack (`PAD`, `PUSH`, and `DUP`) immediately
+    ///   followed by `DROP` are removed.
+    /// - Pairs of operations which cancel each other out (e.g., `SWAP SWAP`, `MOVUP2 MOVDN2`, or
+    ///   `NEG NEG`) are removed.
+    /// - Adjacent pushes are merged: a value pushed onto the stack by a run of push operations
+    ///   which already pushed the same value is duplicated via `DUPn` instead (e.g., `PUSH(7)
+    ///   PUSH(7)` becomes `PUSH(7) DUP0`, `PAD INCR PAD INCR` becomes `PAD INCR DUP0`, and the
+    ///   word pushed by `PUSH(5) PUSH(7) PUSH(5) PUSH(7)` becomes `PUSH(5) PUSH(7) DUP1 DUP1`).
+    ///   This saves the cycle of `INCR`, and the operation group used by the immediate value of
+    ///   `PUSH`.
+    Peephole,
+}
+
+// CONSTANTS
+// ================================================================================================
+
+/// Depth of the deepest stack item which can be copied to the top of the stack by a `DUPn`
+/// operation.
+const MAX_DUP_DEPTH: usize = 15;
+
+// PEEPHOLE OPTIMIZER
+// ================================================================================================
+
+/// Rewrites redundant sequences of the provided operations as described in
+/// [OptimizationLevel::Peephole], updating positions of the decorators and the list of operation
+/// locations accordingly.
+///
+/// The list of locations must either be empty or contain the location of each operation. An
+/// operation to which a decorator is attached, as well as all operations preceding it, are never
+/// rewritten, and neither are the operations covered by cycle counts of AsmOp decorators; thus,
+/// the decorators remain attached to the operations they describe.
+pub fn optimize_ops(
+    ops: &mut Vec<Operation>,
+    decorators: &mut DecoratorList,
+    locations: &mut Vec<SourceLocation>,
+) {
+    debug_assert!(locations.is_empty() || locations.len() == ops.len());
+
+    let mut result = Optimizer::new(ops.len(), !locations.is_empty());
+    let mut decorators_iter = decorators.iter_mut().peekable();
+    for (idx, op) in ops.drain(..).enumerate() {
+        while let Some((op_idx, decorator)) = decorators_iter.next_if(|(op_idx, _)| *op_idx == idx)
+        {
+            *op_idx = result.ops.len();
+            let num_ops = match decorator {
+                Decorator::AsmOp(assembly_op) => assembly_op.num_cycles().max(1) as usize,
+                _ => 1,
+            };
+            result.barrier = result.barrier.max(result.ops.len() + num_ops);
+        }
+        result.push(op, locations.get(idx).copied());
+    }
+    // decorators placed after the last operation
+    for (op_idx, _) in decorators_iter {
+        *op_idx = result.ops.len();
+    }
+
+    *ops = result.ops;
+    *locations = result.locations;
+}
+
+/// Accumulates the optimized operations, rewriting the tail of the operation list each time a
+/// new operation is added.
+struct Optimizer {
+    ops: Vec<Operation>,
+    locations: Vec<SourceLocation>,
+    /// Index of the first operation which may be rewritten.
+    barrier: usize,
+}
+
+impl Optimizer {
+    fn new(capacity: usize, with_locations: bool) -> Self {
+        Self {
+            ops: Vec::with_capacity(capacity),
+            locations: Vec::with_capacity(if with_locations { capacity } else { 0 }),
+            barrier: 0,
+        }
+    }
+
+    /// Appends the operation to the list of operations and applies all rewrites which become
+    /// possible as the result.
+    fn push(&mut self, op: Operation, location: Option<SourceLocation>) {
+        self.ops.push(op);
+        self.locations.extend(location);
+
+        loop {
+            let len = self.ops.len();
+            if len < self.barrier + 2 {
+                break;
+            }
+
+            let (a, b) = (self.ops[len - 2], self.ops[len - 1]);
+            if cancel_out(a, b) {
+                self.truncate(len - 2);
+            } else if !self.duplicate_pushed_value() {
+                break;
+            }
+        }
+    }
+
+    /// Replaces the operations pushing a value onto the stack at the end of the operation list with
+    /// `DUPn` if the same value is already at depth `n` of the stack, i.e., if it was pushed by the
+    /// run of push operations preceding these operations. Returns true if the operations were
+    /// replaced.
+    fn duplicate_pushed_value(&mut self) -> bool {
+        let ops = &self.ops[self.barrier..];
+        let Some((value, num_ops)) = pushed_value(ops) else {
+            return false;
+        };
+        let stack = pushed_run(&ops[..ops.len() - num_ops]);
+        let dup = stack.iter().rev().enumerate().find_map(|(depth, &pushed)| {
+            if pushed == Some(value) {
+                dup_op(depth)
+            } else {
+                None
+            }
+        });
+        match dup {
+            Some(dup) => {
+                let len = self.ops.len() - num_ops + 1;
+                self.ops[len - 1] = dup;
+                self.truncate(len);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Shortens the list of operations (and their locations) to the specified length.
+    fn truncate(&mut self, len: usize) {
+        self.ops.truncate(len);
+        if !self.locations.is_empty() {
+            self.locations.truncate(len);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value pushed onto the stack by the operations at the end of the provided list
+/// together with the number of these operations, if the operations are `PUSH(value)` or
+/// `PAD INCR`.
+///
+/// `PAD` operations are not considered since they are not more expensive than `DUP0`.
+fn pushed_value(ops: &[Operation]) -> Option<(Felt, usize)> {
+    match ops {
+        [.., Operation::Pad, Operation::Incr] => Some((ONE, 2)),
+        [.., Operation::Push(value)] => Some((*value, 1)),
+        _ => None,
+    }
+}
+
+/// Returns the values pushed onto the stack by the run of push operations (`PAD`, `PAD INCR`,
+/// `PUSH`, and `DUPn`) at the end of the provided list, ordered from the deepest to the top of the
+/// stack. Values which cannot be determined (i.e., values duplicated from the part of the stack
+/// which was not pushed by the run) are `None`.
+///
+/// Only the last [MAX_DUP_DEPTH] + 1 values are returned, as deeper values cannot be duplicated.
+fn pushed_run(ops: &[Operation]) -> Vec<Option<Felt>> {
+    // find the start of the run, counting `PAD INCR` as a single push
+    let mut start = ops.len();
+    let mut num_pushes = 0;
+    while start > 0 && num_pushes <= MAX_DUP_DEPTH {
+        match &ops[..start] {
+            [.., Operation::Pad, Operation::Incr] => start -= 2,
+            [.., Operation::Pad | Operation::Push(_)] => start -= 1,
+            [.., op] if dup_depth(*op).is_some() => start -= 1,
+            _ => break,
+        }
+        num_pushes += 1;
+    }
+
+    let mut stack: Vec<Option<Felt>> = Vec::with_capacity(num_pushes);
+    let mut idx = start;
+    while idx < ops.len() {
+        let value = match ops[idx] {
+            Operation::Pad if ops.get(idx + 1) == Some(&Operation::Incr) => {
+                idx += 1;
+                Some(ONE)
+            }
+            Operation::Pad => Some(ZERO),
+            Operation::Push(value) => Some(value),
+            op => dup_depth(op)
+                .and_then(|depth| stack.len().checked_sub(depth + 1).and_then(|pos| stack[pos])),
+        };
+        stack.push(value);
+        idx += 1;
+    }
+    stack
+}
+
+/// Returns the depth of the stack item copied to the top of the stack by the operation if the
+/// operation is `DUPn`.
+fn dup_depth(op: Operation) -> Option<usize> {
+    (0..=MAX_DUP_DEPTH).find(|&depth| dup_op(depth) == Some(op))
+}
+
+/// Returns the `DUPn` operation which copies the stack item at the specified depth to the top of
+/// the stack, if such an operation exists.
+fn dup_op(depth: usize) -> Option<Operation> {
+    use Operation::*;
+
+    match depth {
+        0 => Some(Dup0),
+        1 => Some(Dup1),
+        2 => Some(Dup2),
+        3 => Some(Dup3),
+        4 => Some(Dup4),
+        5 => Some(Dup5),
+        6 => Some(Dup6),
+        7 => Some(Dup7),
+        9 => Some(Dup9),
+        11 => Some(Dup11),
+        13 => Some(Dup13),
+        15 => Some(Dup15),
+        _ => None,
+    }
+}
+
+/// Returns true if executing operation `a` followed by operation `b` leaves the stack unchanged.
+fn cancel_out(a: Operation, b: Operation) -> bool {
+    use Operation::*;
+
+    matches!(
+        (a, b),
+        (Pad | Push(_), Drop)
+            | (Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7, Drop)
+            | (Dup9 | Dup11 | Dup13 | Dup15, Drop)
+            | (Swap, Swap)
+            | (SwapW, SwapW)
+            | (SwapW2, SwapW2)
+            | (SwapW3, SwapW3)
+            | (SwapDW, SwapDW)
+            | (Neg, Neg)
+            | (MovUp2, MovDn2)
+            | (MovUp3, MovDn3)
+            | (MovUp4, MovDn4)
+            | (MovUp5, MovDn5)
+            | (MovUp6, MovDn6)
+            | (MovUp7, MovDn7)
+            | (MovUp8, MovDn8)
+            | (MovDn2, MovUp2)
+            | (MovDn3, MovUp3)
+            | (MovDn4, MovUp4)
+            | (MovDn5, MovUp5)
+            | (MovDn6, MovUp6)
+            | (MovDn7, MovUp7)
+            | (MovDn8, MovUp8)
+    )
+}
@@ -0,0 +1,304 @@
+use super::{
+    ByteReader, ByteWriter, CodeBlock, Deserializable, DeserializationError, LibraryPath, Program,
+    RpoDigest, Serializable,
+};
+use crate::ast::SourceLocation;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+// SOURCE MAP
+// ================================================================================================
+
+/// Maps MAST nodes of compiled programs and operation batches of their SPAN blocks to the ranges
+/// of source code from which they were compiled.
+///
+/// MAST nodes are identified by their MAST roots, and operation batches are identified by the
+/// MAST root of the SPAN block containing them together with the index of the batch within the
+/// block. Since identical code always results in the same MAST, a single MAST node may originate
+/// from several places in the source code; in such cases, the map contains the range of the code
+/// which was compiled first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    nodes: BTreeMap<RpoDigest, SourceSpan>,
+    batches: BTreeMap<(RpoDigest, usize), SourceSpan>,
+}
+
+impl SourceMap {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the source range of the MAST node with the specified MAST root, if known.
+    pub fn node(&self, mast_root: &RpoDigest) -> Option<&SourceSpan> {
+        self.nodes.get(mast_root)
+    }
+
+    /// Returns the source range of the operation batch at the specified index of the SPAN block
+    /// with the specified MAST root, if known.
+    pub fn batch(&self, mast_root: &RpoDigest, batch_idx: usize) -> Option<&SourceSpan> {
+        self.batches.get(&(*mast_root, batch_idx))
+    }
+
+    /// Returns the number of MAST nodes in this source map.
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of operation batches in this source map.
+    pub fn num_batches(&self) -> usize {
+        self.batches.len()
+    }
+
+    /// Returns true if this source map does not contain any entries.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.batches.is_empty()
+    }
+
+    /// Returns a source map containing only the entries of this map which describe the code
+    /// blocks of the provided program.
+    ///
+    /// Procedures invoked via `call` and `syscall` instructions are included if they are present
+    /// in the code block table of the program.
+    pub(super) fn extract(&self, program: &Program) -> Self {
+        let mut result = Self::default();
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![program.root()];
+        while let Some(block) = stack.pop() {
+            let mast_root = block.hash();
+            if !visited.insert(mast_root) {
+                continue;
+            }
+
+            if let Some(span) = self.nodes.get(&mast_root) {
+                result.nodes.insert(mast_root, span.clone());
+            }
+            match block {
+                CodeBlock::Span(span) => {
+                    for batch_idx in 0..span.op_batches().len() {
+                        if let Some(span) = self.batches.get(&(mast_root, batch_idx)) {
+                            result.batches.insert((mast_root, batch_idx), span.clone());
+                        }
+                    }
+                }
+                CodeBlock::Join(block) => {
+                    stack.push(block.first());
+                    stack.push(block.second());
+                }
+                CodeBlock::Split(block) => {
+                    stack.push(block.on_true());
+                    stack.push(block.on_false());
+                }
+                CodeBlock::Loop(block) => stack.push(block.body()),
+                CodeBlock::Call(block) => stack.extend(program.cb_table().get(block.fn_hash())),
+                CodeBlock::Dyn(_) | CodeBlock::Proxy(_) => (),
+            }
+        }
+        result
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the source range of the provided code block and, for SPAN blocks, of its operation
+    /// batches. Code blocks which are already present in this map are left unchanged.
+    pub(super) fn record(&mut self, block: &CodeBlock, source: &BlockSource, path: &LibraryPath) {
+        let mast_root = block.hash();
+        if self.nodes.contains_key(&mast_root) {
+            return;
+        }
+        let Some((start, end)) = source.range() else {
+            return;
+        };
+        self.nodes.insert(mast_root, SourceSpan::new(path.clone(), start, end));
+
+        // record the ranges of operation batches if the locations of individual operations are
+        // known
+        if let (CodeBlock::Span(span), BlockSource::Ops(locations)) = (block, source) {
+            let mut locations = locations.as_slice();
+            for (batch_idx, batch) in span.op_batches().iter().enumerate() {
+                if locations.len() < batch.ops().len() {
+                    break;
+                }
+                let (batch_locations, rest) = locations.split_at(batch.ops().len());
+                if let Some((start, end)) = locations_range(batch_locations) {
+                    let span = SourceSpan::new(path.clone(), start, end);
+                    self.batches.insert((mast_root, batch_idx), span);
+                }
+                locations = rest;
+            }
+        }
+    }
+}
+
+impl Serializable for SourceMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.nodes.len());
+        for (mast_root, span) in self.nodes.iter() {
+            mast_root.write_into(target);
+            span.write_into(target);
+        }
+
+        target.write_usize(self.batches.len());
+        for ((mast_root, batch_idx), span) in self.batches.iter() {
+            mast_root.write_into(target);
+            target.write_usize(*batch_idx);
+            span.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for SourceMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut nodes = BTreeMap::new();
+        for _ in 0..source.read_usize()? {
+            let mast_root = RpoDigest::read_from(source)?;
+            let span = SourceSpan::read_from(source)?;
+            nodes.insert(mast_root, span);
+        }
+
+        let mut batches = BTreeMap::new();
+        for _ in 0..source.read_usize()? {
+            let mast_root = RpoDigest::read_from(source)?;
+            let batch_idx = source.read_usize()?;
+            let span = SourceSpan::read_from(source)?;
+            batches.insert((mast_root, batch_idx), span);
+        }
+
+        Ok(Self { nodes, batches })
+    }
+}
+
+// SOURCE SPAN
+// ================================================================================================
+
+/// A range of source code in a module, specified by the locations of its first and last tokens.
+///
+/// Programs are identified by the [LibraryPath::EXEC_PATH] module path, and modules compiled
+/// without a library path are identified by the [LibraryPath::ANON_PATH] module path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceSpan {
+    path: LibraryPath,
+    start: SourceLocation,
+    end: SourceLocation,
+}
+
+impl SourceSpan {
+    /// Returns a new [SourceSpan] instantiated from the specified parameters.
+    pub fn new(path: LibraryPath, start: SourceLocation, end: SourceLocation) -> Self {
+        Self { path, start, end }
+    }
+
+    /// Returns the path of the module containing this range.
+    pub fn path(&self) -> &LibraryPath {
+        &self.path
+    }
+
+    /// Returns the location of the first token in this range.
+    pub fn start(&self) -> SourceLocation {
+        self.start
+    }
+
+    /// Returns the location of the last token in this range.
+    pub fn end(&self) -> SourceLocation {
+        self.end
+    }
+}
+
+impl Serializable for SourceSpan {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.path.write_into(target);
+        self.start.write_into(target);
+        self.end.write_into(target);
+    }
+}
+
+impl Deserializable for SourceSpan {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let path = read_module_path(source)?;
+        let start = SourceLocation::read_from(source)?;
+        let end = SourceLocation::read_from(source)?;
+        Ok(Self { path, start, end })
+    }
+}
+
+// BLOCK SOURCE
+// ================================================================================================
+
+/// Describes the source code from which a code block was compiled while the block is being built.
+#[derive(Debug, Clone, Default)]
+pub(super) enum BlockSource {
+    /// The source of the block is not known.
+    #[default]
+    Unknown,
+    /// The block is a SPAN block with the specified locations of its operations.
+    Ops(Vec<SourceLocation>),
+    /// The block was compiled from the specified range of source code.
+    Range(SourceLocation, SourceLocation),
+}
+
+impl BlockSource {
+    /// Returns the range of source code described by this source, if known.
+    pub fn range(&self) -> Option<(SourceLocation, SourceLocation)> {
+        match self {
+            Self::Unknown => None,
+            Self::Ops(locations) => locations_range(locations),
+            Self::Range(start, end) => Some((*start, *end)),
+        }
+    }
+
+    /// Returns the source of a SPAN block built by concatenating operations of SPAN blocks with
+    /// the provided sources and numbers of operations.
+    ///
+    /// Operations of blocks for which only a source range is known are assigned the start of the
+    /// range.
+    pub fn concat<I>(sources: I) -> Self
+    where
+        I: IntoIterator<Item = (Self, usize)>,
+    {
+        let mut result = Vec::new();
+        for (source, num_ops) in sources {
+            match source {
+                Self::Unknown => return Self::Unknown,
+                Self::Ops(locations) => result.extend(locations),
+                Self::Range(start, _) => result.resize(result.len() + num_ops, start),
+            }
+        }
+        Self::Ops(result)
+    }
+
+    /// Returns the source of a block which combines blocks with the provided sources.
+    pub fn join(&self, other: &Self) -> Self {
+        match (self.range(), other.range()) {
+            (Some((start1, end1)), Some((start2, end2))) => {
+                Self::Range(start1.min(start2), end1.max(end2))
+            }
+            _ => Self::Unknown,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads a module path from the provided source.
+///
+/// Unlike regular library paths, module paths of programs and anonymous modules do not have any
+/// components after the reserved namespace, and thus, they are handled separately.
+fn read_module_path<R: ByteReader>(source: &mut R) -> Result<LibraryPath, DeserializationError> {
+    let path_len = source.read_u16()? as usize;
+    let path = String::from_utf8(source.read_vec(path_len)?)
+        .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+    match path.as_str() {
+        LibraryPath::EXEC_PATH => Ok(LibraryPath::exec_path()),
+        LibraryPath::ANON_PATH => Ok(LibraryPath::anon_path()),
+        _ => LibraryPath::new(path).map_err(|e| DeserializationError::InvalidValue(e.to_string())),
+    }
+}
+
+/// Returns the smallest range containing all provided locations, or None if the list of locations
+/// is empty.
+fn locations_range(locations: &[SourceLocation]) -> Option<(SourceLocation, SourceLocation)> {
+    let start = locations.iter().min()?;
+    let end = locations.iter().max()?;
+    Some((*start, *end))
+}
@@ -1,6 +1,6 @@
 use super::{
-    AssemblyContext, AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList,
-    Instruction, Operation,
+    peephole, AssemblyContext, AssemblyError, BlockSource, BodyWrapper, Borrow, CodeBlock,
+    Decorator, DecoratorList, Instruction, Operation, OptimizationLevel, SourceLocation,
 };
 use alloc::string::ToString;
 use alloc::vec::Vec;
@@ -19,9 +19,11 @@ use vm_core::{AdviceInjector, AssemblyOp};
 #[derive(Default)]
 pub struct SpanBuilder {
     ops: Vec<Operation>,
+    op_locations: Vec<SourceLocation>,
     decorators: DecoratorList,
     epilogue: Vec<Operation>,
     last_asmop_pos: usize,
+    optimization_level: OptimizationLevel,
 }
 
 impl SpanBuilder {
@@ -32,18 +34,34 @@ impl SpanBuilder {
     /// If the wrapper is provided, the prologue of the wrapper is immediately appended to the
     /// vector of span operations. The epilogue of the wrapper is appended to the list of
     /// operations upon consumption of the builder via `extract_final_span_into()` method.
-    pub(super) fn new(wrapper: Option<BodyWrapper>) -> Self {
+    ///
+    /// Operations of SPAN blocks extracted from the builder are optimized according to the
+    /// specified optimization level.
+    pub(super) fn new(wrapper: Option<BodyWrapper>, optimization_level: OptimizationLevel) -> Self {
         match wrapper {
             Some(wrapper) => Self {
                 ops: wrapper.prologue,
+                op_locations: Vec::new(),
                 decorators: Vec::new(),
                 epilogue: wrapper.epilogue,
                 last_asmop_pos: 0,
+                optimization_level,
+            },
+            None => Self {
+                optimization_level,
+                ..Default::default()
             },
-            None => Self::default(),
         }
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the operations which have been added to this builder so far.
+    pub fn ops(&self) -> &[Operation] {
+        &self.ops
+    }
+
     // OPERATIONS
     // --------------------------------------------------------------------------------------------
 
@@ -83,6 +101,12 @@ impl SpanBuilder {
         self.ops.resize(new_len, op);
     }
 
+    /// Assigns the specified source location to all operations which have been added to this
+    /// builder since the last invocation of this method.
+    pub fn locate_ops(&mut self, location: SourceLocation) {
+        self.op_locations.resize(self.ops.len(), location);
+    }
+
     // DECORATORS
     // --------------------------------------------------------------------------------------------
 
@@ -139,13 +163,30 @@ impl SpanBuilder {
     /// Creates a new SPAN block from the operations and decorators currently in this builder and
     /// appends the block to the provided target.
     ///
+    /// The block is appended together with its source, which lists the locations of the block
+    /// operations if all of them have been located via `locate_ops()`.
+    ///
     /// This consumes all operations and decorators in the builder, but does not touch the
     /// operations in the epilogue of the builder.
-    pub fn extract_span_into(&mut self, target: &mut Vec<CodeBlock>) {
+    pub fn extract_span_into(&mut self, target: &mut Vec<(CodeBlock, BlockSource)>) {
+        let is_located = self.op_locations.len() == self.ops.len();
+        if self.optimization_level >= OptimizationLevel::Peephole {
+            if !is_located {
+                self.op_locations.clear();
+            }
+            peephole::optimize_ops(&mut self.ops, &mut self.decorators, &mut self.op_locations);
+        }
+
         if !self.ops.is_empty() {
+            let source = if is_located {
+                BlockSource::Ops(self.op_locations.drain(..).collect())
+            } else {
+                self.op_locations.clear();
+                BlockSource::Unknown
+            };
             let ops = self.ops.drain(..).collect();
             let decorators = self.decorators.drain(..).collect();
-            target.push(CodeBlock::new_span_with_decorators(ops, decorators));
+            target.push((CodeBlock::new_span_with_decorators(ops, decorators), source));
         } else if !self.decorators.is_empty() {
             // this is a bug in the assembler. we shouldn't have decorators added without their
             // associated operations
@@ -159,10 +200,18 @@ impl SpanBuilder {
     ///
     /// The main differences from the `extract_span_int()` method above are:
     /// - Operations contained in the epilogue of the span builder are appended to the list of
-    ///   ops which go into the new SPAN block.
+    ///   ops which go into the new SPAN block. If the `location` is provided, it is assigned to
+    ///   the epilogue operations.
     /// - The span builder is consumed in the process.
-    pub fn extract_final_span_into(mut self, target: &mut Vec<CodeBlock>) {
+    pub fn extract_final_span_into(
+        mut self,
+        target: &mut Vec<(CodeBlock, BlockSource)>,
+        location: Option<SourceLocation>,
+    ) {
         self.ops.append(&mut self.epilogue);
+        if let Some(location) = location {
+            self.locate_ops(location);
+        }
         self.extract_span_into(target);
     }
 }
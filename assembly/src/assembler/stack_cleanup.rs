@@ -0,0 +1,189 @@
+use super::{CodeBody, Instruction, Node};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+// OPTIMIZATION STATISTICS
+// ================================================================================================
+
+/// Numbers of operations removed by the optimizer from the procedures compiled by an assembler.
+///
+/// Procedures are identified by their fully-qualified names (e.g., `std::math::u64::add`), and
+/// only the procedures from which at least one operation was removed are included.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OptimizationStats {
+    procedures: BTreeMap<String, usize>,
+}
+
+impl OptimizationStats {
+    /// Returns the number of operations removed from the specified procedure, or None if no
+    /// operations were removed from it.
+    pub fn get(&self, proc_name: &str) -> Option<usize> {
+        self.procedures.get(proc_name).copied()
+    }
+
+    /// Returns an iterator over the names of the optimized procedures and the numbers of
+    /// operations removed from them, ordered by procedure name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.procedures.iter().map(|(name, num_removed)| (name.as_str(), *num_removed))
+    }
+
+    /// Returns the total number of operations removed from all procedures.
+    pub fn num_removed_ops(&self) -> usize {
+        self.procedures.values().sum()
+    }
+
+    /// Returns true if no operations were removed from any procedure.
+    pub fn is_empty(&self) -> bool {
+        self.procedures.is_empty()
+    }
+
+    /// Adds the specified number of removed operations to the statistics of a procedure.
+    pub(super) fn record(&mut self, proc_name: String, num_removed: usize) {
+        if num_removed > 0 {
+            *self.procedures.entry(proc_name).or_default() += num_removed;
+        }
+    }
+}
+
+// DEAD STACK OPERATION REMOVAL
+// ================================================================================================
+
+/// Returns a copy of the provided body with stack operations whose results are immediately
+/// discarded removed, together with the number of removed operations.
+///
+/// Specifically, the following sequences of instructions are removed:
+/// - An instruction which pushes a single value onto the stack (`dup.n`, `push.a`, or `locaddr.i`)
+///   immediately followed by `drop`.
+/// - An instruction which pushes a word onto the stack (`dupw.n`, `padw`, or a word pushed via
+///   `push`) immediately followed by `dropw`.
+///
+/// A `drop` following a `push` instruction with several immediate values removes only the last
+/// value (e.g., `push.1.2.3 drop` becomes `push.1.2`). The removal is repeated until no more
+/// sequences can be removed, and thus, nested sequences such as `push.1 dup.0 drop drop` are
+/// removed as well. Such sequences are typically created by expansion of macros and constants.
+///
+/// Sequences interrupted by other nodes (e.g., `dup.1 trace.1 drop`) are not removed. Each removed
+/// instruction and each removed immediate value counts as a single operation.
+pub fn remove_dead_stack_ops(body: &CodeBody) -> (CodeBody, usize) {
+    let mut num_removed = 0;
+    let body = rewrite_body(body, &mut num_removed);
+    (body, num_removed)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Rewrites the provided body, adding the number of removed operations to `num_removed`.
+fn rewrite_body(body: &CodeBody, num_removed: &mut usize) -> CodeBody {
+    let nodes = body.nodes();
+    let locations = body.source_locations();
+    let has_locations = locations.len() >= nodes.len();
+
+    let mut new_nodes: Vec<Node> = Vec::with_capacity(nodes.len());
+    let mut new_locations = Vec::with_capacity(locations.len());
+    for (i, node) in nodes.iter().enumerate() {
+        if let Node::Instruction(instruction) = node {
+            if let Some(Node::Instruction(last)) = new_nodes.last_mut() {
+                match discard(last, instruction) {
+                    Discard::Both => {
+                        new_nodes.pop();
+                        if has_locations {
+                            new_locations.pop();
+                        }
+                        *num_removed += 2;
+                        continue;
+                    }
+                    Discard::Value => {
+                        *num_removed += 2;
+                        continue;
+                    }
+                    Discard::None => (),
+                }
+            }
+        }
+
+        new_nodes.push(rewrite_node(node, num_removed));
+        new_locations.extend(locations.get(i).copied());
+    }
+
+    // keep the location of the `end` token of the body, if any
+    new_locations.extend(locations.iter().skip(nodes.len()).copied());
+
+    CodeBody::new(new_nodes).with_source_locations(new_locations)
+}
+
+/// Rewrites the bodies of the provided control flow node; instruction nodes are returned as is.
+fn rewrite_node(node: &Node, num_removed: &mut usize) -> Node {
+    match node {
+        Node::Instruction(_) => node.clone(),
+        Node::IfElse {
+            true_case,
+            false_case,
+        } => Node::IfElse {
+            true_case: rewrite_body(true_case, num_removed),
+            false_case: rewrite_body(false_case, num_removed),
+        },
+        Node::IfFeature {
+            feature,
+            true_case,
+            false_case,
+        } => Node::IfFeature {
+            feature: feature.clone(),
+            true_case: rewrite_body(true_case, num_removed),
+            false_case: rewrite_body(false_case, num_removed),
+        },
+        Node::Repeat { times, body } => Node::Repeat {
+            times: *times,
+            body: rewrite_body(body, num_removed),
+        },
+        Node::While { body } => Node::While {
+            body: rewrite_body(body, num_removed),
+        },
+    }
+}
+
+/// Describes what is discarded when an instruction is followed by a `drop` or a `dropw`.
+enum Discard {
+    /// Neither instruction can be removed.
+    None,
+    /// Both instructions are removed.
+    Both,
+    /// The last immediate value of the push instruction is removed together with the `drop`.
+    Value,
+}
+
+/// Determines which parts of the `producer` instruction and the following `consumer` instruction
+/// can be removed, shortening the list of immediate values of the producer if needed.
+fn discard(producer: &mut Instruction, consumer: &Instruction) -> Discard {
+    use Instruction::*;
+
+    let single = match (&mut *producer, consumer) {
+        (PushU8List(values), Drop) if values.len() > 1 => pop_value(values).map(PushU8),
+        (PushU16List(values), Drop) if values.len() > 1 => pop_value(values).map(PushU16),
+        (PushU32List(values), Drop) if values.len() > 1 => pop_value(values).map(PushU32),
+        (PushFeltList(values), Drop) if values.len() > 1 => pop_value(values).map(PushFelt),
+        (
+            PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) | PushU8List(_) | PushU16List(_)
+            | PushU32List(_) | PushFeltList(_) | Locaddr(_) | Dup0 | Dup1 | Dup2 | Dup3 | Dup4
+            | Dup5 | Dup6 | Dup7 | Dup8 | Dup9 | Dup10 | Dup11 | Dup12 | Dup13 | Dup14 | Dup15,
+            Drop,
+        )
+        | (PushWord(_) | PadW | DupW0 | DupW1 | DupW2 | DupW3, DropW) => return Discard::Both,
+        _ => return Discard::None,
+    };
+
+    // a push of a single immediate value is represented by its dedicated instruction
+    if let Some(single) = single {
+        *producer = single;
+    }
+    Discard::Value
+}
+
+/// Removes the last value from the provided list of immediate values, returning the remaining
+/// value if only one is left.
+fn pop_value<T: Copy>(values: &mut Vec<T>) -> Option<T> {
+    values.pop();
+    match values.as_slice() {
+        [value] => Some(*value),
+        _ => None,
+    }
+}
@@ -0,0 +1,648 @@
+use crate::{
+    ast::{
+        AdviceInjectorNode, CodeBody, Instruction, ModuleAst, ModuleImports, Node, ProcedureAst,
+        ProcedureSignature, ProgramAst, SourceLocation,
+    },
+    Diagnostic, Label, Token,
+};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt;
+use vm_core::stack::STACK_TOP_SIZE;
+
+// STACK EFFECT
+// ================================================================================================
+
+/// The effect of executing an instruction or a procedure on the stack.
+///
+/// A stack effect is described by the number of elements at the top of the stack which are
+/// accessed (inputs), and the number of elements which replace them (outputs). For example, the
+/// effect of `add` is `2 -> 1`, the effect of `dup.3` is `4 -> 5`, and the effect of a procedure
+/// which reads 3 elements from the stack and leaves 3 other elements in their place is `3 -> 3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StackEffect {
+    inputs: u32,
+    outputs: u32,
+}
+
+impl StackEffect {
+    /// Returns a new stack effect which replaces `inputs` elements at the top of the stack with
+    /// `outputs` elements.
+    pub const fn new(inputs: u32, outputs: u32) -> Self {
+        Self { inputs, outputs }
+    }
+
+    /// Returns the number of elements at the top of the stack which are accessed.
+    pub const fn inputs(&self) -> u32 {
+        self.inputs
+    }
+
+    /// Returns the number of elements which replace the inputs at the top of the stack.
+    pub const fn outputs(&self) -> u32 {
+        self.outputs
+    }
+
+    /// Returns the net change of the stack depth.
+    pub const fn delta(&self) -> i64 {
+        self.outputs as i64 - self.inputs as i64
+    }
+}
+
+impl fmt::Display for StackEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.inputs, self.outputs)
+    }
+}
+
+impl From<&ProcedureSignature> for StackEffect {
+    fn from(signature: &ProcedureSignature) -> Self {
+        Self::new(signature.num_inputs() as u32, signature.num_outputs() as u32)
+    }
+}
+
+// INSTRUCTION STACK EFFECTS
+// ================================================================================================
+
+impl Instruction {
+    /// Returns the effect of this instruction on the stack, or None if the effect cannot be
+    /// determined without knowing the rest of the program.
+    ///
+    /// None is returned for `exec` and `dynexec` instructions since their effect depends on the
+    /// body of the invoked procedure. Procedures invoked via `call`, `syscall`, and `dyncall` are
+    /// executed in a separate context which must end with the same stack depth it started with,
+    /// and thus these instructions do not change the stack depth of the caller.
+    pub fn stack_effect(&self) -> Option<StackEffect> {
+        use Instruction::*;
+
+        let (inputs, outputs) = match self {
+            // ----- field operations -------------------------------------------------------------
+            Assert | AssertWithError(_) | Assertz | AssertzWithError(_) => (1, 0),
+            AssertEq | AssertEqWithError(_) => (2, 0),
+            AssertEqw | AssertEqwWithError(_) => (8, 0),
+            Add | Sub | Mul | Div | Exp | ExpBitLength(_) | And | Or | Xor | Eq | Neq | Lt
+            | Lte | Gt | Gte => (2, 1),
+            AddImm(_) | SubImm(_) | MulImm(_) | DivImm(_) | ExpImm(_) | EqImm(_) | NeqImm(_) => {
+                (1, 1)
+            }
+            Neg | Inv | Incr | Pow2 | ILog2 | Not | IsOdd => (1, 1),
+            Eqw => (8, 9),
+
+            // ----- ext2 operations --------------------------------------------------------------
+            Ext2Add | Ext2Sub | Ext2Mul | Ext2Div => (4, 2),
+            Ext2Neg | Ext2Inv => (2, 2),
+
+            // ----- u32 operations ---------------------------------------------------------------
+            U32Test => (1, 2),
+            U32TestW => (4, 5),
+            U32Assert | U32AssertWithError(_) | U32Cast => (1, 1),
+            U32Assert2 | U32Assert2WithError(_) => (2, 2),
+            U32AssertW | U32AssertWWithError(_) => (4, 4),
+            U32Split => (1, 2),
+            U32WrappingAdd | U32WrappingSub | U32WrappingMul | U32Div | U32Mod | U32And | U32Or
+            | U32Xor | U32Shr | U32Shl | U32Rotr | U32Rotl | U32Lt | U32Lte | U32Gt | U32Gte
+            | U32Min | U32Max => (2, 1),
+            U32WrappingAddImm(_) | U32WrappingSubImm(_) | U32WrappingMulImm(_) | U32DivImm(_)
+            | U32ModImm(_) | U32ShrImm(_) | U32ShlImm(_) | U32RotrImm(_) | U32RotlImm(_) => (1, 1),
+            U32OverflowingAdd | U32OverflowingSub | U32OverflowingMul | U32DivMod => (2, 2),
+            U32OverflowingAddImm(_)
+            | U32OverflowingSubImm(_)
+            | U32OverflowingMulImm(_)
+            | U32DivModImm(_) => (1, 2),
+            U32OverflowingAdd3 | U32OverflowingMadd => (3, 2),
+            U32WrappingAdd3 | U32WrappingMadd => (3, 1),
+            U32Not | U32Popcnt | U32Clz | U32Ctz | U32Clo | U32Cto => (1, 1),
+
+            // ----- stack manipulation -----------------------------------------------------------
+            Drop => (1, 0),
+            DropW => (4, 0),
+            PadW => (0, 4),
+            Dup0 => dup(0),
+            Dup1 => dup(1),
+            Dup2 => dup(2),
+            Dup3 => dup(3),
+            Dup4 => dup(4),
+            Dup5 => dup(5),
+            Dup6 => dup(6),
+            Dup7 => dup(7),
+            Dup8 => dup(8),
+            Dup9 => dup(9),
+            Dup10 => dup(10),
+            Dup11 => dup(11),
+            Dup12 => dup(12),
+            Dup13 => dup(13),
+            Dup14 => dup(14),
+            Dup15 => dup(15),
+            DupW0 => (4, 8),
+            DupW1 => (8, 12),
+            DupW2 => (12, 16),
+            DupW3 => (16, 20),
+            Swap1 | MovUp2 | MovDn2 => permute(2),
+            Swap2 | MovUp3 | MovDn3 => permute(3),
+            Swap3 | MovUp4 | MovDn4 => permute(4),
+            Swap4 | MovUp5 | MovDn5 => permute(5),
+            Swap5 | MovUp6 | MovDn6 => permute(6),
+            Swap6 | MovUp7 | MovDn7 => permute(7),
+            Swap7 | MovUp8 | MovDn8 => permute(8),
+            Swap8 | MovUp9 | MovDn9 => permute(9),
+            Swap9 | MovUp10 | MovDn10 => permute(10),
+            Swap10 | MovUp11 | MovDn11 => permute(11),
+            Swap11 | MovUp12 | MovDn12 => permute(12),
+            Swap12 | MovUp13 | MovDn13 => permute(13),
+            Swap13 | MovUp14 | MovDn14 => permute(14),
+            Swap14 | MovUp15 | MovDn15 => permute(15),
+            Swap15 => permute(16),
+            SwapW1 => permute(8),
+            SwapW2 | MovUpW2 | MovDnW2 => permute(12),
+            SwapW3 | MovUpW3 | MovDnW3 | SwapDw => permute(16),
+            CSwap => (3, 2),
+            CSwapW => (9, 8),
+            CDrop => (3, 1),
+            CDropW => (9, 4),
+
+            // ----- input / output operations ----------------------------------------------------
+            PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) => (0, 1),
+            PushWord(_) => (0, 4),
+            PushU8List(values) => (0, values.len() as u32),
+            PushU16List(values) => (0, values.len() as u32),
+            PushU32List(values) => (0, values.len() as u32),
+            PushFeltList(values) => (0, values.len() as u32),
+            Locaddr(_) | Sdepth | Clk => (0, 1),
+            Caller => (4, 4),
+
+            MemLoad => (1, 1),
+            MemLoadImm(_) | LocLoad(_) => (0, 1),
+            MemLoadW => (5, 4),
+            MemLoadWImm(_) | LocLoadW(_) => (4, 4),
+            MemStore => (2, 0),
+            MemStoreImm(_) | LocStore(_) => (1, 0),
+            MemStoreW => (5, 4),
+            MemStoreWImm(_) | LocStoreW(_) => (4, 4),
+
+            MemStream | AdvPipe => (13, 13),
+            MemProtect => (2, 0),
+
+            AdvPush(n) => (0, *n as u32),
+            AdvLoadW => (4, 4),
+            AdvInject(injector) => permute(injector_inputs(injector)),
+
+            // ----- cryptographic operations -----------------------------------------------------
+            Hash => (4, 4),
+            HMerge | MTreeMerge => (8, 4),
+            HPerm => (12, 12),
+            MTreeGet => (6, 8),
+            MTreeSet => (10, 8),
+            MTreeVerify => (10, 10),
+
+            // ----- STARK proof verification -----------------------------------------------------
+            // the address of the final FRI layer is moved from the stack overflow table
+            FriExt2Fold4 => (17, 16),
+            RCombBase => permute(16),
+
+            // ----- procedure invocations --------------------------------------------------------
+            ExecLocal(_)
+            | ExecImported(_)
+            | ExecLocalPinned(..)
+            | ExecImportedPinned(..)
+            | DynExec => return None,
+            CallLocal(_)
+            | CallMastRoot(_)
+            | CallImported(_)
+            | CallLocalPinned(..)
+            | CallImportedPinned(..)
+            | SysCall(_)
+            | DynCall => (0, 0),
+            ProcRefLocal(_) | ProcRefImported(_) => (0, 4),
+
+            // ----- decorators -------------------------------------------------------------------
+            Breakpoint | Debug(_) | Emit(_) | Trace(_) => (0, 0),
+        };
+
+        Some(StackEffect::new(inputs, outputs))
+    }
+}
+
+// STACK DEPTH ANALYZER
+// ================================================================================================
+
+/// A static analysis pass which checks how procedures change the depth of the stack.
+///
+/// The analyzer tracks the depth of the stack through the bodies of procedures, and reports the
+/// following problems as warnings:
+/// - The branches of an `if.true` or an `@if` block change the depth of the stack differently.
+/// - The body of a `while.true` loop changes the depth of the stack (i.e., it does not leave
+///   exactly one element, the loop condition, on top of the elements it started with).
+/// - A procedure (or the program body) accesses more than 16 elements of the stack it starts
+///   with; since only the top 16 elements of the stack are visible to a procedure invoked via
+///   `call` or `syscall`, and the inputs of a program consist of 16 elements, such accesses read
+///   zeros rather than the intended values.
+///
+/// Stack effects of local procedures are inferred from their bodies and used to analyze their
+/// callers. If a procedure declares a signature in its doc comments (see [ProcedureSignature]),
+/// the declared effect is used instead, and the analyzer additionally reports:
+/// - Procedures whose bodies do not match their declared signatures.
+/// - `exec` instructions invoking a procedure with a declared signature from within a procedure
+///   with a declared signature, if the invoked procedure consumes more elements than the invoking
+///   procedure has available at that point (i.e., its declared inputs plus the elements it has
+///   pushed onto the stack).
+///
+/// Effects of imported procedures and of procedures whose effect depends on the data
+/// they process (e.g., procedures whose branches intentionally leave different numbers of
+/// elements on the stack) can be provided via annotations; procedures which have an annotation
+/// are not analyzed. Once the stack depth cannot be determined (e.g., after executing a
+/// procedure with an unknown effect, or a `dynexec` instruction), the rest of the enclosing body
+/// is not checked.
+#[derive(Debug, Clone, Default)]
+pub struct StackDepthAnalyzer {
+    annotations: BTreeMap<String, StackEffect>,
+}
+
+impl StackDepthAnalyzer {
+    /// Returns a new analyzer without any annotations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Annotates the procedure with the specified name with the provided stack effect.
+    ///
+    /// Local procedures are referred to by their names (e.g., `foo`), while imported procedures
+    /// are referred to by their fully-qualified paths (e.g., `std::math::u64::wrapping_add`).
+    pub fn with_annotation(mut self, procedure: impl Into<String>, effect: StackEffect) -> Self {
+        self.annotations.insert(procedure.into(), effect);
+        self
+    }
+
+    /// Analyzes the local procedures and the body of the provided program, and returns the
+    /// problems found.
+    pub fn analyze_program(&self, program: &ProgramAst) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let effects =
+            self.analyze_procedures(program.procedures(), program.import_info(), &mut diagnostics);
+
+        let start = program.source_locations().next().copied().unwrap_or_default();
+        let mut body = BodyAnalyzer::new(
+            self,
+            program.procedures(),
+            &effects,
+            program.import_info(),
+            &mut diagnostics,
+        );
+        body.analyze_root(program.body(), "program", Label::new(start, Token::BEGIN.len(), ""));
+
+        diagnostics
+    }
+
+    /// Analyzes the local procedures of the provided module, and returns the problems found.
+    pub fn analyze_module(&self, module: &ModuleAst) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        self.analyze_procedures(module.procs(), module.import_info(), &mut diagnostics);
+        diagnostics
+    }
+
+    /// Analyzes the provided procedures in the order of their declaration, and returns their
+    /// stack effects.
+    fn analyze_procedures(
+        &self,
+        procedures: &[ProcedureAst],
+        imports: &ModuleImports,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Vec<Option<StackEffect>> {
+        let mut effects = Vec::with_capacity(procedures.len());
+        for procedure in procedures {
+            let effect = match self.annotations.get(procedure.name.as_ref()) {
+                Some(effect) => Some(*effect),
+                None => {
+                    let keyword = if procedure.data.is_some() {
+                        Token::DATA
+                    } else if procedure.is_export {
+                        Token::EXPORT
+                    } else {
+                        Token::PROC
+                    };
+                    let header = Label::new(procedure.start, keyword.len(), "");
+                    let subject = format!("procedure `{}`", procedure.name);
+                    let declared = procedure.signature.as_ref().map(StackEffect::from);
+                    let inferred =
+                        BodyAnalyzer::new(self, procedures, &effects, imports, diagnostics)
+                            .with_declared_inputs(declared.map(|effect| effect.inputs()))
+                            .analyze_root(&procedure.body, &subject, header.clone());
+
+                    // a procedure may declare inputs it does not access, but it must not access
+                    // more inputs than it declares
+                    if let (Some(declared), Some(inferred)) = (declared, inferred) {
+                        if declared.delta() != inferred.delta()
+                            || declared.inputs() < inferred.inputs()
+                        {
+                            let message = format!(
+                                "{subject} declares stack effect {declared} in its signature, but \
+                                its body has stack effect {inferred}"
+                            );
+                            let label = Label::new(*header.location(), header.len(), "");
+                            diagnostics.push(Diagnostic::warning(message, label));
+                        }
+                    }
+                    declared.or(inferred)
+                }
+            };
+            effects.push(effect);
+        }
+        effects
+    }
+}
+
+// BODY ANALYZER
+// ================================================================================================
+
+/// Depth of the stack relative to the depth at the start of the analyzed procedure.
+#[derive(Debug, Clone, Copy)]
+struct DepthState {
+    /// Current depth of the stack, or None if it cannot be determined.
+    depth: Option<i64>,
+    /// The lowest depth accessed so far.
+    lowest: i64,
+}
+
+impl DepthState {
+    /// Applies the provided stack effect to this state; if the effect is unknown, the depth of
+    /// the stack becomes unknown.
+    fn apply(&mut self, effect: Option<StackEffect>) {
+        if let Some(depth) = self.depth {
+            match effect {
+                Some(effect) => {
+                    self.lowest = self.lowest.min(depth - effect.inputs() as i64);
+                    self.depth = Some(depth + effect.delta());
+                }
+                None => self.depth = None,
+            }
+        }
+    }
+}
+
+/// Tracks the depth of the stack through the body of a single procedure.
+struct BodyAnalyzer<'a> {
+    analyzer: &'a StackDepthAnalyzer,
+    /// All local procedures of the analyzed program or module.
+    procedures: &'a [ProcedureAst],
+    /// Stack effects of the local procedures declared before the analyzed procedure.
+    local_effects: &'a [Option<StackEffect>],
+    imports: &'a ModuleImports,
+    /// Number of inputs declared in the signature of the analyzed procedure, if any.
+    declared_inputs: Option<u32>,
+    diagnostics: &'a mut Vec<Diagnostic>,
+}
+
+impl<'a> BodyAnalyzer<'a> {
+    fn new(
+        analyzer: &'a StackDepthAnalyzer,
+        procedures: &'a [ProcedureAst],
+        local_effects: &'a [Option<StackEffect>],
+        imports: &'a ModuleImports,
+        diagnostics: &'a mut Vec<Diagnostic>,
+    ) -> Self {
+        Self {
+            analyzer,
+            procedures,
+            local_effects,
+            imports,
+            declared_inputs: None,
+            diagnostics,
+        }
+    }
+
+    /// Sets the number of inputs declared in the signature of the analyzed procedure.
+    fn with_declared_inputs(mut self, declared_inputs: Option<u32>) -> Self {
+        self.declared_inputs = declared_inputs;
+        self
+    }
+
+    /// Analyzes the body of a procedure, and returns the stack effect of the procedure if it can
+    /// be determined.
+    ///
+    /// The `subject` describes the procedure in reported messages, and the `header` label points
+    /// at the header of the procedure.
+    fn analyze_root(
+        &mut self,
+        body: &CodeBody,
+        subject: &str,
+        header: Label,
+    ) -> Option<StackEffect> {
+        let mut state = DepthState {
+            depth: Some(0),
+            lowest: 0,
+        };
+        self.analyze_body(body, &mut state);
+
+        let num_inputs = state.lowest.unsigned_abs();
+        if num_inputs > STACK_TOP_SIZE as u64 {
+            let message = format!(
+                "{subject} may underflow the stack: it accesses {num_inputs} elements of its \
+                input stack, but only the top {STACK_TOP_SIZE} elements are guaranteed to be \
+                available"
+            );
+            let label = Label::new(*header.location(), header.len(), "accesses too many inputs");
+            self.diagnostics.push(Diagnostic::warning(message, label));
+        }
+
+        let depth = state.depth?;
+        Some(StackEffect::new(num_inputs as u32, (depth - state.lowest) as u32))
+    }
+
+    /// Analyzes all nodes of the provided body, updating the provided state.
+    fn analyze_body(&mut self, body: &CodeBody, state: &mut DepthState) {
+        let locations = body.source_locations();
+        for (i, node) in body.nodes().iter().enumerate() {
+            if state.depth.is_none() {
+                return;
+            }
+            let location = locations.get(i).copied().unwrap_or_default();
+            self.analyze_node(node, location, state);
+        }
+    }
+
+    /// Analyzes a single node located at the specified location, updating the provided state.
+    fn analyze_node(&mut self, node: &Node, location: SourceLocation, state: &mut DepthState) {
+        match node {
+            Node::Instruction(instruction) => {
+                if let Instruction::ExecLocal(index) | Instruction::ExecLocalPinned(index, _) =
+                    instruction
+                {
+                    self.check_exec_signature(*index, location, state);
+                }
+                state.apply(self.instruction_effect(instruction))
+            }
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                // the condition is removed from the stack before either branch is executed
+                state.apply(Some(StackEffect::new(1, 0)));
+                let label = Label::new(location, "if.true".len(), "branches disagree");
+                self.analyze_branches(true_case, false_case, "if.true", label, state);
+            }
+            Node::IfFeature {
+                feature,
+                true_case,
+                false_case,
+            } => {
+                let block = format!("{} {feature}", Token::FEATURE_IF);
+                let label = Label::new(location, Token::FEATURE_IF.len(), "branches disagree");
+                self.analyze_branches(true_case, false_case, &block, label, state);
+            }
+            Node::While { body } => {
+                // the condition is removed from the stack before every iteration, and the body
+                // must leave a new condition on top of the stack
+                state.apply(Some(StackEffect::new(1, 0)));
+                let depth = state.depth.expect("unknown depth");
+                self.analyze_body(body, state);
+                if let Some(body_depth) = state.depth {
+                    let change = body_depth - 1 - depth;
+                    if change != 0 {
+                        let message = format!(
+                            "body of a `while.true` loop changes the stack depth by {change:+} \
+                            on every iteration"
+                        );
+                        let label = Label::new(location, "while.true".len(), "");
+                        self.diagnostics.push(Diagnostic::warning(message, label));
+                        state.depth = None;
+                    } else {
+                        state.depth = Some(depth);
+                    }
+                }
+            }
+            Node::Repeat { times, body } => {
+                let start = state.depth.expect("unknown depth");
+                let mut body_state = DepthState {
+                    depth: Some(start),
+                    lowest: start,
+                };
+                self.analyze_body(body, &mut body_state);
+                state.lowest = state.lowest.min(body_state.lowest);
+                state.depth = None;
+                if let Some(end) = body_state.depth {
+                    // if the body shrinks the stack, the last iteration accesses the lowest depth
+                    let delta = end - start;
+                    let num_iterations = *times as i64;
+                    let shrinkage = delta.min(0) * (num_iterations - 1).max(0);
+                    state.lowest = state.lowest.min(body_state.lowest + shrinkage);
+                    state.depth = Some(start + delta * num_iterations);
+                }
+            }
+        }
+    }
+
+    /// Analyzes both branches of a conditional block starting from the provided state, and
+    /// updates the state to the state after the block.
+    ///
+    /// If the branches change the depth of the stack differently, a warning is reported and the
+    /// depth after the block becomes unknown.
+    fn analyze_branches(
+        &mut self,
+        true_case: &CodeBody,
+        false_case: &CodeBody,
+        block: &str,
+        label: Label,
+        state: &mut DepthState,
+    ) {
+        let start = state.depth.expect("unknown depth");
+        let mut true_state = *state;
+        self.analyze_body(true_case, &mut true_state);
+        let mut false_state = *state;
+        self.analyze_body(false_case, &mut false_state);
+
+        state.lowest = true_state.lowest.min(false_state.lowest);
+        state.depth = match (true_state.depth, false_state.depth) {
+            (Some(true_depth), Some(false_depth)) if true_depth != false_depth => {
+                let message = format!(
+                    "branches of `{block}` have different stack effects: the first branch \
+                    changes the stack depth by {:+}, but the second branch changes it by {:+}",
+                    true_depth - start,
+                    false_depth - start
+                );
+                self.diagnostics.push(Diagnostic::warning(message, label));
+                None
+            }
+            (Some(depth), Some(_)) => Some(depth),
+            _ => None,
+        };
+    }
+
+    /// Checks that the local procedure with the specified index does not consume more elements
+    /// than are available according to the signature of the analyzed procedure.
+    fn check_exec_signature(&mut self, index: u16, location: SourceLocation, state: &DepthState) {
+        let (Some(declared_inputs), Some(depth)) = (self.declared_inputs, state.depth) else {
+            return;
+        };
+        let Some(procedure) = self.procedures.get(index as usize) else {
+            return;
+        };
+        let Some(signature) = procedure.signature.as_ref() else {
+            return;
+        };
+
+        let available = declared_inputs as i64 + depth;
+        let required = signature.num_inputs() as i64;
+        if required > available {
+            let message = format!(
+                "procedure `{}` consumes {required} elements according to its signature, but \
+                only {available} elements are available according to the signature of the \
+                invoking procedure",
+                procedure.name
+            );
+            let len = Token::EXEC.len() + 1 + procedure.name.as_str().len();
+            let label = Label::new(location, len, "not enough inputs");
+            self.diagnostics.push(Diagnostic::warning(message, label));
+        }
+    }
+
+    /// Returns the stack effect of the provided instruction, looking up the effects of invoked
+    /// procedures.
+    fn instruction_effect(&self, instruction: &Instruction) -> Option<StackEffect> {
+        match instruction {
+            Instruction::ExecLocal(index) | Instruction::ExecLocalPinned(index, _) => {
+                self.local_effects.get(*index as usize).copied()?
+            }
+            Instruction::ExecImported(id) | Instruction::ExecImportedPinned(id, _) => {
+                let (name, path) = self.imports.get_procedure_info(id)?;
+                let name = format!("{path}::{name}");
+                self.analyzer.annotations.get(&name).copied()
+            }
+            _ => instruction.stack_effect(),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the effect of duplicating the element at the specified depth.
+const fn dup(index: u32) -> (u32, u32) {
+    (index + 1, index + 2)
+}
+
+/// Returns the effect of an instruction which rearranges or overwrites the specified number of
+/// elements at the top of the stack without changing the stack depth.
+const fn permute(num_elements: u32) -> (u32, u32) {
+    (num_elements, num_elements)
+}
+
+/// Returns the number of elements at the top of the stack read by the provided advice injector.
+fn injector_inputs(injector: &AdviceInjectorNode) -> u32 {
+    use AdviceInjectorNode::*;
+
+    match injector {
+        PushU64Div => 4,
+        PushU256Div | PushU256ModInv => 16,
+        PushExt2intt => 3,
+        PushSmtGet | PushSmtPeek => 8,
+        PushSmtSet => 12,
+        PushMapVal | PushMapValN => 4,
+        PushMapValImm { offset } | PushMapValNImm { offset } => *offset as u32 + 4,
+        PushMtNode => 6,
+        PushSecp256k1BaseInv
+        | PushSecp256k1ScalarInv
+        | PushSecp256r1BaseInv
+        | PushSecp256r1ScalarInv => 1,
+        InsertMem => 6,
+        InsertHdword | InsertHdwordImm { .. } => 8,
+        InsertHperm => 12,
+        PushSignature { .. } => 8,
+    }
+}
@@ -141,3 +141,164 @@ fn nested_blocks() {
 
     assert_eq!(combined.hash(), program.hash());
 }
+
+#[test]
+fn instruction_cycle_estimates() {
+    use crate::ast::{Instruction, Node, ProgramAst};
+
+    // for instructions which do not depend on the rest of the program, the estimates must match
+    // the number of operations emitted by the assembler
+    let source = "begin
+        push.0 push.1 push.2.3 add.1 sub.5 u32overflowing_add.7 exp.u8 pow2 u32divmod.3
+        mem_load.4 mem_storew.100 hperm mtree_get swapw.2 dup.15 neq.0 assert_eqw
+        emit.1 trace.2 adv_push.4 u32shr.3 is_odd
+    end";
+    let program = ProgramAst::parse(source).unwrap();
+    let estimate = program
+        .body()
+        .nodes()
+        .iter()
+        .map(|node| match node {
+            Node::Instruction(instruction) => instruction.estimated_cycles().unwrap(),
+            _ => panic!("unexpected node"),
+        })
+        .sum::<u32>();
+
+    let program = Assembler::default().compile_ast(&program).unwrap();
+    let num_ops = match program.root() {
+        CodeBlock::Span(span) => span
+            .op_batches()
+            .iter()
+            .flat_map(|batch| batch.ops())
+            .filter(|op| !matches!(op, Operation::Noop))
+            .count(),
+        _ => panic!("expected a span block"),
+    };
+    assert_eq!(num_ops as u32, estimate);
+
+    // decorators do not consume any cycles
+    assert_eq!(Some(0), Instruction::Emit(1).estimated_cycles());
+    assert_eq!(Some(0), Instruction::Breakpoint.estimated_cycles());
+
+    // the cost of invocations depends on the invoked procedure
+    assert_eq!(None, Instruction::ExecLocal(0).estimated_cycles());
+    assert_eq!(None, Instruction::DynCall.estimated_cycles());
+
+    // instructions which require a specific context are estimated without it
+    assert_eq!(Some(1), Instruction::Caller.estimated_cycles());
+    assert_eq!(Some(4), Instruction::ProcRefLocal(0).estimated_cycles());
+    assert_eq!(Some(3), Instruction::LocLoad(0).estimated_cycles());
+}
+
+#[test]
+fn source_maps() {
+    use crate::{ast::SourceLocation, Deserializable, Serializable, SourceMap};
+
+    let source = "\
+begin
+    push.1 push.2
+    if.true
+        add
+    else
+        mul
+    end
+end";
+    let assembler = Assembler::default().with_source_maps(true);
+    let program = assembler.compile(source).unwrap();
+    let source_map = assembler.source_map(&program).unwrap();
+
+    let exec_path = LibraryPath::exec_path();
+    let root = program.root();
+    let (span, split) = match root {
+        CodeBlock::Join(block) => (block.first(), block.second()),
+        _ => panic!("expected a join block"),
+    };
+
+    // the root block spans from the first instruction to the end of the if-else block
+    let root_span = source_map.node(&root.hash()).unwrap();
+    assert_eq!(root_span.path(), &exec_path);
+    assert_eq!(root_span.start(), SourceLocation::new(2, 5));
+    assert_eq!(root_span.end(), SourceLocation::new(7, 5));
+
+    // the span block and its operation batch span both push instructions
+    let span_span = source_map.node(&span.hash()).unwrap();
+    assert_eq!(span_span.start(), SourceLocation::new(2, 5));
+    assert_eq!(span_span.end(), SourceLocation::new(2, 12));
+    assert_eq!(source_map.batch(&span.hash(), 0), Some(span_span));
+    assert_eq!(source_map.batch(&span.hash(), 1), None);
+
+    let split_span = source_map.node(&split.hash()).unwrap();
+    assert_eq!(split_span.start(), SourceLocation::new(3, 5));
+    assert_eq!(split_span.end(), SourceLocation::new(7, 5));
+    let on_true = match split {
+        CodeBlock::Split(block) => block.on_true(),
+        _ => panic!("expected a split block"),
+    };
+    let on_true_span = source_map.batch(&on_true.hash(), 0).unwrap();
+    assert_eq!(on_true_span.start(), SourceLocation::new(4, 9));
+    assert_eq!(on_true_span.end(), SourceLocation::new(4, 9));
+
+    // source maps can be serialized
+    let bytes = source_map.to_bytes();
+    assert_eq!(source_map, SourceMap::read_from_bytes(&bytes).unwrap());
+
+    // source maps are not recorded by default
+    let assembler = Assembler::default();
+    let program = assembler.compile(source).unwrap();
+    assert!(assembler.source_map(&program).is_none());
+}
+
+#[test]
+fn op_frequency_report() {
+    use crate::{OpFrequencyConfig, OpFrequencyReport};
+
+    let source = "\
+proc.foo
+    if.true
+        push.1 add
+    else
+        mul
+    end
+end
+begin
+    push.1 exec.foo
+    push.0 exec.foo
+    call.foo
+end";
+    let program = Assembler::default().compile(source).unwrap();
+
+    // by default, every occurrence of a node is counted, including the body of the callee
+    let report = OpFrequencyReport::new(&program, OpFrequencyConfig::default());
+    assert_eq!(report.node_count("split"), 3);
+    assert_eq!(report.node_count("call"), 1);
+    assert_eq!(report.node_count("loop"), 0);
+    assert_eq!(report.op_count("add"), 3);
+    assert_eq!(report.op_count("mul"), 3);
+    assert_eq!(report.num_ops(), report.ops().map(|(_, count)| count).sum::<usize>());
+    assert_eq!(report.num_nodes(), report.nodes().map(|(_, count)| count).sum::<usize>());
+
+    // entries are ordered from the most to the least frequent
+    let counts = report.ops().map(|(_, count)| count).collect::<Vec<_>>();
+    assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!(report.to_string().contains("mul"));
+
+    // the callee can be excluded from the report
+    let config = OpFrequencyConfig {
+        include_callees: false,
+        ..Default::default()
+    };
+    let report = OpFrequencyReport::new(&program, config);
+    assert_eq!(report.node_count("split"), 2);
+    assert_eq!(report.op_count("mul"), 2);
+
+    // nodes with the same MAST root can be counted once; the body of the callee is the same as the
+    // inlined procedure, and thus, is not counted again
+    let config = OpFrequencyConfig {
+        deduplicate_nodes: true,
+        ..Default::default()
+    };
+    let report = OpFrequencyReport::new(&program, config);
+    assert_eq!(report.node_count("split"), 1);
+    assert_eq!(report.node_count("call"), 1);
+    assert_eq!(report.op_count("mul"), 1);
+}
@@ -0,0 +1,151 @@
+use crate::Version;
+use alloc::{collections::BTreeMap, string::String};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Version of the instruction set in which `rpperm` and `rphash` were renamed.
+const V0_4_0: Version = Version {
+    major: 0,
+    minor: 4,
+    patch: 0,
+};
+
+/// Version of the instruction set in which the u32 instructions were reworked.
+const V0_8_0: Version = Version {
+    major: 0,
+    minor: 8,
+    patch: 0,
+};
+
+/// Deprecated instruction mnemonics accepted by default, together with the mnemonics of the
+/// instructions which replaced them and the versions of the instruction set in which the
+/// instructions were renamed.
+///
+/// Only mnemonics whose replacements have the same semantics are listed. Checked variants of
+/// instructions whose replacements no longer validate their inputs (e.g., `u32checked_shl`) are
+/// not aliased, since silently dropping the validation would change the behavior of existing
+/// programs.
+const DEPRECATED_MNEMONICS: &[(&str, &str, Version)] = &[
+    ("rphash", "hmerge", V0_4_0),
+    ("rpperm", "hperm", V0_4_0),
+    ("u32checked_and", "u32and", V0_8_0),
+    ("u32checked_not", "u32not", V0_8_0),
+    ("u32checked_or", "u32or", V0_8_0),
+    ("u32checked_xor", "u32xor", V0_8_0),
+    ("u32unchecked_div", "u32div", V0_8_0),
+    ("u32unchecked_divmod", "u32divmod", V0_8_0),
+    ("u32unchecked_gt", "u32gt", V0_8_0),
+    ("u32unchecked_gte", "u32gte", V0_8_0),
+    ("u32unchecked_lt", "u32lt", V0_8_0),
+    ("u32unchecked_lte", "u32lte", V0_8_0),
+    ("u32unchecked_max", "u32max", V0_8_0),
+    ("u32unchecked_min", "u32min", V0_8_0),
+    ("u32unchecked_mod", "u32mod", V0_8_0),
+    ("u32unchecked_popcnt", "u32popcnt", V0_8_0),
+    ("u32unchecked_rotl", "u32rotl", V0_8_0),
+    ("u32unchecked_rotr", "u32rotr", V0_8_0),
+    ("u32unchecked_shl", "u32shl", V0_8_0),
+    ("u32unchecked_shr", "u32shr", V0_8_0),
+];
+
+// INSTRUCTION ALIASES
+// ================================================================================================
+
+/// A table of alternative mnemonics under which instructions can be referred to in the source.
+///
+/// When the parser encounters a mnemonic which is not a mnemonic of any instruction, it looks the
+/// mnemonic up in this table; if the mnemonic is an alias, the instruction is parsed as if it
+/// were written using the mnemonic the alias maps to (with the immediate values preserved), and a
+/// warning suggesting to use that mnemonic instead is logged. This allows sources written for
+/// older versions of the instruction set to be assembled while they are being migrated.
+///
+/// The default table contains all deprecated mnemonics of renamed instructions; a table which
+/// contains only the mnemonics renamed after a given version of the instruction set can be
+/// created via [InstructionAliases::for_version()], and an empty table can be created via
+/// [InstructionAliases::none()]. Aliases which collide with mnemonics of existing
+/// instructions are never used, and aliases which map to anything other than a mnemonic of an
+/// existing instruction are reported as invalid instructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionAliases {
+    aliases: BTreeMap<String, InstructionAlias>,
+}
+
+/// The target of an alias in [InstructionAliases].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InstructionAlias {
+    mnemonic: String,
+    /// Version of the instruction set in which the aliased instruction was renamed, if the alias
+    /// is a deprecated mnemonic.
+    renamed_in: Option<Version>,
+}
+
+impl InstructionAliases {
+    /// Returns a table which does not contain any aliases.
+    pub fn none() -> Self {
+        Self {
+            aliases: BTreeMap::new(),
+        }
+    }
+
+    /// Returns a table containing the deprecated mnemonics of instructions renamed after the
+    /// specified version of the instruction set.
+    ///
+    /// This allows sources written for the specified version to be assembled, while mnemonics
+    /// which were already deprecated in that version are rejected.
+    pub fn for_version(version: Version) -> Self {
+        let aliases = DEPRECATED_MNEMONICS
+            .iter()
+            .filter(|(_, _, renamed_in)| *renamed_in > version)
+            .map(|(alias, mnemonic, renamed_in)| {
+                let target = InstructionAlias {
+                    mnemonic: String::from(*mnemonic),
+                    renamed_in: Some(*renamed_in),
+                };
+                (String::from(*alias), target)
+            })
+            .collect();
+        Self { aliases }
+    }
+
+    /// Adds an alias under which the instruction with the specified mnemonic can be referred to,
+    /// replacing the previous mapping of the alias, if any.
+    pub fn with_alias(mut self, alias: impl Into<String>, mnemonic: impl Into<String>) -> Self {
+        let target = InstructionAlias {
+            mnemonic: mnemonic.into(),
+            renamed_in: None,
+        };
+        self.aliases.insert(alias.into(), target);
+        self
+    }
+
+    /// Returns the mnemonic of the instruction the specified alias maps to, or None if the table
+    /// does not contain such an alias.
+    pub fn get(&self, alias: &str) -> Option<&str> {
+        self.aliases.get(alias).map(|target| target.mnemonic.as_str())
+    }
+
+    /// Returns the version of the instruction set in which the instruction referred to via the
+    /// specified alias was renamed, or None if the alias is not a deprecated mnemonic from the
+    /// default table.
+    pub fn renamed_in(&self, alias: &str) -> Option<Version> {
+        self.aliases.get(alias).and_then(|target| target.renamed_in)
+    }
+
+    /// Returns the number of aliases in this table.
+    pub fn len(&self) -> usize {
+        self.aliases.len()
+    }
+
+    /// Returns true if this table does not contain any aliases.
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+}
+
+impl Default for InstructionAliases {
+    /// Returns a table containing all deprecated mnemonics of renamed instructions.
+    fn default() -> Self {
+        Self::for_version(Version::MIN)
+    }
+}
@@ -115,6 +115,14 @@ impl CodeBody {
         &self.nodes
     }
 
+    /// Returns a mutable reference to the [Node] sequence.
+    ///
+    /// The number of nodes cannot be changed via the returned slice, and thus the source locations
+    /// bound to the nodes remain valid.
+    pub fn nodes_mut(&mut self) -> &mut [Node] {
+        &mut self.nodes
+    }
+
     /// Returns the [SourceLocations] bound to the nodes of this body structure.
     pub fn source_locations(&self) -> &[SourceLocation] {
         &self.locations
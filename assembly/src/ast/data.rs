@@ -0,0 +1,144 @@
+use super::{
+    read_len, AdviceInjectorNode, ByteReader, ByteWriter, CodeBody, Deserializable,
+    DeserializationError, Felt, Instruction, Node, RpoDigest, Serializable, StarkField,
+};
+use crate::crypto::hash::Rpo256;
+use alloc::{string::ToString, vec::Vec};
+
+// DATA SEGMENT
+// ================================================================================================
+
+/// A constant array of words declared via a `data.<name>` section of a module or a program.
+///
+/// The assembler adds the values of a data segment to the advice map under the commitment to the
+/// values (see [DataSegment::commitment()]), and generates a procedure with the name of the data
+/// segment which copies the values from the advice provider into memory. The generated procedure
+/// expects the memory address of the first word on the top of the stack:
+///
+/// ```text
+/// Input: [write_ptr, ...]
+/// Output: [...]
+/// ```
+///
+/// The values are copied to memory addresses `write_ptr..write_ptr + num_words`, and the
+/// procedure fails if the values provided by the advice provider do not match the commitment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSegment {
+    values: Vec<Felt>,
+}
+
+impl DataSegment {
+    /// Maximum number of words in a data segment.
+    pub const MAX_WORDS: usize = u16::MAX as usize;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a data segment consisting of the provided values.
+    ///
+    /// # Errors
+    /// Returns an error message if the values do not form a non-empty list of at most
+    /// [DataSegment::MAX_WORDS] words.
+    pub fn new(values: Vec<Felt>) -> Result<Self, &'static str> {
+        if values.is_empty() {
+            Err("data segment must contain at least one word")
+        } else if values.len() % 4 != 0 {
+            Err("number of values in a data segment must be a multiple of 4")
+        } else if values.len() / 4 > Self::MAX_WORDS {
+            Err("data segment contains too many words")
+        } else {
+            Ok(Self { values })
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the values of this data segment.
+    pub fn values(&self) -> &[Felt] {
+        &self.values
+    }
+
+    /// Returns the number of words in this data segment.
+    pub fn num_words(&self) -> usize {
+        self.values.len() / 4
+    }
+
+    /// Returns the commitment to the values of this data segment, computed as a sequential hash
+    /// of the values.
+    pub fn commitment(&self) -> RpoDigest {
+        Rpo256::hash_elements(&self.values)
+    }
+
+    // LOADER PROCEDURE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the body of the procedure which copies the values of this data segment from the
+    /// advice provider into memory.
+    ///
+    /// The words are piped into memory two at a time while they are being hashed, in the same
+    /// way as in `std::mem::pipe_preimage_to_memory`, and the resulting hash is compared with the
+    /// commitment to the data segment.
+    pub(crate) fn loader_body(&self) -> Vec<Node> {
+        use Instruction::*;
+
+        let commitment = self.commitment().as_elements().to_vec();
+        let needs_padding = self.num_words() % 2 == 1;
+
+        // => [write_ptr, COM, ...] with the values of the data segment on the advice stack
+        let mut body =
+            vec![PushFeltList(commitment), AdvInject(AdviceInjectorNode::PushMapVal), MovUp4];
+
+        // => [C, B, A, write_ptr, COM, ...] where the first element of the capacity word A is set
+        // to 1 if the last word needs padding
+        body.push(PushU8List(vec![needs_padding as u8, 0, 0, 0]));
+        body.extend([PadW, PadW]);
+        let mut nodes: Vec<Node> = body.into_iter().map(Node::Instruction).collect();
+
+        if self.num_words() >= 2 {
+            let pipe = [AdvPipe, HPerm].into_iter().map(Node::Instruction);
+            nodes.push(Node::Repeat {
+                times: (self.num_words() / 2) as u32,
+                body: CodeBody::new(pipe),
+            });
+        }
+
+        let mut body = Vec::new();
+        if needs_padding {
+            // overwrite B with the last word, store it in memory, and pad it with [1, 0, 0, 0]
+            body.extend([DropW, AdvLoadW, MovUp8, Dup0, Incr, MovDn5, MemStoreW, MovUp4, MovDn8]);
+            body.extend([PushU8List(vec![1, 0, 0, 0]), HPerm]);
+        }
+
+        // => [HASH, COM, ...]
+        body.extend([DropW, SwapW1, DropW, MovUp4, Drop, AssertEqw]);
+        nodes.extend(body.into_iter().map(Node::Instruction));
+        nodes
+    }
+}
+
+impl Serializable for DataSegment {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.num_words());
+        for value in self.values.iter() {
+            target.write_u64(value.as_int());
+        }
+    }
+}
+
+impl Deserializable for DataSegment {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_words = read_len(source, Self::MAX_WORDS)?;
+        let mut values = Vec::with_capacity(num_words * 4);
+        for _ in 0..num_words * 4 {
+            let value = source.read_u64()?;
+            if value >= Felt::MODULUS {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid data segment value {value}"
+                )));
+            }
+            values.push(Felt::new(value));
+        }
+        Self::new(values).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
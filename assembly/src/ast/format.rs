@@ -2,7 +2,7 @@ use super::{
     CodeBody, FormattableNode, InvokedProcsMap, LibraryPath, ProcedureAst, ProcedureId,
     ProcedureName,
 };
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use core::fmt;
 
 const INDENT_STRING: &str = "    ";
@@ -84,6 +84,21 @@ impl<'a> FormattableProcedureAst<'a> {
 
 impl fmt::Display for FormattableProcedureAst<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Data sections are printed as declared; the docs and the body of the procedure are
+        // generated from the data
+        if let Some(ref data) = self.proc.data {
+            self.context.indent(f)?;
+            writeln!(f, "data.{}", self.proc.name)?;
+            let inner_context = self.context.inner_scope_context();
+            for word in data.values().chunks(4) {
+                inner_context.indent(f)?;
+                let word = word.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+                writeln!(f, "{}", word.join("."))?;
+            }
+            self.context.indent(f)?;
+            return writeln!(f, "end");
+        }
+
         // Docs
         self.context.indent(f)?;
         if let Some(ref doc) = self.proc.docs {
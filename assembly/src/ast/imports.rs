@@ -1,7 +1,7 @@
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, InvokedProcsMap, LibraryPath,
-    ParsingError, ProcedureId, ProcedureName, Serializable, Token, TokenStream, MAX_IMPORTS,
-    MAX_INVOKED_IMPORTED_PROCS,
+    read_len, ByteReader, ByteWriter, Deserializable, DeserializationError, InvokedProcsMap,
+    LibraryPath, ModuleAst, ParsingError, ProcedureId, ProcedureName, Serializable, Token,
+    TokenStream, MAX_IMPORTS, MAX_INVOKED_IMPORTED_PROCS,
 };
 use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
@@ -171,11 +171,11 @@ impl ModuleImports {
 
 impl Serializable for ModuleImports {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write_u16(self.imports.len() as u16);
+        target.write_usize(self.imports.len());
         // We don't need to serialize the library names (the keys), since the libraty paths (the
         // values) contain the library names
         self.imports.values().for_each(|i| i.write_into(target));
-        target.write_u16(self.invoked_procs.len() as u16);
+        target.write_usize(self.invoked_procs.len());
         for (proc_id, (proc_name, lib_path)) in self.invoked_procs.iter() {
             proc_id.write_into(target);
             proc_name.write_into(target);
@@ -187,14 +187,14 @@ impl Serializable for ModuleImports {
 impl Deserializable for ModuleImports {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let mut imports = BTreeMap::<String, LibraryPath>::new();
-        let num_imports = source.read_u16()?;
+        let num_imports = read_len(source, MAX_IMPORTS)?;
         for _ in 0..num_imports {
             let path = LibraryPath::read_from(source)?;
             imports.insert(path.last().to_string(), path);
         }
 
         let mut used_imported_procs = InvokedProcsMap::new();
-        let num_used_imported_procs = source.read_u16()?;
+        let num_used_imported_procs = read_len(source, MAX_INVOKED_IMPORTED_PROCS)?;
         for _ in 0..num_used_imported_procs {
             let proc_id = ProcedureId::read_from(source)?;
             let proc_name = ProcedureName::read_from(source)?;
@@ -204,3 +204,28 @@ impl Deserializable for ModuleImports {
         Ok(Self::new(imports, used_imported_procs))
     }
 }
+
+// IMPORTED CONSTANTS
+// ================================================================================================
+
+/// Constants defined in modules which can be imported by the parsed source.
+///
+/// A constant defined in an imported module can be referenced in constant expressions as
+/// `<module>::<NAME>`, where `<module>` is the name under which the module is imported.
+#[derive(Debug, Default, Clone)]
+pub struct ImportedConstants<'a> {
+    modules: BTreeMap<&'a LibraryPath, &'a ModuleAst>,
+}
+
+impl<'a> ImportedConstants<'a> {
+    /// Makes constants defined in the provided module available under the specified path.
+    pub fn add_module(&mut self, path: &'a LibraryPath, module: &'a ModuleAst) {
+        self.modules.insert(path, module);
+    }
+
+    /// Returns the value of the constant with the specified name defined in the module with the
+    /// specified path, or None if such a constant is not available.
+    pub fn get(&self, path: &LibraryPath, name: &str) -> Option<u64> {
+        self.modules.get(path)?.constants().get(name).copied()
+    }
+}
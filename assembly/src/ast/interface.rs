@@ -0,0 +1,155 @@
+use super::{ModuleAst, ProcedureName, ProcedureSignature};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+// MODULE INTERFACE
+// ================================================================================================
+
+/// A summary of the items of a module which are visible to other modules.
+///
+/// The interface consists of the module docs, the constants defined in the module, and the
+/// procedures exported from the module (including re-exported procedures). Constants and exports
+/// are sorted by name, and thus, the interfaces of two versions of a module can be compared
+/// item by item (e.g., to detect breaking changes between library versions).
+///
+/// The interface is formatted as a list of MASM declarations, each one preceded by its doc
+/// comments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleInterface {
+    docs: Option<String>,
+    constants: Vec<ConstantInterface>,
+    exports: Vec<ExportInterface>,
+}
+
+impl ModuleInterface {
+    /// Returns the interface of the provided module.
+    pub fn new(module: &ModuleAst) -> Self {
+        let constants = module
+            .constants()
+            .iter()
+            .map(|(name, &value)| ConstantInterface {
+                name: name.clone(),
+                value,
+                docs: module.constant_docs(name).map(String::from),
+            })
+            .collect();
+
+        let local_exports =
+            module.procs().iter().filter(|proc| proc.is_export).map(|proc| ExportInterface {
+                name: proc.name.clone(),
+                signature: proc.signature.clone(),
+                docs: proc.docs.clone(),
+                is_reexport: false,
+            });
+        let reexports = module.reexported_procs().iter().map(|proc| ExportInterface {
+            name: proc.name().clone(),
+            signature: proc.docs().and_then(|docs| ProcedureSignature::parse(docs).ok()?),
+            docs: proc.docs().map(String::from),
+            is_reexport: true,
+        });
+        let mut exports: Vec<_> = local_exports.chain(reexports).collect();
+        exports.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            docs: module.docs().cloned(),
+            constants,
+            exports,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns doc comments of the module.
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
+
+    /// Returns constants defined in the module, sorted by name.
+    pub fn constants(&self) -> &[ConstantInterface] {
+        &self.constants
+    }
+
+    /// Returns procedures exported from the module, sorted by name.
+    pub fn exports(&self) -> &[ExportInterface] {
+        &self.exports
+    }
+}
+
+impl fmt::Display for ModuleInterface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut items = Vec::new();
+        if let Some(docs) = &self.docs {
+            items.push(format_docs(docs));
+        }
+        items.extend(self.constants.iter().map(|constant| constant.to_string()));
+        items.extend(self.exports.iter().map(|export| export.to_string()));
+        write!(f, "{}", items.join("\n\n"))
+    }
+}
+
+// CONSTANT INTERFACE
+// ================================================================================================
+
+/// A constant defined in a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstantInterface {
+    pub name: String,
+    pub value: u64,
+    pub docs: Option<String>,
+}
+
+impl fmt::Display for ConstantInterface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(docs) = &self.docs {
+            writeln!(f, "{}", format_docs(docs))?;
+        }
+        write!(f, "const.{}={}", self.name, self.value)
+    }
+}
+
+// EXPORT INTERFACE
+// ================================================================================================
+
+/// A procedure exported from a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportInterface {
+    pub name: ProcedureName,
+    pub signature: Option<ProcedureSignature>,
+    pub docs: Option<String>,
+    /// True if the procedure is defined in another module and re-exported from this module.
+    pub is_reexport: bool,
+}
+
+impl fmt::Display for ExportInterface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(docs) = &self.docs {
+            writeln!(f, "{}", format_docs(docs))?;
+        }
+        write!(f, "export.{}", self.name)?;
+        if self.is_reexport {
+            write!(f, " # re-exported")?;
+        }
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Formats the provided doc comments as a block of `#!` lines.
+fn format_docs(docs: &str) -> String {
+    docs.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "#!".into()
+            } else {
+                format!("#! {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
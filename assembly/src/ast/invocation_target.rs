@@ -54,6 +54,19 @@ impl<'a> InvocationTarget<'a> {
         }
     }
 
+    /// Parses a MAST root pinned for the target of an invocation instruction.
+    ///
+    /// The parameter must consist of the `digest!` prefix followed by a hexadecimal string
+    /// representation of an [RpoDigest] starting with "0x".
+    pub fn parse_pinned_root(param: &str, token: &Token) -> Result<RpoDigest, ParsingError> {
+        let label = param
+            .strip_prefix(Token::PINNED_ROOT_PREFIX)
+            .filter(|label| label.starts_with("0x"))
+            .ok_or_else(|| ParsingError::invalid_param(token, 2))?;
+        decode_hex_rpo_digest_label(label)
+            .map_err(|err| ParsingError::invalid_pinned_proc_root(token, label, err))
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
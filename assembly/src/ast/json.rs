@@ -0,0 +1,468 @@
+//! JSON representation of module ASTs.
+//!
+//! The JSON representation mirrors the binary serialization of [ModuleAst], but identifies every
+//! value by name rather than by position. This makes it possible for external tools to inspect
+//! and construct ASTs without linking against a specific version of this crate.
+//!
+//! # Schema
+//! A module is encoded as an object with the following fields:
+//! - `format_version`: the version of the binary AST format which the representation mirrors
+//!   (see [AST_FORMAT_VERSION]).
+//! - `docs`: module doc comments, or `null`.
+//! - `imports`: an object with two fields: `modules`, a list of paths of the imported modules
+//!   (e.g., `"std::math::u64"`), and `invoked_procs`, a list of `{ "id", "name", "path" }`
+//!   objects describing the imported procedures invoked from the module.
+//! - `reexports`: a list of `{ "id", "name", "docs" }` objects describing re-exported procedures.
+//! - `procedures`: a list of `{ "name", "docs", "is_export", "num_locals", "body", "data" }`
+//!   objects in declaration order. `data` holds the values of the data segment of procedures
+//!   generated for `data` sections, and is `null` for all other procedures.
+//! - `constants`: a list of `{ "name", "value", "docs" }` objects.
+//!
+//! Procedure IDs are encoded as `0x`-prefixed hex strings, and field elements and other numbers
+//! are encoded as unsigned integers.
+//!
+//! A body is a list of nodes. Every node is an object whose `op` field holds the name of the
+//! operation code of the node in the binary format:
+//! - `{ "op": "IfElse", "true_case": [..], "false_case": [..] }`
+//! - `{ "op": "Repeat", "times": n, "body": [..] }`
+//! - `{ "op": "While", "body": [..] }`
+//! - `{ "op": "IfFeature", "feature": "..", "true_case": [..], "false_case": [..] }`
+//! - `{ "op": "<opcode>", "imm": ".." }` for all other instructions (e.g., `"AddImm"`), where
+//!   `imm` holds the hex encoding of the immediate values of the instruction exactly as they are
+//!   encoded in the binary format (i.e., all bytes which follow the opcode). `imm` is an empty
+//!   string for instructions without immediate values.
+//!
+//! Source locations are not part of the representation.
+
+use super::{
+    nodes::OpCode, ByteReader, CodeBody, DataSegment, Deserializable, DeserializationError, Felt,
+    Instruction, InvokedProcsMap, LibraryPath, LocalConstDocsMap, LocalConstMap, ModuleAst,
+    ModuleImports, Node, ProcReExport, ProcedureAst, ProcedureId, ProcedureName,
+    ProcedureSignature, Serializable, SliceReader, SourceLocation, StarkField, AST_FORMAT_VERSION,
+    AST_MIN_FORMAT_VERSION, CONSTANT_LABEL_PARSER, FEATURE_LABEL_PARSER, MAX_BODY_LEN,
+    MAX_DOCS_LEN, MAX_IMPORTS, MAX_INVOKED_IMPORTED_PROCS, MAX_LOCAL_PROCS, MAX_REEXPORTED_PROCS,
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde_json::{json, Value};
+
+// TYPE ALIASES
+// ================================================================================================
+
+type OpCodeMap = BTreeMap<String, OpCode>;
+
+// MODULE AST
+// ================================================================================================
+
+impl ModuleAst {
+    /// Returns the JSON representation of this [ModuleAst].
+    ///
+    /// The schema of the representation is described in the documentation of the `json` module.
+    pub fn to_json(&self) -> String {
+        let modules: Vec<String> =
+            self.import_info.import_paths().iter().map(|path| path.to_string()).collect();
+        let invoked_procs = self.import_info.invoked_procs().iter().map(|(id, (name, path))| {
+            json!({
+                "id": id.to_string(),
+                "name": name.to_string(),
+                "path": path.to_string(),
+            })
+        });
+        let imports = json!({
+            "modules": modules,
+            "invoked_procs": invoked_procs.collect::<Vec<_>>(),
+        });
+
+        let reexports = self
+            .reexported_procs
+            .iter()
+            .map(|proc| {
+                json!({
+                    "id": proc.proc_id.to_string(),
+                    "name": proc.name.to_string(),
+                    "docs": proc.docs,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let procedures = self
+            .local_procs
+            .iter()
+            .map(|proc| {
+                json!({
+                    "name": proc.name.to_string(),
+                    "docs": proc.docs,
+                    "is_export": proc.is_export,
+                    "num_locals": proc.num_locals,
+                    "body": body_to_json(proc.body.nodes()),
+                    "data": proc.data.as_ref().map(|data| {
+                        data.values().iter().map(|value| value.as_int()).collect::<Vec<_>>()
+                    }),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let constants = self
+            .constants
+            .iter()
+            .map(|(name, value)| {
+                json!({
+                    "name": name,
+                    "value": value,
+                    "docs": self.const_docs.get(name),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "format_version": AST_FORMAT_VERSION,
+            "docs": self.docs,
+            "imports": imports,
+            "reexports": reexports,
+            "procedures": procedures,
+            "constants": constants,
+        })
+        .to_string()
+    }
+
+    /// Returns a [ModuleAst] parsed from the provided JSON representation.
+    ///
+    /// # Errors
+    /// Returns an error if the provided string is not a valid JSON representation of a module
+    /// AST, or if it was produced for a binary format version which is not supported.
+    pub fn from_json(json: &str) -> Result<Self, DeserializationError> {
+        let module: Value = serde_json::from_str(json)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        let version = get_u64(&module, "format_version")?;
+        if !(AST_MIN_FORMAT_VERSION as u64..=AST_FORMAT_VERSION as u64).contains(&version) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported AST format version {version}, expected a version between \
+                {AST_MIN_FORMAT_VERSION} and {AST_FORMAT_VERSION}"
+            )));
+        }
+
+        let docs = get_docs(&module, "docs")?;
+        let import_info = imports_from_json(get(&module, "imports")?)?;
+
+        let reexports = get_array(&module, "reexports", MAX_REEXPORTED_PROCS)?;
+        let reexported_procs = reexports
+            .iter()
+            .map(|proc| {
+                Ok(ProcReExport::new(
+                    get_proc_id(proc, "id")?,
+                    get_proc_name(proc, "name")?,
+                    get_docs(proc, "docs")?,
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let opcodes = opcode_map();
+        let procedures = get_array(&module, "procedures", MAX_LOCAL_PROCS)?;
+        let local_procs = procedures
+            .iter()
+            .map(|proc| procedure_from_json(proc, &opcodes))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut constants = LocalConstMap::new();
+        let mut const_docs = LocalConstDocsMap::new();
+        for constant in get_array(&module, "constants", u16::MAX as usize)? {
+            let name = get_str(constant, "name")?;
+            CONSTANT_LABEL_PARSER
+                .parse_label(name)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            if constants.insert(name.to_string(), get_u64(constant, "value")?).is_some() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "constant {name} is defined more than once"
+                )));
+            }
+            if let Some(docs) = get_docs(constant, "docs")? {
+                const_docs.insert(name.to_string(), docs);
+            }
+        }
+
+        match Self::new(local_procs, reexported_procs, docs) {
+            Err(err) => Err(DeserializationError::UnknownError(err.message().clone())),
+            Ok(res) => Ok(res
+                .with_import_info(import_info)
+                .with_constants(constants)
+                .with_constant_docs(const_docs)),
+        }
+    }
+}
+
+// ENCODING
+// ================================================================================================
+
+fn body_to_json(nodes: &[Node]) -> Vec<Value> {
+    nodes.iter().map(node_to_json).collect()
+}
+
+fn node_to_json(node: &Node) -> Value {
+    match node {
+        Node::Instruction(instruction) => {
+            let bytes = instruction.to_bytes();
+            let mut source = SliceReader::new(&bytes);
+            let opcode = OpCode::read_from(&mut source).expect("invalid instruction encoding");
+            let imm = &bytes[opcode.to_bytes().len()..];
+            json!({
+                "op": format!("{opcode:?}"),
+                "imm": to_hex(imm),
+            })
+        }
+        Node::IfElse {
+            true_case,
+            false_case,
+        } => json!({
+            "op": format!("{:?}", OpCode::IfElse),
+            "true_case": body_to_json(true_case.nodes()),
+            "false_case": body_to_json(false_case.nodes()),
+        }),
+        Node::Repeat { times, body } => json!({
+            "op": format!("{:?}", OpCode::Repeat),
+            "times": times,
+            "body": body_to_json(body.nodes()),
+        }),
+        Node::While { body } => json!({
+            "op": format!("{:?}", OpCode::While),
+            "body": body_to_json(body.nodes()),
+        }),
+        Node::IfFeature {
+            feature,
+            true_case,
+            false_case,
+        } => json!({
+            "op": format!("{:?}", OpCode::IfFeature),
+            "feature": feature,
+            "true_case": body_to_json(true_case.nodes()),
+            "false_case": body_to_json(false_case.nodes()),
+        }),
+    }
+}
+
+// DECODING
+// ================================================================================================
+
+fn imports_from_json(imports: &Value) -> Result<ModuleImports, DeserializationError> {
+    let mut modules = BTreeMap::new();
+    for path in get_array(imports, "modules", MAX_IMPORTS)? {
+        let path = path.as_str().ok_or_else(|| {
+            DeserializationError::InvalidValue("module path must be a string".to_string())
+        })?;
+        let path = LibraryPath::new(path)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        modules.insert(path.last().to_string(), path);
+    }
+
+    let mut invoked_procs = InvokedProcsMap::new();
+    for proc in get_array(imports, "invoked_procs", MAX_INVOKED_IMPORTED_PROCS)? {
+        let path = LibraryPath::new(get_str(proc, "path")?)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        invoked_procs.insert(get_proc_id(proc, "id")?, (get_proc_name(proc, "name")?, path));
+    }
+
+    Ok(ModuleImports::new(modules, invoked_procs))
+}
+
+fn procedure_from_json(
+    proc: &Value,
+    opcodes: &OpCodeMap,
+) -> Result<ProcedureAst, DeserializationError> {
+    let docs = get_docs(proc, "docs")?;
+    let num_locals = u16::try_from(get_u64(proc, "num_locals")?)
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+    let data = match get(proc, "data")? {
+        Value::Null => None,
+        _ => {
+            let values = get_array(proc, "data", usize::MAX)?
+                .iter()
+                .map(felt_from_json)
+                .collect::<Result<Vec<_>, _>>()?;
+            let data = DataSegment::new(values)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            Some(data)
+        }
+    };
+
+    // signatures are not encoded separately since they are declared in the doc comments
+    let signature = docs.as_deref().and_then(|docs| ProcedureSignature::parse(docs).ok()?);
+    Ok(ProcedureAst {
+        name: get_proc_name(proc, "name")?,
+        docs,
+        signature,
+        num_locals,
+        body: body_from_json(proc, "body", opcodes)?,
+        start: SourceLocation::default(),
+        is_export: get_bool(proc, "is_export")?,
+        data,
+    })
+}
+
+fn body_from_json(
+    value: &Value,
+    field: &str,
+    opcodes: &OpCodeMap,
+) -> Result<CodeBody, DeserializationError> {
+    let nodes = get_array(value, field, MAX_BODY_LEN)?
+        .iter()
+        .map(|node| node_from_json(node, opcodes))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(CodeBody::new(nodes))
+}
+
+fn node_from_json(node: &Value, opcodes: &OpCodeMap) -> Result<Node, DeserializationError> {
+    let op = get_str(node, "op")?;
+    let opcode = *opcodes
+        .get(op)
+        .ok_or_else(|| DeserializationError::InvalidValue(format!("unknown opcode {op}")))?;
+
+    match opcode {
+        OpCode::IfElse => Ok(Node::IfElse {
+            true_case: body_from_json(node, "true_case", opcodes)?,
+            false_case: body_from_json(node, "false_case", opcodes)?,
+        }),
+        OpCode::Repeat => Ok(Node::Repeat {
+            times: u32::try_from(get_u64(node, "times")?)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?,
+            body: body_from_json(node, "body", opcodes)?,
+        }),
+        OpCode::While => Ok(Node::While {
+            body: body_from_json(node, "body", opcodes)?,
+        }),
+        OpCode::IfFeature => {
+            let feature = get_str(node, "feature")?;
+            let feature = FEATURE_LABEL_PARSER
+                .parse_label(feature)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?
+                .to_string();
+            Ok(Node::IfFeature {
+                feature,
+                true_case: body_from_json(node, "true_case", opcodes)?,
+                false_case: body_from_json(node, "false_case", opcodes)?,
+            })
+        }
+        _ => {
+            let mut bytes = opcode.to_bytes();
+            bytes.extend(from_hex(get_str(node, "imm")?)?);
+            let mut source = SliceReader::new(&bytes);
+            let instruction = Instruction::read_from(&mut source)?;
+            if source.has_more_bytes() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "too many immediate bytes for opcode {op}"
+                )));
+            }
+            Ok(Node::Instruction(instruction))
+        }
+    }
+}
+
+fn felt_from_json(value: &Value) -> Result<Felt, DeserializationError> {
+    match value.as_u64() {
+        Some(value) if value < Felt::MODULUS => Ok(Felt::new(value)),
+        _ => Err(DeserializationError::InvalidValue(format!(
+            "{value} is not a valid field element"
+        ))),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a map from the names of all operation codes to the operation codes.
+fn opcode_map() -> OpCodeMap {
+    (0..=u16::MAX)
+        .filter_map(|value| OpCode::try_from(value).ok())
+        .map(|opcode| (format!("{opcode:?}"), opcode))
+        .collect()
+}
+
+fn get<'a>(value: &'a Value, field: &str) -> Result<&'a Value, DeserializationError> {
+    value
+        .get(field)
+        .ok_or_else(|| DeserializationError::InvalidValue(format!("missing field {field}")))
+}
+
+fn get_str<'a>(value: &'a Value, field: &str) -> Result<&'a str, DeserializationError> {
+    get(value, field)?.as_str().ok_or_else(|| {
+        DeserializationError::InvalidValue(format!("field {field} must be a string"))
+    })
+}
+
+fn get_u64(value: &Value, field: &str) -> Result<u64, DeserializationError> {
+    get(value, field)?.as_u64().ok_or_else(|| {
+        DeserializationError::InvalidValue(format!("field {field} must be an unsigned integer"))
+    })
+}
+
+fn get_bool(value: &Value, field: &str) -> Result<bool, DeserializationError> {
+    get(value, field)?.as_bool().ok_or_else(|| {
+        DeserializationError::InvalidValue(format!("field {field} must be a boolean"))
+    })
+}
+
+/// Returns the elements of the array in the specified field, making sure there are at most `max`
+/// of them.
+fn get_array<'a>(
+    value: &'a Value,
+    field: &str,
+    max: usize,
+) -> Result<&'a [Value], DeserializationError> {
+    let array = get(value, field)?.as_array().ok_or_else(|| {
+        DeserializationError::InvalidValue(format!("field {field} must be a list"))
+    })?;
+    if array.len() > max {
+        return Err(DeserializationError::InvalidValue(format!(
+            "length {} of field {field} exceeds the maximum of {max}",
+            array.len()
+        )));
+    }
+    Ok(array)
+}
+
+/// Returns the doc comments in the specified field; empty doc comments are treated as absent, as
+/// in the binary format.
+fn get_docs(value: &Value, field: &str) -> Result<Option<String>, DeserializationError> {
+    let docs = match get(value, field)? {
+        Value::Null => return Ok(None),
+        _ => get_str(value, field)?,
+    };
+    if docs.len() > MAX_DOCS_LEN {
+        return Err(DeserializationError::InvalidValue(format!(
+            "length {} of field {field} exceeds the maximum of {MAX_DOCS_LEN}",
+            docs.len()
+        )));
+    }
+    Ok((!docs.is_empty()).then(|| docs.to_string()))
+}
+
+fn get_proc_name(value: &Value, field: &str) -> Result<ProcedureName, DeserializationError> {
+    ProcedureName::try_from(get_str(value, field)?)
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
+
+fn get_proc_id(value: &Value, field: &str) -> Result<ProcedureId, DeserializationError> {
+    let id = get_str(value, field)?;
+    let bytes = id.strip_prefix("0x").map(from_hex).transpose()?.unwrap_or_default();
+    let bytes = <[u8; ProcedureId::SIZE]>::try_from(bytes).map_err(|_| {
+        DeserializationError::InvalidValue(format!("{id} is not a valid procedure ID"))
+    })?;
+    Ok(ProcedureId::from(bytes))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, DeserializationError> {
+    let invalid = || DeserializationError::InvalidValue(format!("{hex} is not a valid hex string"));
+    if !hex.is_ascii() || hex.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid()))
+        .collect()
+}
@@ -16,6 +16,9 @@ pub use tracing::{event, info_span, instrument, Level};
 
 pub use super::tokens::SourceLocation;
 
+mod aliases;
+pub use aliases::InstructionAliases;
+
 mod nodes;
 use nodes::FormattableNode;
 pub use nodes::{AdviceInjectorNode, Instruction, Node};
@@ -23,16 +26,23 @@ pub use nodes::{AdviceInjectorNode, Instruction, Node};
 mod code_body;
 pub use code_body::CodeBody;
 
+mod data;
+pub use data::DataSegment;
+
 mod format;
 use format::*;
 
 mod imports;
-pub use imports::ModuleImports;
+pub use imports::{ImportedConstants, ModuleImports};
+
+mod interface;
+pub use interface::{ConstantInterface, ExportInterface, ModuleInterface};
 
 mod invocation_target;
 pub use invocation_target::InvocationTarget;
 
 mod parsers;
+use parsers::MacroDef;
 
 mod module;
 pub use module::ModuleAst;
@@ -43,12 +53,24 @@ pub use procedure::{ProcReExport, ProcedureAst};
 mod program;
 pub use program::ProgramAst;
 
+mod signature;
+pub use signature::ProcedureSignature;
+
 pub(crate) use parsers::{
-    parse_param_with_constant_lookup, NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER,
+    parse_named_param_with_constant_lookup, parse_param_with_constant_lookup, ALIAS_LABEL_PARSER,
+    CONSTANT_LABEL_PARSER, FEATURE_LABEL_PARSER, MACRO_LABEL_PARSER, NAMESPACE_LABEL_PARSER,
+    PROCEDURE_LABEL_PARSER,
 };
 
+pub mod visit;
+pub use visit::{MutVisitor, Visitor};
+
+#[cfg(feature = "json")]
+mod json;
+
 mod serde;
-pub use serde::AstSerdeOptions;
+pub(crate) use serde::{read_len, read_u16_varint};
+pub use serde::{AstSerdeOptions, AST_FORMAT_VERSION, AST_MIN_FORMAT_VERSION};
 
 #[cfg(test)]
 pub mod tests;
@@ -81,6 +103,10 @@ const MAX_STACK_WORD_OFFSET: u8 = 12;
 // ================================================================================================
 type LocalProcMap = BTreeMap<ProcedureName, (u16, ProcedureAst)>;
 type LocalConstMap = BTreeMap<String, u64>;
+type LocalConstDocsMap = BTreeMap<String, String>;
+type LocalAliasMap = BTreeMap<String, u16>;
+type LocalMacroMap = BTreeMap<String, MacroDef>;
+type LocalProcRootMap = BTreeMap<String, String>;
 type ReExportedProcMap = BTreeMap<ProcedureName, ProcReExport>;
 type InvokedProcsMap = BTreeMap<ProcedureId, (ProcedureName, LibraryPath)>;
 
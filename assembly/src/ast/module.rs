@@ -2,16 +2,19 @@ use super::check_unused_imports;
 use super::{
     format::*,
     imports::ModuleImports,
-    parsers::{parse_constants, ParserContext},
+    parsers::{parse_constants, ParserContext, CONSTANT_LABEL_PARSER},
+    read_len,
     serde::AstSerdeOptions,
-    sort_procs_into_vec, LocalProcMap, ProcReExport, ProcedureAst, ReExportedProcMap, MAX_DOCS_LEN,
-    MAX_LOCAL_PROCS, MAX_REEXPORTED_PROCS,
+    sort_procs_into_vec, ImportedConstants, InstructionAliases, LocalAliasMap, LocalConstDocsMap,
+    LocalConstMap, LocalMacroMap, LocalProcMap, ModuleInterface, ProcReExport, ProcedureAst,
+    ReExportedProcMap, MAX_DOCS_LEN, MAX_LOCAL_PROCS, MAX_REEXPORTED_PROCS,
     {
         ByteReader, ByteWriter, Deserializable, DeserializationError, ParsingError, SliceReader,
         Token, TokenStream,
     },
 };
 
+use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::{fmt, str::from_utf8};
@@ -23,12 +26,15 @@ use vm_core::utils::Serializable;
 /// An abstract syntax tree of a Miden module.
 ///
 /// A module AST consists of a list of procedure ASTs, a list of re-exported procedures, a list of
-/// imports, and module documentation. Local procedures could be internal or exported.
+/// imports, a list of constants, and module documentation. Local procedures could be internal or
+/// exported.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ModuleAst {
     pub(super) local_procs: Vec<ProcedureAst>,
     pub(super) reexported_procs: Vec<ProcReExport>,
     pub(super) import_info: ModuleImports,
+    pub(super) constants: LocalConstMap,
+    pub(super) const_docs: LocalConstDocsMap,
     pub(super) docs: Option<String>,
 }
 
@@ -61,6 +67,8 @@ impl ModuleAst {
             local_procs,
             reexported_procs,
             import_info: Default::default(),
+            constants: Default::default(),
+            const_docs: Default::default(),
             docs,
         })
     }
@@ -75,21 +83,76 @@ impl ModuleAst {
         self
     }
 
+    /// Adds the provided constants to the module.
+    ///
+    /// These constants can be referenced from constant expressions of modules which import this
+    /// module.
+    ///
+    /// # Panics
+    /// Panics if constants have already been added.
+    pub fn with_constants(mut self, constants: BTreeMap<String, u64>) -> Self {
+        assert!(self.constants.is_empty(), "module constants have already been added");
+        self.constants = constants;
+        self
+    }
+
+    /// Adds the provided doc comments of constants to the module.
+    ///
+    /// # Panics
+    /// Panics if doc comments of constants have already been added, or if any of the doc comments
+    /// is attached to a constant which is not defined in this module.
+    pub fn with_constant_docs(mut self, const_docs: BTreeMap<String, String>) -> Self {
+        assert!(self.const_docs.is_empty(), "constant doc comments have already been added");
+        assert!(
+            const_docs.keys().all(|name| self.constants.contains_key(name)),
+            "doc comments attached to an undefined constant"
+        );
+        self.const_docs = const_docs;
+        self
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses the provided source into a [ModuleAst].
     ///
     /// A module consists of internal and exported procedures but does not contain a body.
     pub fn parse(source: &str) -> Result<Self, ParsingError> {
+        Self::parse_with_imported_constants(source, &ImportedConstants::default())
+    }
+
+    /// Parses the provided source into a [ModuleAst], resolving references to constants defined
+    /// in imported modules against the provided `imported_constants`.
+    pub fn parse_with_imported_constants(
+        source: &str,
+        imported_constants: &ImportedConstants,
+    ) -> Result<Self, ParsingError> {
+        Self::parse_with_aliases(source, imported_constants, &InstructionAliases::default())
+    }
+
+    /// Parses the provided source into a [ModuleAst] in the same way as
+    /// [ModuleAst::parse_with_imported_constants()], but resolves mnemonics which do not belong to
+    /// any instruction against the provided `instruction_aliases`.
+    pub fn parse_with_aliases(
+        source: &str,
+        imported_constants: &ImportedConstants,
+        instruction_aliases: &InstructionAliases,
+    ) -> Result<Self, ParsingError> {
         let mut tokens = TokenStream::new(source)?;
         let mut import_info = ModuleImports::parse(&mut tokens)?;
-        let local_constants = parse_constants(&mut tokens)?;
+        let (local_constants, const_docs, local_proc_roots) =
+            parse_constants(&mut tokens, &import_info, imported_constants)?;
         let mut context = ParserContext {
             import_info: &mut import_info,
             local_procs: LocalProcMap::default(),
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
+            local_proc_roots,
             num_proc_locals: 0,
+            local_aliases: LocalAliasMap::default(),
+            num_feature_blocks: 0,
+            local_macros: LocalMacroMap::default(),
+            macro_scope: None,
+            instruction_aliases,
         };
         context.parse_procedures(&mut tokens, true)?;
 
@@ -112,8 +175,12 @@ impl ModuleAst {
         let docs = tokens.take_module_comments();
 
         check_unused_imports(context.import_info);
+        let constants = context.local_constants;
 
-        Ok(Self::new(local_procs, reexported_procs, docs)?.with_import_info(import_info))
+        Ok(Self::new(local_procs, reexported_procs, docs)?
+            .with_import_info(import_info)
+            .with_constants(constants)
+            .with_constant_docs(const_docs))
     }
 
     // PUBLIC ACCESSORS
@@ -134,11 +201,28 @@ impl ModuleAst {
         self.docs.as_ref()
     }
 
+    /// Returns a summary of the constants and procedures which this module makes available to
+    /// other modules (see [ModuleInterface]).
+    pub fn interface(&self) -> ModuleInterface {
+        ModuleInterface::new(self)
+    }
+
     /// Returns a reference to the import information for this module
     pub fn import_info(&self) -> &ModuleImports {
         &self.import_info
     }
 
+    /// Returns a map of constants defined in this module, keyed by constant name.
+    pub fn constants(&self) -> &BTreeMap<String, u64> {
+        &self.constants
+    }
+
+    /// Returns doc comments of the constant with the specified name, or None if the constant is
+    /// not defined in this module or is not documented.
+    pub fn constant_docs(&self, name: &str) -> Option<&str> {
+        self.const_docs.get(name).map(String::as_str)
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -162,11 +246,11 @@ impl ModuleAst {
         match &self.docs {
             Some(docs) => {
                 assert!(docs.len() <= u16::MAX as usize, "docs too long");
-                target.write_u16(docs.len() as u16);
+                target.write_usize(docs.len());
                 target.write_bytes(docs.as_bytes());
             }
             None => {
-                target.write_u16(0);
+                target.write_usize(0);
             }
         }
 
@@ -181,10 +265,23 @@ impl ModuleAst {
             self.reexported_procs.len() <= MAX_REEXPORTED_PROCS,
             "too many re-exported procs"
         );
-        target.write_u16((self.reexported_procs.len()) as u16);
+        target.write_usize(self.reexported_procs.len());
         target.write_many(&self.reexported_procs);
-        target.write_u16(self.local_procs.len() as u16);
+        target.write_usize(self.local_procs.len());
         target.write_many(&self.local_procs);
+
+        // serialize constants
+        assert!(self.constants.len() <= u16::MAX as usize, "too many constants");
+        target.write_usize(self.constants.len());
+        for (name, value) in self.constants.iter() {
+            target.write_u8(name.len() as u8);
+            target.write_bytes(name.as_bytes());
+            target.write_u64(*value);
+            let docs = self.const_docs.get(name).map(String::as_str).unwrap_or_default();
+            assert!(docs.len() <= MAX_DOCS_LEN, "docs too long");
+            target.write_usize(docs.len());
+            target.write_bytes(docs.as_bytes());
+        }
     }
 
     /// Returns a [ModuleAst] struct deserialized from the provided source.
@@ -195,7 +292,7 @@ impl ModuleAst {
         options: AstSerdeOptions,
     ) -> Result<Self, DeserializationError> {
         // deserialize docs
-        let docs_len = source.read_u16()? as usize;
+        let docs_len = read_len(source, MAX_DOCS_LEN)?;
         let docs = if docs_len != 0 {
             let str = source.read_vec(docs_len)?;
             let str =
@@ -213,16 +310,46 @@ impl ModuleAst {
         };
 
         // deserialize re-exports
-        let num_reexported_procs = source.read_u16()? as usize;
+        let num_reexported_procs = read_len(source, MAX_REEXPORTED_PROCS)?;
         let reexported_procs = source.read_many::<ProcReExport>(num_reexported_procs)?;
 
         // deserialize local procs
-        let num_local_procs = source.read_u16()? as usize;
+        let num_local_procs = read_len(source, MAX_LOCAL_PROCS)?;
         let local_procs = source.read_many::<ProcedureAst>(num_local_procs)?;
 
+        // deserialize constants
+        let num_constants = read_len(source, u16::MAX as usize)?;
+        let mut constants = LocalConstMap::new();
+        let mut const_docs = LocalConstDocsMap::new();
+        for _ in 0..num_constants {
+            let name_len = source.read_u8()? as usize;
+            let name = source.read_vec(name_len)?;
+            let name =
+                from_utf8(&name).map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+            CONSTANT_LABEL_PARSER
+                .parse_label(name)
+                .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+            constants.insert(name.to_string(), source.read_u64()?);
+
+            // doc comments of constants are encoded starting with format version 5
+            if options.format_version() < 5 {
+                continue;
+            }
+            let docs_len = read_len(source, MAX_DOCS_LEN)?;
+            if docs_len != 0 {
+                let docs = source.read_vec(docs_len)?;
+                let docs = from_utf8(&docs)
+                    .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+                const_docs.insert(name.to_string(), docs.to_string());
+            }
+        }
+
         match Self::new(local_procs, reexported_procs, docs) {
             Err(err) => Err(DeserializationError::UnknownError(err.message().clone())),
-            Ok(res) => Ok(res.with_import_info(import_info)),
+            Ok(res) => Ok(res
+                .with_import_info(import_info)
+                .with_constants(constants)
+                .with_constant_docs(const_docs)),
         }
     }
 
@@ -20,6 +20,8 @@ use vm_core::{AdviceInjector, Felt, SignatureKind, ZERO};
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum AdviceInjectorNode {
     PushU64Div,
+    PushU256Div,
+    PushU256ModInv,
     PushExt2intt,
     PushSmtGet,
     PushSmtSet,
@@ -29,6 +31,10 @@ pub enum AdviceInjectorNode {
     PushMapValN,
     PushMapValNImm { offset: u8 },
     PushMtNode,
+    PushSecp256k1BaseInv,
+    PushSecp256k1ScalarInv,
+    PushSecp256r1BaseInv,
+    PushSecp256r1ScalarInv,
     InsertMem,
     InsertHdword,
     InsertHdwordImm { domain: u8 },
@@ -41,6 +47,8 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
         use AdviceInjectorNode::*;
         match value {
             PushU64Div => Self::U64Div,
+            PushU256Div => Self::U256Div,
+            PushU256ModInv => Self::U256ModInv,
             PushExt2intt => Self::Ext2Intt,
             PushSmtGet => Self::SmtGet,
             PushSmtSet => Self::SmtSet,
@@ -62,6 +70,10 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
                 key_offset: (*offset) as usize,
             },
             PushMtNode => Self::MerkleNodeToStack,
+            PushSecp256k1BaseInv => Self::Secp256k1BaseInv,
+            PushSecp256k1ScalarInv => Self::Secp256k1ScalarInv,
+            PushSecp256r1BaseInv => Self::Secp256r1BaseInv,
+            PushSecp256r1ScalarInv => Self::Secp256r1ScalarInv,
             InsertMem => Self::MemToMap,
             InsertHdword => Self::HdwordToMap { domain: ZERO },
             InsertHdwordImm { domain } => Self::HdwordToMap {
@@ -78,6 +90,8 @@ impl fmt::Display for AdviceInjectorNode {
         use AdviceInjectorNode::*;
         match self {
             PushU64Div => write!(f, "push_u64div"),
+            PushU256Div => write!(f, "push_u256div"),
+            PushU256ModInv => write!(f, "push_u256modinv"),
             PushExt2intt => write!(f, "push_ext2intt"),
             PushSmtGet => write!(f, "push_smtget"),
             PushSmtSet => write!(f, "push_smtset"),
@@ -87,6 +101,10 @@ impl fmt::Display for AdviceInjectorNode {
             PushMapValN => write!(f, "push_mapvaln"),
             PushMapValNImm { offset } => write!(f, "push_mapvaln.{offset}"),
             PushMtNode => write!(f, "push_mtnode"),
+            PushSecp256k1BaseInv => write!(f, "push_secp256k1_base_inv"),
+            PushSecp256k1ScalarInv => write!(f, "push_secp256k1_scalar_inv"),
+            PushSecp256r1BaseInv => write!(f, "push_secp256r1_base_inv"),
+            PushSecp256r1ScalarInv => write!(f, "push_secp256r1_scalar_inv"),
             InsertMem => write!(f, "insert_mem"),
             InsertHdword => write!(f, "insert_hdword"),
             InsertHdwordImm { domain } => write!(f, "insert_hdword.{domain}"),
@@ -114,12 +132,20 @@ const INSERT_HDWORD: u8 = 11;
 const INSERT_HDWORD_IMM: u8 = 12;
 const INSERT_HPERM: u8 = 13;
 const PUSH_SIG: u8 = 14;
+const PUSH_SECP256K1_BASE_INV: u8 = 15;
+const PUSH_SECP256K1_SCALAR_INV: u8 = 16;
+const PUSH_SECP256R1_BASE_INV: u8 = 17;
+const PUSH_SECP256R1_SCALAR_INV: u8 = 18;
+const PUSH_U256DIV: u8 = 19;
+const PUSH_U256MODINV: u8 = 20;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         use AdviceInjectorNode::*;
         match self {
             PushU64Div => target.write_u8(PUSH_U64DIV),
+            PushU256Div => target.write_u8(PUSH_U256DIV),
+            PushU256ModInv => target.write_u8(PUSH_U256MODINV),
             PushExt2intt => target.write_u8(PUSH_EXT2INTT),
             PushSmtGet => target.write_u8(PUSH_SMTGET),
             PushSmtSet => target.write_u8(PUSH_SMTSET),
@@ -135,6 +161,10 @@ impl Serializable for AdviceInjectorNode {
                 target.write_u8(*offset);
             }
             PushMtNode => target.write_u8(PUSH_MTNODE),
+            PushSecp256k1BaseInv => target.write_u8(PUSH_SECP256K1_BASE_INV),
+            PushSecp256k1ScalarInv => target.write_u8(PUSH_SECP256K1_SCALAR_INV),
+            PushSecp256r1BaseInv => target.write_u8(PUSH_SECP256R1_BASE_INV),
+            PushSecp256r1ScalarInv => target.write_u8(PUSH_SECP256R1_SCALAR_INV),
             InsertMem => target.write_u8(INSERT_MEM),
             InsertHdword => target.write_u8(INSERT_HDWORD),
             InsertHdwordImm { domain } => {
@@ -154,6 +184,8 @@ impl Deserializable for AdviceInjectorNode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         match source.read_u8()? {
             PUSH_U64DIV => Ok(AdviceInjectorNode::PushU64Div),
+            PUSH_U256DIV => Ok(AdviceInjectorNode::PushU256Div),
+            PUSH_U256MODINV => Ok(AdviceInjectorNode::PushU256ModInv),
             PUSH_EXT2INTT => Ok(AdviceInjectorNode::PushExt2intt),
             PUSH_SMTGET => Ok(AdviceInjectorNode::PushSmtGet),
             PUSH_SMTSET => Ok(AdviceInjectorNode::PushSmtSet),
@@ -175,6 +207,10 @@ impl Deserializable for AdviceInjectorNode {
                 Ok(AdviceInjectorNode::PushMapValNImm { offset })
             }
             PUSH_MTNODE => Ok(AdviceInjectorNode::PushMtNode),
+            PUSH_SECP256K1_BASE_INV => Ok(AdviceInjectorNode::PushSecp256k1BaseInv),
+            PUSH_SECP256K1_SCALAR_INV => Ok(AdviceInjectorNode::PushSecp256k1ScalarInv),
+            PUSH_SECP256R1_BASE_INV => Ok(AdviceInjectorNode::PushSecp256r1BaseInv),
+            PUSH_SECP256R1_SCALAR_INV => Ok(AdviceInjectorNode::PushSecp256r1ScalarInv),
             INSERT_MEM => Ok(AdviceInjectorNode::InsertMem),
             INSERT_HDWORD => Ok(AdviceInjectorNode::InsertHdword),
             INSERT_HDWORD_IMM => {
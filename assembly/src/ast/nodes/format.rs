@@ -1,4 +1,4 @@
-use super::{AstFormatterContext, FormattableCodeBody, Instruction, Node};
+use super::{AstFormatterContext, FormattableCodeBody, Instruction, Node, Token};
 use core::fmt;
 
 // FORMATTING OF NODES
@@ -71,6 +71,31 @@ impl fmt::Display for FormattableNode<'_> {
                 self.context.indent(f)?;
                 writeln!(f, "end")
             }
+            Node::IfFeature {
+                feature,
+                true_case,
+                false_case,
+            } => {
+                self.context.indent(f)?;
+                writeln!(f, "@if {feature}")?;
+                write!(
+                    f,
+                    "{}",
+                    FormattableCodeBody::new(true_case, &self.context.inner_scope_context())
+                )?;
+                if !false_case.nodes().is_empty() {
+                    self.context.indent(f)?;
+                    writeln!(f, "@else")?;
+
+                    write!(
+                        f,
+                        "{}",
+                        FormattableCodeBody::new(false_case, &self.context.inner_scope_context())
+                    )?;
+                }
+                self.context.indent(f)?;
+                writeln!(f, "@end")
+            }
         }
     }
 }
@@ -112,6 +137,26 @@ impl fmt::Display for FormattableInstruction<'_> {
                 let (_, path) = self.context.imported_proc(proc_id);
                 write!(f, "call.{path}")?;
             }
+            Instruction::ExecLocalPinned(index, root) => {
+                let proc_name = self.context.local_proc(*index as usize);
+                write!(f, "exec.{proc_name}.{}", Token::PINNED_ROOT_PREFIX)?;
+                display_hex_bytes(f, &root.as_bytes())?;
+            }
+            Instruction::CallLocalPinned(index, root) => {
+                let proc_name = self.context.local_proc(*index as usize);
+                write!(f, "call.{proc_name}.{}", Token::PINNED_ROOT_PREFIX)?;
+                display_hex_bytes(f, &root.as_bytes())?;
+            }
+            Instruction::ExecImportedPinned(proc_id, root) => {
+                let (_, path) = self.context.imported_proc(proc_id);
+                write!(f, "exec.{path}.{}", Token::PINNED_ROOT_PREFIX)?;
+                display_hex_bytes(f, &root.as_bytes())?;
+            }
+            Instruction::CallImportedPinned(proc_id, root) => {
+                let (_, path) = self.context.imported_proc(proc_id);
+                write!(f, "call.{path}.{}", Token::PINNED_ROOT_PREFIX)?;
+                display_hex_bytes(f, &root.as_bytes())?;
+            }
             Instruction::SysCall(proc_id) => {
                 let (_, path) = self.context.imported_proc(proc_id);
                 write!(f, "syscall.{path}")?;
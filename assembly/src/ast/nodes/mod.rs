@@ -1,5 +1,7 @@
-use super::{AstFormatterContext, CodeBody, Felt, FormattableCodeBody, ProcedureId, RpoDigest};
-use alloc::vec::Vec;
+use super::{
+    AstFormatterContext, CodeBody, Felt, FormattableCodeBody, ProcedureId, RpoDigest, Token,
+};
+use alloc::{string::String, vec::Vec};
 use core::fmt;
 use vm_core::DebugOptions;
 
@@ -10,6 +12,8 @@ mod format;
 pub use format::*;
 
 mod serde;
+#[cfg(feature = "json")]
+pub(super) use serde::OpCode;
 
 // TYPE ALIASES
 // ================================================================================================
@@ -35,6 +39,13 @@ pub enum Node {
     While {
         body: CodeBody,
     },
+    /// A conditional assembly block; `true_case` is compiled if the assembler is instantiated
+    /// with the specified feature flag, and `false_case` is compiled otherwise.
+    IfFeature {
+        feature: String,
+        true_case: CodeBody,
+        false_case: CodeBody,
+    },
 }
 
 /// An instruction of Miden assembly program, excluding control flow instruction.
@@ -255,6 +266,7 @@ pub enum Instruction {
 
     MemStream,
     AdvPipe,
+    MemProtect,
 
     AdvPush(u8),
     AdvLoadW,
@@ -277,9 +289,13 @@ pub enum Instruction {
     // ----- exec / call --------------------------------------------------------------------------
     ExecLocal(u16),
     ExecImported(ProcedureId),
+    ExecLocalPinned(u16, RpoDigest),
+    ExecImportedPinned(ProcedureId, RpoDigest),
     CallLocal(u16),
     CallMastRoot(RpoDigest),
     CallImported(ProcedureId),
+    CallLocalPinned(u16, RpoDigest),
+    CallImportedPinned(ProcedureId, RpoDigest),
     SysCall(ProcedureId),
     DynExec,
     DynCall,
@@ -520,6 +536,7 @@ impl fmt::Display for Instruction {
             Self::LocStoreW(value) => write!(f, "loc_storew.{value}"),
 
             Self::MemStream => write!(f, "mem_stream"),
+            Self::MemProtect => write!(f, "mem_protect"),
             Self::AdvPipe => write!(f, "adv_pipe"),
 
             Self::AdvPush(value) => write!(f, "adv_push.{value}"),
@@ -549,6 +566,22 @@ impl fmt::Display for Instruction {
                 display_hex_bytes(f, &root.as_bytes())
             }
             Self::CallImported(proc_id) => write!(f, "call.{proc_id}"),
+            Self::ExecLocalPinned(index, root) => {
+                write!(f, "exec.{index}.{}", Token::PINNED_ROOT_PREFIX)?;
+                display_hex_bytes(f, &root.as_bytes())
+            }
+            Self::ExecImportedPinned(proc_id, root) => {
+                write!(f, "exec.{proc_id}.{}", Token::PINNED_ROOT_PREFIX)?;
+                display_hex_bytes(f, &root.as_bytes())
+            }
+            Self::CallLocalPinned(index, root) => {
+                write!(f, "call.{index}.{}", Token::PINNED_ROOT_PREFIX)?;
+                display_hex_bytes(f, &root.as_bytes())
+            }
+            Self::CallImportedPinned(proc_id, root) => {
+                write!(f, "call.{proc_id}.{}", Token::PINNED_ROOT_PREFIX)?;
+                display_hex_bytes(f, &root.as_bytes())
+            }
             Self::SysCall(proc_id) => write!(f, "syscall.{proc_id}"),
             Self::DynExec => write!(f, "dynexec"),
             Self::DynCall => write!(f, "dyncall"),
@@ -2,7 +2,9 @@ use super::{
     super::AdviceInjectorNode, debug, ByteReader, CodeBody, Deserializable, DeserializationError,
     Felt, Instruction, Node, OpCode, ProcedureId, RpoDigest, MAX_PUSH_INPUTS,
 };
+use crate::ast::{read_len, read_u16_varint, FEATURE_LABEL_PARSER, MAX_BODY_LEN};
 use alloc::string::ToString;
+use core::str::from_utf8;
 
 // NODE DESERIALIZATION
 // ================================================================================================
@@ -14,11 +16,11 @@ impl Deserializable for Node {
         if first_byte == OpCode::IfElse as u8 {
             source.read_u8()?;
 
-            let if_block_len = source.read_u16()? as usize;
+            let if_block_len = read_len(source, MAX_BODY_LEN)?;
             let nodes = source.read_many::<Node>(if_block_len)?;
             let true_case = CodeBody::new(nodes);
 
-            let else_block_len = source.read_u16()? as usize;
+            let else_block_len = read_len(source, MAX_BODY_LEN)?;
             let nodes = source.read_many::<Node>(else_block_len)?;
             let false_case = CodeBody::new(nodes);
 
@@ -31,7 +33,7 @@ impl Deserializable for Node {
 
             let times = source.read_u32()?;
 
-            let nodes_len = source.read_u16()? as usize;
+            let nodes_len = read_len(source, MAX_BODY_LEN)?;
             let nodes = source.read_many::<Node>(nodes_len)?;
             let body = CodeBody::new(nodes);
 
@@ -39,11 +41,36 @@ impl Deserializable for Node {
         } else if first_byte == OpCode::While as u8 {
             source.read_u8()?;
 
-            let nodes_len = source.read_u16()? as usize;
+            let nodes_len = read_len(source, MAX_BODY_LEN)?;
             let nodes = source.read_many::<Node>(nodes_len)?;
             let body = CodeBody::new(nodes);
 
             Ok(Node::While { body })
+        } else if first_byte == OpCode::IfFeature as u8 {
+            source.read_u8()?;
+
+            let feature_len = source.read_u8()? as usize;
+            let feature = source.read_vec(feature_len)?;
+            let feature = from_utf8(&feature)
+                .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+            let feature = FEATURE_LABEL_PARSER
+                .parse_label(feature)
+                .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?
+                .to_string();
+
+            let if_block_len = read_len(source, MAX_BODY_LEN)?;
+            let nodes = source.read_many::<Node>(if_block_len)?;
+            let true_case = CodeBody::new(nodes);
+
+            let else_block_len = read_len(source, MAX_BODY_LEN)?;
+            let nodes = source.read_many::<Node>(else_block_len)?;
+            let false_case = CodeBody::new(nodes);
+
+            Ok(Node::IfFeature {
+                feature,
+                true_case,
+                false_case,
+            })
         } else {
             let inner = Deserializable::read_from(source)?;
             Ok(Node::Instruction(inner))
@@ -289,7 +316,7 @@ impl Deserializable for Instruction {
                     .map(Instruction::PushFeltList)
             }
 
-            OpCode::Locaddr => Ok(Instruction::Locaddr(source.read_u16()?)),
+            OpCode::Locaddr => Ok(Instruction::Locaddr(read_u16_varint(source)?)),
             OpCode::Sdepth => Ok(Instruction::Sdepth),
             OpCode::Caller => Ok(Instruction::Caller),
             OpCode::Clk => Ok(Instruction::Clk),
@@ -298,16 +325,17 @@ impl Deserializable for Instruction {
             OpCode::MemLoadImm => Ok(Instruction::MemLoadImm(source.read_u32()?)),
             OpCode::MemLoadW => Ok(Instruction::MemLoadW),
             OpCode::MemLoadWImm => Ok(Instruction::MemLoadWImm(source.read_u32()?)),
-            OpCode::LocLoad => Ok(Instruction::LocLoad(source.read_u16()?)),
-            OpCode::LocLoadW => Ok(Instruction::LocLoadW(source.read_u16()?)),
+            OpCode::LocLoad => Ok(Instruction::LocLoad(read_u16_varint(source)?)),
+            OpCode::LocLoadW => Ok(Instruction::LocLoadW(read_u16_varint(source)?)),
             OpCode::MemStore => Ok(Instruction::MemStore),
             OpCode::MemStoreImm => Ok(Instruction::MemStoreImm(source.read_u32()?)),
-            OpCode::LocStore => Ok(Instruction::LocStore(source.read_u16()?)),
+            OpCode::LocStore => Ok(Instruction::LocStore(read_u16_varint(source)?)),
             OpCode::MemStoreW => Ok(Instruction::MemStoreW),
             OpCode::MemStoreWImm => Ok(Instruction::MemStoreWImm(source.read_u32()?)),
-            OpCode::LocStoreW => Ok(Instruction::LocStoreW(source.read_u16()?)),
+            OpCode::LocStoreW => Ok(Instruction::LocStoreW(read_u16_varint(source)?)),
 
             OpCode::MemStream => Ok(Instruction::MemStream),
+            OpCode::MemProtect => Ok(Instruction::MemProtect),
             OpCode::AdvPipe => Ok(Instruction::AdvPipe),
 
             OpCode::AdvPush => Ok(Instruction::AdvPush(source.read_u8()?)),
@@ -329,15 +357,31 @@ impl Deserializable for Instruction {
             OpCode::RCombBase => Ok(Instruction::RCombBase),
 
             // ----- exec / call ------------------------------------------------------------------
-            OpCode::ExecLocal => Ok(Instruction::ExecLocal(source.read_u16()?)),
+            OpCode::ExecLocal => Ok(Instruction::ExecLocal(read_u16_varint(source)?)),
             OpCode::ExecImported => Ok(Instruction::ExecImported(ProcedureId::read_from(source)?)),
-            OpCode::CallLocal => Ok(Instruction::CallLocal(source.read_u16()?)),
+            OpCode::CallLocal => Ok(Instruction::CallLocal(read_u16_varint(source)?)),
             OpCode::CallMastRoot => Ok(Instruction::CallMastRoot(RpoDigest::read_from(source)?)),
             OpCode::CallImported => Ok(Instruction::CallImported(ProcedureId::read_from(source)?)),
+            OpCode::ExecLocalPinned => {
+                let index = read_u16_varint(source)?;
+                Ok(Instruction::ExecLocalPinned(index, RpoDigest::read_from(source)?))
+            }
+            OpCode::ExecImportedPinned => {
+                let proc_id = ProcedureId::read_from(source)?;
+                Ok(Instruction::ExecImportedPinned(proc_id, RpoDigest::read_from(source)?))
+            }
+            OpCode::CallLocalPinned => {
+                let index = read_u16_varint(source)?;
+                Ok(Instruction::CallLocalPinned(index, RpoDigest::read_from(source)?))
+            }
+            OpCode::CallImportedPinned => {
+                let proc_id = ProcedureId::read_from(source)?;
+                Ok(Instruction::CallImportedPinned(proc_id, RpoDigest::read_from(source)?))
+            }
             OpCode::SysCall => Ok(Instruction::SysCall(ProcedureId::read_from(source)?)),
             OpCode::DynExec => Ok(Instruction::DynExec),
             OpCode::DynCall => Ok(Instruction::DynCall),
-            OpCode::ProcRefLocal => Ok(Instruction::ProcRefLocal(source.read_u16()?)),
+            OpCode::ProcRefLocal => Ok(Instruction::ProcRefLocal(read_u16_varint(source)?)),
             OpCode::ProcRefImported => {
                 Ok(Instruction::ProcRefImported(ProcedureId::read_from(source)?))
             }
@@ -358,6 +402,7 @@ impl Deserializable for Instruction {
             OpCode::IfElse => unreachable!(),
             OpCode::Repeat => unreachable!(),
             OpCode::While => unreachable!(),
+            OpCode::IfFeature => unreachable!(),
         }
     }
 }
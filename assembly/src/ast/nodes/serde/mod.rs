@@ -9,10 +9,25 @@ mod deserialization;
 mod serialization;
 pub mod signatures;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Prefix byte of extended opcodes.
+///
+/// Opcodes in the range `0..=255` (except for this prefix) are encoded as a single byte. All
+/// other opcodes are encoded as this prefix followed by the opcode as a `u16` value, so that new
+/// instructions can be added without changing the encoding of existing ones.
+const EXTENDED_OPCODE_PREFIX: u8 = 0xfb;
+
 // OPERATION CODES ENUM
 // ================================================================================================
 
-#[repr(u8)]
+/// Operation codes of serialized instructions and control flow nodes.
+///
+/// The encoding of an opcode must never change once released, since this would break previously
+/// serialized libraries. New opcodes should be assigned values starting from 256, which are
+/// encoded using the [EXTENDED_OPCODE_PREFIX].
+#[repr(u16)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, TryFromPrimitive)]
 pub enum OpCode {
     Assert = 0,
@@ -268,7 +283,17 @@ pub enum OpCode {
     Emit = 227,
     Trace = 228,
 
+    // ----- memory protection --------------------------------------------------------------------
+    MemProtect = 229,
+
+    // ----- exec / call with pinned MAST roots ---------------------------------------------------
+    ExecLocalPinned = 230,
+    ExecImportedPinned = 231,
+    CallLocalPinned = 232,
+    CallImportedPinned = 233,
+
     // ----- control flow -------------------------------------------------------------------------
+    IfFeature = 252,
     IfElse = 253,
     Repeat = 254,
     While = 255,
@@ -276,13 +301,33 @@ pub enum OpCode {
 
 impl Serializable for OpCode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write_u8(*self as u8);
+        let value = *self as u16;
+        match u8::try_from(value) {
+            Ok(value) if value != EXTENDED_OPCODE_PREFIX => target.write_u8(value),
+            _ => {
+                target.write_u8(EXTENDED_OPCODE_PREFIX);
+                target.write_u16(value);
+            }
+        }
     }
 }
 
 impl Deserializable for OpCode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let value = source.read_u8()?;
+        let value = match source.read_u8()? {
+            EXTENDED_OPCODE_PREFIX => {
+                // only opcodes which cannot be encoded as a single byte may use the extended
+                // encoding; this keeps the encoding of every opcode unique
+                let value = source.read_u16()?;
+                if value <= u8::MAX as u16 && value != EXTENDED_OPCODE_PREFIX as u16 {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "opcode {value} must not use the extended encoding"
+                    )));
+                }
+                value
+            }
+            value => value as u16,
+        };
         Self::try_from(value).map_err(|_| {
             DeserializationError::InvalidValue("could not read a valid opcode".to_string())
         })
@@ -20,11 +20,11 @@ impl Serializable for Node {
                 OpCode::IfElse.write_into(target);
 
                 assert!(true_case.nodes().len() <= MAX_BODY_LEN, "too many body nodes");
-                target.write_u16(true_case.nodes().len() as u16);
+                target.write_usize(true_case.nodes().len());
                 target.write_many(true_case.nodes());
 
                 assert!(false_case.nodes().len() <= MAX_BODY_LEN, "too many body nodes");
-                target.write_u16(false_case.nodes().len() as u16);
+                target.write_usize(false_case.nodes().len());
                 target.write_many(false_case.nodes());
             }
             Self::Repeat { times, body } => {
@@ -32,16 +32,36 @@ impl Serializable for Node {
                 target.write_u32(*times);
 
                 assert!(body.nodes().len() <= MAX_BODY_LEN, "too many body nodes");
-                target.write_u16(body.nodes().len() as u16);
+                target.write_usize(body.nodes().len());
                 target.write_many(body.nodes());
             }
             Self::While { body } => {
                 OpCode::While.write_into(target);
 
                 assert!(body.nodes().len() <= MAX_BODY_LEN, "too many body nodes");
-                target.write_u16(body.nodes().len() as u16);
+                target.write_usize(body.nodes().len());
                 target.write_many(body.nodes());
             }
+            Self::IfFeature {
+                feature,
+                true_case,
+                false_case,
+            } => {
+                OpCode::IfFeature.write_into(target);
+
+                // feature flag length is enforced by the label parser
+                assert!(feature.len() <= u8::MAX as usize, "feature flag too long");
+                target.write_u8(feature.len() as u8);
+                target.write_bytes(feature.as_bytes());
+
+                assert!(true_case.nodes().len() <= MAX_BODY_LEN, "too many body nodes");
+                target.write_usize(true_case.nodes().len());
+                target.write_many(true_case.nodes());
+
+                assert!(false_case.nodes().len() <= MAX_BODY_LEN, "too many body nodes");
+                target.write_usize(false_case.nodes().len());
+                target.write_many(false_case.nodes());
+            }
         }
     }
 }
@@ -363,7 +383,7 @@ impl Serializable for Instruction {
             }
             Self::Locaddr(v) => {
                 OpCode::Locaddr.write_into(target);
-                target.write_u16(*v);
+                target.write_usize(*v as usize);
             }
             Self::Sdepth => OpCode::Sdepth.write_into(target),
             Self::Caller => OpCode::Caller.write_into(target),
@@ -381,11 +401,11 @@ impl Serializable for Instruction {
             }
             Self::LocLoad(v) => {
                 OpCode::LocLoad.write_into(target);
-                target.write_u16(*v);
+                target.write_usize(*v as usize);
             }
             Self::LocLoadW(v) => {
                 OpCode::LocLoadW.write_into(target);
-                target.write_u16(*v);
+                target.write_usize(*v as usize);
             }
             Self::MemStore => OpCode::MemStore.write_into(target),
             Self::MemStoreImm(v) => {
@@ -394,7 +414,7 @@ impl Serializable for Instruction {
             }
             Self::LocStore(v) => {
                 OpCode::LocStore.write_into(target);
-                target.write_u16(*v);
+                target.write_usize(*v as usize);
             }
             Self::MemStoreW => OpCode::MemStoreW.write_into(target),
             Self::MemStoreWImm(v) => {
@@ -403,10 +423,11 @@ impl Serializable for Instruction {
             }
             Self::LocStoreW(v) => {
                 OpCode::LocStoreW.write_into(target);
-                target.write_u16(*v);
+                target.write_usize(*v as usize);
             }
 
             Self::MemStream => OpCode::MemStream.write_into(target),
+            Self::MemProtect => OpCode::MemProtect.write_into(target),
             Self::AdvPipe => OpCode::AdvPipe.write_into(target),
 
             Self::AdvPush(v) => {
@@ -436,7 +457,7 @@ impl Serializable for Instruction {
             // ----- exec / call ------------------------------------------------------------------
             Self::ExecLocal(v) => {
                 OpCode::ExecLocal.write_into(target);
-                target.write_u16(*v);
+                target.write_usize(*v as usize);
             }
             Self::ExecImported(imported) => {
                 OpCode::ExecImported.write_into(target);
@@ -444,7 +465,7 @@ impl Serializable for Instruction {
             }
             Self::CallLocal(v) => {
                 OpCode::CallLocal.write_into(target);
-                target.write_u16(*v);
+                target.write_usize(*v as usize);
             }
             Self::CallMastRoot(root) => {
                 OpCode::CallMastRoot.write_into(target);
@@ -454,6 +475,26 @@ impl Serializable for Instruction {
                 OpCode::CallImported.write_into(target);
                 imported.write_into(target)
             }
+            Self::ExecLocalPinned(v, root) => {
+                OpCode::ExecLocalPinned.write_into(target);
+                target.write_usize(*v as usize);
+                root.write_into(target);
+            }
+            Self::ExecImportedPinned(imported, root) => {
+                OpCode::ExecImportedPinned.write_into(target);
+                imported.write_into(target);
+                root.write_into(target);
+            }
+            Self::CallLocalPinned(v, root) => {
+                OpCode::CallLocalPinned.write_into(target);
+                target.write_usize(*v as usize);
+                root.write_into(target);
+            }
+            Self::CallImportedPinned(imported, root) => {
+                OpCode::CallImportedPinned.write_into(target);
+                imported.write_into(target);
+                root.write_into(target);
+            }
             Self::SysCall(imported) => {
                 OpCode::SysCall.write_into(target);
                 imported.write_into(target)
@@ -462,7 +503,7 @@ impl Serializable for Instruction {
             Self::DynCall => OpCode::DynCall.write_into(target),
             Self::ProcRefLocal(v) => {
                 OpCode::ProcRefLocal.write_into(target);
-                target.write_u16(*v)
+                target.write_usize(*v as usize)
             }
             Self::ProcRefImported(imported) => {
                 OpCode::ProcRefImported.write_into(target);
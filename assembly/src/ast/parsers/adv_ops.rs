@@ -26,6 +26,14 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             2 => AdvInject(PushU64Div),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_u256div" => match op.num_parts() {
+            2 => AdvInject(PushU256Div),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_u256modinv" => match op.num_parts() {
+            2 => AdvInject(PushU256ModInv),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_ext2intt" => match op.num_parts() {
             2 => AdvInject(PushExt2intt),
             _ => return Err(ParsingError::extra_param(op)),
@@ -70,6 +78,22 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             2 => AdvInject(PushMtNode),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_secp256k1_base_inv" => match op.num_parts() {
+            2 => AdvInject(PushSecp256k1BaseInv),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_secp256k1_scalar_inv" => match op.num_parts() {
+            2 => AdvInject(PushSecp256k1ScalarInv),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_secp256r1_base_inv" => match op.num_parts() {
+            2 => AdvInject(PushSecp256r1BaseInv),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_secp256r1_scalar_inv" => match op.num_parts() {
+            2 => AdvInject(PushSecp256r1ScalarInv),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "insert_mem" => match op.num_parts() {
             2 => AdvInject(InsertMem),
             _ => return Err(ParsingError::extra_param(op)),
@@ -1,4 +1,7 @@
-use super::{Felt, LocalConstMap, ParsingError, Token};
+use super::{
+    parse_int_literal, Felt, ImportedConstants, IntLiteralError, LocalConstMap, ModuleImports,
+    ParsingError, StarkField, Token,
+};
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::Display;
@@ -6,7 +9,46 @@ use core::fmt::Display;
 // CONSTANT VALUE EXPRESSIONS
 // ================================================================================================
 
-const OPERATORS: [char; 6] = ['+', '-', '*', '/', '(', ')'];
+const OPERATORS: [char; 11] = ['+', '-', '*', '/', '(', ')', '<', '>', '&', '|', '^'];
+
+/// Constants which can be referenced from a constant expression.
+pub struct ConstantScope<'a> {
+    /// Constants defined in the module being parsed.
+    local: &'a LocalConstMap,
+    /// Modules imported by the module being parsed.
+    imports: &'a ModuleImports,
+    /// Constants defined in modules which can be imported by the module being parsed.
+    imported: &'a ImportedConstants<'a>,
+}
+
+impl<'a> ConstantScope<'a> {
+    /// Returns a new [ConstantScope] instantiated with the provided constants and imports.
+    pub fn new(
+        local: &'a LocalConstMap,
+        imports: &'a ModuleImports,
+        imported: &'a ImportedConstants<'a>,
+    ) -> Self {
+        Self {
+            local,
+            imports,
+            imported,
+        }
+    }
+
+    /// Returns the value of the constant with the specified name.
+    ///
+    /// Names of the form `<module>::<NAME>` refer to constants defined in the module imported
+    /// under the name `<module>`; all other names refer to local constants.
+    fn get(&self, name: &str) -> Option<u64> {
+        match name.split_once("::") {
+            Some((module_name, const_name)) => {
+                let path = self.imports.get_module_path(module_name)?;
+                self.imported.get(path, const_name)
+            }
+            None => self.local.get(name).copied(),
+        }
+    }
+}
 
 /// An operation used in constant expressions
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -16,6 +58,11 @@ enum Operation {
     Mul,
     FeltDiv,
     IntDiv,
+    Shl,
+    Shr,
+    And,
+    Or,
+    Xor,
     LPar,
     RPar,
     Value(Felt),
@@ -31,6 +78,11 @@ impl Display for Operation {
             Mul => write!(f, "*"),
             FeltDiv => write!(f, "/"),
             IntDiv => write!(f, "//"),
+            Shl => write!(f, "<<"),
+            Shr => write!(f, ">>"),
+            And => write!(f, "&"),
+            Or => write!(f, "|"),
+            Xor => write!(f, "^"),
             LPar => write!(f, "("),
             RPar => write!(f, ")"),
             Value(v) => write!(f, "{}", v),
@@ -39,10 +91,15 @@ impl Display for Operation {
 }
 
 /// Calculates expression in the constant value.
+///
+/// Arithmetic operators (`+`, `-`, `*`, `/`) are evaluated over field elements, except for
+/// integer division (`//`). Shift (`<<`, `>>`) and bitwise (`&`, `|`, `^`) operators are
+/// evaluated over the integer representations of their operands, and fail if the result is not
+/// a valid field element. Operators have the same precedence as in Rust.
 pub fn calculate_const_value(
     op: &Token,
     expression: &str,
-    constants: &LocalConstMap,
+    constants: &ConstantScope,
 ) -> Result<Felt, ParsingError> {
     let postfix_expression = build_postfix_expression(op, expression, constants)?;
     evaluate_postfix_expression(op, expression, postfix_expression)
@@ -52,7 +109,7 @@ pub fn calculate_const_value(
 fn build_postfix_expression(
     op: &Token,
     expression: &str,
-    constants: &LocalConstMap,
+    constants: &ConstantScope,
 ) -> Result<Vec<Operation>, ParsingError> {
     let mut stack = Vec::new();
     let mut postfix_expression = Vec::new();
@@ -122,7 +179,7 @@ fn evaluate_postfix_expression(
             _ => {
                 let right = stack.pop().expect("stack is empty");
                 let left = stack.pop().expect("stack is empty");
-                stack.push(compute_statement(op, left, right, operation)?);
+                stack.push(compute_statement(op, expression, left, right, operation)?);
             }
         }
     }
@@ -148,12 +205,12 @@ struct OperationIterator<'a> {
     op: &'a Token<'a>,
     original_expression: &'a str,
     expression: &'a str,
-    constants: &'a LocalConstMap,
+    constants: &'a ConstantScope<'a>,
 }
 
 impl<'a> OperationIterator<'a> {
     /// Returns a new instance of the [OperationIterator].
-    pub fn new(op: &'a Token<'a>, expression: &'a str, constants: &'a LocalConstMap) -> Self {
+    pub fn new(op: &'a Token<'a>, expression: &'a str, constants: &'a ConstantScope<'a>) -> Self {
         OperationIterator {
             op,
             original_expression: expression,
@@ -187,6 +244,32 @@ impl<'a> OperationIterator<'a> {
                 self.expression = &self.expression[1..];
                 Ok(Some(Operation::Mul))
             }
+            Some('&') => {
+                self.expression = &self.expression[1..];
+                Ok(Some(Operation::And))
+            }
+            Some('|') => {
+                self.expression = &self.expression[1..];
+                Ok(Some(Operation::Or))
+            }
+            Some('^') => {
+                self.expression = &self.expression[1..];
+                Ok(Some(Operation::Xor))
+            }
+            Some(shift @ ('<' | '>')) => {
+                if char_iter.next() != Some(shift) {
+                    return Err(ParsingError::invalid_const_value(
+                        self.op,
+                        self.original_expression,
+                        &format!("unknown operator {}", shift),
+                    ));
+                }
+                self.expression = &self.expression[2..];
+                match shift {
+                    '<' => Ok(Some(Operation::Shl)),
+                    _ => Ok(Some(Operation::Shr)),
+                }
+            }
             Some('/') => match char_iter.next() {
                 Some('/') => {
                     self.expression = &self.expression[2..];
@@ -219,49 +302,92 @@ impl<'a> OperationIterator<'a> {
 }
 
 /// Returns the number in `value` or the constant value if the value is the name of the constant.
+///
+/// Numbers can be specified in decimal, hexadecimal (with the `0x` prefix), or binary (with the
+/// `0b` prefix) notation, and their digits can be separated by underscores.
 fn parse_operand(
     op: &Token,
     expression: &str,
-    constants: &LocalConstMap,
+    constants: &ConstantScope,
     value: String,
 ) -> Result<Operation, ParsingError> {
-    let parsed_number = value.parse::<u64>();
-    // if the parsed value is a number push it on the stack
-    if let Ok(parsed_number) = parsed_number {
-        Ok(Operation::Value(Felt::new(parsed_number)))
-    }
-    // if it is a name of the constant get its value from the `constants` map
-    else {
-        let parsed_number = constants.get(&value).ok_or_else(|| {
-            ParsingError::invalid_const_value(
-                op,
-                expression,
-                &format!("constant with name {} was not initialized", value),
-            )
-        })?;
-        Ok(Operation::Value(Felt::new(*parsed_number)))
+    match parse_int_literal(&value) {
+        // if the parsed value is a number push it on the stack
+        Ok(parsed_number) if parsed_number < Felt::MODULUS => {
+            Ok(Operation::Value(Felt::new(parsed_number)))
+        }
+        Ok(_) | Err(IntLiteralError::Overflow) => Err(ParsingError::invalid_const_value(
+            op,
+            expression,
+            &format!("value {} must be smaller than {}", value, Felt::MODULUS),
+        )),
+        // if it is a name of the constant get its value from the available constants
+        Err(IntLiteralError::Malformed) => {
+            let parsed_number = constants.get(&value).ok_or_else(|| {
+                ParsingError::invalid_const_value(
+                    op,
+                    expression,
+                    &format!("constant with name {} was not initialized", value),
+                )
+            })?;
+            Ok(Operation::Value(Felt::new(parsed_number)))
+        }
     }
 }
 
 /// Returns `true` if th left operator has higher priority than the right, `false` otherwise.
 fn left_has_greater_precedence(left: &Operation, right: &Operation) -> bool {
+    precedence(left) > precedence(right)
+}
+
+/// Returns the priority of the operator; operators with higher priority are evaluated first.
+fn precedence(operator: &Operation) -> u8 {
     use Operation::*;
 
-    let left_level = match left {
-        Mul | FeltDiv | IntDiv => 2,
-        Add | Sub => 1,
-        _ => 0,
-    };
-    let right_level = match right {
-        Mul | FeltDiv | IntDiv => 2,
-        Add | Sub => 1,
+    match operator {
+        Mul | FeltDiv | IntDiv => 6,
+        Add | Sub => 5,
+        Shl | Shr => 4,
+        And => 3,
+        Xor => 2,
+        Or => 1,
         _ => 0,
-    };
-    left_level > right_level
+    }
 }
 
 /// Computes the expression based on provided `operator` character.
 fn compute_statement(
+    op: &Token,
+    expression: &str,
+    left: Felt,
+    right: Felt,
+    operator: &Operation,
+) -> Result<Felt, ParsingError> {
+    use Operation::*;
+    let (left_int, right_int) = (left.as_int(), right.as_int());
+    let result = match operator {
+        Shl if right_int < u64::BITS as u64 => (left_int as u128) << right_int,
+        Shr if right_int < u64::BITS as u64 => (left_int >> right_int) as u128,
+        Shl | Shr => {
+            let reason = format!("shift amount {} must be smaller than {}", right_int, u64::BITS);
+            return Err(ParsingError::invalid_const_value(op, expression, &reason));
+        }
+        And => (left_int & right_int) as u128,
+        Or => (left_int | right_int) as u128,
+        Xor => (left_int ^ right_int) as u128,
+        _ => return compute_field_statement(op, left, right, operator),
+    };
+
+    if result >= Felt::MODULUS as u128 {
+        let reason =
+            format!("result of {} {} {} is not a valid field element", left, operator, right);
+        return Err(ParsingError::invalid_const_value(op, expression, &reason));
+    }
+    Ok(Felt::new(result as u64))
+}
+
+/// Computes the expression based on provided arithmetic `operator`.
+fn compute_field_statement(
     op: &Token,
     left: Felt,
     right: Felt,
@@ -292,10 +418,11 @@ fn compute_statement(
 // ================================================================================================
 #[cfg(test)]
 mod tests {
-    use super::{Felt, LocalConstMap, Token};
+    use super::{Felt, ImportedConstants, LocalConstMap, ModuleImports, Token};
     use crate::{
         ast::parsers::constants::{
-            build_postfix_expression, evaluate_postfix_expression, Operation,
+            build_postfix_expression, calculate_const_value, evaluate_postfix_expression,
+            ConstantScope, Operation,
         },
         ONE,
     };
@@ -305,6 +432,9 @@ mod tests {
     #[test]
     fn test_build_postfix_expression() {
         let constants = LocalConstMap::from([("A".to_string(), 3), ("B".to_string(), 10)]);
+        let imports = ModuleImports::default();
+        let imported = ImportedConstants::default();
+        let constants = ConstantScope::new(&constants, &imports, &imported);
 
         let expression = "51-A+22";
         let result = build_postfix_expression(&Token::new_dummy(), expression, &constants).unwrap();
@@ -373,4 +503,36 @@ mod tests {
         let expected = Felt::new(48);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_bitwise_expressions() {
+        let constants = LocalConstMap::from([("A".to_string(), 16), ("B".to_string(), 0xff)]);
+        let imports = ModuleImports::default();
+        let imported = ImportedConstants::default();
+        let constants = ConstantScope::new(&constants, &imports, &imported);
+        let eval = |expression: &str| {
+            calculate_const_value(&Token::new_dummy(), expression, &constants).map(|v| v.as_int())
+        };
+
+        assert_eq!(eval("1<<4").unwrap(), 16);
+        assert_eq!(eval("B>>4").unwrap(), 0xf);
+        assert_eq!(eval("(A-1)&0xF|0x100").unwrap(), 0x10f);
+        assert_eq!(eval("B^0x0F").unwrap(), 0xf0);
+        assert_eq!(eval("1<<62").unwrap(), 1 << 62);
+
+        // shifts bind weaker than arithmetic, and bitwise operators bind weaker than shifts
+        assert_eq!(eval("1<<2+1").unwrap(), 8);
+        assert_eq!(eval("A>>2*2").unwrap(), 1);
+        assert_eq!(eval("1|2^3&2").unwrap(), 1);
+        assert_eq!(eval("(1|2^3)&2").unwrap(), 0);
+
+        // shift amounts must be smaller than 64, and results must be valid field elements
+        assert!(eval("1<<64").is_err());
+        assert!(eval("1<<63<<1").is_err());
+        assert!(eval("0xFFFFFFFFFFFFFFFF").is_err());
+
+        // single `<` and `>` are not valid operators
+        assert!(eval("1<2").is_err());
+        assert!(eval("1>2").is_err());
+    }
 }
@@ -1,11 +1,15 @@
 use super::{
-    super::ProcReExport, adv_ops, debug, events, field_ops, io_ops, stack_ops, sys_ops, u32_ops,
-    CodeBody, Instruction, InvocationTarget, LibraryPath, LocalConstMap, LocalProcMap,
-    ModuleImports, Node, ParsingError, ProcedureAst, ProcedureId, ProcedureName, ReExportedProcMap,
-    Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN,
+    super::{ProcReExport, ProcedureSignature},
+    adv_ops, debug, event, events, field_ops, io_ops, parse_procroot_label, stack_ops, sys_ops,
+    u32_ops, CodeBody, DataSegment, Instruction, InstructionAliases, InvocationTarget, Level,
+    LibraryPath, LocalAliasMap, LocalConstMap, LocalMacroMap, LocalProcMap, LocalProcRootMap,
+    MacroDef, MacroScope, ModuleImports, Node, ParsingError, ProcedureAst, ProcedureId,
+    ProcedureName, ReExportedProcMap, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN,
 };
+use crate::{Label, Suggestion, ONE, ZERO};
 use alloc::string::ToString;
 use alloc::vec::Vec;
+use core::mem;
 
 // PARSER CONTEXT
 // ================================================================================================
@@ -16,7 +20,18 @@ pub struct ParserContext<'a> {
     pub local_procs: LocalProcMap,
     pub reexported_procs: ReExportedProcMap,
     pub local_constants: LocalConstMap,
+    pub local_proc_roots: LocalProcRootMap,
     pub num_proc_locals: u16,
+    /// Local aliases declared in the procedure currently being parsed.
+    pub local_aliases: LocalAliasMap,
+    /// Number of conditional assembly blocks enclosing the body currently being parsed.
+    pub num_feature_blocks: usize,
+    /// Macros declared in the module so far.
+    pub local_macros: LocalMacroMap,
+    /// Scope of the macro currently being expanded, if any.
+    pub macro_scope: Option<MacroScope>,
+    /// Alternative mnemonics under which instructions can be referred to.
+    pub instruction_aliases: &'a InstructionAliases,
 }
 
 impl ParserContext<'_> {
@@ -160,41 +175,214 @@ impl ParserContext<'_> {
         Ok(Node::Repeat { times, body })
     }
 
+    /// Parses a `repeat.dyn.max=<max_iterations>` statement from the provided token stream into
+    /// AST nodes.
+    ///
+    /// The statement is a `while.true` loop which may execute at most `max_iterations` times. The
+    /// number of remaining iterations is kept in a procedure local allocated by the parser; the
+    /// local is initialized to `max_iterations` before the loop, and every iteration asserts that
+    /// it is not zero and decrements it before executing the loop body.
+    fn parse_dyn_repeat(&mut self, tokens: &mut TokenStream) -> Result<Vec<Node>, ParsingError> {
+        // record start of the repeat block and consume the 'repeat' token
+        let repeat_start = tokens.pos();
+        let repeat_token = tokens.read().expect("no repeat token");
+        let max_iterations = repeat_token.parse_dyn_repeat(&self.local_constants)?;
+        let location = *repeat_token.location();
+
+        // allocate a local for the iteration counter
+        let counter = self.num_proc_locals;
+        self.num_proc_locals = counter.checked_add(1).ok_or_else(|| {
+            ParsingError::too_many_proc_locals(repeat_token, u16::MAX as u64 + 1, u16::MAX as u64)
+        })?;
+        tokens.advance();
+
+        // read the loop body
+        let body = self.parse_body(tokens, false)?;
+
+        // consume the `end` token
+        match tokens.read() {
+            None => {
+                let token = tokens.read_at(repeat_start).expect("no repeat token");
+                Err(ParsingError::unmatched_repeat(token))
+            }
+            Some(token) => match token.parts()[0] {
+                Token::END => token.validate_end(),
+                Token::ELSE => Err(ParsingError::dangling_else(token)),
+                _ => {
+                    let token = tokens.read_at(repeat_start).expect("no repeat token");
+                    Err(ParsingError::unmatched_repeat(token))
+                }
+            },
+        }?;
+        tokens.advance();
+
+        // => [counter, ...]: assert that the counter is not zero and decrement it
+        let check = [
+            Instruction::LocLoad(counter),
+            Instruction::Dup0,
+            Instruction::EqImm(ZERO),
+            Instruction::Assertz,
+            Instruction::SubImm(ONE),
+            Instruction::LocStore(counter),
+        ];
+        let (body_nodes, body_locations) = body.into_parts();
+        if body_nodes.len() + check.len() > MAX_BODY_LEN {
+            let token = tokens.read_at(repeat_start).expect("no repeat token");
+            let body_size = body_nodes.len() + check.len();
+            return Err(ParsingError::body_too_long(token, body_size, MAX_BODY_LEN));
+        }
+        let body_locations = if body_locations.is_empty() {
+            body_locations
+        } else {
+            check.iter().map(|_| location).chain(body_locations).collect()
+        };
+        let body = check
+            .into_iter()
+            .map(Node::Instruction)
+            .chain(body_nodes)
+            .collect::<CodeBody>()
+            .with_source_locations(body_locations);
+
+        Ok(vec![
+            Node::Instruction(Instruction::PushU32(max_iterations)),
+            Node::Instruction(Instruction::LocStore(counter)),
+            Node::While { body },
+        ])
+    }
+
+    /// Parses a conditional assembly block from the provided token stream into an AST node.
+    fn parse_feature_if(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+        // record start of the conditional block and consume the '@if' token
+        let if_start = tokens.pos();
+        let if_token = tokens.read().expect("no @if token");
+        if_token.validate_feature_if()?;
+        tokens.advance();
+
+        // read and consume the feature flag
+        let feature = match tokens.read() {
+            Some(token) => token.parse_feature_flag()?,
+            None => {
+                let token = tokens.read_at(if_start).expect("no @if token");
+                return Err(ParsingError::missing_param(token, "@if <flag>"));
+            }
+        };
+        tokens.advance();
+
+        // read the `@if` clause
+        self.num_feature_blocks += 1;
+        let mut true_case = self.parse_body(tokens, false)?;
+
+        // build the `@else` clause; if the clause is not specified, set it to an empty body
+        let false_case = match tokens.read() {
+            Some(token) => match token.parts()[0] {
+                Token::FEATURE_ELSE => {
+                    // record start of the `@else` block and consume the `@else` token
+                    token.validate_feature_else()?;
+                    let else_start = tokens.pos();
+                    tokens.advance();
+
+                    // parse the `false` branch
+                    let false_case = self.parse_body(tokens, false)?;
+
+                    // consume the `@end` token
+                    match tokens.read() {
+                        Some(token) if token.parts()[0] == Token::FEATURE_END => {
+                            // as with `if.true` blocks, both branches point to the same `@end`
+                            true_case.add_final_location(*token.location());
+                            token.validate_feature_end()
+                        }
+                        Some(token) if token.parts()[0] == Token::FEATURE_ELSE => {
+                            Err(ParsingError::dangling_feature_else(token))
+                        }
+                        _ => {
+                            let token = tokens.read_at(else_start).expect("no @else token");
+                            Err(ParsingError::unmatched_feature_else(token))
+                        }
+                    }?;
+                    tokens.advance();
+
+                    false_case
+                }
+                Token::FEATURE_END => {
+                    // consume the `@end` token and return an empty body
+                    token.validate_feature_end()?;
+                    tokens.advance();
+                    CodeBody::default()
+                }
+                _ => {
+                    let token = tokens.read_at(if_start).expect("no @if token");
+                    return Err(ParsingError::unmatched_feature_if(token));
+                }
+            },
+            None => {
+                let token = tokens.read_at(if_start).expect("no @if token");
+                return Err(ParsingError::unmatched_feature_if(token));
+            }
+        };
+        self.num_feature_blocks -= 1;
+
+        Ok(Node::IfFeature {
+            feature,
+            true_case,
+            false_case,
+        })
+    }
+
     // CALL PARSERS
     // --------------------------------------------------------------------------------------------
 
     /// Parse an `exec` token into an instruction node.
+    ///
+    /// If the target is followed by a `digest!<root>` parameter, the MAST root of the invoked
+    /// procedure is checked against the pinned root when the instruction is compiled.
     fn parse_exec(&mut self, token: &Token) -> Result<Node, ParsingError> {
-        match token.parse_invocation(token.parts()[0])? {
-            InvocationTarget::MastRoot(_) => Err(ParsingError::exec_with_mast_root(token)),
-            InvocationTarget::ProcedureName(proc_name) => {
+        match token.parse_pinned_invocation(token.parts()[0])? {
+            (InvocationTarget::MastRoot(_), _) => Err(ParsingError::exec_with_mast_root(token)),
+            (InvocationTarget::ProcedureName(proc_name), pinned_root) => {
                 let index = self.get_local_proc_index(proc_name, token)?;
-                let inner = Instruction::ExecLocal(index);
+                let inner = match pinned_root {
+                    Some(root) => Instruction::ExecLocalPinned(index, root),
+                    None => Instruction::ExecLocal(index),
+                };
                 Ok(Node::Instruction(inner))
             }
-            InvocationTarget::ProcedurePath { name, module } => {
+            (InvocationTarget::ProcedurePath { name, module }, pinned_root) => {
                 let proc_id = self.import_info.add_invoked_proc(&name, module, token)?;
-                let inner = Instruction::ExecImported(proc_id);
+                let inner = match pinned_root {
+                    Some(root) => Instruction::ExecImportedPinned(proc_id, root),
+                    None => Instruction::ExecImported(proc_id),
+                };
                 Ok(Node::Instruction(inner))
             }
         }
     }
 
     /// Parse a `call` token into an instruction node.
+    ///
+    /// If the target is followed by a `digest!<root>` parameter, the MAST root of the invoked
+    /// procedure is checked against the pinned root when the instruction is compiled.
     fn parse_call(&mut self, token: &Token) -> Result<Node, ParsingError> {
-        match token.parse_invocation(token.parts()[0])? {
-            InvocationTarget::MastRoot(root_hash) => {
+        match token.parse_pinned_invocation(token.parts()[0])? {
+            (InvocationTarget::MastRoot(root_hash), None) => {
                 let inner = Instruction::CallMastRoot(root_hash);
                 Ok(Node::Instruction(inner))
             }
-            InvocationTarget::ProcedureName(proc_name) => {
+            // a procedure invoked via its MAST root is already pinned to it
+            (InvocationTarget::MastRoot(_), Some(_)) => Err(ParsingError::extra_param(token)),
+            (InvocationTarget::ProcedureName(proc_name), pinned_root) => {
                 let index = self.get_local_proc_index(proc_name, token)?;
-                let inner = Instruction::CallLocal(index);
+                let inner = match pinned_root {
+                    Some(root) => Instruction::CallLocalPinned(index, root),
+                    None => Instruction::CallLocal(index),
+                };
                 Ok(Node::Instruction(inner))
             }
-            InvocationTarget::ProcedurePath { name, module } => {
+            (InvocationTarget::ProcedurePath { name, module }, pinned_root) => {
                 let proc_id = self.import_info.add_invoked_proc(&name, module, token)?;
-                let inner = Instruction::CallImported(proc_id);
+                let inner = match pinned_root {
+                    Some(root) => Instruction::CallImportedPinned(proc_id, root),
+                    None => Instruction::CallImported(proc_id),
+                };
                 Ok(Node::Instruction(inner))
             }
         }
@@ -220,7 +408,67 @@ impl ParserContext<'_> {
 
     /// Parse a `procref` token into an instruction node.
     pub fn parse_procref(&mut self, token: &Token) -> Result<Node, ParsingError> {
-        match token.parse_invocation(token.parts()[0])? {
+        let target = token.parse_invocation(token.parts()[0])?;
+        self.build_procref(target, token)
+    }
+
+    /// Parse a `push` token into an instruction node.
+    ///
+    /// In addition to the parameters accepted by [io_ops::parse_push()], the instruction accepts a
+    /// `procroot(<procedure>)` expression or a name of a constant declared via such an expression.
+    /// In this case, the MAST root of the referenced procedure is pushed onto the stack, same as
+    /// with `procref.<procedure>`.
+    ///
+    /// The instruction also accepts a `&<alias>` parameter, where `<alias>` is a local alias
+    /// declared in the current procedure. In this case, the address of the aliased local is pushed
+    /// onto the stack, same as with `locaddr.<index>`.
+    fn parse_push(&mut self, token: &Token) -> Result<Node, ParsingError> {
+        if token.num_parts() == 2 {
+            let param = token.parts()[1];
+            if let Some(alias) = param.strip_prefix('&') {
+                return match self.local_aliases.get(alias) {
+                    Some(&index) => Ok(Node::Instruction(Instruction::Locaddr(index))),
+                    None => Err(ParsingError::undefined_alias(token, alias)),
+                };
+            }
+            let label = match parse_procroot_label(param) {
+                Some(label) => Some(label.to_string()),
+                None => self.local_proc_roots.get(param).cloned(),
+            };
+            if let Some(label) = label {
+                let target = InvocationTarget::parse(&label, token)?;
+                return self.build_procref(target, token);
+            }
+        }
+        io_ops::parse_push(token, &self.local_constants)
+    }
+
+    /// Parses an instruction which accesses a procedure local (e.g., `loc_load.<index>`).
+    ///
+    /// If the parameter of the instruction is a local alias, the instruction is built from the
+    /// index of the aliased local; otherwise, the token is parsed by the provided `parser`.
+    fn parse_local_op(
+        &self,
+        op: &Token,
+        instruction: fn(u16) -> Instruction,
+        parser: fn(&Token, &LocalConstMap) -> Result<Node, ParsingError>,
+    ) -> Result<Node, ParsingError> {
+        if op.num_parts() == 2 {
+            if let Some(&index) = self.local_aliases.get(op.parts()[1]) {
+                return Ok(Node::Instruction(instruction(index)));
+            }
+        }
+        parser(op, &self.local_constants)
+    }
+
+    /// Builds an instruction node which pushes the MAST root of the specified procedure onto the
+    /// stack.
+    fn build_procref(
+        &mut self,
+        target: InvocationTarget,
+        token: &Token,
+    ) -> Result<Node, ParsingError> {
+        match target {
             InvocationTarget::ProcedureName(proc_name) => {
                 let index = self.get_local_proc_index(proc_name, token)?;
                 let inner = Instruction::ProcRefLocal(index);
@@ -258,6 +506,19 @@ impl ParserContext<'_> {
                     // no validation needed, parse the procedure below
                     false
                 }
+                Token::MACRO => {
+                    // macros do not produce any procedures; they are expanded at invocation sites
+                    self.parse_macro(tokens)?;
+                    continue;
+                }
+                Token::DATA => {
+                    // data sections produce procedures which copy the data into memory; these are
+                    // exported from library modules
+                    let proc = self.parse_data_section(tokens, allow_export)?;
+                    let proc_idx = self.local_procs.len() as u16;
+                    self.local_procs.insert(proc.name.clone(), (proc_idx, proc));
+                    continue;
+                }
                 _ => break,
             };
 
@@ -292,14 +553,31 @@ impl ParserContext<'_> {
         let header = tokens.read().expect("missing procedure header");
         let (name, num_locals, is_export) = header.parse_proc()?;
         if self.contains_proc_name(&name) {
-            return Err(ParsingError::duplicate_proc_name(header, name.as_str()));
+            let err = ParsingError::duplicate_proc_name(header, name.as_str());
+            return Err(self.with_previous_definition(err, &name));
+        }
+        if is_instruction_mnemonic(name.as_str()) {
+            event!(
+                Level::WARN,
+                "procedure name '{}' at {} shadows an instruction with the same name",
+                name,
+                header.location()
+            );
         }
         let start = *header.location();
         tokens.advance();
 
-        // attach doc comments (if any) to exported procedures
+        // parse the signature declared in the doc comments (if any), and attach doc comments to
+        // exported procedures
+        let docs = tokens.take_doc_comment_at(proc_start);
+        let signature = match docs.as_deref().map(ProcedureSignature::parse).transpose() {
+            Ok(signature) => signature.flatten(),
+            Err(err) => {
+                let token = tokens.read_at(proc_start).expect("no proc token");
+                return Err(ParsingError::invalid_proc_signature(token, &err));
+            }
+        };
         let docs = if is_export {
-            let docs = tokens.take_doc_comment_at(proc_start);
             // make sure procedure docs don't exceed the allowed limit
             if let Some(ref docs) = docs {
                 if docs.len() > MAX_DOCS_LEN {
@@ -317,7 +595,9 @@ impl ParserContext<'_> {
         // parse procedure body
         let body = self.parse_body(tokens, false)?;
 
-        self.num_proc_locals = 0;
+        // the body may have allocated additional locals (e.g., for `repeat.dyn` loop counters)
+        let num_locals = mem::take(&mut self.num_proc_locals);
+        self.local_aliases.clear();
 
         // consume the 'end' token
         match tokens.read() {
@@ -337,8 +617,12 @@ impl ParserContext<'_> {
 
         // build and return the procedure
         let (nodes, locations) = body.into_parts();
-        Ok(ProcedureAst::new(name, num_locals, nodes, is_export, docs)
-            .with_source_locations(locations, start))
+        let procedure = ProcedureAst::new(name, num_locals, nodes, is_export, docs)
+            .with_source_locations(locations, start);
+        Ok(match signature {
+            Some(signature) => procedure.with_signature(signature),
+            None => procedure,
+        })
     }
 
     /// Parses procedure re-export from the token stream and adds it to the set of procedures
@@ -359,14 +643,21 @@ impl ParserContext<'_> {
         let header = tokens.read().expect("missing procedure header");
         let (proc_name, ref_name, module) = header.parse_reexported_proc()?;
         if self.contains_proc_name(&proc_name) {
-            return Err(ParsingError::duplicate_proc_name(header, proc_name.as_str()));
+            let err = ParsingError::duplicate_proc_name(header, proc_name.as_str());
+            return Err(self.with_previous_definition(err, &proc_name));
         }
 
-        // check if the module from which the procedure is re-exported was imported
-        let module_path = self
-            .import_info
-            .get_module_path(module)
-            .ok_or(ParsingError::procedure_module_not_imported(header, module))?;
+        // resolve the path of the module from which the procedure is re-exported: the module is
+        // either referenced by a fully-qualified path, or must have been imported
+        let module_path = if module.contains(LibraryPath::PATH_DELIM) {
+            LibraryPath::new(module)
+                .map_err(|_| ParsingError::invalid_module_path(header, module))?
+        } else {
+            self.import_info
+                .get_module_path(module)
+                .ok_or(ParsingError::procedure_module_not_imported(header, module))?
+                .clone()
+        };
 
         // consume the `export` token
         tokens.advance();
@@ -382,16 +673,235 @@ impl ParserContext<'_> {
             }
         }
 
-        let proc_id = ProcedureId::from_name(&ref_name, module_path);
+        let proc_id = ProcedureId::from_name(&ref_name, &module_path);
         Ok(ProcReExport::new(proc_id, proc_name, docs))
     }
 
+    /// Parses an `alias.<name>=loc.<index>` declaration and adds the alias to the set of local
+    /// aliases of the procedure currently being parsed.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The alias declaration is malformed.
+    /// - An alias or a constant with the same name has already been declared.
+    /// - The aliased local is out of bounds for the current procedure.
+    fn parse_alias(&mut self, token: &Token) -> Result<(), ParsingError> {
+        let (name, index) = token.parse_alias(&self.local_constants)?;
+        if self.local_aliases.contains_key(name)
+            || self.local_constants.contains_key(name)
+            || self.local_proc_roots.contains_key(name)
+        {
+            return Err(ParsingError::duplicate_alias_name(token, name));
+        }
+        if index >= self.num_proc_locals {
+            return Err(ParsingError::alias_index_out_of_bounds(
+                token,
+                index,
+                self.num_proc_locals,
+            ));
+        }
+        self.local_aliases.insert(name.to_string(), index);
+        Ok(())
+    }
+
+    // MACRO PARSERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Parses a macro declaration from the token stream and adds it to the set of macros declared
+    /// in this context.
+    ///
+    /// The body of the macro is not parsed at this point; instead, its tokens are recorded and
+    /// parsed every time the macro is invoked.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The macro declaration is malformed.
+    /// - A macro with the same name has already been declared.
+    /// - A macro parameter has the same name as a constant.
+    /// - The macro body is not terminated with a matching `end` token.
+    fn parse_macro(&mut self, tokens: &mut TokenStream) -> Result<(), ParsingError> {
+        let macro_start = tokens.pos();
+
+        // parse the macro declaration and consume the `macro` token
+        let header = tokens.read().expect("missing macro header");
+        let (name, params) = header.parse_macro()?;
+        if self.local_macros.contains_key(name) {
+            return Err(ParsingError::duplicate_macro_name(header, name));
+        }
+        for &param in params.iter() {
+            if self.local_constants.contains_key(param) || self.local_proc_roots.contains_key(param)
+            {
+                return Err(ParsingError::duplicate_macro_param(header, param));
+            }
+        }
+        let name = name.to_string();
+        let params = params.into_iter().map(|param| param.to_string()).collect();
+        let mut macro_def = MacroDef::new(params, self.local_macros.len(), self.local_procs.len());
+        tokens.advance();
+
+        // record body tokens up to and including the `end` token matching the macro declaration
+        let mut depth = 0_usize;
+        loop {
+            let token = match tokens.read() {
+                Some(token) => token,
+                None => {
+                    let token = tokens.read_at(macro_start).expect("no macro token");
+                    return Err(ParsingError::unmatched_macro(token, &name));
+                }
+            };
+            match token.parts()[0] {
+                Token::IF | Token::WHILE | Token::REPEAT => depth += 1,
+                Token::END if depth == 0 => {
+                    token.validate_end()?;
+                    macro_def.push_token(token);
+                    tokens.advance();
+                    break;
+                }
+                Token::END => depth -= 1,
+                Token::MACRO => return Err(ParsingError::macro_inside_body(token)),
+                Token::EXPORT | Token::PROC | Token::BEGIN => {
+                    let token = tokens.read_at(macro_start).expect("no macro token");
+                    return Err(ParsingError::unmatched_macro(token, &name));
+                }
+                _ => (),
+            }
+            macro_def.push_token(token);
+            tokens.advance();
+        }
+
+        self.local_macros.insert(name, macro_def);
+        Ok(())
+    }
+
+    /// Parses a `data.<name>` section from the token stream and returns the procedure which copies
+    /// the declared values into memory.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The declaration is malformed or any of the values is not a valid field element.
+    /// - The number of values is not a positive multiple of 4.
+    /// - A procedure with the same name has already been declared in this context.
+    fn parse_data_section(
+        &mut self,
+        tokens: &mut TokenStream,
+        is_export: bool,
+    ) -> Result<ProcedureAst, ParsingError> {
+        let data_start = tokens.pos();
+
+        // parse the declaration and consume the `data` token; doc comments of data sections are
+        // replaced with the description of the generated procedure
+        let header = tokens.read().expect("missing data header");
+        let name = header.parse_data()?;
+        if self.contains_proc_name(&name) {
+            let err = ParsingError::duplicate_proc_name(header, name.as_str());
+            return Err(self.with_previous_definition(err, &name));
+        }
+        let start = *header.location();
+        tokens.take_doc_comment_at(data_start);
+        tokens.advance();
+
+        // parse values up to the `end` token matching the declaration
+        let mut values = Vec::new();
+        loop {
+            let token = match tokens.read() {
+                Some(token) => token,
+                None => {
+                    let token = tokens.read_at(data_start).expect("no data token");
+                    return Err(ParsingError::unmatched_data(token, name.as_str()));
+                }
+            };
+            match token.parts()[0] {
+                Token::END => {
+                    token.validate_end()?;
+                    tokens.advance();
+                    break;
+                }
+                Token::EXPORT | Token::PROC | Token::MACRO | Token::DATA | Token::BEGIN => {
+                    let token = tokens.read_at(data_start).expect("no data token");
+                    return Err(ParsingError::unmatched_data(token, name.as_str()));
+                }
+                _ => (),
+            }
+            values.extend(io_ops::parse_data_values(token, &self.local_constants)?);
+            tokens.advance();
+        }
+
+        let data = DataSegment::new(values).map_err(|err| {
+            let token = tokens.read_at(data_start).expect("no data token");
+            ParsingError::invalid_data_segment(token, err)
+        })?;
+        let mut proc = ProcedureAst::from_data_segment(name, data, is_export);
+        proc.start = start;
+        Ok(proc)
+    }
+
+    /// Expands a `!<name>[.<arg>]*` macro invocation into a code body.
+    ///
+    /// The body of the macro is parsed with the macro parameters bound to the values of the
+    /// provided arguments. Local aliases and parameters of the enclosing macro (if any) are not
+    /// visible from the macro body, and aliases declared in the macro body are not visible after
+    /// the invocation.
+    ///
+    /// # Errors
+    /// Returns an error if the macro has not been declared before the invocation, if the number of
+    /// arguments differs from the number of macro parameters, or if the macro body is malformed.
+    fn expand_macro(&mut self, token: &Token) -> Result<CodeBody, ParsingError> {
+        let (name, args) = token.parse_macro_invocation(&self.local_constants)?;
+        let macro_def = match self.local_macros.get(name) {
+            Some(macro_def)
+                if self
+                    .macro_scope
+                    .as_ref()
+                    .map_or(true, |scope| scope.is_macro_visible(macro_def.index())) =>
+            {
+                macro_def
+            }
+            _ => return Err(ParsingError::undefined_macro(token, name)),
+        };
+        if args.len() != macro_def.params().len() {
+            return Err(ParsingError::wrong_macro_arity(
+                token,
+                name,
+                macro_def.params().len(),
+                args.len(),
+            ));
+        }
+
+        // bind macro parameters to the values of the arguments
+        let mut constants = self.local_constants.clone();
+        if let Some(scope) = self.macro_scope.as_ref() {
+            for param in scope.params.iter() {
+                constants.remove(param);
+            }
+        }
+        constants.extend(macro_def.params().iter().cloned().zip(args));
+
+        // build a token stream from the macro body
+        let scope = macro_def.scope();
+        let (body_tokens, locations): (Vec<_>, Vec<_>) = macro_def.body().iter().cloned().unzip();
+        let mut body_stream =
+            TokenStream::from_tokens(body_tokens.iter().map(|t| t.as_str()).collect(), locations);
+
+        // parse the macro body in its own scope and restore the current scope afterwards
+        let constants = mem::replace(&mut self.local_constants, constants);
+        let aliases = mem::take(&mut self.local_aliases);
+        let num_feature_blocks = mem::take(&mut self.num_feature_blocks);
+        let outer_scope = self.macro_scope.replace(scope);
+        let body = self.parse_body(&mut body_stream, false);
+        self.local_constants = constants;
+        self.local_aliases = aliases;
+        self.num_feature_blocks = num_feature_blocks;
+        self.macro_scope = outer_scope;
+
+        body
+    }
+
     // BODY PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses AST tokens from the token stream and add them to the nodes vector.
     ///
-    /// Nodes are added to the list until `if`, `else`, `while`, `repeat`, `end`, `export`, `proc`,
-    /// or `begin` tokens are encountered, or an error occurs.
+    /// Nodes are added to the list until `else`, `end`, `@else`, `@end`, `export`, `proc`, or
+    /// `begin` tokens are encountered, or an error occurs.
     pub fn parse_body(
         &mut self,
         tokens: &mut TokenStream,
@@ -420,11 +930,38 @@ impl ParserContext<'_> {
                     let body = self.parse_while(tokens)?;
                     nodes.push(body);
                 }
+                Token::REPEAT if token.is_dyn_repeat() => {
+                    let location = *token.location();
+                    for node in self.parse_dyn_repeat(tokens)? {
+                        locations.push(location);
+                        nodes.push(node);
+                    }
+                }
                 Token::REPEAT => {
                     locations.push(*token.location());
                     let body = self.parse_repeat(tokens)?;
                     nodes.push(body);
                 }
+                Token::FEATURE_IF => {
+                    locations.push(*token.location());
+                    let body = self.parse_feature_if(tokens)?;
+                    nodes.push(body);
+                }
+                Token::FEATURE_ELSE => {
+                    token.validate_feature_else()?;
+                    if self.num_feature_blocks > 0 {
+                        break;
+                    }
+                    return Err(ParsingError::dangling_feature_else(token));
+                }
+                Token::FEATURE_END => {
+                    token.validate_feature_end()?;
+                    if self.num_feature_blocks > 0 {
+                        locations.push(*token.location());
+                        break;
+                    }
+                    return Err(ParsingError::dangling_feature_end(token));
+                }
                 Token::END => {
                     locations.push(*token.location());
                     token.validate_end()?;
@@ -433,11 +970,27 @@ impl ParserContext<'_> {
                 Token::USE => {
                     return Err(ParsingError::import_inside_body(token));
                 }
+                Token::MACRO => {
+                    return Err(ParsingError::macro_inside_body(token));
+                }
+                Token::ALIAS => {
+                    // aliases are resolved during parsing and do not produce any nodes
+                    self.parse_alias(token)?;
+                    tokens.advance();
+                }
                 Token::EXPORT | Token::PROC | Token::BEGIN => {
                     // break out of the loop; whether this results in an error will be determined
                     // by the function which invoked parse_body()
                     break;
                 }
+                op if op.starts_with(Token::MACRO_PREFIX) => {
+                    // splice the expanded macro body without its closing `end` location
+                    let (body_nodes, mut body_locations) = self.expand_macro(token)?.into_parts();
+                    body_locations.pop();
+                    nodes.extend(body_nodes);
+                    locations.extend(body_locations);
+                    tokens.advance();
+                }
                 _ => {
                     locations.push(*token.location());
                     nodes.push(self.parse_op_token(token)?);
@@ -447,7 +1000,7 @@ impl ParserContext<'_> {
         }
 
         if nodes.len() > MAX_BODY_LEN {
-            let token = tokens.read_at(start_pos - 1).expect("no body start token");
+            let token = tokens.read_at(start_pos.saturating_sub(1)).expect("no body start token");
             return Err(ParsingError::body_too_long(token, nodes.len(), MAX_BODY_LEN));
         }
 
@@ -578,26 +1131,27 @@ impl ParserContext<'_> {
             "cdropw" => simple_instruction(op, CDropW),
 
             // ----- input / output operations ----------------------------------------------------
-            "push" => io_ops::parse_push(op, &self.local_constants),
+            "push" => self.parse_push(op),
 
             "sdepth" => simple_instruction(op, Sdepth),
-            "locaddr" => io_ops::parse_locaddr(op, &self.local_constants),
+            "locaddr" => self.parse_local_op(op, Locaddr, io_ops::parse_locaddr),
             "caller" => simple_instruction(op, Caller), // TODO: error if not in SYSCALL (issue #551)
             "clk" => simple_instruction(op, Clk),
 
             "mem_load" => io_ops::parse_mem_load(op, &self.local_constants),
-            "loc_load" => io_ops::parse_loc_load(op, &self.local_constants),
+            "loc_load" => self.parse_local_op(op, LocLoad, io_ops::parse_loc_load),
 
             "mem_loadw" => io_ops::parse_mem_loadw(op, &self.local_constants),
-            "loc_loadw" => io_ops::parse_loc_loadw(op, &self.local_constants),
+            "loc_loadw" => self.parse_local_op(op, LocLoadW, io_ops::parse_loc_loadw),
 
             "mem_store" => io_ops::parse_mem_store(op, &self.local_constants),
-            "loc_store" => io_ops::parse_loc_store(op, &self.local_constants),
+            "loc_store" => self.parse_local_op(op, LocStore, io_ops::parse_loc_store),
 
             "mem_storew" => io_ops::parse_mem_storew(op, &self.local_constants),
-            "loc_storew" => io_ops::parse_loc_storew(op, &self.local_constants),
+            "loc_storew" => self.parse_local_op(op, LocStoreW, io_ops::parse_loc_storew),
 
             "mem_stream" => simple_instruction(op, MemStream),
+            "mem_protect" => simple_instruction(op, MemProtect),
             "adv_pipe" => simple_instruction(op, AdvPipe),
 
             "adv_push" => io_ops::parse_adv_push(op),
@@ -639,7 +1193,43 @@ impl ParserContext<'_> {
             "trace" => events::parse_trace(op, &self.local_constants),
 
             // ----- catch all --------------------------------------------------------------------
-            _ => Err(ParsingError::invalid_op(op)),
+            name => {
+                // parse instructions referred to via aliases as if they used current mnemonics
+                let aliases = self.instruction_aliases;
+                if let Some(mnemonic) = aliases.get(name) {
+                    if is_instruction_mnemonic(mnemonic) {
+                        match aliases.renamed_in(name) {
+                            Some(version) => event!(
+                                Level::WARN,
+                                "instruction '{}' at {} was renamed in version {}, use '{}' instead",
+                                name,
+                                op.location(),
+                                version,
+                                mnemonic
+                            ),
+                            None => event!(
+                                Level::WARN,
+                                "instruction '{}' at {} is deprecated, use '{}' instead",
+                                name,
+                                op.location(),
+                                mnemonic
+                            ),
+                        }
+                        return self.parse_op_token(&op.with_mnemonic(mnemonic));
+                    }
+                }
+
+                let err = ParsingError::invalid_op(op);
+                match closest_instruction_mnemonic(op.parts()[0]) {
+                    Some(mnemonic) => Err(err.with_suggestion(Suggestion::new(
+                        *op.location(),
+                        op.parts()[0].len(),
+                        mnemonic,
+                        format!("there is an instruction with a similar name: `{mnemonic}`"),
+                    ))),
+                    None => Err(err),
+                }
+            }
         }
     }
 
@@ -654,8 +1244,11 @@ impl ParserContext<'_> {
     ) -> Result<u16, ParsingError> {
         self.local_procs
             .get(&proc_name)
-            .ok_or_else(|| ParsingError::undefined_local_proc(token, proc_name.as_ref()))
             .map(|(index, _)| *index)
+            .filter(|&index| {
+                self.macro_scope.as_ref().map_or(true, |scope| scope.is_proc_visible(index))
+            })
+            .ok_or_else(|| ParsingError::undefined_local_proc(token, proc_name.as_ref()))
     }
 
     /// Returns true if a procedure with the specified name is present in the set of local or
@@ -663,11 +1256,87 @@ impl ParserContext<'_> {
     fn contains_proc_name(&self, proc_name: &ProcedureName) -> bool {
         self.local_procs.contains_key(proc_name) || self.reexported_procs.contains_key(proc_name)
     }
+
+    /// Labels the header of the local procedure with the specified name in the provided error as
+    /// the previous definition of that name.
+    fn with_previous_definition(
+        &self,
+        err: ParsingError,
+        proc_name: &ProcedureName,
+    ) -> ParsingError {
+        match self.local_procs.get(proc_name) {
+            Some((_, proc)) => {
+                let keyword = if proc.is_export { Token::EXPORT } else { Token::PROC };
+                let label = format!("previous definition of `{proc_name}` here");
+                err.with_label(Label::new(proc.start, keyword.len(), label))
+            }
+            None => err,
+        }
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Mnemonics of all instructions recognized by [ParserContext::parse_op_token()]. The list must be
+/// kept sorted.
+#[rustfmt::skip]
+const INSTRUCTION_MNEMONICS: &[&str] = &[
+    "add", "adv", "adv_loadw", "adv_pipe", "adv_push", "and", "assert", "assert_eq", "assert_eqw",
+    "assertz", "breakpoint", "call", "caller", "cdrop", "cdropw", "clk", "const", "cswap",
+    "cswapw", "debug", "div", "drop", "dropw", "dup", "dupw", "dyncall", "dynexec", "emit", "eq",
+    "eqw", "exec", "exp", "ext2add", "ext2div", "ext2inv", "ext2mul", "ext2neg", "ext2sub",
+    "fri_ext2fold4", "gt", "gte", "hash", "hmerge", "hperm", "ilog2", "inv", "is_odd", "loc_load",
+    "loc_loadw", "loc_store", "loc_storew", "locaddr", "lt", "lte", "mem_load", "mem_loadw",
+    "mem_protect", "mem_store", "mem_storew", "mem_stream", "movdn", "movdnw", "movup", "movupw",
+    "mtree_get", "mtree_merge", "mtree_set", "mtree_verify", "mul", "neg", "neq", "not", "or",
+    "padw", "pow2", "procref", "push", "rcomb_base", "sdepth", "sub", "swap", "swapdw", "swapw",
+    "syscall", "trace", "u32and", "u32assert", "u32assert2", "u32assertw", "u32cast", "u32clo",
+    "u32clz", "u32cto", "u32ctz", "u32div", "u32divmod", "u32gt", "u32gte", "u32lt", "u32lte",
+    "u32max", "u32min", "u32mod", "u32not", "u32or", "u32overflowing_add", "u32overflowing_add3",
+    "u32overflowing_madd", "u32overflowing_mul", "u32overflowing_sub", "u32popcnt", "u32rotl",
+    "u32rotr", "u32shl", "u32shr", "u32split", "u32test", "u32testw", "u32wrapping_add",
+    "u32wrapping_add3", "u32wrapping_madd", "u32wrapping_mul", "u32wrapping_sub", "u32xor", "xor",
+];
+
+/// Returns true if the provided name is a mnemonic of an instruction.
+///
+/// Procedure names which collide with instruction mnemonics are deliberately accepted rather than
+/// rejected: the standard library exports many such procedures (e.g., `and`, `or`, `eq`, and `lt`
+/// in `std::math::u64`), and procedures are always invoked via `exec`, `call`, or `procref`, so
+/// the names cannot be confused with instructions. Such collisions are reported as warnings only.
+fn is_instruction_mnemonic(name: &str) -> bool {
+    INSTRUCTION_MNEMONICS.binary_search(&name).is_ok()
+}
+
+/// Returns the instruction mnemonic closest to the provided name, if the name is within a few
+/// edits of it.
+fn closest_instruction_mnemonic(name: &str) -> Option<&'static str> {
+    let max_distance = (name.len() / 3).max(1);
+    INSTRUCTION_MNEMONICS
+        .iter()
+        .map(|mnemonic| (edit_distance(name, mnemonic), *mnemonic))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, mnemonic)| mnemonic)
+}
+
+/// Returns the Levenshtein distance between the two provided strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev_diag + usize::from(ca != *cb);
+            prev_diag = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(prev_diag + 1);
+        }
+    }
+    row[b.len()]
+}
+
 /// Validates that the provided token does not contain any immediate parameters and returns a node
 /// for the specified instruction.
 ///
@@ -1,12 +1,13 @@
 use super::{
-    parse_checked_param, parse_hex_value, parse_param_with_constant_lookup, Endianness, Felt,
+    parse_checked_param, parse_hex_value, parse_param_with_constant_lookup, strip_digit_separators,
+    Endianness, Felt,
     Instruction::*,
     LocalConstMap,
     Node::{self, Instruction},
     ParsingError, Token, CONSTANT_LABEL_PARSER, HEX_CHUNK_SIZE,
 };
 use crate::{StarkField, ADVICE_READ_LIMIT, MAX_PUSH_INPUTS};
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use core::ops::RangeBounds;
 use vm_core::WORD_SIZE;
 
@@ -31,15 +32,15 @@ pub fn parse_push(op: &Token, constants: &LocalConstMap) -> Result<Node, Parsing
         1 => Err(ParsingError::missing_param(op, "push.<a?>")),
         2 => {
             let param_str = op.parts()[1];
-            match param_str.strip_prefix("0x") {
+            match parse_hex_digits(op, param_str, 1)? {
                 // if we have only one hex parameter
                 Some(param_str) if param_str.len() <= HEX_CHUNK_SIZE => {
-                    let value = parse_hex_value(op, param_str, 1, Endianness::Big)?;
+                    let value = parse_hex_value(op, &param_str, 1, Endianness::Big)?;
                     build_push_one_instruction(value)
                 }
                 // if we have many hex parameters without delimiter
-                Some(param_str) => parse_long_hex_param(op, param_str),
-                // if we have one decimal parameter
+                Some(param_str) => parse_long_hex_param(op, &param_str),
+                // if we have one decimal or binary parameter
                 None => {
                     let value = parse_non_hex_param_with_constants_lookup(
                         op,
@@ -241,28 +242,71 @@ pub fn parse_loc_storew(op: &Token, constants: &LocalConstMap) -> Result<Node, P
     }
 }
 
+// DATA SECTIONS
+// ================================================================================================
+
+/// Parses all parts of a token inside a `data` section into field elements.
+///
+/// Each part can be a decimal, hexadecimal, or binary value, or the name of a constant; thus,
+/// values can be specified one per token (e.g., `1 2 3 4`) or as dot-separated lists (e.g.,
+/// `1.2.3.4`).
+///
+/// # Errors
+/// Returns an error if any of the parts is not a valid field element.
+pub fn parse_data_values(op: &Token, constants: &LocalConstMap) -> Result<Vec<Felt>, ParsingError> {
+    (0..op.num_parts())
+        .map(|param_idx| {
+            let value = match parse_hex_digits(op, op.parts()[param_idx], param_idx)? {
+                Some(param_str) => parse_hex_value(op, &param_str, param_idx, Endianness::Big)?,
+                None => parse_non_hex_param_with_constants_lookup(
+                    op,
+                    constants,
+                    param_idx,
+                    0..Felt::MODULUS,
+                )?,
+            };
+            Ok(Felt::new(value))
+        })
+        .collect()
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Parses a list of parameters (each of which could be in decimal or hexadecimal form) and returns
-/// an appropriate push instruction node.
+/// Parses a list of parameters (each of which could be in decimal, hexadecimal, or binary form)
+/// and returns an appropriate push instruction node.
 fn parse_param_list(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
     let values =
-        op.parts().iter().enumerate().skip(1).map(|(param_idx, &param_str)| {
-            match param_str.strip_prefix("0x") {
-                Some(param_str) => parse_hex_value(op, param_str, param_idx, Endianness::Big),
+        op.parts().iter().enumerate().skip(1).map(
+            |(param_idx, &param_str)| match parse_hex_digits(op, param_str, param_idx)? {
+                Some(param_str) => parse_hex_value(op, &param_str, param_idx, Endianness::Big),
                 None => parse_non_hex_param_with_constants_lookup(
                     op,
                     constants,
                     param_idx,
                     0..Felt::MODULUS,
                 ),
-            }
-        });
+            },
+        );
 
     build_push_many_instruction(values)
 }
 
+/// Returns the digits of a hexadecimal parameter with the `0x` prefix and the underscores
+/// separating the digits removed, or None if the parameter is not in hexadecimal form.
+fn parse_hex_digits(
+    op: &Token,
+    param_str: &str,
+    param_idx: usize,
+) -> Result<Option<String>, ParsingError> {
+    match param_str.strip_prefix("0x") {
+        Some(hex_str) => strip_digit_separators(hex_str, 16)
+            .map(Some)
+            .ok_or_else(|| ParsingError::invalid_param(op, param_idx)),
+        None => Ok(None),
+    }
+}
+
 /// Parses a non hexadecimal parameter and returns the value. Takes as argument a constant map
 /// for constant lookup.
 fn parse_non_hex_param_with_constants_lookup<R: RangeBounds<u64>>(
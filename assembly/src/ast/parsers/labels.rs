@@ -2,6 +2,26 @@ use super::{Deserializable, LabelError, RpoDigest, SliceReader, MAX_LABEL_LEN};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
+// RESERVED KEYWORDS
+// ================================================================================================
+
+/// Keywords which cannot be used as procedure or constant names (the latter are compared
+/// case-insensitively).
+///
+/// This includes keywords which are already a part of the assembly grammar as well as keywords
+/// reserved for future extensions of the language. The list must be kept sorted.
+#[rustfmt::skip]
+pub const RESERVED_KEYWORDS: &[&str] = &[
+    "begin", "break", "call", "const", "continue", "else", "end", "enum", "exec", "export",
+    "false", "fn", "for", "if", "import", "let", "macro", "module", "proc", "pub", "repeat",
+    "return", "struct", "syscall", "true", "type", "use", "while",
+];
+
+/// Returns true if the provided label is one of the [RESERVED_KEYWORDS].
+pub fn is_reserved_keyword(label: &str) -> bool {
+    RESERVED_KEYWORDS.binary_search(&label).is_ok()
+}
+
 // LABEL PARSERS
 // ================================================================================================
 
@@ -11,6 +31,25 @@ pub const CONSTANT_LABEL_PARSER: LabelParser = LabelParser {
     max_len: MAX_LABEL_LEN,
     numbers_letters_underscore: true,
     start_with_letter: true,
+    reject_keywords: false,
+};
+
+/// Local alias label parser.
+pub const ALIAS_LABEL_PARSER: LabelParser = LabelParser {
+    caps: false,
+    max_len: MAX_LABEL_LEN,
+    numbers_letters_underscore: true,
+    start_with_letter: true,
+    reject_keywords: true,
+};
+
+/// Feature flag label parser.
+pub const FEATURE_LABEL_PARSER: LabelParser = LabelParser {
+    caps: false,
+    max_len: MAX_LABEL_LEN,
+    numbers_letters_underscore: true,
+    start_with_letter: true,
+    reject_keywords: false,
 };
 
 /// Library namespace label parser.
@@ -19,6 +58,16 @@ pub const NAMESPACE_LABEL_PARSER: LabelParser = LabelParser {
     max_len: MAX_LABEL_LEN,
     numbers_letters_underscore: true,
     start_with_letter: true,
+    reject_keywords: false,
+};
+
+/// Macro label parser.
+pub const MACRO_LABEL_PARSER: LabelParser = LabelParser {
+    caps: false,
+    max_len: MAX_LABEL_LEN,
+    numbers_letters_underscore: true,
+    start_with_letter: true,
+    reject_keywords: true,
 };
 
 /// Procedure label parser.
@@ -27,6 +76,7 @@ pub const PROCEDURE_LABEL_PARSER: LabelParser = LabelParser {
     max_len: MAX_LABEL_LEN,
     numbers_letters_underscore: true,
     start_with_letter: true,
+    reject_keywords: true,
 };
 
 // LABEL PARSER IMPLEMENTATION
@@ -38,6 +88,7 @@ pub struct LabelParser {
     pub max_len: usize,
     pub numbers_letters_underscore: bool,
     pub start_with_letter: bool,
+    pub reject_keywords: bool,
 }
 
 impl LabelParser {
@@ -67,6 +118,9 @@ impl LabelParser {
         {
             // all letters must be uppercase
             return Err(LabelError::must_be_uppercase(label));
+        } else if self.reject_keywords && is_reserved_keyword(label) {
+            // label cannot be a reserved keyword
+            return Err(LabelError::reserved_keyword(label));
         }
         Ok(label)
     }
@@ -0,0 +1,103 @@
+use super::{SourceLocation, Token};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+// MACRO DEFINITION
+// ================================================================================================
+
+/// A macro declared via a `macro.<name>[.<PARAM>]*` statement.
+///
+/// A macro is a sequence of tokens which is parsed in place of every `!<name>[.<arg>]*`
+/// invocation of the macro, with the parameters of the macro bound to the values of the provided
+/// arguments. Macros do not produce any nodes by themselves, and thus, they do not affect the
+/// serialized form of the AST.
+#[derive(Clone, Debug)]
+pub struct MacroDef {
+    /// Names of the macro parameters in the order in which they were declared.
+    params: Vec<String>,
+    /// Body tokens of the macro, including the closing `end` token, with their source locations.
+    body: Vec<(String, SourceLocation)>,
+    /// Declaration order of this macro among all macros of the module.
+    index: usize,
+    /// Number of local procedures declared before this macro.
+    num_procs: usize,
+}
+
+impl MacroDef {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [MacroDef] instantiated with the specified parameters and an empty body.
+    pub fn new(params: Vec<String>, index: usize, num_procs: usize) -> Self {
+        Self {
+            params,
+            body: Vec::new(),
+            index,
+            num_procs,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the declaration order of this macro among all macros of the module.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the names of the parameters of this macro.
+    pub fn params(&self) -> &[String] {
+        &self.params
+    }
+
+    /// Returns the body tokens of this macro together with their source locations.
+    pub fn body(&self) -> &[(String, SourceLocation)] {
+        &self.body
+    }
+
+    /// Returns the scope in which the body of this macro is parsed.
+    ///
+    /// Only macros and local procedures declared before this macro are visible from its body.
+    /// This guarantees that macros cannot be recursive and that their bodies always refer to the
+    /// same procedures irrespective of where they are invoked.
+    pub fn scope(&self) -> MacroScope {
+        MacroScope {
+            params: self.params.clone(),
+            num_macros: self.index,
+            num_procs: self.num_procs,
+        }
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the provided token to the body of this macro.
+    pub fn push_token(&mut self, token: &Token) {
+        self.body.push((token.to_string(), *token.location()));
+    }
+}
+
+// MACRO SCOPE
+// ================================================================================================
+
+/// Describes the names visible from the body of the macro currently being expanded.
+#[derive(Clone, Debug)]
+pub struct MacroScope {
+    /// Names of the macro parameters.
+    pub params: Vec<String>,
+    /// Number of macros visible from the macro body.
+    pub num_macros: usize,
+    /// Number of local procedures visible from the macro body.
+    pub num_procs: usize,
+}
+
+impl MacroScope {
+    /// Returns true if the macro with the specified declaration index is visible from this scope.
+    pub fn is_macro_visible(&self, index: usize) -> bool {
+        index < self.num_macros
+    }
+
+    /// Returns true if the local procedure with the specified index is visible from this scope.
+    pub fn is_proc_visible(&self, index: u16) -> bool {
+        (index as usize) < self.num_procs
+    }
+}
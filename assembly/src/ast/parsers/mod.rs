@@ -1,14 +1,15 @@
 use super::{
-    bound_into_included_u64, AdviceInjectorNode, CodeBody, Deserializable, Felt, Instruction,
-    InvocationTarget, LabelError, LibraryPath, LocalConstMap, LocalProcMap, ModuleImports, Node,
-    ParsingError, ProcedureAst, ProcedureId, ProcedureName, ReExportedProcMap, RpoDigest,
-    SliceReader, StarkField, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN, MAX_LABEL_LEN,
-    MAX_STACK_WORD_OFFSET,
+    bound_into_included_u64, event, AdviceInjectorNode, CodeBody, DataSegment, Deserializable,
+    Felt, ImportedConstants, Instruction, InstructionAliases, InvocationTarget, LabelError, Level,
+    LibraryPath, LocalAliasMap, LocalConstDocsMap, LocalConstMap, LocalMacroMap, LocalProcMap,
+    LocalProcRootMap, ModuleImports, Node, ParsingError, ProcedureAst, ProcedureId, ProcedureName,
+    ReExportedProcMap, RpoDigest, SliceReader, SourceLocation, StarkField, Token, TokenStream,
+    MAX_BODY_LEN, MAX_DOCS_LEN, MAX_LABEL_LEN, MAX_STACK_WORD_OFFSET,
 };
 use crate::HEX_CHUNK_SIZE;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use core::{fmt::Display, ops::RangeBounds};
+use core::{fmt::Display, num::IntErrorKind, ops::RangeBounds};
 
 mod adv_ops;
 mod debug;
@@ -20,15 +21,19 @@ mod sys_ops;
 mod u32_ops;
 
 mod constants;
-use constants::calculate_const_value;
+use constants::{calculate_const_value, ConstantScope};
 
 mod context;
 pub use context::ParserContext;
 
+mod macros;
+pub use macros::{MacroDef, MacroScope};
+
 mod labels;
+use labels::is_reserved_keyword;
 pub use labels::{
-    decode_hex_rpo_digest_label, CONSTANT_LABEL_PARSER, NAMESPACE_LABEL_PARSER,
-    PROCEDURE_LABEL_PARSER,
+    decode_hex_rpo_digest_label, ALIAS_LABEL_PARSER, CONSTANT_LABEL_PARSER, FEATURE_LABEL_PARSER,
+    MACRO_LABEL_PARSER, NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER,
 };
 
 /// Helper enum for endianness determination in the parsing functions.
@@ -41,33 +46,76 @@ pub enum Endianness {
 // PARSERS FUNCTIONS
 // ================================================================================================
 
-/// Parses all `const` statements into a map which maps a const name to a value
-pub fn parse_constants(tokens: &mut TokenStream) -> Result<LocalConstMap, ParsingError> {
-    // instantiate new constant map for this module
+/// Parses all `const` statements into a map which maps a const name to a value, a map which maps
+/// a const name to its doc comments, and a map which maps a const name to a label of the procedure
+/// whose MAST root the constant refers to.
+///
+/// Constant values may refer to constants defined in modules imported by the parsed source; values
+/// of such constants are looked up in the provided `imported_constants`. Doc comments are retained
+/// only for constants whose values are field elements.
+pub fn parse_constants(
+    tokens: &mut TokenStream,
+    import_info: &ModuleImports,
+    imported_constants: &ImportedConstants,
+) -> Result<(LocalConstMap, LocalConstDocsMap, LocalProcRootMap), ParsingError> {
+    // instantiate new constant maps for this module
     let mut constants = LocalConstMap::new();
+    let mut const_docs = LocalConstDocsMap::new();
+    let mut proc_roots = LocalProcRootMap::new();
 
     // iterate over tokens until we find a const declaration
     while let Some(token) = tokens.read() {
         match token.parts()[0] {
             Token::CONST => {
-                let (name, value) = parse_constant(token, &constants)?;
+                let scope = ConstantScope::new(&constants, import_info, imported_constants);
+                let (name, value) = parse_constant(token, &scope)?;
 
-                if constants.contains_key(&name) {
+                if constants.contains_key(&name) || proc_roots.contains_key(&name) {
                     return Err(ParsingError::duplicate_const_name(token, &name));
                 }
 
-                constants.insert(name, value);
+                let docs = tokens.take_doc_comment_at(tokens.pos());
+                match value {
+                    ConstValue::Felt(value) => {
+                        if let Some(docs) = docs {
+                            if docs.len() > MAX_DOCS_LEN {
+                                let token = tokens.read().expect("no const token");
+                                return Err(ParsingError::const_docs_too_long(
+                                    token,
+                                    docs.len(),
+                                    MAX_DOCS_LEN,
+                                ));
+                            }
+                            const_docs.insert(name.clone(), docs);
+                        }
+                        constants.insert(name, value);
+                    }
+                    ConstValue::ProcRoot(label) => {
+                        proc_roots.insert(name, label);
+                    }
+                }
                 tokens.advance();
             }
             _ => break,
         }
     }
 
-    Ok(constants)
+    Ok((constants, const_docs, proc_roots))
+}
+
+/// Value of a constant declared via a `const` statement.
+enum ConstValue {
+    /// A field element.
+    Felt(u64),
+    /// MAST root of the procedure with the specified label, declared as `procroot(<label>)`.
+    ProcRoot(String),
 }
 
 /// Parses a constant token and returns a (constant_name, constant_value) tuple
-fn parse_constant(token: &Token, constants: &LocalConstMap) -> Result<(String, u64), ParsingError> {
+fn parse_constant(
+    token: &Token,
+    constants: &ConstantScope,
+) -> Result<(String, ConstValue), ParsingError> {
     match token.num_parts() {
         0 => unreachable!(),
         1 => Err(ParsingError::missing_param(token, "const.<name>=<value>")),
@@ -80,7 +128,32 @@ fn parse_constant(token: &Token, constants: &LocalConstMap) -> Result<(String, u
                     let name = CONSTANT_LABEL_PARSER
                         .parse_label(const_declaration[0])
                         .map_err(|err| ParsingError::invalid_const_name(token, err))?;
-                    let value = parse_const_value(token, const_declaration[1], constants)?;
+                    // constant names are upper-case, and thus are checked against reserved
+                    // keywords case-insensitively
+                    if is_reserved_keyword(&name.to_lowercase()) {
+                        let err = LabelError::reserved_keyword(name);
+                        return Err(ParsingError::invalid_const_name(token, err));
+                    }
+                    let value = match parse_procroot_label(const_declaration[1]) {
+                        Some(label) => {
+                            if let InvocationTarget::MastRoot(_) =
+                                InvocationTarget::parse(label, token)?
+                            {
+                                let reason = "procroot() requires a procedure name";
+                                return Err(ParsingError::invalid_const_value(
+                                    token,
+                                    const_declaration[1],
+                                    reason,
+                                ));
+                            }
+                            ConstValue::ProcRoot(label.to_string())
+                        }
+                        None => ConstValue::Felt(parse_const_value(
+                            token,
+                            const_declaration[1],
+                            constants,
+                        )?),
+                    };
                     Ok((name.to_string(), value))
                 }
                 _ => Err(ParsingError::extra_param(token)),
@@ -115,24 +188,31 @@ fn try_get_constant_value(
 fn parse_const_value(
     op: &Token,
     const_value: &str,
-    constants: &LocalConstMap,
+    constants: &ConstantScope,
 ) -> Result<u64, ParsingError> {
-    let result = match const_value.parse::<u64>() {
-        Ok(value) => value,
-        Err(_) => match const_value.strip_prefix("0x") {
-            Some(param_str) => parse_hex_value(op, param_str, 1, Endianness::Big)?,
-            None => calculate_const_value(op, const_value, constants)?.as_int(),
-        },
+    let result = match parse_int_literal(const_value) {
+        Ok(value) => Some(value),
+        Err(IntLiteralError::Overflow) => None,
+        Err(IntLiteralError::Malformed) => {
+            Some(calculate_const_value(op, const_value, constants)?.as_int())
+        }
     };
 
-    if result >= Felt::MODULUS {
-        let reason = format!("constant value must be smaller than {}", Felt::MODULUS);
-        Err(ParsingError::invalid_const_value(op, const_value, &reason))
-    } else {
-        Ok(result)
+    match result {
+        Some(result) if result < Felt::MODULUS => Ok(result),
+        _ => {
+            let reason = format!("constant value must be smaller than {}", Felt::MODULUS);
+            Err(ParsingError::invalid_const_value(op, const_value, &reason))
+        }
     }
 }
 
+/// If the provided value is a `procroot(<label>)` expression, returns the label of the procedure;
+/// otherwise, returns None.
+fn parse_procroot_label(value: &str) -> Option<&str> {
+    value.strip_prefix("procroot(")?.strip_suffix(')')
+}
+
 /// Parses a param from the op token with the specified type and index. If the param is a constant
 /// label, it will be looked up in the provided constant map.
 pub(crate) fn parse_param_with_constant_lookup<R>(
@@ -141,7 +221,7 @@ pub(crate) fn parse_param_with_constant_lookup<R>(
     constants: &LocalConstMap,
 ) -> Result<R, ParsingError>
 where
-    R: TryFrom<u64> + core::str::FromStr,
+    R: TryFrom<u64>,
 {
     let param_str = op.parts()[param_idx];
     match try_get_constant_value(op, param_str, constants)? {
@@ -152,43 +232,92 @@ where
     }
 }
 
-/// Parses a param from the op token with the specified type.
-fn parse_param<I: core::str::FromStr>(op: &Token, param_idx: usize) -> Result<I, ParsingError> {
-    let param_value = op.parts()[param_idx];
-
-    let result = match param_value.parse::<I>() {
-        Ok(i) => i,
-        Err(_) => return Err(ParsingError::invalid_param(op, param_idx)),
-    };
+/// Parses a named param of the form `<name>=<value>` from the op token with the specified type and
+/// index. If the value is a constant label, it will be looked up in the provided constant map.
+pub(crate) fn parse_named_param_with_constant_lookup<R>(
+    op: &Token,
+    param_idx: usize,
+    name: &str,
+    constants: &LocalConstMap,
+) -> Result<R, ParsingError>
+where
+    R: TryFrom<u64>,
+{
+    let param_str = op.parts()[param_idx]
+        .strip_prefix(name)
+        .and_then(|param| param.strip_prefix('='))
+        .ok_or_else(|| ParsingError::invalid_param(op, param_idx))?;
+    match try_get_constant_value(op, param_str, constants)? {
+        Some(val) => val
+            .try_into()
+            .map_err(|_| ParsingError::const_conversion_failed(op, core::any::type_name::<R>())),
+        None => parse_int_param(op, param_idx, param_str),
+    }
+}
 
-    Ok(result)
+/// Parses a param from the op token with the specified type.
+///
+/// The param can be specified in any notation supported by [parse_int_literal()].
+fn parse_param<I: TryFrom<u64>>(op: &Token, param_idx: usize) -> Result<I, ParsingError> {
+    parse_int_param(op, param_idx, op.parts()[param_idx])
 }
 
 /// Parses a param from the op token with the specified type and ensures that it falls within the
 /// bounds specified by the caller.
+///
+/// The param can be specified in any notation supported by [parse_int_literal()].
 fn parse_checked_param<I, R>(op: &Token, param_idx: usize, range: R) -> Result<I, ParsingError>
 where
-    I: core::str::FromStr + Ord + Clone + Into<u64> + Display,
+    I: TryFrom<u64> + Ord + Clone + Into<u64> + Display,
     R: RangeBounds<I>,
 {
-    let param_value = op.parts()[param_idx];
-
-    let result = match param_value.parse::<I>() {
-        Ok(i) => i,
-        Err(_) => return Err(ParsingError::invalid_param(op, param_idx)),
+    let result = match parse_int_literal(op.parts()[param_idx]) {
+        Ok(value) => I::try_from(value).ok(),
+        Err(IntLiteralError::Overflow) => None,
+        Err(IntLiteralError::Malformed) => return Err(ParsingError::invalid_param(op, param_idx)),
     };
 
     // check that the parameter is within the specified bounds
-    range.contains(&result).then_some(result).ok_or_else(||
-        ParsingError::invalid_param_with_reason(
-            op,
-            param_idx,
-            format!(
-                "parameter value must be greater than or equal to {lower_bound} and less than or equal to {upper_bound}", lower_bound = bound_into_included_u64(range.start_bound(), true),
-                upper_bound = bound_into_included_u64(range.end_bound(), false)
-            )
-            .as_str(),
+    result
+        .filter(|result| range.contains(result))
+        .ok_or_else(|| param_out_of_range(op, param_idx, &range))
+}
+
+/// Parses an integer literal specified as the `param_idx`-th param of the op token (or as a part
+/// of it) into a value of the specified type.
+fn parse_int_param<I: TryFrom<u64>>(
+    op: &Token,
+    param_idx: usize,
+    literal: &str,
+) -> Result<I, ParsingError> {
+    let value = match parse_int_literal(literal) {
+        Ok(value) => I::try_from(value).ok(),
+        Err(IntLiteralError::Overflow) => None,
+        Err(IntLiteralError::Malformed) => return Err(ParsingError::invalid_param(op, param_idx)),
+    };
+
+    value.ok_or_else(|| {
+        let reason = format!("value does not fit into {}", core::any::type_name::<I>());
+        ParsingError::invalid_param_with_reason(op, param_idx, &reason)
+    })
+}
+
+/// Returns an error indicating that the value of the `param_idx`-th param of the op token falls
+/// outside of the specified range.
+fn param_out_of_range<I, R>(op: &Token, param_idx: usize, range: &R) -> ParsingError
+where
+    I: Clone + Into<u64>,
+    R: RangeBounds<I>,
+{
+    ParsingError::invalid_param_with_reason(
+        op,
+        param_idx,
+        format!(
+            "parameter value must be greater than or equal to {lower_bound} and less than or equal to {upper_bound}",
+            lower_bound = bound_into_included_u64(range.start_bound(), true),
+            upper_bound = bound_into_included_u64(range.end_bound(), false)
         )
+        .as_str(),
     )
 }
 
@@ -223,7 +352,7 @@ fn parse_error_code(token: &Token, constants: &LocalConstMap) -> Result<u32, Par
             let err_code_str = err_code_parts[1];
             let err_code = match try_get_constant_value(token, err_code_str, constants)? {
                 Some(val) => val.try_into().map_err(|_| ParsingError::invalid_param(token, 1))?,
-                None => err_code_str.parse().map_err(|_| ParsingError::invalid_param(token, 1))?,
+                None => parse_int_param(token, 1, err_code_str)?,
             };
             Ok(err_code)
         }
@@ -282,12 +411,49 @@ fn parse_hex_value(
     };
 
     if value >= Felt::MODULUS {
-        Err(ParsingError::invalid_param_with_reason(
-            op,
-            param_idx,
-            &format!("hex string '{hex_str}' contains value greater than field modulus"),
-        ))
+        Err(param_out_of_range(op, param_idx, &(0..Felt::MODULUS)))
     } else {
         Ok(value)
     }
 }
+
+/// Reasons for which an integer literal could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntLiteralError {
+    /// The literal is not a valid decimal, hexadecimal, or binary number.
+    Malformed,
+    /// The value of the literal does not fit into 64 bits.
+    Overflow,
+}
+
+/// Parses an integer literal into a u64 value.
+///
+/// The literal can be specified in decimal, hexadecimal (with the `0x` prefix), or binary (with
+/// the `0b` prefix) notation. Digits of the literal can be separated by underscores for
+/// readability (e.g., `1_000_000`, `0xdead_beef`, or `0b1010_0101`).
+fn parse_int_literal(literal: &str) -> Result<u64, IntLiteralError> {
+    let (digits, radix) = if let Some(digits) = literal.strip_prefix("0x") {
+        (digits, 16)
+    } else if let Some(digits) = literal.strip_prefix("0b") {
+        (digits, 2)
+    } else {
+        (literal, 10)
+    };
+
+    let digits = strip_digit_separators(digits, radix).ok_or(IntLiteralError::Malformed)?;
+    u64::from_str_radix(&digits, radix).map_err(|err| match err.kind() {
+        IntErrorKind::PosOverflow => IntLiteralError::Overflow,
+        _ => IntLiteralError::Malformed,
+    })
+}
+
+/// Removes underscores separating the digits of a number in the specified radix.
+///
+/// Returns None if `digits` is empty, contains characters which are not valid digits in the
+/// specified radix, or contains underscores which are not placed between two digits.
+fn strip_digit_separators(digits: &str, radix: u32) -> Option<String> {
+    digits
+        .split('_')
+        .all(|group| !group.is_empty() && group.chars().all(|c| c.is_digit(radix)))
+        .then(|| digits.replace('_', ""))
+}
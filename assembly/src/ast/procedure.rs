@@ -3,11 +3,12 @@ use alloc::{
     vec::Vec,
 };
 
-use crate::ast::{MAX_BODY_LEN, MAX_DOCS_LEN};
+use crate::ast::{read_len, read_u16_varint, MAX_BODY_LEN, MAX_DOCS_LEN};
 
 use super::{
     super::tokens::SourceLocation, code_body::CodeBody, nodes::Node, ByteReader, ByteWriter,
-    Deserializable, DeserializationError, LibraryPath, ProcedureId, ProcedureName, Serializable,
+    DataSegment, Deserializable, DeserializationError, LibraryPath, ProcedureId, ProcedureName,
+    ProcedureSignature, Serializable,
 };
 use core::{iter, str::from_utf8};
 
@@ -19,14 +20,23 @@ use core::{iter, str::from_utf8};
 /// A procedure AST consists of a list of body nodes and additional metadata about the procedure
 /// (e.g., procedure name, number of memory locals used by the procedure, and whether a procedure
 /// is exported or internal).
+///
+/// The signature of a procedure is declared in its doc comments (see [ProcedureSignature]). Doc
+/// comments of internal procedures are not retained, and thus, signatures of internal procedures
+/// are lost when the procedure is serialized.
+///
+/// Procedures generated for `data` sections carry the declared [DataSegment], which the assembler
+/// adds to the advice map when the procedure is compiled.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProcedureAst {
     pub name: ProcedureName,
     pub docs: Option<String>,
+    pub signature: Option<ProcedureSignature>,
     pub num_locals: u16,
     pub body: CodeBody,
     pub start: SourceLocation,
     pub is_export: bool,
+    pub data: Option<DataSegment>,
 }
 
 impl ProcedureAst {
@@ -48,13 +58,40 @@ impl ProcedureAst {
         Self {
             name,
             docs,
+            signature: None,
             num_locals,
             body,
             is_export,
             start,
+            data: None,
+        }
+    }
+
+    /// Constructs a [ProcedureAst] which copies the values of the provided data segment into
+    /// memory (see [DataSegment] for the description of the generated procedure).
+    pub fn from_data_segment(name: ProcedureName, data: DataSegment, is_export: bool) -> Self {
+        let signature = ProcedureSignature::new(["write_ptr"], [] as [&str; 0]);
+        let docs = is_export.then(|| {
+            format!(
+                "Copies the {} words of data segment `{name}` into memory starting at \
+                write_ptr.\n\n{signature}",
+                data.num_words()
+            )
+        });
+        let body = data.loader_body();
+        Self {
+            data: Some(data),
+            signature: Some(signature),
+            ..Self::new(name, 0, body, is_export, docs)
         }
     }
 
+    /// Sets the signature of this procedure.
+    pub fn with_signature(mut self, signature: ProcedureSignature) -> Self {
+        self.signature = Some(signature);
+        self
+    }
+
     /// Binds the provided `locations` into the ast nodes.
     ///
     /// The `start` location points to the first node of this block.
@@ -119,26 +156,31 @@ impl Serializable for ProcedureAst {
         match &self.docs {
             Some(docs) => {
                 assert!(docs.len() <= MAX_DOCS_LEN, "docs too long");
-                target.write_u16(docs.len() as u16);
+                target.write_usize(docs.len());
                 target.write_bytes(docs.as_bytes());
             }
             None => {
-                target.write_u16(0);
+                target.write_usize(0);
             }
         }
 
         target.write_bool(self.is_export);
-        target.write_u16(self.num_locals);
+        target.write_usize(self.num_locals as usize);
         assert!(self.body.nodes().len() <= MAX_BODY_LEN, "too many body instructions");
-        target.write_u16(self.body.nodes().len() as u16);
+        target.write_usize(self.body.nodes().len());
         target.write_many(self.body.nodes());
+
+        target.write_bool(self.data.is_some());
+        if let Some(data) = &self.data {
+            data.write_into(target);
+        }
     }
 }
 
 impl Deserializable for ProcedureAst {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let name = ProcedureName::read_from(source)?;
-        let docs_len = source.read_u16()? as usize;
+        let docs_len = read_len(source, MAX_DOCS_LEN)?;
         let docs = if docs_len != 0 {
             let str = source.read_vec(docs_len)?;
             let str =
@@ -149,18 +191,28 @@ impl Deserializable for ProcedureAst {
         };
 
         let is_export = source.read_bool()?;
-        let num_locals = source.read_u16()?;
-        let body_len = source.read_u16()? as usize;
+        let num_locals = read_u16_varint(source)?;
+        let body_len = read_len(source, MAX_BODY_LEN)?;
         let nodes = source.read_many::<Node>(body_len)?;
         let body = CodeBody::new(nodes);
         let start = SourceLocation::default();
+        let data = if source.read_bool()? {
+            Some(DataSegment::read_from(source)?)
+        } else {
+            None
+        };
+
+        // signatures are not serialized separately since they are declared in the doc comments
+        let signature = docs.as_deref().and_then(|docs| ProcedureSignature::parse(docs).ok()?);
         Ok(Self {
             name,
+            signature,
             num_locals,
             body,
             start,
             is_export,
             docs,
+            data,
         })
     }
 }
@@ -222,11 +274,11 @@ impl Serializable for ProcReExport {
         match &self.docs {
             Some(docs) => {
                 assert!(docs.len() <= MAX_DOCS_LEN, "docs too long");
-                target.write_u16(docs.len() as u16);
+                target.write_usize(docs.len());
                 target.write_bytes(docs.as_bytes());
             }
             None => {
-                target.write_u16(0);
+                target.write_usize(0);
             }
         }
     }
@@ -236,7 +288,7 @@ impl Deserializable for ProcReExport {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let proc_id = ProcedureId::read_from(source)?;
         let name = ProcedureName::read_from(source)?;
-        let docs_len = source.read_u16()? as usize;
+        let docs_len = read_len(source, MAX_DOCS_LEN)?;
         let docs = if docs_len != 0 {
             let str = source.read_vec(docs_len)?;
             let str =
@@ -1,19 +1,20 @@
 use alloc::vec::Vec;
 
-use crate::ast::MAX_BODY_LEN;
+use crate::ast::{read_len, read_u16_varint, MAX_BODY_LEN};
 
 use super::{
     super::tokens::SourceLocation,
     check_unused_imports,
     code_body::CodeBody,
-    imports::ModuleImports,
+    imports::{ImportedConstants, ModuleImports},
     instrument,
     nodes::Node,
     parsers::{parse_constants, ParserContext},
     serde::AstSerdeOptions,
+    InstructionAliases,
     {
-        format::*, sort_procs_into_vec, LocalProcMap, ProcedureAst, ReExportedProcMap,
-        MAX_LOCAL_PROCS,
+        format::*, sort_procs_into_vec, LocalAliasMap, LocalMacroMap, LocalProcMap, ProcedureAst,
+        ReExportedProcMap, MAX_LOCAL_PROCS,
     },
     {
         ByteReader, ByteWriter, Deserializable, DeserializationError, ParsingError, Serializable,
@@ -29,12 +30,14 @@ use std::{fs, io, path::Path};
 
 /// An abstract syntax tree of an executable Miden program.
 ///
-/// A program AST consists of a body of the program, a list of internal procedure ASTs, a list of
-/// imported libraries, a map from procedure ids to procedure names for imported procedures used in
-/// the module, and the source location of the program.
+/// A program AST consists of a body of the program, the number of memory locals available to the
+/// body, a list of internal procedure ASTs, a list of imported libraries, a map from procedure ids
+/// to procedure names for imported procedures used in the module, and the source location of the
+/// program.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProgramAst {
     pub(super) body: CodeBody,
+    pub(super) num_locals: u16,
     pub(super) local_procs: Vec<ProcedureAst>,
     pub(super) import_info: ModuleImports,
     pub(super) start: SourceLocation,
@@ -64,12 +67,19 @@ impl ProgramAst {
         let body = CodeBody::new(body);
         Ok(Self {
             body,
+            num_locals: 0,
             local_procs,
             import_info: Default::default(),
             start,
         })
     }
 
+    /// Sets the number of memory locals available to the body of the program.
+    pub fn with_num_locals(mut self, num_locals: u16) -> Self {
+        self.num_locals = num_locals;
+        self
+    }
+
     /// Adds the provided import information to the program.
     ///
     /// # Panics
@@ -114,6 +124,11 @@ impl ProgramAst {
         &self.body
     }
 
+    /// Returns the number of memory locals available to the body of this program.
+    pub fn num_locals(&self) -> u16 {
+        self.num_locals
+    }
+
     /// Returns a reference to the import info for this program
     pub fn import_info(&self) -> &ModuleImports {
         &self.import_info
@@ -124,18 +139,45 @@ impl ProgramAst {
     /// Parses the provided source into a [ProgramAst].
     ///
     /// A program consist of a body and a set of internal (i.e., not exported) procedures.
-    #[instrument(name = "parse_program", skip_all)]
     pub fn parse(source: &str) -> Result<ProgramAst, ParsingError> {
+        Self::parse_with_imported_constants(source, &ImportedConstants::default())
+    }
+
+    /// Parses the provided source into a [ProgramAst], resolving references to constants defined
+    /// in imported modules against the provided `imported_constants`.
+    pub fn parse_with_imported_constants(
+        source: &str,
+        imported_constants: &ImportedConstants,
+    ) -> Result<ProgramAst, ParsingError> {
+        Self::parse_with_aliases(source, imported_constants, &InstructionAliases::default())
+    }
+
+    /// Parses the provided source into a [ProgramAst] in the same way as
+    /// [ProgramAst::parse_with_imported_constants()], but resolves mnemonics which do not belong to
+    /// any instruction against the provided `instruction_aliases`.
+    #[instrument(name = "parse_program", skip_all)]
+    pub fn parse_with_aliases(
+        source: &str,
+        imported_constants: &ImportedConstants,
+        instruction_aliases: &InstructionAliases,
+    ) -> Result<ProgramAst, ParsingError> {
         let mut tokens = TokenStream::new(source)?;
         let mut import_info = ModuleImports::parse(&mut tokens)?;
-        let local_constants = parse_constants(&mut tokens)?;
+        let (local_constants, _, local_proc_roots) =
+            parse_constants(&mut tokens, &import_info, imported_constants)?;
 
         let mut context = ParserContext {
             import_info: &mut import_info,
             local_procs: LocalProcMap::default(),
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
+            local_proc_roots,
             num_proc_locals: 0,
+            local_aliases: LocalAliasMap::default(),
+            num_feature_blocks: 0,
+            local_macros: LocalMacroMap::default(),
+            macro_scope: None,
+            instruction_aliases,
         };
 
         context.parse_procedures(&mut tokens, false)?;
@@ -152,7 +194,7 @@ impl ProgramAst {
         // consume the 'begin' token
         let header = tokens.read().expect("missing program header");
         let start = *header.location();
-        header.validate_begin()?;
+        let num_locals = header.parse_begin()?;
         tokens.advance();
 
         // make sure there is something to be read
@@ -161,8 +203,12 @@ impl ProgramAst {
         }
 
         // parse the sequence of nodes and add each node to the list
+        context.num_proc_locals = num_locals;
         let body = context.parse_body(&mut tokens, false)?;
 
+        // the body may have allocated additional locals (e.g., for `repeat.dyn` loop counters)
+        let num_locals = context.num_proc_locals;
+
         // consume the 'end' token
         match tokens.read() {
             None => Err(ParsingError::unmatched_begin(
@@ -188,6 +234,7 @@ impl ProgramAst {
         let local_procs = sort_procs_into_vec(context.local_procs);
         let (nodes, locations) = body.into_parts();
         Ok(Self::new(nodes, local_procs)?
+            .with_num_locals(num_locals)
             .with_source_locations(locations, start)
             .with_import_info(import_info))
     }
@@ -213,12 +260,13 @@ impl ProgramAst {
 
         // serialize procedures
         assert!(self.local_procs.len() <= MAX_LOCAL_PROCS, "too many local procs");
-        target.write_u16(self.local_procs.len() as u16);
+        target.write_usize(self.local_procs.len());
         target.write_many(&self.local_procs);
 
         // serialize program body
+        target.write_usize(self.num_locals as usize);
         assert!(self.body.nodes().len() <= MAX_BODY_LEN, "too many body instructions");
-        target.write_u16(self.body.nodes().len() as u16);
+        target.write_usize(self.body.nodes().len());
         target.write_many(self.body.nodes());
     }
 
@@ -247,16 +295,17 @@ impl ProgramAst {
         };
 
         // deserialize local procs
-        let num_local_procs = source.read_u16()?.into();
+        let num_local_procs = read_len(source, MAX_LOCAL_PROCS)?;
         let local_procs = source.read_many::<ProcedureAst>(num_local_procs)?;
 
         // deserialize program body
-        let body_len = source.read_u16()? as usize;
+        let num_locals = read_u16_varint(source)?;
+        let body_len = read_len(source, MAX_BODY_LEN)?;
         let nodes = source.read_many::<Node>(body_len)?;
 
         match Self::new(nodes, local_procs) {
             Err(err) => Err(DeserializationError::UnknownError(err.message().clone())),
-            Ok(res) => Ok(res.with_import_info(import_info)),
+            Ok(res) => Ok(res.with_num_locals(num_locals).with_import_info(import_info)),
         }
     }
 
@@ -321,9 +370,7 @@ impl ProgramAst {
             fs::create_dir_all(dir)?;
         }
 
-        let bytes = self.to_bytes(AstSerdeOptions {
-            serialize_imports: true,
-        });
+        let bytes = self.to_bytes(AstSerdeOptions::new(true));
         fs::write(path, bytes)
     }
 }
@@ -355,7 +402,11 @@ impl fmt::Display for ProgramAst {
         }
 
         // Main progrma
-        writeln!(f, "begin")?;
+        if self.num_locals > 0 {
+            writeln!(f, "{}.{}", Token::BEGIN, self.num_locals)?;
+        } else {
+            writeln!(f, "{}", Token::BEGIN)?;
+        }
         write!(f, "{}", FormattableCodeBody::new(&self.body, &context.inner_scope_context()))?;
         writeln!(f, "end")
     }
@@ -3,30 +3,107 @@
 //! Structs in this module are used to serialize and deserialize ASTs into a binary format.
 
 use super::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use alloc::string::ToString;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Version of the binary AST format.
+///
+/// The version is bumped every time the encoding of the AST changes in an incompatible way.
+/// Version 1 encodes all list lengths and local indexes as variable-length integers.
+/// Version 2 additionally encodes the number of locals declared for the program body.
+/// Version 3 additionally encodes the data segments of procedures generated for `data` sections.
+/// Version 4 reserves the opcode byte `0xfb` as a prefix for extended `u16` opcodes.
+/// Version 5 additionally encodes the doc comments of module constants.
+pub const AST_FORMAT_VERSION: u8 = 5;
+
+/// The oldest version of the binary AST format which can still be deserialized.
+///
+/// Every version in the range `AST_MIN_FORMAT_VERSION..=AST_FORMAT_VERSION` must be readable by
+/// the current deserializer; this is checked by the compatibility tests against fixture blobs.
+/// Version 4 only added the extended opcode prefix which was never written by version 3, and
+/// thus the encodings of version 3 remain valid.
+pub const AST_MIN_FORMAT_VERSION: u8 = 3;
+
+// AST SERDE OPTIONS
+// ================================================================================================
 
 /// Serialization options
 /// Used to enable or disable serialization of parts of the AST.  Serialization options are
 /// serialized along with the AST to make the serialization format self-contained.
+///
+/// The options are encoded as a single byte which also carries the [AST_FORMAT_VERSION]; ASTs
+/// serialized with a format version older than [AST_MIN_FORMAT_VERSION] or newer than
+/// [AST_FORMAT_VERSION] are rejected during deserialization.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct AstSerdeOptions {
     pub serialize_imports: bool,
+    format_version: u8,
 }
 
 impl AstSerdeOptions {
     pub const fn new(serialize_imports: bool) -> Self {
-        Self { serialize_imports }
+        Self {
+            serialize_imports,
+            format_version: AST_FORMAT_VERSION,
+        }
+    }
+
+    /// Returns the version of the binary AST format described by these options.
+    ///
+    /// ASTs are always serialized with the latest [AST_FORMAT_VERSION]; older versions are
+    /// returned only for options read from ASTs serialized by older versions of the assembler.
+    pub const fn format_version(&self) -> u8 {
+        self.format_version
     }
 }
 
 impl Serializable for AstSerdeOptions {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write_bool(self.serialize_imports);
+        target.write_u8((AST_FORMAT_VERSION << 1) | self.serialize_imports as u8);
     }
 }
 
 impl Deserializable for AstSerdeOptions {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let serialize_imports = source.read_bool()?;
-        Ok(Self::new(serialize_imports))
+        let header = source.read_u8()?;
+        let version = header >> 1;
+        if !(AST_MIN_FORMAT_VERSION..=AST_FORMAT_VERSION).contains(&version) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported AST format version {version}, expected a version between \
+                {AST_MIN_FORMAT_VERSION} and {AST_FORMAT_VERSION}"
+            )));
+        }
+        let serialize_imports = header & 1 == 1;
+        Ok(Self {
+            serialize_imports,
+            format_version: version,
+        })
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads a variable-length encoded length from the `source`, making sure it does not exceed `max`.
+///
+/// This guards against allocating huge buffers when reading malformed inputs.
+pub(crate) fn read_len<R: ByteReader>(
+    source: &mut R,
+    max: usize,
+) -> Result<usize, DeserializationError> {
+    let len = source.read_usize()?;
+    if len > max {
+        return Err(DeserializationError::InvalidValue(format!(
+            "length {len} exceeds the maximum of {max}"
+        )));
+    }
+    Ok(len)
+}
+
+/// Reads a variable-length encoded `u16` value (e.g., a local index) from the `source`.
+pub(crate) fn read_u16_varint<R: ByteReader>(source: &mut R) -> Result<u16, DeserializationError> {
+    let value = source.read_usize()?;
+    u16::try_from(value).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
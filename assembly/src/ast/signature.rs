@@ -0,0 +1,153 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+// PROCEDURE SIGNATURE
+// ================================================================================================
+
+/// A machine-readable description of the stack inputs and outputs of a procedure.
+///
+/// Signatures are declared in the doc comments of procedures using the following syntax:
+///
+/// ```text
+/// #! Computes c = a + b.
+/// #!
+/// #! stack_in: [b, a] stack_out: [c]
+/// export.foo
+///     add
+/// end
+/// ```
+///
+/// The `stack_in` and `stack_out` keys may be placed on the same line or on separate lines, but
+/// if one of them is present, the other one must be present as well. Both lists name the
+/// elements of the stack starting from the top, and every name stands for a single element;
+/// thus, words must be spelled out as four elements (e.g., `[A3, A2, A1, A0]`), and the `...`
+/// placeholder is not allowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureSignature {
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl ProcedureSignature {
+    /// Key which introduces the list of inputs of a procedure.
+    pub const STACK_IN: &'static str = "stack_in:";
+
+    /// Key which introduces the list of outputs of a procedure.
+    pub const STACK_OUT: &'static str = "stack_out:";
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a signature with the specified inputs and outputs.
+    pub fn new<I, O>(inputs: I, outputs: O) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        O: IntoIterator,
+        O::Item: Into<String>,
+    {
+        Self {
+            inputs: inputs.into_iter().map(Into::into).collect(),
+            outputs: outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Parses the signature declared in the provided doc comment.
+    ///
+    /// Returns `Ok(None)` if the doc comment does not declare a signature.
+    ///
+    /// # Errors
+    /// Returns an error message if the declaration is malformed.
+    pub fn parse(docs: &str) -> Result<Option<Self>, String> {
+        let inputs = parse_elements(docs, Self::STACK_IN)?;
+        let outputs = parse_elements(docs, Self::STACK_OUT)?;
+        match (inputs, outputs) {
+            (None, None) => Ok(None),
+            (Some(inputs), Some(outputs)) => Ok(Some(Self { inputs, outputs })),
+            (Some(_), None) => {
+                Err(format!("'{}' is declared without '{}'", Self::STACK_IN, Self::STACK_OUT))
+            }
+            (None, Some(_)) => {
+                Err(format!("'{}' is declared without '{}'", Self::STACK_OUT, Self::STACK_IN))
+            }
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the names of the elements consumed by the procedure, starting from the top of the
+    /// stack.
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// Returns the names of the elements produced by the procedure, starting from the top of the
+    /// stack.
+    pub fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    /// Returns the number of elements consumed by the procedure.
+    pub fn num_inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Returns the number of elements produced by the procedure.
+    pub fn num_outputs(&self) -> usize {
+        self.outputs.len()
+    }
+}
+
+impl fmt::Display for ProcedureSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} [{}] {} [{}]",
+            Self::STACK_IN,
+            self.inputs.join(", "),
+            Self::STACK_OUT,
+            self.outputs.join(", ")
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Parses the list of elements introduced by the specified key, returning None if the key does
+/// not occur in the provided doc comment.
+fn parse_elements(docs: &str, key: &str) -> Result<Option<Vec<String>>, String> {
+    let mut occurrences = docs.match_indices(key);
+    let Some((start, _)) = occurrences.next() else {
+        return Ok(None);
+    };
+    if occurrences.next().is_some() {
+        return Err(format!("'{key}' is declared more than once"));
+    }
+
+    let list = docs[start + key.len()..].trim_start();
+    let list = list
+        .strip_prefix('[')
+        .and_then(|list| list.split_once(']'))
+        .map(|(list, _)| list)
+        .ok_or_else(|| format!("'{key}' must be followed by a list of elements in brackets"))?;
+
+    if list.trim().is_empty() {
+        return Ok(Some(Vec::new()));
+    }
+    list.split(',')
+        .map(|element| {
+            let element = element.trim();
+            if element.is_empty() || element == "..." || element.contains(char::is_whitespace) {
+                Err(format!("invalid element '{element}' in the list of '{key}'"))
+            } else {
+                Ok(element.to_string())
+            }
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
@@ -1,13 +1,15 @@
 use super::{
-    AstSerdeOptions, CodeBody, Felt, Instruction, LocalProcMap, ModuleAst, Node, ParsingError,
-    ProcedureAst, ProcedureId, ProcedureName, ProgramAst, SourceLocation, Token,
+    visit, AstSerdeOptions, CodeBody, Felt, Instruction, LocalProcMap, ModuleAst, MutVisitor, Node,
+    ParsingError, ProcedureAst, ProcedureId, ProcedureName, ProgramAst, SourceLocation, Token,
+    Visitor, AST_FORMAT_VERSION, AST_MIN_FORMAT_VERSION,
 };
+use crate::Suggestion;
 use alloc::{
     collections::BTreeMap,
     string::{String, ToString},
     vec::Vec,
 };
-use vm_core::utils::SliceReader;
+use vm_core::utils::{Serializable, SliceReader};
 
 // UNIT TESTS
 // ================================================================================================
@@ -25,6 +27,31 @@ fn test_ast_parsing_program_simple() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_program_locals() {
+    let source = "begin.2 push.1 loc_store.1 loc_load.1 end";
+    let program = ProgramAst::parse(source).unwrap();
+    assert_eq!(program.num_locals(), 2);
+    assert_eq!(
+        program.body().nodes(),
+        &[
+            Node::Instruction(Instruction::PushU8(1)),
+            Node::Instruction(Instruction::LocStore(1)),
+            Node::Instruction(Instruction::LocLoad(1)),
+        ]
+    );
+    assert_eq!(
+        program.to_string(),
+        "begin.2\n    push.1\n    loc_store.1\n    loc_load.1\nend\n"
+    );
+    assert_correct_program_serialization(source, false);
+
+    let source = "begin.x push.1 end";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(1, 1);
+    assert_eq!(err, ParsingError::invalid_proc_locals(&Token::new("begin.x", location), "x"));
+}
+
 #[test]
 fn test_ast_parsing_program_push() {
     let source = "\
@@ -84,6 +111,67 @@ fn test_ast_parsing_program_push() {
                                   characters");
 }
 
+#[test]
+fn test_ast_parsing_numeric_literals() {
+    let source = "\
+    const.MASK=0b1111_0000
+    const.LIMIT=1_000_000+0x10
+
+    begin
+        push.0xdead_beef
+        push.0b1010
+        push.1_000
+        push.0x0000000000000000_0100000000000000_0200000000000000_0300000000000000
+        push.0b1.0x02.3
+        push.MASK push.LIMIT
+        eq.0xff
+        u32wrapping_add.0b11
+        mem_load.0x10
+        assert.err=0x1_00
+    end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::PushU32(0xdead_beef)),
+        Node::Instruction(Instruction::PushU8(10)),
+        Node::Instruction(Instruction::PushU16(1000)),
+        Node::Instruction(Instruction::PushU8List(vec![0, 1, 2, 3])),
+        Node::Instruction(Instruction::PushU8List(vec![1, 2, 3])),
+        Node::Instruction(Instruction::PushU8(0xf0)),
+        Node::Instruction(Instruction::PushU32(1_000_016)),
+        Node::Instruction(Instruction::EqImm(Felt::new(255))),
+        Node::Instruction(Instruction::U32WrappingAddImm(3)),
+        Node::Instruction(Instruction::MemLoadImm(16)),
+        Node::Instruction(Instruction::AssertWithError(256)),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    // underscores must separate digits
+    for literal in ["1_", "_1", "1__0", "0x_ff", "0b", "0b12"] {
+        let source = format!("begin push.{literal} end");
+        assert!(ProgramAst::parse(&source).is_err(), "{literal} should not be accepted");
+    }
+
+    // values which do not fit into the parameter type are reported consistently
+    let felt_range =
+        "parameter value must be greater than or equal to 0 and less than or equal to \
+        18446744069414584320";
+    for source in [
+        "begin push.0xffff_ffff_0000_0001 end".to_string(),
+        "begin push.18_446_744_069_414_584_321 end".to_string(),
+        format!("begin eq.0b1{} end", "_0000".repeat(16)),
+    ] {
+        let err = ProgramAst::parse(&source).err().unwrap();
+        assert!(err.message().ends_with(felt_range), "{}", err.message());
+    }
+
+    let err = ProgramAst::parse("begin u32wrapping_add.0x1_0000_0000 end").err().unwrap();
+    assert_eq!(
+        err.message(),
+        "malformed instruction 'u32wrapping_add.0x1_0000_0000', parameter 0x1_0000_0000 is invalid: \
+        value does not fit into u32"
+    );
+}
+
 #[test]
 fn test_ast_parsing_program_u32() {
     let source = "\
@@ -228,12 +316,20 @@ fn test_ast_parsing_adv_injection() {
     use super::AdviceInjectorNode::*;
     use Instruction::AdvInject;
 
-    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem end";
+    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem \
+        adv.push_secp256k1_base_inv adv.push_secp256k1_scalar_inv adv.push_secp256r1_base_inv \
+        adv.push_secp256r1_scalar_inv adv.push_u256div adv.push_u256modinv end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(AdvInject(PushU64Div)),
         Node::Instruction(AdvInject(PushMapVal)),
         Node::Instruction(AdvInject(PushSmtGet)),
         Node::Instruction(AdvInject(InsertMem)),
+        Node::Instruction(AdvInject(PushSecp256k1BaseInv)),
+        Node::Instruction(AdvInject(PushSecp256k1ScalarInv)),
+        Node::Instruction(AdvInject(PushSecp256r1BaseInv)),
+        Node::Instruction(AdvInject(PushSecp256r1ScalarInv)),
+        Node::Instruction(AdvInject(PushU256Div)),
+        Node::Instruction(AdvInject(PushU256ModInv)),
     ];
 
     assert_program_output(source, BTreeMap::new(), nodes);
@@ -550,9 +646,355 @@ fn test_unterminated_if() {
     }
 }
 
+#[test]
+fn test_reserved_keyword_proc_name() {
+    let source = "proc.end add end begin exec.end end";
+
+    let result = ProgramAst::parse(source);
+    match result {
+        Ok(_) => panic!("should have panicked"),
+        Err(err) => assert!(err
+            .to_string()
+            .contains("'end' is a reserved keyword, consider renaming it to 'end_'")),
+    }
+
+    let source = "export.type add end";
+    let result = ModuleAst::parse(source);
+    match result {
+        Ok(_) => panic!("should have panicked"),
+        Err(err) => assert!(err.to_string().contains("'type' is a reserved keyword")),
+    }
+}
+
+#[test]
+fn test_reserved_keyword_const_name() {
+    let source = "const.BEGIN=1 begin push.BEGIN end";
+    let result = ProgramAst::parse(source);
+    match result {
+        Ok(_) => panic!("should have panicked"),
+        Err(err) => assert!(err
+            .to_string()
+            .contains("'BEGIN' is a reserved keyword, consider renaming it to 'BEGIN_'")),
+    }
+
+    // constant names which only contain a keyword are accepted
+    let source = "const.BEGIN_ADDR=1 begin push.BEGIN_ADDR end";
+    assert!(ProgramAst::parse(source).is_ok());
+}
+
+#[test]
+fn test_instruction_mnemonic_proc_name() {
+    // procedure names which shadow instructions are allowed since they can only be invoked via
+    // `exec`, `call`, or `procref`
+    let source = "proc.add add end begin exec.add end";
+    let program = ProgramAst::parse(source).unwrap();
+    assert_eq!(program.procedures()[0].name, str_to_proc_name("add"));
+}
+
 // DOCUMENTATION PARSING TESTS
 // ================================================================================================
 
+#[test]
+fn test_ast_parsing_feature_if() {
+    let source = "\
+    begin
+        push.1
+        @if debug
+            dup
+            assert
+        @else
+            @if fast
+                drop
+            @end
+        @end
+    end";
+
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::PushU8(1)),
+        Node::IfFeature {
+            feature: "debug".to_string(),
+            true_case: CodeBody::new([
+                Node::Instruction(Instruction::Dup0),
+                Node::Instruction(Instruction::Assert),
+            ]),
+            false_case: CodeBody::new([Node::IfFeature {
+                feature: "fast".to_string(),
+                true_case: CodeBody::new([Node::Instruction(Instruction::Drop)]),
+                false_case: CodeBody::default(),
+            }]),
+        },
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+}
+
+#[test]
+fn test_ast_parsing_feature_if_errors() {
+    // conditional blocks must be closed with `@end`
+    let source = "begin\n@if debug\npush.1\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(2, 1);
+    assert_eq!(err, ParsingError::unmatched_feature_if(&Token::new("@if", location)));
+
+    let source = "begin\n@if debug\npush.1\n@else\npush.2\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(4, 1);
+    assert_eq!(err, ParsingError::unmatched_feature_else(&Token::new("@else", location)));
+
+    // `@else` and `@end` tokens must be preceded by `@if`
+    let source = "begin\npush.1\n@else\npush.2\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(3, 1);
+    assert_eq!(err, ParsingError::dangling_feature_else(&Token::new("@else", location)));
+
+    let source = "begin\npush.1\n@end\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(3, 1);
+    assert_eq!(err, ParsingError::dangling_feature_end(&Token::new("@end", location)));
+
+    // conditional blocks cannot cross boundaries of other blocks
+    let source = "begin\n@if debug\nif.true\npush.1\n@end\nend\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // feature flags must be valid labels
+    let source = "begin\n@if debug.mode\npush.1\n@end\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    let source = "begin\n@if 1debug\npush.1\n@end\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    let source = "begin\npush.1\n@if";
+    assert!(ProgramAst::parse(source).is_err());
+}
+
+#[test]
+fn test_ast_parsing_local_aliases() {
+    let source = "\
+    export.foo.3
+        alias.x=loc.0
+        alias.acc=loc.2
+        loc_store.x
+        push.&acc
+        if.true
+            loc_loadw.acc
+            locaddr.x
+        end
+    end
+    export.bar.1
+        alias.x=loc.0
+        loc_load.x
+    end";
+
+    // aliases must be resolved into the same AST as the one using plain local indexes
+    let expected = "\
+    export.foo.3
+        loc_store.0
+        locaddr.2
+        if.true
+            loc_loadw.2
+            locaddr.0
+        end
+    end
+    export.bar.1
+        loc_load.0
+    end";
+
+    // source locations differ, so the ASTs are compared via their serialized representations
+    let options = AstSerdeOptions::new(true);
+    let module = ModuleAst::parse(source).unwrap().to_bytes(options);
+    let expected = ModuleAst::parse(expected).unwrap().to_bytes(options);
+    assert_eq!(module, expected);
+}
+
+#[test]
+fn test_ast_parsing_local_aliases_errors() {
+    // aliases must be declared before they are used
+    let source = "proc.foo.1\npush.&x\nend\nbegin\nexec.foo\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(2, 1);
+    assert_eq!(err, ParsingError::undefined_alias(&Token::new("push.&x", location), "x"));
+
+    // aliases must refer to locals of the current procedure
+    let source = "proc.foo.1\nalias.x=loc.1\nend\nbegin\nexec.foo\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(2, 1);
+    let token = Token::new("alias.x=loc.1", location);
+    assert_eq!(err, ParsingError::alias_index_out_of_bounds(&token, 1, 1));
+
+    let source = "begin\nalias.x=loc.0\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // aliases names must be unique within a procedure and must not shadow constants
+    let source = "proc.foo.2\nalias.x=loc.0\nalias.x=loc.1\nend\nbegin\nexec.foo\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(3, 1);
+    assert_eq!(
+        err,
+        ParsingError::duplicate_alias_name(&Token::new("alias.x=loc.1", location), "x")
+    );
+
+    let source = "const.X=1\nproc.foo.1\nalias.X=loc.0\nend\nbegin\nexec.foo\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // aliases are scoped to the procedure in which they are declared
+    let source =
+        "proc.foo.1\nalias.x=loc.0\nend\nproc.bar.1\nloc_load.x\nend\nbegin\nexec.bar\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // alias declarations must be well-formed
+    let source = "proc.foo.1\nalias.x=mem.0\nend\nbegin\nexec.foo\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(2, 1);
+    assert_eq!(
+        err,
+        ParsingError::invalid_alias_target(&Token::new("alias.x=mem.0", location), "mem")
+    );
+
+    let source = "proc.foo.1\nalias.x\nend\nbegin\nexec.foo\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    let source = "proc.foo.1\nalias.1x=loc.0\nend\nbegin\nexec.foo\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    let source = "proc.foo.1\nalias.end=loc.0\nend\nbegin\nexec.foo\nend";
+    assert!(ProgramAst::parse(source).is_err());
+}
+
+#[test]
+fn test_ast_parsing_macros() {
+    let source = "\
+    const.ADDR=100
+    macro.store_pair.OFFSET
+        mem_store.OFFSET
+        push.OFFSET
+        add.1
+        mem_store
+    end
+    macro.pack.SHIFT
+        push.SHIFT
+        pow2
+        mul
+        add
+    end
+    macro.pack_and_store.SHIFT.OFFSET
+        !pack.SHIFT
+        if.true
+            !store_pair.OFFSET
+        end
+    end
+    proc.foo.1
+        alias.x=loc.0
+        !pack.32
+        loc_store.x
+    end
+    export.bar
+        !pack_and_store.8.ADDR
+        exec.foo
+    end";
+
+    // macro invocations must be expanded in place
+    let expected = "\
+    const.ADDR=100
+    proc.foo.1
+        push.32
+        pow2
+        mul
+        add
+        loc_store.0
+    end
+    export.bar
+        push.8
+        pow2
+        mul
+        add
+        if.true
+            mem_store.100
+            push.100
+            add.1
+            mem_store
+        end
+        exec.foo
+    end";
+
+    // source locations differ, so the ASTs are compared via their serialized representations
+    let options = AstSerdeOptions::new(true);
+    let module = ModuleAst::parse(source).unwrap().to_bytes(options);
+    let expected = ModuleAst::parse(expected).unwrap().to_bytes(options);
+    assert_eq!(module, expected);
+}
+
+#[test]
+fn test_ast_parsing_macros_errors() {
+    // macros must be declared before they are invoked
+    let source = "begin\n!foo\nend\nmacro.foo\nadd\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(2, 1);
+    assert_eq!(err, ParsingError::undefined_macro(&Token::new("!foo", location), "foo"));
+
+    // macros cannot invoke themselves
+    let source = "macro.foo\n!foo\nend\nbegin\n!foo\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(2, 1);
+    assert_eq!(err, ParsingError::undefined_macro(&Token::new("!foo", location), "foo"));
+
+    // macros must be invoked with the declared number of arguments
+    let source = "macro.foo.A.B\npush.A.B\nend\nbegin\n!foo.1\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(5, 1);
+    assert_eq!(
+        err,
+        ParsingError::wrong_macro_arity(&Token::new("!foo.1", location), "foo", 2, 1)
+    );
+
+    // macro names and parameters must be unique and must not shadow constants
+    let source = "macro.foo\nadd\nend\nmacro.foo\nmul\nend\nbegin\n!foo\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(4, 1);
+    assert_eq!(
+        err,
+        ParsingError::duplicate_macro_name(&Token::new("macro.foo", location), "foo")
+    );
+
+    let source = "macro.foo.A.A\npush.A\nend\nbegin\n!foo.1.2\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    let source = "const.A=1\nmacro.foo.A\npush.A\nend\nbegin\n!foo.2\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // macro bodies can see only procedures declared before the macro
+    let source = "macro.foo\nexec.bar\nend\nproc.bar\nadd\nend\nbegin\n!foo\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // local aliases are not visible from macro bodies and aliases declared in macro bodies are
+    // not visible after the invocation
+    let source = "macro.foo\nloc_load.x\nend\nproc.bar.1\nalias.x=loc.0\n!foo\nend\nbegin\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    let source = "macro.foo\nalias.x=loc.0\nend\nproc.bar.1\n!foo\nloc_load.x\nend\nbegin\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // macros must be declared outside of procedure bodies and must be terminated with `end`
+    let source = "begin\nmacro.foo\nadd\nend\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(2, 1);
+    assert_eq!(err, ParsingError::macro_inside_body(&Token::new("macro.foo", location)));
+
+    let source = "macro.foo\nif.true\nadd\nend\nbegin\n!foo\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(1, 1);
+    assert_eq!(err, ParsingError::unmatched_macro(&Token::new("macro.foo", location), "foo"));
+
+    // macro declarations must be well-formed
+    let source = "macro\nadd\nend\nbegin\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    let source = "macro.end\nadd\nend\nbegin\nend";
+    assert!(ProgramAst::parse(source).is_err());
+
+    let source = "macro.foo.a\nadd\nend\nbegin\nend";
+    assert!(ProgramAst::parse(source).is_err());
+}
+
 #[test]
 fn test_ast_parsing_simple_docs() {
     let source = "\
@@ -775,6 +1217,104 @@ fn test_ast_parsing_module_docs_fail() {
         .expect_err("Procedure comment is not immediately followed by a procedure declaration.");
 }
 
+#[test]
+fn test_ast_parsing_module_constant_docs() {
+    let source = "\
+    #! module doc
+
+    #! preceding doc
+    const.A=1
+    const.B=2 #! trailing doc
+    #! preceding doc
+    const.C=3 #! trailing doc
+    const.D=4
+
+    export.foo
+        push.A
+    end
+    ";
+    let module = ModuleAst::parse(source).unwrap();
+    assert_eq!(module.docs(), Some(&"module doc".to_string()));
+    assert_eq!(module.constant_docs("A"), Some("preceding doc"));
+    assert_eq!(module.constant_docs("B"), Some("trailing doc"));
+    assert_eq!(module.constant_docs("C"), Some("preceding doc\ntrailing doc"));
+    assert_eq!(module.constant_docs("D"), None);
+
+    // doc comments of constants survive serialization
+    let module_serialized = module.to_bytes(AstSerdeOptions::new(true));
+    let module_deserialized = ModuleAst::from_bytes(module_serialized.as_slice()).unwrap();
+    assert_eq!(clear_procs_loc_module(module), module_deserialized);
+
+    // trailing doc comments are allowed only for constant declarations
+    let source = "\
+    export.foo #! trailing doc
+        push.1
+    end
+    ";
+    ModuleAst::parse(source)
+        .expect_err("Procedure comment is not immediately followed by a procedure declaration.");
+}
+
+#[test]
+fn test_ast_module_interface() {
+    let source = "\
+    #! Module docs.
+
+    use.std::math::u64
+
+    #! Number of rounds.
+    const.ROUNDS=8
+    const.MASK=255 #! Mask of the lower byte.
+
+    #! Adds two elements.
+    #!
+    #! stack_in: [b, a] stack_out: [c]
+    export.foo
+        add
+    end
+
+    #! Internal procedures are not part of the interface.
+    proc.helper
+        push.ROUNDS drop
+    end
+
+    export.u64::checked_add->add64
+    ";
+    let interface = ModuleAst::parse(source).unwrap().interface();
+
+    assert_eq!(interface.docs(), Some("Module docs."));
+    let constants: Vec<_> =
+        interface.constants().iter().map(|c| (c.name.as_str(), c.value)).collect();
+    assert_eq!(constants, [("MASK", 255), ("ROUNDS", 8)]);
+
+    let exports = interface.exports();
+    assert_eq!(exports.len(), 2);
+    assert_eq!(exports[0].name.as_ref(), "add64");
+    assert!(exports[0].is_reexport);
+    assert_eq!(exports[1].name.as_ref(), "foo");
+    assert!(!exports[1].is_reexport);
+    assert_eq!(
+        exports[1].signature.as_ref().unwrap().to_string(),
+        "stack_in: [b, a] stack_out: [c]"
+    );
+
+    let expected = "\
+#! Module docs.
+
+#! Mask of the lower byte.
+const.MASK=255
+
+#! Number of rounds.
+const.ROUNDS=8
+
+export.add64 # re-exported
+
+#! Adds two elements.
+#! stack_in: [b, a] stack_out: [c]
+export.foo";
+    assert_eq!(interface.to_string(), expected);
+}
+
 // SERIALIZATION AND DESERIALIZATION TESTS
 // ================================================================================================
 
@@ -844,6 +1384,29 @@ fn test_ast_program_serde_control_flow() {
     assert_correct_program_serialization(source, true);
 }
 
+#[test]
+fn test_ast_program_serde_feature_if() {
+    let source = "\
+    proc.foo
+        @if debug
+            dup
+            assert
+        @end
+    end
+
+    begin
+        @if fast
+            push.1
+        @else
+            push.2
+            @if debug
+                exec.foo
+            @end
+        @end
+    end";
+    assert_correct_program_serialization(source, true);
+}
+
 #[test]
 fn assert_parsing_line_unmatched_begin() {
     let source = "\n\nbegin\npush.1.2\n\nadd mul".to_string();
@@ -896,7 +1459,17 @@ fn assert_parsing_line_invalid_op() {
     end";
     let err = ProgramAst::parse(source).err().unwrap();
     let location = SourceLocation::new(28, 13);
-    assert_eq!(err, ParsingError::invalid_op(&Token::new("u32overflowing_mulx", location)));
+    let suggestion = Suggestion::new(
+        location,
+        19,
+        "u32overflowing_mul",
+        "there is an instruction with a similar name: `u32overflowing_mul`",
+    );
+    assert_eq!(
+        err,
+        ParsingError::invalid_op(&Token::new("u32overflowing_mulx", location))
+            .with_suggestion(suggestion)
+    );
 }
 
 #[test]
@@ -915,6 +1488,59 @@ fn assert_parsing_line_unexpected_token() {
     assert_eq!(err, ParsingError::unexpected_token(&Token::new("mul", location), "begin"));
 }
 
+// DIAGNOSTICS TESTS
+// ================================================================================================
+
+#[test]
+fn test_diagnostic_invalid_param() {
+    let source = "begin\n    exp.u65\nend";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let expected = "\
+error: malformed instruction 'exp.u65', parameter u65 is invalid: parameter can at max be a u64 but found u65
+ --> 2:5
+  |
+2 |     exp.u65
+  |     ^^^^^^^
+  |         --- parameter can at max be a u64 but found u65
+";
+    assert_eq!(err.diagnostic().render(source), expected);
+}
+
+#[test]
+fn test_diagnostic_duplicate_proc_name() {
+    let source = "proc.foo add end\nproc.foo mul end\nbegin exec.foo end";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let expected = "\
+error: duplicate procedure name: foo
+ --> 2:1
+  |
+1 | proc.foo add end
+  | ---- previous definition of `foo` here
+2 | proc.foo mul end
+  | ^^^^^^^^
+";
+    assert_eq!(err.diagnostic().render(source), expected);
+}
+
+#[test]
+fn test_diagnostic_reserved_keyword_suggestion() {
+    let source = "proc.end add end\nbegin exec.end end";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let expected = "\
+error: invalid procedure name: 'end' is a reserved keyword, consider renaming it to 'end_'
+ --> 1:1
+  |
+1 | proc.end add end
+  | ^^^^^^^^
+  |
+  = help: consider renaming the procedure to `end_`
+  |
+1 | proc.end_ add end
+  |      ~~~~
+";
+    assert_eq!(err.diagnostic().render(source), expected);
+}
+
 #[test]
 fn test_ast_program_serde_imports_serialized() {
     let source = "\
@@ -971,6 +1597,232 @@ fn test_ast_module_serde_imports_not_serialized() {
     assert_correct_module_serialization(source, false);
 }
 
+#[test]
+fn test_ast_module_serde_constants() {
+    let source = "\
+    const.A=1<<4
+    const.B=A|0x3
+
+    export.foo
+        push.A
+        push.B
+    end";
+    assert_correct_module_serialization(source, false);
+
+    let module = ModuleAst::parse(source).unwrap();
+    let expected = BTreeMap::from([("A".to_string(), 16), ("B".to_string(), 19)]);
+    assert_eq!(module.constants(), &expected);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_ast_module_json() {
+    let source = "\
+    #! Module docs.
+
+    use.std::math::u64
+
+    #! Constant docs.
+    const.A=1<<4
+
+    export.u64::checked_add->add
+
+    proc.bar
+        @if blake3
+            add
+        @else
+            sub
+        @end
+    end
+
+    #! Foo docs.
+    export.foo.2
+        push.A push.1.2.3 loc_store.1
+        if.true
+            exec.u64::wrapping_add
+        else
+            repeat.3 mul.5 end
+        end
+        while.true
+            exec.bar
+        end
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    let json = module.to_json();
+    assert_eq!(clear_procs_loc_module(module), ModuleAst::from_json(&json).unwrap());
+
+    // instructions are encoded by the names of their opcodes
+    let module = ModuleAst::parse("export.foo add.7 eqw end").unwrap();
+    let json: serde_json::Value = serde_json::from_str(&module.to_json()).unwrap();
+    let body = &json["procedures"][0]["body"];
+    assert_eq!(body[0]["op"], "AddImm");
+    assert_eq!(body[0]["imm"], "0700000000000000");
+    assert_eq!(body[1]["op"], "Eqw");
+    assert_eq!(body[1]["imm"], "");
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_ast_module_json_invalid() {
+    let module = ModuleAst::parse("export.foo add.7 end").unwrap();
+    let json = module.to_json();
+    assert!(ModuleAst::from_json(&json).is_ok());
+
+    assert!(ModuleAst::from_json("{}").is_err());
+    assert!(ModuleAst::from_json(&json.replace("AddImm", "Foo")).is_err());
+    assert!(ModuleAst::from_json(&json.replace("0700000000000000", "07")).is_err());
+    assert!(ModuleAst::from_json(&json.replace("0700000000000000", "070000000000000000")).is_err());
+
+    let version = format!("\"format_version\":{AST_FORMAT_VERSION}");
+    let future_version = format!("\"format_version\":{}", AST_FORMAT_VERSION + 1);
+    assert!(ModuleAst::from_json(&json.replace(&version, &future_version)).is_err());
+}
+
+#[test]
+fn test_ast_program_serde_compact_lengths() {
+    // the header byte carries the format version; the number of local procedures (0), the number
+    // of body locals (0), and the body length (1) are encoded as single-byte variable-length
+    // integers
+    let program = ProgramAst::parse("begin add end").unwrap();
+    let bytes = program.to_bytes(AstSerdeOptions::new(false));
+    assert_eq!(
+        bytes,
+        vec![AST_FORMAT_VERSION << 1, 0b1, 0b1, 0b11, Instruction::Add.to_bytes()[0]]
+    );
+}
+
+#[test]
+fn test_ast_serde_unsupported_format_version() {
+    let program = ProgramAst::parse("begin add end").unwrap();
+    let mut bytes = program.to_bytes(AstSerdeOptions::new(true));
+    assert!(ProgramAst::from_bytes(&bytes).is_ok());
+
+    // headers written before the format version was introduced only contained a boolean flag
+    bytes[0] = 1;
+    assert!(ProgramAst::from_bytes(&bytes).is_err());
+
+    let module = ModuleAst::parse("export.foo add end").unwrap();
+    let mut bytes = module.to_bytes(AstSerdeOptions::new(false));
+    assert!(ModuleAst::from_bytes(&bytes).is_ok());
+
+    bytes[0] = 0;
+    assert!(ModuleAst::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_ast_serde_unsupported_future_format_version() {
+    let program = ProgramAst::parse("begin add end").unwrap();
+    let mut bytes = program.to_bytes(AstSerdeOptions::new(false));
+    bytes[0] = (AST_FORMAT_VERSION + 1) << 1;
+    assert!(ProgramAst::from_bytes(&bytes).is_err());
+}
+
+/// `begin push.1 if.true add else mul end end` serialized with AST format version 3.
+#[rustfmt::skip]
+const V3_PROGRAM_FIXTURE: [u8; 11] = [
+    0b0000_0110, // format version 3, imports not serialized
+    0b1,         // no local procedures
+    0b1,         // no body locals
+    0b101,       // 2 body nodes
+    177, 1,      // push.1
+    253,         // if.true
+    0b11, 8,     // add
+    0b11, 12,    // else mul
+];
+
+/// `export.foo add end` serialized with AST format version 3.
+#[rustfmt::skip]
+const V3_MODULE_FIXTURE: [u8; 15] = [
+    0b0000_0110,    // format version 3, imports not serialized
+    0b1,                 // no docs
+    0b1,                 // no re-exported procedures
+    0b11,                // 1 local procedure
+    3, b'f', b'o', b'o', // procedure name
+    0b1,                 // no procedure docs
+    1,                   // exported
+    0b1,                 // no locals
+    0b11, 8,             // add
+    0,                   // no data segment
+    0b1,                 // no constants
+];
+
+#[test]
+fn test_ast_serde_format_compatibility() {
+    const _: () = assert!(AST_MIN_FORMAT_VERSION <= 3);
+
+    // blobs serialized with older format versions must still be readable
+    let source = "begin push.1 if.true add else mul end end";
+    let expected = clear_procs_loc_program(ProgramAst::parse(source).unwrap());
+    let mut program = ProgramAst::from_bytes(&V3_PROGRAM_FIXTURE).unwrap();
+    program.clear_imports();
+    let mut expected_program = expected.clone();
+    expected_program.clear_imports();
+    assert_eq!(program, expected_program);
+
+    let mut expected_module =
+        clear_procs_loc_module(ModuleAst::parse("export.foo add end").unwrap());
+    expected_module.clear_imports();
+    let module = ModuleAst::from_bytes(&V3_MODULE_FIXTURE).unwrap();
+    assert_eq!(module, expected_module);
+
+    // the encoding of existing nodes must not change; only the header carries the new version
+    let bytes = expected.to_bytes(AstSerdeOptions::new(false));
+    assert_eq!(bytes[0], AST_FORMAT_VERSION << 1);
+    assert_eq!(bytes[1..], V3_PROGRAM_FIXTURE[1..]);
+
+    let bytes = expected_module.to_bytes(AstSerdeOptions::new(false));
+    assert_eq!(bytes[0], AST_FORMAT_VERSION << 1);
+    assert_eq!(bytes[1..], V3_MODULE_FIXTURE[1..]);
+}
+
+/// `const.A=1 export.foo add end` serialized with AST format version 4.
+#[rustfmt::skip]
+const V4_MODULE_WITH_CONSTANT_FIXTURE: [u8; 25] = [
+    0b0000_1000,            // format version 4, imports not serialized
+    0b1,                    // no docs
+    0b1,                    // no re-exported procedures
+    0b11,                   // 1 local procedure
+    3, b'f', b'o', b'o',    // procedure name
+    0b1,                    // no procedure docs
+    1,                      // exported
+    0b1,                    // no locals
+    0b11, 8,                // add
+    0,                      // no data segment
+    0b11,                   // 1 constant
+    1, b'A',                // constant name
+    1, 0, 0, 0, 0, 0, 0, 0, // constant value
+];
+
+#[test]
+fn test_ast_serde_constant_docs_compatibility() {
+    // constants serialized before doc comments of constants were encoded must still be readable
+    let module = ModuleAst::from_bytes(&V4_MODULE_WITH_CONSTANT_FIXTURE).unwrap();
+    let mut expected =
+        clear_procs_loc_module(ModuleAst::parse("const.A=1 export.foo add end").unwrap());
+    expected.clear_imports();
+    assert_eq!(module, expected);
+
+    // with the current format version, the doc comments follow the value of each constant
+    let bytes = expected.to_bytes(AstSerdeOptions::new(false));
+    assert_eq!(bytes[0], AST_FORMAT_VERSION << 1);
+    let num_bytes = V4_MODULE_WITH_CONSTANT_FIXTURE.len();
+    assert_eq!(bytes[1..num_bytes], V4_MODULE_WITH_CONSTANT_FIXTURE[1..]);
+    assert_eq!(bytes[num_bytes..], [0b1]); // no constant docs
+}
+
+#[test]
+fn test_ast_serde_extended_opcodes() {
+    let header = AST_FORMAT_VERSION << 1;
+
+    // opcodes which fit into a single byte must not use the extended encoding
+    let bytes = [header, 0b1, 0b1, 0b11, 0xfb, 8, 0];
+    assert!(ProgramAst::from_bytes(&bytes).is_err());
+
+    // unknown extended opcodes are rejected
+    let bytes = [header, 0b1, 0b1, 0b11, 0xfb, 0xff, 0xff];
+    assert!(ProgramAst::from_bytes(&bytes).is_err());
+}
+
 #[test]
 fn test_repeat_with_constant_count() {
     let source = "\
@@ -1003,6 +1855,93 @@ fn test_repeat_with_constant_count() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_visitor() {
+    #[derive(Default)]
+    struct Counter {
+        procedures: usize,
+        control_flow: usize,
+        instructions: usize,
+    }
+
+    impl Visitor for Counter {
+        fn visit_procedure(&mut self, procedure: &ProcedureAst) {
+            self.procedures += 1;
+            visit::walk_procedure(self, procedure);
+        }
+
+        fn visit_node(&mut self, node: &Node) {
+            if !matches!(node, Node::Instruction(_)) {
+                self.control_flow += 1;
+            }
+            visit::walk_node(self, node);
+        }
+
+        fn visit_instruction(&mut self, _instruction: &Instruction) {
+            self.instructions += 1;
+        }
+    }
+
+    let source = "\
+    proc.foo
+        add
+        mul
+    end
+
+    begin
+        push.1
+        if.true
+            exec.foo
+        else
+            repeat.2
+                drop
+            end
+        end
+        while.true
+            push.0
+        end
+    end";
+    let mut counter = Counter::default();
+    counter.visit_program(&ProgramAst::parse(source).unwrap());
+    assert_eq!(counter.procedures, 1);
+    assert_eq!(counter.control_flow, 3);
+    assert_eq!(counter.instructions, 6);
+
+    let source = "export.foo add end proc.bar mul if.true swap end end";
+    let mut counter = Counter::default();
+    counter.visit_module(&ModuleAst::parse(source).unwrap());
+    assert_eq!(counter.procedures, 2);
+    assert_eq!(counter.control_flow, 1);
+    assert_eq!(counter.instructions, 3);
+}
+
+#[test]
+fn test_ast_mut_visitor() {
+    struct AddToMul;
+
+    impl MutVisitor for AddToMul {
+        fn visit_instruction_mut(&mut self, instruction: &mut Instruction) {
+            if *instruction == Instruction::Add {
+                *instruction = Instruction::Mul;
+            }
+        }
+    }
+
+    let source = "proc.foo add end begin push.1 push.2 add if.true add end exec.foo end";
+    let mut program = ProgramAst::parse(source).unwrap();
+    AddToMul.visit_program_mut(&mut program);
+
+    // source locations of rewritten nodes are preserved
+    let source = "proc.foo mul end begin push.1 push.2 mul if.true mul end exec.foo end";
+    assert_eq!(program, ProgramAst::parse(source).unwrap());
+
+    let source = "export.foo add end proc.bar push.1 while.true add end end";
+    let mut module = ModuleAst::parse(source).unwrap();
+    AddToMul.visit_module_mut(&mut module);
+    let source = "export.foo mul end proc.bar push.1 while.true mul end end";
+    assert_eq!(module, ModuleAst::parse(source).unwrap());
+}
+
 fn assert_program_output(source: &str, procedures: LocalProcMap, body: Vec<Node>) {
     let program = ProgramAst::parse(source).unwrap();
     assert_eq!(program.body.nodes(), body);
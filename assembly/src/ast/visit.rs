@@ -0,0 +1,182 @@
+//! Traversal of Miden assembly ASTs.
+//!
+//! The [Visitor] trait can be used to inspect the nodes of a parsed program or module, and the
+//! [MutVisitor] trait can be used to rewrite them in place. Both traits provide a method for every
+//! kind of AST element; the default implementations of these methods descend into the children of
+//! the element by calling the corresponding `walk_*` function from this module. Thus, an
+//! implementation needs to override only the methods for the elements it is interested in, and is
+//! not affected by new instructions or node kinds being added to the AST.
+//!
+//! When overriding a method, call the corresponding `walk_*` function from the overridden method
+//! to keep traversing the children of the element.
+use super::{CodeBody, Instruction, ModuleAst, Node, ProcedureAst, ProgramAst};
+
+// VISITOR
+// ================================================================================================
+
+/// A visitor over an immutable AST.
+///
+/// The nodes are visited in the order in which they appear in the source code, with procedures
+/// visited before the program body.
+pub trait Visitor {
+    /// Visits a program, including its local procedures and its body.
+    fn visit_program(&mut self, program: &ProgramAst) {
+        walk_program(self, program);
+    }
+
+    /// Visits a module, including all of its local procedures.
+    fn visit_module(&mut self, module: &ModuleAst) {
+        walk_module(self, module);
+    }
+
+    /// Visits a procedure, including its body.
+    fn visit_procedure(&mut self, procedure: &ProcedureAst) {
+        walk_procedure(self, procedure);
+    }
+
+    /// Visits a code body, including all of its nodes.
+    fn visit_code_body(&mut self, body: &CodeBody) {
+        walk_code_body(self, body);
+    }
+
+    /// Visits a node, including the code bodies of control flow nodes.
+    fn visit_node(&mut self, node: &Node) {
+        walk_node(self, node);
+    }
+
+    /// Visits a single instruction.
+    fn visit_instruction(&mut self, _instruction: &Instruction) {}
+}
+
+/// Visits the local procedures and the body of the specified program.
+pub fn walk_program<V: Visitor + ?Sized>(visitor: &mut V, program: &ProgramAst) {
+    for procedure in program.local_procs.iter() {
+        visitor.visit_procedure(procedure);
+    }
+    visitor.visit_code_body(&program.body);
+}
+
+/// Visits the local procedures of the specified module.
+pub fn walk_module<V: Visitor + ?Sized>(visitor: &mut V, module: &ModuleAst) {
+    for procedure in module.local_procs.iter() {
+        visitor.visit_procedure(procedure);
+    }
+}
+
+/// Visits the body of the specified procedure.
+pub fn walk_procedure<V: Visitor + ?Sized>(visitor: &mut V, procedure: &ProcedureAst) {
+    visitor.visit_code_body(&procedure.body);
+}
+
+/// Visits all nodes of the specified code body.
+pub fn walk_code_body<V: Visitor + ?Sized>(visitor: &mut V, body: &CodeBody) {
+    for node in body.nodes() {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits the instruction of an instruction node, or the code bodies of a control flow node.
+pub fn walk_node<V: Visitor + ?Sized>(visitor: &mut V, node: &Node) {
+    match node {
+        Node::Instruction(instruction) => visitor.visit_instruction(instruction),
+        Node::IfElse {
+            true_case,
+            false_case,
+        }
+        | Node::IfFeature {
+            true_case,
+            false_case,
+            ..
+        } => {
+            visitor.visit_code_body(true_case);
+            visitor.visit_code_body(false_case);
+        }
+        Node::Repeat { body, .. } | Node::While { body } => visitor.visit_code_body(body),
+    }
+}
+
+// MUTABLE VISITOR
+// ================================================================================================
+
+/// A visitor which can rewrite an AST in place.
+///
+/// Source locations are tracked per node, and thus a rewrite which replaces a node with a
+/// different node keeps the location of the original node. Information derived from the AST
+/// during parsing (e.g., the set of imported procedures invoked by a program) is not updated
+/// when a node is rewritten.
+pub trait MutVisitor {
+    /// Visits a program, including its local procedures and its body.
+    fn visit_program_mut(&mut self, program: &mut ProgramAst) {
+        walk_program_mut(self, program);
+    }
+
+    /// Visits a module, including all of its local procedures.
+    fn visit_module_mut(&mut self, module: &mut ModuleAst) {
+        walk_module_mut(self, module);
+    }
+
+    /// Visits a procedure, including its body.
+    fn visit_procedure_mut(&mut self, procedure: &mut ProcedureAst) {
+        walk_procedure_mut(self, procedure);
+    }
+
+    /// Visits a code body, including all of its nodes.
+    fn visit_code_body_mut(&mut self, body: &mut CodeBody) {
+        walk_code_body_mut(self, body);
+    }
+
+    /// Visits a node, including the code bodies of control flow nodes.
+    fn visit_node_mut(&mut self, node: &mut Node) {
+        walk_node_mut(self, node);
+    }
+
+    /// Visits a single instruction.
+    fn visit_instruction_mut(&mut self, _instruction: &mut Instruction) {}
+}
+
+/// Visits the local procedures and the body of the specified program.
+pub fn walk_program_mut<V: MutVisitor + ?Sized>(visitor: &mut V, program: &mut ProgramAst) {
+    for procedure in program.local_procs.iter_mut() {
+        visitor.visit_procedure_mut(procedure);
+    }
+    visitor.visit_code_body_mut(&mut program.body);
+}
+
+/// Visits the local procedures of the specified module.
+pub fn walk_module_mut<V: MutVisitor + ?Sized>(visitor: &mut V, module: &mut ModuleAst) {
+    for procedure in module.local_procs.iter_mut() {
+        visitor.visit_procedure_mut(procedure);
+    }
+}
+
+/// Visits the body of the specified procedure.
+pub fn walk_procedure_mut<V: MutVisitor + ?Sized>(visitor: &mut V, procedure: &mut ProcedureAst) {
+    visitor.visit_code_body_mut(&mut procedure.body);
+}
+
+/// Visits all nodes of the specified code body.
+pub fn walk_code_body_mut<V: MutVisitor + ?Sized>(visitor: &mut V, body: &mut CodeBody) {
+    for node in body.nodes_mut() {
+        visitor.visit_node_mut(node);
+    }
+}
+
+/// Visits the instruction of an instruction node, or the code bodies of a control flow node.
+pub fn walk_node_mut<V: MutVisitor + ?Sized>(visitor: &mut V, node: &mut Node) {
+    match node {
+        Node::Instruction(instruction) => visitor.visit_instruction_mut(instruction),
+        Node::IfElse {
+            true_case,
+            false_case,
+        }
+        | Node::IfFeature {
+            true_case,
+            false_case,
+            ..
+        } => {
+            visitor.visit_code_body_mut(true_case);
+            visitor.visit_code_body_mut(false_case);
+        }
+        Node::Repeat { body, .. } | Node::While { body } => visitor.visit_code_body_mut(body),
+    }
+}
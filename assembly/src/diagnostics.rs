@@ -0,0 +1,304 @@
+use super::ast::SourceLocation;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+
+// SEVERITY
+// ================================================================================================
+
+/// The severity of a [Diagnostic].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+// LABEL
+// ================================================================================================
+
+/// A span of source code annotated with a message.
+///
+/// The span starts at the specified location and covers `len` characters of the line on which it
+/// starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    location: SourceLocation,
+    len: usize,
+    message: String,
+}
+
+impl Label {
+    /// Returns a new label covering `len` characters starting at the specified location.
+    pub fn new(location: SourceLocation, len: usize, message: impl Into<String>) -> Self {
+        Self {
+            location,
+            len,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the location at which the labeled span starts.
+    pub const fn location(&self) -> &SourceLocation {
+        &self.location
+    }
+
+    /// Returns the number of characters covered by the labeled span.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the labeled span does not cover any characters.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the message attached to this label; the message may be an empty string.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+// SUGGESTION
+// ================================================================================================
+
+/// A suggested fix for a [Diagnostic], describing how a span of the source should be replaced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    span: Label,
+    replacement: String,
+}
+
+impl Suggestion {
+    /// Returns a new suggestion to replace `len` characters starting at the specified location
+    /// with the `replacement` string.
+    pub fn new(
+        location: SourceLocation,
+        len: usize,
+        replacement: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            span: Label::new(location, len, message),
+            replacement: replacement.into(),
+        }
+    }
+
+    /// Returns the location of the span to be replaced.
+    pub const fn location(&self) -> &SourceLocation {
+        self.span.location()
+    }
+
+    /// Returns the number of characters to be replaced.
+    pub const fn len(&self) -> usize {
+        self.span.len()
+    }
+
+    /// Returns true if this suggestion inserts the replacement without removing any characters.
+    pub const fn is_empty(&self) -> bool {
+        self.span.is_empty()
+    }
+
+    /// Returns the string with which the span should be replaced.
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Returns the message describing this suggestion.
+    pub fn message(&self) -> &str {
+        self.span.message()
+    }
+}
+
+// DIAGNOSTIC
+// ================================================================================================
+
+/// A structured description of a problem found in Miden assembly source code.
+///
+/// A diagnostic consists of a message, a primary label pointing at the place where the problem
+/// was detected, any number of secondary labels pointing at related places in the source, and
+/// optional suggestions on how the problem could be fixed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    severity: Severity,
+    message: String,
+    primary: Label,
+    secondary: Vec<Label>,
+    suggestions: Vec<Suggestion>,
+}
+
+impl Diagnostic {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new diagnostic with the specified severity, message, and primary label.
+    pub fn new(severity: Severity, message: impl Into<String>, primary: Label) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            primary,
+            secondary: Vec::new(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Returns a new error diagnostic with the specified message and primary label.
+    pub fn error(message: impl Into<String>, primary: Label) -> Self {
+        Self::new(Severity::Error, message, primary)
+    }
+
+    /// Returns a new warning diagnostic with the specified message and primary label.
+    pub fn warning(message: impl Into<String>, primary: Label) -> Self {
+        Self::new(Severity::Warning, message, primary)
+    }
+
+    /// Adds a secondary label to this diagnostic.
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.secondary.push(label);
+        self
+    }
+
+    /// Adds a suggested fix to this diagnostic.
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the severity of this diagnostic.
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the message of this diagnostic.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the label pointing at the place where the problem was detected.
+    pub const fn primary_label(&self) -> &Label {
+        &self.primary
+    }
+
+    /// Returns the labels pointing at places in the source related to the problem.
+    pub fn secondary_labels(&self) -> &[Label] {
+        &self.secondary
+    }
+
+    /// Returns the suggested fixes for the problem.
+    pub fn suggestions(&self) -> &[Suggestion] {
+        &self.suggestions
+    }
+
+    // RENDERING
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders this diagnostic against the source code it was generated for.
+    ///
+    /// The output contains the message followed by the source lines referenced by the labels of
+    /// this diagnostic, with the labeled spans underlined and annotated with label messages.
+    /// Suggestions are rendered as the affected source lines with the replacements applied.
+    pub fn render(&self, source: &str) -> String {
+        let lines: Vec<&str> = source.lines().collect();
+
+        let mut labels: Vec<(&Label, bool)> = Vec::with_capacity(self.secondary.len() + 1);
+        labels.push((&self.primary, true));
+        labels.extend(self.secondary.iter().map(|label| (label, false)));
+        labels.sort_by_key(|(label, _)| *label.location());
+
+        let max_line = labels
+            .iter()
+            .map(|(label, _)| label.location().line())
+            .chain(self.suggestions.iter().map(|s| s.location().line()))
+            .max()
+            .unwrap_or(1);
+        let gutter = " ".repeat(max_line.to_string().len());
+
+        let mut out = String::new();
+        writeln!(out, "{}: {}", self.severity, self.message).unwrap();
+        let location = self.primary.location();
+        writeln!(out, "{gutter}--> {}:{}", location.line(), location.column()).unwrap();
+        writeln!(out, "{gutter} |").unwrap();
+
+        let mut prev_line = None;
+        for (label, is_primary) in labels.iter() {
+            let line = label.location().line();
+            if prev_line != Some(line) {
+                if matches!(prev_line, Some(prev) if line > prev + 1) {
+                    writeln!(out, "{gutter}...").unwrap();
+                }
+                let text = source_line(&lines, line);
+                writeln!(out, "{line:>width$} | {text}", width = gutter.len()).unwrap();
+                prev_line = Some(line);
+            }
+            let marker = if *is_primary { "^" } else { "-" };
+            let padding = " ".repeat(label.location().column().saturating_sub(1) as usize);
+            let underline = marker.repeat(label.len().max(1));
+            let message = label.message();
+            if message.is_empty() {
+                writeln!(out, "{gutter} | {padding}{underline}").unwrap();
+            } else {
+                writeln!(out, "{gutter} | {padding}{underline} {message}").unwrap();
+            }
+        }
+
+        for suggestion in self.suggestions.iter() {
+            let line = suggestion.location().line();
+            let text = source_line(&lines, line);
+            let start = suggestion.location().column().saturating_sub(1) as usize;
+            let prefix: String = text.chars().take(start).collect();
+            let suffix: String = text.chars().skip(start + suggestion.len()).collect();
+
+            writeln!(out, "{gutter} |").unwrap();
+            writeln!(out, "{gutter} = help: {}", suggestion.message()).unwrap();
+            writeln!(out, "{gutter} |").unwrap();
+            writeln!(
+                out,
+                "{line:>width$} | {prefix}{}{suffix}",
+                suggestion.replacement(),
+                width = gutter.len()
+            )
+            .unwrap();
+            let padding = " ".repeat(prefix.chars().count());
+            let underline = "~".repeat(suggestion.replacement().chars().count().max(1));
+            writeln!(out, "{gutter} | {padding}{underline}").unwrap();
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} at {}", self.severity, self.message, self.primary.location)?;
+        for suggestion in self.suggestions.iter() {
+            write!(f, "\n  help: {}", suggestion.message())?;
+        }
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the line with the specified 1-based number, or an empty string if the source does not
+/// contain such a line.
+fn source_line<'a>(lines: &[&'a str], line: u32) -> &'a str {
+    (line as usize)
+        .checked_sub(1)
+        .and_then(|idx| lines.get(idx))
+        .copied()
+        .unwrap_or("")
+}
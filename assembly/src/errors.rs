@@ -1,8 +1,10 @@
 use super::{
-    ast::ProcReExport, crypto::hash::RpoDigest, tokens::SourceLocation, KernelError,
-    LibraryNamespace, ProcedureId, ProcedureName, Token,
+    ast::ProcReExport, crypto::hash::RpoDigest, tokens::SourceLocation, Diagnostic, KernelError,
+    Label, LibraryNamespace, LibraryPath, MastNodeId, Operation, ProcedureId, ProcedureName,
+    Suggestion, Token,
 };
 use alloc::{
+    boxed::Box,
     string::{String, ToString},
     vec::Vec,
 };
@@ -22,22 +24,28 @@ pub enum AssemblyError {
     DivisionByZero,
     DuplicateProcId(ProcedureId),
     DuplicateProcName(String, String),
+    EmptySpanNode,
     ExportedProcInProgram(String),
     ImportedProcModuleNotFound(ProcedureId, String),
     ImportedProcNotFoundInModule(ProcedureId, String),
     InvalidCacheLock,
+    InvalidSpanNodeOperation(String),
     InvalidProgramAssemblyContext,
     Io(String),
     KernelError(KernelError),
     KernelProcNotFound(ProcedureId),
     LibraryError(String),
     LocalProcNotFound(u16, String),
+    MastNodeNotFound(MastNodeId),
+    ModuleCacheNotSet,
     ParamOutOfBounds(u64, u64, u64),
     ParsingError(String),
     PhantomCallsNotAllowed(RpoDigest),
+    PinnedProcRootMismatch(RpoDigest, RpoDigest),
     ProcedureNameError(String),
     ReExportedProcModuleNotFound(ProcReExport),
     SysCallInKernel(String),
+    SysCallTargetNotInKernel(RpoDigest),
 }
 
 impl AssemblyError {
@@ -72,6 +80,14 @@ impl AssemblyError {
         Self::DuplicateProcId(*proc_id)
     }
 
+    pub fn empty_span_node() -> Self {
+        Self::EmptySpanNode
+    }
+
+    pub fn control_op_in_span_node(op: &Operation) -> Self {
+        Self::InvalidSpanNodeOperation(op.to_string())
+    }
+
     pub fn exported_proc_in_program(proc_name: &str) -> Self {
         Self::ExportedProcInProgram(proc_name.to_string())
     }
@@ -99,6 +115,10 @@ impl AssemblyError {
         Self::LocalProcNotFound(proc_idx, module_path.to_string())
     }
 
+    pub fn mast_node_not_found(node_id: MastNodeId) -> Self {
+        Self::MastNodeNotFound(node_id)
+    }
+
     pub fn param_out_of_bounds(value: u64, min: u64, max: u64) -> Self {
         Self::ParamOutOfBounds(value, min, max)
     }
@@ -107,10 +127,18 @@ impl AssemblyError {
         Self::PhantomCallsNotAllowed(mast_root)
     }
 
+    pub fn pinned_proc_root_mismatch(expected: RpoDigest, actual: RpoDigest) -> Self {
+        Self::PinnedProcRootMismatch(expected, actual)
+    }
+
     pub fn syscall_in_kernel(kernel_proc_name: &str) -> Self {
         Self::SysCallInKernel(kernel_proc_name.to_string())
     }
 
+    pub fn syscall_target_not_in_kernel(mast_root: RpoDigest) -> Self {
+        Self::SysCallTargetNotInKernel(mast_root)
+    }
+
     pub fn invalid_cache_lock() -> Self {
         Self::InvalidCacheLock
     }
@@ -146,20 +174,26 @@ impl fmt::Display for AssemblyError {
             DivisionByZero => write!(f, "division by zero"),
             DuplicateProcId(proc_id) => write!(f, "duplicate proc id {proc_id}"),
             DuplicateProcName(proc_name, module_path) => write!(f, "duplicate proc name '{proc_name}' in module {module_path}"),
+            EmptySpanNode => write!(f, "span node must contain at least one operation"),
             ExportedProcInProgram(proc_name) => write!(f, "exported procedure '{proc_name}' in executable program"),
             ImportedProcModuleNotFound(proc_id, proc_name) => write!(f, "module for imported procedure `{proc_name}` with ID {proc_id} not found"),
             ImportedProcNotFoundInModule(proc_id, module_path) => write!(f, "imported procedure {proc_id} not found in module {module_path}"),
             InvalidCacheLock => write!(f, "an attempt was made to lock a borrowed procedures cache"),
+            InvalidSpanNodeOperation(op) => write!(f, "control flow operation {op} cannot be used in a span node"),
             InvalidProgramAssemblyContext => write!(f, "assembly context improperly initialized for program compilation"),
             Io(description) => write!(f, "I/O error: {description}"),
             KernelError(error) => write!(f, "{}", error),
             KernelProcNotFound(proc_id) => write!(f, "procedure {proc_id} not found in kernel"),
             LibraryError(err) | ParsingError(err) | ProcedureNameError(err) => write!(f, "{err}"),
             LocalProcNotFound(proc_idx, module_path) => write!(f, "procedure at index {proc_idx} not found in module {module_path}"),
+            MastNodeNotFound(node_id) => write!(f, "MAST node {node_id} not found in MAST forest"),
+            ModuleCacheNotSet => write!(f, "module cache has not been set for the assembler"),
             ParamOutOfBounds(value, min, max) => write!(f, "parameter value must be greater than or equal to {min} and less than or equal to {max}, but was {value}"),
             PhantomCallsNotAllowed(mast_root) => write!(f, "cannot call phantom procedure with MAST root {mast_root}: phantom calls not allowed"),
+            PinnedProcRootMismatch(expected, actual) => write!(f, "invoked procedure has MAST root {actual}, but its MAST root was pinned to {expected}"),
             ReExportedProcModuleNotFound(reexport) => write!(f, "re-exported proc {} with id {} not found", reexport.name(), reexport.proc_id()),
             SysCallInKernel(proc_name) => write!(f, "syscall instruction used in kernel procedure '{proc_name}'"),
+            SysCallTargetNotInKernel(mast_root) => write!(f, "procedure with MAST root {mast_root} invoked via syscall not found in kernel"),
         }
     }
 }
@@ -178,11 +212,17 @@ impl std::error::Error for AssemblyError {}
 // ================================================================================================
 
 /// An error which can be generated while parsing a Miden assembly source code into an AST.
+///
+/// Besides the message and the location of the offending token, the error may carry labels
+/// pointing at related places in the source and a suggested fix. Use [ParsingError::diagnostic()]
+/// to obtain a [Diagnostic] which can be rendered against the source code.
 #[derive(Clone, PartialEq, Eq)]
 pub struct ParsingError {
     message: String,
     location: SourceLocation,
     op: String,
+    labels: Vec<Label>,
+    suggestion: Option<Box<Suggestion>>,
 }
 
 impl ParsingError {
@@ -194,6 +234,8 @@ impl ParsingError {
             message: "source code cannot be an empty string".to_string(),
             location: SourceLocation::default(),
             op: "".to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -202,6 +244,8 @@ impl ParsingError {
             message: "unexpected EOF".to_string(),
             location,
             op: "".to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -210,6 +254,8 @@ impl ParsingError {
             message: format!("unexpected token: expected '{expected}' but was '{token}'"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -220,6 +266,20 @@ impl ParsingError {
             message: format!("duplicate constant name: '{label}'"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn const_docs_too_long(token: &Token, doc_len: usize, max_len: usize) -> Self {
+        ParsingError {
+            message: format!(
+                "constant doc comments cannot exceed {max_len} bytes, but was {doc_len}"
+            ),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -228,6 +288,8 @@ impl ParsingError {
             message: format!("invalid constant name: {err}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -238,6 +300,8 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -246,6 +310,8 @@ impl ParsingError {
             message: format!("invalid constant declaration: `{token}` - constants can only be defined below imports and above procedure / program bodies"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -254,6 +320,8 @@ impl ParsingError {
             message: format!("constant used in operation `{token}` not found"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -264,6 +332,8 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -272,6 +342,166 @@ impl ParsingError {
             message: format!("constant expression {token} contains division by zero"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    // LOCAL ALIASES
+    // --------------------------------------------------------------------------------------------
+    pub fn duplicate_alias_name(token: &Token, label: &str) -> Self {
+        ParsingError {
+            message: format!("duplicate local alias name: '{label}'"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn invalid_alias_name(token: &Token, err: LabelError) -> Self {
+        ParsingError {
+            message: format!("invalid local alias name: {err}"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn invalid_alias_target(token: &Token, target: &str) -> Self {
+        ParsingError {
+            message: format!("invalid local alias target `{target}` - expected `loc.<index>`"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn alias_index_out_of_bounds(token: &Token, index: u16, num_locals: u16) -> Self {
+        ParsingError {
+            message: format!(
+                "local alias refers to local {index}, but the procedure has {num_locals} locals"
+            ),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn undefined_alias(token: &Token, label: &str) -> Self {
+        ParsingError {
+            message: format!("undefined local alias: '{label}'"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    // MACROS
+    // --------------------------------------------------------------------------------------------
+    pub fn duplicate_macro_name(token: &Token, label: &str) -> Self {
+        ParsingError {
+            message: format!("duplicate macro name: '{label}'"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn invalid_macro_name(token: &Token, err: LabelError) -> Self {
+        ParsingError {
+            message: format!("invalid macro name: {err}"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn duplicate_macro_param(token: &Token, label: &str) -> Self {
+        ParsingError {
+            message: format!("macro parameter '{label}' is already defined"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn invalid_macro_param(token: &Token, err: LabelError) -> Self {
+        ParsingError {
+            message: format!("invalid macro parameter name: {err}"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn unmatched_macro(token: &Token, label: &str) -> Self {
+        ParsingError {
+            message: format!("macro '{label}' has no matching end"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn unmatched_data(token: &Token, label: &str) -> Self {
+        ParsingError {
+            message: format!("data section '{label}' has no matching end"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn invalid_data_segment(token: &Token, err: &str) -> Self {
+        ParsingError {
+            message: format!("invalid data section: {err}"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn macro_inside_body(token: &Token) -> Self {
+        ParsingError {
+            message: "macro declaration in procedure body".to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn undefined_macro(token: &Token, label: &str) -> Self {
+        ParsingError {
+            message: format!("undefined macro: '{label}'"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn wrong_macro_arity(token: &Token, label: &str, expected: usize, actual: usize) -> Self {
+        ParsingError {
+            message: format!(
+                "macro '{label}' expects {expected} arguments, but {actual} were provided"
+            ),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -283,6 +513,8 @@ impl ParsingError {
             message: format!("instruction '{token}' is invalid"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -294,6 +526,12 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            labels: vec![Label::new(
+                *token.location(),
+                token_len(token),
+                format!("expected `{expected_format}`"),
+            )],
+            suggestion: None,
         }
     }
 
@@ -302,6 +540,8 @@ impl ParsingError {
             message: format!("malformed instruction '{token}': too many parameters provided"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -313,6 +553,8 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            labels: vec![param_label(token, part_idx, "invalid parameter")],
+            suggestion: None,
         }
     }
 
@@ -324,6 +566,8 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            labels: vec![param_label(token, part_idx, reason)],
+            suggestion: None,
         }
     }
 
@@ -335,6 +579,8 @@ impl ParsingError {
             message: "else without matching if".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -343,6 +589,8 @@ impl ParsingError {
             message: "if without matching else/end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -351,6 +599,8 @@ impl ParsingError {
             message: "while without matching end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -359,6 +609,8 @@ impl ParsingError {
             message: "repeat without matching end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -367,6 +619,58 @@ impl ParsingError {
             message: "else without matching end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn dangling_feature_else(token: &Token) -> Self {
+        ParsingError {
+            message: "@else without matching @if".to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn dangling_feature_end(token: &Token) -> Self {
+        ParsingError {
+            message: "@end without matching @if".to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn unmatched_feature_if(token: &Token) -> Self {
+        ParsingError {
+            message: "@if without matching @else/@end".to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn unmatched_feature_else(token: &Token) -> Self {
+        ParsingError {
+            message: "@else without matching @end".to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn invalid_feature_flag(token: &Token, err: LabelError) -> Self {
+        ParsingError {
+            message: format!("invalid feature flag: {err}"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -375,6 +679,8 @@ impl ParsingError {
             message: "begin without matching end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -383,6 +689,8 @@ impl ParsingError {
             message: "dangling instructions after program end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -391,6 +699,8 @@ impl ParsingError {
             message: "dangling instructions after module end".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -400,6 +710,8 @@ impl ParsingError {
                 .to_string(),
             location,
             op: "".to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -408,6 +720,8 @@ impl ParsingError {
             message: "not a module: `begin` instruction found".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -418,6 +732,8 @@ impl ParsingError {
             ),
             location: SourceLocation::default(),
             op: "".to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -428,6 +744,8 @@ impl ParsingError {
             ),
             location: SourceLocation::default(),
             op: "".to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -438,6 +756,8 @@ impl ParsingError {
             ),
             location: SourceLocation::default(),
             op: "".to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -446,6 +766,8 @@ impl ParsingError {
             message: format!("body block size cannot contain more than {max_body_size} instructions, but had {body_size}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -457,14 +779,30 @@ impl ParsingError {
             message: format!("duplicate procedure name: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
     pub fn invalid_proc_name(token: &Token, err: LabelError) -> Self {
+        // reserved keywords can be fixed by appending an underscore to the name
+        let suggestion = match &err {
+            LabelError::ReservedKeyword(label) => label_location(token, label).map(|location| {
+                Box::new(Suggestion::new(
+                    location,
+                    label.len(),
+                    format!("{label}_"),
+                    format!("consider renaming the procedure to `{label}_`"),
+                ))
+            }),
+            _ => None,
+        };
         ParsingError {
             message: format!("invalid procedure name: {err}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion,
         }
     }
 
@@ -473,6 +811,8 @@ impl ParsingError {
             message: format!("invalid re-exported procedure: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -484,6 +824,8 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -492,6 +834,8 @@ impl ParsingError {
             message: format!("invalid procedure locals: {locals}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -500,6 +844,8 @@ impl ParsingError {
             message: format!("number of procedure locals cannot be greater than {max_locals} characters, but was {num_locals}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -508,6 +854,8 @@ impl ParsingError {
             message: format!("procedure '{proc_name}' has no matching end"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -516,6 +864,8 @@ impl ParsingError {
             message: format!("exported procedures not allowed in this context: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -526,6 +876,18 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn invalid_proc_signature(token: &Token, err: &str) -> Self {
+        ParsingError {
+            message: format!("invalid procedure signature: {err}"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -537,6 +899,18 @@ impl ParsingError {
             message: format!("invalid procedure root invocation: {label} - {err}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
+        }
+    }
+
+    pub fn invalid_pinned_proc_root(token: &Token, label: &str, err: LabelError) -> Self {
+        ParsingError {
+            message: format!("invalid pinned procedure root: {label} - {err}"),
+            location: *token.location(),
+            op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -545,6 +919,8 @@ impl ParsingError {
             message: format!("invalid procedure invocation: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -553,6 +929,8 @@ impl ParsingError {
             message: "invalid exec: cannot invoke a procedure on a mast root".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -561,6 +939,8 @@ impl ParsingError {
             message: "invalid syscall: cannot invoke a syscall on a named module".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -569,6 +949,8 @@ impl ParsingError {
             message: "invalid syscall: cannot invoke a syscall on a mast root".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -577,6 +959,8 @@ impl ParsingError {
             message: format!("undefined local procedure: {label}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -585,6 +969,8 @@ impl ParsingError {
             message: format!("module '{module_name}' was not imported"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -599,6 +985,8 @@ impl ParsingError {
             ),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -610,6 +998,8 @@ impl ParsingError {
             message: format!("duplicate module import found: {module}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -618,6 +1008,8 @@ impl ParsingError {
             message: format!("invalid module import path: {module_path}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -626,6 +1018,8 @@ impl ParsingError {
             message: format!("invalid module name: {name}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -634,6 +1028,8 @@ impl ParsingError {
             message: "import in procedure body".to_string(),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -642,6 +1038,8 @@ impl ParsingError {
             message: format!("invalid path resolution: {error}"),
             location: *token.location(),
             op: token.to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
@@ -652,9 +1050,26 @@ impl ParsingError {
             ),
             location: SourceLocation::default(),
             op: "".to_string(),
+            labels: Vec::new(),
+            suggestion: None,
         }
     }
 
+    // DIAGNOSTIC DETAILS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds a label pointing at a place in the source related to this error.
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    /// Sets the suggested fix for this error.
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestion = Some(Box::new(suggestion));
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
     pub fn message(&self) -> &String {
@@ -668,6 +1083,38 @@ impl ParsingError {
     pub const fn location(&self) -> &SourceLocation {
         &self.location
     }
+
+    /// Returns the labels pointing at places in the source related to this error.
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// Returns the suggested fix for this error, if any.
+    pub fn suggestion(&self) -> Option<&Suggestion> {
+        self.suggestion.as_deref()
+    }
+
+    /// Returns a [Diagnostic] describing this error.
+    ///
+    /// The primary label of the diagnostic spans the token at which the error was detected.
+    pub fn diagnostic(&self) -> Diagnostic {
+        let primary = Label::new(self.location, self.op.chars().count(), "");
+        let diagnostic = self
+            .labels
+            .iter()
+            .cloned()
+            .fold(Diagnostic::error(self.message.clone(), primary), Diagnostic::with_label);
+        match &self.suggestion {
+            Some(suggestion) => diagnostic.with_suggestion(suggestion.as_ref().clone()),
+            None => diagnostic,
+        }
+    }
+}
+
+impl From<ParsingError> for Diagnostic {
+    fn from(err: ParsingError) -> Self {
+        err.diagnostic()
+    }
 }
 
 impl fmt::Debug for ParsingError {
@@ -692,6 +1139,121 @@ impl From<ParsingError> for std::io::Error {
 #[cfg(feature = "std")]
 impl std::error::Error for ParsingError {}
 
+/// Returns the number of characters in the source text of the specified token.
+fn token_len(token: &Token) -> usize {
+    token.to_string().chars().count()
+}
+
+/// Returns a label spanning the part of the specified token at index `part_idx`.
+fn param_label(token: &Token, part_idx: usize, message: &str) -> Label {
+    let offset: usize = token.parts()[..part_idx].iter().map(|part| part.chars().count() + 1).sum();
+    let mut location = *token.location();
+    location.move_column(offset as u32);
+    Label::new(location, token.parts()[part_idx].chars().count(), message)
+}
+
+/// Returns the location of the first occurrence of `label` in the specified token after its first
+/// part, or None if the token does not contain the label.
+fn label_location(token: &Token, label: &str) -> Option<SourceLocation> {
+    let source = token.to_string();
+    let start = token.parts()[0].len();
+    let offset = source[start..].find(label)? + start;
+    let mut location = *token.location();
+    location.move_column(source[..offset].chars().count() as u32);
+    Some(location)
+}
+
+// ERROR REPORT
+// ================================================================================================
+
+/// A collection of errors encountered while parsing modules of a library, each tagged with the
+/// path of the module in which it occurred.
+///
+/// The number of errors retained by a report is limited; errors which exceed the limit are only
+/// counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorReport {
+    errors: Vec<(LibraryPath, ParsingError)>,
+    limit: usize,
+    num_omitted: usize,
+}
+
+impl ErrorReport {
+    /// The default maximum number of errors retained by a report.
+    pub const DEFAULT_LIMIT: usize = 64;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty report which retains at most `limit` errors.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            errors: Vec::new(),
+            limit,
+            num_omitted: 0,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the errors retained by this report together with the paths of the modules in
+    /// which they occurred, in the order in which they were added to the report.
+    pub fn errors(&self) -> &[(LibraryPath, ParsingError)] {
+        &self.errors
+    }
+
+    /// Returns the total number of errors added to this report, including the omitted ones.
+    pub fn num_errors(&self) -> usize {
+        self.errors.len() + self.num_omitted
+    }
+
+    /// Returns the number of errors which were added to this report after the limit had been
+    /// reached.
+    pub fn num_omitted(&self) -> usize {
+        self.num_omitted
+    }
+
+    /// Returns true if no errors were added to this report.
+    pub fn is_empty(&self) -> bool {
+        self.num_errors() == 0
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds an error which occurred in the module with the specified path to this report.
+    pub fn push(&mut self, path: LibraryPath, error: ParsingError) {
+        if self.errors.len() < self.limit {
+            self.errors.push((path, error));
+        } else {
+            self.num_omitted += 1;
+        }
+    }
+}
+
+impl Default for ErrorReport {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_LIMIT)
+    }
+}
+
+impl fmt::Display for ErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "found {} error(s) in library modules", self.num_errors())?;
+        for (path, error) in self.errors.iter() {
+            write!(f, "\n  {path}: {error}")?;
+        }
+        if self.num_omitted > 0 {
+            write!(f, "\n  ... and {} more error(s)", self.num_omitted)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ErrorReport {}
+
 // NAME ERROR
 // ================================================================================================
 
@@ -704,6 +1266,7 @@ pub enum LabelError {
     InvalidFirstLetter(String),
     InvalidChars(String),
     LabelTooLong(String, usize),
+    ReservedKeyword(String),
     Uppercase(String),
 }
 
@@ -728,6 +1291,10 @@ impl LabelError {
         Self::LabelTooLong(label.to_string(), max_len)
     }
 
+    pub fn reserved_keyword(label: &str) -> Self {
+        Self::ReservedKeyword(label.to_string())
+    }
+
     pub fn must_be_uppercase(label: &str) -> Self {
         Self::Uppercase(label.to_string())
     }
@@ -756,6 +1323,9 @@ impl fmt::Display for LabelError {
             LabelTooLong(label, max_len) => {
                 write!(f, "'{label}' is over {max_len} characters long")
             }
+            ReservedKeyword(label) => {
+                write!(f, "'{label}' is a reserved keyword, consider renaming it to '{label}_'")
+            }
             Uppercase(label) => write!(f, "'{label}' cannot contain lower-case characters"),
         }
     }
@@ -774,6 +1344,7 @@ pub enum LibraryError {
         expected: String,
         actual: String,
     },
+    InvalidMetadataKey(String),
     InvalidNamespace(LabelError),
     InvalidPath(PathError),
     InvalidVersionNumber {
@@ -784,6 +1355,11 @@ pub enum LibraryError {
         version: String,
         component: String,
     },
+    MetadataValueTooLong {
+        key: String,
+        len: usize,
+        max_len: usize,
+    },
     ModuleNotFound(String),
     NoModulesInLibrary {
         name: LibraryNamespace,
@@ -793,6 +1369,9 @@ pub enum LibraryError {
         num_dependencies: usize,
         max_dependencies: usize,
     },
+    TooManyMetadataEntries {
+        max_entries: usize,
+    },
     TooManyModulesInLibrary {
         name: LibraryNamespace,
         num_modules: usize,
@@ -827,6 +1406,10 @@ impl LibraryError {
         }
     }
 
+    pub fn invalid_metadata_key(key: &str) -> Self {
+        Self::InvalidMetadataKey(key.into())
+    }
+
     pub fn invalid_namespace(err: LabelError) -> Self {
         Self::InvalidNamespace(err)
     }
@@ -845,6 +1428,14 @@ impl LibraryError {
         }
     }
 
+    pub fn metadata_value_too_long(key: &str, len: usize, max_len: usize) -> Self {
+        Self::MetadataValueTooLong {
+            key: key.into(),
+            len,
+            max_len,
+        }
+    }
+
     pub fn no_modules_in_library(name: LibraryNamespace) -> Self {
         Self::NoModulesInLibrary { name }
     }
@@ -873,6 +1464,10 @@ impl LibraryError {
         }
     }
 
+    pub fn too_many_metadata_entries(max_entries: usize) -> Self {
+        Self::TooManyMetadataEntries { max_entries }
+    }
+
     pub fn too_many_version_components(version: &str) -> Self {
         Self::TooManyVersionComponents {
             version: version.into(),
@@ -895,6 +1490,7 @@ impl fmt::Display for LibraryError {
             InconsistentNamespace { expected, actual } => {
                 write!(f, "inconsistent module namespace: expected '{expected}', but was {actual}")
             }
+            InvalidMetadataKey(key) => write!(f, "invalid library metadata key '{key}'"),
             InvalidNamespace(err) => {
                 write!(f, "invalid namespace: {err}")
             }
@@ -907,6 +1503,9 @@ impl fmt::Display for LibraryError {
             MissingVersionComponent { version, component } => {
                 write!(f, "version '{version}' is invalid: missing {component} version component")
             }
+            MetadataValueTooLong { key, len, max_len } => {
+                write!(f, "value of library metadata key '{key}' is {len} bytes long, but max is {max_len}")
+            }
             ModuleNotFound(path) => write!(f, "module '{path}' not found"),
             NoModulesInLibrary { name } => {
                 write!(f, "library '{}' does not contain any modules", name.as_str())
@@ -922,6 +1521,9 @@ impl fmt::Display for LibraryError {
                     name.as_str()
                 )
             }
+            TooManyMetadataEntries { max_entries } => {
+                write!(f, "library metadata contains more than {max_entries} entries")
+            }
             TooManyModulesInLibrary {
                 name,
                 num_modules,
@@ -17,7 +17,9 @@ use vm_core::{
 };
 
 mod library;
-pub use library::{Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, Version};
+pub use library::{
+    Library, LibraryMetadata, LibraryNamespace, LibraryPath, MaslLibrary, Module, Version,
+};
 
 mod procedures;
 use procedures::{CallSet, NamedProcedure, Procedure};
@@ -29,11 +31,18 @@ use ast::{NAMESPACE_LABEL_PARSER, PROCEDURE_LABEL_PARSER};
 mod tokens;
 use tokens::{Token, TokenStream};
 
+mod diagnostics;
+pub use diagnostics::{Diagnostic, Label, Severity, Suggestion};
+
 mod errors;
-pub use errors::{AssemblyError, LabelError, LibraryError, ParsingError, PathError};
+pub use errors::{AssemblyError, ErrorReport, LabelError, LibraryError, ParsingError, PathError};
 
 mod assembler;
-pub use assembler::{Assembler, AssemblyContext};
+pub use assembler::{
+    disassemble, Assembler, AssemblyContext, Disassembler, MastForest, MastNodeId, ModuleCache,
+    OpFrequencyConfig, OpFrequencyReport, OptimizationLevel, OptimizationStats, SourceMap,
+    SourceSpan, StackDepthAnalyzer, StackEffect,
+};
 
 #[cfg(test)]
 mod tests;
@@ -1,7 +1,7 @@
 use super::{
-    AstSerdeOptions, ByteReader, ByteWriter, Deserializable, DeserializationError, Library,
-    LibraryError, LibraryNamespace, LibraryPath, Module, ModuleAst, Serializable, Version,
-    MAX_DEPENDENCIES, MAX_MODULES,
+    read_len, AstSerdeOptions, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Library, LibraryError, LibraryMetadata, LibraryNamespace, LibraryPath, Module, ModuleAst,
+    Serializable, Version, MAX_DEPENDENCIES, MAX_MODULES,
 };
 use alloc::{collections::BTreeSet, vec::Vec};
 use core::slice::Iter;
@@ -12,9 +12,7 @@ use core::slice::Iter;
 
 /// Serialization options for [ModuleAst]. Imports and information about imported procedures are
 /// part of the ModuleAst serialization by default.
-const AST_DEFAULT_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions {
-    serialize_imports: true,
-};
+const AST_DEFAULT_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(true);
 
 // LIBRARY IMPLEMENTATION FOR MASL FILES
 // ================================================================================================
@@ -36,6 +34,8 @@ pub struct MaslLibrary {
     modules: Vec<Module>,
     /// Dependencies of the library.
     dependencies: Vec<LibraryNamespace>,
+    /// Build metadata of the library.
+    metadata: LibraryMetadata,
 }
 
 impl Library for MaslLibrary {
@@ -56,6 +56,10 @@ impl Library for MaslLibrary {
     fn dependencies(&self) -> &[LibraryNamespace] {
         &self.dependencies
     }
+
+    fn metadata(&self) -> &LibraryMetadata {
+        &self.metadata
+    }
 }
 
 impl MaslLibrary {
@@ -105,15 +109,25 @@ impl MaslLibrary {
             has_source_locations,
             modules,
             dependencies,
+            metadata: LibraryMetadata::new(),
         })
     }
 
+    /// Attaches the provided build metadata to this library, replacing any existing metadata.
+    pub fn with_metadata(mut self, metadata: LibraryMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
     /// Clears the source locations from this bundle.
+    ///
+    /// After this, source locations are no longer serialized with the bundle.
     pub fn clear_locations(&mut self) {
-        self.modules.iter_mut().for_each(|m| m.clear_locations())
+        self.modules.iter_mut().for_each(|m| m.clear_locations());
+        self.has_source_locations = false;
     }
 }
 
@@ -121,7 +135,10 @@ impl MaslLibrary {
 mod use_std {
     use alloc::{collections::BTreeMap, string::ToString};
 
-    use super::{super::super::ast::instrument, *};
+    use super::{
+        super::super::{ast::instrument, ErrorReport},
+        *,
+    };
     use std::{fs, io, path::Path};
 
     impl MaslLibrary {
@@ -131,6 +148,11 @@ mod use_std {
         ///
         /// For every file, pick and parse the ones with `masm` extension; skip otherwise.
         ///
+        /// Parsing does not stop at the first module which fails to parse. Instead, parsing errors
+        /// of all modules (up to [ErrorReport::DEFAULT_LIMIT]) are collected into an
+        /// [ErrorReport], which is returned as the inner error of the returned [io::Error] and
+        /// can be retrieved via [io::Error::get_ref()].
+        ///
         /// Example:
         ///
         /// - ./sys.masm            -> ("sys",          ast(./sys.masm))
@@ -168,15 +190,18 @@ mod use_std {
                 ));
             }
 
+            let mut report = ErrorReport::default();
             let modules = read_from_dir_helper(
                 Default::default(),
                 path,
                 &module_path,
                 &mut dependencies_set,
-            )?
-            .into_iter()
-            .map(|(path, ast)| Module { path, ast })
-            .collect();
+                &mut report,
+            )?;
+            if !report.is_empty() {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, report));
+            }
+            let modules = modules.into_iter().map(|(path, ast)| Module { path, ast }).collect();
 
             let dependencies =
                 dependencies_set.into_iter().filter(|dep| dep != &namespace).collect();
@@ -223,12 +248,16 @@ mod use_std {
 
     /// Read a directory and recursively feed the state map with path->ast tuples.
     ///
+    /// Parsing errors are added to the provided `report`, and modules which fail to parse are not
+    /// added to the state map.
+    ///
     /// Helper for [`Self::read_from_dir`].
     fn read_from_dir_helper<P>(
         mut state: BTreeMap<LibraryPath, ModuleAst>,
         dir: P,
         module_path: &LibraryPath,
         deps: &mut BTreeSet<LibraryNamespace>,
+        report: &mut ErrorReport,
     ) -> io::Result<BTreeMap<LibraryPath, ModuleAst>>
     where
         P: AsRef<Path>,
@@ -246,7 +275,7 @@ mod use_std {
                 let module_path = module_path
                     .append(name)
                     .map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{err}")))?;
-                state = read_from_dir_helper(state, path, &module_path, deps)?;
+                state = read_from_dir_helper(state, path, &module_path, deps, report)?;
             // if file, check if `masm`, parse & append; skip otherwise
             } else if ty.is_file() {
                 let path = entry.path();
@@ -270,9 +299,25 @@ mod use_std {
                         ));
                     }
 
-                    // read & parse file
+                    // build module path
+                    let module = if name == MaslLibrary::MOD {
+                        module_path.clone()
+                    } else {
+                        module_path
+                            .append(name)
+                            .map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{err}")))?
+                    };
+
+                    // read & parse file; if parsing fails, record the error and proceed with the
+                    // next file
                     let contents = fs::read_to_string(&path)?;
-                    let ast = ModuleAst::parse(&contents)?;
+                    let ast = match ModuleAst::parse(&contents) {
+                        Ok(ast) => ast,
+                        Err(err) => {
+                            report.push(module, err);
+                            continue;
+                        }
+                    };
 
                     // add dependencies of this module to the dependencies of this library
                     for path in ast.import_info().import_paths() {
@@ -280,14 +325,7 @@ mod use_std {
                         deps.insert(ns);
                     }
 
-                    // build module path and add it to the map of modules
-                    let module = if name == MaslLibrary::MOD {
-                        module_path.clone()
-                    } else {
-                        module_path
-                            .append(name)
-                            .map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{err}")))?
-                    };
+                    // add the module to the map of modules
 
                     if state.insert(module, ast).is_some() {
                         unreachable!(
@@ -306,16 +344,19 @@ impl Serializable for MaslLibrary {
         self.namespace.write_into(target);
         self.version.write_into(target);
 
+        // write the AST serde options; this also records the AST format version
+        AST_DEFAULT_SERDE_OPTIONS.write_into(target);
+
         let modules = self.modules();
 
         // write dependencies
-        target.write_u16(self.dependencies.len() as u16);
+        target.write_usize(self.dependencies.len());
         self.dependencies.iter().for_each(|dep| dep.write_into(target));
 
         // this assert is OK because maximum number of modules is enforced by Library constructor
         debug_assert!(modules.len() <= MAX_MODULES, "too many modules");
 
-        target.write_u16(modules.len() as u16);
+        target.write_usize(modules.len());
         modules.for_each(|module| {
             LibraryPath::strip_first(&module.path)
                 .expect("module path consists of a single component")
@@ -329,6 +370,10 @@ impl Serializable for MaslLibrary {
         if self.has_source_locations {
             self.modules.iter().for_each(|m| m.write_source_locations(target));
         }
+
+        // write the metadata last, so that libraries serialized before metadata was introduced
+        // can still be read
+        self.metadata.write_into(target);
     }
 }
 
@@ -337,21 +382,24 @@ impl Deserializable for MaslLibrary {
         let namespace = LibraryNamespace::read_from(source)?;
         let version = Version::read_from(source)?;
 
+        // read the AST serde options; this fails if the AST format version is not supported
+        let options = AstSerdeOptions::read_from(source)?;
+
         // read dependencies
-        let num_deps = source.read_u16()? as usize;
+        let num_deps = read_len(source, MAX_DEPENDENCIES)?;
         // TODO: check for duplicate/self-referential dependencies?
         let deps_set: BTreeSet<LibraryNamespace> = (0..num_deps)
             .map(|_| LibraryNamespace::read_from(source))
             .collect::<Result<_, _>>()?;
 
         // read modules
-        let num_modules = source.read_u16()? as usize;
+        let num_modules = read_len(source, MAX_MODULES)?;
         let mut modules = Vec::with_capacity(num_modules);
         for _ in 0..num_modules {
             let path = LibraryPath::read_from(source)?
                 .prepend(&namespace)
                 .map_err(|err| DeserializationError::InvalidValue(format!("{err}")))?;
-            let ast = ModuleAst::read_from(source, AST_DEFAULT_SERDE_OPTIONS)?;
+            let ast = ModuleAst::read_from(source, options)?;
             modules.push(Module { path, ast });
         }
 
@@ -361,8 +409,16 @@ impl Deserializable for MaslLibrary {
             modules.iter_mut().try_for_each(|m| m.load_source_locations(source))?;
         }
 
+        // libraries serialized before metadata was introduced end right after the locations
+        let metadata = if source.has_more_bytes() {
+            LibraryMetadata::read_from(source)?
+        } else {
+            LibraryMetadata::new()
+        };
+
         let deps = deps_set.into_iter().collect();
         Self::new(namespace, version, has_source_locations, modules, deps)
+            .map(|library| library.with_metadata(metadata))
             .map_err(|err| DeserializationError::InvalidValue(format!("{err}")))
     }
 }
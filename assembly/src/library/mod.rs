@@ -1,12 +1,15 @@
 use super::{
-    ast::{AstSerdeOptions, ModuleAst},
+    ast::{read_len, AstSerdeOptions, ModuleAst, ProcedureSignature},
     ByteReader, ByteWriter, Deserializable, DeserializationError, LibraryError, PathError,
-    Serializable, MAX_LABEL_LEN, NAMESPACE_LABEL_PARSER,
+    ProcedureName, Serializable, MAX_LABEL_LEN, NAMESPACE_LABEL_PARSER,
 };
 use core::{cmp::Ordering, fmt, ops::Deref, str::from_utf8};
 
 mod masl;
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
 pub use masl::MaslLibrary;
 
 mod path;
@@ -21,6 +24,15 @@ const MAX_MODULES: usize = u16::MAX as usize;
 /// Maximum number of dependencies in a library.
 const MAX_DEPENDENCIES: usize = u16::MAX as usize;
 
+/// Maximum number of metadata entries in a library.
+const MAX_METADATA_ENTRIES: usize = u16::MAX as usize;
+
+/// Maximum length (in bytes) of a metadata value.
+const MAX_METADATA_VALUE_LEN: usize = u16::MAX as usize;
+
+/// Metadata of libraries which do not provide any.
+static EMPTY_METADATA: LibraryMetadata = LibraryMetadata::new();
+
 // LIBRARY
 // ================================================================================================
 
@@ -43,17 +55,43 @@ pub trait Library {
     /// Returns the dependency libraries of this library.
     fn dependencies(&self) -> &[LibraryNamespace];
 
+    /// Returns the build metadata attached to this library (e.g., git hash, build timestamp).
+    ///
+    /// By default, libraries do not carry any metadata.
+    fn metadata(&self) -> &LibraryMetadata {
+        &EMPTY_METADATA
+    }
+
     /// Returns the AST of the module stored at the provided path.
     fn get_module_ast(&self, path: &LibraryPath) -> Option<&ModuleAst> {
         self.modules().find(|&module| module.path == *path).map(|module| &module.ast)
     }
+
+    /// Returns the signature declared by the procedure with the specified name exported from the
+    /// module stored at the provided path.
+    ///
+    /// Returns None if the module does not export such a procedure, or if the procedure does not
+    /// declare a signature.
+    fn get_procedure_signature(
+        &self,
+        path: &LibraryPath,
+        name: &ProcedureName,
+    ) -> Option<&ProcedureSignature> {
+        self.get_module_ast(path)?
+            .procs()
+            .iter()
+            .find(|procedure| procedure.is_export && procedure.name == *name)?
+            .signature
+            .as_ref()
+    }
 }
 
 impl<T> Library for &T
 where
     T: Library,
 {
-    type ModuleIterator<'a> = T::ModuleIterator<'a>
+    type ModuleIterator<'a>
+        = T::ModuleIterator<'a>
     where
         Self: 'a;
 
@@ -73,9 +111,21 @@ where
         T::dependencies(self)
     }
 
+    fn metadata(&self) -> &LibraryMetadata {
+        T::metadata(self)
+    }
+
     fn get_module_ast(&self, path: &LibraryPath) -> Option<&ModuleAst> {
         T::get_module_ast(self, path)
     }
+
+    fn get_procedure_signature(
+        &self,
+        path: &LibraryPath,
+        name: &ProcedureName,
+    ) -> Option<&ProcedureSignature> {
+        T::get_procedure_signature(self, path, name)
+    }
 }
 
 // MODULE
@@ -317,6 +367,136 @@ impl TryFrom<&str> for Version {
     }
 }
 
+// LIBRARY METADATA
+// ================================================================================================
+
+/// Key-value metadata attached to a library at build time.
+///
+/// Metadata is not used by the assembler during compilation; it allows tracing compiled library
+/// artifacts back to their sources (e.g., via a git commit hash, a build timestamp, or a semantic
+/// version of the package the library was built from).
+///
+/// Keys must be non-empty, must not be longer than 255 bytes, and must consist of ASCII letters,
+/// numbers, and `_`, `-`, or `.` characters. Values must not be longer than [u16::MAX] bytes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LibraryMetadata {
+    entries: BTreeMap<String, String>,
+}
+
+impl LibraryMetadata {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new empty [LibraryMetadata].
+    pub const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value associated with the specified key, if any.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    /// Returns the number of entries in this metadata.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if this metadata does not contain any entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the key-value pairs of this metadata, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Associates the specified value with the specified key, returning the previous value
+    /// associated with the key, if any.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The key is empty, longer than 255 bytes, or contains invalid characters.
+    /// - The value is longer than [u16::MAX] bytes.
+    /// - The metadata already contains [u16::MAX] entries and the key is not one of them.
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> Result<Option<String>, LibraryError>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let key = key.into();
+        let value = value.into();
+
+        let is_valid_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.');
+        if key.is_empty() || key.len() > u8::MAX as usize || !key.chars().all(is_valid_char) {
+            return Err(LibraryError::invalid_metadata_key(&key));
+        }
+        if value.len() > MAX_METADATA_VALUE_LEN {
+            return Err(LibraryError::metadata_value_too_long(
+                &key,
+                value.len(),
+                MAX_METADATA_VALUE_LEN,
+            ));
+        }
+        if self.entries.len() >= MAX_METADATA_ENTRIES && !self.entries.contains_key(&key) {
+            return Err(LibraryError::too_many_metadata_entries(MAX_METADATA_ENTRIES));
+        }
+
+        Ok(self.entries.insert(key, value))
+    }
+}
+
+impl Serializable for LibraryMetadata {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // these assertions should pass because `insert()` enforces the limits
+        debug_assert!(self.entries.len() <= MAX_METADATA_ENTRIES, "too many metadata entries");
+        target.write_usize(self.entries.len());
+        for (key, value) in self.entries.iter() {
+            debug_assert!(key.len() <= u8::MAX as usize, "metadata key too long");
+            target.write_u8(key.len() as u8);
+            target.write_bytes(key.as_bytes());
+            target.write_usize(value.len());
+            target.write_bytes(value.as_bytes());
+        }
+    }
+}
+
+impl Deserializable for LibraryMetadata {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entries = read_len(source, MAX_METADATA_ENTRIES)?;
+        let mut metadata = Self::new();
+        for _ in 0..num_entries {
+            let key_len = source.read_u8()? as usize;
+            let key = source.read_vec(key_len)?;
+            let key = String::from_utf8(key)
+                .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+            let value_len = read_len(source, MAX_METADATA_VALUE_LEN)?;
+            let value = source.read_vec(value_len)?;
+            let value = String::from_utf8(value)
+                .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+
+            let previous = metadata
+                .insert(key, value)
+                .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+            if previous.is_some() {
+                return Err(DeserializationError::InvalidValue(
+                    "duplicate library metadata key".to_string(),
+                ));
+            }
+        }
+        Ok(metadata)
+    }
+}
+
 // LIBRARY NAMESPACE
 // ================================================================================================
 
@@ -1,5 +1,8 @@
-use super::{Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, ModuleAst, Version};
-use alloc::vec::Vec;
+use super::{
+    Library, LibraryMetadata, LibraryNamespace, LibraryPath, MaslLibrary, Module, ModuleAst,
+    ProcedureName, ProcedureSignature, Version,
+};
+use alloc::{string::ToString, vec::Vec};
 use vm_core::utils::{Deserializable, Serializable, SliceReader};
 
 #[test]
@@ -91,3 +94,127 @@ end
 
     assert!(bundle.get_module_ast(&LibraryPath::new("test::bar").unwrap()).is_none());
 }
+
+#[test]
+fn get_procedure_signature() {
+    let source = r#"
+        #! Adds two elements.
+        #!
+        #! stack_in: [b, a]
+        #! stack_out: [c]
+        export.foo
+            add
+        end
+        export.bar
+            mul
+        end
+    "#;
+    let path = LibraryPath::new("test::foo").unwrap();
+    let ast = ModuleAst::parse(source).unwrap();
+    let modules = [Module::new(path.clone(), ast)].to_vec();
+
+    let namespace = LibraryNamespace::new("test").unwrap();
+    let bundle = MaslLibrary::new(namespace, Version::MIN, false, modules, Vec::new()).unwrap();
+
+    // signatures are restored from the doc comments of deserialized procedures
+    let bundle = MaslLibrary::read_from_bytes(&bundle.to_bytes()).unwrap();
+
+    let foo = ProcedureName::try_from("foo").unwrap();
+    let bar = ProcedureName::try_from("bar").unwrap();
+    let signature = bundle.get_procedure_signature(&path, &foo).unwrap();
+    assert_eq!(signature, &ProcedureSignature::new(["b", "a"], ["c"]));
+    assert_eq!(signature.to_string(), "stack_in: [b, a] stack_out: [c]");
+    assert!(bundle.get_procedure_signature(&path, &bar).is_none());
+}
+
+#[test]
+fn masl_metadata_serialization() {
+    let path = LibraryPath::new("test::foo").unwrap();
+    let ast = ModuleAst::parse("export.foo add end").unwrap();
+    let modules = [Module::new(path, ast)].to_vec();
+    let namespace = LibraryNamespace::new("test").unwrap();
+    let bundle = MaslLibrary::new(namespace, Version::MIN, false, modules, Vec::new()).unwrap();
+    assert!(bundle.metadata().is_empty());
+
+    // libraries serialized without metadata are read with empty metadata
+    let legacy_bytes = bundle.to_bytes();
+    let legacy_bytes = &legacy_bytes[..legacy_bytes.len() - 1];
+    let deserialized = MaslLibrary::read_from_bytes(legacy_bytes).unwrap();
+    assert_eq!(bundle, deserialized);
+
+    let mut metadata = LibraryMetadata::new();
+    metadata.insert("git", "1a2b3c4").unwrap();
+    metadata.insert("build.timestamp", "2024-01-01T00:00:00Z").unwrap();
+    assert_eq!(metadata.insert("git", "5d6e7f8").unwrap().as_deref(), Some("1a2b3c4"));
+    let bundle = bundle.with_metadata(metadata);
+
+    let deserialized = MaslLibrary::read_from_bytes(&bundle.to_bytes()).unwrap();
+    assert_eq!(bundle, deserialized);
+    assert_eq!(deserialized.metadata().get("git"), Some("5d6e7f8"));
+    assert_eq!(
+        deserialized.metadata().iter().collect::<Vec<_>>(),
+        [("build.timestamp", "2024-01-01T00:00:00Z"), ("git", "5d6e7f8")]
+    );
+
+    // the metadata is available through the assembler for any path within the library
+    let assembler = crate::Assembler::default().with_library(&deserialized).unwrap();
+    let metadata = assembler.library_metadata("test::foo::foo").unwrap();
+    assert_eq!(metadata.get("git"), Some("5d6e7f8"));
+    assert!(assembler.library_metadata("test").is_some());
+    assert!(assembler.library_metadata("std::foo").is_none());
+}
+
+#[test]
+fn masl_metadata_invalid_keys() {
+    let mut metadata = LibraryMetadata::new();
+    assert!(metadata.insert("", "value").is_err());
+    assert!(metadata.insert("git hash", "value").is_err());
+    assert!(metadata.insert("a".repeat(256), "value").is_err());
+    assert!(metadata.insert("git", "v".repeat(u16::MAX as usize + 1)).is_err());
+    assert!(metadata.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_from_dir_reports_all_parsing_errors() {
+    use crate::ErrorReport;
+    use std::{fs, io, process};
+
+    // build a library directory with two invalid modules and one valid module
+    let dir = std::env::temp_dir().join(format!("miden_masl_errors_{}", process::id()));
+    fs::create_dir_all(dir.join("math")).unwrap();
+    fs::write(dir.join("foo.masm"), "export.foo add end").unwrap();
+    fs::write(dir.join("bar.masm"), "export.bar push.a end").unwrap();
+    fs::write(dir.join("math").join("u64.masm"), "export.add add").unwrap();
+
+    let namespace = LibraryNamespace::new("test").unwrap();
+    let err = MaslLibrary::read_from_dir(&dir, namespace, false, Version::MIN).unwrap_err();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let report = err.get_ref().unwrap().downcast_ref::<ErrorReport>().unwrap();
+    assert_eq!(report.num_errors(), 2);
+    assert_eq!(report.num_omitted(), 0);
+
+    let mut paths = report.errors().iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>();
+    paths.sort_unstable();
+    assert_eq!(paths, ["test::bar", "test::math::u64"]);
+}
+
+#[test]
+fn error_report_limit() {
+    use crate::{ErrorReport, ParsingError};
+    use alloc::string::ToString;
+
+    let path = LibraryPath::new("test::foo").unwrap();
+    let mut report = ErrorReport::new(2);
+    assert!(report.is_empty());
+    for _ in 0..3 {
+        report.push(path.clone(), ParsingError::empty_source());
+    }
+
+    assert_eq!(report.errors().len(), 2);
+    assert_eq!(report.num_errors(), 3);
+    assert_eq!(report.num_omitted(), 1);
+    assert!(report.to_string().ends_with("... and 1 more error(s)"));
+}
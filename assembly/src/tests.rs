@@ -1,7 +1,11 @@
 use crate::{
-    ast::{ModuleAst, ProgramAst},
-    Assembler, AssemblyContext, AssemblyError, Library, LibraryNamespace, LibraryPath, MaslLibrary,
-    Module, ProcedureName, Version,
+    ast::{
+        AstSerdeOptions, DataSegment, InstructionAliases, ModuleAst, ProcedureSignature, ProgramAst,
+    },
+    utils::{Deserializable, Serializable},
+    Assembler, AssemblyContext, AssemblyError, CodeBlock, Disassembler, Felt, Library,
+    LibraryNamespace, LibraryPath, MaslLibrary, MastForest, Module, ModuleCache, Operation,
+    OptimizationLevel, ProcedureName, Program, StackDepthAnalyzer, StackEffect, Version,
 };
 use alloc::{string::ToString, vec::Vec};
 use core::slice::Iter;
@@ -350,6 +354,94 @@ fn get_proc_name_of_unknown_module() {
     assert_eq!(compilation_error, expected_error);
 }
 
+#[test]
+fn procroot_immediates() {
+    let assembler = Assembler::default();
+
+    // compile a module which exports a procedure
+    let module_path1 = LibraryPath::new("module::path::one").unwrap();
+    let module_source1 = ModuleAst::parse(
+        "
+        export.foo
+            push.1.2
+        end",
+    )
+    .unwrap();
+
+    assembler
+        .compile_module(
+            &module_source1,
+            Some(&module_path1),
+            &mut AssemblyContext::for_module(false),
+        )
+        .unwrap();
+
+    // MAST roots referenced via `procroot()` expressions and constants are the same as the ones
+    // referenced via `procref`
+    let compile = |source: &str| {
+        let program = ProgramAst::parse(source).unwrap();
+        assembler
+            .compile_in_context(&program, &mut AssemblyContext::for_program(Some(&program)))
+            .unwrap()
+    };
+
+    let expected = compile(
+        "
+        use.module::path::one
+
+        proc.bar
+            push.3.4
+        end
+
+        begin
+            procref.one::foo
+            procref.bar
+        end",
+    );
+
+    let program = compile(
+        "
+        use.module::path::one
+
+        proc.bar
+            push.3.4
+        end
+
+        begin
+            push.procroot(one::foo)
+            push.procroot(bar)
+        end",
+    );
+    assert_eq!(expected.hash(), program.hash());
+
+    let program = compile(
+        "
+        use.module::path::one
+
+        const.FOO_ROOT=procroot(one::foo)
+        const.BAR_ROOT=procroot(bar)
+
+        proc.bar
+            push.3.4
+        end
+
+        begin
+            push.FOO_ROOT
+            push.BAR_ROOT
+        end",
+    );
+    assert_eq!(expected.hash(), program.hash());
+
+    // MAST roots cannot be used as targets of `procroot()` expressions
+    let source = "
+        const.FOO_ROOT=procroot(0x0000000000000000000000000000000000000000000000000000000000000000)
+
+        begin
+            push.FOO_ROOT
+        end";
+    assert!(ProgramAst::parse(source).is_err());
+}
+
 // CONSTANTS
 // ================================================================================================
 
@@ -427,6 +519,34 @@ fn constant_alphanumeric_expression() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn constant_bitwise_expression() {
+    let assembler = Assembler::default();
+    let source = "const.SHIFT=4 \
+    const.FLAG=1<<SHIFT \
+    const.MASK=(FLAG-1)&0xF|0x100 \
+    const.TOGGLED=MASK^0x3>>1 \
+    begin \
+    push.FLAG.MASK.TOGGLED \
+    end \
+    ";
+    let expected = "\
+    begin \
+        span \
+            push(16) push(271) push(270) \
+        end \
+    end";
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(expected, format!("{program}"));
+
+    let source = "const.TOO_BIG=1<<64 \
+    begin \
+    push.TOO_BIG \
+    end \
+    ";
+    assert!(assembler.compile(source).is_err());
+}
+
 #[test]
 fn constant_hexadecimal_value() {
     let assembler = Assembler::default();
@@ -1016,6 +1136,115 @@ fn program_with_proc_locals() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn program_with_body_locals() {
+    let assembler = Assembler::default();
+    let source = "\
+        proc.foo.1 \
+            loc_store.0 \
+        end \
+        begin.1 \
+            push.4 push.3 \
+            loc_store.0 \
+            exec.foo \
+            loc_load.0 \
+        end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                push(1) fmpupdate \
+                push(4) push(3) \
+                pad fmpadd mstore drop \
+                push(1) fmpupdate \
+                pad fmpadd mstore drop \
+                push(18446744069414584320) fmpupdate \
+                pad fmpadd mload \
+                push(18446744069414584320) fmpupdate \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn program_with_proc_local_aliases() {
+    let assembler = Assembler::default();
+    let source = "\
+        proc.foo.2 \
+            alias.a=loc.0 \
+            alias.b=loc.1 \
+            loc_store.a \
+            loc_store.b \
+            push.&a \
+            loc_load.a \
+            loc_load.b \
+        end \
+        begin \
+            push.4 push.3 push.2 \
+            exec.foo \
+        end";
+    let program = assembler.compile(source).unwrap();
+
+    // aliases are resolved by the parser and must not affect the generated MAST
+    let source = "\
+        proc.foo.2 \
+            loc_store.0 \
+            loc_store.1 \
+            locaddr.0 \
+            loc_load.0 \
+            loc_load.1 \
+        end \
+        begin \
+            push.4 push.3 push.2 \
+            exec.foo \
+        end";
+    let expected = assembler.compile(source).unwrap();
+    assert_eq!(program.hash(), expected.hash());
+    assert_eq!(format!("{expected}"), format!("{program}"));
+}
+
+#[test]
+fn program_with_macros() {
+    let assembler = Assembler::default();
+    let source = "\
+        const.BASE=1000 \
+        macro.pack.SHIFT \
+            push.SHIFT \
+            pow2 \
+            mul \
+            add \
+        end \
+        macro.pack_store.SHIFT.ADDR \
+            !pack.SHIFT \
+            mem_store.ADDR \
+        end \
+        proc.foo \
+            !pack_store.32.BASE \
+        end \
+        begin \
+            push.1.2 \
+            exec.foo \
+            push.3.4 \
+            !pack_store.16.1001 \
+        end";
+    let program = assembler.compile(source).unwrap();
+
+    // macros are expanded by the parser and must not affect the generated MAST
+    let source = "\
+        proc.foo \
+            push.32 pow2 mul add mem_store.1000 \
+        end \
+        begin \
+            push.1.2 \
+            exec.foo \
+            push.3.4 \
+            push.16 pow2 mul add mem_store.1001 \
+        end";
+    let expected = assembler.compile(source).unwrap();
+    assert_eq!(program.hash(), expected.hash());
+    assert_eq!(format!("{expected}"), format!("{program}"));
+}
+
 #[test]
 fn program_with_exported_procedure() {
     let assembler = Assembler::default();
@@ -1023,6 +1252,35 @@ fn program_with_exported_procedure() {
     assert!(assembler.compile(source).is_err());
 }
 
+#[test]
+fn program_with_unreachable_procedures() {
+    let source = "\
+        proc.foo add end
+        proc.bar call.foo end
+        proc.baz push.1 drop end
+        begin
+            call.baz
+        end";
+    let assembler = Assembler::default();
+    let foo = assembler.compile("begin add end").unwrap().hash();
+    let bar = assembler.compile("proc.foo add end begin call.foo end").unwrap().hash();
+    let baz = assembler.compile("begin push.1 drop end").unwrap().hash();
+
+    // by default, only procedures reachable from the main procedure are retained
+    assert!(assembler.eliminates_dead_code());
+    let program = assembler.compile(source).unwrap();
+    assert!(program.cb_table().has(baz));
+    assert!(!program.cb_table().has(foo));
+    assert!(!program.cb_table().has(bar));
+
+    // if dead code elimination is disabled, all local procedures are retained
+    let assembler = Assembler::default().with_dead_code_elimination(false);
+    let program = assembler.compile(source).unwrap();
+    assert!(program.cb_table().has(baz));
+    assert!(program.cb_table().has(foo));
+    assert!(program.cb_table().has(bar));
+}
+
 // PROGRAMS WITH DYNAMIC CODE BLOCKS
 // ================================================================================================
 
@@ -1103,9 +1361,118 @@ fn program_with_phantom_mast_call() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn program_with_pinned_mast_roots() {
+    let namespace = LibraryNamespace::try_from("dummy".to_string()).unwrap();
+    let path = LibraryPath::try_from("math".to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse("export.foo push.3 add end").unwrap();
+    let modules = vec![Module { path, ast }];
+    let library = DummyLibrary::new(namespace, modules);
+    let assembler = Assembler::default().with_library(&library).unwrap();
+
+    // the body of a program which consists of a single exec is the body of the invoked procedure
+    let foo_root = assembler.compile("use.dummy::math begin exec.math::foo end").unwrap().hash();
+    let bar_root = Assembler::default().compile("begin push.4 mul end").unwrap().hash();
+
+    // invocations of procedures with matching roots are compiled as usual
+    let source = format!(
+        "use.dummy::math
+        proc.bar push.4 mul end
+        begin
+            exec.math::foo.digest!{foo_root} call.math::foo.digest!{foo_root}
+            exec.bar.digest!{bar_root} call.bar.digest!{bar_root}
+        end"
+    );
+    let program = assembler.compile(source).unwrap();
+    let source = "
+        use.dummy::math
+        proc.bar push.4 mul end
+        begin exec.math::foo call.math::foo exec.bar call.bar end";
+    let expected = assembler.compile(source).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+
+    // invocations of procedures with different roots fail to compile
+    let source = format!("use.dummy::math begin exec.math::foo.digest!{bar_root} end");
+    let err = assembler.compile(source).unwrap_err();
+    let expected_error = format!(
+        "invoked procedure has MAST root {foo_root}, but its MAST root was pinned to {bar_root}"
+    );
+    assert_eq!(expected_error, err.to_string());
+
+    let source = format!("proc.bar push.4 mul end begin call.bar.digest!{foo_root} end");
+    let err = assembler.compile(source).unwrap_err();
+    let expected_error = format!(
+        "invoked procedure has MAST root {bar_root}, but its MAST root was pinned to {foo_root}"
+    );
+    assert_eq!(expected_error, err.to_string());
+
+    // pinned roots must be valid digests
+    let source = "proc.bar push.4 mul end begin exec.bar.digest!0x1234 end";
+    let err = assembler.compile(source).unwrap_err();
+    let expected_error = "invalid pinned procedure root: 0x1234 - rpo digest hex label must have 66 characters, but was 6";
+    assert_eq!(expected_error, err.to_string());
+}
+
 // IMPORTS
 // ================================================================================================
 
+#[test]
+fn program_with_imported_constants() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE: &str = "flags";
+    const MODULE_SOURCE: &str = r#"
+        const.FLAG_A=1<<3
+        const.FLAG_B=FLAG_A<<1
+
+        export.noop
+            push.FLAG_A
+            drop
+        end"#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(MODULE_SOURCE).unwrap();
+    let modules = vec![Module { path, ast }];
+    let library = DummyLibrary::new(namespace, modules);
+
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::{MODULE}
+        const.FLAGS=flags::FLAG_A|flags::FLAG_B
+        begin
+            push.FLAGS
+        end"#
+    );
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                push(24) \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // constants can only be referenced from modules which are imported
+    let source = "\
+        const.FLAGS=flags::FLAG_A
+        begin
+            push.FLAGS
+        end";
+    assert!(assembler.compile(source).is_err());
+
+    // referencing an undefined constant of an imported module is an error
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::{MODULE}
+        const.FLAGS=flags::FLAG_C
+        begin
+            push.FLAGS
+        end"#
+    );
+    assert!(assembler.compile(source).is_err());
+}
+
 #[test]
 fn program_with_one_import_and_hex_call() {
     const NAMESPACE: &str = "dummy";
@@ -1369,33 +1736,106 @@ fn program_with_reexported_proc_in_another_library() {
 }
 
 #[test]
-fn module_alias() {
-    const NAMESPACE: &str = "dummy";
-    const MODULE: &str = "math::u64";
-    const PROCEDURE: &str = r#"
-        export.checked_add
-            swap
-            movup.3
-            u32assert2
-            u32overflowing_add
-            movup.3
-            movup.3
+fn program_with_reexported_proc_by_full_path() {
+    // re-exported proc is referenced by its fully-qualified module path without importing it
+    const NAMESPACE: &str = "dummy1";
+    const REF_NAMESPACE: &str = "dummy2";
+    const REF_MODULE: &str = "math::u64";
+    const REF_MODULE_BODY: &str = r#"
+        export.checked_eqz
             u32assert2
-            u32overflowing_add3
             eq.0
-            assert
-        end"#;
+            swap
+            eq.0
+            and
+        end
+        export.unchecked_eqz
+            eq.0
+            swap
+            eq.0
+            and
+        end
+    "#;
 
+    const MODULE: &str = "math";
+    const MODULE_BODY: &str = r#"
+        export.dummy2::math::u64::checked_eqz # re-export
+        export.dummy2::math::u64::unchecked_eqz->notchecked_eqz # re-export with alias
+    "#;
     let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
     let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
-    let ast = ModuleAst::parse(PROCEDURE).unwrap();
-    let modules = vec![Module { path, ast }];
-    let library = DummyLibrary::new(namespace, modules);
-
-    let assembler = Assembler::default().with_library(&library).unwrap();
-
-    let source = "
-        use.dummy::math::u64->bigint
+    let ast = ModuleAst::parse(MODULE_BODY).unwrap();
+    assert_eq!(ast.reexported_procs().len(), 2);
+
+    let ref_namespace = LibraryNamespace::try_from(REF_NAMESPACE.to_string()).unwrap();
+    let ref_path = LibraryPath::try_from(REF_MODULE.to_string())
+        .unwrap()
+        .prepend(&ref_namespace)
+        .unwrap();
+    let ref_ast = ModuleAst::parse(REF_MODULE_BODY).unwrap();
+    let modules = vec![Module { path, ast }];
+    let ref_modules = vec![Module {
+        path: ref_path,
+        ast: ref_ast,
+    }];
+    let dummy_library_1 = DummyLibrary::new(namespace, modules);
+    let dummy_library_2 = DummyLibrary::new(ref_namespace, ref_modules);
+    let assembler = Assembler::default()
+        .with_libraries([&dummy_library_1, &dummy_library_2].into_iter())
+        .unwrap();
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::{MODULE}
+        begin
+            push.4 push.3
+            exec.math::checked_eqz
+            exec.math::notchecked_eqz
+        end"#
+    );
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                push(4) push(3) \
+                u32assert2(0) \
+                eqz swap eqz and \
+                eqz swap eqz and \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // a module referenced by name rather than by its full path must be imported
+    assert!(ModuleAst::parse("export.u64::checked_eqz").is_err());
+}
+
+#[test]
+fn module_alias() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE: &str = "math::u64";
+    const PROCEDURE: &str = r#"
+        export.checked_add
+            swap
+            movup.3
+            u32assert2
+            u32overflowing_add
+            movup.3
+            movup.3
+            u32assert2
+            u32overflowing_add3
+            eq.0
+            assert
+        end"#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(PROCEDURE).unwrap();
+    let modules = vec![Module { path, ast }];
+    let library = DummyLibrary::new(namespace, modules);
+
+    let assembler = Assembler::default().with_library(&library).unwrap();
+
+    let source = "
+        use.dummy::math::u64->bigint
 
         begin
             push.1.0
@@ -1549,6 +1989,853 @@ fn comment_after_program() {
     assert_eq!(expected, format!("{program}"));
 }
 
+// DEAD STORE ELIMINATION
+// ================================================================================================
+
+#[test]
+fn dead_stores_straight_line() {
+    // overwritten and never read values are not stored
+    let source = "
+    proc.foo.3
+        loc_store.0 loc_store.0 loc_load.0
+        loc_storew.1 loc_storew.1 dropw
+        loc_storew.2 loc_store.2 loc_load.2
+    end
+    begin exec.foo end";
+    let expected = "
+    proc.foo.3
+        drop loc_store.0 loc_load.0
+        dropw
+        loc_store.2 loc_load.2
+    end
+    begin exec.foo end";
+    assert_dead_stores_eliminated(source, expected);
+
+    // loc_store does not overwrite the elements read by loc_loadw
+    let source = "
+    proc.foo.1
+        loc_storew.0 loc_store.0 loc_loadw.0
+    end
+    begin exec.foo end";
+    assert_dead_stores_eliminated(source, source);
+}
+
+#[test]
+fn dead_stores_control_flow() {
+    // values read in only one branch are still stored
+    let source = "
+    proc.foo.2
+        loc_store.0 loc_store.1
+        if.true loc_load.0 else push.1 end
+    end
+    begin exec.foo end";
+    let expected = "
+    proc.foo.2
+        loc_store.0 drop
+        if.true loc_load.0 else push.1 end
+    end
+    begin exec.foo end";
+    assert_dead_stores_eliminated(source, expected);
+
+    // values read in the next iteration of a loop are still stored
+    let source = "
+    proc.foo.2
+        loc_store.0
+        while.true
+            loc_load.0 add.1 loc_store.0
+            loc_store.1 dup
+        end
+    end
+    begin exec.foo end";
+    let expected = "
+    proc.foo.2
+        loc_store.0
+        while.true
+            loc_load.0 add.1 loc_store.0
+            drop dup
+        end
+    end
+    begin exec.foo end";
+    assert_dead_stores_eliminated(source, expected);
+
+    let source = "
+    proc.foo.1
+        repeat.4 loc_load.0 add.1 loc_store.0 end
+    end
+    begin exec.foo end";
+    assert_dead_stores_eliminated(source, source);
+}
+
+#[test]
+fn dead_stores_untracked_reads() {
+    // locals may be accessed via their addresses
+    let source = "
+    proc.foo.1
+        loc_store.0 locaddr.0 mem_load
+    end
+    begin exec.foo end";
+    assert_dead_stores_eliminated(source, source);
+
+    // locals may be read by the host
+    let source = "
+    proc.foo.1
+        loc_store.0 emit.1
+    end
+    begin exec.foo end";
+    assert_dead_stores_eliminated(source, source);
+}
+
+/// Asserts that compiling `source` with dead store elimination enabled results in the same
+/// program as compiling `expected` with dead store elimination disabled.
+fn assert_dead_stores_eliminated(source: &str, expected: &str) {
+    let assembler = Assembler::default().with_dead_store_elimination(true);
+    let program = assembler.compile(source).unwrap();
+    let expected = Assembler::default().compile(expected).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+}
+
+// PEEPHOLE OPTIMIZATION
+// ================================================================================================
+
+#[test]
+fn peephole_redundant_ops() {
+    // pushed and immediately dropped values are removed
+    assert_peephole_optimized("begin push.0 drop push.5 drop dup.3 drop add end", "begin add end");
+
+    // operations which cancel each other out are removed, including the ones which become
+    // adjacent after other operations are removed
+    let source = "
+    begin
+        swap swap movup.3 movdn.3
+        movdn.2 swapw movup.7 push.5 drop movdn.7 swapw movup.2
+        neg neg mul
+    end";
+    assert_peephole_optimized(source, "begin mul end");
+
+    // a value pushed right after the same value is duplicated instead
+    let source = "begin push.7 push.7 push.1.1.1 end";
+    let expected = "begin push.7 dup push.1 dup dup end";
+    assert_peephole_optimized(source, expected);
+
+    // adjacent pushes are merged by duplicating the values already pushed by them
+    let source = "begin push.5.7.5.7 push.9.1.0.9.1 dup.1 push.1 end";
+    let expected = "begin push.5.7 dup.1 dup.1 push.9.1.0 dup.2 dup.2 dup.1 dup.1 end";
+    assert_peephole_optimized(source, expected);
+
+    // values pushed before other operations, or deeper than 15 items, are not duplicated
+    let source = "begin push.5 add push.5 push.6.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0 push.0.6 end";
+    assert_peephole_optimized(source, source);
+
+    // operations which do not cancel each other out are preserved
+    let source = "begin movup.2 movdn.3 swapw swapw.2 push.2.3 end";
+    assert_peephole_optimized(source, source);
+
+    // a body which is removed entirely is compiled into a noop
+    let program = Assembler::default()
+        .with_optimization_level(OptimizationLevel::Peephole)
+        .compile("begin push.0 drop end")
+        .unwrap();
+    assert_eq!("begin span noop end end", format!("{program}"));
+}
+
+#[test]
+fn peephole_block_boundaries() {
+    // operations are not rewritten across control flow blocks
+    let source =
+        "begin push.1 if.true drop push.0 else push.2 drop end swap while.true swap end end";
+    let expected = "begin push.1 if.true drop push.0 end swap while.true swap end end";
+    assert_peephole_optimized(source, expected);
+
+    // procedures are optimized as well, but not across exec boundaries
+    let source = "proc.foo swap swap drop end begin push.3 exec.foo end";
+    let expected = "proc.foo drop end begin push.3 exec.foo end";
+    assert_peephole_optimized(source, expected);
+}
+
+#[test]
+fn peephole_decorators() {
+    // operations to which decorators are attached are not rewritten
+    let source = "begin push.0 adv.push_u64div drop swap emit.1 swap end";
+    let assembler = Assembler::default().with_optimization_level(OptimizationLevel::Peephole);
+    let program = assembler.compile(source).unwrap();
+    let expected = Assembler::default().compile(source).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+
+    // in debug mode, operations of each instruction are tracked by AsmOp decorators
+    let source = "begin push.1 push.1 swap swap end";
+    let assembler = Assembler::default()
+        .with_debug_mode(true)
+        .with_optimization_level(OptimizationLevel::Peephole);
+    let program = assembler.compile(source).unwrap();
+    let expected = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+}
+
+#[test]
+fn peephole_dead_stack_ops() {
+    // instructions whose results are immediately dropped are removed before the instructions are
+    // compiled, and thus, they are removed in debug mode as well
+    let source = "
+    const.A=5
+    proc.foo.1 padw dropw push.A dup.1 drop drop locaddr.0 drop add end
+    begin
+        push.1.2.3 drop dup.1 drop
+        if.true push.A.A drop drop else dupw.2 dropw end
+        exec.foo
+    end";
+    let expected = "
+    proc.foo.1 add end
+    begin
+        push.1.2
+        if.true end
+        exec.foo
+    end";
+    let assembler = Assembler::default()
+        .with_debug_mode(true)
+        .with_optimization_level(OptimizationLevel::Peephole);
+    let program = assembler.compile(source).unwrap();
+    let expected = Assembler::default().with_debug_mode(true).compile(expected).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+
+    // the numbers of removed instructions are recorded per procedure
+    let stats = assembler.optimization_stats();
+    assert_eq!(Some(8), stats.get("#exec::foo"));
+    assert_eq!(Some(10), stats.get("#exec::#main"));
+    assert_eq!(18, stats.num_removed_ops());
+
+    // sequences interrupted by other instructions are preserved
+    let source = "begin dup.1 trace.1 drop padw add dropw end";
+    let assembler = Assembler::default().with_optimization_level(OptimizationLevel::Peephole);
+    let program = assembler.compile(source).unwrap();
+    let expected = Assembler::default().compile(source).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+    assert!(assembler.optimization_stats().is_empty());
+}
+
+/// Asserts that compiling `source` with peephole optimization results in the same program as
+/// compiling `expected` without optimizations.
+fn assert_peephole_optimized(source: &str, expected: &str) {
+    let assembler = Assembler::default().with_optimization_level(OptimizationLevel::Peephole);
+    assert_eq!(assembler.optimization_level(), OptimizationLevel::Peephole);
+    let program = assembler.compile(source).unwrap();
+    let expected = Assembler::default().compile(expected).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+}
+
+// CONDITIONAL ASSEMBLY
+// ================================================================================================
+
+#[test]
+fn conditional_assembly_program() {
+    let source = "
+    proc.foo
+        @if debug
+            dup assert
+        @end
+        add
+    end
+    begin
+        push.1.2
+        @if fast
+            exec.foo
+        @else
+            push.3 @if debug dup assert @end add add
+        @end
+        push.4
+    end";
+
+    let expected = "
+    proc.foo add end
+    begin push.1.2 push.3 add add push.4 end";
+    assert_features_resolved(source, &[], expected);
+
+    let expected = "
+    begin push.1.2 push.3 dup assert add add push.4 end";
+    assert_features_resolved(source, &["debug"], expected);
+
+    let expected = "
+    proc.foo dup assert add end
+    begin push.1.2 exec.foo push.4 end";
+    assert_features_resolved(source, &["debug", "fast"], expected);
+
+    // a conditional block which results in no code is compiled into a noop
+    let source = "begin @if debug push.1 drop @end end";
+    let program = Assembler::default().compile(source).unwrap();
+    assert_eq!("begin span noop end end", format!("{program}"));
+}
+
+#[test]
+fn conditional_assembly_library() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE: &str = "checks";
+    const MODULE_SOURCE: &str = r#"
+        export.checked_add
+            @if debug
+                u32assert2
+            @end
+            u32wrapping_add
+        end"#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(MODULE_SOURCE).unwrap();
+    let modules = vec![Module { path, ast }];
+    let library = DummyLibrary::new(namespace, modules);
+
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::{MODULE}
+        begin
+            push.1.2
+            exec.checks::checked_add
+        end"#
+    );
+
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    assert!(!assembler.is_feature_enabled("debug"));
+    let program = assembler.compile(&source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                pad incr push(2) u32add drop \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    let assembler = Assembler::default().with_features(["debug"]).with_library(&library).unwrap();
+    assert!(assembler.is_feature_enabled("debug"));
+    let program = assembler.compile(&source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                pad incr push(2) u32assert2(0) u32add drop \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+/// Asserts that compiling `source` with the specified features enabled results in the same
+/// program as compiling `expected` without any features.
+fn assert_features_resolved(source: &str, features: &[&str], expected: &str) {
+    let assembler = Assembler::default().with_features(features.iter().copied());
+    let program = assembler.compile(source).unwrap();
+    let expected = Assembler::default().compile(expected).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+}
+
+// INSTRUCTION ALIASES
+// ================================================================================================
+
+#[test]
+fn deprecated_instruction_aliases() {
+    let source = "begin push.5.3 u32unchecked_shl.2 u32checked_and u32unchecked_divmod.3 end";
+    let expected = "begin push.5.3 u32shl.2 u32and u32divmod.3 end";
+    let program = Assembler::default().compile(source).unwrap();
+    let expected = Assembler::default().compile(expected).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+
+    // deprecated mnemonics are rejected when aliases are disabled
+    let assembler = Assembler::default().with_instruction_aliases(InstructionAliases::none());
+    let error = assembler.compile(source).unwrap_err();
+    assert!(error.to_string().contains("instruction 'u32unchecked_shl.2' is invalid"));
+}
+
+#[test]
+fn custom_instruction_aliases() {
+    let aliases = InstructionAliases::none()
+        .with_alias("u32shift_left", "u32shl")
+        .with_alias("add", "mul")
+        .with_alias("foo", "bar");
+    let assembler = Assembler::default().with_instruction_aliases(aliases);
+
+    // aliases which collide with mnemonics of existing instructions are ignored
+    let program = assembler.compile("begin push.5 u32shift_left.2 add end").unwrap();
+    let expected = Assembler::default().compile("begin push.5 u32shl.2 add end").unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+
+    // aliases which do not map to instructions are rejected
+    let error = assembler.compile("begin foo end").unwrap_err();
+    assert!(error.to_string().contains("instruction 'foo' is invalid"));
+}
+
+#[test]
+fn versioned_instruction_aliases() {
+    let version = |version: &str| Version::try_from(version).unwrap();
+    let u32_source = "begin push.5.3 u32unchecked_shl.2 end";
+    let hash_source = "begin padw padw padw rpperm end";
+    let compile = |version: Version, source: &str| {
+        Assembler::default().with_instruction_set_version(version).compile(source)
+    };
+
+    // mnemonics renamed after the specified version are accepted
+    let expected = Assembler::default().compile("begin padw padw padw hperm end").unwrap();
+    let program = compile(version("0.3.0"), hash_source).unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+    assert!(compile(version("0.3.0"), u32_source).is_ok());
+    assert!(compile(version("0.7.0"), u32_source).is_ok());
+
+    // mnemonics which had already been renamed in the specified version are rejected
+    assert!(compile(version("0.4.0"), hash_source).is_err());
+    assert!(compile(version("0.7.0"), hash_source).is_err());
+    assert!(compile(version("0.8.0"), u32_source).is_err());
+
+    // the default table contains all deprecated mnemonics
+    let aliases = InstructionAliases::default();
+    assert_eq!(aliases, InstructionAliases::for_version(Version::MIN));
+    assert_eq!(aliases.get("rpperm"), Some("hperm"));
+    assert_eq!(aliases.renamed_in("u32unchecked_shl"), Some(version("0.8.0")));
+    assert_eq!(InstructionAliases::none().with_alias("foo", "add").renamed_in("foo"), None);
+}
+
+// STACK DEPTH ANALYSIS
+// ================================================================================================
+
+#[test]
+fn stack_depth_analysis() {
+    let source = "
+    proc.branches
+        if.true
+            push.1
+        end
+    end
+    proc.spin
+        push.1
+        while.true
+            push.1 push.1
+        end
+    end
+    proc.pop
+        drop
+    end
+    begin
+        repeat.17
+            exec.pop
+        end
+    end";
+
+    let program = ProgramAst::parse(source).unwrap();
+    let warnings = StackDepthAnalyzer::new().analyze_program(&program);
+    let messages = warnings.iter().map(|warning| warning.message()).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "branches of `if.true` have different stack effects: the first branch changes the \
+            stack depth by +1, but the second branch changes it by +0",
+            "body of a `while.true` loop changes the stack depth by +1 on every iteration",
+            "program may underflow the stack: it accesses 17 elements of its input stack, but \
+            only the top 16 elements are guaranteed to be available",
+        ]
+    );
+    assert_eq!(warnings[0].primary_label().location().line(), 3);
+
+    // the 16 elements of the program inputs can be accessed
+    let program = ProgramAst::parse("begin dup.15 drop repeat.16 drop end end").unwrap();
+    assert!(StackDepthAnalyzer::new().analyze_program(&program).is_empty());
+}
+
+#[test]
+fn stack_depth_analysis_annotations() {
+    let source = "
+    proc.maybe_push
+        if.true
+            push.1
+        end
+    end
+    begin
+        repeat.9
+            exec.maybe_push
+        end
+    end";
+
+    // annotated procedures are not analyzed, and their annotations are used to analyze callers
+    let program = ProgramAst::parse(source).unwrap();
+    let analyzer = StackDepthAnalyzer::new().with_annotation("maybe_push", StackEffect::new(2, 0));
+    let warnings = analyzer.analyze_program(&program);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message().starts_with("program may underflow the stack"));
+}
+
+#[test]
+fn stack_depth_analysis_signatures() {
+    let source = "
+    #! stack_in: [b, a] stack_out: [c]
+    proc.add_two
+        add
+    end
+
+    #! stack_in: [b, a] stack_out: [c]
+    proc.wrong
+        push.1 add
+    end
+
+    #! stack_in: [a]
+    #! stack_out: [b]
+    proc.caller
+        exec.add_two
+    end
+
+    begin
+        push.1 push.2 exec.add_two
+    end";
+
+    let program = ProgramAst::parse(source).unwrap();
+    assert_eq!(
+        program.procedures()[0].signature,
+        Some(ProcedureSignature::new(["b", "a"], ["c"]))
+    );
+
+    let warnings = StackDepthAnalyzer::new().analyze_program(&program);
+    let messages = warnings.iter().map(|warning| warning.message()).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "procedure `wrong` declares stack effect 2 -> 1 in its signature, but its body has \
+            stack effect 1 -> 1",
+            "procedure `add_two` consumes 2 elements according to its signature, but only 1 \
+            elements are available according to the signature of the invoking procedure",
+            "procedure `caller` declares stack effect 1 -> 1 in its signature, but its body has \
+            stack effect 2 -> 1",
+        ]
+    );
+
+    // malformed signatures are rejected by the parser
+    let source = "
+    #! stack_in: [a, b]
+    export.foo
+        add
+    end";
+    let err = ModuleAst::parse(source).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "invalid procedure signature: 'stack_in:' is declared without 'stack_out:'"
+    );
+}
+
+// MODULE CACHE
+// ================================================================================================
+
+#[test]
+fn program_with_module_cache() {
+    const NAMESPACE: &str = "dummy";
+    const MATH_MODULE: &str = r#"
+        use.dummy::utils
+
+        export.quadruple
+            exec.utils::double
+            exec.utils::double
+        end"#;
+    const PROGRAM: &str = "use.dummy::math begin push.1 exec.math::quadruple end";
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let math_path = LibraryPath::new("dummy::math").unwrap();
+    let utils_path = LibraryPath::new("dummy::utils").unwrap();
+    let build_library = |utils_source: &str| {
+        let modules = vec![
+            Module::new(math_path.clone(), ModuleAst::parse(MATH_MODULE).unwrap()),
+            Module::new(utils_path.clone(), ModuleAst::parse(utils_source).unwrap()),
+        ];
+        DummyLibrary::new(namespace.clone(), modules)
+    };
+    let library = build_library("export.double dup add end");
+
+    // compiled library modules are added to the cache
+    let assembler = Assembler::default()
+        .with_library(&library)
+        .unwrap()
+        .with_module_cache(ModuleCache::new());
+    let program = assembler.compile(PROGRAM).unwrap();
+    let module_cache = assembler.into_module_cache().unwrap();
+    assert_eq!(module_cache.len(), 2);
+    assert!(module_cache.contains_module(&math_path));
+    assert!(module_cache.contains_module(&utils_path));
+
+    // library modules are loaded from the cache by another assembler
+    let assembler = Assembler::default()
+        .with_library(&library)
+        .unwrap()
+        .with_module_cache(module_cache);
+    assert_eq!(program.hash(), assembler.compile(PROGRAM).unwrap().hash());
+    let module_cache = assembler.into_module_cache().unwrap();
+    assert_eq!(module_cache.len(), 2);
+
+    // the math module is compiled again when its dependency changes
+    let library = build_library("export.double push.2 mul end");
+    let expected = Assembler::default().with_library(&library).unwrap().compile(PROGRAM).unwrap();
+    assert_ne!(program.hash(), expected.hash());
+
+    let assembler = Assembler::default()
+        .with_library(&library)
+        .unwrap()
+        .with_module_cache(module_cache);
+    assert_eq!(expected.hash(), assembler.compile(PROGRAM).unwrap().hash());
+    let mut module_cache = assembler.into_module_cache().unwrap();
+    assert_eq!(module_cache.len(), 3);
+
+    // all compiled modules with the specified path are evicted from the cache
+    assert!(module_cache.evict(&utils_path));
+    assert!(!module_cache.evict(&utils_path));
+    assert!(!module_cache.contains_module(&utils_path));
+    assert_eq!(module_cache.len(), 1);
+
+    // the cache can be pre-warmed with all library modules
+    let assembler = Assembler::default()
+        .with_library(&library)
+        .unwrap()
+        .with_module_cache(ModuleCache::new());
+    assembler.warm_module_cache().unwrap();
+    assert_eq!(assembler.module_cache().unwrap().len(), 2);
+    assert_eq!(expected.hash(), assembler.compile(PROGRAM).unwrap().hash());
+
+    // the cache cannot be pre-warmed if it has not been set
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    assert_eq!(assembler.warm_module_cache(), Err(AssemblyError::ModuleCacheNotSet));
+}
+
+// DATA SEGMENTS
+// ================================================================================================
+
+#[test]
+fn program_with_data_segment() {
+    let source = "\
+        const.ONE=1
+        data.table
+            ONE.2.3.4
+            5 6 7 8
+            0x09 10 11 12
+        end
+        begin
+            push.100 exec.table
+        end";
+    let program = ProgramAst::parse(source).unwrap();
+    let data = program.procedures()[0].data.as_ref().unwrap();
+    assert_eq!(data.num_words(), 3);
+    assert_eq!(data.values(), (1..=12).map(Felt::new).collect::<Vec<_>>().as_slice());
+
+    // the values of the data segment are added to the advice map under their commitment
+    let assembler = Assembler::default();
+    assembler.compile_ast(&program).unwrap();
+    let values = (1..=12).map(Felt::new).collect::<Vec<_>>();
+    let commitment = DataSegment::new(values.clone()).unwrap().commitment();
+    assert_eq!(assembler.advice_map().len(), 1);
+    assert_eq!(assembler.advice_map().get(&commitment), Some(&values));
+
+    // data segments are preserved when the program is formatted and serialized
+    let formatted = ProgramAst::parse(&program.to_string()).unwrap();
+    assert_eq!(program.procedures()[0].data, formatted.procedures()[0].data);
+    let deserialized =
+        ProgramAst::from_bytes(&program.to_bytes(AstSerdeOptions::new(false))).unwrap();
+    assert_eq!(program.procedures()[0].data, deserialized.procedures()[0].data);
+}
+
+#[test]
+fn program_with_imported_data_segment() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE: &str = "tables";
+    const MODULE_SOURCE: &str = "\
+        data.powers_of_two
+            1.2.4.8
+            16.32.64.128
+        end";
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(MODULE_SOURCE).unwrap();
+    assert!(ast.procs()[0].is_export);
+    let modules = vec![Module { path, ast }];
+    let library = DummyLibrary::new(namespace, modules);
+
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::{MODULE}
+        begin
+            push.0 exec.{MODULE}::powers_of_two
+        end"#
+    );
+    assembler.compile(source).unwrap();
+    let values = [1, 2, 4, 8, 16, 32, 64, 128].into_iter().map(Felt::new).collect::<Vec<_>>();
+    let commitment = DataSegment::new(values.clone()).unwrap().commitment();
+    assert_eq!(assembler.advice_map().get(&commitment), Some(&values));
+}
+
+#[test]
+fn invalid_data_segments() {
+    // the number of values must be a multiple of 4
+    let source = "data.table 1 2 3 end begin push.0 exec.table end";
+    let err = ProgramAst::parse(source).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "invalid data section: number of values in a data segment must be a multiple of 4"
+    );
+
+    // data segments cannot be empty
+    let source = "data.table end begin push.0 exec.table end";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // data sections must be terminated with an `end` token
+    let source = "data.table 1 2 3 4 begin push.0 exec.table end";
+    let err = ProgramAst::parse(source).unwrap_err();
+    assert_eq!(err.message(), "data section 'table' has no matching end");
+
+    // names of data sections cannot collide with procedure names
+    let source = "proc.table push.1 end data.table 1 2 3 4 end begin push.0 exec.table end";
+    assert!(ProgramAst::parse(source).is_err());
+
+    // values must be valid field elements
+    let source = "data.table 1 2 3 0xffffffffffffffff end begin push.0 exec.table end";
+    assert!(ProgramAst::parse(source).is_err());
+}
+
+// MAST FOREST
+// ================================================================================================
+
+#[test]
+fn program_from_mast_forest() {
+    let mut forest = MastForest::new();
+    let double = forest.add_span(vec![Operation::Dup0, Operation::Add]).unwrap();
+    let push = forest.add_span(vec![Operation::Push(Felt::new(3))]).unwrap();
+    let call = forest.add_call(double).unwrap();
+    let split = forest.add_split(call, push).unwrap();
+    let body = forest.add_span(vec![Operation::Pad]).unwrap();
+    let loop_node = forest.add_loop(body).unwrap();
+    let root = forest.add_join(split, loop_node).unwrap();
+
+    // this procedure is not reachable from the entrypoint
+    let unused = forest.add_span(vec![Operation::Drop]).unwrap();
+    forest.add_call(unused).unwrap();
+
+    let program = Assembler::default().compile_mast_forest(&forest, root).unwrap();
+
+    let double = CodeBlock::new_span(vec![Operation::Dup0, Operation::Add]);
+    let expected = CodeBlock::new_join([
+        CodeBlock::new_split(
+            CodeBlock::new_call(double.hash()),
+            CodeBlock::new_span(vec![Operation::Push(Felt::new(3))]),
+        ),
+        CodeBlock::new_loop(CodeBlock::new_span(vec![Operation::Pad])),
+    ]);
+    assert_eq!(program.hash(), expected.hash());
+    assert!(program.cb_table().has(double.hash()));
+    assert!(!program.cb_table().has(CodeBlock::new_span(vec![Operation::Drop]).hash()));
+}
+
+#[test]
+fn program_from_mast_forest_with_syscall() {
+    let kernel_source = "export.foo add end";
+    let assembler = Assembler::default().with_kernel(kernel_source).unwrap();
+    let foo = CodeBlock::new_span(vec![Operation::Add]);
+
+    let mut forest = MastForest::new();
+    let root = forest.add_syscall(foo.hash());
+    let program = assembler.compile_mast_forest(&forest, root).unwrap();
+    assert_eq!(program.hash(), CodeBlock::new_syscall(foo.hash()).hash());
+    assert!(program.cb_table().has(foo.hash()));
+
+    // procedures invoked via syscall must be exported from the kernel
+    let bar = CodeBlock::new_span(vec![Operation::Mul]);
+    let root = forest.add_syscall(bar.hash());
+    assert_eq!(
+        assembler.compile_mast_forest(&forest, root).unwrap_err(),
+        AssemblyError::syscall_target_not_in_kernel(bar.hash())
+    );
+}
+
+#[test]
+fn invalid_mast_forest_nodes() {
+    let mut forest = MastForest::new();
+    assert_eq!(forest.add_span(Vec::new()), Err(AssemblyError::empty_span_node()));
+    assert_eq!(
+        forest.add_span(vec![Operation::Add, Operation::Join]),
+        Err(AssemblyError::control_op_in_span_node(&Operation::Join))
+    );
+
+    // nodes must be added to the forest before they are referenced
+    let mut other_forest = MastForest::new();
+    other_forest.add_dyn();
+    let node = other_forest.add_dyncall();
+    assert_eq!(forest.add_loop(node), Err(AssemblyError::mast_node_not_found(node)));
+    assert_eq!(
+        Assembler::default().compile_mast_forest(&forest, node).unwrap_err(),
+        AssemblyError::mast_node_not_found(node)
+    );
+}
+
+// DISASSEMBLER
+// ================================================================================================
+
+#[test]
+fn program_disassembly() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE: &str = "math";
+    const MODULE_SOURCE: &str = "export.select if.true add else mul end end";
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(MODULE_SOURCE).unwrap();
+    let library = DummyLibrary::new(namespace, vec![Module { path, ast }]);
+
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::{MODULE}
+        proc.foo push.7 end
+        begin
+            push.1
+            if.true
+                exec.{MODULE}::select
+            else
+                call.foo
+            end
+            while.true push.0 end
+        end"#
+    );
+    let program = assembler.compile(source).unwrap();
+
+    // programs survive serialization
+    let program = Program::read_from_bytes(&program.to_bytes()).unwrap();
+
+    let disassembler = Disassembler::new().with_library(&library).unwrap();
+    let output = disassembler.disassemble(&program);
+    let foo = CodeBlock::new_span(vec![Operation::Push(Felt::new(7))]).hash();
+    let foo_label = format!("0x{}", vm_core::utils::to_hex(&foo.as_bytes()).unwrap());
+    let lines = output.lines().collect::<Vec<_>>();
+
+    // called procedures are printed before the program
+    assert_eq!(lines[0], format!("proc.{foo_label}"));
+    assert!(lines[1].starts_with("    push.7 "));
+    assert!(lines[1].ends_with("# immediate 0x0000000000000007"));
+    assert_eq!(lines[2], "end");
+
+    // procedures inlined via exec are named if they are exported from a known library
+    let call_foo = format!("        call.{foo_label}");
+    let expected = [
+        "begin",
+        "    pad",
+        "    incr",
+        "    if.true",
+        "        # exec.dummy::math::select",
+        "        if.true",
+        "            add",
+        "        else",
+        "            mul",
+        "        end",
+        "        # end exec.dummy::math::select",
+        "    else",
+        call_foo.as_str(),
+        "    end",
+        "    while.true",
+        "        pad",
+        "    end",
+        "end",
+    ];
+    let begin = lines.iter().position(|line| *line == "begin").unwrap();
+    assert_eq!(lines[begin..], expected);
+
+    // without libraries, procedures are referred to by their MAST roots
+    let output = crate::disassemble(&program);
+    assert!(!output.contains("dummy::math::select"));
+}
+
 // ERRORS
 // ================================================================================================
 
@@ -1697,6 +2984,69 @@ fn invalid_repeat() {
     }
 }
 
+#[test]
+fn dyn_repeat() {
+    // loop counters are kept in locals allocated after the declared locals
+    let source = "
+        export.foo.2
+            repeat.dyn.max=5
+                repeat.dyn.max=7
+                    push.0
+                end
+                push.0
+            end
+        end";
+    let module = ModuleAst::parse(source).unwrap();
+    assert_eq!(module.procs()[0].num_locals, 4);
+
+    let expected = "\
+export.foo.4
+    push.5
+    loc_store.2
+    while.true
+        loc_load.2
+        dup.0
+        eq.0
+        assertz
+        sub.1
+        loc_store.2
+        push.7
+        loc_store.3
+        while.true
+            loc_load.3
+            dup.0
+            eq.0
+            assertz
+            sub.1
+            loc_store.3
+            push.0
+        end
+        push.0
+    end
+end
+
+";
+    assert_eq!(format!("{module}"), expected);
+
+    // the bound can be specified via a constant, and the loop can be used in the program body
+    let source = "const.MAX=3 begin repeat.dyn.max=MAX push.0 end end";
+    let program = ProgramAst::parse(source).unwrap();
+    assert_eq!(program.num_locals(), 1);
+    assert!(Assembler::default().compile(source).is_ok());
+
+    // invalid bounds
+    let assembler = Assembler::default();
+    let program = assembler.compile("begin repeat.dyn push.0 end end");
+    assert_eq!(
+        program.unwrap_err().to_string(),
+        "malformed instruction 'repeat.dyn': expected format `repeat.dyn.max=<max_iterations>`"
+    );
+    let program = assembler.compile("begin repeat.dyn.max=0 push.0 end end");
+    assert!(program.is_err());
+    let program = assembler.compile("begin repeat.dyn.min=3 push.0 end end");
+    assert!(program.is_err());
+}
+
 #[test]
 fn invalid_while() {
     let assembler = Assembler::default();
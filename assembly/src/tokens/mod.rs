@@ -1,5 +1,9 @@
 use super::{
-    ast::{parse_param_with_constant_lookup, InvocationTarget},
+    ast::{
+        parse_named_param_with_constant_lookup, parse_param_with_constant_lookup, InvocationTarget,
+        ALIAS_LABEL_PARSER, CONSTANT_LABEL_PARSER, FEATURE_LABEL_PARSER, MACRO_LABEL_PARSER,
+    },
+    crypto::hash::RpoDigest,
     ByteReader, ByteWriter, Deserializable, DeserializationError, LibraryPath, ParsingError,
     ProcedureName, Serializable,
 };
@@ -39,10 +43,13 @@ pub struct Token<'a> {
 impl<'a> Token<'a> {
     // DEFINITION TOKENS
     // --------------------------------------------------------------------------------------------
+    pub const ALIAS: &'static str = "alias";
     pub const BEGIN: &'static str = "begin";
     pub const CONST: &'static str = "const";
+    pub const DATA: &'static str = "data";
     pub const END: &'static str = "end";
     pub const EXPORT: &'static str = "export";
+    pub const MACRO: &'static str = "macro";
     pub const PROC: &'static str = "proc";
     pub const USE: &'static str = "use";
 
@@ -53,14 +60,23 @@ impl<'a> Token<'a> {
     pub const EXEC: &'static str = "exec";
     pub const IF: &'static str = "if";
     pub const REPEAT: &'static str = "repeat";
+    pub const REPEAT_DYN: &'static str = "dyn";
     pub const SYSCALL: &'static str = "syscall";
     pub const WHILE: &'static str = "while";
 
+    // CONDITIONAL ASSEMBLY TOKENS
+    // --------------------------------------------------------------------------------------------
+    pub const FEATURE_IF: &'static str = "@if";
+    pub const FEATURE_ELSE: &'static str = "@else";
+    pub const FEATURE_END: &'static str = "@end";
+
     // DELIMITERS
     // --------------------------------------------------------------------------------------------
     pub const DOC_COMMENT_PREFIX: &'static str = "#!";
     pub const COMMENT_PREFIX: char = '#';
     pub const ALIAS_DELIM: &'static str = "->";
+    pub const MACRO_PREFIX: char = '!';
+    pub const PINNED_ROOT_PREFIX: &'static str = "digest!";
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -102,6 +118,20 @@ impl<'a> Token<'a> {
         &self.parts
     }
 
+    /// Returns a copy of this token in which the first part is replaced with the specified
+    /// mnemonic, e.g. `u32unchecked_shl.2` becomes `u32shl.2` for mnemonic `u32shl`.
+    pub fn with_mnemonic<'b>(&self, mnemonic: &'b str) -> Token<'b>
+    where
+        'a: 'b,
+    {
+        let mut parts = self.parts.clone();
+        parts[0] = mnemonic;
+        Token {
+            parts,
+            location: self.location,
+        }
+    }
+
     // STATE MUTATOR
     // --------------------------------------------------------------------------------------------
     /// Updates the contents of this token from the specified string and position.
@@ -139,12 +169,15 @@ impl<'a> Token<'a> {
         }
     }
 
-    pub fn validate_begin(&self) -> Result<(), ParsingError> {
+    /// Parses a `begin` or `begin.<num_locals>` token and returns the number of locals declared
+    /// for the program body.
+    pub fn parse_begin(&self) -> Result<u16, ParsingError> {
         assert_eq!(Self::BEGIN, self.parts[0], "not a begin");
-        if self.num_parts() > 1 {
-            Err(ParsingError::extra_param(self))
-        } else {
-            Ok(())
+        match self.num_parts() {
+            0 => unreachable!(),
+            1 => Ok(0),
+            2 => validate_proc_locals(self.parts[1], self),
+            _ => Err(ParsingError::extra_param(self)),
         }
     }
 
@@ -170,6 +203,89 @@ impl<'a> Token<'a> {
             .map_err(|err| ParsingError::invalid_proc_name(self, err))
     }
 
+    /// Parses a `data.<name>` declaration and returns the name of the procedure generated for the
+    /// data section.
+    pub fn parse_data(&self) -> Result<ProcedureName, ParsingError> {
+        assert_eq!(Self::DATA, self.parts[0], "not a data section");
+        match self.num_parts() {
+            0 => unreachable!(),
+            1 => Err(ParsingError::missing_param(self, "data.<name>")),
+            2 => ProcedureName::try_from(self.parts[1].to_string())
+                .map_err(|err| ParsingError::invalid_proc_name(self, err)),
+            _ => Err(ParsingError::extra_param(self)),
+        }
+    }
+
+    /// Parses an `alias.<name>=loc.<index>` declaration and returns the name of the alias together
+    /// with the index of the local it refers to.
+    pub fn parse_alias(
+        &self,
+        constants: &BTreeMap<String, u64>,
+    ) -> Result<(&str, u16), ParsingError> {
+        assert_eq!(Self::ALIAS, self.parts[0], "not an alias");
+        match self.num_parts() {
+            0 => unreachable!(),
+            1 | 2 => Err(ParsingError::missing_param(self, "alias.<name>=loc.<index>")),
+            3 => {
+                let (name, target) = match self.parts[1].split_once('=') {
+                    Some(declaration) => declaration,
+                    None => {
+                        return Err(ParsingError::missing_param(self, "alias.<name>=loc.<index>"))
+                    }
+                };
+                if target != "loc" {
+                    return Err(ParsingError::invalid_alias_target(self, target));
+                }
+                let name = ALIAS_LABEL_PARSER
+                    .parse_label(name)
+                    .map_err(|err| ParsingError::invalid_alias_name(self, err))?;
+                let index = parse_param_with_constant_lookup::<u16>(self, 2, constants)?;
+                Ok((name, index))
+            }
+            _ => Err(ParsingError::extra_param(self)),
+        }
+    }
+
+    /// Parses a `macro.<name>[.<PARAM>]*` declaration and returns the name of the macro together
+    /// with the names of its parameters.
+    pub fn parse_macro(&self) -> Result<(&str, Vec<&str>), ParsingError> {
+        assert_eq!(Self::MACRO, self.parts[0], "not a macro");
+        if self.num_parts() == 1 {
+            return Err(ParsingError::missing_param(self, "macro.<name>"));
+        }
+        let name = MACRO_LABEL_PARSER
+            .parse_label(self.parts[1])
+            .map_err(|err| ParsingError::invalid_macro_name(self, err))?;
+        let mut params = Vec::with_capacity(self.num_parts() - 2);
+        for param in &self.parts[2..] {
+            let param = CONSTANT_LABEL_PARSER
+                .parse_label(param)
+                .map_err(|err| ParsingError::invalid_macro_param(self, err))?;
+            if params.contains(&param) {
+                return Err(ParsingError::duplicate_macro_param(self, param));
+            }
+            params.push(param);
+        }
+        Ok((name, params))
+    }
+
+    /// Parses a `!<name>[.<arg>]*` macro invocation and returns the name of the invoked macro
+    /// together with the values of its arguments. An argument can be either a number or a name of
+    /// a constant.
+    pub fn parse_macro_invocation(
+        &self,
+        constants: &BTreeMap<String, u64>,
+    ) -> Result<(&str, Vec<u64>), ParsingError> {
+        let name = self.parts[0].strip_prefix(Self::MACRO_PREFIX).expect("not a macro invocation");
+        let name = MACRO_LABEL_PARSER
+            .parse_label(name)
+            .map_err(|err| ParsingError::invalid_macro_name(self, err))?;
+        let args = (1..self.num_parts())
+            .map(|idx| parse_param_with_constant_lookup::<u64>(self, idx, constants))
+            .collect::<Result<_, _>>()?;
+        Ok((name, args))
+    }
+
     pub fn parse_reexported_proc(
         &self,
     ) -> Result<(ProcedureName, ProcedureName, &str), ParsingError> {
@@ -178,18 +294,18 @@ impl<'a> Token<'a> {
             0 => unreachable!(),
             1 => Err(ParsingError::missing_param(self, "export.<procedure_path>")),
             2 => {
-                if self.parts[1].matches(LibraryPath::PATH_DELIM).count() != 1 {
-                    return Err(ParsingError::invalid_reexported_procedure(self, self.parts[1]));
-                }
-                // get module and proc name
-                let (module, proc_name_with_alias) = self.parts()[1]
-                    .split_once(LibraryPath::PATH_DELIM)
-                    .expect("Invalid procedure export {self.parts[1]}");
-
                 // get the alias name if it exists else export it with the original name
-                let (ref_name, proc_name) = proc_name_with_alias
+                let (proc_path, alias) = self.parts[1]
                     .split_once(Self::ALIAS_DELIM)
-                    .unwrap_or((proc_name_with_alias, proc_name_with_alias));
+                    .map_or((self.parts[1], None), |(path, alias)| (path, Some(alias)));
+
+                // get module and proc name; the module is either the name of an imported module
+                // or a fully-qualified module path
+                let (module, ref_name) =
+                    proc_path.rsplit_once(LibraryPath::PATH_DELIM).ok_or_else(|| {
+                        ParsingError::invalid_reexported_procedure(self, self.parts[1])
+                    })?;
+                let proc_name = alias.unwrap_or(ref_name);
 
                 // validate the procedure names
                 let ref_name = ProcedureName::try_from(ref_name.to_string())
@@ -254,6 +370,31 @@ impl<'a> Token<'a> {
         }
     }
 
+    /// Returns true if this token starts a `repeat.dyn.max=<max_iterations>` statement.
+    pub fn is_dyn_repeat(&self) -> bool {
+        self.parts[0] == Self::REPEAT && self.parts.get(1) == Some(&Self::REPEAT_DYN)
+    }
+
+    /// Parses a `repeat.dyn.max=<max_iterations>` statement and returns the maximum number of
+    /// iterations of the loop.
+    pub fn parse_dyn_repeat(&self, constants: &BTreeMap<String, u64>) -> Result<u32, ParsingError> {
+        assert!(self.is_dyn_repeat(), "not a dynamic repeat");
+        match self.num_parts() {
+            0 | 1 => unreachable!(),
+            2 => Err(ParsingError::missing_param(self, "repeat.dyn.max=<max_iterations>")),
+            3 => {
+                let max_iterations =
+                    parse_named_param_with_constant_lookup::<u32>(self, 2, "max", constants)?;
+                if max_iterations == 0 {
+                    let reason = "the maximum number of iterations must be greater than zero";
+                    return Err(ParsingError::invalid_param_with_reason(self, 2, reason));
+                }
+                Ok(max_iterations)
+            }
+            _ => Err(ParsingError::extra_param(self)),
+        }
+    }
+
     pub fn parse_invocation(
         &self,
         invocation_token: &str,
@@ -270,6 +411,23 @@ impl<'a> Token<'a> {
         }
     }
 
+    /// Parses the target of an invocation instruction which may be followed by a pinned MAST root
+    /// of the invoked procedure (e.g., `exec.foo::bar.digest!0x...`).
+    pub fn parse_pinned_invocation(
+        &self,
+        invocation_token: &str,
+    ) -> Result<(InvocationTarget<'_>, Option<RpoDigest>), ParsingError> {
+        assert_eq!(invocation_token, self.parts[0], "not an {invocation_token}");
+        match self.num_parts() {
+            3 => {
+                let target = InvocationTarget::parse(self.parts[1], self)?;
+                let root = InvocationTarget::parse_pinned_root(self.parts[2], self)?;
+                Ok((target, Some(root)))
+            }
+            _ => self.parse_invocation(invocation_token).map(|target| (target, None)),
+        }
+    }
+
     pub fn validate_end(&self) -> Result<(), ParsingError> {
         assert_eq!(Self::END, self.parts[0], "not an end");
         if self.num_parts() > 1 {
@@ -278,6 +436,45 @@ impl<'a> Token<'a> {
             Ok(())
         }
     }
+
+    // CONDITIONAL ASSEMBLY TOKEN PARSERS / VALIDATORS
+    // --------------------------------------------------------------------------------------------
+
+    pub fn validate_feature_if(&self) -> Result<(), ParsingError> {
+        assert_eq!(Self::FEATURE_IF, self.parts[0], "not an @if");
+        if self.num_parts() > 1 {
+            Err(ParsingError::extra_param(self))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn validate_feature_else(&self) -> Result<(), ParsingError> {
+        assert_eq!(Self::FEATURE_ELSE, self.parts[0], "not an @else");
+        if self.num_parts() > 1 {
+            Err(ParsingError::extra_param(self))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn validate_feature_end(&self) -> Result<(), ParsingError> {
+        assert_eq!(Self::FEATURE_END, self.parts[0], "not an @end");
+        if self.num_parts() > 1 {
+            Err(ParsingError::extra_param(self))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Parses the name of the feature flag following an `@if` token.
+    pub fn parse_feature_flag(&self) -> Result<String, ParsingError> {
+        let flag = self.parts.join(".");
+        FEATURE_LABEL_PARSER
+            .parse_label(&flag)
+            .map(|flag| flag.to_string())
+            .map_err(|err| ParsingError::invalid_feature_flag(self, err))
+    }
 }
 
 impl<'a> fmt::Display for Token<'a> {
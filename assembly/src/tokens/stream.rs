@@ -30,11 +30,17 @@ impl<'a> TokenStream<'a> {
         for line_info in LinesStream::from(source) {
             match line_info.contents() {
                 Some(line) => {
+                    let first_token = tokens.len();
+                    let is_const = line.starts_with(Token::CONST);
+
                     // fill the doc comments for procedures
-                    if line.starts_with(Token::EXPORT) || line.starts_with(Token::PROC) {
+                    if line.starts_with(Token::EXPORT)
+                        || line.starts_with(Token::PROC)
+                        || line.starts_with(Token::DATA)
+                    {
                         let doc_comment = build_comment(line_info.docs());
-                        proc_comments.insert(tokens.len(), doc_comment);
-                    } else if !line_info.docs().is_empty() {
+                        proc_comments.insert(first_token, doc_comment);
+                    } else if !is_const && !line_info.docs().is_empty() {
                         return Err(ParsingError::dangling_procedure_comment(line_info.into()));
                     }
 
@@ -46,8 +52,14 @@ impl<'a> TokenStream<'a> {
                         locations.push(location);
                     }
 
-                    // if the line ends with a procedure doc comment, return an error
-                    if let Some(location) = tokenizer.take_dangling() {
+                    if is_const {
+                        // constants can be documented both by the doc comment preceding the
+                        // declaration and by a doc comment trailing it on the same line
+                        let mut docs = line_info.docs().to_vec();
+                        docs.extend(tokenizer.take_dangling_docs().filter(|d| !d.is_empty()));
+                        proc_comments.insert(first_token, build_comment(&docs));
+                    } else if let Some(location) = tokenizer.take_dangling() {
+                        // if the line ends with a procedure doc comment, return an error
                         return Err(ParsingError::dangling_procedure_comment(location));
                     }
                 }
@@ -82,6 +94,27 @@ impl<'a> TokenStream<'a> {
         })
     }
 
+    /// Returns a new token stream consisting of the provided tokens located at the provided
+    /// source locations.
+    ///
+    /// # Panics
+    /// Panics if the list of tokens is empty or if the number of tokens is different from the
+    /// number of locations.
+    pub fn from_tokens(tokens: Vec<&'a str>, locations: Vec<SourceLocation>) -> Self {
+        assert!(!tokens.is_empty(), "no tokens");
+        assert_eq!(tokens.len(), locations.len(), "every token must have a location");
+        let current = Token::new(tokens[0], locations[0]);
+        Self {
+            tokens,
+            locations,
+            current,
+            pos: 0,
+            temp: Token::default(),
+            proc_comments: BTreeMap::new(),
+            module_comment: None,
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -9,7 +9,7 @@ use super::{LineInfo, SourceLocation, Token};
 pub struct LineTokenizer<'a> {
     line: &'a str,
     location: SourceLocation,
-    dangling: Option<SourceLocation>,
+    dangling: Option<(SourceLocation, &'a str)>,
 }
 
 impl<'a> LineTokenizer<'a> {
@@ -34,7 +34,12 @@ impl<'a> LineTokenizer<'a> {
 
     /// Takes dangling docs location, if present.
     pub fn take_dangling(&mut self) -> Option<SourceLocation> {
-        self.dangling.take()
+        self.dangling.take().map(|(location, _)| location)
+    }
+
+    /// Takes the trimmed contents of dangling docs, if present.
+    pub fn take_dangling_docs(&mut self) -> Option<&'a str> {
+        self.dangling.take().map(|(_, docs)| docs)
     }
 }
 
@@ -46,10 +51,10 @@ impl<'a> Iterator for LineTokenizer<'a> {
             return None;
         }
 
-        if self.line.starts_with(Token::DOC_COMMENT_PREFIX) {
+        if let Some(docs) = self.line.strip_prefix(Token::DOC_COMMENT_PREFIX) {
             let mut location = self.location;
             location.move_column(1);
-            self.dangling.replace(location);
+            self.dangling.replace((location, docs.trim()));
             return None;
         }
 
@@ -150,6 +155,16 @@ mod tests {
         assert_eq!(Some(SourceLocation::new(1, 24)), tokenizer.take_dangling());
     }
 
+    #[test]
+    fn single_token_with_dangling_docs() {
+        let info = LineInfo::new(1, 0).with_contents("const.A=1 #!  foo bar ");
+        let mut tokenizer = LineTokenizer::new(&info).unwrap();
+        assert_eq!(l("const.A=1", 1, 1), tokenizer.next());
+        assert_eq!(None, tokenizer.next());
+        assert_eq!(Some("foo bar"), tokenizer.take_dangling_docs());
+        assert!(tokenizer.take_dangling().is_none());
+    }
+
     // TESTS HELPERS
     // ============================================================================================
 
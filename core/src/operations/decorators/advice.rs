@@ -1,5 +1,8 @@
 use super::SignatureKind;
-use crate::Felt;
+use crate::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Felt,
+};
 use core::fmt;
 
 // ADVICE INJECTORS
@@ -219,6 +222,106 @@ pub enum AdviceInjector {
     ///   Advice stack: [ilog2(n), ...]
     ILog2,
 
+    /// Given an element of the secp256k1 base field in Montgomery form on the top of the stack,
+    /// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto
+    /// the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+    /// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed
+    /// result is also zero.
+    Secp256k1BaseInv,
+
+    /// Given an element of the secp256k1 scalar field in Montgomery form on the top of the stack,
+    /// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto
+    /// the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+    /// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed
+    /// result is also zero.
+    Secp256k1ScalarInv,
+
+    /// Given an element of the secp256r1 base field in Montgomery form on the top of the stack,
+    /// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto
+    /// the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+    /// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed
+    /// result is also zero.
+    Secp256r1BaseInv,
+
+    /// Given an element of the secp256r1 scalar field in Montgomery form on the top of the stack,
+    /// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto
+    /// the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+    /// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed
+    /// result is also zero.
+    Secp256r1ScalarInv,
+
+    /// Pushes the result of u256 division (both the quotient and the remainder) onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [q0, q1, q2, q3, q4, q5, q6, q7, r0, r1, r2, r3, r4, r5, r6, r7, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the dividend and the divisor respectively
+    /// (with a0 and b0 being the least significant limbs). Similarly, q[0..8] and r[0..8] are the
+    /// limbs of the quotient and the remainder respectively.
+    U256Div,
+
+    /// Pushes the multiplicative inverse of a u256 value modulo a u256 modulus onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [m7, m6, m5, m4, m3, m2, m1, m0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [m7, m6, m5, m4, m3, m2, m1, m0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [b0, b1, b2, b3, b4, b5, b6, b7, ...]
+    ///
+    /// Where a[0..8], m[0..8], and b[0..8] are the 32-bit limbs of the input, the modulus, and the
+    /// inverse of the input modulo m respectively (with a0, m0, and b0 being the least significant
+    /// limbs). If the input has no inverse modulo m, the pushed result is zero.
+    U256ModInv,
+
     // ADVICE MAP INJECTORS
     // --------------------------------------------------------------------------------------------
     /// Reads words from memory at the specified range and inserts them into the advice map under
@@ -310,6 +413,12 @@ impl fmt::Display for AdviceInjector {
             Self::U32Clo => write!(f, "u32clo"),
             Self::U32Cto => write!(f, "u32cto"),
             Self::ILog2 => write!(f, "ilog2"),
+            Self::Secp256k1BaseInv => write!(f, "secp256k1_base_inv"),
+            Self::Secp256k1ScalarInv => write!(f, "secp256k1_scalar_inv"),
+            Self::Secp256r1BaseInv => write!(f, "secp256r1_base_inv"),
+            Self::Secp256r1ScalarInv => write!(f, "secp256r1_scalar_inv"),
+            Self::U256Div => write!(f, "div_u256"),
+            Self::U256ModInv => write!(f, "mod_inv_u256"),
             Self::MemToMap => write!(f, "mem_to_map"),
             Self::HdwordToMap { domain } => write!(f, "hdword_to_map.{domain}"),
             Self::HpermToMap => write!(f, "hperm_to_map"),
@@ -317,3 +426,97 @@ impl fmt::Display for AdviceInjector {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceInjector {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::MerkleNodeMerge => target.write_u8(0),
+            Self::MerkleNodeToStack => target.write_u8(1),
+            Self::UpdateMerkleNode => target.write_u8(2),
+            Self::MapValueToStack {
+                include_len,
+                key_offset,
+            } => {
+                target.write_u8(3);
+                target.write_bool(*include_len);
+                target.write_usize(*key_offset);
+            }
+            Self::U64Div => target.write_u8(4),
+            Self::Ext2Inv => target.write_u8(5),
+            Self::Ext2Intt => target.write_u8(6),
+            Self::SmtGet => target.write_u8(7),
+            Self::SmtSet => target.write_u8(8),
+            Self::SmtPeek => target.write_u8(9),
+            Self::U32Clz => target.write_u8(10),
+            Self::U32Ctz => target.write_u8(11),
+            Self::U32Clo => target.write_u8(12),
+            Self::U32Cto => target.write_u8(13),
+            Self::ILog2 => target.write_u8(14),
+            Self::Secp256k1BaseInv => target.write_u8(15),
+            Self::Secp256k1ScalarInv => target.write_u8(16),
+            Self::Secp256r1BaseInv => target.write_u8(17),
+            Self::Secp256r1ScalarInv => target.write_u8(18),
+            Self::MemToMap => target.write_u8(19),
+            Self::HdwordToMap { domain } => {
+                target.write_u8(20);
+                domain.write_into(target);
+            }
+            Self::HpermToMap => target.write_u8(21),
+            Self::SigToStack { kind } => {
+                target.write_u8(22);
+                kind.write_into(target);
+            }
+            Self::U256Div => target.write_u8(23),
+            Self::U256ModInv => target.write_u8(24),
+        }
+    }
+}
+
+impl Deserializable for AdviceInjector {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tag = source.read_u8()?;
+        let injector = match tag {
+            0 => Self::MerkleNodeMerge,
+            1 => Self::MerkleNodeToStack,
+            2 => Self::UpdateMerkleNode,
+            3 => Self::MapValueToStack {
+                include_len: source.read_bool()?,
+                key_offset: source.read_usize()?,
+            },
+            4 => Self::U64Div,
+            5 => Self::Ext2Inv,
+            6 => Self::Ext2Intt,
+            7 => Self::SmtGet,
+            8 => Self::SmtSet,
+            9 => Self::SmtPeek,
+            10 => Self::U32Clz,
+            11 => Self::U32Ctz,
+            12 => Self::U32Clo,
+            13 => Self::U32Cto,
+            14 => Self::ILog2,
+            15 => Self::Secp256k1BaseInv,
+            16 => Self::Secp256k1ScalarInv,
+            17 => Self::Secp256r1BaseInv,
+            18 => Self::Secp256r1ScalarInv,
+            19 => Self::MemToMap,
+            20 => Self::HdwordToMap {
+                domain: source.read()?,
+            },
+            21 => Self::HpermToMap,
+            22 => Self::SigToStack {
+                kind: source.read()?,
+            },
+            23 => Self::U256Div,
+            24 => Self::U256ModInv,
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid advice injector tag {tag}"
+                )))
+            }
+        };
+        Ok(injector)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::string::String;
 use core::fmt;
 
@@ -63,3 +64,36 @@ impl fmt::Display for AssemblyOp {
         )
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AssemblyOp {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_string(target, &self.context_name);
+        target.write_u8(self.num_cycles);
+        write_string(target, &self.op);
+        target.write_bool(self.should_break);
+    }
+}
+
+impl Deserializable for AssemblyOp {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let context_name = read_string(source)?;
+        let num_cycles = source.read_u8()?;
+        let op = read_string(source)?;
+        let should_break = source.read_bool()?;
+        Ok(Self::new(context_name, num_cycles, op, should_break))
+    }
+}
+
+fn write_string<W: ByteWriter>(target: &mut W, value: &str) {
+    target.write_usize(value.len());
+    target.write_bytes(value.as_bytes());
+}
+
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_usize()?;
+    let bytes = source.read_vec(len)?;
+    String::from_utf8(bytes).map_err(|err| DeserializationError::InvalidValue(format!("{err}")))
+}
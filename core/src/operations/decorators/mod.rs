@@ -1,3 +1,4 @@
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use core::fmt;
 
@@ -32,6 +33,10 @@ pub enum Decorator {
     Debug(DebugOptions),
     /// Emits an event to the host.
     Event(u32),
+    /// Marks memory addresses in the range [a, b) of the current execution context as read-only,
+    /// where a and b are the first and second elements on the stack respectively. Once marked,
+    /// writing to any of these addresses results in an execution error.
+    MemProtect,
     /// Emmits a trace to the host.
     Trace(u32),
 }
@@ -45,11 +50,54 @@ impl fmt::Display for Decorator {
             }
             Self::Debug(options) => write!(f, "debug({options})"),
             Self::Event(event_id) => write!(f, "event({})", event_id),
+            Self::MemProtect => write!(f, "mem_protect"),
             Self::Trace(trace_id) => write!(f, "trace({})", trace_id),
         }
     }
 }
 
+impl Serializable for Decorator {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Advice(injector) => {
+                target.write_u8(0);
+                injector.write_into(target);
+            }
+            Self::AsmOp(assembly_op) => {
+                target.write_u8(1);
+                assembly_op.write_into(target);
+            }
+            Self::Debug(options) => {
+                target.write_u8(2);
+                options.write_into(target);
+            }
+            Self::Event(event_id) => {
+                target.write_u8(3);
+                target.write_u32(*event_id);
+            }
+            Self::MemProtect => target.write_u8(4),
+            Self::Trace(trace_id) => {
+                target.write_u8(5);
+                target.write_u32(*trace_id);
+            }
+        }
+    }
+}
+
+impl Deserializable for Decorator {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Advice(source.read()?)),
+            1 => Ok(Self::AsmOp(source.read()?)),
+            2 => Ok(Self::Debug(source.read()?)),
+            3 => Ok(Self::Event(source.read_u32()?)),
+            4 => Ok(Self::MemProtect),
+            5 => Ok(Self::Trace(source.read_u32()?)),
+            tag => Err(DeserializationError::InvalidValue(format!("invalid decorator tag {tag}"))),
+        }
+    }
+}
+
 /// Vector consisting of a tuple of operation index (within a span block) and decorator at that index
 pub type DecoratorList = Vec<(usize, Decorator)>;
 
@@ -108,3 +156,22 @@ impl fmt::Display for SignatureKind {
         }
     }
 }
+
+impl Serializable for SignatureKind {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::RpoFalcon512 => target.write_u8(0),
+        }
+    }
+}
+
+impl Deserializable for SignatureKind {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::RpoFalcon512),
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid signature kind tag {tag}")))
+            }
+        }
+    }
+}
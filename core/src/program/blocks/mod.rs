@@ -1,4 +1,7 @@
-use super::{hasher, Digest, Felt, Operation};
+use super::{
+    hasher, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Operation,
+    Serializable,
+};
 use crate::DecoratorList;
 use alloc::vec::Vec;
 use core::fmt;
@@ -138,3 +141,59 @@ impl fmt::Display for CodeBlock {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+/// Code blocks are serialized as trees: control blocks are followed by their children, while
+/// procedures invoked via `call` and `syscall` are referenced only by their MAST roots. Hashes of
+/// the blocks are not serialized, but are recomputed when the blocks are deserialized.
+impl Serializable for CodeBlock {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            CodeBlock::Span(block) => {
+                target.write_u8(0);
+                block.write_into(target);
+            }
+            CodeBlock::Join(block) => {
+                target.write_u8(1);
+                block.first().write_into(target);
+                block.second().write_into(target);
+            }
+            CodeBlock::Split(block) => {
+                target.write_u8(2);
+                block.on_true().write_into(target);
+                block.on_false().write_into(target);
+            }
+            CodeBlock::Loop(block) => {
+                target.write_u8(3);
+                block.body().write_into(target);
+            }
+            CodeBlock::Call(block) => {
+                target.write_u8(if block.is_syscall() { 5 } else { 4 });
+                block.fn_hash().write_into(target);
+            }
+            CodeBlock::Dyn(_) => target.write_u8(6),
+            CodeBlock::Proxy(block) => {
+                target.write_u8(7);
+                block.hash().write_into(target);
+            }
+        }
+    }
+}
+
+impl Deserializable for CodeBlock {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Span(source.read()?)),
+            1 => Ok(Self::new_join([source.read()?, source.read()?])),
+            2 => Ok(Self::new_split(source.read()?, source.read()?)),
+            3 => Ok(Self::new_loop(source.read()?)),
+            4 => Ok(Self::new_call(source.read()?)),
+            5 => Ok(Self::new_syscall(source.read()?)),
+            6 => Ok(Self::new_dyn()),
+            7 => Ok(Self::new_proxy(source.read()?)),
+            tag => Err(DeserializationError::InvalidValue(format!("invalid code block tag {tag}"))),
+        }
+    }
+}
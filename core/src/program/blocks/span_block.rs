@@ -1,4 +1,7 @@
-use super::{fmt, hasher, Digest, Felt, Operation};
+use super::{
+    fmt, hasher, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt,
+    Operation, Serializable,
+};
 use crate::{DecoratorIterator, DecoratorList, ZERO};
 use alloc::vec::Vec;
 use winter_utils::flatten_slice_elements;
@@ -164,6 +167,48 @@ impl fmt::Display for Span {
     }
 }
 
+impl Serializable for Span {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let ops = self.get_ops();
+        target.write_usize(ops.len());
+        target.write_many(&ops);
+        target.write_usize(self.decorators.len());
+        for (op_idx, decorator) in self.decorators.iter() {
+            target.write_usize(*op_idx);
+            decorator.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for Span {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_ops = source.read_usize()?;
+        if num_ops == 0 {
+            return Err(DeserializationError::InvalidValue("span block has no operations".into()));
+        }
+        let ops = source.read_many::<Operation>(num_ops)?;
+        if let Some(op) = ops.iter().find(|op| op.is_control_op()) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "span block contains control flow operation {op}"
+            )));
+        }
+
+        let num_decorators = source.read_usize()?;
+        let mut decorators = DecoratorList::with_capacity(num_decorators);
+        for _ in 0..num_decorators {
+            let op_idx = source.read_usize()?;
+            if op_idx > num_ops || decorators.last().is_some_and(|(prev, _)| *prev > op_idx) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid decorator position {op_idx} in span block"
+                )));
+            }
+            decorators.push((op_idx, source.read()?));
+        }
+
+        Ok(Self::with_decorators(ops, decorators))
+    }
+}
+
 // OPERATION BATCH
 // ================================================================================================
 
@@ -76,6 +76,32 @@ impl fmt::Display for Program {
     }
 }
 
+/// Programs are serialized as their MAST: the root code block, the code blocks in the code block
+/// table, and the kernel. Decorators attached to span blocks are serialized together with the
+/// blocks.
+impl Serializable for Program {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.root.write_into(target);
+        self.kernel.write_into(target);
+        target.write_usize(self.cb_table.0.len());
+        for block in self.cb_table.0.values() {
+            block.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for Program {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let root = source.read()?;
+        let kernel = source.read()?;
+        let mut cb_table = CodeBlockTable::default();
+        for _ in 0..source.read_usize()? {
+            cb_table.insert(source.read()?);
+        }
+        Ok(Self::with_kernel(root, kernel, cb_table))
+    }
+}
+
 // CODE BLOCK TABLE
 // ================================================================================================
 
@@ -1,5 +1,10 @@
-use super::{blocks::Dyn, Deserializable, Digest, Felt, Kernel, ProgramInfo, Serializable};
-use crate::{chiplets::hasher, Word};
+use super::{
+    blocks::{CodeBlock, Dyn},
+    CodeBlockTable, Deserializable, Digest, Felt, Kernel, Program, ProgramInfo, Serializable,
+};
+use crate::{
+    chiplets::hasher, AdviceInjector, AssemblyOp, DebugOptions, Decorator, Operation, Word,
+};
 use alloc::vec::Vec;
 use proptest::prelude::*;
 use rand_utils::prng_array;
@@ -11,6 +16,49 @@ fn dyn_hash_is_correct() {
     assert_eq!(expected_constant, Dyn::new().hash());
 }
 
+#[test]
+fn program_serialization() {
+    let decorators = vec![
+        (
+            0,
+            Decorator::Advice(AdviceInjector::MapValueToStack {
+                include_len: true,
+                key_offset: 4,
+            }),
+        ),
+        (0, Decorator::AsmOp(AssemblyOp::new("foo".into(), 2, "push.1".into(), false))),
+        (1, Decorator::Debug(DebugOptions::LocalInterval(1, 2, 3))),
+        (2, Decorator::Event(7)),
+    ];
+    let ops = vec![Operation::Push(Felt::new(1)), Operation::Assert(5), Operation::U32add];
+    let span = CodeBlock::new_span_with_decorators(ops, decorators);
+    let callee = CodeBlock::new_span(vec![Operation::U32assert2(Felt::new(3))]);
+    let root = CodeBlock::new_join([
+        CodeBlock::new_split(span, CodeBlock::new_loop(CodeBlock::new_dyn())),
+        CodeBlock::new_join([
+            CodeBlock::new_call(callee.hash()),
+            CodeBlock::new_syscall(callee.hash()),
+        ]),
+    ]);
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(callee.clone());
+    let kernel = Kernel::new(&[callee.hash()]).unwrap();
+    let program = Program::with_kernel(root.clone(), kernel.clone(), cb_table);
+
+    let bytes = program.to_bytes();
+    let deser = Program::read_from_bytes(&bytes).unwrap();
+    assert_eq!(deser.root(), &root);
+    assert_eq!(deser.kernel(), &kernel);
+    assert_eq!(deser.cb_table().get(callee.hash()), Some(&callee));
+    assert_eq!(deser.to_bytes(), bytes);
+
+    // span blocks must not contain control flow operations
+    let mut bytes = CodeBlock::new_span(vec![Operation::Add]).to_bytes();
+    let op_idx = bytes.len() - 2;
+    bytes[op_idx] = Operation::Join.op_code();
+    assert!(CodeBlock::read_from_bytes(&bytes).is_err());
+}
+
 proptest! {
     #[test]
     fn arbitrary_program_info_serialization_works(
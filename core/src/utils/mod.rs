@@ -126,6 +126,27 @@ fn debug_assert_is_checked() {
     debug_assert!(false);
 }
 
+// CONSTANT-TIME COMPARISON
+// ================================================================================================
+
+/// Returns true if the two provided sequences of field elements are equal.
+///
+/// Unlike `==`, this comparison does not exit early on the first mismatching element, and thus
+/// the time it takes depends only on the lengths of the sequences and not on their contents. This
+/// should be used when comparing values such as program hashes or output digests in contexts where
+/// timing side channels matter (e.g., when verification is exposed as a network service).
+pub fn ct_eq(a: &[Felt], b: &[Felt]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let diff = a
+        .iter()
+        .zip(b.iter())
+        .fold(0_u64, |acc, (x, y)| acc | (x.as_int() ^ y.as_int()));
+    core::hint::black_box(diff) == 0
+}
+
 // FORMATTING
 // ================================================================================================
 
@@ -148,3 +169,22 @@ pub fn write_hex_bytes(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result
     }
     Ok(())
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ct_eq, Felt};
+
+    #[test]
+    fn ct_eq_matches_eq() {
+        let a = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let b = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(5)];
+
+        assert!(ct_eq(&a, &a));
+        assert!(!ct_eq(&a, &b));
+        assert!(!ct_eq(&a, &a[..3]));
+        assert!(ct_eq(&[], &[]));
+    }
+}
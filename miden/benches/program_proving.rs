@@ -0,0 +1,207 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use miden_vm::{
+    crypto::{MerkleStore, RpoDigest, RpoRandomCoin},
+    execute, prove_trace,
+    utils::Serializable,
+    AdviceInputs, Assembler, DefaultHost, MemAdviceProvider, Program, ProvingOptions, StackInputs,
+    Word,
+};
+use processor::{ExecutionOptions, ExecutionTrace, Process};
+use std::time::Duration;
+use stdlib::StdLibrary;
+use test_utils::crypto::{rpo_falcon512::SecretKey, Smt};
+use vm_core::{Felt, ZERO};
+
+// WORKLOADS
+// ================================================================================================
+
+/// A program together with the inputs required to execute it.
+struct Workload {
+    name: &'static str,
+    program: Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+}
+
+impl Workload {
+    fn new(name: &'static str, source: &str, stack_inputs: &[u64]) -> Self {
+        let program = Assembler::default()
+            .with_library(&StdLibrary::default())
+            .expect("failed to load stdlib")
+            .compile(source)
+            .expect("failed to compile workload source");
+
+        Self {
+            name,
+            program,
+            stack_inputs: StackInputs::try_from_ints(stack_inputs.iter().copied())
+                .expect("invalid stack inputs"),
+            advice_inputs: AdviceInputs::default(),
+        }
+    }
+
+    fn with_advice_inputs(mut self, advice_inputs: AdviceInputs) -> Self {
+        self.advice_inputs = advice_inputs;
+        self
+    }
+
+    fn host(&self) -> DefaultHost<MemAdviceProvider> {
+        DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()))
+    }
+
+    /// Returns a process which is ready to execute the workload program.
+    fn process(&self) -> Process<DefaultHost<MemAdviceProvider>> {
+        Process::new(
+            self.program.kernel().clone(),
+            self.stack_inputs.clone(),
+            self.host(),
+            ExecutionOptions::default(),
+        )
+    }
+}
+
+/// Computes 1000 terms of the Fibonacci sequence; exercises basic control flow.
+fn fibonacci() -> Workload {
+    let source = "
+        begin
+            repeat.1000
+                swap dup.1 add
+            end
+        end";
+    Workload::new("fibonacci", source, &[0, 1])
+}
+
+/// Computes a chain of 10 BLAKE3 1-to-1 hashes; exercises the bitwise chiplet.
+fn blake3() -> Workload {
+    let source = "
+        use.std::crypto::hashes::blake3
+
+        begin
+            repeat.10
+                exec.blake3::hash_1to1
+            end
+        end";
+    Workload::new("blake3", source, &[u32::MAX as u64; 8])
+}
+
+/// Inserts a single value into a sparse Merkle tree; exercises the hasher chiplet and the advice
+/// provider.
+fn smt_set() -> Workload {
+    let source = "
+        use.std::collections::smt
+
+        begin
+            exec.smt::set
+        end";
+
+    let key = RpoDigest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]);
+    let value: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let smt = Smt::with_entries([(
+        RpoDigest::new([Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)]),
+        [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)],
+    )])
+    .expect("failed to build SMT");
+
+    // stack is expected to be [VALUE, KEY, ROOT, ...]
+    let stack_inputs: Vec<u64> = Word::from(smt.root())
+        .into_iter()
+        .chain(Word::from(key))
+        .chain(value)
+        .map(|v| v.as_int())
+        .collect();
+
+    let store = MerkleStore::from(&smt);
+    let advice_map = smt.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements()));
+    let advice_inputs = AdviceInputs::default().with_merkle_store(store).with_map(advice_map);
+
+    Workload::new("smt_set", source, &stack_inputs).with_advice_inputs(advice_inputs)
+}
+
+/// Verifies a single Falcon-512 signature; exercises a large and computationally heavy program.
+fn falcon() -> Workload {
+    let source = "
+        use.std::crypto::dsa::rpo_falcon512
+
+        begin
+            exec.rpo_falcon512::verify
+        end";
+
+    let mut rng = RpoRandomCoin::new([ZERO; 4]);
+    let sk = SecretKey::with_rng(&mut rng);
+    let pk: Word = sk.public_key().into();
+    let message: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    // the signature is generated by the host from the secret key stored in the advice map
+    let sk_felts = sk.to_bytes().into_iter().map(|b| Felt::new(b as u64)).collect();
+    let advice_inputs = AdviceInputs::default().with_map([(RpoDigest::from(pk), sk_felts)]);
+
+    let stack_inputs: Vec<u64> = message.into_iter().chain(pk).map(|v| v.as_int()).collect();
+
+    Workload::new("falcon", source, &stack_inputs).with_advice_inputs(advice_inputs)
+}
+
+// BENCHMARKS
+// ================================================================================================
+
+/// Benchmarks program execution, execution trace generation, and proof generation of each
+/// workload separately.
+fn program_proving(c: &mut Criterion) {
+    let workloads = [fibonacci(), blake3(), smt_set(), falcon()];
+
+    for workload in workloads.iter() {
+        let mut group = c.benchmark_group(format!("program_proving/{}", workload.name));
+        group.sample_size(10);
+        group.measurement_time(Duration::from_secs(20));
+
+        group.bench_function("execute", |bench| {
+            bench.iter_batched(
+                || workload.process(),
+                |mut process| {
+                    process.execute(&workload.program).expect("failed to execute workload")
+                },
+                BatchSize::PerIteration,
+            );
+        });
+
+        group.bench_function("build_trace", |bench| {
+            bench.iter_batched(
+                || {
+                    let mut process = workload.process();
+                    let stack_outputs =
+                        process.execute(&workload.program).expect("failed to execute workload");
+                    (process, stack_outputs)
+                },
+                |(process, stack_outputs)| ExecutionTrace::from_process(process, stack_outputs),
+                BatchSize::PerIteration,
+            );
+        });
+
+        group.bench_function("prove", |bench| {
+            bench.iter_batched(
+                || {
+                    execute(
+                        &workload.program,
+                        workload.stack_inputs.clone(),
+                        workload.host(),
+                        ExecutionOptions::default(),
+                    )
+                    .expect("failed to execute workload")
+                },
+                |trace| {
+                    prove_trace(
+                        trace,
+                        workload.stack_inputs.clone(),
+                        ProvingOptions::with_96_bit_security(false),
+                    )
+                    .expect("failed to prove workload")
+                },
+                BatchSize::PerIteration,
+            );
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(proving_group, program_proving);
+criterion_main!(proving_group);
@@ -0,0 +1,38 @@
+use assembly::{
+    utils::{Deserializable, Serializable},
+    MaslLibrary,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::time::Duration;
+use stdlib::StdLibrary;
+
+fn stdlib_serialization(c: &mut Criterion) {
+    let mut group = c.benchmark_group("stdlib_serialization");
+    group.measurement_time(Duration::from_secs(10));
+
+    let with_locations: MaslLibrary = StdLibrary::default().into();
+    let mut without_locations = with_locations.clone();
+    without_locations.clear_locations();
+
+    for (name, library) in
+        [("with_locations", with_locations), ("without_locations", without_locations)]
+    {
+        let bytes = library.to_bytes();
+        println!("stdlib serialized size ({name}): {} bytes", bytes.len());
+
+        group.bench_function(format!("serialize_{name}"), |bench| {
+            bench.iter(|| library.to_bytes());
+        });
+
+        group.bench_function(format!("deserialize_{name}"), |bench| {
+            bench.iter(|| {
+                MaslLibrary::read_from_bytes(&bytes).expect("failed to deserialize stdlib")
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(stdlib_serialization_group, stdlib_serialization);
+criterion_main!(stdlib_serialization_group);
@@ -1,4 +1,4 @@
-use assembly::{LibraryNamespace, MaslLibrary, Version};
+use assembly::{LibraryMetadata, LibraryNamespace, MaslLibrary, Version};
 use clap::Parser;
 use std::path::PathBuf;
 
@@ -17,6 +17,9 @@ pub struct BundleCmd {
     /// Version of the library, defaults to `0.1.0`.
     #[clap(short, long, default_value = "0.1.0")]
     version: String,
+    /// Build metadata attached to the library as `key=value` pairs, e.g. `--metadata git=1a2b3c4`.
+    #[clap(long = "metadata", value_parser = parse_metadata_entry)]
+    metadata: Vec<(String, String)>,
 }
 
 impl BundleCmd {
@@ -38,6 +41,10 @@ impl BundleCmd {
         let library_namespace =
             LibraryNamespace::try_from(namespace.clone()).expect("invalid base namespace");
         let version = Version::try_from(self.version.as_ref()).expect("invalid cargo version");
+        let mut metadata = LibraryMetadata::new();
+        for (key, value) in self.metadata.iter() {
+            metadata.insert(key.as_str(), value.as_str()).map_err(|e| e.to_string())?;
+        }
         let with_source_locations = true;
         let stdlib = MaslLibrary::read_from_dir(
             self.dir.clone(),
@@ -45,7 +52,8 @@ impl BundleCmd {
             with_source_locations,
             version,
         )
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| e.to_string())?
+        .with_metadata(metadata);
 
         // write the masl output
         stdlib.write_to_dir(self.dir.clone()).map_err(|e| e.to_string())?;
@@ -55,3 +63,14 @@ impl BundleCmd {
         Ok(())
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Parses a `key=value` metadata entry.
+fn parse_metadata_entry(entry: &str) -> Result<(String, String), String> {
+    let (key, value) = entry
+        .split_once('=')
+        .ok_or_else(|| format!("metadata entry '{entry}' must be of the form key=value"))?;
+    Ok((key.to_string(), value.to_string()))
+}
@@ -1,6 +1,7 @@
 use clap::Parser;
 
-use super::data::{Debug, Libraries, ProgramFile};
+use super::data::{Debug, Libraries, MastFile, ProgramFile};
+use assembly::{OpFrequencyConfig, OpFrequencyReport, OptimizationLevel};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Parser)]
@@ -15,6 +16,24 @@ pub struct CompileCmd {
     /// Path to output file
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
+    /// Path to which the MAST of the compiled program is written (e.g., for `miden disasm`)
+    #[clap(long = "mast", value_parser)]
+    mast_file: Option<PathBuf>,
+    /// Remove redundant instructions and operations from the compiled program
+    #[clap(short = 'O', long = "optimize")]
+    optimize: bool,
+    /// Print the number of instructions removed from each procedure by the optimizer
+    #[clap(long = "opt-stats", requires = "optimize")]
+    opt_stats: bool,
+    /// Print a histogram of the VM operations and MAST node kinds of the compiled program
+    #[clap(long = "op-stats")]
+    op_stats: bool,
+    /// Count MAST nodes which occur several times in the program (e.g., inlined procedures) once
+    #[clap(long = "op-stats-unique", requires = "op_stats")]
+    op_stats_unique: bool,
+    /// Exclude the bodies of procedures invoked via `call` and `syscall` from the histogram
+    #[clap(long = "op-stats-no-callees", requires = "op_stats")]
+    op_stats_no_callees: bool,
 }
 
 impl CompileCmd {
@@ -30,12 +49,40 @@ impl CompileCmd {
         let libraries = Libraries::new(&self.library_paths)?;
 
         // compile the program
-        let compiled_program = program.compile(&Debug::Off, libraries.libraries)?;
+        let optimization_level = if self.optimize {
+            OptimizationLevel::Peephole
+        } else {
+            OptimizationLevel::None
+        };
+        let (compiled_program, stats) =
+            program.compile_with_stats(&Debug::Off, libraries.libraries, optimization_level)?;
+
+        // report optimization statistics to user
+        if self.opt_stats {
+            for (proc_name, num_removed) in stats.iter() {
+                println!("{proc_name}: removed {num_removed} instructions");
+            }
+            println!("total: removed {} instructions", stats.num_removed_ops());
+        }
+
+        // report operation frequencies to user
+        if self.op_stats {
+            let config = OpFrequencyConfig {
+                deduplicate_nodes: self.op_stats_unique,
+                include_callees: !self.op_stats_no_callees,
+            };
+            print!("{}", OpFrequencyReport::new(&compiled_program, config));
+        }
 
         // report program hash to user
         let program_hash: [u8; 32] = compiled_program.hash().into();
         println!("program hash is {}", hex::encode(program_hash));
 
+        // write the MAST of the compiled program
+        if let Some(mast_file) = &self.mast_file {
+            MastFile::write(&compiled_program, mast_file)?;
+        }
+
         // write the compiled file
         program.write(self.output_file.clone())
     }
@@ -1,8 +1,8 @@
-use assembly::{Library, MaslLibrary};
+use assembly::{Library, MaslLibrary, OptimizationLevel, OptimizationStats, SourceMap};
 use miden_vm::{
     crypto::{MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, RpoDigest, SimpleSmt},
     math::Felt,
-    utils::{Deserializable, SliceReader},
+    utils::{Deserializable, Serializable, SliceReader},
     AdviceInputs, Assembler, Digest, ExecutionProof, MemAdviceProvider, Program, ProgramAst,
     StackInputs, StackOutputs, Word,
 };
@@ -41,7 +41,7 @@ impl Debug {
 
 /// Struct used to deserialize merkle data from input file. Merkle data can be represented as a
 /// merkle tree or a Sparse Merkle Tree.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub enum MerkleData {
     /// String representation of a merkle tree. The merkle tree is represented as a vector of
     /// 32 byte hex strings where each string represents a leaf in the tree.
@@ -69,7 +69,7 @@ pub enum MerkleData {
 /// - advice_stack
 /// - advice_map
 /// - merkle_store
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct InputFile {
     /// String representation of the initial operand stack, composed of chained field elements.
     pub operand_stack: Vec<String>,
@@ -378,6 +378,7 @@ impl OutputFile {
 
 pub struct ProgramFile {
     ast: ProgramAst,
+    source: String,
     path: PathBuf,
 }
 
@@ -393,37 +394,96 @@ impl ProgramFile {
 
         // parse the program into an AST
         let ast = ProgramAst::parse(&source).map_err(|err| {
-            format!("Failed to parse program file `{}` - {}\n", path.display(), err)
+            format!(
+                "Failed to parse program file `{}`\n{}",
+                path.display(),
+                err.diagnostic().render(&source)
+            )
         })?;
 
         Ok(Self {
             ast,
+            source,
             path: path.clone(),
         })
     }
 
+    /// Returns the source code of this program file.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
     /// Compiles this program file into a [Program].
-    #[instrument(name = "compile_program", skip_all)]
     pub fn compile<I, L>(&self, debug: &Debug, libraries: I) -> Result<Program, String>
+    where
+        I: IntoIterator<Item = L>,
+        L: Library,
+    {
+        self.compile_with_stats(debug, libraries, OptimizationLevel::None)
+            .map(|(program, _)| program)
+    }
+
+    /// Compiles this program file into a [Program] using the specified optimization level, and
+    /// returns the program together with the statistics of the applied optimizations.
+    #[instrument(name = "compile_program", skip_all)]
+    pub fn compile_with_stats<I, L>(
+        &self,
+        debug: &Debug,
+        libraries: I,
+        optimization_level: OptimizationLevel,
+    ) -> Result<(Program, OptimizationStats), String>
     where
         I: IntoIterator<Item = L>,
         L: Library,
     {
         // compile program
-        let mut assembler = Assembler::default()
+        let assembler = Assembler::default()
             .with_debug_mode(debug.is_on())
-            .with_library(&StdLibrary::default())
-            .map_err(|err| format!("Failed to load stdlib - {}", err))?;
+            .with_optimization_level(optimization_level);
+        let assembler = Self::add_libraries(assembler, libraries)?;
 
-        assembler = assembler
-            .with_libraries(libraries.into_iter())
-            .map_err(|err| format!("Failed to load libraries `{}`", err))?;
+        let program = assembler
+            .compile_ast(&self.ast)
+            .map_err(|err| format!("Failed to compile program - {}", err))?;
+
+        let stats = assembler.optimization_stats().clone();
+        Ok((program, stats))
+    }
+
+    /// Compiles this program file into a [Program] with source maps enabled, and returns the
+    /// program together with its source map.
+    #[instrument(name = "compile_program", skip_all)]
+    pub fn compile_with_source_map<I, L>(
+        &self,
+        debug: &Debug,
+        libraries: I,
+    ) -> Result<(Program, SourceMap), String>
+    where
+        I: IntoIterator<Item = L>,
+        L: Library,
+    {
+        let assembler = Assembler::default().with_debug_mode(debug.is_on()).with_source_maps(true);
+        let assembler = Self::add_libraries(assembler, libraries)?;
 
         let program = assembler
             .compile_ast(&self.ast)
             .map_err(|err| format!("Failed to compile program - {}", err))?;
 
-        Ok(program)
+        let source_map = assembler.source_map(&program).expect("source maps are enabled");
+        Ok((program, source_map))
+    }
+
+    /// Adds the standard library and the provided libraries to the assembler.
+    fn add_libraries<I, L>(assembler: Assembler, libraries: I) -> Result<Assembler, String>
+    where
+        I: IntoIterator<Item = L>,
+        L: Library,
+    {
+        assembler
+            .with_library(&StdLibrary::default())
+            .map_err(|err| format!("Failed to load stdlib - {}", err))?
+            .with_libraries(libraries.into_iter())
+            .map_err(|err| format!("Failed to load libraries `{}`", err))
     }
 
     /// Writes this file into the specified path, if one is provided. If the path is not provided,
@@ -441,6 +501,29 @@ impl ProgramFile {
     }
 }
 
+// MAST FILE
+// ================================================================================================
+
+/// Helper methods to interact with files containing serialized program MAST.
+pub struct MastFile;
+
+impl MastFile {
+    /// Reads the program MAST from the file at the specified path.
+    #[instrument(name = "read_mast_file", fields(path = %path.display()))]
+    pub fn read(path: &PathBuf) -> Result<Program, String> {
+        let bytes = fs::read(path)
+            .map_err(|err| format!("Failed to open MAST file `{}` - {}", path.display(), err))?;
+        Program::read_from_bytes(&bytes)
+            .map_err(|err| format!("Failed to decode MAST file `{}` - {}", path.display(), err))
+    }
+
+    /// Writes the MAST of the provided program into the file at the specified path.
+    pub fn write(program: &Program, path: &PathBuf) -> Result<(), String> {
+        fs::write(path, program.to_bytes())
+            .map_err(|err| format!("Failed to write MAST file `{}` - {}", path.display(), err))
+    }
+}
+
 // PROOF FILE
 // ================================================================================================
 
@@ -123,14 +123,21 @@ impl DebugExecutor {
     }
 
     /// print all stack items.
+    ///
+    /// Items of the overflow table which belong to the calling execution contexts are marked as
+    /// such.
     pub fn print_stack(&self) {
+        let stack_depth = self.vm_state.stack_depth;
         println!(
             "{}",
             self.vm_state
                 .stack
                 .iter()
                 .enumerate()
-                .map(|(i, f)| format!("[{i}] {f}"))
+                .map(|(i, f)| match i < stack_depth {
+                    true => format!("[{i}] {f}"),
+                    false => format!("[{i}] {f} (caller context)"),
+                })
                 .collect::<Vec<_>>()
                 .join("\n"),
         )
@@ -139,7 +146,7 @@ impl DebugExecutor {
     /// print specified stack item.
     pub fn print_stack_item(&self, index: usize) {
         let len = self.vm_state.stack.len();
-        println!("stack len {}", len);
+        println!("stack len {}, depth in current context {}", len, self.vm_state.stack_depth);
         if index >= len {
             println!("stack index must be < {len}")
         } else {
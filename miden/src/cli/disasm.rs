@@ -0,0 +1,48 @@
+use super::data::{Libraries, MastFile};
+use assembly::Disassembler;
+use clap::Parser;
+use std::{fs, path::PathBuf};
+use stdlib::StdLibrary;
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Convert the MAST of a compiled miden program into readable pseudo-assembly")]
+pub struct DisasmCmd {
+    /// Path to the MAST file of the program (see `miden compile --mast`)
+    #[clap(value_parser)]
+    mast_file: PathBuf,
+    /// Paths to .masl library files used to name the procedures of the program
+    #[clap(short = 'l', long = "libraries", value_parser)]
+    library_paths: Vec<PathBuf>,
+    /// Path to output file; if not provided, the pseudo-assembly is printed to stdout
+    #[clap(short = 'o', long = "output", value_parser)]
+    output_file: Option<PathBuf>,
+}
+
+impl DisasmCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        // load the program MAST from file
+        let program = MastFile::read(&self.mast_file)?;
+
+        // procedures of the standard library and of the provided libraries are named
+        let libraries = Libraries::new(&self.library_paths)?;
+        let mut disassembler = Disassembler::new()
+            .with_library(&StdLibrary::default())
+            .map_err(|err| format!("Failed to load stdlib - {err}"))?;
+        for library in libraries.libraries.iter() {
+            disassembler = disassembler
+                .with_library(library)
+                .map_err(|err| format!("Failed to load libraries `{err}`"))?;
+        }
+
+        let output = disassembler.disassemble(&program);
+        match &self.output_file {
+            Some(path) => fs::write(path, output).map_err(|err| {
+                format!("Failed to write output file `{}` - {}", path.display(), err)
+            }),
+            None => {
+                print!("{output}");
+                Ok(())
+            }
+        }
+    }
+}
@@ -2,8 +2,10 @@ mod bundle;
 mod compile;
 mod data;
 mod debug;
+mod disasm;
 mod prove;
 mod repl;
+mod replay;
 mod run;
 mod verify;
 
@@ -11,7 +13,9 @@ pub use bundle::BundleCmd;
 pub use compile::CompileCmd;
 pub use data::InputFile;
 pub use debug::DebugCmd;
+pub use disasm::DisasmCmd;
 pub use prove::ProveCmd;
 pub use repl::ReplCmd;
+pub use replay::ReplayCmd;
 pub use run::RunCmd;
 pub use verify::VerifyCmd;
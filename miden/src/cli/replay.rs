@@ -0,0 +1,532 @@
+use super::data::{instrument, InputFile};
+use assembly::SourceMap;
+use clap::Parser;
+use processor::{
+    AdviceExtractor, ExecutionOptions, Host, HostResponse, ProcessState, Program, StackOutputs,
+};
+use std::{fs, path::PathBuf};
+use vm_core::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AdviceInjector, DebugOptions, Felt, Word,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Bytes which all bundle files start with.
+const BUNDLE_MAGIC: &[u8; 4] = b"MVR\0";
+
+/// Version of the bundle file format; bumped whenever the layout of the bundle changes.
+const BUNDLE_FORMAT_VERSION: u8 = 2;
+
+// REPLAY COMMAND
+// ================================================================================================
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Replay a run recorded via `miden run --record`")]
+pub struct ReplayCmd {
+    /// Path to the .mvr bundle file
+    #[clap(value_parser)]
+    bundle_file: PathBuf,
+
+    /// Number of outputs
+    #[clap(short = 'n', long = "num-outputs", default_value = "16")]
+    num_outputs: usize,
+
+    /// Print the source code and the source map of the recorded program
+    #[clap(long = "source")]
+    print_source: bool,
+}
+
+impl ReplayCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        println!("===============================================================================");
+        println!("Replay bundle: {}", self.bundle_file.display());
+        println!("-------------------------------------------------------------------------------");
+
+        let bundle = RunBundle::read(&self.bundle_file)?;
+
+        println!("Recorded with miden-vm {}", bundle.vm_version);
+        if bundle.vm_version != env!("CARGO_PKG_VERSION") {
+            println!(
+                "Warning: replaying with miden-vm {}, the results may differ",
+                env!("CARGO_PKG_VERSION")
+            );
+        }
+        println!("Program: {}", bundle.source_path);
+        let program_hash: [u8; 32] = bundle.program.hash().into();
+        println!("Program hash: {}", hex::encode(program_hash));
+        if self.print_source {
+            println!("{}", bundle.source);
+            println!(
+                "Source map: {} MAST nodes, {} operation batches",
+                bundle.source_map.num_nodes(),
+                bundle.source_map.num_batches()
+            );
+        }
+
+        let (outcome, events) = bundle.replay()?;
+
+        match &outcome {
+            RunOutcome::Success(outputs) => {
+                println!("Output: {:?}", outputs.stack_truncated(self.num_outputs))
+            }
+            RunOutcome::Failure(err) => println!("Execution failed: {err}"),
+        }
+        for event in events.iter() {
+            println!("{event}");
+        }
+
+        if outcome != bundle.outcome {
+            return Err(format!(
+                "Replay diverged from the recorded run: expected {}, but got {}",
+                bundle.outcome, outcome
+            ));
+        }
+        if events != bundle.events {
+            return Err(format!(
+                "Replay diverged from the recorded run: expected {} host events, but got {}",
+                bundle.events.len(),
+                events.len()
+            ));
+        }
+        println!("Replay matches the recorded run");
+
+        Ok(())
+    }
+}
+
+// RUN BUNDLE
+// ================================================================================================
+
+/// A record of a single program run which contains everything needed to reproduce it.
+///
+/// The bundle contains the MAST of the executed program (so that it can be replayed without the
+/// libraries it was compiled against), the source of the program together with the source map
+/// linking its MAST nodes to the source, the inputs and the execution options of the run, the
+/// version of the VM, and the outcome of the run together with the log of the events the host
+/// received during the execution.
+#[derive(Debug, Clone)]
+pub struct RunBundle {
+    pub vm_version: String,
+    pub program: Program,
+    pub source_path: String,
+    pub source: String,
+    pub source_map: SourceMap,
+    /// Inputs of the run, serialized in the format of the input file.
+    pub inputs: String,
+    pub max_cycles: u32,
+    pub expected_cycles: u32,
+    pub tracing: bool,
+    pub local_canaries: bool,
+    pub outcome: RunOutcome,
+    pub events: Vec<HostEvent>,
+}
+
+impl RunBundle {
+    /// Returns the execution options of the recorded run.
+    pub fn execution_options(&self) -> Result<ExecutionOptions, String> {
+        let mut options =
+            ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles, self.tracing)
+                .map_err(|err| format!("{err}"))?;
+        if self.local_canaries {
+            options = options.with_local_canaries();
+        }
+        Ok(options)
+    }
+
+    /// Executes the recorded program against the recorded inputs, and returns the outcome of the
+    /// execution together with the log of the host events.
+    pub fn replay(&self) -> Result<(RunOutcome, Vec<HostEvent>), String> {
+        let input_data: InputFile = serde_json::from_str(&self.inputs)
+            .map_err(|err| format!("Failed to deserialize recorded inputs - {err}"))?;
+        let stack_inputs = input_data.parse_stack_inputs()?;
+        let mut host =
+            RecordingHost::new(processor::DefaultHost::new(input_data.parse_advice_provider()?));
+
+        let outcome =
+            processor::execute(&self.program, stack_inputs, &mut host, self.execution_options()?)
+                .map(|trace| RunOutcome::Success(trace.stack_outputs().clone()))
+                .unwrap_or_else(|err| RunOutcome::Failure(err.to_string()));
+
+        Ok((outcome, host.into_events()))
+    }
+
+    /// Reads the bundle from the file at the specified path.
+    #[instrument(name = "read_bundle_file", fields(path = %path.display()))]
+    pub fn read(path: &PathBuf) -> Result<Self, String> {
+        let bytes = fs::read(path)
+            .map_err(|err| format!("Failed to open bundle file `{}` - {}", path.display(), err))?;
+        Self::read_from_bytes(&bytes)
+            .map_err(|err| format!("Failed to decode bundle file `{}` - {}", path.display(), err))
+    }
+
+    /// Writes the bundle into the file at the specified path.
+    pub fn write(&self, path: &PathBuf) -> Result<(), String> {
+        fs::write(path, self.to_bytes())
+            .map_err(|err| format!("Failed to write bundle file `{}` - {}", path.display(), err))
+    }
+}
+
+impl Serializable for RunBundle {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(BUNDLE_MAGIC);
+        target.write_u8(BUNDLE_FORMAT_VERSION);
+        write_string(target, &self.vm_version);
+        self.program.write_into(target);
+        write_string(target, &self.source_path);
+        write_string(target, &self.source);
+        self.source_map.write_into(target);
+        write_string(target, &self.inputs);
+        target.write_u32(self.max_cycles);
+        target.write_u32(self.expected_cycles);
+        target.write_bool(self.tracing);
+        target.write_bool(self.local_canaries);
+        self.outcome.write_into(target);
+        target.write_usize(self.events.len());
+        target.write_many(&self.events);
+    }
+}
+
+impl Deserializable for RunBundle {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic = source.read_vec(BUNDLE_MAGIC.len())?;
+        if magic != BUNDLE_MAGIC {
+            return Err(DeserializationError::InvalidValue("not a bundle file".into()));
+        }
+        let version = source.read_u8()?;
+        if version != BUNDLE_FORMAT_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported bundle format version {version}, expected {BUNDLE_FORMAT_VERSION}"
+            )));
+        }
+
+        let vm_version = read_string(source)?;
+        let program = Program::read_from(source)?;
+        let source_path = read_string(source)?;
+        let program_source = read_string(source)?;
+        let source_map = SourceMap::read_from(source)?;
+        let inputs = read_string(source)?;
+        let max_cycles = source.read_u32()?;
+        let expected_cycles = source.read_u32()?;
+        let tracing = source.read_bool()?;
+        let local_canaries = source.read_bool()?;
+        let outcome = RunOutcome::read_from(source)?;
+        let num_events = source.read_usize()?;
+        let events = source.read_many::<HostEvent>(num_events)?;
+
+        Ok(Self {
+            vm_version,
+            program,
+            source_path,
+            source: program_source,
+            source_map,
+            inputs,
+            max_cycles,
+            expected_cycles,
+            tracing,
+            local_canaries,
+            outcome,
+            events,
+        })
+    }
+}
+
+// RUN OUTCOME
+// ================================================================================================
+
+/// Outcome of a program run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunOutcome {
+    /// The program executed successfully and produced the specified stack outputs.
+    Success(StackOutputs),
+    /// The execution failed with the specified error.
+    Failure(String),
+}
+
+impl core::fmt::Display for RunOutcome {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Success(outputs) => write!(f, "outputs {:?}", outputs.stack()),
+            Self::Failure(err) => write!(f, "failure `{err}`"),
+        }
+    }
+}
+
+impl Serializable for RunOutcome {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Success(outputs) => {
+                target.write_u8(0);
+                outputs.write_into(target);
+            }
+            Self::Failure(err) => {
+                target.write_u8(1);
+                write_string(target, err);
+            }
+        }
+    }
+}
+
+impl Deserializable for RunOutcome {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Success(StackOutputs::read_from(source)?)),
+            1 => Ok(Self::Failure(read_string(source)?)),
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid run outcome tag {tag}")))
+            }
+        }
+    }
+}
+
+// HOST EVENTS
+// ================================================================================================
+
+/// An event or a trace emitted by the program and received by the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostEvent {
+    pub kind: HostEventKind,
+    pub id: u32,
+    pub clk: u32,
+    pub ctx: u32,
+}
+
+/// Kind of a [HostEvent].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostEventKind {
+    /// Emitted via the `emit` instruction.
+    Event,
+    /// Emitted via the `trace` instruction.
+    Trace,
+}
+
+impl core::fmt::Display for HostEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let kind = match self.kind {
+            HostEventKind::Event => "Event",
+            HostEventKind::Trace => "Trace",
+        };
+        write!(
+            f,
+            "{kind} with id {} emitted at step {} in context {}",
+            self.id, self.clk, self.ctx
+        )
+    }
+}
+
+impl Serializable for HostEvent {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.kind as u8);
+        target.write_u32(self.id);
+        target.write_u32(self.clk);
+        target.write_u32(self.ctx);
+    }
+}
+
+impl Deserializable for HostEvent {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let kind = match source.read_u8()? {
+            0 => HostEventKind::Event,
+            1 => HostEventKind::Trace,
+            tag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid host event tag {tag}"
+                )))
+            }
+        };
+        Ok(Self {
+            kind,
+            id: source.read_u32()?,
+            clk: source.read_u32()?,
+            ctx: source.read_u32()?,
+        })
+    }
+}
+
+// RECORDING HOST
+// ================================================================================================
+
+/// A [Host] which forwards all requests to the wrapped host, and records the events and traces
+/// emitted by the program.
+pub struct RecordingHost<H> {
+    host: H,
+    events: Vec<HostEvent>,
+}
+
+impl<H: Host> RecordingHost<H> {
+    pub fn new(host: H) -> Self {
+        Self {
+            host,
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the events recorded during the execution.
+    pub fn into_events(self) -> Vec<HostEvent> {
+        self.events
+    }
+
+    fn record<S: ProcessState>(&mut self, process: &S, kind: HostEventKind, id: u32) {
+        self.events.push(HostEvent {
+            kind,
+            id,
+            clk: process.clk(),
+            ctx: process.ctx().into(),
+        });
+    }
+}
+
+impl<H: Host> Host for RecordingHost<H> {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, processor::ExecutionError> {
+        self.host.get_advice(process, extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, processor::ExecutionError> {
+        self.host.set_advice(process, injector)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, processor::ExecutionError> {
+        self.record(process, HostEventKind::Event, event_id);
+        self.host.on_event(process, event_id)
+    }
+
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, processor::ExecutionError> {
+        self.host.on_debug(process, options)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, processor::ExecutionError> {
+        self.record(process, HostEventKind::Trace, trace_id);
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn evict_overflow_rows(
+        &mut self,
+        key: Word,
+        rows: Vec<Felt>,
+    ) -> Result<(), processor::ExecutionError> {
+        self.host.evict_overflow_rows(key, rows)
+    }
+
+    fn restore_overflow_rows(&mut self, key: Word) -> Result<Vec<Felt>, processor::ExecutionError> {
+        self.host.restore_overflow_rows(key)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn write_string<W: ByteWriter>(target: &mut W, value: &str) {
+    target.write_usize(value.len());
+    target.write_bytes(value.as_bytes());
+}
+
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_usize()?;
+    let bytes = source.read_vec(len)?;
+    String::from_utf8(bytes).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{HostEventKind, RunBundle, RunOutcome};
+    use miden_vm::Assembler;
+    use vm_core::{
+        utils::{Deserializable, Serializable},
+        Felt,
+    };
+
+    fn build_bundle(source: &str, inputs: &str) -> RunBundle {
+        let assembler = Assembler::default().with_source_maps(true);
+        let program = assembler.compile(source).unwrap();
+        let source_map = assembler.source_map(&program).unwrap();
+        let mut bundle = RunBundle {
+            vm_version: env!("CARGO_PKG_VERSION").into(),
+            program,
+            source_path: "test.masm".into(),
+            source: source.into(),
+            source_map,
+            inputs: inputs.into(),
+            max_cycles: u32::MAX,
+            expected_cycles: 64,
+            tracing: true,
+            local_canaries: false,
+            outcome: RunOutcome::Failure(String::new()),
+            events: Vec::new(),
+        };
+        let (outcome, events) = bundle.replay().unwrap();
+        bundle.outcome = outcome;
+        bundle.events = events;
+        bundle
+    }
+
+    #[test]
+    fn bundle_serialization() {
+        let source = "begin adv_push.1 add emit.7 trace.3 end";
+        let bundle = build_bundle(source, r#"{"operand_stack": ["5"], "advice_stack": ["6"]}"#);
+
+        assert!(matches!(
+            &bundle.outcome,
+            RunOutcome::Success(outputs) if outputs.stack()[0] == Felt::new(11)
+        ));
+        assert_eq!(bundle.events.len(), 2);
+        assert_eq!(bundle.events[0].kind, HostEventKind::Event);
+        assert_eq!(bundle.events[0].id, 7);
+        assert_eq!(bundle.events[1].kind, HostEventKind::Trace);
+
+        let deserialized = RunBundle::read_from_bytes(&bundle.to_bytes()).unwrap();
+        assert_eq!(deserialized.program.hash(), bundle.program.hash());
+        assert_eq!(deserialized.source, bundle.source);
+        assert!(!deserialized.source_map.is_empty());
+        assert_eq!(deserialized.source_map, bundle.source_map);
+        assert_eq!(deserialized.inputs, bundle.inputs);
+        assert_eq!(deserialized.outcome, bundle.outcome);
+        assert_eq!(deserialized.events, bundle.events);
+
+        // replaying the deserialized bundle reproduces the run
+        let (outcome, events) = deserialized.replay().unwrap();
+        assert_eq!(outcome, bundle.outcome);
+        assert_eq!(events, bundle.events);
+    }
+
+    #[test]
+    fn bundle_failed_run() {
+        let source = "begin push.1 assertz end";
+        let bundle = build_bundle(source, r#"{"operand_stack": []}"#);
+        assert!(matches!(bundle.outcome, RunOutcome::Failure(_)));
+
+        let deserialized = RunBundle::read_from_bytes(&bundle.to_bytes()).unwrap();
+        assert_eq!(deserialized.replay().unwrap().0, bundle.outcome);
+    }
+
+    #[test]
+    fn bundle_invalid_magic() {
+        let bundle = build_bundle("begin push.1 end", r#"{"operand_stack": []}"#);
+        let mut bytes = bundle.to_bytes();
+        bytes[0] = b'X';
+        assert!(RunBundle::read_from_bytes(&bytes).is_err());
+    }
+}
@@ -1,7 +1,14 @@
-use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile};
-use clap::Parser;
-use processor::{DefaultHost, ExecutionOptions, ExecutionTrace};
-use std::{path::PathBuf, time::Instant};
+use super::{
+    data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile},
+    replay::{RecordingHost, RunBundle, RunOutcome},
+};
+use clap::{Parser, ValueEnum};
+use processor::{
+    ContextId, DefaultHost, ExecutionOptions, ExecutionTrace, ProcessState, ProcessStateSnapshot,
+    ZERO,
+};
+use serde_derive::Serialize;
+use std::{path::PathBuf, str::FromStr, time::Instant};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Run a miden program")]
@@ -18,6 +25,10 @@ pub struct RunCmd {
     #[clap(short = 'i', long = "input", value_parser)]
     input_file: Option<PathBuf>,
 
+    /// Surround frames of procedure locals with canary words to detect out-of-bounds writes
+    #[clap(long = "local-canaries")]
+    local_canaries: bool,
+
     /// Paths to .masl library files
     #[clap(short = 'l', long = "libraries", value_parser)]
     library_paths: Vec<PathBuf>,
@@ -26,6 +37,10 @@ pub struct RunCmd {
     #[clap(short = 'm', long = "max-cycles", default_value = "4294967295")]
     max_cycles: u32,
 
+    /// Memory ranges to include in the outputs, specified as `ctx:start..end` (end exclusive)
+    #[clap(long = "memory", value_parser = MemoryRange::from_str)]
+    memory_ranges: Vec<MemoryRange>,
+
     /// Number of outputs
     #[clap(short = 'n', long = "num-outputs", default_value = "16")]
     num_outputs: usize,
@@ -34,6 +49,15 @@ pub struct RunCmd {
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
 
+    /// Format in which the results of the execution are printed
+    #[clap(long = "outputs", value_enum, default_value_t = OutputFormat::Text)]
+    output_format: OutputFormat,
+
+    /// Path to a .mvr bundle file into which the run is recorded, so that it can be reproduced
+    /// via `miden replay`
+    #[clap(long = "record", value_parser)]
+    record_file: Option<PathBuf>,
+
     /// Enable tracing to monitor execution of the VM
     #[clap(short = 't', long = "tracing")]
     tracing: bool,
@@ -41,13 +65,17 @@ pub struct RunCmd {
 
 impl RunCmd {
     pub fn execute(&self) -> Result<(), String> {
+        if self.output_format == OutputFormat::Json {
+            return self.execute_json();
+        }
+
         println!("===============================================================================");
         println!("Run program: {}", self.assembly_file.display());
         println!("-------------------------------------------------------------------------------");
 
         let now = Instant::now();
 
-        let (trace, program_hash) = run_program(self)?;
+        let (trace, snapshot, program_hash) = run_program(self)?;
 
         println!(
             "Executed the program with hash {} in {} ms",
@@ -63,6 +91,14 @@ impl RunCmd {
             println!("Output: {:?}", trace.stack_outputs().stack_truncated(self.num_outputs));
         }
 
+        // write the requested memory ranges to the screen
+        for range in self.memory_ranges.iter() {
+            println!("Memory {range}:");
+            for (addr, value) in range.read(&snapshot) {
+                println!("    {addr}: {value:?}");
+            }
+        }
+
         // calculate the percentage of padded rows
         let padding_percentage = (trace.trace_len_summary().padded_trace_len()
             - trace.trace_len_summary().trace_len())
@@ -92,37 +128,264 @@ impl RunCmd {
 
         Ok(())
     }
+
+    /// Executes the program and prints the results of the execution to the screen as a single
+    /// JSON object.
+    fn execute_json(&self) -> Result<(), String> {
+        let now = Instant::now();
+        let (trace, snapshot, program_hash) = run_program(self)?;
+        let execution_time_ms = now.elapsed().as_millis();
+
+        if let Some(output_path) = &self.output_file {
+            OutputFile::write(trace.stack_outputs(), output_path)?;
+        }
+
+        let report = RunReport::new(self, &trace, &snapshot, program_hash, execution_time_ms);
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|err| format!("Failed to serialize execution results - {err}"))?;
+        println!("{json}");
+
+        Ok(())
+    }
+}
+
+// OUTPUT FORMAT
+// ================================================================================================
+
+/// Format in which the results of the execution are printed to the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable console output.
+    Text,
+    /// A single JSON object containing the stack outputs, the requested memory ranges, and the
+    /// cycle statistics of the execution.
+    Json,
+}
+
+// MEMORY RANGE
+// ================================================================================================
+
+/// A range of memory addresses in a given execution context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryRange {
+    ctx: u32,
+    start: u32,
+    end: u32,
+}
+
+impl MemoryRange {
+    /// Returns the values of all addresses in this range at the end of the execution. Addresses
+    /// which were never accessed by the program are initialized to zeros.
+    fn read(&self, snapshot: &ProcessStateSnapshot) -> Vec<(u32, [u64; 4])> {
+        let ctx = ContextId::from(self.ctx);
+        (self.start..self.end)
+            .map(|addr| {
+                let value = snapshot.get_mem_value(ctx, addr).unwrap_or([ZERO; 4]);
+                (addr, value.map(|element| element.as_int()))
+            })
+            .collect()
+    }
+}
+
+impl FromStr for MemoryRange {
+    type Err = String;
+
+    /// Parses a memory range specified as `ctx:start..end`, where `end` is exclusive.
+    fn from_str(range: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid memory range `{range}`, expected `ctx:start..end`");
+
+        let (ctx, addrs) = range.split_once(':').ok_or_else(err)?;
+        let (start, end) = addrs.split_once("..").ok_or_else(err)?;
+        let ctx = ctx.trim().parse::<u32>().map_err(|_| err())?;
+        let start = start.trim().parse::<u32>().map_err(|_| err())?;
+        let end = end.trim().parse::<u32>().map_err(|_| err())?;
+        if start >= end {
+            return Err(format!("memory range `{range}` is empty"));
+        }
+
+        Ok(Self { ctx, start, end })
+    }
+}
+
+impl core::fmt::Display for MemoryRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}..{}", self.ctx, self.start, self.end)
+    }
+}
+
+// JSON REPORT
+// ================================================================================================
+
+/// Results of the execution of a program printed when `--outputs json` is specified.
+///
+/// Field elements are serialized as strings so that their values are not rounded by JSON parsers
+/// which represent all numbers as doubles.
+#[derive(Serialize)]
+struct RunReport {
+    program_hash: String,
+    execution_time_ms: u128,
+    stack: Vec<String>,
+    overflow_addrs: Vec<String>,
+    memory: Vec<MemoryRangeReport>,
+    cycles: CyclesReport,
+}
+
+#[derive(Serialize)]
+struct MemoryRangeReport {
+    ctx: u32,
+    start: u32,
+    end: u32,
+    values: Vec<MemoryValueReport>,
+}
+
+#[derive(Serialize)]
+struct MemoryValueReport {
+    addr: u32,
+    value: [String; 4],
+}
+
+#[derive(Serialize)]
+struct CyclesReport {
+    trace_len: usize,
+    padded_trace_len: usize,
+    padding_percentage: usize,
+    stack_rows: usize,
+    range_checker_rows: usize,
+    chiplets_rows: usize,
+    hash_chiplet_rows: usize,
+    bitwise_chiplet_rows: usize,
+    memory_chiplet_rows: usize,
+    kernel_rom_rows: usize,
+}
+
+impl RunReport {
+    fn new(
+        params: &RunCmd,
+        trace: &ExecutionTrace,
+        snapshot: &ProcessStateSnapshot,
+        program_hash: [u8; 32],
+        execution_time_ms: u128,
+    ) -> Self {
+        let stack_outputs = trace.stack_outputs();
+        let summary = trace.trace_len_summary();
+        let chiplets = summary.chiplets_trace_len();
+
+        let memory = params
+            .memory_ranges
+            .iter()
+            .map(|range| MemoryRangeReport {
+                ctx: range.ctx,
+                start: range.start,
+                end: range.end,
+                values: range
+                    .read(snapshot)
+                    .into_iter()
+                    .map(|(addr, value)| MemoryValueReport {
+                        addr,
+                        value: value.map(|element| element.to_string()),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            program_hash: hex::encode(program_hash),
+            execution_time_ms,
+            stack: stack_outputs
+                .stack_truncated(params.num_outputs)
+                .iter()
+                .map(|value| value.to_string())
+                .collect(),
+            overflow_addrs: stack_outputs
+                .overflow_addrs()
+                .iter()
+                .map(|value| value.to_string())
+                .collect(),
+            memory,
+            cycles: CyclesReport {
+                trace_len: summary.trace_len(),
+                padded_trace_len: summary.padded_trace_len(),
+                padding_percentage: (summary.padded_trace_len() - summary.trace_len()) * 100
+                    / summary.padded_trace_len(),
+                stack_rows: summary.main_trace_len(),
+                range_checker_rows: summary.range_trace_len(),
+                chiplets_rows: chiplets.trace_len(),
+                hash_chiplet_rows: chiplets.hash_chiplet_len(),
+                bitwise_chiplet_rows: chiplets.bitwise_chiplet_len(),
+                memory_chiplet_rows: chiplets.memory_chiplet_len(),
+                kernel_rom_rows: chiplets.kernel_rom_len(),
+            },
+        }
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
 #[instrument(name = "run_program", skip_all)]
-fn run_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), String> {
+fn run_program(
+    params: &RunCmd,
+) -> Result<(ExecutionTrace, ProcessStateSnapshot, [u8; 32]), String> {
     // load libraries from files
     let libraries = Libraries::new(&params.library_paths)?;
 
-    // load program from file and compile
-    let program =
-        ProgramFile::read(&params.assembly_file)?.compile(&Debug::Off, libraries.libraries)?;
+    // load program from file and compile; source maps are needed only to record the run
+    let program_file = ProgramFile::read(&params.assembly_file)?;
+    let (program, source_map) = if params.record_file.is_some() {
+        let (program, source_map) =
+            program_file.compile_with_source_map(&Debug::Off, libraries.libraries)?;
+        (program, Some(source_map))
+    } else {
+        (program_file.compile(&Debug::Off, libraries.libraries)?, None)
+    };
 
     // load input data from file
     let input_data = InputFile::read(&params.input_file, &params.assembly_file)?;
 
     // get execution options
-    let execution_options =
+    let mut execution_options =
         ExecutionOptions::new(Some(params.max_cycles), params.expected_cycles, params.tracing)
             .map_err(|err| format!("{err}"))?;
+    if params.local_canaries {
+        execution_options = execution_options.with_local_canaries();
+    }
 
     // fetch the stack and program inputs from the arguments
     let stack_inputs = input_data.parse_stack_inputs()?;
-    let host = DefaultHost::new(input_data.parse_advice_provider()?);
+    let mut host = RecordingHost::new(DefaultHost::new(input_data.parse_advice_provider()?));
 
     let program_hash: [u8; 32] = program.hash().into();
 
     // execute program and generate outputs
-    let trace = processor::execute(&program, stack_inputs, host, execution_options)
-        .map_err(|err| format!("Failed to generate execution trace = {:?}", err))?;
+    let result =
+        processor::execute_with_snapshot(&program, stack_inputs, &mut host, execution_options);
+
+    // record the run before reporting a failure, so that failed runs can be replayed as well
+    if let Some(record_path) = &params.record_file {
+        let outcome = match &result {
+            Ok((trace, _)) => RunOutcome::Success(trace.stack_outputs().clone()),
+            Err(err) => RunOutcome::Failure(err.to_string()),
+        };
+        let bundle = RunBundle {
+            vm_version: env!("CARGO_PKG_VERSION").into(),
+            program,
+            source_path: params.assembly_file.display().to_string(),
+            source: program_file.source().into(),
+            source_map: source_map.unwrap_or_default(),
+            inputs: serde_json::to_string(&input_data)
+                .map_err(|err| format!("Failed to serialize input data - {err}"))?,
+            max_cycles: params.max_cycles,
+            expected_cycles: params.expected_cycles,
+            tracing: params.tracing,
+            local_canaries: params.local_canaries,
+            outcome,
+            events: host.into_events(),
+        };
+        bundle.write(record_path)?;
+    }
+
+    let (trace, snapshot) =
+        result.map_err(|err| format!("Failed to generate execution trace = {:?}", err))?;
 
-    Ok((trace, program_hash))
+    Ok((trace, snapshot, program_hash))
 }
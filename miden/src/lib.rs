@@ -6,15 +6,37 @@
 
 pub use assembly::{
     ast::{ModuleAst, ProgramAst},
-    Assembler, AssemblyError, ParsingError,
+    disassemble, Assembler, AssemblyError, Disassembler, MastForest, MastNodeId, OpFrequencyConfig,
+    OpFrequencyReport, ParsingError,
 };
 pub use processor::{
-    crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, DefaultHost,
-    ExecutionError, ExecutionTrace, Host, Kernel, MemAdviceProvider, Operation, Program,
-    ProgramInfo, StackInputs, VmState, VmStateIterator, ZERO,
+    crypto, execute, execute_block, execute_iter, execute_recoverable, execute_with_memory,
+    execute_with_snapshot, execute_with_token, utils, AdviceInputs, AdviceProvider, AdviceStream,
+    AsmOpInfo, BlockTrace, DefaultHost, ErrorContext, ExecutionError, ExecutionOutcome,
+    ExecutionTrace, HaltedState, Host, Kernel, MemAdviceProvider, MemoryInputs, Operation, Program,
+    ProgramInfo, StackInputs, StreamingAdviceProvider, VmState, VmStateIterator, ZERO,
 };
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, ProvingOptions,
-    StackOutputs, StarkProof, Word,
+    math, prove, prove_trace, prove_with_token, CancellationToken, Digest, ExecutionProof,
+    FieldExtension, HashFunction, InputError, ProvingOptions, StackOutputs, StarkProof, Word,
 };
-pub use verifier::{verify, VerificationError};
+pub use verifier::{verify, BusArgument, BusArguments, BusRelation, VerificationError};
+
+#[cfg(feature = "std")]
+pub use prover::{prove_async, ProvingHandle, ProvingPool, ProvingResult};
+
+// PRELUDE
+// ================================================================================================
+
+/// The set of types and functions needed by most users of Miden VM.
+///
+/// Items in this module form the stable API of this crate: an item will not be removed from the
+/// prelude, nor will its path change, without a major version bump of the crate. Thus, it is
+/// recommended to import these items via `use miden_vm::prelude::*` rather than from the
+/// underlying crates directly.
+pub mod prelude {
+    pub use super::{
+        prove, verify, AdviceInputs, Assembler, DefaultHost, ExecutionProof, MemAdviceProvider,
+        Program, ProgramInfo, ProvingOptions, StackInputs, StackOutputs,
+    };
+}
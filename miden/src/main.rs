@@ -27,8 +27,10 @@ pub enum Actions {
     Compile(cli::CompileCmd),
     Bundle(cli::BundleCmd),
     Debug(cli::DebugCmd),
+    Disasm(cli::DisasmCmd),
     Example(examples::ExampleOptions),
     Prove(cli::ProveCmd),
+    Replay(cli::ReplayCmd),
     Run(cli::RunCmd),
     Verify(cli::VerifyCmd),
     #[cfg(feature = "std")]
@@ -43,8 +45,10 @@ impl Cli {
             Actions::Compile(compile) => compile.execute(),
             Actions::Bundle(compile) => compile.execute(),
             Actions::Debug(debug) => debug.execute(),
+            Actions::Disasm(disasm) => disasm.execute(),
             Actions::Example(example) => example.execute(),
             Actions::Prove(prove) => prove.execute(),
+            Actions::Replay(replay) => replay.execute(),
             Actions::Run(run) => run.execute(),
             Actions::Verify(verify) => verify.execute(),
             #[cfg(feature = "std")]
@@ -102,7 +106,7 @@ impl fmt::Display for ProgramError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ProgramError::AssemblyError(e) => write!(f, "Assembly Error: {:?}", e),
-            ProgramError::ExecutionError(e) => write!(f, "Execution Error: {:?}", e),
+            ProgramError::ExecutionError(e) => write!(f, "Execution Error: {e}"),
         }
     }
 }
@@ -1,7 +1,7 @@
 use super::{cli::InputFile, ProgramError};
 use clap::Parser;
 use core::fmt;
-use miden_vm::{Assembler, DefaultHost, Host, Operation, StackInputs};
+use miden_vm::{Assembler, DefaultHost, Host, ModuleAst, Operation, StackInputs};
 use processor::{AsmOpInfo, TraceLenSummary};
 use std::{fs, path::PathBuf};
 use stdlib::StdLibrary;
@@ -19,6 +19,9 @@ pub struct Analyze {
     /// Path to .inputs file
     #[clap(short = 'i', long = "input", value_parser)]
     input_file: Option<PathBuf>,
+    /// Print the interface of the module in the assembly file instead of analyzing its execution
+    #[clap(long = "interface", conflicts_with = "input_file")]
+    interface: bool,
 }
 
 /// Implements CLI execution logic
@@ -27,6 +30,13 @@ impl Analyze {
         let program = fs::read_to_string(&self.assembly_file)
             .map_err(|e| format!("could not read masm file: {e}"))?;
 
+        if self.interface {
+            let module = ModuleAst::parse(&program)
+                .map_err(|e| format!("could not parse masm module: {e}"))?;
+            println!("{}", module.interface());
+            return Ok(());
+        }
+
         // load input data from file
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
 
@@ -326,7 +336,9 @@ mod tests {
         let stack_inputs = StackInputs::try_from_ints(stack_inputs).unwrap();
         let host = DefaultHost::default();
         let execution_details = super::analyze(source, stack_inputs, host);
-        let expected_error = "Execution Error: DivideByZero(1)";
+        let expected_error = "Execution Error: Division by zero at clock cycle 1 (operation 0 of \
+            span block aae32e87f6e5537817bae4f5d02f87bdb150953848198db96d642ee1c02bc09d, \
+            instruction `div` in `#main`)";
         assert_eq!(execution_details.err().unwrap().to_string(), expected_error);
     }
 
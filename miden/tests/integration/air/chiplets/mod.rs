@@ -1,4 +1,8 @@
-use test_utils::{build_test, rand::rand_vector};
+use miden_vm::Assembler;
+use test_utils::{
+    build_test, prove, rand::rand_vector, verify, DefaultHost, ExecutionOptions, MemAdviceProvider,
+    ProgramInfo, ProvingOptions, StackInputs,
+};
 
 mod bitwise;
 mod hasher;
@@ -16,3 +20,39 @@ fn chiplets() {
 
     build_test!(source, &pub_inputs).prove_and_verify(pub_inputs, false);
 }
+
+// UNUSED CHIPLETS
+// ================================================================================================
+
+#[test]
+fn unused_chiplets_skipped() {
+    let kernel = "
+        export.foo add end
+        export.bar mul end
+        export.baz sub end";
+    let assembler = Assembler::default().with_kernel(kernel).unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1, 2, 3]).unwrap();
+    let host = || DefaultHost::new(MemAdviceProvider::default());
+    let execute = |program, options| {
+        let trace = miden_vm::execute(program, stack_inputs.clone(), host(), options).unwrap();
+        trace.trace_len_summary().chiplets_trace_len().kernel_rom_len()
+    };
+    let options = ExecutionOptions::default().with_unused_chiplets_skipped();
+
+    // the kernel ROM trace is omitted only if no kernel procedures are invoked
+    let program = assembler.compile("begin push.1 add end").unwrap();
+    assert_eq!(3, execute(&program, ExecutionOptions::default()));
+    assert_eq!(0, execute(&program, options));
+
+    let syscall_program = assembler.compile("begin syscall.foo end").unwrap();
+    assert_eq!(3, execute(&syscall_program, options));
+
+    // proofs of executions with skipped chiplets can be verified
+    for program in [program, syscall_program] {
+        let proving_options = ProvingOptions::default().with_execution_options(options);
+        let (outputs, proof) = prove(&program, stack_inputs.clone(), host(), proving_options)
+            .expect("failed to generate proof");
+        let program_info = ProgramInfo::from(program);
+        assert!(verify(program_info, stack_inputs.clone(), outputs, proof).is_ok());
+    }
+}
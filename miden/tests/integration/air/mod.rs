@@ -1,3 +1,101 @@
+use miden_vm::{
+    prove_async, BusArgument, BusArguments, BusRelation, CancellationToken, ExecutionProof,
+    ProvingPool, VerificationError,
+};
+use std::{thread, time::Duration};
+use test_utils::{
+    build_test, prove, verify, DefaultHost, ExecutionError, Felt, MemAdviceProvider, ProgramInfo,
+    ProvingOptions,
+};
+
 mod chiplets;
 mod range;
 mod stack;
+
+// PROVING
+// ================================================================================================
+
+#[test]
+fn async_proving() {
+    let test = build_test!("begin push.1 push.2 add end");
+    let program = test.compile().unwrap();
+    let host = || DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+
+    // a job submitted to the default pool produces a valid proof
+    let handle =
+        prove_async(program.clone(), test.stack_inputs.clone(), host(), ProvingOptions::default());
+    let (outputs, proof) = handle.wait().unwrap();
+    assert_eq!(Felt::new(3), outputs.stack()[0]);
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, test.stack_inputs.clone(), outputs, proof).is_ok());
+
+    // a job which never terminates on its own is aborted once its handle is cancelled
+    let pool = ProvingPool::new(1);
+    let program = build_test!("begin push.1 while.true push.1 end end").compile().unwrap();
+    let handle =
+        pool.prove(program.clone(), test.stack_inputs.clone(), host(), ProvingOptions::default());
+    thread::sleep(Duration::from_millis(10));
+    handle.cancel();
+    assert!(matches!(handle.wait(), Err(ExecutionError::Cancelled)));
+
+    // jobs cancelled while waiting in the queue are skipped
+    let blocker =
+        pool.prove(program.clone(), test.stack_inputs.clone(), host(), ProvingOptions::default());
+    let queued =
+        pool.prove(program.clone(), test.stack_inputs.clone(), host(), ProvingOptions::default());
+    queued.cancel();
+    blocker.cancel();
+    assert!(matches!(queued.wait(), Err(ExecutionError::Cancelled)));
+    assert!(matches!(blocker.wait(), Err(ExecutionError::Cancelled)));
+
+    // dropping a handle does not cancel a token provided by the caller
+    let token = CancellationToken::new();
+    let submit = |program| {
+        let options = ProvingOptions::default();
+        pool.prove_with_token(program, test.stack_inputs.clone(), host(), options, token.clone())
+    };
+    let first = submit(program.clone());
+    let second = submit(program);
+    drop(first);
+    assert!(!token.is_cancelled());
+    token.cancel();
+    assert!(matches!(second.wait(), Err(ExecutionError::Cancelled)));
+}
+
+#[test]
+fn bus_arguments() {
+    let test = build_test!("begin push.1 push.2 add end");
+    let program = test.compile().unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let (outputs, proof) =
+        prove(&program, test.stack_inputs.clone(), host, ProvingOptions::default()).unwrap();
+    let program_info = ProgramInfo::from(program);
+
+    // proofs enforce all bus relations via auxiliary columns, and are serialized as before
+    assert_eq!(proof.bus_arguments(), BusArguments::AUX_COLUMNS);
+    let bytes = proof.to_bytes();
+    assert_eq!(bytes[0], proof.hash_fn() as u8);
+    assert_eq!(ExecutionProof::from_bytes(&bytes).unwrap(), proof);
+
+    // trailing bytes are rejected
+    let mut padded = bytes.clone();
+    padded.push(0);
+    assert!(ExecutionProof::from_bytes(&padded).is_err());
+
+    // bus arguments survive serialization
+    let bus_arguments =
+        BusArguments::AUX_COLUMNS.with(BusRelation::RangeChecker, BusArgument::VirtualBus);
+    let migrated = proof.clone().with_bus_arguments(bus_arguments);
+    let restored = ExecutionProof::from_bytes(&migrated.to_bytes()).unwrap();
+    assert_eq!(restored.bus_arguments().get(BusRelation::RangeChecker), BusArgument::VirtualBus);
+    assert_eq!(restored.bus_arguments().get(BusRelation::Chiplets), BusArgument::AuxColumn);
+    assert_eq!(restored, migrated);
+
+    // the verifier dispatches on the bus arguments recorded in the proof
+    let result = verify(program_info.clone(), test.stack_inputs.clone(), outputs.clone(), migrated);
+    assert_eq!(
+        result,
+        Err(VerificationError::UnsupportedBusArgument(BusRelation::RangeChecker))
+    );
+    assert!(verify(program_info, test.stack_inputs.clone(), outputs, proof).is_ok());
+}
@@ -1,4 +1,4 @@
-use test_utils::build_op_test;
+use test_utils::{build_op_test, build_test, DefaultHost, ExecutionOptions, MemAdviceProvider};
 
 mod field_ops;
 mod stack_manipualtion_ops;
@@ -68,3 +68,36 @@ fn overflow_inputs_overflow_outputs() {
 
     build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
 }
+
+// OVERFLOW TABLE EVICTION
+// ================================================================================================
+
+#[test]
+fn overflow_table_eviction() {
+    // push values deep into the overflow table both in the root context and in a called procedure,
+    // and leave some of them in the overflow table at the end of execution
+    let source = "
+        proc.foo
+            repeat.40 push.1 add.1 end
+            repeat.40 add end
+        end
+
+        begin
+            repeat.40 dup add.1 end
+            call.foo
+            repeat.30 add end
+        end";
+
+    let test = build_test!(source, &[1, 2, 3]);
+    let program = test.compile().unwrap();
+
+    let execute = |options| {
+        let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+        miden_vm::execute(&program, test.stack_inputs.clone(), host, options).unwrap()
+    };
+    let expected = execute(ExecutionOptions::default());
+    let actual = execute(ExecutionOptions::default().with_overflow_eviction(4));
+
+    assert_eq!(expected.stack_outputs(), actual.stack_outputs());
+    assert_eq!(expected.program_info(), actual.program_info());
+}
@@ -35,3 +35,80 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn cli_run_json_outputs() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let mut cmd = bin_under_test.command();
+
+    cmd.arg("run")
+        .arg("-a")
+        .arg("./examples/fib/fib.masm")
+        .arg("-n")
+        .arg("1")
+        .arg("-m")
+        .arg("4096")
+        .arg("-e")
+        .arg("4096")
+        .arg("--outputs")
+        .arg("json")
+        .arg("--memory")
+        .arg("0:0..2");
+
+    let output = cmd.unwrap();
+
+    // the output must contain the JSON report without any human-oriented console text
+    output
+        .assert()
+        .stdout(predicate::str::starts_with("{"))
+        .stdout(predicate::str::contains("\"cycles\""))
+        .stdout(predicate::str::contains("\"addr\": 1"))
+        .stdout(predicate::str::contains("VM cycles").not());
+
+    Ok(())
+}
+
+#[test]
+fn cli_run_record_and_replay() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let bundle_path = std::env::temp_dir().join("miden_cli_record_and_replay.mvr");
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("run")
+        .arg("-a")
+        .arg("./examples/fib/fib.masm")
+        .arg("-n")
+        .arg("1")
+        .arg("-m")
+        .arg("4096")
+        .arg("-e")
+        .arg("4096")
+        .arg("--record")
+        .arg(&bundle_path);
+    cmd.unwrap().assert().success();
+
+    // the recorded run must be reproduced from the bundle alone
+    let mut cmd = bin_under_test.command();
+    cmd.arg("replay").arg(&bundle_path);
+    cmd.unwrap()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Replay matches the recorded run"));
+
+    std::fs::remove_file(bundle_path)?;
+    Ok(())
+}
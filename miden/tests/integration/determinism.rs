@@ -0,0 +1,249 @@
+use miden_air::{ProcessorAir, PublicInputs};
+use miden_vm::{Assembler, ExecutionProof};
+use processor::crypto::RpoRandomCoin;
+use test_utils::{
+    crypto::{RandomCoin, Rpo256},
+    math::{FieldElement, QuadExtension, ToElements},
+    prove, verify, AdviceInputs, DefaultHost, Felt, MemAdviceProvider, ProgramInfo, ProvingOptions,
+    StackInputs,
+};
+use winter_air::{Air, ConstraintCompositionCoefficients};
+
+type QuadExt = QuadExtension<Felt>;
+
+// CONSTANTS
+// ================================================================================================
+
+/// A program which is proven by the determinism test, along with the values expected to be
+/// produced when proving it.
+///
+/// The expected values were recorded on x86_64 and must be the same on every platform and with
+/// every combination of features (e.g., `concurrent`); if a change to the VM alters them
+/// intentionally, they need to be updated.
+struct ProgramCase {
+    name: &'static str,
+    source: &'static str,
+    stack_inputs: &'static [u64],
+    program_hash: [u64; 4],
+    stack_outputs: &'static [u64],
+    overflow_addrs: &'static [u64],
+    /// Random elements for the auxiliary trace segments followed by the out-of-domain point, as
+    /// base field elements.
+    challenges: &'static [u64],
+    /// BLAKE3 hash of the serialized proof.
+    proof_hash: &'static str,
+}
+
+/// Programs which exercise all components of the VM.
+#[rustfmt::skip]
+const PROGRAMS: [ProgramCase; 4] = [
+    ProgramCase {
+        name: "field_ops",
+        source: "begin repeat.2048 swap dup.1 mul add.7 end end",
+        stack_inputs: &[3, 5],
+        program_hash: [
+            7142840598826100307, 16000275472015050379, 16129299018069952477, 12304693369882629977,
+        ],
+        stack_outputs: &[
+            5680436737844239143, 12004976665218812512, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        overflow_addrs: &[],
+        challenges: &[
+            8952386458566386357, 2070338966951249390, 8330930476098512425, 16147670603360554990,
+            229146823159583904, 16998752562553385195, 10313128370281534619, 14641565281447745710,
+            18424516083964420023, 12546950306249274573, 7647176499889731554, 2677852618356805254,
+            4563900268517906138, 596222770278497710, 6316315927512715519, 15519087823892291469,
+            16395544477018382792, 16946947505597262877, 17862230126357494130, 17247869168657652279,
+            11525807254672439827, 3296085791811035641, 18216220984572616078, 9303074193029628696,
+            8893680474627934061, 8045679134228425414, 13967142232923795153, 231564330507238668,
+            336186412699331032, 2690622102856470319, 15574559882579349997, 13444268193979171872,
+            11299439665171513135, 17658106093899799621,
+        ],
+        proof_hash: "cbedd878176aa52fb0df87286f0afc380687020961fe26354bf5c68a683b549a",
+    },
+    ProgramCase {
+        name: "u32_ops",
+        source: "begin
+            repeat.256
+                dup.1 dup.1 u32wrapping_add dup.1 u32xor swap.2 drop u32rotl.3
+            end
+        end",
+        stack_inputs: &[1, 2],
+        program_hash: [
+            5399606655730513588, 12451091730318764565, 380838018152021222, 2201420322612661209,
+        ],
+        stack_outputs: &[2, 7476161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        overflow_addrs: &[],
+        challenges: &[
+            17237699089431579663, 1969319848825954790, 3012824975148283532, 17422396996915361894,
+            8075596874886003238, 9705403019342015966, 6415524711606292442, 2383385862333432046,
+            1051296403794780147, 4135531047903396347, 8537052234502068443, 9541673390409880755,
+            5310049466080427082, 4964211273650142131, 6335491930477100397, 11749606620722910157,
+            1177098238538701248, 3680681791693296701, 13450362362807412524, 17091132578432040137,
+            4528274921351990115, 11871107232760701751, 159315660706081721, 17622986689792745694,
+            1600880483478546680, 1030830672022099801, 9897992415665962453, 3576874507768539417,
+            17597317638460081296, 12885092558613600077, 13842780086392357941, 11037186330955781096,
+            14682700745933894803, 1821507411514048990,
+        ],
+        proof_hash: "fb7d12bdd79e7dfced99f1404b20e9d3b98ebf55d20755097955172f7580ea35",
+    },
+    ProgramCase {
+        name: "hashing_and_memory",
+        source: "begin
+            padw padw padw
+            repeat.64
+                hperm dupw push.1000 mem_storew dropw
+            end
+            dropw dropw dropw
+            push.1000 padw movup.4 mem_loadw
+        end",
+        stack_inputs: &[],
+        program_hash: [
+            1923215290594084336, 322113446008411926, 376418664535371639, 9461784319546341545,
+        ],
+        stack_outputs: &[
+            5414163072266743237, 3819969227434727164, 544453453279054292, 13445859874421621931, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        overflow_addrs: &[0, 747, 748, 749, 750],
+        challenges: &[
+            14734086166582461812, 16334860053844346259, 7869887709597223252, 17345603929027648172,
+            642953363722917025, 13991581397735336902, 12181434897074931698, 961432770790302772,
+            3167292975860782391, 618940729812700105, 6107637787430985322, 10713914855174781506,
+            1423758013741412956, 4728178754295718474, 1058271080539282361, 10407681485144078115,
+            8054292969254813255, 18011555539944905544, 10655596330013286475, 7969784870333162936,
+            12321486769274555891, 12034542390178306452, 11203291091854489434, 15215039708592175191,
+            4834810058840806164, 8251342986063152115, 15057889155019744075, 15931004153839442095,
+            1228464915279365872, 4518371336052551542, 3331336133171757652, 7443785720028096602,
+            17195257110796445664, 2732761052852688247,
+        ],
+        proof_hash: "5aab3d951af28af8b50f7bc7f026a892a09d815485cd4047bcf9379f13398a66",
+    },
+    ProgramCase {
+        name: "stack_overflow",
+        source: "begin repeat.20 dup.15 end end",
+        stack_inputs: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+        program_hash: [
+            4110089181946886936, 5335838815019319615, 11981751320467126204, 5713663038364573983,
+        ],
+        stack_outputs: &[
+            4, 3, 2, 1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 16, 15, 14, 13, 12,
+            11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        ],
+        overflow_addrs: &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20],
+        challenges: &[
+            14063189306254700341, 2939111868942465610, 18095595146304443780, 6258631474280835460,
+            16323074018354462125, 700556006007644730, 16786404309698644270, 1817948331095009922,
+            6418699510894851974, 14271548569855909314, 8863811756578223382, 12210134225436121753,
+            3421245946118373765, 4034475777391981327, 4088344978172158589, 8977350036682173160,
+            12154062680887184719, 10149394784149539659, 9959778611541722601, 12560358874539935277,
+            4155685565413016176, 6294629748490620648, 15825936983148042598, 10403192836655982755,
+            10900227186941851922, 14285153399903583077, 9675638798516816150, 9066547440046919472,
+            12467453520725674865, 17896609684698887534, 7074517481146895102, 17746749753521826604,
+            5031358315526943195, 8274057788868241980,
+        ],
+        proof_hash: "fb76a6e71df5fed928431d973f53df1f6647f8770f5bbe8a708b6d64b49d51d6",
+    },
+];
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn cross_platform_determinism() {
+    for program in PROGRAMS.iter() {
+        let proof_hash = check_program(program);
+
+        // proving the same program again must produce the same proof, which catches
+        // nondeterminism within a single platform (e.g., in parallel folds)
+        assert_eq!(
+            proof_hash,
+            check_program(program),
+            "{}: proving is not deterministic",
+            program.name
+        );
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Proves the specified program, and checks that the program hash, the stack outputs, the
+/// challenges drawn by the verifier from the proof transcript, and the hash of the proof are equal
+/// to the expected ones. Returns the hash of the proof.
+fn check_program(program: &ProgramCase) -> String {
+    let name = program.name;
+    let compiled = Assembler::default().compile(program.source).unwrap();
+    let stack_inputs = StackInputs::try_from_ints(program.stack_inputs.iter().copied()).unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::from(AdviceInputs::default()));
+    let options = ProvingOptions::with_96_bit_security(true);
+    let (outputs, proof) = prove(&compiled, stack_inputs.clone(), host, options).unwrap();
+
+    let program_info = ProgramInfo::from(compiled);
+    assert_eq!(
+        to_ints(program_info.program_hash().as_elements()),
+        program.program_hash,
+        "{name}: program hash"
+    );
+    assert_eq!(to_ints(outputs.stack()), program.stack_outputs, "{name}: stack outputs");
+    assert_eq!(
+        to_ints(outputs.overflow_addrs()),
+        program.overflow_addrs,
+        "{name}: overflow addresses"
+    );
+
+    let pub_inputs = PublicInputs::new(program_info.clone(), stack_inputs.clone(), outputs.clone());
+    let challenges = draw_challenges(&proof, pub_inputs);
+    assert_eq!(
+        to_ints(QuadExt::slice_as_base_elements(&challenges)),
+        program.challenges,
+        "{name}: challenges"
+    );
+
+    let proof_hash = blake3::hash(&proof.to_bytes()).to_hex().to_string();
+    assert_eq!(proof_hash, program.proof_hash, "{name}: proof hash");
+
+    verify(program_info, stack_inputs, outputs, proof).unwrap();
+    proof_hash
+}
+
+/// Replays the proof transcript in the same way as the verifier does, and returns the random
+/// elements for the auxiliary trace segments followed by the out-of-domain point.
+fn draw_challenges(proof: &ExecutionProof, pub_inputs: PublicInputs) -> Vec<QuadExt> {
+    let proof = proof.stark_proof();
+    let mut seed = proof.context.to_elements();
+    seed.extend_from_slice(&pub_inputs.to_elements());
+    let air = ProcessorAir::new(proof.get_trace_info(), pub_inputs, proof.options().clone());
+
+    let num_fri_layers = air.options().to_fri_options().num_fri_layers(air.lde_domain_size());
+    let (trace_roots, constraint_root, _) = proof
+        .commitments
+        .clone()
+        .parse::<Rpo256>(air.trace_layout().num_segments(), num_fri_layers)
+        .unwrap();
+
+    let mut public_coin = RpoRandomCoin::new(Rpo256::hash_elements(&seed).into());
+    let mut challenges = Vec::new();
+
+    // random elements for the auxiliary trace segments
+    public_coin.reseed(trace_roots[0]);
+    for (i, root) in trace_roots.iter().skip(1).enumerate() {
+        let rand_elements: Vec<QuadExt> =
+            air.get_aux_trace_segment_random_elements(i, &mut public_coin).unwrap();
+        challenges.extend(rand_elements);
+        public_coin.reseed(*root);
+    }
+
+    // coefficients of the composition polynomial are drawn before the constraint commitment is
+    // received, and thus, need to be drawn to get the out-of-domain point
+    let _: ConstraintCompositionCoefficients<QuadExt> =
+        air.get_constraint_composition_coefficients(&mut public_coin).unwrap();
+    public_coin.reseed(constraint_root);
+    challenges.push(public_coin.draw().unwrap());
+
+    challenges
+}
+
+fn to_ints(elements: &[Felt]) -> Vec<u64> {
+    elements.iter().map(|e| e.as_int()).collect()
+}
@@ -23,6 +23,7 @@ fn test_exec_iter() {
             op: None,
             asmop: None,
             stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
+            stack_depth: 16,
             fmp,
             memory: Vec::new(),
         },
@@ -31,7 +32,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Span),
             asmop: None,
-            stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 1].to_elements(),
+            stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
+            stack_depth: 16,
             fmp,
             memory: Vec::new(),
         },
@@ -44,6 +46,7 @@ fn test_exec_iter() {
                 1,
             )),
             stack: [0, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
+            stack_depth: 17,
             fmp,
             memory: Vec::new(),
         },
@@ -55,7 +58,8 @@ fn test_exec_iter() {
                 AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
                 2,
             )),
-            stack: [1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2].to_elements(),
+            stack: [1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
+            stack_depth: 17,
             fmp,
             memory: Vec::new(),
         },
@@ -68,6 +72,7 @@ fn test_exec_iter() {
                 3,
             )),
             stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
+            stack_depth: 16,
             fmp,
             memory: mem.clone(),
         },
@@ -80,6 +85,7 @@ fn test_exec_iter() {
                 1,
             )),
             stack: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0].to_elements(),
+            stack_depth: 16,
             fmp,
             memory: mem.clone(),
         },
@@ -92,6 +98,7 @@ fn test_exec_iter() {
                 2,
             )),
             stack: [14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0].to_elements(),
+            stack_depth: 16,
             fmp,
             memory: mem.clone(),
         },
@@ -104,6 +111,7 @@ fn test_exec_iter() {
                 3,
             )),
             stack: [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
+            stack_depth: 16,
             fmp,
             memory: mem.clone(),
         },
@@ -115,7 +123,8 @@ fn test_exec_iter() {
                 AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
                 4,
             )),
-            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
+            stack_depth: 16,
             fmp,
             memory: mem.clone(),
         },
@@ -128,6 +137,7 @@ fn test_exec_iter() {
                 1,
             )),
             stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
+            stack_depth: 17,
             fmp,
             memory: mem.clone(),
         },
@@ -136,7 +146,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Noop),
             asmop: None,
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
+            stack_depth: 17,
             fmp,
             memory: mem.clone(),
         },
@@ -145,7 +156,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Push(ONE)),
             asmop: None,
-            stack: [1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
+            stack: [1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
+            stack_depth: 18,
             fmp,
             memory: mem.clone(),
         },
@@ -154,7 +166,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::FmpUpdate),
             asmop: None,
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
+            stack_depth: 17,
             fmp: next_fmp,
             memory: mem.clone(),
         },
@@ -167,6 +180,7 @@ fn test_exec_iter() {
                 1,
             )),
             stack: [0, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
+            stack_depth: 18,
             fmp: next_fmp,
             memory: mem.clone(),
         },
@@ -178,8 +192,9 @@ fn test_exec_iter() {
                 AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
                 2,
             )),
-            stack: [2u64.pow(30) + 1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0]
+            stack: [2u64.pow(30) + 1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0]
                 .to_elements(),
+            stack_depth: 18,
             fmp: next_fmp,
             memory: mem,
         },
@@ -191,7 +206,8 @@ fn test_exec_iter() {
                 AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
                 3,
             )),
-            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
+            stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
+            stack_depth: 17,
             fmp: next_fmp,
             memory: vec![
                 (1_u64, slice_to_word(&[13, 14, 15, 16])),
@@ -206,7 +222,8 @@ fn test_exec_iter() {
                 AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
                 4,
             )),
-            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
+            stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
+            stack_depth: 16,
             fmp: next_fmp,
             memory: vec![
                 (1_u64, slice_to_word(&[13, 14, 15, 16])),
@@ -220,6 +237,32 @@ fn test_exec_iter() {
     }
 }
 
+#[test]
+fn test_exec_iter_stack_depth() {
+    // the overflow table row added in the root context is not a part of the callee's stack
+    let source = "proc.foo push.3 drop end begin push.2 call.foo drop end";
+    let init_stack: Vec<u64> = (1..=16).collect();
+    let test = build_debug_test!(source, &init_stack);
+    let states: Vec<VmState> = test.execute_iter().map(|state| state.unwrap()).collect();
+
+    let callee_state = states
+        .iter()
+        .find(|state| {
+            state.ctx != ContextId::root() && state.op == Some(Operation::Push(Felt::new(3)))
+        })
+        .unwrap();
+    assert_eq!(callee_state.stack_depth, 17);
+    assert_eq!(callee_state.stack.len(), 18);
+    assert_eq!(callee_state.context_stack()[..2], [3, 2].to_elements());
+    assert_eq!(callee_state.overflow(), [2, 1].to_elements());
+
+    // once the call returns, the overflow table row is accessible again
+    let last_state = states.last().unwrap();
+    assert_eq!(last_state.stack_depth, 16);
+    assert_eq!(last_state.context_stack(), last_state.stack);
+    assert!(last_state.overflow().is_empty());
+}
+
 // HELPER FUNCTIONS
 // =================================================================
 fn slice_to_word(values: &[i32]) -> [Felt; 4] {
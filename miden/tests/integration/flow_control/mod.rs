@@ -1,8 +1,15 @@
 use assembly::{Assembler, AssemblyContext, LibraryPath};
-use miden_vm::ModuleAst;
+use miden_vm::{
+    execute_recoverable, execute_with_token, prove_with_token, CancellationToken, ExecutionOutcome,
+    MastForest, ModuleAst, Operation,
+};
 use processor::ExecutionError;
+use std::{thread, time::Duration};
 use stdlib::StdLibrary;
-use test_utils::{build_test, AdviceInputs, StackInputs, Test, TestError};
+use test_utils::{
+    build_test, prove, verify, AdviceInputs, DefaultHost, ExecutionOptions, Felt,
+    MemAdviceProvider, ProgramInfo, ProvingOptions, StackInputs, Test, TestError, ZERO,
+};
 
 // SIMPLE FLOW CONTROL TESTS
 // ================================================================================================
@@ -69,6 +76,30 @@ fn counter_controlled_loop() {
     test.expect_stack(&[1024]);
 }
 
+#[test]
+fn bounded_loop() {
+    // counts down from the value at the top of the stack, executing at most 10 iterations
+    let source = "
+        begin
+            dup neq.0
+            repeat.dyn.max=10
+                sub.1 dup neq.0
+            end
+        end";
+
+    // --- skipping the loop ----------------------------------------------------------------------
+    let test = build_test!(source, &[0]);
+    test.expect_stack(&[0]);
+
+    // --- executing the loop up to the bound -----------------------------------------------------
+    let test = build_test!(source, &[10]);
+    test.expect_stack(&[0]);
+
+    // --- exceeding the bound --------------------------------------------------------------------
+    let test = build_test!(source, &[11]);
+    assert!(matches!(test.execute(), Err(ExecutionError::FailedAssertion { .. })));
+}
+
 // NESTED CONTROL FLOW
 // ================================================================================================
 
@@ -442,3 +473,116 @@ fn procref() {
 
     test.prove_and_verify(vec![], false);
 }
+
+// MAST FORESTS
+// ================================================================================================
+
+#[test]
+fn mast_forest_program() {
+    // programs built directly from MAST nodes can be proven and verified as any other program
+    let mut forest = MastForest::new();
+    let push = forest.add_span(vec![Operation::Push(Felt::new(3))]).unwrap();
+    let double = forest.add_span(vec![Operation::Dup0, Operation::Add]).unwrap();
+    let call = forest.add_call(double).unwrap();
+    let root = forest.add_join(push, call).unwrap();
+    let program = Assembler::default().compile_mast_forest(&forest, root).unwrap();
+
+    let host = DefaultHost::new(MemAdviceProvider::from(AdviceInputs::default()));
+    let (outputs, proof) =
+        prove(&program, StackInputs::default(), host, ProvingOptions::default()).unwrap();
+    assert_eq!(Felt::new(6), outputs.stack()[0]);
+
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, StackInputs::default(), outputs, proof).is_ok());
+}
+
+// EXECUTION CONTROL
+// ================================================================================================
+
+#[test]
+fn recoverable_execution() {
+    let source = "begin push.1 add adv_push.1 assert end";
+    let test = build_test!(source, &[2]);
+    let program = test.compile().unwrap();
+
+    // the same host is reused across executions, and each execution consumes one advice value
+    let advice_inputs = AdviceInputs::default().with_stack_values([0, 1]).unwrap();
+    let mut host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    let mut execute = || {
+        let stack_inputs = test.stack_inputs.clone();
+        execute_recoverable(&program, stack_inputs, &mut host, ExecutionOptions::default()).unwrap()
+    };
+
+    // a failed assertion terminates execution with the state at the failing cycle
+    let ExecutionOutcome::Halted(state) = execute() else {
+        panic!("expected execution to halt");
+    };
+    assert!(matches!(state.error, ExecutionError::FailedAssertion { .. }));
+    assert_eq!(state.stack[..2], [ZERO, Felt::new(3)]);
+
+    // valid advice results in the program being executed to completion
+    assert!(execute().is_completed());
+
+    // advice stack underflow terminates execution in the same way
+    let ExecutionOutcome::Halted(state) = execute() else {
+        panic!("expected execution to halt");
+    };
+    assert!(matches!(state.error, ExecutionError::AdviceStackReadFailed(_)));
+    assert_eq!(state.stack[0], Felt::new(3));
+}
+
+#[test]
+fn cancelled_execution() {
+    // the program never terminates on its own
+    let test = build_test!("begin push.1 while.true push.1 end end");
+    let program = test.compile().unwrap();
+    let host = || DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+
+    // execution is aborted once the token is cancelled from another thread
+    let token = CancellationToken::new();
+    let handle = thread::spawn({
+        let token = token.clone();
+        move || {
+            thread::sleep(Duration::from_millis(10));
+            token.cancel();
+        }
+    });
+    let options = ExecutionOptions::default();
+    let result =
+        execute_with_token(&program, test.stack_inputs.clone(), host(), options, token.clone());
+    assert!(matches!(result, Err(ExecutionError::Cancelled)));
+    handle.join().unwrap();
+
+    // a job with an already cancelled token is aborted before proof generation starts
+    let program = build_test!("begin push.1 push.2 add end").compile().unwrap();
+    let options = ProvingOptions::default();
+    let result = prove_with_token(&program, test.stack_inputs.clone(), host(), options, token);
+    assert!(matches!(result, Err(ExecutionError::Cancelled)));
+}
+
+#[test]
+fn expected_program_hash() {
+    let test = build_test!("begin push.1 push.2 add end");
+    let program = test.compile().unwrap();
+    let stale_program = build_test!("begin push.1 push.3 add end").compile().unwrap();
+    let host = || DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let options = ExecutionOptions::default().with_expected_program_hash(program.hash());
+
+    // a program with the expected hash is executed and proven as usual
+    let trace = miden_vm::execute(&program, test.stack_inputs.clone(), host(), options).unwrap();
+    assert_eq!(Felt::new(3), trace.stack_outputs().stack()[0]);
+    let proving_options = ProvingOptions::default().with_execution_options(options);
+    assert!(prove(&program, test.stack_inputs.clone(), host(), proving_options.clone()).is_ok());
+
+    // a program with a different hash is rejected by both executing and proving
+    let result = miden_vm::execute(&stale_program, test.stack_inputs.clone(), host(), options);
+    assert_eq!(
+        result.err(),
+        Some(ExecutionError::ProgramHashMismatch {
+            expected: program.hash(),
+            actual: stale_program.hash(),
+        })
+    );
+    let result = prove(&stale_program, test.stack_inputs.clone(), host(), proving_options);
+    assert!(matches!(result, Err(ExecutionError::ProgramHashMismatch { .. })));
+}
@@ -1,7 +1,8 @@
-use test_utils::build_test;
+use test_utils::{build_test, Felt};
 
 mod air;
 mod cli;
+mod determinism;
 mod exec_iters;
 mod flow_control;
 mod operations;
@@ -19,3 +20,18 @@ fn multi_output_program() {
     let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
     test.prove_and_verify(vec![1, 2, 3], false);
 }
+
+#[test]
+fn prelude_round_trip() {
+    // compiling, proving, and verifying a program requires nothing beyond the prelude
+    use miden_vm::prelude::*;
+
+    let program = Assembler::default().compile("begin push.3 push.5 add end").unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::from(AdviceInputs::default()));
+    let (outputs, proof) =
+        prove(&program, StackInputs::default(), host, ProvingOptions::default()).unwrap();
+    assert_eq!(Felt::new(8), outputs.stack()[0]);
+
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, StackInputs::default(), outputs, proof).is_ok());
+}
@@ -1,8 +1,11 @@
+use miden_vm::{Assembler, Digest};
+use processor::{AdviceInjector, ExecutionError};
 use test_utils::{
     build_test,
-    crypto::{MerkleStore, RpoDigest},
+    crypto::{MerkleStore, Rpo256, RpoDigest},
     rand::rand_value,
-    Felt,
+    AdviceInputs, DefaultHost, ExecutionOptions, Felt, MemAdviceProvider, StackInputs, TestError,
+    ONE,
 };
 
 // ADVICE INJECTION
@@ -122,6 +125,68 @@ fn advice_push_u64div_conditional_execution() {
     test.expect_stack(&[0, 0, 0, 0, 0, 4, 0, 8]);
 }
 
+#[test]
+fn advice_push_u256div() {
+    // push a/b onto the advice stack, drop a and b, and then move the results onto the operand
+    // stack.
+    let source = "begin adv.push_u256div dropw dropw dropw dropw adv_push.16 end";
+
+    // get a random 128-bit dividend and a random 64-bit divisor; the upper limbs of both values
+    // are zeros
+    let a = ((rand_value::<u64>() as u128) << 64) | rand_value::<u64>() as u128;
+    let b = rand_value::<u64>() as u128;
+    let (q, r) = (a / b, a % b);
+
+    let a_limbs = u128_to_u256_limbs(a);
+    let b_limbs = u128_to_u256_limbs(b);
+    let inputs = a_limbs.iter().chain(b_limbs.iter()).copied().collect::<Vec<_>>();
+
+    let expected = u128_to_u256_limbs(r)
+        .into_iter()
+        .rev()
+        .chain(u128_to_u256_limbs(q).into_iter().rev())
+        .collect::<Vec<_>>();
+
+    let test = build_test!(source, &inputs);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn advice_push_u256modinv() {
+    // push the inverse of a modulo m onto the advice stack, drop a and m, and then move the result
+    // onto the operand stack.
+    let source = "begin adv.push_u256modinv dropw dropw dropw dropw adv_push.8 end";
+
+    // 2 * 2^63 = 2^64 = 1 (mod 2^64 - 1); the upper limbs of all values are zeros
+    let m = u64::MAX as u128;
+    let inputs = u128_to_u256_limbs(2)
+        .into_iter()
+        .chain(u128_to_u256_limbs(m))
+        .collect::<Vec<_>>();
+    let expected = u128_to_u256_limbs(1 << 63).into_iter().rev().collect::<Vec<_>>();
+
+    let test = build_test!(source, &inputs);
+    test.expect_stack(&expected);
+
+    // 3 has no inverse modulo 2^64 - 1, so zero is pushed
+    let inputs = u128_to_u256_limbs(3)
+        .into_iter()
+        .chain(u128_to_u256_limbs(m))
+        .collect::<Vec<_>>();
+    let test = build_test!(source, &inputs);
+    test.expect_stack(&[0; 8]);
+}
+
+#[test]
+fn advice_push_smtget_unsupported() {
+    // unsupported injectors terminate the execution with an error instead of a panic
+    let source = "begin push.1 adv.push_smtget drop end";
+    let test = build_test!(source, &[]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::UnsupportedAdviceInjector(
+        AdviceInjector::SmtGet,
+    )));
+}
+
 #[test]
 fn advice_insert_mem() {
     let source = "begin
@@ -329,3 +394,42 @@ fn advice_insert_hdword() {
     let test = build_test!(source, &stack_inputs);
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
+
+// ADVICE COMMITMENT
+// ================================================================================================
+
+#[test]
+fn advice_commitment_map_reads() {
+    // keys of the advice map entries read during execution are committed to before the values
+    let source = "
+        begin
+            push.4.3.2.1
+            adv.push_mapval
+            dropw
+            adv_push.2
+        end";
+
+    let key = [Felt::new(4), Felt::new(3), Felt::new(2), ONE];
+    let values = vec![Felt::new(5), Felt::new(6)];
+    let advice_inputs = AdviceInputs::default().with_map([(Digest::from(key), values.clone())]);
+    let program = Assembler::default().compile(source).unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    let options = ExecutionOptions::default().with_advice_commitment();
+    let trace = miden_vm::execute(&program, StackInputs::default(), host, options).unwrap();
+
+    let expected = Rpo256::hash_elements(&[key.to_vec(), values].concat());
+    assert_eq!(Some(expected), trace.advice_commitment());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the 8 32-bit limbs of a 256-bit integer with the specified value, with the least
+/// significant limb first.
+fn u128_to_u256_limbs(value: u128) -> [u64; 8] {
+    let mut limbs = [0; 8];
+    for (i, limb) in limbs.iter_mut().take(4).enumerate() {
+        *limb = (value >> (32 * i)) as u32 as u64;
+    }
+    limbs
+}
@@ -1,6 +1,10 @@
 use super::TestHost;
 use assembly::Assembler;
-use processor::ExecutionOptions;
+use processor::{ContextId, ExecutionError, ExecutionOptions, ProcessState, ProcessStateSnapshot};
+use vm_core::{
+    utils::{Deserializable, Serializable},
+    Felt,
+};
 
 #[test]
 fn test_event_handling() {
@@ -22,6 +26,88 @@ fn test_event_handling() {
     assert_eq!(host.event_handler, expected);
 }
 
+#[test]
+fn test_event_error() {
+    let source = "\
+    begin
+        push.1
+        emit.1
+        push.2
+        emit.2
+        push.3
+    end";
+
+    // compile and execute program with a host which fails to handle the second event
+    let program = Assembler::default().compile(source).unwrap();
+    let mut host = TestHost {
+        failing_event: Some(2),
+        ..TestHost::default()
+    };
+    let result = processor::execute(&program, Default::default(), &mut host, Default::default());
+    assert_eq!(host.event_handler, vec![1]);
+
+    // the host error must be reported together with the event ID and the clock cycle
+    match result {
+        Err(ExecutionError::EventError {
+            event_id,
+            clk,
+            error,
+            context,
+        }) => {
+            assert_eq!(event_id, 2);
+            assert_eq!(clk, 4);
+            assert_eq!(error, ExecutionError::AdviceStackReadFailed(clk).to_string());
+            assert!(context.is_some());
+        }
+        Err(err) => panic!("unexpected error: {err}"),
+        Ok(_) => panic!("execution should have failed"),
+    }
+}
+
+#[test]
+fn test_process_state_snapshot() {
+    let source = "\
+    begin
+        push.1.2.3.4
+        mem_storew.8
+        emit.1
+        dropw
+        push.5
+        emit.2
+    end";
+
+    // compile and execute program taking a snapshot of the process state on every event
+    let program = Assembler::default().compile(source).unwrap();
+    let mut host = TestHost::default();
+    processor::execute(&program, Default::default(), &mut host, Default::default()).unwrap();
+
+    let snapshots = host.event_snapshots;
+    assert_eq!(snapshots.len(), 2);
+
+    let first = &snapshots[0];
+    let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    assert_eq!(first.get_stack_word(0), word);
+    assert_eq!(first.get_mem_value(ContextId::root(), 8), Some(word));
+    assert_eq!(first.get_mem_written_addresses(ContextId::root()), vec![8]);
+
+    // snapshots are not affected by the subsequent execution of the program
+    let second = &snapshots[1];
+    assert!(second.clk() > first.clk());
+    assert_eq!(first.get_stack_item(0), Felt::new(4));
+    assert_eq!(second.get_stack_item(0), Felt::new(5));
+    assert_eq!(second.get_mem_contexts(), vec![ContextId::root()]);
+    assert_eq!(second.get_mem_value(ContextId::root(), 8), Some(word));
+
+    // snapshots can be sent to another thread and serialized there
+    let handle = std::thread::spawn(move || {
+        snapshots.iter().map(|snapshot| snapshot.to_bytes()).collect::<Vec<_>>()
+    });
+    let bytes = handle.join().unwrap();
+    let first = ProcessStateSnapshot::read_from_bytes(&bytes[0]).unwrap();
+    assert_eq!(first.get_stack_word(0), word);
+    assert_eq!(first.get_mem_state(ContextId::root()), vec![(8, word)]);
+}
+
 #[test]
 fn test_trace_handling() {
     let source = "\
@@ -52,3 +138,73 @@ fn test_trace_handling() {
     let expected = vec![1, 2];
     assert_eq!(host.trace_handler, expected);
 }
+
+#[test]
+fn test_cycle_interval_handling() {
+    let source = "\
+    begin
+        repeat.20
+            push.1
+            drop
+        end
+    end";
+
+    // compile program
+    let program = Assembler::default().compile(source).unwrap();
+    let mut host = TestHost::default();
+
+    // execute program without the cycle interval set
+    processor::execute(&program, Default::default(), &mut host, Default::default()).unwrap();
+    let expected = Vec::<u32>::new();
+    assert_eq!(host.cycle_handler, expected);
+
+    // execute program with the cycle interval set
+    let options = ExecutionOptions::default().with_cycle_interval(16);
+    processor::execute(&program, Default::default(), &mut host, options).unwrap();
+    let expected = vec![16, 32, 48];
+    assert_eq!(host.cycle_handler, expected);
+
+    // execution is terminated once the handler returns an error
+    let mut host = TestHost {
+        cycle_budget: 20,
+        ..TestHost::default()
+    };
+    let result = processor::execute(&program, Default::default(), &mut host, options);
+    assert!(matches!(result, Err(ExecutionError::Cancelled)));
+    assert_eq!(host.cycle_handler, vec![16]);
+}
+
+#[test]
+fn test_assert_failed_handling() {
+    let source = "\
+    begin
+        push.0
+        assert.err=5
+        push.7
+    end";
+
+    // compile program
+    let program = Assembler::default().compile(source).unwrap();
+    let mut host = TestHost {
+        ignore_failed_asserts: true,
+        ..TestHost::default()
+    };
+
+    // outside of debug mode the host cannot recover from a failed assertion
+    let result = processor::execute(&program, Default::default(), &mut host, Default::default());
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { err_code: 5, .. })));
+    assert_eq!(host.assert_handler, Vec::<u32>::new());
+
+    // in debug mode the host can choose to continue the execution
+    let options = ExecutionOptions::default().with_debugging();
+    let trace = processor::execute(&program, Default::default(), &mut host, options)
+        .expect("execution should continue after the failed assertion");
+    assert_eq!(trace.stack_outputs().stack()[0].as_int(), 7);
+    assert_eq!(host.assert_handler, vec![5]);
+
+    // or to abort it
+    let mut host = TestHost::default();
+    let result = processor::execute(&program, Default::default(), &mut host, options);
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { err_code: 5, .. })));
+    assert_eq!(host.assert_handler, vec![5]);
+}
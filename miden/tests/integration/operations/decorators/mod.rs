@@ -1,6 +1,6 @@
 use processor::{
-    AdviceExtractor, AdviceProvider, ExecutionError, Host, HostResponse, MemAdviceProvider,
-    ProcessState,
+    AdviceExtractor, AdviceProvider, AssertionAction, ExecutionError, Host, HostResponse,
+    MemAdviceProvider, ProcessState, ProcessStateSnapshot,
 };
 use vm_core::AdviceInjector;
 
@@ -13,7 +13,13 @@ mod events;
 pub struct TestHost<A> {
     pub adv_provider: A,
     pub event_handler: Vec<u32>,
+    pub event_snapshots: Vec<ProcessStateSnapshot>,
+    pub failing_event: Option<u32>,
     pub trace_handler: Vec<u32>,
+    pub cycle_handler: Vec<u32>,
+    pub cycle_budget: u32,
+    pub assert_handler: Vec<u32>,
+    pub ignore_failed_asserts: bool,
 }
 
 impl Default for TestHost<MemAdviceProvider> {
@@ -21,7 +27,13 @@ impl Default for TestHost<MemAdviceProvider> {
         Self {
             adv_provider: MemAdviceProvider::default(),
             event_handler: Vec::new(),
+            event_snapshots: Vec::new(),
+            failing_event: None,
             trace_handler: Vec::new(),
+            cycle_handler: Vec::new(),
+            cycle_budget: u32::MAX,
+            assert_handler: Vec::new(),
+            ignore_failed_asserts: false,
         }
     }
 }
@@ -45,10 +57,14 @@ impl<A: AdviceProvider> Host for TestHost<A> {
 
     fn on_event<S: ProcessState>(
         &mut self,
-        _process: &S,
+        process: &S,
         event_id: u32,
     ) -> Result<HostResponse, ExecutionError> {
+        if self.failing_event == Some(event_id) {
+            return Err(ExecutionError::AdviceStackReadFailed(process.clk()));
+        }
         self.event_handler.push(event_id);
+        self.event_snapshots.push(process.snapshot());
         Ok(HostResponse::None)
     }
 
@@ -60,4 +76,28 @@ impl<A: AdviceProvider> Host for TestHost<A> {
         self.trace_handler.push(trace_id);
         Ok(HostResponse::None)
     }
+
+    fn on_cycle_interval<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        if process.clk() > self.cycle_budget {
+            return Err(ExecutionError::Cancelled);
+        }
+        self.cycle_handler.push(process.clk());
+        Ok(HostResponse::None)
+    }
+
+    fn on_assert_failed_in_debug<S: ProcessState>(
+        &mut self,
+        _process: &S,
+        err_code: u32,
+    ) -> AssertionAction {
+        self.assert_handler.push(err_code);
+        if self.ignore_failed_asserts {
+            AssertionAction::Continue
+        } else {
+            AssertionAction::Abort
+        }
+    }
 }
@@ -205,7 +205,10 @@ fn div_fail() {
 
     // --- test divide by zero --------------------------------------------------------------------
     let test = build_op_test!(asm_op, &[1, 0]);
-    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero(1)));
+    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero {
+        clk: 1,
+        context: None,
+    }));
 }
 
 #[test]
@@ -262,7 +265,10 @@ fn inv_fail() {
 
     // --- test no inv on 0 -----------------------------------------------------------------------
     let test = build_op_test!(asm_op, &[0]);
-    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero(1)));
+    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero {
+        clk: 1,
+        context: None,
+    }));
 
     let asm_op = "inv.1";
 
@@ -296,6 +302,7 @@ fn pow2_fail() {
             clk: 16,
             err_code: 0,
             err_msg: None,
+            context: None,
         },
     ));
 }
@@ -328,6 +335,7 @@ fn exp_bits_length_fail() {
             clk: 18,
             err_code: 0,
             err_msg: None,
+            context: None,
         },
     ));
 
@@ -1,6 +1,11 @@
 use super::{build_op_test, build_test, TestError};
+use miden_vm::{prove_trace, StreamingAdviceProvider};
 use processor::ExecutionError;
 use processor::ExecutionError::AdviceStackReadFailed;
+use test_utils::{
+    crypto::Rpo256, verify, DefaultHost, ExecutionOptions, MemAdviceProvider, ProgramInfo,
+    ProvingOptions,
+};
 use vm_core::{chiplets::hasher::apply_permutation, utils::ToElements, Felt};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
@@ -114,3 +119,86 @@ fn adv_pipe_with_hperm() {
     let test = build_test!(source, &[], &advice_stack);
     test.expect_stack(&final_stack);
 }
+
+// ADVICE COMMITMENT
+// ================================================================================================
+
+#[test]
+fn advice_commitment() {
+    // consume advice values via all instructions which read from the advice stack
+    let source = "
+        begin
+            adv_push.2 drop drop
+            padw adv_loadw dropw
+            push.100 padw padw padw adv_pipe dropw dropw dropw drop
+        end";
+
+    let advice_stack: Vec<u64> = (1..=14).collect();
+    let test = build_test!(source, &[], &advice_stack);
+    let program = test.compile().unwrap();
+
+    let execute = |options| {
+        let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+        miden_vm::execute(&program, test.stack_inputs.clone(), host, options).unwrap()
+    };
+
+    // the commitment is not computed by default
+    assert_eq!(None, execute(ExecutionOptions::default()).advice_commitment());
+
+    // values are committed to in the order in which they were consumed
+    let expected =
+        Rpo256::hash_elements(&advice_stack.into_iter().map(Felt::new).collect::<Vec<_>>());
+    let trace = execute(ExecutionOptions::default().with_advice_commitment());
+    assert_eq!(Some(expected), trace.advice_commitment());
+
+    // the commitment is not a part of the proof, since the AIR does not constrain it
+    let stack_outputs = trace.stack_outputs().clone();
+    let proof = prove_trace(trace, test.stack_inputs.clone(), ProvingOptions::default()).unwrap();
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, test.stack_inputs.clone(), stack_outputs, proof).is_ok());
+}
+
+// STREAMING ADVICE
+// ================================================================================================
+
+#[test]
+fn streaming_advice() {
+    let source = "
+        begin
+            adv_push.1
+            padw adv_loadw
+            push.100 padw padw padw adv_pipe dropw dropw dropw drop
+            padw adv_loadw
+        end";
+
+    // the advice stack is followed by 4 words supplied by the stream
+    let advice_stack: Vec<u64> = (1..=17).collect();
+    let words = advice_stack[1..]
+        .chunks(4)
+        .map(|word| [word[0], word[1], word[2], word[3]].map(Felt::new))
+        .collect::<Vec<_>>();
+    let test = build_test!(source, &[], &advice_stack[..1]);
+    let program = test.compile().unwrap();
+
+    // the program is executed in the same way as with all advice provided upfront
+    let expected = build_test!(source, &[], &advice_stack).execute().unwrap();
+    for chunk_size in [1, 2, 8] {
+        let provider = StreamingAdviceProvider::new(
+            test.advice_inputs.clone(),
+            words.clone().into_iter(),
+            chunk_size,
+        );
+        let host = DefaultHost::new(provider);
+        let trace =
+            miden_vm::execute(&program, test.stack_inputs.clone(), host, Default::default())
+                .unwrap();
+        assert_eq!(expected.stack_outputs(), trace.stack_outputs());
+    }
+
+    // reading past the end of the stream fails
+    let provider =
+        StreamingAdviceProvider::new(test.advice_inputs.clone(), words[..3].iter().copied(), 2);
+    let host = DefaultHost::new(provider);
+    let result = miden_vm::execute(&program, test.stack_inputs.clone(), host, Default::default());
+    assert!(matches!(result, Err(ExecutionError::AdviceStackReadFailed(_))));
+}
@@ -1,4 +1,6 @@
 use super::build_test;
+use processor::{ExecutionError, ExecutionOptions};
+use test_utils::{DefaultHost, Felt, MemAdviceProvider, StackInputs};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
 // ================================================================================================
@@ -307,3 +309,59 @@ fn free_memory_pointer() {
     let test = build_test!(source, &inputs);
     test.expect_stack(&[7, 6, 5, 4, 1]);
 }
+
+#[test]
+fn local_canaries() {
+    let execute = |source: &str, options: ExecutionOptions| {
+        let program = build_test!(source).compile().unwrap();
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        let stack_inputs = StackInputs::try_from_ints([3]).unwrap();
+        processor::execute(&program, stack_inputs, host, options)
+    };
+    let options = ExecutionOptions::default().with_local_canaries();
+
+    // --- correct accesses of nested frames are not affected by canaries -------------------------
+    let source = "
+        proc.bar.2
+            loc_store.0
+            loc_store.1
+            loc_load.0
+            loc_load.1
+            add
+        end
+        proc.foo.1
+            loc_store.0
+            push.1.2
+            exec.bar
+            loc_load.0
+            add
+        end
+        begin
+            exec.foo
+        end";
+    let trace = execute(source, options).unwrap();
+    assert_eq!(trace.stack_outputs().stack()[0], Felt::new(6));
+
+    // --- writes just outside of a frame are detected when the frame is released -----------------
+    let fmp_min = 2_u32.pow(30);
+    for (write, addr) in [("locaddr.0 sub.1", fmp_min + 1), ("locaddr.0 add.1", fmp_min + 3)] {
+        let source = format!(
+            "
+            proc.foo.1
+                push.7 {write} mem_store
+            end
+            begin
+                exec.foo
+            end"
+        );
+
+        // without canaries, the write silently succeeds
+        assert!(execute(&source, ExecutionOptions::default()).is_ok());
+
+        let err = execute(&source, options).err().unwrap();
+        assert!(
+            matches!(err, ExecutionError::LocalCanaryOverwritten { addr: a, .. } if a == addr),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -1,4 +1,12 @@
-use super::{apply_permutation, build_op_test, build_test, Felt, ToElements};
+use super::{apply_permutation, build_op_test, build_test, Felt, TestError, ToElements};
+use assembly::{ast::DataSegment, Assembler};
+use miden_vm::{execute_iter, execute_with_memory, prove_trace, MemoryInputs};
+use processor::ExecutionError;
+use test_utils::crypto::MerkleStore;
+use test_utils::{
+    verify, ContextId, DefaultHost, ExecutionOptions, ProgramInfo, ProvingOptions, StackInputs,
+    ONE, ZERO,
+};
 
 // LOADING SINGLE ELEMENT ONTO THE STACK (MLOAD)
 // ================================================================================================
@@ -157,6 +165,79 @@ fn mem_stream_with_hperm() {
     test.expect_stack(&final_stack);
 }
 
+// MEMORY PROTECTION
+// ================================================================================================
+
+#[test]
+fn mem_protect() {
+    // --- protected memory can still be read ------------------------------------------------------
+    let source = "
+        begin
+            push.1.2.3.4.100 mem_storew dropw
+            push.101.100 mem_protect
+            push.100 mem_loadw
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[4, 3, 2, 1]);
+
+    // --- addresses outside of the protected range can be written to -----------------------------
+    let source = "
+        begin
+            push.102.100 mem_protect
+            push.7.99 mem_store push.8.102 mem_store
+            mem_load.99 mem_load.102
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[8, 7]);
+
+    // --- writes to protected addresses fail ---------------------------------------------------
+    for write in ["push.1.101 mem_store", "push.101 mem_storew", "push.101 mem_storew.101"] {
+        let source = format!("begin push.102.100 mem_protect {write} end");
+        let test = build_test!(source, &[]);
+        test.expect_error(TestError::ExecutionError(ExecutionError::ReadOnlyMemoryWrite {
+            addr: 101,
+            context: None,
+        }));
+    }
+
+    // --- adv_pipe fails if either of the written addresses is protected -------------------------
+    let source = "
+        begin
+            push.101.100 mem_protect
+            push.99 padw padw padw adv_pipe
+        end";
+
+    let test = build_test!(source, &[], &[1, 2, 3, 4, 5, 6, 7, 8]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::ReadOnlyMemoryWrite {
+        addr: 100,
+        context: None,
+    }));
+
+    // --- protection applies only to the context in which it was set -----------------------------
+    let source = "
+        proc.write
+            push.5.100 mem_store
+        end
+
+        begin
+            push.101.100 mem_protect
+            call.write
+            mem_load.100
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[0]);
+
+    // --- the start of the range must not exceed its end -----------------------------------------
+    let test = build_test!("begin push.100.101 mem_protect end", &[]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::InvalidMemoryRange {
+        start_addr: 101,
+        end_addr: 100,
+    }));
+}
+
 // PAIRED OPERATIONS
 // ================================================================================================
 
@@ -213,3 +294,102 @@ fn read_after_write() {
     let test = build_op_test!("mem_storew.0 dropw mem_loadw.0", &[1, 2, 3, 4, 5, 6, 7, 8]);
     test.expect_stack(&[8, 7, 6, 5]);
 }
+
+// DATA SEGMENTS
+// ================================================================================================
+
+#[test]
+fn data_segment_to_memory() {
+    let write_ptr = 100;
+
+    // --- even number of words -------------------------------------------------------------------
+    let values = (1..=16).collect::<Vec<u64>>();
+    let source = "
+        data.table
+            1.2.3.4 5.6.7.8
+            9.10.11.12 13.14.15.16
+        end
+        begin
+            exec.table
+        end";
+    let data = DataSegment::new(values.iter().map(|&value| Felt::new(value)).collect()).unwrap();
+    let adv_map = [(data.commitment(), data.values().to_vec())];
+    let test = build_test!(source, &[write_ptr], [], MerkleStore::default(), adv_map);
+    test.expect_stack_and_memory(&[], write_ptr as u32, &values);
+
+    // --- odd number of words --------------------------------------------------------------------
+    let values = (1..=12).collect::<Vec<u64>>();
+    let source = "
+        data.table
+            1.2.3.4 5.6.7.8 9.10.11.12
+        end
+        begin
+            exec.table
+        end";
+    let data = DataSegment::new(values.iter().map(|&value| Felt::new(value)).collect()).unwrap();
+    let adv_map = [(data.commitment(), data.values().to_vec())];
+    let test = build_test!(source, &[write_ptr], [], MerkleStore::default(), adv_map);
+    test.expect_stack_and_memory(&[], write_ptr as u32, &values);
+
+    // --- values which do not match the commitment are rejected ----------------------------------
+    let adv_map = [(data.commitment(), vec![Felt::new(0); 12])];
+    let test = build_test!(source, &[write_ptr], [], MerkleStore::default(), adv_map);
+    assert!(test.execute().is_err());
+}
+
+// MEMORY INPUTS
+// ================================================================================================
+
+#[test]
+fn memory_inputs() {
+    // reads words from the memory of the root context, and from the memory of the context
+    // created by `call`
+    let source = "
+        proc.foo
+            push.7 mem_load movup.15 drop
+        end
+
+        begin
+            push.100 mem_load push.101 mem_load call.foo
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+
+    // the ID of the context created by `call` depends on the clock cycle of the call
+    let ctx = execute_iter(&program, StackInputs::default(), DefaultHost::default())
+        .map(|state| state.unwrap().ctx)
+        .max()
+        .unwrap();
+
+    let memory_inputs = MemoryInputs::new()
+        .with_words(
+            ContextId::root(),
+            100,
+            [[ONE, ZERO, ZERO, ZERO], [Felt::new(2), ZERO, ZERO, ZERO]],
+        )
+        .with_words(ctx, 7, [[Felt::new(3), ZERO, ZERO, ZERO]]);
+    let execute = |options| {
+        execute_with_memory(
+            &program,
+            StackInputs::default(),
+            memory_inputs.clone(),
+            DefaultHost::default(),
+            options,
+        )
+        .unwrap()
+    };
+
+    // the commitment is not computed by default
+    let trace = execute(ExecutionOptions::default());
+    assert_eq!(None, trace.memory_commitment());
+    assert_eq!(&[Felt::new(3), Felt::new(2), ONE], trace.stack_outputs().stack_truncated(3));
+
+    let trace = execute(ExecutionOptions::default().with_memory_commitment());
+    assert_eq!(Some(memory_inputs.commitment()), trace.memory_commitment());
+
+    // the initial memory writes are consistent with the constraints of the memory chiplet; the
+    // commitment is not a part of the proof, since these writes are not on the chiplets bus
+    let outputs = trace.stack_outputs().clone();
+    let proof = prove_trace(trace, StackInputs::default(), ProvingOptions::default()).unwrap();
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, StackInputs::default(), outputs, proof).is_ok());
+}
@@ -1,5 +1,5 @@
 use processor::ExecutionError;
-use test_utils::{build_op_test, TestError};
+use test_utils::{build_debug_test, build_op_test, build_test, TestError};
 
 // SYSTEM OPS ASSERTIONS - MANUAL TESTS
 // ================================================================================================
@@ -25,6 +25,7 @@ fn assert_with_code() {
         clk: 1,
         err_code: 123,
         err_msg: None,
+        context: None,
     }));
 }
 
@@ -37,9 +38,41 @@ fn assert_fail() {
         clk: 1,
         err_code: 0,
         err_msg: None,
+        context: None,
     }));
 }
 
+#[test]
+fn assert_fail_context() {
+    let source = "
+        proc.foo
+            push.2
+            assert
+        end
+
+        begin
+            swap drop
+            exec.foo
+        end";
+
+    // the location of the failing operation is attached to the error
+    let test = build_test!(source, &[1, 2]);
+    let program = test.compile().unwrap();
+    let error = test.execute().err().unwrap();
+    let context = error.context().unwrap();
+    assert_eq!(program.hash(), context.mast_root());
+    assert_eq!(3, context.op_idx());
+    assert!(context.asm_op().is_none());
+
+    // in debug mode, the originating assembly instruction is attached to the error as well
+    let test = build_debug_test!(source, &[1, 2]);
+    let error = test.execute().err().unwrap();
+    let asm_op = error.context().unwrap().asm_op().unwrap();
+    assert_eq!("assert", asm_op.op());
+    assert_eq!("foo", asm_op.context_name());
+    assert!(error.to_string().ends_with("instruction `assert` in `foo`)"));
+}
+
 #[test]
 fn assert_eq() {
     let asm_op = "assert_eq";
@@ -60,6 +93,7 @@ fn assert_eq_fail() {
         clk: 2,
         err_code: 0,
         err_msg: None,
+        context: None,
     }));
 
     let test = build_op_test!(asm_op, &[1, 4]);
@@ -67,5 +101,6 @@ fn assert_eq_fail() {
         clk: 2,
         err_code: 0,
         err_msg: None,
+        context: None,
     }));
 }
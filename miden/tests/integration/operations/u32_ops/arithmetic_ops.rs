@@ -468,7 +468,10 @@ fn u32div_fail() {
 
     // should fail if b == 0.
     let test = build_op_test!(asm_op, &[1, 0]);
-    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero(1)));
+    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero {
+        clk: 1,
+        context: None,
+    }));
 }
 
 #[test]
@@ -509,7 +512,10 @@ fn u32mod_fail() {
 
     // should fail if b == 0
     let test = build_op_test!(asm_op, &[1, 0]);
-    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero(1)));
+    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero {
+        clk: 1,
+        context: None,
+    }));
 }
 
 #[test]
@@ -555,7 +561,10 @@ fn u32divmod_fail() {
 
     // should fail if b == 0.
     let test = build_op_test!(asm_op, &[1, 0]);
-    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero(1)));
+    test.expect_error(TestError::ExecutionError(ExecutionError::DivideByZero {
+        clk: 1,
+        context: None,
+    }));
 }
 
 // U32 OPERATIONS TESTS - RANDOMIZED - ARITHMETIC OPERATIONS
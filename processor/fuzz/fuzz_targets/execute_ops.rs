@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_processor::fuzz::{check_invariants, ops_from_bytes};
+
+fuzz_target!(|data: &[u8]| {
+    let ops = ops_from_bytes(data);
+    if let Err(violation) = check_invariants(&ops) {
+        panic!("{violation}\noperations: {ops:?}");
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_processor::fuzz::{check_program, program_from_bytes};
+
+fuzz_target!(|data: &[u8]| {
+    let program = program_from_bytes(data);
+    if let Err(violation) = check_program(&program) {
+        panic!("{violation}\nprogram: {program:?}");
+    }
+});
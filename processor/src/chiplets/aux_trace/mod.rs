@@ -339,8 +339,10 @@ fn build_respan_block_request<E: FieldElement<BaseField = Felt>>(
         + alphas[2].mul_base(addr_nxt - ONE)
         + alphas[3].mul_base(ZERO);
 
-    let state = &main_trace.chiplet_hasher_state(row - 2)[CAPACITY_LEN..];
-    let state_nxt = &main_trace.chiplet_hasher_state(row - 1)[CAPACITY_LEN..];
+    // the absorption of the next batch is performed in the last row of the previous hash cycle
+    let hasher_row = addr_to_row_index(addr_nxt - ONE);
+    let state = &main_trace.chiplet_hasher_state(hasher_row)[CAPACITY_LEN..];
+    let state_nxt = &main_trace.chiplet_hasher_state(hasher_row + 1)[CAPACITY_LEN..];
 
     header + build_value(&alphas[8..16], state_nxt) - build_value(&alphas[8..16], state)
 }
@@ -936,7 +938,7 @@ where
 /// Reduces a slice of elements to a single field element in the field specified by E using a slice
 /// of alphas of matching length. This can be used to build the value for a single word or for an
 /// entire [HasherState].
-fn build_value<E: FieldElement<BaseField = Felt>>(alphas: &[E], elements: &[Felt]) -> E {
+fn build_value<E: FieldElement>(alphas: &[E], elements: &[E::BaseField]) -> E {
     assert_eq!(alphas.len(), elements.len());
     let mut value = E::ZERO;
     for (&alpha, &element) in alphas.iter().zip(elements.iter()) {
@@ -66,6 +66,11 @@ impl KernelRom {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns true if any of the kernel procedures has been accessed from the program.
+    pub fn has_accesses(&self) -> bool {
+        self.access_map.values().any(|access_info| access_info.num_accesses > 0)
+    }
+
     /// Returns length of execution trace required to describe kernel ROM.
     pub const fn trace_len(&self) -> usize {
         self.trace_len
@@ -4,6 +4,7 @@ use super::{
 };
 use crate::system::ContextId;
 use alloc::{collections::BTreeMap, vec::Vec};
+use core::ops::Range;
 use miden_air::trace::chiplets::memory::{
     ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, V_COL_RANGE,
 };
@@ -20,6 +21,24 @@ mod tests;
 /// Initial value of every memory cell.
 const INIT_MEM_VALUE: Word = EMPTY_WORD;
 
+// MEMORY ACCESS RECORD
+// ================================================================================================
+
+/// A single access to a memory address recorded by the memory chiplet.
+///
+/// For reads, the old and the new values are the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryAccessRecord {
+    /// Clock cycle at which the access happened.
+    pub clk: u32,
+    /// True if the access was a write, and false if it was a read.
+    pub is_write: bool,
+    /// Word stored at the address before the access.
+    pub old_value: Word,
+    /// Word stored at the address after the access.
+    pub new_value: Word,
+}
+
 // RANDOM ACCESS MEMORY
 // ================================================================================================
 
@@ -37,6 +56,10 @@ const INIT_MEM_VALUE: Word = EMPTY_WORD;
 /// Memory for a a given address is always initialized to zeros. That is, reading from an address
 /// before writing to it will return four ZERO elements.
 ///
+/// Ranges of addresses within a segment can be marked as read-only. The memory controller itself
+/// does not prevent writes to such addresses; it is the responsibility of the caller to check
+/// whether an address is writable via [Memory::is_read_only()] before writing to it.
+///
 /// ## Execution trace
 /// The layout of the memory access trace is shown below.
 ///
@@ -79,6 +102,9 @@ pub struct Memory {
     /// Total number of entries in the trace (across all contexts); tracked separately so that we
     /// don't have to sum up lengths of all address trace vectors for all contexts all the time.
     num_trace_rows: usize,
+
+    /// Ranges of addresses marked as read-only, grouped by their execution context ID.
+    read_only: BTreeMap<ContextId, Vec<Range<u32>>>,
 }
 
 impl Memory {
@@ -110,6 +136,30 @@ impl Memory {
         self.get_value(ctx, addr).unwrap_or(INIT_MEM_VALUE)
     }
 
+    /// Returns all accesses to the specified context/address recorded so far, sorted by clock
+    /// cycle, or an empty vector if the address hasn't been accessed previously.
+    pub fn get_accesses(&self, ctx: ContextId, addr: u32) -> Vec<MemoryAccessRecord> {
+        match self.trace.get(&ctx) {
+            Some(segment) => segment.get_accesses(addr),
+            None => vec![],
+        }
+    }
+
+    /// Returns the clock cycle at which the specified context/address was last written to, or None
+    /// if the address has never been written to.
+    pub fn get_last_write_clk(&self, ctx: ContextId, addr: u32) -> Option<u32> {
+        self.trace.get(&ctx)?.get_last_write_clk(addr)
+    }
+
+    /// Returns true if the specified address in the specified context has been marked as
+    /// read-only.
+    pub fn is_read_only(&self, ctx: ContextId, addr: u32) -> bool {
+        match self.read_only.get(&ctx) {
+            Some(ranges) => ranges.iter().any(|range| range.contains(&addr)),
+            None => false,
+        }
+    }
+
     /// Returns the entire memory state for the specified execution context at the specified cycle.
     /// The state is returned as a vector of (address, value) tuples, and includes addresses which
     /// have been accessed at least once.
@@ -124,6 +174,24 @@ impl Memory {
         }
     }
 
+    /// Returns IDs of all execution contexts whose memory has been accessed at least once, sorted
+    /// in ascending order.
+    pub fn contexts(&self) -> Vec<ContextId> {
+        self.trace.keys().copied().collect()
+    }
+
+    /// Returns addresses in the specified execution context which have been written to at least
+    /// once, sorted in ascending order.
+    ///
+    /// Addresses which have only been read (and thus still hold their initial value) are not
+    /// included.
+    pub fn get_written_addresses(&self, ctx: ContextId) -> Vec<u32> {
+        match self.trace.get(&ctx) {
+            Some(segment) => segment.written_addresses().collect(),
+            None => vec![],
+        }
+    }
+
     // STATE ACCESSORS AND MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -142,22 +210,31 @@ impl Memory {
         self.trace.entry(ctx).or_default().write(addr, Felt::from(clk), value);
     }
 
+    /// Marks the specified range of addresses in the specified context as read-only.
+    ///
+    /// Marking addresses as read-only does not affect the memory access trace.
+    pub fn set_read_only(&mut self, ctx: ContextId, addrs: Range<u32>) {
+        if !addrs.is_empty() {
+            self.read_only.entry(ctx).or_default().push(addrs);
+        }
+    }
+
     // EXECUTION TRACE GENERATION
     // --------------------------------------------------------------------------------------------
 
     /// Adds all of the range checks required by the [Memory] chiplet to the provided
     /// [RangeChecker] chiplet instance, along with their row in the finalized execution trace.
     pub fn append_range_checks(&self, memory_start_row: usize, range: &mut RangeChecker) {
-        // set the previous address and clock cycle to the first address and clock cycle of the
-        // trace; we also adjust the clock cycle so that delta value for the first row would end
-        // up being ZERO. if the trace is empty, return without any further processing.
+        // set the previous context, address, and clock cycle to the ones of the first row of the
+        // trace. if the trace is empty, return without any further processing.
         let (mut prev_ctx, mut prev_addr, mut prev_clk) = match self.get_first_row_info() {
-            Some((ctx, addr, clk)) => (ctx, addr, clk.as_int() - 1),
+            Some((ctx, addr, clk)) => (ctx, addr, clk.as_int()),
             None => return,
         };
 
         // op range check index
-        let mut row = memory_start_row as u32;
+        let first_row = memory_start_row as u32;
+        let mut row = first_row;
 
         for (&ctx, segment) in self.trace.iter() {
             for (&addr, addr_trace) in segment.inner().iter() {
@@ -171,6 +248,12 @@ impl Memory {
                         (u32::from(ctx) - u32::from(prev_ctx)).into()
                     } else if prev_addr != addr {
                         (addr - prev_addr) as u64
+                    } else if row == first_row {
+                        // the delta of the first row is ZERO. this is handled explicitly, rather
+                        // than by setting the previous clock cycle to the one before the first
+                        // access, because the first access happens at clock cycle 0 when memory
+                        // is initialized before execution (see MemoryInputs and execute_block())
+                        0
                     } else {
                         clk - prev_clk - 1
                     };
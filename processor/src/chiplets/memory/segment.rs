@@ -3,7 +3,7 @@ use miden_air::trace::chiplets::memory::{
     Selectors, MEMORY_COPY_READ, MEMORY_INIT_READ, MEMORY_WRITE,
 };
 
-use super::{Felt, Word, INIT_MEM_VALUE};
+use super::{Felt, MemoryAccessRecord, Word, INIT_MEM_VALUE};
 
 // MEMORY SEGMENT TRACE
 // ================================================================================================
@@ -62,6 +62,54 @@ impl MemorySegmentTrace {
         result
     }
 
+    /// Returns an iterator over addresses which have been written to at least once, sorted in
+    /// ascending order.
+    pub fn written_addresses(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.iter().filter_map(|(&addr, addr_trace)| {
+            addr_trace
+                .iter()
+                .any(|access| access.op == MemoryOperation::Write)
+                .then_some(addr)
+        })
+    }
+
+    /// Returns all accesses to the specified address sorted by clock cycle, or an empty vector if
+    /// the address hasn't been accessed previously.
+    pub fn get_accesses(&self, addr: u32) -> Vec<MemoryAccessRecord> {
+        let addr_trace = match self.0.get(&addr) {
+            Some(addr_trace) => addr_trace,
+            None => return Vec::new(),
+        };
+
+        // the old value of every access is the value after the previous access; the old value of
+        // the first access is the initial value of the memory
+        let mut old_value = INIT_MEM_VALUE;
+        addr_trace
+            .iter()
+            .map(|access| {
+                let record = MemoryAccessRecord {
+                    clk: access.clk.as_int() as u32,
+                    is_write: access.op == MemoryOperation::Write,
+                    old_value,
+                    new_value: access.value,
+                };
+                old_value = access.value;
+                record
+            })
+            .collect()
+    }
+
+    /// Returns the clock cycle at which the specified address was last written to, or None if the
+    /// address has never been written to.
+    pub fn get_last_write_clk(&self, addr: u32) -> Option<u32> {
+        self.0
+            .get(&addr)?
+            .iter()
+            .rev()
+            .find(|access| access.op == MemoryOperation::Write)
+            .map(|access| access.clk.as_int() as u32)
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -1,6 +1,6 @@
 use super::{
-    super::ZERO, Felt, FieldElement, Memory, TraceFragment, ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX,
-    D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, EMPTY_WORD, ONE, V_COL_RANGE,
+    super::ZERO, Felt, FieldElement, Memory, MemoryAccessRecord, TraceFragment, ADDR_COL_IDX,
+    CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, EMPTY_WORD, ONE, V_COL_RANGE,
 };
 use crate::ContextId;
 use alloc::vec::Vec;
@@ -293,6 +293,103 @@ fn mem_get_state_at() {
     assert_eq!(mem.get_state_at(3.into(), 5), vec![(3, value7)]);
 }
 
+#[test]
+fn mem_read_only() {
+    let mut mem = Memory::default();
+    mem.set_read_only(ContextId::root(), 3..5);
+    mem.set_read_only(ContextId::root(), 7..7);
+
+    assert!(!mem.is_read_only(ContextId::root(), 2));
+    assert!(mem.is_read_only(ContextId::root(), 3));
+    assert!(mem.is_read_only(ContextId::root(), 4));
+    assert!(!mem.is_read_only(ContextId::root(), 5));
+    assert!(!mem.is_read_only(ContextId::root(), 7));
+
+    // read-only ranges are tracked per context
+    assert!(!mem.is_read_only(3.into(), 3));
+
+    // marking addresses as read-only does not affect the trace
+    assert_eq!(0, mem.trace_len());
+}
+
+#[test]
+fn mem_written_addresses() {
+    let mut mem = Memory::default();
+    assert_eq!(mem.contexts(), vec![]);
+
+    let value = [ONE, ZERO, ZERO, ZERO];
+    mem.write(3.into(), 7, 1, value);
+    mem.write(ContextId::root(), 5, 2, value);
+    mem.write(ContextId::root(), 2, 3, value);
+    mem.write(ContextId::root(), 5, 4, value);
+
+    // addresses which have only been read are not reported as written
+    mem.read(ContextId::root(), 9, 5);
+    mem.read(ContextId::root(), 2, 6);
+    mem.read(4.into(), 1, 7);
+
+    assert_eq!(mem.contexts(), vec![ContextId::root(), 3.into(), 4.into()]);
+    assert_eq!(mem.get_written_addresses(ContextId::root()), vec![2, 5]);
+    assert_eq!(mem.get_written_addresses(3.into()), vec![7]);
+    assert_eq!(mem.get_written_addresses(4.into()), vec![]);
+    assert_eq!(mem.get_written_addresses(5.into()), vec![]);
+}
+
+#[test]
+fn mem_accesses() {
+    let mut mem = Memory::default();
+    let value1 = [ONE, ZERO, ZERO, ZERO];
+    let value2 = [ZERO, ONE, ZERO, ZERO];
+
+    mem.read(ContextId::root(), 5, 1);
+    mem.write(ContextId::root(), 5, 2, value1);
+    mem.write(3.into(), 5, 3, value2);
+    mem.read(ContextId::root(), 5, 4);
+    mem.write(ContextId::root(), 5, 5, value2);
+
+    // accesses are reported per context, and the old value of every access is the value after
+    // the previous access to the same address
+    let expected = vec![
+        MemoryAccessRecord {
+            clk: 1,
+            is_write: false,
+            old_value: EMPTY_WORD,
+            new_value: EMPTY_WORD,
+        },
+        MemoryAccessRecord {
+            clk: 2,
+            is_write: true,
+            old_value: EMPTY_WORD,
+            new_value: value1,
+        },
+        MemoryAccessRecord {
+            clk: 4,
+            is_write: false,
+            old_value: value1,
+            new_value: value1,
+        },
+        MemoryAccessRecord {
+            clk: 5,
+            is_write: true,
+            old_value: value1,
+            new_value: value2,
+        },
+    ];
+    assert_eq!(mem.get_accesses(ContextId::root(), 5), expected);
+
+    let expected = vec![MemoryAccessRecord {
+        clk: 3,
+        is_write: true,
+        old_value: EMPTY_WORD,
+        new_value: value2,
+    }];
+    assert_eq!(mem.get_accesses(3.into(), 5), expected);
+
+    // addresses and contexts which haven't been accessed have no accesses
+    assert_eq!(mem.get_accesses(ContextId::root(), 6), vec![]);
+    assert_eq!(mem.get_accesses(4.into(), 5), vec![]);
+}
+
 // HELPER STRUCT & FUNCTIONS
 // ================================================================================================
 
@@ -18,6 +18,7 @@ use hasher::Hasher;
 
 mod memory;
 use memory::Memory;
+pub use memory::MemoryAccessRecord;
 
 mod kernel_rom;
 use kernel_rom::KernelRom;
@@ -119,19 +120,25 @@ pub struct Chiplets {
     bitwise: Bitwise,
     memory: Memory,
     kernel_rom: KernelRom,
+    /// If true, the kernel ROM trace is omitted when no kernel procedures were accessed.
+    skip_unused_chiplets: bool,
 }
 
 impl Chiplets {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns a new [Chiplets] component instantiated with the provided Kernel.
-    pub fn new(kernel: Kernel) -> Self {
+    ///
+    /// If `skip_unused_chiplets` is true, the kernel ROM trace is omitted from the chiplets trace
+    /// when none of the kernel procedures are accessed during execution.
+    pub fn new(kernel: Kernel, skip_unused_chiplets: bool) -> Self {
         Self {
             clk: 0,
             hasher: Hasher::default(),
             bitwise: Bitwise::default(),
             memory: Memory::default(),
             kernel_rom: KernelRom::new(kernel),
+            skip_unused_chiplets,
         }
     }
 
@@ -145,7 +152,7 @@ impl Chiplets {
         self.hasher.trace_len()
             + self.bitwise.trace_len()
             + self.memory.trace_len()
-            + self.kernel_rom.trace_len()
+            + self.kernel_rom_trace_len()
             + 1
     }
 
@@ -166,7 +173,7 @@ impl Chiplets {
 
     /// Returns the index of the first row of the padding section of the execution trace.
     pub fn padding_start(&self) -> usize {
-        self.kernel_rom_start() + self.kernel_rom.trace_len()
+        self.kernel_rom_start() + self.kernel_rom_trace_len()
     }
 
     /// Returns the underlying kernel used to initilize this instance.
@@ -306,28 +313,66 @@ impl Chiplets {
     }
 
     /// Writes the provided word at the specified context/address.
-    pub fn write_mem(&mut self, ctx: ContextId, addr: u32, word: Word) {
+    ///
+    /// # Errors
+    /// Returns an error if the address has been marked as read-only.
+    pub fn write_mem(
+        &mut self,
+        ctx: ContextId,
+        addr: u32,
+        word: Word,
+    ) -> Result<(), ExecutionError> {
+        self.check_mem_writable(ctx, addr)?;
         self.memory.write(ctx, addr, self.clk, word);
+        Ok(())
     }
 
     /// Writes the provided element into the specified context/address leaving the remaining 3
     /// elements of the word previously stored at that address unchanged.
-    pub fn write_mem_element(&mut self, ctx: ContextId, addr: u32, value: Felt) -> Word {
+    ///
+    /// # Errors
+    /// Returns an error if the address has been marked as read-only.
+    pub fn write_mem_element(
+        &mut self,
+        ctx: ContextId,
+        addr: u32,
+        value: Felt,
+    ) -> Result<Word, ExecutionError> {
+        self.check_mem_writable(ctx, addr)?;
         let old_word = self.memory.get_old_value(ctx, addr);
         let new_word = [value, old_word[1], old_word[2], old_word[3]];
 
         self.memory.write(ctx, addr, self.clk, new_word);
 
-        old_word
+        Ok(old_word)
     }
 
     /// Writes the two provided words to two consecutive addresses in memory in the specified
     /// context, starting at the specified address.
-    pub fn write_mem_double(&mut self, ctx: ContextId, addr: u32, words: [Word; 2]) {
+    ///
+    /// # Errors
+    /// Returns an error if either of the addresses has been marked as read-only; in such a case
+    /// neither of the words is written.
+    pub fn write_mem_double(
+        &mut self,
+        ctx: ContextId,
+        addr: u32,
+        words: [Word; 2],
+    ) -> Result<(), ExecutionError> {
         let addr2 = addr + 1;
+        self.check_mem_writable(ctx, addr)?;
+        self.check_mem_writable(ctx, addr2)?;
+
         // write two words to memory at addr and addr + 1
         self.memory.write(ctx, addr, self.clk, words[0]);
         self.memory.write(ctx, addr2, self.clk, words[1]);
+        Ok(())
+    }
+
+    /// Marks memory addresses in the range [start_addr, end_addr) of the specified context as
+    /// read-only. Any subsequent attempt to write to these addresses results in an error.
+    pub fn protect_mem(&mut self, ctx: ContextId, start_addr: u32, end_addr: u32) {
+        self.memory.set_read_only(ctx, start_addr..end_addr);
     }
 
     /// Returns a word located at the specified context/address, or None if the address hasn't
@@ -339,6 +384,18 @@ impl Chiplets {
         self.memory.get_value(ctx, addr)
     }
 
+    /// Returns all accesses to the specified context/address recorded so far, sorted by clock
+    /// cycle, or an empty vector if the address hasn't been accessed previously.
+    pub fn get_mem_accesses(&self, ctx: ContextId, addr: u32) -> Vec<MemoryAccessRecord> {
+        self.memory.get_accesses(ctx, addr)
+    }
+
+    /// Returns the clock cycle at which the specified context/address was last written to, or None
+    /// if the address has never been written to.
+    pub fn get_mem_last_write_clk(&self, ctx: ContextId, addr: u32) -> Option<u32> {
+        self.memory.get_last_write_clk(ctx, addr)
+    }
+
     /// Returns the entire memory state for the specified execution context at the specified cycle.
     /// The state is returned as a vector of (address, value) tuples, and includes addresses which
     /// have been accessed at least once.
@@ -346,6 +403,18 @@ impl Chiplets {
         self.memory.get_state_at(ctx, clk)
     }
 
+    /// Returns IDs of all execution contexts whose memory has been accessed at least once, sorted
+    /// in ascending order.
+    pub fn get_mem_contexts(&self) -> Vec<ContextId> {
+        self.memory.contexts()
+    }
+
+    /// Returns addresses in the specified execution context which have been written to at least
+    /// once, sorted in ascending order.
+    pub fn get_mem_written_addresses(&self, ctx: ContextId) -> Vec<u32> {
+        self.memory.get_written_addresses(ctx)
+    }
+
     /// Returns current size of the memory (in words) across all execution contexts.
     #[cfg(test)]
     pub fn get_mem_size(&self) -> usize {
@@ -409,6 +478,31 @@ impl Chiplets {
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the number of rows occupied by the [KernelRom] execution trace.
+    ///
+    /// Other chiplets are sized according to their usage, but the kernel ROM contains a row for
+    /// every kernel procedure. Thus, this is zero only if unused chiplets are skipped and the
+    /// program did not access any kernel procedures.
+    fn kernel_rom_trace_len(&self) -> usize {
+        if self.skip_unused_chiplets && !self.kernel_rom.has_accesses() {
+            0
+        } else {
+            self.kernel_rom.trace_len()
+        }
+    }
+
+    /// Returns an error if the specified address in the specified context has been marked as
+    /// read-only.
+    fn check_mem_writable(&self, ctx: ContextId, addr: u32) -> Result<(), ExecutionError> {
+        if self.memory.is_read_only(ctx, addr) {
+            return Err(ExecutionError::ReadOnlyMemoryWrite {
+                addr,
+                context: None,
+            });
+        }
+        Ok(())
+    }
+
     /// Fills the provided trace for the chiplets module with the stacked execution traces of the
     /// Hasher, Bitwise, and Memory chiplets, along with selector columns to identify each chiplet
     /// trace and padding to fill the rest of the trace.
@@ -421,6 +515,7 @@ impl Chiplets {
         let memory_start = self.memory_start();
         let kernel_rom_start = self.kernel_rom_start();
         let padding_start = self.padding_start();
+        let kernel_rom_len = self.kernel_rom_trace_len();
 
         let Chiplets {
             clk: _,
@@ -428,6 +523,7 @@ impl Chiplets {
             bitwise,
             memory,
             kernel_rom,
+            skip_unused_chiplets: _,
         } = self;
 
         // populate external selector columns for all chiplets
@@ -466,7 +562,7 @@ impl Chiplets {
                     let rest = hasher_fragment.push_column_slice(column, hasher.trace_len());
                     let rest = bitwise_fragment.push_column_slice(rest, bitwise.trace_len());
                     let rest = memory_fragment.push_column_slice(rest, memory.trace_len());
-                    kernel_rom_fragment.push_column_slice(rest, kernel_rom.trace_len());
+                    kernel_rom_fragment.push_column_slice(rest, kernel_rom_len);
                 }
                 _ => panic!("invalid column index"),
             }
@@ -477,7 +573,9 @@ impl Chiplets {
         hasher.fill_trace(&mut hasher_fragment);
         bitwise.fill_trace(&mut bitwise_fragment);
         memory.fill_trace(&mut memory_fragment);
-        kernel_rom.fill_trace(&mut kernel_rom_fragment);
+        if kernel_rom_len > 0 {
+            kernel_rom.fill_trace(&mut kernel_rom_fragment);
+        }
     }
 }
 
@@ -94,6 +94,29 @@ fn stacked_chiplet_trace() {
     validate_padding(&chiplets_trace, kernel_rom_end, trace_len);
 }
 
+#[test]
+fn stacked_chiplet_trace_unused_kernel_rom_skipped() {
+    let stack = [8, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 1];
+    let ops = vec![Operation::U32xor, Operation::Push(ZERO), Operation::MStoreW, Operation::HPerm];
+    let options = ExecutionOptions::default().with_unused_chiplets_skipped();
+    let (chiplets_trace, trace_len) =
+        build_trace_with_options(&stack, ops, build_kernel(), options);
+    let memory_len = 1;
+
+    let hasher_start = HASH_CYCLE_LEN;
+    let hasher_end = hasher_start + HASH_CYCLE_LEN;
+    validate_hasher_trace(&chiplets_trace, hasher_start, hasher_end);
+
+    let bitwise_end = hasher_end + OP_CYCLE_LEN;
+    validate_bitwise_trace(&chiplets_trace, hasher_end, bitwise_end);
+
+    let memory_end = bitwise_end + memory_len;
+    validate_memory_trace(&chiplets_trace, bitwise_end, memory_end);
+
+    // no kernel procedures were accessed, so the padding starts right after the memory trace
+    validate_padding(&chiplets_trace, memory_end, trace_len);
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -110,10 +133,20 @@ fn build_trace(
     stack_inputs: &[u64],
     operations: Vec<Operation>,
     kernel: Kernel,
+) -> (ChipletsTrace, usize) {
+    build_trace_with_options(stack_inputs, operations, kernel, ExecutionOptions::default())
+}
+
+/// Builds a sample trace in the same way as [build_trace], using the specified execution options.
+fn build_trace_with_options(
+    stack_inputs: &[u64],
+    operations: Vec<Operation>,
+    kernel: Kernel,
+    options: ExecutionOptions,
 ) -> (ChipletsTrace, usize) {
     let stack_inputs = StackInputs::try_from_ints(stack_inputs.iter().copied()).unwrap();
     let host = DefaultHost::default();
-    let mut process = Process::new(kernel, stack_inputs, host, ExecutionOptions::default());
+    let mut process = Process::new(kernel, stack_inputs, host, options);
     let program = CodeBlock::new_span(operations);
     process.execute_code_block(&program, &CodeBlockTable::default()).unwrap();
 
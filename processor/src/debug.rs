@@ -5,9 +5,14 @@ use crate::{
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt;
-use vm_core::{AssemblyOp, Operation, StackOutputs, Word};
+use vm_core::{stack::STACK_TOP_SIZE, AssemblyOp, Operation, StackOutputs, Word};
 
 /// VmState holds a current process state information at a specific clock cycle.
+///
+/// The `stack` contains the top 16 items of the stack followed by all items of the overflow table,
+/// including the items which belong to the calling execution contexts and thus are not accessible
+/// in the current context. The first `stack_depth` items of the `stack` form the stack of the
+/// current execution context.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VmState {
     pub clk: u32,
@@ -16,9 +21,24 @@ pub struct VmState {
     pub asmop: Option<AsmOpInfo>,
     pub fmp: Felt,
     pub stack: Vec<Felt>,
+    pub stack_depth: usize,
     pub memory: Vec<(u64, Word)>,
 }
 
+impl VmState {
+    /// Returns the items of the stack which are accessible in the current execution context, with
+    /// the top of the stack in the first position.
+    pub fn context_stack(&self) -> &[Felt] {
+        &self.stack[..self.stack_depth.min(self.stack.len())]
+    }
+
+    /// Returns the items of the overflow table (i.e., all stack items beyond the top 16), with the
+    /// most recently added item in the first position.
+    pub fn overflow(&self) -> &[Felt] {
+        &self.stack[STACK_TOP_SIZE.min(self.stack.len())..]
+    }
+}
+
 impl fmt::Display for VmState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let stack: Vec<u64> = self.stack.iter().map(|x| x.as_int()).collect();
@@ -26,7 +46,7 @@ impl fmt::Display for VmState {
             self.memory.iter().map(|x| (x.0, word_to_ints(&x.1))).collect();
         write!(
             f,
-            "clk={}{}{}, fmp={}, stack={stack:?}, memory={memory:?}",
+            "clk={}{}{}, fmp={}, stack={stack:?}, stack_depth={}, memory={memory:?}",
             self.clk,
             match self.op {
                 Some(op) => format!(", op={op}"),
@@ -36,11 +56,40 @@ impl fmt::Display for VmState {
                 Some(op) => format!(", {op}"),
                 None => "".to_string(),
             },
-            self.fmp
+            self.fmp,
+            self.stack_depth
         )
     }
 }
 
+/// Outcome of executing a program via [execute_recoverable()](crate::execute_recoverable).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExecutionOutcome {
+    /// The program was executed to completion.
+    Completed(StackOutputs),
+    /// Execution of the program was terminated early due to a recoverable error.
+    Halted(HaltedState),
+}
+
+impl ExecutionOutcome {
+    /// Returns true if the program was executed to completion.
+    pub fn is_completed(&self) -> bool {
+        matches!(self, Self::Completed(_))
+    }
+}
+
+/// HaltedState holds the process state information at the clock cycle at which execution was
+/// terminated, together with the error which caused the termination.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HaltedState {
+    pub clk: u32,
+    pub ctx: ContextId,
+    pub fmp: Felt,
+    pub stack: Vec<Felt>,
+    pub memory: Vec<(u64, Word)>,
+    pub error: ExecutionError,
+}
+
 /// Iterator that iterates through vm state at each step of the execution.
 /// This allows debugging or replaying ability to view various process state
 /// at each clock cycle.
@@ -164,6 +213,7 @@ impl VmStateIterator {
             asmop,
             fmp: self.system.get_fmp_at(self.clk),
             stack: self.stack.get_state_at(self.clk),
+            stack_depth: self.stack.get_depth_at(self.clk),
             memory: self.chiplets.get_mem_state_at(ctx, self.clk),
         });
 
@@ -234,6 +284,7 @@ impl Iterator for VmStateIterator {
             asmop,
             fmp: self.system.get_fmp_at(self.clk),
             stack: self.stack.get_state_at(self.clk),
+            stack_depth: self.stack.get_depth_at(self.clk),
             memory: self.chiplets.get_mem_state_at(ctx, self.clk),
         }));
 
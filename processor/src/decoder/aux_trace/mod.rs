@@ -12,6 +12,12 @@ use block_stack_table::BlockStackColumnBuilder;
 
 mod op_group_table;
 use op_group_table::OpGroupTableColumnBuilder;
+pub use op_group_table::OpGroupTableEntry;
+
+mod op_group_report;
+pub use op_group_report::{
+    DecoderRow, OpBatchSummary, OpGroupMismatch, OpGroupMismatchKind, OpGroupTableReport,
+};
 
 // CONSTANTS
 // ================================================================================================
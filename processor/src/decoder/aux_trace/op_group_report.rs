@@ -0,0 +1,311 @@
+use super::{
+    op_group_table::{get_inserted_groups, get_removed_group, is_group_removed_at},
+    Felt, MainTrace, OpGroupTableEntry, PUSH, RESPAN, SPAN,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::fmt;
+use miden_air::trace::{
+    decoder::{NUM_HASHER_COLUMNS, NUM_OP_BATCH_FLAGS, OP_INDEX_COL_IDX},
+    DECODER_TRACE_OFFSET,
+};
+
+// OP GROUP TABLE REPORT
+// ================================================================================================
+
+/// Describes the contents of the decoder's op group table over an execution trace, together with
+/// all rows at which the table is inconsistent.
+///
+/// The report is built by replaying insertions into and removals from the op group table in the
+/// same way as the `p3` auxiliary column is built, but without reducing table entries to random
+/// linear combinations. Thus, unlike the `p3` column, the report can point out which entries of
+/// the table are missing or were never removed, which makes it useful for diagnosing traces for
+/// which the `p3` column does not end with ONE (e.g., spans with many immediate values).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpGroupTableReport {
+    batches: Vec<OpBatchSummary>,
+    mismatches: Vec<OpGroupMismatch>,
+}
+
+impl OpGroupTableReport {
+    /// Returns a report describing the op group table over the provided main trace.
+    pub fn new(main_trace: &MainTrace) -> Self {
+        // entries currently in the table together with the rows at which they were inserted
+        let mut table: Vec<(usize, OpGroupTableEntry)> = Vec::new();
+        let mut batches: Vec<OpBatchSummary> = Vec::new();
+        let mut batch_indexes: BTreeMap<u64, usize> = BTreeMap::new();
+        let mut mismatches = Vec::new();
+
+        for i in 0..main_trace.num_rows() - 1 {
+            let op_code = main_trace.get_op_code(i).as_int() as u8;
+
+            if is_group_removed_at(main_trace, i) {
+                let entry = get_removed_group(main_trace, i);
+                let is_immediate = op_code == PUSH;
+                match table.iter().position(|(_, table_entry)| *table_entry == entry) {
+                    Some(position) => {
+                        table.remove(position);
+                        if let Some(&batch_idx) = batch_indexes.get(&entry.batch_id.as_int()) {
+                            batches[batch_idx].num_removed += 1;
+                            batches[batch_idx].num_immediates += is_immediate as usize;
+                        }
+                    }
+                    None => mismatches.push(OpGroupMismatch::new(
+                        main_trace,
+                        i,
+                        OpGroupMismatchKind::MissingEntry { is_immediate },
+                        entry,
+                        &table,
+                    )),
+                }
+            }
+
+            if op_code == SPAN || op_code == RESPAN {
+                let entries = get_inserted_groups(main_trace, i);
+                let batch_id = main_trace.addr(i + 1);
+                batch_indexes.insert(batch_id.as_int(), batches.len());
+                batches.push(OpBatchSummary {
+                    batch_id,
+                    row: i,
+                    group_count: main_trace.group_count(i),
+                    num_inserted: entries.len(),
+                    num_removed: 0,
+                    num_immediates: 0,
+                });
+                table.extend(entries.into_iter().map(|entry| (i, entry)));
+            }
+        }
+
+        // entries left in the table were never removed
+        for &(row, entry) in table.iter() {
+            let kind = OpGroupMismatchKind::UnremovedEntry;
+            mismatches.push(OpGroupMismatch::new(main_trace, row, kind, entry, &table));
+        }
+        mismatches.sort_by_key(|mismatch| mismatch.row);
+
+        Self {
+            batches,
+            mismatches,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if every entry inserted into the op group table was removed from it exactly
+    /// once, and no other entries were removed from it.
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Returns summaries of all operation batches executed in the trace, in the order in which
+    /// the batches were executed.
+    pub fn batches(&self) -> &[OpBatchSummary] {
+        &self.batches
+    }
+
+    /// Returns all inconsistencies of the op group table, ordered by the row at which they occur.
+    pub fn mismatches(&self) -> &[OpGroupMismatch] {
+        &self.mismatches
+    }
+}
+
+impl fmt::Display for OpGroupTableReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "op group table: {} batches executed, {} mismatches",
+            self.batches.len(),
+            self.mismatches.len()
+        )?;
+
+        for mismatch in self.mismatches.iter() {
+            writeln!(f)?;
+            writeln!(f, "{mismatch}")?;
+            let batch = self.batches.iter().find(|batch| batch.batch_id == mismatch.entry.batch_id);
+            match batch {
+                Some(batch) => writeln!(f, "  {batch}")?,
+                None => writeln!(f, "  batch {} was never started", mismatch.entry.batch_id)?,
+            }
+            writeln!(f, "  table entries of batch {}:", mismatch.entry.batch_id)?;
+            if mismatch.table.is_empty() {
+                writeln!(f, "    (none)")?;
+            }
+            for (row, entry) in mismatch.table.iter() {
+                writeln!(
+                    f,
+                    "    group_pos={}, group_value={} (inserted at row {row})",
+                    entry.group_pos, entry.group_value
+                )?;
+            }
+            writeln!(f, "  decoder rows:")?;
+            for row in mismatch.decoder_rows.iter() {
+                writeln!(f, "    {row}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// OP BATCH SUMMARY
+// ================================================================================================
+
+/// Describes how the groups of a single operation batch moved through the op group table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpBatchSummary {
+    /// Address of the batch, i.e., the batch ID used in the op group table.
+    pub batch_id: Felt,
+    /// Row at which the batch was started by a SPAN or a RESPAN operation.
+    pub row: usize,
+    /// Number of groups left in the span block when the batch was started.
+    pub group_count: Felt,
+    /// Number of entries inserted into the table for the batch.
+    pub num_inserted: usize,
+    /// Number of entries of the batch removed from the table.
+    pub num_removed: usize,
+    /// Number of removed entries which were immediate values of PUSH operations.
+    pub num_immediates: usize,
+}
+
+impl fmt::Display for OpBatchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "batch {} started at row {} with group count {}: {} groups inserted, {} removed \
+            ({} immediate values)",
+            self.batch_id,
+            self.row,
+            self.group_count,
+            self.num_inserted,
+            self.num_removed,
+            self.num_immediates
+        )
+    }
+}
+
+// OP GROUP MISMATCH
+// ================================================================================================
+
+/// Describes the kind of an inconsistency of the op group table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpGroupMismatchKind {
+    /// An entry was removed from the table, but the table did not contain it; `is_immediate`
+    /// is set to true if the entry was removed by a PUSH operation.
+    MissingEntry { is_immediate: bool },
+    /// An entry was inserted into the table, but it was never removed.
+    UnremovedEntry,
+}
+
+/// Describes an inconsistency of the op group table, together with the state of the table and
+/// the decoder columns at the offending row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpGroupMismatch {
+    /// Row at which the entry was removed from the table or, for unremoved entries, the row at
+    /// which the entry was inserted.
+    pub row: usize,
+    pub kind: OpGroupMismatchKind,
+    pub entry: OpGroupTableEntry,
+    /// Entries of the same batch in the table at the offending row (or at the end of the trace
+    /// for unremoved entries), together with the rows at which they were inserted.
+    pub table: Vec<(usize, OpGroupTableEntry)>,
+    /// Decoder columns at the offending row and at the row following it; the removed entry is
+    /// computed from both rows.
+    pub decoder_rows: [DecoderRow; 2],
+}
+
+impl OpGroupMismatch {
+    fn new(
+        main_trace: &MainTrace,
+        row: usize,
+        kind: OpGroupMismatchKind,
+        entry: OpGroupTableEntry,
+        table: &[(usize, OpGroupTableEntry)],
+    ) -> Self {
+        let table = table
+            .iter()
+            .filter(|(_, table_entry)| table_entry.batch_id == entry.batch_id)
+            .copied()
+            .collect();
+        Self {
+            row,
+            kind,
+            entry,
+            table,
+            decoder_rows: [DecoderRow::new(main_trace, row), DecoderRow::new(main_trace, row + 1)],
+        }
+    }
+}
+
+impl fmt::Display for OpGroupMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entry = &self.entry;
+        match self.kind {
+            OpGroupMismatchKind::MissingEntry { is_immediate } => write!(
+                f,
+                "row {}: {} (batch_id={}, group_pos={}, group_value={}) was removed from the op \
+                group table, but the table does not contain it",
+                self.row,
+                if is_immediate { "immediate value" } else { "op group" },
+                entry.batch_id,
+                entry.group_pos,
+                entry.group_value
+            ),
+            OpGroupMismatchKind::UnremovedEntry => write!(
+                f,
+                "row {}: group (batch_id={}, group_pos={}, group_value={}) was inserted into the op \
+                group table, but it was never removed",
+                self.row, entry.batch_id, entry.group_pos, entry.group_value
+            ),
+        }
+    }
+}
+
+// DECODER ROW
+// ================================================================================================
+
+/// Values of the decoder columns relevant to the op group table at a single row of the trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecoderRow {
+    pub row: usize,
+    pub addr: Felt,
+    pub op_code: u8,
+    pub hasher_state: [Felt; NUM_HASHER_COLUMNS],
+    pub in_span: Felt,
+    pub group_count: Felt,
+    pub op_index: Felt,
+    pub op_batch_flags: [Felt; NUM_OP_BATCH_FLAGS],
+}
+
+impl DecoderRow {
+    fn new(main_trace: &MainTrace, row: usize) -> Self {
+        Self {
+            row,
+            addr: main_trace.addr(row),
+            op_code: main_trace.get_op_code(row).as_int() as u8,
+            hasher_state: main_trace.decoder_hasher_state(row),
+            in_span: main_trace.is_in_span(row),
+            group_count: main_trace.group_count(row),
+            op_index: main_trace.get(DECODER_TRACE_OFFSET + OP_INDEX_COL_IDX, row),
+            op_batch_flags: main_trace.op_batch_flag(row),
+        }
+    }
+}
+
+impl fmt::Display for DecoderRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [c0, c1, c2] = self.op_batch_flags;
+        write!(
+            f,
+            "row {}: addr={}, op_code={:#04x}, in_span={}, group_count={}, op_index={}, \
+            batch_flags=[{c0}, {c1}, {c2}], h=[",
+            self.row, self.addr, self.op_code, self.in_span, self.group_count, self.op_index
+        )?;
+        for (i, value) in self.hasher_state.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value}")?;
+        }
+        write!(f, "]")
+    }
+}
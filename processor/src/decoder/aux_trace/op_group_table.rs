@@ -1,4 +1,5 @@
 use super::{AuxColumnBuilder, Felt, FieldElement, MainTrace, ONE, PUSH, RESPAN, SPAN};
+use alloc::vec::Vec;
 use miden_air::trace::decoder::{OP_BATCH_2_GROUPS, OP_BATCH_4_GROUPS, OP_BATCH_8_GROUPS};
 
 // OP GROUP TABLE COLUMN
@@ -12,10 +13,8 @@ pub struct OpGroupTableColumnBuilder {}
 impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for OpGroupTableColumnBuilder {
     /// Removes a row from the block hash table.
     fn get_requests_at(&self, main_trace: &MainTrace, alphas: &[E], i: usize) -> E {
-        let delete_group_flag = main_trace.delta_group_count(i) * main_trace.is_in_span(i);
-
-        if delete_group_flag == ONE {
-            get_op_group_table_removal_multiplicand(main_trace, i, alphas)
+        if is_group_removed_at(main_trace, i) {
+            get_removed_group(main_trace, i).to_value(alphas)
         } else {
             E::ONE
         }
@@ -27,63 +26,87 @@ impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for OpGroupTableColu
         let op_code = op_code_felt.as_int() as u8;
 
         match op_code {
-            SPAN | RESPAN => get_op_group_table_inclusion_multiplicand(main_trace, i, alphas),
+            SPAN | RESPAN => get_inserted_groups(main_trace, i)
+                .iter()
+                .fold(E::ONE, |acc, group| acc * group.to_value(alphas)),
             _ => E::ONE,
         }
     }
 }
 
+// OP GROUP TABLE ENTRY
+// ================================================================================================
+
+/// An entry in the op group table, i.e., a tuple (batch_id, group_pos, group_value).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpGroupTableEntry {
+    /// Address of the operation batch to which the group belongs.
+    pub batch_id: Felt,
+    /// Position of the group in the span block, expressed as the group count at which the group
+    /// is removed from the table.
+    pub group_pos: Felt,
+    /// Value of the group; this is either an encoded operation group or an immediate value.
+    pub group_value: Felt,
+}
+
+impl OpGroupTableEntry {
+    /// Reduces this entry to a single field element in the field specified by E. This requires
+    /// at least 4 alpha values.
+    fn to_value<E: FieldElement<BaseField = Felt>>(self, alphas: &[E]) -> E {
+        alphas[0]
+            + alphas[1].mul_base(self.batch_id)
+            + alphas[2].mul_base(self.group_pos)
+            + alphas[3].mul_base(self.group_value)
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Computes the multiplicand representing the inclusion of a new row to the op group table.
-fn get_op_group_table_inclusion_multiplicand<E: FieldElement<BaseField = Felt>>(
-    main_trace: &MainTrace,
-    i: usize,
-    alphas: &[E],
-) -> E {
-    let block_id = main_trace.addr(i + 1);
+/// Returns the entries inserted into the op group table at row i; entries are inserted only when
+/// a SPAN or a RESPAN operation is executed.
+///
+/// All groups of an operation batch except for the first one are inserted into the table, since
+/// the first group starts executing right away.
+pub(super) fn get_inserted_groups(main_trace: &MainTrace, i: usize) -> Vec<OpGroupTableEntry> {
+    let batch_id = main_trace.addr(i + 1);
     let group_count = main_trace.group_count(i);
     let op_batch_flag = main_trace.op_batch_flag(i);
 
-    if op_batch_flag == OP_BATCH_8_GROUPS {
-        let h = main_trace.decoder_hasher_state(i);
-        (1..8_u8).fold(E::ONE, |acc, k| {
-            acc * (alphas[0]
-                + alphas[1].mul_base(block_id)
-                + alphas[2].mul_base(group_count - Felt::from(k))
-                + alphas[3].mul_base(h[k as usize]))
-        })
+    let num_groups = if op_batch_flag == OP_BATCH_8_GROUPS {
+        8
     } else if op_batch_flag == OP_BATCH_4_GROUPS {
-        let h = main_trace.decoder_hasher_state_first_half(i);
-        (1..4_u8).fold(E::ONE, |acc, k| {
-            acc * (alphas[0]
-                + alphas[1].mul_base(block_id)
-                + alphas[2].mul_base(group_count - Felt::from(k))
-                + alphas[3].mul_base(h[k as usize]))
-        })
+        4
     } else if op_batch_flag == OP_BATCH_2_GROUPS {
-        let h = main_trace.decoder_hasher_state_first_half(i);
-        alphas[0]
-            + alphas[1].mul_base(block_id)
-            + alphas[2].mul_base(group_count - ONE)
-            + alphas[3].mul_base(h[1])
+        2
     } else {
-        E::ONE
-    }
+        return Vec::new();
+    };
+
+    let h = main_trace.decoder_hasher_state(i);
+    (1..num_groups)
+        .map(|k| OpGroupTableEntry {
+            batch_id,
+            group_pos: group_count - Felt::from(k as u8),
+            group_value: h[k],
+        })
+        .collect()
 }
 
-/// Computes the multiplicand representing the removal of a row from the op group table.
-fn get_op_group_table_removal_multiplicand<E: FieldElement<BaseField = Felt>>(
-    main_trace: &MainTrace,
-    i: usize,
-    alphas: &[E],
-) -> E {
-    let group_count = main_trace.group_count(i);
-    let block_id = main_trace.addr(i);
+/// Returns true if an entry is removed from the op group table at row i; this happens when the
+/// group count decreases inside a span block.
+pub(super) fn is_group_removed_at(main_trace: &MainTrace, i: usize) -> bool {
+    main_trace.delta_group_count(i) * main_trace.is_in_span(i) == ONE
+}
 
+/// Returns the entry removed from the op group table at row i.
+///
+/// If the operation at row i is a PUSH, the removed group is the immediate value pushed onto the
+/// stack; otherwise, the removed group is the next operation group, which is reconstructed from
+/// the decoder state at the next row.
+pub(super) fn get_removed_group(main_trace: &MainTrace, i: usize) -> OpGroupTableEntry {
     let op_code = main_trace.get_op_code(i);
-    let tmp = if op_code == Felt::from(PUSH) {
+    let group_value = if op_code == Felt::from(PUSH) {
         main_trace.stack_element(0, i + 1)
     } else {
         let h0 = main_trace.decoder_hasher_state_first_half(i + 1)[0];
@@ -91,8 +114,10 @@ fn get_op_group_table_removal_multiplicand<E: FieldElement<BaseField = Felt>>(
         let op_prime = main_trace.get_op_code(i + 1);
         h0.mul_small(1 << 7) + op_prime
     };
-    alphas[0]
-        + alphas[1].mul_base(block_id)
-        + alphas[2].mul_base(group_count)
-        + alphas[3].mul_base(tmp)
+
+    OpGroupTableEntry {
+        batch_id: main_trace.addr(i),
+        group_pos: main_trace.group_count(i),
+        group_value,
+    }
 }
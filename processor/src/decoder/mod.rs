@@ -16,7 +16,10 @@ mod trace;
 use trace::DecoderTrace;
 
 mod aux_trace;
-pub use aux_trace::AuxTraceBuilder;
+pub use aux_trace::{
+    AuxTraceBuilder, DecoderRow, OpBatchSummary, OpGroupMismatch, OpGroupMismatchKind,
+    OpGroupTableEntry, OpGroupTableReport,
+};
 
 mod block_stack;
 use block_stack::{BlockStack, BlockType, ExecutionContextInfo};
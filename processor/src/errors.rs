@@ -1,11 +1,16 @@
 use super::{
     crypto::MerkleError,
     system::{FMP_MAX, FMP_MIN},
-    CodeBlock, Digest, Felt, QuadFelt, Word,
+    CodeBlock, Digest, Felt, InputError, QuadFelt, Word,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
 };
-use alloc::string::String;
 use core::fmt::{Display, Formatter};
-use vm_core::{stack::STACK_TOP_SIZE, utils::to_hex};
+use vm_core::{
+    code_blocks::Span, stack::STACK_TOP_SIZE, utils::to_hex, AdviceInjector, AssemblyOp, Decorator,
+};
 use winter_prover::{math::FieldElement, ProverError};
 
 #[cfg(feature = "std")]
@@ -18,17 +23,28 @@ use std::error::Error;
 pub enum ExecutionError {
     AdviceMapKeyNotFound(Word),
     AdviceStackReadFailed(u32),
+    CallInSyscall(Digest),
     CallerNotInSyscall,
+    Cancelled,
     CodeBlockNotFound(Digest),
     CycleLimitExceeded(u32),
-    DivideByZero(u32),
+    DivideByZero {
+        clk: u32,
+        context: Option<Box<ErrorContext>>,
+    },
     DynamicCodeBlockNotFound(Digest),
-    EventError(String),
+    EventError {
+        event_id: u32,
+        clk: u32,
+        error: String,
+        context: Option<Box<ErrorContext>>,
+    },
     Ext2InttError(Ext2InttError),
     FailedAssertion {
         clk: u32,
         err_code: u32,
         err_msg: Option<String>,
+        context: Option<Box<ErrorContext>>,
     },
     FailedSignatureGeneration(&'static str),
     InvalidFmpValue(Felt, Felt),
@@ -38,7 +54,12 @@ pub enum ExecutionError {
         start_addr: u64,
         end_addr: u64,
     },
+    InvalidMerklePathLength {
+        depth: Felt,
+        path_len: usize,
+    },
     InvalidStackDepthOnReturn(usize),
+    InvalidStackState(InputError),
     InvalidStackWordOffset(usize),
     InvalidTreeDepth {
         depth: Felt,
@@ -47,9 +68,18 @@ pub enum ExecutionError {
         depth: Felt,
         value: Felt,
     },
+    LocalCanaryOverwritten {
+        addr: u32,
+        clk: u32,
+        write_clk: u32,
+        context: Option<Box<ErrorContext>>,
+    },
     LogArgumentZero(u32),
     MalformedSignatureKey(&'static str),
-    MemoryAddressOutOfBounds(u64),
+    MemoryAddressOutOfBounds {
+        addr: u64,
+        context: Option<Box<ErrorContext>>,
+    },
     MerklePathVerificationFailed {
         value: Word,
         index: Felt,
@@ -60,11 +90,130 @@ pub enum ExecutionError {
     MerkleStoreUpdateFailed(MerkleError),
     NotBinaryValue(Felt),
     NotU32Value(Felt, Felt),
+    OverflowTableEvictionNotSupported,
+    OverflowTableRestoreFailed(Word),
+    ProgramHashMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
     ProverError(ProverError),
+    ReadOnlyMemoryWrite {
+        addr: u32,
+        context: Option<Box<ErrorContext>>,
+    },
     SmtNodeNotFound(Word),
     SmtNodePreImageNotValid(Word, usize),
     SyscallTargetNotInKernel(Digest),
     UnexecutableCodeBlock(CodeBlock),
+    UnexpectedHostResponse {
+        expected: &'static str,
+        actual: &'static str,
+    },
+    UnsupportedAdviceInjector(AdviceInjector),
+}
+
+impl ExecutionError {
+    /// Returns true if this error is caused by the executed program or its inputs (e.g., a failed
+    /// assertion or an advice stack underflow), rather than by an inconsistency in the VM, the
+    /// host, or the program's MAST.
+    ///
+    /// Execution terminated with a recoverable error leaves the VM in a consistent state as of
+    /// the cycle at which the error occurred.
+    pub fn is_recoverable(&self) -> bool {
+        use ExecutionError::*;
+
+        matches!(
+            self,
+            AdviceMapKeyNotFound(_)
+                | AdviceStackReadFailed(_)
+                | CycleLimitExceeded(_)
+                | DivideByZero { .. }
+                | FailedAssertion { .. }
+                | InvalidFmpValue(_, _)
+                | InvalidStackDepthOnReturn(_)
+                | LocalCanaryOverwritten { .. }
+                | LogArgumentZero(_)
+                | MemoryAddressOutOfBounds { .. }
+                | MerklePathVerificationFailed { .. }
+                | NotBinaryValue(_)
+                | NotU32Value(_, _)
+                | ReadOnlyMemoryWrite { .. }
+        )
+    }
+
+    /// Returns the location in the program at which this error occurred, or None if the location
+    /// is not tracked for this error.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        use ExecutionError::*;
+
+        match self {
+            DivideByZero { context, .. }
+            | EventError { context, .. }
+            | FailedAssertion { context, .. }
+            | LocalCanaryOverwritten { context, .. }
+            | MemoryAddressOutOfBounds { context, .. }
+            | ReadOnlyMemoryWrite { context, .. } => context.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns this error with the location at which it occurred removed.
+    ///
+    /// This is useful for comparing errors when the exact location is not of interest.
+    pub fn without_context(mut self) -> Self {
+        use ExecutionError::*;
+
+        match &mut self {
+            DivideByZero { context, .. }
+            | EventError { context, .. }
+            | FailedAssertion { context, .. }
+            | LocalCanaryOverwritten { context, .. }
+            | MemoryAddressOutOfBounds { context, .. }
+            | ReadOnlyMemoryWrite { context, .. } => *context = None,
+            _ => (),
+        }
+        self
+    }
+
+    /// Attaches the location built by `build_context` to this error, unless the error does not
+    /// track its location or the location has already been set.
+    pub(crate) fn with_context<F>(mut self, build_context: F) -> Self
+    where
+        F: FnOnce() -> ErrorContext,
+    {
+        use ExecutionError::*;
+
+        match &mut self {
+            DivideByZero { context, .. }
+            | EventError { context, .. }
+            | FailedAssertion { context, .. }
+            | LocalCanaryOverwritten { context, .. }
+            | MemoryAddressOutOfBounds { context, .. }
+            | ReadOnlyMemoryWrite { context, .. }
+                if context.is_none() =>
+            {
+                *context = Some(Box::new(build_context()))
+            }
+            _ => (),
+        }
+        self
+    }
+
+    /// Converts an error returned by the host while handling the event with the specified ID into
+    /// an [ExecutionError::EventError] emitted at the specified clock cycle.
+    ///
+    /// If the host returned an [ExecutionError::EventError] already, the error is returned as is.
+    pub(crate) fn into_event_error(self, event_id: u32, clk: u32) -> Self {
+        match self {
+            ExecutionError::EventError { .. } => self,
+            err => ExecutionError::EventError {
+                event_id,
+                clk,
+                error: err.to_string(),
+                context: None,
+            },
+        }
+    }
 }
 
 impl Display for ExecutionError {
@@ -77,9 +226,14 @@ impl Display for ExecutionError {
                 write!(f, "Value for key {hex} not present in the advice map")
             }
             AdviceStackReadFailed(step) => write!(f, "Advice stack read failed at step {step}"),
+            CallInSyscall(proc) => {
+                let hex = to_hex(&proc.as_bytes())?;
+                write!(f, "Call to procedure with root {hex} cannot be made from a syscall")
+            }
             CallerNotInSyscall => {
                 write!(f, "Instruction `caller` used outside of kernel context")
             }
+            Cancelled => write!(f, "Execution was cancelled"),
             CodeBlockNotFound(digest) => {
                 let hex = to_hex(&digest.as_bytes())?;
                 write!(
@@ -90,7 +244,10 @@ impl Display for ExecutionError {
             CycleLimitExceeded(max_cycles) => {
                 write!(f, "Exceeded the allowed number of cycles (max cycles = {max_cycles})")
             }
-            DivideByZero(clk) => write!(f, "Division by zero at clock cycle {clk}"),
+            DivideByZero { clk, context } => {
+                write!(f, "Division by zero at clock cycle {clk}")?;
+                write_context(f, context)
+            }
             DynamicCodeBlockNotFound(digest) => {
                 let hex = to_hex(&digest.as_bytes())?;
                 write!(
@@ -98,21 +255,31 @@ impl Display for ExecutionError {
                     "Failed to execute the dynamic code block provided by the stack with root {hex}; the block could not be found"
                 )
             }
-            EventError(error) => write!(f, "Failed to process event - {error}"),
+            EventError {
+                event_id,
+                clk,
+                error,
+                context,
+            } => {
+                write!(f, "Failed to process event {event_id} at clock cycle {clk}: {error}")?;
+                write_context(f, context)
+            }
             Ext2InttError(err) => write!(f, "Failed to execute Ext2Intt operation: {err}"),
             FailedAssertion {
                 clk,
                 err_code,
                 err_msg,
+                context,
             } => {
                 if let Some(err_msg) = err_msg {
                     write!(
                         f,
                         "Assertion failed at clock cycle {clk} with error code {err_code}: {err_msg}"
-                    )
+                    )?;
                 } else {
-                    write!(f, "Assertion failed at clock cycle {clk} with error code {err_code}")
+                    write!(f, "Assertion failed at clock cycle {clk} with error code {err_code}")?;
                 }
+                write_context(f, context)
             }
             FailedSignatureGeneration(signature) => {
                 write!(f, "Failed to generate signature: {signature}")
@@ -132,9 +299,15 @@ impl Display for ExecutionError {
             } => {
                 write!(f, "Memory range start address cannot exceed end address, but was ({start_addr}, {end_addr})")
             }
+            InvalidMerklePathLength { depth, path_len } => {
+                write!(f, "Merkle path provided by the host must contain {depth} nodes, but contained {path_len}")
+            }
             InvalidStackDepthOnReturn(depth) => {
                 write!(f, "When returning from a call, stack depth must be {STACK_TOP_SIZE}, but was {depth}")
             }
+            InvalidStackState(error) => {
+                write!(f, "Stack state cannot be used to initialize the stack: {error}")
+            }
             InvalidStackWordOffset(offset) => {
                 write!(f, "Stack word offset cannot exceed 12, but was {offset}")
             }
@@ -144,6 +317,15 @@ impl Display for ExecutionError {
             InvalidTreeNodeIndex { depth, value } => {
                 write!(f, "The provided index {value} is out of bounds for a node at depth {depth}")
             }
+            LocalCanaryOverwritten {
+                addr,
+                clk,
+                write_clk,
+                context,
+            } => {
+                write!(f, "Canary word at memory address {addr} guarding procedure locals was overwritten at clock cycle {write_clk}; the overwrite was detected at clock cycle {clk}")?;
+                write_context(f, context)
+            }
             LogArgumentZero(clk) => {
                 write!(
                     f,
@@ -151,8 +333,9 @@ impl Display for ExecutionError {
                 )
             }
             MalformedSignatureKey(signature) => write!(f, "Malformed signature key: {signature}"),
-            MemoryAddressOutOfBounds(addr) => {
-                write!(f, "Memory address cannot exceed 2^32 but was {addr}")
+            MemoryAddressOutOfBounds { addr, context } => {
+                write!(f, "Memory address cannot exceed 2^32 but was {addr}")?;
+                write_context(f, context)
             }
             MerklePathVerificationFailed { value, index, root } => {
                 let value = to_hex(Felt::elements_as_bytes(value))?;
@@ -177,6 +360,16 @@ impl Display for ExecutionError {
                     "An operation expected a u32 value, but received {v} (error code: {err_code})"
                 )
             }
+            OverflowTableEvictionNotSupported => {
+                write!(f, "Host does not support eviction of stack overflow table rows")
+            }
+            OverflowTableRestoreFailed(key) => {
+                let hex = to_hex(Felt::elements_as_bytes(key))?;
+                write!(
+                    f,
+                    "Failed to restore evicted stack overflow table rows with commitment {hex}"
+                )
+            }
             SmtNodeNotFound(node) => {
                 let node_hex = to_hex(Felt::elements_as_bytes(node))?;
                 write!(f, "Smt node {node_hex} not found")
@@ -185,7 +378,16 @@ impl Display for ExecutionError {
                 let node_hex = to_hex(Felt::elements_as_bytes(node))?;
                 write!(f, "Invalid pre-image for node {node_hex}. Expected pre-image length to be a multiple of 8, but was {preimage_len}")
             }
+            ProgramHashMismatch { expected, actual } => {
+                let expected = to_hex(&expected.as_bytes())?;
+                let actual = to_hex(&actual.as_bytes())?;
+                write!(f, "Expected a program with hash {expected}, but the hash of the provided program is {actual}")
+            }
             ProverError(error) => write!(f, "Proof generation failed: {error}"),
+            ReadOnlyMemoryWrite { addr, context } => {
+                write!(f, "Memory address {addr} is read-only and cannot be written to")?;
+                write_context(f, context)
+            }
             SyscallTargetNotInKernel(proc) => {
                 let hex = to_hex(&proc.as_bytes())?;
                 write!(f, "Syscall failed: procedure with root {hex} was not found in the kernel")
@@ -193,6 +395,12 @@ impl Display for ExecutionError {
             UnexecutableCodeBlock(block) => {
                 write!(f, "Execution reached unexecutable code block {block:?}")
             }
+            UnexpectedHostResponse { expected, actual } => {
+                write!(f, "Host responded with {actual}, but {expected} was expected")
+            }
+            UnsupportedAdviceInjector(injector) => {
+                write!(f, "Advice injector {injector} is not supported by the host")
+            }
         }
     }
 }
@@ -206,6 +414,76 @@ impl From<Ext2InttError> for ExecutionError {
     }
 }
 
+/// Writes the location at which an error occurred (if it is known) to the formatter.
+fn write_context(f: &mut Formatter<'_>, context: &Option<Box<ErrorContext>>) -> core::fmt::Result {
+    match context {
+        Some(context) => write!(f, " ({context})"),
+        None => Ok(()),
+    }
+}
+
+// ERROR CONTEXT
+// ================================================================================================
+
+/// Location in the program at which an execution error occurred.
+///
+/// The location is identified by the MAST root of the span block which was being executed and
+/// the index of the failing operation within this block. If the program was compiled in debug
+/// mode, the assembly instruction from which the failing operation originated (together with the
+/// name of the procedure containing this instruction) is included as well.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    mast_root: Digest,
+    op_idx: usize,
+    asm_op: Option<AssemblyOp>,
+}
+
+impl ErrorContext {
+    /// Returns the context for an error which occurred while executing the operation at the
+    /// specified index of the provided span block.
+    pub(crate) fn new(block: &Span, op_idx: usize) -> Self {
+        // the instruction an operation originated from is the one described by the last AsmOp
+        // decorator preceding the operation
+        let asm_op = block.decorators().iter().rev().find_map(|(pos, decorator)| match decorator {
+            Decorator::AsmOp(asm_op) if *pos <= op_idx => Some(asm_op.clone()),
+            _ => None,
+        });
+
+        Self {
+            mast_root: block.hash(),
+            op_idx,
+            asm_op,
+        }
+    }
+
+    /// Returns the MAST root of the span block which was being executed when the error occurred.
+    pub fn mast_root(&self) -> Digest {
+        self.mast_root
+    }
+
+    /// Returns the index of the failing operation within its span block.
+    pub fn op_idx(&self) -> usize {
+        self.op_idx
+    }
+
+    /// Returns the assembly instruction from which the failing operation originated, or None if
+    /// the program was not compiled in debug mode.
+    pub fn asm_op(&self) -> Option<&AssemblyOp> {
+        self.asm_op.as_ref()
+    }
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let hex = to_hex(&self.mast_root.as_bytes())?;
+        write!(f, "operation {} of span block {hex}", self.op_idx)?;
+        if let Some(asm_op) = &self.asm_op {
+            write!(f, ", instruction `{}` in `{}`", asm_op.op(), asm_op.context_name())?;
+        }
+        Ok(())
+    }
+}
+
 // EXT2INTT ERROR
 // ================================================================================================
 
@@ -0,0 +1,723 @@
+//! Deterministic fuzzing harness for the processor.
+//!
+//! The harness turns arbitrary bytes (or a seed) into a sequence of operations which can always be
+//! decoded by the VM, executes the sequence, and checks a set of invariants which must hold for
+//! every execution which completed successfully:
+//! - Stack depth at every cycle matches the depth implied by the executed operations, both in the
+//!   debug state of the VM and in the stack depth column of the execution trace.
+//! - Execution in debug mode and in regular mode produces the same outcome.
+//! - Every memory read returns the last value written to the same address, and the memory chiplet
+//!   contains exactly one row per memory access.
+//! - All multiset checks of the auxiliary trace segment are balanced.
+//!
+//! Sequences which fail to execute are not considered invariant violations, as long as both
+//! execution modes fail in the same way.
+//!
+//! The harness can also generate complete programs (i.e., trees of control blocks together with
+//! their code block tables and kernels) which are not required to be well-formed. Such programs
+//! are used to check that the VM never panics, regardless of the program it executes: execution
+//! either completes or terminates with an [ExecutionError].
+//!
+//! `cargo-fuzz` targets driving this harness are located in the `fuzz` directory of this crate.
+
+use super::{
+    crypto::{RandomCoin, RpoRandomCoin},
+    execute, execute_iter, execute_recoverable,
+    trace::NUM_RAND_ROWS,
+    CodeBlockTable, DefaultHost, Digest, ExecutionError, ExecutionOptions, ExecutionOutcome,
+    ExecutionTrace, Felt, Kernel, Program, StackInputs, VmState, Word, MIN_TRACE_LEN, ONE, ZERO,
+};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::fmt;
+use miden_air::trace::{
+    stack::B0_COL_IDX, AUX_TRACE_RAND_ELEMENTS, CHIPLETS_AUX_TRACE_OFFSET, DECODER_AUX_TRACE_RANGE,
+    HASHER_AUX_TRACE_OFFSET, RANGE_CHECK_AUX_TRACE_OFFSET, STACK_TRACE_OFFSET,
+};
+use vm_core::{code_blocks::CodeBlock, stack::STACK_TOP_SIZE, Operation};
+use winter_prover::Trace;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of distinct operation snippets which can be generated by the harness.
+const NUM_SNIPPETS: u8 = 28;
+
+/// Number of distinct code block kinds which can be generated by the harness.
+const NUM_BLOCK_KINDS: u8 = 9;
+
+/// Maximum nesting depth of control blocks in generated programs.
+const MAX_BLOCK_DEPTH: usize = 6;
+
+/// Maximum number of procedures (i.e., targets of calls, syscalls, and dynamic calls) in a
+/// generated program.
+const MAX_PROCEDURES: usize = 16;
+
+/// Maximum number of cycles a generated program is allowed to execute; generated loops are not
+/// guaranteed to terminate.
+const MAX_CYCLES: u32 = 1 << 14;
+
+// OPERATION SEQUENCE GENERATION
+// ================================================================================================
+
+/// Returns a sequence of operations decoded from the provided bytes.
+///
+/// Each operation snippet is selected by a single byte; snippets which require an immediate value
+/// (i.e., a value to push onto the stack or a memory address) consume the next byte as well.
+/// Memory operations are always preceded by a push of their address. The returned sequence is
+/// never empty.
+pub fn ops_from_bytes(data: &[u8]) -> Vec<Operation> {
+    let mut ops = Vec::new();
+    let mut bytes = data.iter().copied();
+    while let Some(selector) = bytes.next() {
+        append_snippet(&mut ops, selector, || bytes.next().unwrap_or_default());
+    }
+
+    if ops.is_empty() {
+        ops.push(Operation::Noop);
+    }
+    ops
+}
+
+/// Returns a sequence of `num_snippets` operation snippets generated deterministically from the
+/// provided seed.
+pub fn ops_from_seed(seed: u64, num_snippets: usize) -> Vec<Operation> {
+    let mut state = seed;
+    let mut ops = Vec::new();
+    for _ in 0..num_snippets {
+        let selector = splitmix64(&mut state) as u8;
+        append_snippet(&mut ops, selector, || splitmix64(&mut state) as u8);
+    }
+
+    if ops.is_empty() {
+        ops.push(Operation::Noop);
+    }
+    ops
+}
+
+/// Appends the operation snippet specified by the selector to the provided list of operations.
+///
+/// Operations which fail for some inputs (e.g., u32 operations or inversion) are preceded by
+/// operations which bring their operands into the valid range, so that most of the generated
+/// sequences can be executed to completion.
+fn append_snippet(ops: &mut Vec<Operation>, selector: u8, mut imm: impl FnMut() -> u8) {
+    use Operation::*;
+
+    // reduces the top two stack elements to u32 values
+    const TO_U32: [Operation; 6] = [U32split, Drop, Swap, U32split, Drop, U32assert2(ZERO)];
+
+    let mut imm = || Felt::from(imm());
+    match selector % NUM_SNIPPETS {
+        0 => ops.push(Push(imm())),
+        1 => ops.push(Pad),
+        2 => ops.push(Drop),
+        3 => ops.push(Dup0),
+        4 => ops.push(Dup1),
+        5 => ops.push(Dup5),
+        6 => ops.push(Swap),
+        7 => ops.push(SwapW),
+        8 => ops.push(MovUp2),
+        9 => ops.push(MovDn3),
+        10 => ops.push(Add),
+        11 => ops.push(Mul),
+        12 => ops.push(Neg),
+        13 => ops.push(Incr),
+        14 => ops.extend([Dup0, Eqz, Add, Inv]),
+        15 => ops.push(Eq),
+        16 => ops.push(Eqz),
+        17 => ops.push(U32split),
+        18 => ops.extend(TO_U32.into_iter().chain([U32add])),
+        19 => ops.extend(TO_U32.into_iter().chain([U32and])),
+        20 => ops.extend(TO_U32.into_iter().chain([U32mul])),
+        21 => ops.extend([Eqz, CSwap]),
+        22 => ops.push(HPerm),
+        23 => ops.extend([Push(imm()), MStore]),
+        24 => ops.extend([Push(imm()), MLoad]),
+        25 => ops.extend([Push(imm()), MStoreW]),
+        26 => ops.extend([Push(imm()), MLoadW]),
+        _ => ops.push(Noop),
+    }
+}
+
+// PROGRAM GENERATION
+// ================================================================================================
+
+/// Returns a program decoded from the provided bytes.
+///
+/// The bytes select a tree of control blocks (joins, splits, loops, calls, syscalls, dynamic
+/// calls, and proxies) with span blocks at its leaves; span blocks consist of the same operation
+/// snippets as the sequences returned by [ops_from_bytes()]. Targets of calls, syscalls, and dynamic calls are added to the code
+/// block table of the program, and targets of syscalls are added to its kernel as well.
+///
+/// The returned program is not guaranteed to execute successfully: for example, a split block may
+/// be entered with a non-binary value on top of the stack, a loop may never terminate, or a proxy
+/// block may be reached.
+pub fn program_from_bytes(data: &[u8]) -> Program {
+    ProgramBuilder::new(data.iter().copied()).build()
+}
+
+/// Returns a program generated deterministically from the provided seed, using `len`
+/// pseudo-random bytes to select its blocks and operations.
+pub fn program_from_seed(seed: u64, len: usize) -> Program {
+    let mut state = seed;
+    ProgramBuilder::new((0..len).map(move |_| splitmix64(&mut state) as u8)).build()
+}
+
+/// Builds a program from a stream of bytes selecting its blocks and operations.
+struct ProgramBuilder<I: Iterator<Item = u8>> {
+    bytes: I,
+    cb_table: CodeBlockTable,
+    procedures: Vec<Digest>,
+    kernel: Vec<Digest>,
+}
+
+impl<I: Iterator<Item = u8>> ProgramBuilder<I> {
+    fn new(bytes: I) -> Self {
+        Self {
+            bytes,
+            cb_table: CodeBlockTable::default(),
+            procedures: Vec::new(),
+            kernel: Vec::new(),
+        }
+    }
+
+    fn build(mut self) -> Program {
+        let root = self.build_block(0);
+        let kernel = Kernel::new(&self.kernel).expect("kernel procedures are distinct");
+        Program::with_kernel(root, kernel, self.cb_table)
+    }
+
+    /// Returns the next byte, or zero if all bytes have been consumed.
+    fn next_byte(&mut self) -> u8 {
+        self.bytes.next().unwrap_or_default()
+    }
+
+    /// Builds a block at the specified nesting depth. Only span blocks are built once all bytes
+    /// have been consumed or the maximum nesting depth has been reached.
+    fn build_block(&mut self, depth: usize) -> CodeBlock {
+        let selector = match self.bytes.next() {
+            Some(selector) if depth < MAX_BLOCK_DEPTH => selector % NUM_BLOCK_KINDS,
+            _ => return self.build_span(),
+        };
+
+        match selector {
+            0 => self.build_span(),
+            1 => {
+                let first = self.build_block(depth + 1);
+                let second = self.build_block(depth + 1);
+                CodeBlock::new_join([first, second])
+            }
+            2 => {
+                let condition = self.build_condition();
+                let t_branch = self.build_block(depth + 1);
+                let f_branch = self.build_block(depth + 1);
+                CodeBlock::new_join([condition, CodeBlock::new_split(t_branch, f_branch)])
+            }
+            3 => {
+                let condition = self.build_condition();
+                let body = self.build_block(depth + 1);
+                let body = CodeBlock::new_join([body, self.build_condition()]);
+                CodeBlock::new_join([condition, CodeBlock::new_loop(body)])
+            }
+            4 => CodeBlock::new_call(self.build_procedure(depth)),
+            5 => {
+                let target = self.build_procedure(depth);
+                if !self.kernel.contains(&target) {
+                    self.kernel.push(target);
+                }
+                CodeBlock::new_syscall(target)
+            }
+            6 | 7 => {
+                // the hash of the target is pushed onto the stack for three out of four bytes;
+                // otherwise, an empty word, which is not the hash of any procedure, is pushed.
+                // the target is never taken from the values already on the stack since these may
+                // hold the hash of the enclosing procedure, which would make the program recurse
+                let target = self.build_procedure(depth);
+                let ops = match self.next_byte() % 4 {
+                    0 => vec![Operation::Pad; 4],
+                    _ => target.as_elements().iter().map(|&value| Operation::Push(value)).collect(),
+                };
+                let block = if selector == 6 {
+                    CodeBlock::new_dyn()
+                } else {
+                    CodeBlock::new_dyncall()
+                };
+                CodeBlock::new_join([CodeBlock::new_span(ops), block])
+            }
+            _ => CodeBlock::new_proxy(self.build_block(depth + 1).hash()),
+        }
+    }
+
+    /// Builds a span block consisting of up to 7 operation snippets.
+    fn build_span(&mut self) -> CodeBlock {
+        let num_snippets = self.next_byte() % 8;
+
+        let mut ops = Vec::new();
+        for _ in 0..num_snippets {
+            let selector = self.next_byte();
+            append_snippet(&mut ops, selector, || self.next_byte());
+        }
+
+        if ops.is_empty() {
+            ops.push(Operation::Noop);
+        }
+        CodeBlock::new_span(ops)
+    }
+
+    /// Builds a span block which pushes a condition for a split or a loop block onto the stack.
+    ///
+    /// The condition is zero for four out of eight bytes, one for three out of eight bytes, and a
+    /// non-binary value otherwise.
+    fn build_condition(&mut self) -> CodeBlock {
+        let condition = match self.next_byte() % 8 {
+            0..=3 => ZERO,
+            4..=6 => ONE,
+            _ => Felt::from(2_u8),
+        };
+        CodeBlock::new_span(vec![Operation::Push(condition)])
+    }
+
+    /// Returns the hash of a procedure which can be invoked from a block at the specified depth.
+    ///
+    /// For odd bytes, or once the maximum number of procedures has been reached, one of the
+    /// existing procedures is returned. Otherwise, a new procedure is built and added to the code
+    /// block table. Procedures can invoke only the procedures built before them, and thus, the
+    /// generated programs never recurse.
+    fn build_procedure(&mut self, depth: usize) -> Digest {
+        let selector = self.next_byte() as usize;
+        if !self.procedures.is_empty()
+            && (selector % 2 == 1 || self.procedures.len() >= MAX_PROCEDURES)
+        {
+            return self.procedures[selector % self.procedures.len()];
+        }
+
+        let body = self.build_block(depth + 1);
+        let hash = body.hash();
+        self.cb_table.insert(body);
+        if !self.procedures.contains(&hash) {
+            self.procedures.push(hash);
+        }
+        hash
+    }
+}
+
+// INVARIANT CHECKS
+// ================================================================================================
+
+/// Executes the provided sequence of operations as a single span block and checks that all
+/// invariants hold for the resulting execution.
+///
+/// # Errors
+/// Returns an error describing the first invariant which was violated.
+pub fn check_invariants(ops: &[Operation]) -> Result<(), InvariantViolation> {
+    let program = Program::new(CodeBlock::new_span(ops.to_vec()));
+
+    // execute the program in debug mode to get the state of the VM at every cycle, and in
+    // regular mode to get the execution trace
+    let states = execute_iter(&program, StackInputs::default(), DefaultHost::default())
+        .collect::<Result<Vec<_>, _>>();
+    let trace = execute(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    );
+
+    let (states, mut trace) = match (states, trace) {
+        (Ok(states), Ok(trace)) => (states, trace),
+        (Err(debug_err), Err(err)) if debug_err == err => return Ok(()),
+        (debug_result, result) => {
+            return Err(InvariantViolation::ExecutionModeMismatch {
+                debug_error: debug_result.err().map(Box::new),
+                error: result.err().map(Box::new),
+            })
+        }
+    };
+
+    check_stack_depth(&states, &trace)?;
+    check_memory(&states, &trace)?;
+    check_aux_buses(&mut trace)
+}
+
+/// Executes the provided program and checks that the outcome of the execution is consistent
+/// across the executors of the processor.
+///
+/// The program is executed via [execute()] and via [execute_recoverable()] with a limit of
+/// [MAX_CYCLES] cycles. Either both executions complete with the same outputs, or both terminate
+/// with the same error (in which case [execute_recoverable()] returns the error as is only if
+/// it is not recoverable). Neither execution is allowed to panic.
+///
+/// # Errors
+/// Returns an error if the outcomes of the two executions are inconsistent.
+pub fn check_program(program: &Program) -> Result<(), InvariantViolation> {
+    let options = ExecutionOptions::new(Some(MAX_CYCLES), MIN_TRACE_LEN as u32, false)
+        .expect("execution options are valid");
+
+    let trace = execute(program, StackInputs::default(), DefaultHost::default(), options);
+    let outcome =
+        execute_recoverable(program, StackInputs::default(), DefaultHost::default(), options);
+
+    match (trace, outcome) {
+        (Ok(trace), Ok(ExecutionOutcome::Completed(outputs)))
+            if *trace.stack_outputs() == outputs =>
+        {
+            Ok(())
+        }
+        (Err(err), Ok(ExecutionOutcome::Halted(state)))
+            if err.is_recoverable() && err == state.error =>
+        {
+            Ok(())
+        }
+        (Err(err), Err(outcome_err)) if !err.is_recoverable() && err == outcome_err => Ok(()),
+        (trace, outcome) => Err(InvariantViolation::RecoverableExecutionMismatch {
+            error: trace.err().map(Box::new),
+            outcome: Box::new(outcome),
+        }),
+    }
+}
+
+/// Checks that the stack depth at every cycle matches the depth implied by executed operations.
+fn check_stack_depth(states: &[VmState], trace: &ExecutionTrace) -> Result<(), InvariantViolation> {
+    let depth_column = trace.main_segment().get_column(STACK_TRACE_OFFSET + B0_COL_IDX);
+
+    let mut expected = STACK_TOP_SIZE;
+    for state in states {
+        if let Some(op) = state.op {
+            expected =
+                (expected as isize + stack_depth_delta(op)).max(STACK_TOP_SIZE as isize) as usize;
+        }
+
+        // the last rows of the trace are overwritten with random values, and thus, the depth
+        // recorded in the trace can be checked only for the cycles preceding them
+        let clk = state.clk;
+        let depth_in_trace = depth_column
+            .get(clk as usize)
+            .filter(|_| (clk as usize) < trace.length() - NUM_RAND_ROWS)
+            .map(|depth| depth.as_int() as usize);
+        for actual in [Some(state.stack.len()), depth_in_trace].into_iter().flatten() {
+            if actual != expected {
+                return Err(InvariantViolation::StackDepthMismatch {
+                    clk,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that every memory read returns the last value written to the same address, that the
+/// final state of memory matches all writes, and that the memory chiplet contains one row per
+/// memory access.
+fn check_memory(states: &[VmState], trace: &ExecutionTrace) -> Result<(), InvariantViolation> {
+    // memory words are kept in memory order (i.e., the reverse of stack order)
+    let mut memory = BTreeMap::<u64, Word>::new();
+    let mut num_accesses = 0;
+
+    for (prev, state) in states.iter().zip(states.iter().skip(1)) {
+        let Some(op) = state.op else { continue };
+        let addr = prev.stack[0].as_int();
+        let word = memory.entry(addr).or_insert([ZERO; 4]);
+
+        match op {
+            Operation::MStore => word[0] = prev.stack[1],
+            Operation::MStoreW => {
+                *word = [prev.stack[4], prev.stack[3], prev.stack[2], prev.stack[1]];
+            }
+            Operation::MLoad | Operation::MLoadW => {
+                let actual = if op == Operation::MLoad {
+                    [state.stack[0], word[1], word[2], word[3]]
+                } else {
+                    [state.stack[3], state.stack[2], state.stack[1], state.stack[0]]
+                };
+                if actual != *word {
+                    return Err(InvariantViolation::MemoryMismatch {
+                        clk: state.clk,
+                        addr,
+                        expected: *word,
+                        actual,
+                    });
+                }
+            }
+            _ => continue,
+        }
+        num_accesses += 1;
+    }
+
+    // make sure the final state of memory reported by the VM matches the model
+    let final_state = states.last().expect("no VM states");
+    for (&addr, &expected) in memory.iter() {
+        let actual = final_state
+            .memory
+            .iter()
+            .find(|(a, _)| *a == addr)
+            .map_or([ZERO; 4], |(_, word)| *word);
+        if actual != expected {
+            return Err(InvariantViolation::MemoryMismatch {
+                clk: final_state.clk,
+                addr,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    let num_rows = trace.trace_len_summary().chiplets_trace_len().memory_chiplet_len();
+    if num_rows != num_accesses {
+        return Err(InvariantViolation::MemoryChipletLengthMismatch {
+            expected: num_accesses,
+            actual: num_rows,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the multiset checks of the decoder, the range checker, the hasher, and the chiplets
+/// bus are balanced.
+fn check_aux_buses(trace: &mut ExecutionTrace) -> Result<(), InvariantViolation> {
+    let mut rng = RpoRandomCoin::new(trace.program_hash().into());
+    let rand_elements = (0..AUX_TRACE_RAND_ELEMENTS)
+        .map(|_| rng.draw().expect("failed to draw a random value"))
+        .collect::<Vec<Felt>>();
+    let aux_segment = trace
+        .build_aux_segment(&[], &rand_elements)
+        .expect("failed to build aux segment");
+
+    let last_row = trace.length() - NUM_RAND_ROWS - 1;
+    let expected_values = DECODER_AUX_TRACE_RANGE.map(|column| (column, ONE)).chain([
+        (RANGE_CHECK_AUX_TRACE_OFFSET, ONE),
+        (HASHER_AUX_TRACE_OFFSET, ONE),
+        (CHIPLETS_AUX_TRACE_OFFSET, ONE),
+    ]);
+
+    for (column, expected) in expected_values {
+        let actual = aux_segment.get_column(column)[last_row];
+        if actual != expected {
+            return Err(InvariantViolation::UnbalancedAuxColumn { column, actual });
+        }
+    }
+
+    Ok(())
+}
+
+// INVARIANT VIOLATION
+// ================================================================================================
+
+/// An invariant which was violated during execution of a sequence of operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    ExecutionModeMismatch {
+        debug_error: Option<Box<ExecutionError>>,
+        error: Option<Box<ExecutionError>>,
+    },
+    MemoryChipletLengthMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    MemoryMismatch {
+        clk: u32,
+        addr: u64,
+        expected: Word,
+        actual: Word,
+    },
+    RecoverableExecutionMismatch {
+        error: Option<Box<ExecutionError>>,
+        outcome: Box<Result<ExecutionOutcome, ExecutionError>>,
+    },
+    StackDepthMismatch {
+        clk: u32,
+        expected: usize,
+        actual: usize,
+    },
+    UnbalancedAuxColumn {
+        column: usize,
+        actual: Felt,
+    },
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use InvariantViolation::*;
+
+        match self {
+            ExecutionModeMismatch { debug_error, error } => {
+                write!(f, "Execution in debug mode resulted in {debug_error:?}, but execution in regular mode resulted in {error:?}")
+            }
+            MemoryChipletLengthMismatch { expected, actual } => {
+                write!(f, "Memory chiplet contains {actual} rows, but {expected} memory accesses were made")
+            }
+            MemoryMismatch {
+                clk,
+                addr,
+                expected,
+                actual,
+            } => {
+                write!(f, "Memory at address {addr} at clock cycle {clk} contains {actual:?}, but {expected:?} was expected")
+            }
+            RecoverableExecutionMismatch { error, outcome } => {
+                write!(f, "Execution resulted in {error:?}, but recoverable execution resulted in {outcome:?}")
+            }
+            StackDepthMismatch {
+                clk,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Stack depth at clock cycle {clk} is {actual}, but {expected} was expected"
+                )
+            }
+            UnbalancedAuxColumn { column, actual } => {
+                write!(f, "Auxiliary column {column} is not balanced; its final value is {actual}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvariantViolation {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the change of the stack depth caused by executing the specified operation.
+///
+/// This is defined only for the operations which can be generated by the harness, as well as for
+/// the operations the VM executes to decode a span block.
+fn stack_depth_delta(op: Operation) -> isize {
+    match op {
+        Operation::Push(_)
+        | Operation::Pad
+        | Operation::Dup0
+        | Operation::Dup1
+        | Operation::Dup5
+        | Operation::U32split => 1,
+        Operation::Drop
+        | Operation::Add
+        | Operation::Mul
+        | Operation::Eq
+        | Operation::U32and
+        | Operation::CSwap
+        | Operation::MStore
+        | Operation::MStoreW
+        | Operation::MLoadW => -1,
+        _ => 0,
+    }
+}
+
+/// Advances the state of the SplitMix64 generator and returns the next pseudo-random value.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_invariants, check_program, ops_from_bytes, ops_from_seed, program_from_bytes,
+        program_from_seed, CodeBlock, Operation, Program,
+    };
+
+    #[test]
+    fn random_sequences_satisfy_invariants() {
+        // sequences of different lengths span different numbers of operation batches
+        for seed in 0..64 {
+            let ops = ops_from_seed(seed, 16 + seed as usize * 4);
+            if let Err(violation) = check_invariants(&ops) {
+                panic!("seed {seed} violated an invariant: {violation}");
+            }
+        }
+    }
+
+    #[test]
+    fn memory_round_trip_satisfies_invariants() {
+        // push 7 at address 3, then load it back
+        let ops = ops_from_bytes(&[0, 7, 23, 3, 24, 3, 26, 3, 25, 4, 26, 4]);
+        assert_eq!(
+            ops[..3],
+            [Operation::Push(7_u32.into()), Operation::Push(3_u32.into()), Operation::MStore]
+        );
+        assert_eq!(Ok(()), check_invariants(&ops));
+    }
+
+    #[test]
+    fn failed_execution_is_not_a_violation() {
+        // loading from an invalid memory address fails in both execution modes
+        let ops = [Operation::Push(1_u32.into()), Operation::Neg, Operation::MLoad];
+        assert_eq!(Ok(()), check_invariants(&ops));
+    }
+
+    #[test]
+    fn empty_input_is_decodable() {
+        assert_eq!(ops_from_bytes(&[]), [Operation::Noop]);
+        assert_eq!(ops_from_seed(0, 0), [Operation::Noop]);
+    }
+
+    #[test]
+    fn seeded_generation_is_deterministic() {
+        assert_eq!(ops_from_seed(42, 16), ops_from_seed(42, 16));
+        assert_ne!(ops_from_seed(42, 16), ops_from_seed(43, 16));
+    }
+
+    #[test]
+    fn random_programs_do_not_panic() {
+        // longer byte sequences result in deeper block trees and more procedures
+        for seed in 0..64 {
+            let program = program_from_seed(seed, 32 + seed as usize * 8);
+            if let Err(violation) = check_program(&program) {
+                panic!("seed {seed} violated an invariant: {violation}");
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_programs_fail_consistently() {
+        // a proxy block cannot be executed
+        let program = program_from_bytes(&[8, 0]);
+        assert!(matches!(program.root(), CodeBlock::Proxy(_)));
+        assert_eq!(Ok(()), check_program(&program));
+
+        // a loop whose body always pushes one onto the stack never terminates
+        let program = program_from_bytes(&[3, 4, 0, 0, 4]);
+        assert_eq!(Ok(()), check_program(&program));
+
+        // a split block entered with a non-binary value on top of the stack
+        let program = program_from_bytes(&[2, 7]);
+        assert_eq!(Ok(()), check_program(&program));
+    }
+
+    #[test]
+    fn procedures_are_added_to_program() {
+        // a syscall to a procedure which increments the top of the stack
+        let program = program_from_bytes(&[5, 0, 0, 1, 13]);
+        assert_eq!(1, program.kernel().proc_hashes().len());
+        assert!(program.cb_table().has(program.kernel().proc_hashes()[0]));
+        assert_eq!(Ok(()), check_program(&program));
+
+        // a dynamic call to the same procedure, whose hash is pushed onto the stack
+        let program = program_from_bytes(&[7, 0, 0, 1, 13, 1]);
+        assert!(program.kernel().is_empty());
+        assert_eq!(Ok(()), check_program(&program));
+    }
+
+    #[test]
+    fn empty_input_is_decodable_as_program() {
+        let expected = Program::new(CodeBlock::new_span(vec![Operation::Noop]));
+        assert_eq!(expected.hash(), program_from_bytes(&[]).hash());
+        assert_eq!(expected.hash(), program_from_seed(0, 0).hash());
+    }
+
+    #[test]
+    fn seeded_program_generation_is_deterministic() {
+        assert_eq!(program_from_seed(42, 64).hash(), program_from_seed(42, 64).hash());
+        assert_ne!(program_from_seed(42, 64).hash(), program_from_seed(43, 64).hash());
+    }
+}
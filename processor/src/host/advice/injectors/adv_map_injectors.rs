@@ -171,10 +171,16 @@ fn get_mem_addr_range<S: ProcessState>(
     let end_addr = process.get_stack_item(end_idx).as_int();
 
     if start_addr > u32::MAX as u64 {
-        return Err(ExecutionError::MemoryAddressOutOfBounds(start_addr));
+        return Err(ExecutionError::MemoryAddressOutOfBounds {
+            addr: start_addr,
+            context: None,
+        });
     }
     if end_addr > u32::MAX as u64 {
-        return Err(ExecutionError::MemoryAddressOutOfBounds(end_addr));
+        return Err(ExecutionError::MemoryAddressOutOfBounds {
+            addr: end_addr,
+            context: None,
+        });
     }
 
     if start_addr > end_addr {
@@ -1,4 +1,5 @@
 use super::super::{AdviceSource, ExecutionError, Felt, HostResponse};
+use super::foreign_field;
 use crate::{AdviceProvider, Ext2InttError, FieldElement, ProcessState, ZERO};
 use alloc::vec::Vec;
 use vm_core::{QuadExtension, SignatureKind};
@@ -129,7 +130,10 @@ pub(crate) fn push_u64_div_result<S: ProcessState, A: AdviceProvider>(
     let divisor = (divisor_hi << 32) + divisor_lo;
 
     if divisor == 0 {
-        return Err(ExecutionError::DivideByZero(process.clk()));
+        return Err(ExecutionError::DivideByZero {
+            clk: process.clk(),
+            context: None,
+        });
     }
 
     let dividend_hi = process.get_stack_item(2).as_int();
@@ -150,6 +154,88 @@ pub(crate) fn push_u64_div_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes the result of u256 division (both the quotient and the remainder) onto the advice
+/// stack.
+///
+/// Inputs:
+///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+///   Advice stack: [q0, q1, q2, q3, q4, q5, q6, q7, r0, r1, r2, r3, r4, r5, r6, r7, ...]
+///
+/// Where a[0..8] and b[0..8] are the 32-bit limbs of the dividend and the divisor respectively
+/// (with a0 and b0 being the least significant limbs). Similarly, q[0..8] and r[0..8] are the
+/// limbs of the quotient and the remainder respectively.
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the top 16 stack elements is not a u32 value.
+/// - The divisor is ZERO.
+pub(crate) fn push_u256_div_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let divisor = get_u256_limbs(process, 0)?;
+    if divisor == [0; 8] {
+        return Err(ExecutionError::DivideByZero {
+            clk: process.clk(),
+            context: None,
+        });
+    }
+    let dividend = get_u256_limbs(process, 8)?;
+
+    let (quotient, remainder) = foreign_field::div_rem(dividend, divisor);
+
+    for limb in remainder.into_iter().rev().chain(quotient.into_iter().rev()) {
+        advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the multiplicative inverse of a u256 value modulo a u256 modulus onto the advice
+/// stack.
+///
+/// Inputs:
+///   Operand stack: [m7, m6, m5, m4, m3, m2, m1, m0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [m7, m6, m5, m4, m3, m2, m1, m0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+///   Advice stack: [b0, b1, b2, b3, b4, b5, b6, b7, ...]
+///
+/// Where a[0..8], m[0..8], and b[0..8] are the 32-bit limbs of the input, the modulus, and the
+/// inverse of the input modulo m respectively (with a0, m0, and b0 being the least significant
+/// limbs). If the input has no inverse modulo m, the pushed result is zero.
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the top 16 stack elements is not a u32 value.
+/// - The modulus is ZERO.
+pub(crate) fn push_u256_mod_inv<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let modulus = get_u256_limbs(process, 0)?;
+    if modulus == [0; 8] {
+        return Err(ExecutionError::DivideByZero {
+            clk: process.clk(),
+            context: None,
+        });
+    }
+    let value = get_u256_limbs(process, 8)?;
+
+    let inverse = foreign_field::mod_inv(value, modulus).unwrap_or([0; 8]);
+
+    for limb in inverse.into_iter().rev() {
+        advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
 /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
 /// computes its multiplicative inverse and push the result onto the advice stack.
 ///
@@ -175,7 +261,10 @@ pub(crate) fn push_ext2_inv_result<S: ProcessState, A: AdviceProvider>(
 
     let element = QuadFelt::new(coef0, coef1);
     if element == QuadFelt::ZERO {
-        return Err(ExecutionError::DivideByZero(process.clk()));
+        return Err(ExecutionError::DivideByZero {
+            clk: process.clk(),
+            context: None,
+        });
     }
     let result = element.inv().to_base_elements();
 
@@ -297,6 +386,106 @@ pub(crate) fn push_signature<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Given an element of the secp256k1 base field in Montgomery form on the top of the stack,
+/// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto the
+/// advice stack.
+///
+/// Inputs:
+///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+///
+/// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+/// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed result
+/// is also zero.
+///
+/// # Errors
+/// Returns an error if any of the top 8 stack elements is not a u32 value.
+pub(crate) fn push_secp256k1_base_inv<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    push_mont_inv(advice_provider, process, &foreign_field::SECP256K1_BASE_FIELD)
+}
+
+/// Given an element of the secp256k1 scalar field in Montgomery form on the top of the stack,
+/// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto the
+/// advice stack.
+///
+/// Inputs:
+///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+///
+/// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+/// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed result
+/// is also zero.
+///
+/// # Errors
+/// Returns an error if any of the top 8 stack elements is not a u32 value.
+pub(crate) fn push_secp256k1_scalar_inv<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    push_mont_inv(advice_provider, process, &foreign_field::SECP256K1_SCALAR_FIELD)
+}
+
+/// Given an element of the secp256r1 base field in Montgomery form on the top of the stack,
+/// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto the
+/// advice stack.
+///
+/// Inputs:
+///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+///
+/// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+/// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed result
+/// is also zero.
+///
+/// # Errors
+/// Returns an error if any of the top 8 stack elements is not a u32 value.
+pub(crate) fn push_secp256r1_base_inv<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    push_mont_inv(advice_provider, process, &foreign_field::SECP256R1_BASE_FIELD)
+}
+
+/// Given an element of the secp256r1 scalar field in Montgomery form on the top of the stack,
+/// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto the
+/// advice stack.
+///
+/// Inputs:
+///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+///
+/// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+/// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed result
+/// is also zero.
+///
+/// # Errors
+/// Returns an error if any of the top 8 stack elements is not a u32 value.
+pub(crate) fn push_secp256r1_scalar_inv<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    push_mont_inv(advice_provider, process, &foreign_field::SECP256R1_SCALAR_FIELD)
+}
+
 /// Pushes the number of the leading zeros of the top stack element onto the advice stack.
 ///
 /// Inputs:
@@ -418,3 +607,42 @@ fn push_transformed_stack_top<S: ProcessState, A: AdviceProvider>(
     advice_provider.push_stack(AdviceSource::Value(transformed_stack_top))?;
     Ok(HostResponse::None)
 }
+
+/// Reads a 256-bit integer from 8 stack elements starting at the specified position, with the
+/// most significant limb being the closest to the top of the stack, and returns its 32-bit limbs
+/// in little-endian order.
+fn get_u256_limbs<S: ProcessState>(process: &S, start: usize) -> Result<[u32; 8], ExecutionError> {
+    let mut limbs = [0u32; 8];
+    for (i, limb) in limbs.iter_mut().rev().enumerate() {
+        let value = process.get_stack_item(start + i);
+        *limb = value
+            .as_int()
+            .try_into()
+            .map_err(|_| ExecutionError::NotU32Value(value, ZERO))?;
+    }
+    Ok(limbs)
+}
+
+/// Reads a 256-bit field element from the top 8 stack elements, computes its Montgomery inverse
+/// in the specified prime field and pushes the result onto the advice stack such that the least
+/// significant limb can be popped last.
+fn push_mont_inv<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    field: &foreign_field::MontgomeryField,
+) -> Result<HostResponse, ExecutionError> {
+    let mut limbs = [0u32; 8];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let value = process.get_stack_item(i);
+        *limb = value
+            .as_int()
+            .try_into()
+            .map_err(|_| ExecutionError::NotU32Value(value, ZERO))?;
+    }
+
+    let result = foreign_field::mont_inv(limbs, field);
+    for limb in result {
+        advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+    }
+    Ok(HostResponse::None)
+}
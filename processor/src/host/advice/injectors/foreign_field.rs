@@ -0,0 +1,409 @@
+use core::cmp::Ordering;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Prime modulus of the secp256k1 base field (p = 2^256 - 2^32 - 977), as 64-bit limbs in
+/// little-endian order.
+pub const SECP256K1_BASE_FIELD_MODULUS: U256 =
+    [0xFFFFFFFEFFFFFC2F, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+
+/// Order of the secp256k1 group (i.e., the prime modulus of the scalar field), as 64-bit limbs in
+/// little-endian order.
+pub const SECP256K1_SCALAR_FIELD_MODULUS: U256 =
+    [0xBFD25E8CD0364141, 0xBAAEDCE6AF48A03B, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF];
+
+/// Prime modulus of the secp256r1 base field (p = 2^256 - 2^224 + 2^192 + 2^96 - 1), as 64-bit
+/// limbs in little-endian order.
+pub const SECP256R1_BASE_FIELD_MODULUS: U256 =
+    [0xFFFFFFFFFFFFFFFF, 0x00000000FFFFFFFF, 0x0000000000000000, 0xFFFFFFFF00000001];
+
+/// Order of the secp256r1 group (i.e., the prime modulus of the scalar field), as 64-bit limbs in
+/// little-endian order.
+pub const SECP256R1_SCALAR_FIELD_MODULUS: U256 =
+    [0xF3B9CAC2FC632551, 0xBCE6FAADA7179E84, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFF00000000];
+
+/// Montgomery arithmetic parameters of the secp256k1 base field.
+pub const SECP256K1_BASE_FIELD: MontgomeryField = MontgomeryField {
+    modulus: SECP256K1_BASE_FIELD_MODULUS,
+    r2: [0x000007A2000E90A1, 0x0000000000000001, 0x0000000000000000, 0x0000000000000000],
+    p_inv: 0xD838091DD2253531,
+};
+
+/// Montgomery arithmetic parameters of the secp256k1 scalar field.
+pub const SECP256K1_SCALAR_FIELD: MontgomeryField = MontgomeryField {
+    modulus: SECP256K1_SCALAR_FIELD_MODULUS,
+    r2: [0x896CF21467D7D140, 0x741496C20E7CF878, 0xE697F5E45BCD07C6, 0x9D671CD581C69BC5],
+    p_inv: 0x4B0DFF665588B13F,
+};
+
+/// Montgomery arithmetic parameters of the secp256r1 base field.
+pub const SECP256R1_BASE_FIELD: MontgomeryField = MontgomeryField {
+    modulus: SECP256R1_BASE_FIELD_MODULUS,
+    r2: [0x0000000000000003, 0xFFFFFFFBFFFFFFFF, 0xFFFFFFFFFFFFFFFE, 0x00000004FFFFFFFD],
+    p_inv: 0x0000000000000001,
+};
+
+/// Montgomery arithmetic parameters of the secp256r1 scalar field.
+pub const SECP256R1_SCALAR_FIELD: MontgomeryField = MontgomeryField {
+    modulus: SECP256R1_SCALAR_FIELD_MODULUS,
+    r2: [0x83244C95BE79EEA2, 0x4699799C49BD6FA6, 0x2845B2392B6BEC59, 0x66E12D94F3D95620],
+    p_inv: 0xCCD1C8AAEE00BC4F,
+};
+
+// TYPE ALIASES
+// ================================================================================================
+
+/// A 256-bit unsigned integer represented by four 64-bit limbs in little-endian order.
+type U256 = [u64; 4];
+
+// MONTGOMERY FIELD
+// ================================================================================================
+
+/// Parameters of a prime field needed for Montgomery arithmetic with R = 2^256.
+pub struct MontgomeryField {
+    /// Prime modulus p of the field; must be greater than 2^255.
+    modulus: U256,
+    /// R^2 mod p.
+    r2: U256,
+    /// -p^-1 mod 2^64.
+    p_inv: u64,
+}
+
+// INVERSION
+// ================================================================================================
+
+/// Computes the multiplicative inverse of a field element in Montgomery form (with R = 2^256)
+/// modulo the specified prime and returns the result in Montgomery form.
+///
+/// Both the input and the output are represented as eight 32-bit limbs in little-endian order.
+/// The input is not required to be fully reduced, but must be smaller than 2^256. If the input is
+/// congruent to zero, zero is returned.
+///
+/// The inverse is computed via Fermat's little theorem using Montgomery multiplication: given
+/// a = x * R, exponentiation in Montgomery form yields x^(p - 2) * R = x^-1 * R.
+pub fn mont_inv(a: [u32; 8], field: &MontgomeryField) -> [u32; 8] {
+    let a = reduce(from_u32_limbs(a), &field.modulus);
+
+    let mut exp = field.modulus;
+    sub_assign(&mut exp, &[2, 0, 0, 0]);
+    to_u32_limbs(mont_pow(&a, &exp, field))
+}
+
+/// Computes the multiplicative inverse of a modulo the specified modulus using the extended
+/// Euclidean algorithm, or returns None if a and the modulus are not coprime.
+///
+/// All values are represented as eight 32-bit limbs in little-endian order. The input is not
+/// required to be reduced modulo the modulus, which must not be zero.
+pub fn mod_inv(a: [u32; 8], modulus: [u32; 8]) -> Option<[u32; 8]> {
+    let one = [1, 0, 0, 0, 0, 0, 0, 0];
+    let m = from_u32_limbs(modulus);
+
+    // the invariant r_i = t_i * a (mod m) holds for both pairs, with t_i kept reduced modulo m
+    let (mut r0, mut r1) = (modulus, div_rem(a, modulus).1);
+    let (mut t0, mut t1) = ([0; 4], from_u32_limbs(div_rem(one, modulus).1));
+    while r1 != [0; 8] {
+        let (q, r) = div_rem(r0, r1);
+        let t = sub_mod(&t0, &mul_mod(&t1, &from_u32_limbs(q), &m), &m);
+        (r0, r1) = (r1, r);
+        (t0, t1) = (t1, t);
+    }
+
+    (r0 == one).then(|| to_u32_limbs(t0))
+}
+
+// DIVISION
+// ================================================================================================
+
+/// Computes the quotient and the remainder of dividing a by b using binary long division.
+///
+/// All values are represented as eight 32-bit limbs in little-endian order. The divisor must not
+/// be zero.
+pub fn div_rem(a: [u32; 8], b: [u32; 8]) -> ([u32; 8], [u32; 8]) {
+    let a = from_u32_limbs(a);
+    let b = from_u32_limbs(b);
+    debug_assert_ne!(b, [0; 4], "division by zero");
+
+    let mut quotient = [0u64; 4];
+    let mut remainder = [0u64; 4];
+    for i in (0..256).rev() {
+        // the remainder is smaller than b here, so if doubling it overflows, it is larger than b
+        let addend = remainder;
+        let carry = add_assign(&mut remainder, &addend);
+        remainder[0] |= (a[i / 64] >> (i % 64)) & 1;
+        if carry || cmp(&remainder, &b) != Ordering::Less {
+            sub_assign(&mut remainder, &b);
+            quotient[i / 64] |= 1 << (i % 64);
+        }
+    }
+
+    (to_u32_limbs(quotient), to_u32_limbs(remainder))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn from_u32_limbs(limbs: [u32; 8]) -> U256 {
+    let mut result = [0u64; 4];
+    for (i, limb) in result.iter_mut().enumerate() {
+        *limb = (limbs[2 * i] as u64) | ((limbs[2 * i + 1] as u64) << 32);
+    }
+    result
+}
+
+fn to_u32_limbs(value: U256) -> [u32; 8] {
+    let mut result = [0u32; 8];
+    for (i, limb) in value.iter().enumerate() {
+        result[2 * i] = *limb as u32;
+        result[2 * i + 1] = (*limb >> 32) as u32;
+    }
+    result
+}
+
+fn cmp(a: &U256, b: &U256) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Computes a += b and returns the carry.
+fn add_assign(a: &mut U256, b: &U256) -> bool {
+    let mut carry = false;
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let (sum, c1) = x.overflowing_add(*y);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        *x = sum;
+        carry = c1 || c2;
+    }
+    carry
+}
+
+/// Computes a -= b, wrapping around on underflow.
+fn sub_assign(a: &mut U256, b: &U256) {
+    let mut borrow = false;
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let (diff, b1) = x.overflowing_sub(*y);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        *x = diff;
+        borrow = b1 || b2;
+    }
+}
+
+/// Reduces a value smaller than 2^256 modulo the specified prime; this requires the modulus to be
+/// greater than 2^255.
+fn reduce(mut a: U256, modulus: &U256) -> U256 {
+    if cmp(&a, modulus) != Ordering::Less {
+        sub_assign(&mut a, modulus);
+    }
+    a
+}
+
+/// Computes (a + b) mod p for a, b < p.
+fn add_mod(a: &U256, b: &U256, modulus: &U256) -> U256 {
+    let mut result = *a;
+    let carry = add_assign(&mut result, b);
+    if carry || cmp(&result, modulus) != Ordering::Less {
+        sub_assign(&mut result, modulus);
+    }
+    result
+}
+
+/// Computes (a - b) mod p for a, b < p.
+fn sub_mod(a: &U256, b: &U256, modulus: &U256) -> U256 {
+    let mut result = *a;
+    if cmp(a, b) == Ordering::Less {
+        // the sum may overflow, but the final result is smaller than p
+        add_assign(&mut result, modulus);
+    }
+    sub_assign(&mut result, b);
+    result
+}
+
+/// Computes (a * b) mod m for a < m using double-and-add.
+///
+/// Unlike [mont_mul()], this works for any modulus, including even ones.
+fn mul_mod(a: &U256, b: &U256, modulus: &U256) -> U256 {
+    let mut result = [0u64; 4];
+    for i in (0..256).rev() {
+        result = add_mod(&result, &result, modulus);
+        if (b[i / 64] >> (i % 64)) & 1 == 1 {
+            result = add_mod(&result, a, modulus);
+        }
+    }
+    result
+}
+
+/// Computes a * b * R^-1 mod p for a, b < p using the CIOS method.
+fn mont_mul(a: &U256, b: &U256, field: &MontgomeryField) -> U256 {
+    let p = &field.modulus;
+    let mut t = [0u64; 6];
+    for &b_i in b.iter() {
+        // t += a * b_i
+        let mut carry = 0;
+        for j in 0..4 {
+            (t[j], carry) = mac(t[j], a[j], b_i, carry);
+        }
+        (t[4], t[5]) = mac(t[4], 1, carry, 0);
+
+        // t = (t + m * p) / 2^64, where m is chosen such that the lowest limb of t + m * p is zero
+        let m = t[0].wrapping_mul(field.p_inv);
+        let (_, mut carry) = mac(t[0], m, p[0], 0);
+        for j in 1..4 {
+            (t[j - 1], carry) = mac(t[j], m, p[j], carry);
+        }
+        (t[3], carry) = mac(t[4], 1, carry, 0);
+        t[4] = t[5] + carry;
+    }
+
+    // t < 2p here, so a single subtraction is enough to reduce the result
+    let mut result = [t[0], t[1], t[2], t[3]];
+    if t[4] != 0 || cmp(&result, p) != Ordering::Less {
+        sub_assign(&mut result, p);
+    }
+    result
+}
+
+/// Computes (base ^ exp) * R^(1 - exp) mod p for base < p using square-and-multiply in
+/// Montgomery form, i.e., if base is the Montgomery form of x, returns the Montgomery form of
+/// x^exp.
+fn mont_pow(base: &U256, exp: &U256, field: &MontgomeryField) -> U256 {
+    // Montgomery form of 1, i.e., R mod p = R^2 * R^-1 mod p
+    let mut result = mont_mul(&field.r2, &[1, 0, 0, 0], field);
+    for i in (0..256).rev() {
+        result = mont_mul(&result, &result, field);
+        if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+            result = mont_mul(&result, base, field);
+        }
+    }
+    result
+}
+
+/// Computes a + b * c + carry and returns the result as (low, high) 64-bit limbs.
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let result = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (result as u64, (result >> 64) as u64)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_mod, div_rem, mod_inv, mont_inv, mont_mul, mul_mod, to_u32_limbs, MontgomeryField,
+        SECP256K1_BASE_FIELD, SECP256K1_SCALAR_FIELD, SECP256R1_BASE_FIELD, SECP256R1_SCALAR_FIELD,
+    };
+
+    const FIELDS: [&MontgomeryField; 4] = [
+        &SECP256K1_BASE_FIELD,
+        &SECP256K1_SCALAR_FIELD,
+        &SECP256R1_BASE_FIELD,
+        &SECP256R1_SCALAR_FIELD,
+    ];
+
+    #[test]
+    fn montgomery_constants() {
+        for field in FIELDS {
+            // R^2 mod p = 2^512 mod p
+            let mut r2 = [1, 0, 0, 0];
+            for _ in 0..512 {
+                r2 = add_mod(&r2, &r2, &field.modulus);
+            }
+            assert_eq!(r2, field.r2);
+
+            // p * p_inv = -1 (mod 2^64)
+            assert_eq!(u64::MAX, field.modulus[0].wrapping_mul(field.p_inv));
+        }
+    }
+
+    #[test]
+    fn mont_mul_matches_mul_mod() {
+        let a = [0x0123456789ABCDEF, 0xFEDCBA9876543210, 0x0F1E2D3C4B5A6978, 0x7766554433221100];
+        let b = [u64::MAX - 5, u64::MAX - 7, u64::MAX - 11, 0x7FFFFFFFFFFFFFFF];
+        for field in FIELDS {
+            // (a * R) * (b * R) * R^-1 = (a * b) * R
+            let to_mont = |x| mont_mul(&x, &field.r2, field);
+            let expected = to_mont(mul_mod(&a, &b, &field.modulus));
+            assert_eq!(expected, mont_mul(&to_mont(a), &to_mont(b), field));
+        }
+    }
+
+    #[test]
+    fn mont_inv_of_one() {
+        // Montgomery form of 1 is R mod p; its inverse is also 1
+        let one = [977, 1, 0, 0, 0, 0, 0, 0];
+        assert_eq!(one, mont_inv(one, &SECP256K1_BASE_FIELD));
+
+        let one = [801750719, 1076732275, 1354194884, 1162945305, 1, 0, 0, 0];
+        assert_eq!(one, mont_inv(one, &SECP256K1_SCALAR_FIELD));
+
+        let one = [1, 0, 0, 4294967295, 4294967295, 4294967295, 4294967294, 0];
+        assert_eq!(one, mont_inv(one, &SECP256R1_BASE_FIELD));
+
+        let one = [60611247, 205927741, 1491624315, 1125713234, 0, 0, 4294967295, 0];
+        assert_eq!(one, mont_inv(one, &SECP256R1_SCALAR_FIELD));
+    }
+
+    #[test]
+    fn mont_inv_of_zero() {
+        for field in FIELDS {
+            assert_eq!([0; 8], mont_inv([0; 8], field));
+        }
+    }
+
+    #[test]
+    fn mont_inv_round_trip() {
+        let a = [1, 2, 3, 4, 5, 6, 7, 8];
+        for field in FIELDS {
+            assert_eq!(a, mont_inv(mont_inv(a, field), field));
+        }
+    }
+
+    #[test]
+    fn div_rem_edge_cases() {
+        let max = [u32::MAX; 8];
+        let one = [1, 0, 0, 0, 0, 0, 0, 0];
+
+        assert_eq!((max, [0; 8]), div_rem(max, one));
+        assert_eq!((one, [0; 8]), div_rem(max, max));
+        assert_eq!(([0; 8], one), div_rem(one, max));
+
+        // (2^256 - 1) / 2^255 = 1 rem 2^255 - 1
+        let mut msb = [0; 8];
+        msb[7] = 1 << 31;
+        let mut rem = max;
+        rem[7] = u32::MAX >> 1;
+        assert_eq!((one, rem), div_rem(max, msb));
+
+        // 12345678 * (2^64 + 3) + 5
+        let a = [37037039, 0, 12345678, 0, 0, 0, 0, 0];
+        let b = [3, 0, 1, 0, 0, 0, 0, 0];
+        assert_eq!(([12345678, 0, 0, 0, 0, 0, 0, 0], [5, 0, 0, 0, 0, 0, 0, 0]), div_rem(a, b));
+    }
+
+    #[test]
+    fn mod_inv_small_moduli() {
+        let to_limbs = |value: u32| [value, 0, 0, 0, 0, 0, 0, 0];
+        for m in [1u32, 2, 15, 97, 1024] {
+            for a in 0..(2 * m) {
+                let expected = (0..m).find(|b| a * b % m == 1 % m).map(to_limbs);
+                assert_eq!(expected, mod_inv(to_limbs(a), to_limbs(m)));
+            }
+        }
+    }
+
+    #[test]
+    fn mod_inv_large_moduli() {
+        let a = [1, 2, 3, 4, 5, 6, 7, 8];
+        for field in FIELDS {
+            let modulus = to_u32_limbs(field.modulus);
+            let inv = mod_inv(a, modulus).unwrap();
+            assert_eq!(Some(a), mod_inv(inv, modulus));
+        }
+
+        // (m - 1)^2 = 1 (mod m), and 2^256 - 1 is divisible by 3
+        let max = [u32::MAX; 8];
+        let mut max_minus_one = max;
+        max_minus_one[0] -= 1;
+        assert_eq!(Some(max_minus_one), mod_inv(max_minus_one, max));
+        assert_eq!(None, mod_inv([3, 0, 0, 0, 0, 0, 0, 0], max));
+    }
+}
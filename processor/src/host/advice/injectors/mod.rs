@@ -1,5 +1,6 @@
 pub(super) mod adv_map_injectors;
 pub(super) mod adv_stack_injectors;
 pub(super) mod dsa;
+pub(super) mod foreign_field;
 pub(super) mod merkle_store_injectors;
 pub(super) mod smt;
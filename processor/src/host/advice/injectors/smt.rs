@@ -6,7 +6,7 @@ use vm_core::{
         hash::RpoDigest,
         merkle::{EmptySubtreeRoots, Smt, SMT_DEPTH},
     },
-    WORD_SIZE,
+    AdviceInjector, WORD_SIZE,
 };
 
 // SMT INJECTORS
@@ -65,20 +65,26 @@ pub(crate) fn push_smtpeek_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
-/// Currently unimplemented
+/// Currently unsupported.
+///
+/// # Errors
+/// Always returns [ExecutionError::UnsupportedAdviceInjector].
 pub(crate) fn push_smtget_inputs<S: ProcessState, A: AdviceProvider>(
     _advice_provider: &mut A,
     _process: &S,
 ) -> Result<HostResponse, ExecutionError> {
-    unimplemented!()
+    Err(ExecutionError::UnsupportedAdviceInjector(AdviceInjector::SmtGet))
 }
 
-/// Currently unimplemented
+/// Currently unsupported.
+///
+/// # Errors
+/// Always returns [ExecutionError::UnsupportedAdviceInjector].
 pub(crate) fn push_smtset_inputs<S: ProcessState, A: AdviceProvider>(
     _advice_provider: &mut A,
     _process: &S,
 ) -> Result<HostResponse, ExecutionError> {
-    unimplemented!()
+    Err(ExecutionError::UnsupportedAdviceInjector(AdviceInjector::SmtSet))
 }
 
 // HELPER METHODS
@@ -19,11 +19,14 @@ pub use inputs::AdviceInputs;
 mod injectors;
 
 mod providers;
-pub use providers::{MemAdviceProvider, RecAdviceProvider};
+pub use providers::{MemAdviceProvider, RecAdviceProvider, StreamingAdviceProvider};
 
 mod source;
 pub use source::AdviceSource;
 
+mod stream;
+pub use stream::AdviceStream;
+
 mod map;
 pub use map::AdviceMap;
 
@@ -74,6 +77,12 @@ pub trait AdviceProvider: Sized {
             AdviceInjector::U32Clo => self.push_leading_ones(process),
             AdviceInjector::U32Cto => self.push_trailing_ones(process),
             AdviceInjector::ILog2 => self.push_ilog2(process),
+            AdviceInjector::Secp256k1BaseInv => self.push_secp256k1_base_inv(process),
+            AdviceInjector::Secp256k1ScalarInv => self.push_secp256k1_scalar_inv(process),
+            AdviceInjector::Secp256r1BaseInv => self.push_secp256r1_base_inv(process),
+            AdviceInjector::Secp256r1ScalarInv => self.push_secp256r1_scalar_inv(process),
+            AdviceInjector::U256Div => self.push_u256_div_result(process),
+            AdviceInjector::U256ModInv => self.push_u256_mod_inv(process),
 
             AdviceInjector::MemToMap => self.insert_mem_values_into_adv_map(process),
             AdviceInjector::HdwordToMap { domain } => {
@@ -445,6 +454,158 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::push_ilog2(self, process)
     }
 
+    /// Given an element of the secp256k1 base field in Montgomery form on the top of the stack,
+    /// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto
+    /// the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+    /// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed
+    /// result is also zero.
+    ///
+    /// # Errors
+    /// Returns an error if any of the top 8 stack elements is not a u32 value.
+    fn push_secp256k1_base_inv<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_secp256k1_base_inv(self, process)
+    }
+
+    /// Given an element of the secp256k1 scalar field in Montgomery form on the top of the stack,
+    /// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto
+    /// the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+    /// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed
+    /// result is also zero.
+    ///
+    /// # Errors
+    /// Returns an error if any of the top 8 stack elements is not a u32 value.
+    fn push_secp256k1_scalar_inv<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_secp256k1_scalar_inv(self, process)
+    }
+
+    /// Given an element of the secp256r1 base field in Montgomery form on the top of the stack,
+    /// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto
+    /// the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+    /// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed
+    /// result is also zero.
+    ///
+    /// # Errors
+    /// Returns an error if any of the top 8 stack elements is not a u32 value.
+    fn push_secp256r1_base_inv<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_secp256r1_base_inv(self, process)
+    }
+
+    /// Given an element of the secp256r1 scalar field in Montgomery form on the top of the stack,
+    /// computes its multiplicative inverse (also in Montgomery form) and pushes the result onto
+    /// the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the input and its inverse respectively
+    /// (with a0 and b0 being the least significant limbs). If the input is zero, the pushed
+    /// result is also zero.
+    ///
+    /// # Errors
+    /// Returns an error if any of the top 8 stack elements is not a u32 value.
+    fn push_secp256r1_scalar_inv<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_secp256r1_scalar_inv(self, process)
+    }
+
+    /// Pushes the result of u256 division (both the quotient and the remainder) onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b7, b6, b5, b4, b3, b2, b1, b0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [q0, q1, q2, q3, q4, q5, q6, q7, r0, r1, r2, r3, r4, r5, r6, r7, ...]
+    ///
+    /// Where a[0..8] and b[0..8] are the 32-bit limbs of the dividend and the divisor respectively
+    /// (with a0 and b0 being the least significant limbs). Similarly, q[0..8] and r[0..8] are the
+    /// limbs of the quotient and the remainder respectively.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the top 16 stack elements is not a u32 value.
+    /// - The divisor is ZERO.
+    fn push_u256_div_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_u256_div_result(self, process)
+    }
+
+    /// Pushes the multiplicative inverse of a u256 value modulo a u256 modulus onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [m7, m6, m5, m4, m3, m2, m1, m0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [m7, m6, m5, m4, m3, m2, m1, m0, a7, a6, a5, a4, a3, a2, a1, a0, ...]
+    ///   Advice stack: [b0, b1, b2, b3, b4, b5, b6, b7, ...]
+    ///
+    /// Where a[0..8], m[0..8], and b[0..8] are the 32-bit limbs of the input, the modulus, and the
+    /// inverse of the input modulo m respectively (with a0, m0, and b0 being the least significant
+    /// limbs). If the input has no inverse modulo m, the pushed result is zero.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the top 16 stack elements is not a u32 value.
+    /// - The modulus is ZERO.
+    fn push_u256_mod_inv<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_u256_mod_inv(self, process)
+    }
+
     // DEFAULT MERKLE STORE INJECTORS
     // --------------------------------------------------------------------------------------------
 
@@ -533,7 +694,7 @@ pub trait AdviceProvider: Sized {
         injectors::smt::push_smtpeek_result(self, process)
     }
 
-    /// Currently unimplemented
+    /// Currently unsupported; always returns [ExecutionError::UnsupportedAdviceInjector].
     fn push_smtget_inputs<S: ProcessState>(
         &mut self,
         process: &S,
@@ -541,7 +702,7 @@ pub trait AdviceProvider: Sized {
         injectors::smt::push_smtget_inputs(self, process)
     }
 
-    /// Currently unimplemented
+    /// Currently unsupported; always returns [ExecutionError::UnsupportedAdviceInjector].
     fn push_smtset_inputs<S: ProcessState>(
         &mut self,
         process: &S,
@@ -1,8 +1,8 @@
 use crate::ProcessState;
 
 use super::{
-    injectors, AdviceInputs, AdviceProvider, AdviceSource, ExecutionError, Felt, MerklePath,
-    MerkleStore, NodeIndex, RpoDigest, StoreNode, Word,
+    injectors, AdviceInputs, AdviceProvider, AdviceSource, AdviceStream, ExecutionError, Felt,
+    MerklePath, MerkleStore, NodeIndex, RpoDigest, StoreNode, Word,
 };
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
@@ -478,3 +478,131 @@ impl RecAdviceProvider {
         (proof, stack, map, store.into())
     }
 }
+
+// STREAMING ADVICE PROVIDER
+// ================================================================================================
+
+/// An in-memory [AdviceProvider] implementation which refills the advice stack from an
+/// [AdviceStream] in chunks.
+///
+/// The elements supplied by the stream are placed below the elements of the advice stack the
+/// provider was created with. That is, executing a program with this provider is equivalent to
+/// executing it with a [MemAdviceProvider] whose advice stack is followed by all elements of the
+/// stream; however, the elements of the stream are requested only once the VM actually needs
+/// them, `chunk_size` words at a time.
+#[derive(Debug, Clone)]
+pub struct StreamingAdviceProvider<T: AdviceStream> {
+    provider: BaseAdviceProvider<SimpleAdviceMap, SimpleMerkleMap>,
+    stream: T,
+    chunk_size: usize,
+    exhausted: bool,
+}
+
+impl<T: AdviceStream> StreamingAdviceProvider<T> {
+    /// Returns a new provider initialized with the specified inputs, which requests the elements
+    /// following the advice stack from `stream` in chunks of `chunk_size` words.
+    ///
+    /// Chunk size of 0 is rounded up to 1.
+    pub fn new(inputs: AdviceInputs, stream: T, chunk_size: usize) -> Self {
+        Self {
+            provider: inputs.into(),
+            stream,
+            chunk_size: chunk_size.max(1),
+            exhausted: false,
+        }
+    }
+
+    /// Returns the number of words requested from the stream at a time.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Requests chunks from the stream until the advice stack contains at least `num_elements`
+    /// elements, or the stream is exhausted.
+    fn refill(&mut self, num_elements: usize) {
+        while self.provider.stack.len() < num_elements && !self.exhausted {
+            let chunk = self.stream.next_chunk(self.chunk_size);
+            if chunk.is_empty() {
+                self.exhausted = true;
+            } else {
+                // the top of the advice stack is at the end of the vector, and thus, the new
+                // elements are inserted at its beginning in reverse order
+                let values = chunk.into_iter().rev().flat_map(|word| word.into_iter().rev());
+                self.provider.stack.splice(0..0, values);
+            }
+        }
+    }
+}
+
+/// Accessors to internal data structures of the provider used for testing purposes.
+#[cfg(any(test, feature = "internals"))]
+impl<T: AdviceStream> StreamingAdviceProvider<T> {
+    /// Returns the current state of the advice stack, excluding the elements which have not been
+    /// requested from the stream yet.
+    pub fn stack(&self) -> &[Felt] {
+        &self.provider.stack
+    }
+}
+
+/// Pass-through implementations of [AdviceProvider] methods, refilling the advice stack before
+/// elements are popped from it.
+#[rustfmt::skip]
+impl<T: AdviceStream> AdviceProvider for StreamingAdviceProvider<T> {
+    fn pop_stack<S: ProcessState>(&mut self, process: &S) -> Result<Felt, ExecutionError> {
+        self.refill(1);
+        self.provider.pop_stack(process)
+    }
+
+    fn pop_stack_word<S: ProcessState>(&mut self, process: &S) -> Result<Word, ExecutionError> {
+        self.refill(4);
+        self.provider.pop_stack_word(process)
+    }
+
+    fn pop_stack_dword<S: ProcessState>(&mut self, process: &S) -> Result<[Word; 2], ExecutionError> {
+        self.refill(8);
+        self.provider.pop_stack_dword(process)
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        self.provider.push_stack(source)
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.provider.insert_into_map(key, values)
+    }
+
+    fn get_signature(&self, kind: SignatureKind, pub_key: Word, msg: Word) -> Result<Vec<Felt>, ExecutionError> {
+        self.provider.get_signature(kind, pub_key, msg)
+    }
+
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]> {
+        self.provider.get_mapped_values(key)
+    }
+
+    fn get_tree_node(&self, root: Word, depth: &Felt, index: &Felt) -> Result<Word, ExecutionError> {
+        self.provider.get_tree_node(root, depth, index)
+    }
+
+    fn get_merkle_path(&self, root: Word, depth: &Felt, index: &Felt) -> Result<MerklePath, ExecutionError> {
+        self.provider.get_merkle_path(root, depth, index)
+    }
+
+    fn get_leaf_depth(&self, root: Word, tree_depth: &Felt, index: &Felt) -> Result<u8, ExecutionError> {
+        self.provider.get_leaf_depth(root, tree_depth, index)
+    }
+
+    fn update_merkle_node(&mut self, root: Word, depth: &Felt, index: &Felt, value: Word) -> Result<(MerklePath, Word), ExecutionError> {
+        self.provider.update_merkle_node(root, depth, index, value)
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        self.provider.merge_roots(lhs, rhs)
+    }
+
+    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+        where
+            I: Iterator<Item = R>,
+            R: core::borrow::Borrow<RpoDigest> {
+        self.provider.get_store_subset(roots)
+    }
+}
@@ -0,0 +1,34 @@
+use super::Word;
+use alloc::vec::Vec;
+
+// ADVICE STREAM
+// ================================================================================================
+
+/// A source of advice stack elements which are supplied to the VM in chunks.
+///
+/// A stream is used by a [StreamingAdviceProvider](super::StreamingAdviceProvider) to lazily
+/// extend the bottom of the advice stack: whenever the VM needs more elements than the advice
+/// stack contains, the provider requests the next chunk of words from the stream. This allows
+/// programs which consume large amounts of advice data (e.g., via `adv_pipe`) to be executed
+/// without materializing all of the data upfront, and without interacting with the host for
+/// every consumed word.
+///
+/// Words are supplied in the order in which they are consumed by the VM, and the elements of each
+/// word are consumed in the order in which they appear in the word (i.e., the same order as the
+/// elements of [AdviceInputs](super::AdviceInputs) stack).
+pub trait AdviceStream {
+    /// Returns the next chunk of at most `max_words` words from this stream.
+    ///
+    /// An empty chunk indicates that the stream has been exhausted, and no further chunks are
+    /// requested from the stream afterwards.
+    fn next_chunk(&mut self, max_words: usize) -> Vec<Word>;
+}
+
+impl<I> AdviceStream for I
+where
+    I: Iterator<Item = Word>,
+{
+    fn next_chunk(&mut self, max_words: usize) -> Vec<Word> {
+        self.by_ref().take(max_words).collect()
+    }
+}
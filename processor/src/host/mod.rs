@@ -1,5 +1,6 @@
 use super::{ExecutionError, Felt, ProcessState};
 use crate::MemAdviceProvider;
+use alloc::vec::Vec;
 use vm_core::{crypto::merkle::MerklePath, AdviceInjector, DebugOptions, Word};
 
 pub(super) mod advice;
@@ -20,6 +21,10 @@ mod debug;
 /// the VM in the form of [HostResponse]. The host is provided with a reference to the current
 /// state of the VM ([ProcessState]), which it can use to extract the data required to fulfill the
 /// request.
+///
+/// The VM never trusts the responses of the host: a response of an unexpected variant results in
+/// [ExecutionError::UnexpectedHostResponse], and data which is inconsistent with the state of the
+/// VM (e.g., a Merkle path of the wrong length) results in an error rather than a panic.
 pub trait Host {
     // REQUIRED METHODS
     // --------------------------------------------------------------------------------------------
@@ -56,6 +61,10 @@ pub trait Host {
     }
 
     /// Handles the event emitted from the VM.
+    ///
+    /// Errors returned from this method terminate the execution and are reported as
+    /// [ExecutionError::EventError] carrying the ID of the event and the clock cycle at which it
+    /// was emitted.
     fn on_event<S: ProcessState>(
         &mut self,
         process: &S,
@@ -98,22 +107,55 @@ pub trait Host {
         Ok(HostResponse::None)
     }
 
+    /// Handles the periodic callback invoked by the VM every `n` cycles.
+    ///
+    /// This is invoked only when the cycle interval is set via [ExecutionOptions], and can be used
+    /// to implement gas metering, progress reporting, or cooperative cancellation; returning an
+    /// error from this handler terminates program execution.
+    ///
+    /// [ExecutionOptions]: crate::ExecutionOptions
+    fn on_cycle_interval<S: ProcessState>(
+        &mut self,
+        _process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        Ok(HostResponse::None)
+    }
+
     /// Handles the failure of the assertion instruction.
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         ExecutionError::FailedAssertion {
             clk: process.clk(),
             err_code,
             err_msg: None,
+            context: None,
         }
     }
 
+    /// Handles the failure of the assertion instruction when the VM is executed in debug mode,
+    /// and decides whether the execution should be aborted or continued.
+    ///
+    /// This is invoked before [Host::on_assert_failed()], which is invoked only if this handler
+    /// returns [AssertionAction::Abort]. If [AssertionAction::Continue] is returned, the failure is
+    /// treated as a warning: the value being asserted is removed from the stack and the execution
+    /// proceeds as if the assertion succeeded. Note that the execution trace generated in this case
+    /// does not satisfy the constraints of the VM, and thus cannot be proven.
+    ///
+    /// This handler is not invoked when the VM is executed outside of debug mode.
+    fn on_assert_failed_in_debug<S: ProcessState>(
+        &mut self,
+        _process: &S,
+        _err_code: u32,
+    ) -> AssertionAction {
+        AssertionAction::Abort
+    }
+
     /// Pops an element from the advice stack and returns it.
     ///
     /// # Errors
     /// Returns an error if the advice stack is empty.
     fn pop_adv_stack<S: ProcessState>(&mut self, process: &S) -> Result<Felt, ExecutionError> {
         let response = self.get_advice(process, AdviceExtractor::PopStack)?;
-        Ok(response.into())
+        response.try_into()
     }
 
     /// Pops a word (4 elements) from the advice stack and returns it.
@@ -125,7 +167,7 @@ pub trait Host {
     /// Returns an error if the advice stack does not contain a full word.
     fn pop_adv_stack_word<S: ProcessState>(&mut self, process: &S) -> Result<Word, ExecutionError> {
         let response = self.get_advice(process, AdviceExtractor::PopStackWord)?;
-        Ok(response.into())
+        response.try_into()
     }
 
     /// Pops a double word (8 elements) from the advice stack and returns them.
@@ -141,7 +183,7 @@ pub trait Host {
         process: &S,
     ) -> Result<[Word; 2], ExecutionError> {
         let response = self.get_advice(process, AdviceExtractor::PopStackDWord)?;
-        Ok(response.into())
+        response.try_into()
     }
 
     /// Returns a path to a node at the specified depth and index in a Merkle tree with the
@@ -158,7 +200,25 @@ pub trait Host {
         process: &S,
     ) -> Result<MerklePath, ExecutionError> {
         let response = self.get_advice(process, AdviceExtractor::GetMerklePath)?;
-        Ok(response.into())
+        response.try_into()
+    }
+
+    /// Stores a chunk of rows evicted from the stack overflow table under the specified key.
+    ///
+    /// This is invoked only when overflow table eviction is enabled via [ExecutionOptions]. The
+    /// default implementation returns an error.
+    ///
+    /// [ExecutionOptions]: crate::ExecutionOptions
+    fn evict_overflow_rows(&mut self, _key: Word, _rows: Vec<Felt>) -> Result<(), ExecutionError> {
+        Err(ExecutionError::OverflowTableEvictionNotSupported)
+    }
+
+    /// Returns a chunk of stack overflow table rows previously stored under the specified key via
+    /// [Host::evict_overflow_rows()].
+    ///
+    /// The default implementation returns an error.
+    fn restore_overflow_rows(&mut self, key: Word) -> Result<Vec<Felt>, ExecutionError> {
+        Err(ExecutionError::OverflowTableRestoreFailed(key))
     }
 }
 
@@ -206,9 +266,44 @@ where
         H::on_trace(self, process, trace_id)
     }
 
+    fn on_cycle_interval<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        H::on_cycle_interval(self, process)
+    }
+
+    fn on_assert_failed_in_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        err_code: u32,
+    ) -> AssertionAction {
+        H::on_assert_failed_in_debug(self, process, err_code)
+    }
+
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         H::on_assert_failed(self, process, err_code)
     }
+
+    fn evict_overflow_rows(&mut self, key: Word, rows: Vec<Felt>) -> Result<(), ExecutionError> {
+        H::evict_overflow_rows(self, key, rows)
+    }
+
+    fn restore_overflow_rows(&mut self, key: Word) -> Result<Vec<Felt>, ExecutionError> {
+        H::restore_overflow_rows(self, key)
+    }
+}
+
+// ASSERTION ACTION
+// ================================================================================================
+
+/// Action to be taken by the VM upon a failed assertion in debug mode, as decided by the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssertionAction {
+    /// Terminate the execution with an error.
+    Abort,
+    /// Ignore the failed assertion and continue the execution.
+    Continue,
 }
 
 // HOST RESPONSE
@@ -224,38 +319,71 @@ pub enum HostResponse {
     None,
 }
 
-impl From<HostResponse> for MerklePath {
-    fn from(response: HostResponse) -> Self {
+impl HostResponse {
+    /// Returns the name of this response variant, used to report unexpected responses.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::MerklePath(_) => "MerklePath",
+            Self::DoubleWord(_) => "DoubleWord",
+            Self::Word(_) => "Word",
+            Self::Element(_) => "Element",
+            Self::None => "None",
+        }
+    }
+}
+
+impl TryFrom<HostResponse> for MerklePath {
+    type Error = ExecutionError;
+
+    fn try_from(response: HostResponse) -> Result<Self, Self::Error> {
         match response {
-            HostResponse::MerklePath(path) => path,
-            _ => panic!("expected MerklePath, but got {:?}", response),
+            HostResponse::MerklePath(path) => Ok(path),
+            _ => Err(ExecutionError::UnexpectedHostResponse {
+                expected: "MerklePath",
+                actual: response.name(),
+            }),
         }
     }
 }
 
-impl From<HostResponse> for Word {
-    fn from(response: HostResponse) -> Self {
+impl TryFrom<HostResponse> for Word {
+    type Error = ExecutionError;
+
+    fn try_from(response: HostResponse) -> Result<Self, Self::Error> {
         match response {
-            HostResponse::Word(word) => word,
-            _ => panic!("expected Word, but got {:?}", response),
+            HostResponse::Word(word) => Ok(word),
+            _ => Err(ExecutionError::UnexpectedHostResponse {
+                expected: "Word",
+                actual: response.name(),
+            }),
         }
     }
 }
 
-impl From<HostResponse> for [Word; 2] {
-    fn from(response: HostResponse) -> Self {
+impl TryFrom<HostResponse> for [Word; 2] {
+    type Error = ExecutionError;
+
+    fn try_from(response: HostResponse) -> Result<Self, Self::Error> {
         match response {
-            HostResponse::DoubleWord(word) => word,
-            _ => panic!("expected DoubleWord, but got {:?}", response),
+            HostResponse::DoubleWord(word) => Ok(word),
+            _ => Err(ExecutionError::UnexpectedHostResponse {
+                expected: "DoubleWord",
+                actual: response.name(),
+            }),
         }
     }
 }
 
-impl From<HostResponse> for Felt {
-    fn from(response: HostResponse) -> Self {
+impl TryFrom<HostResponse> for Felt {
+    type Error = ExecutionError;
+
+    fn try_from(response: HostResponse) -> Result<Self, Self::Error> {
         match response {
-            HostResponse::Element(element) => element,
-            _ => panic!("expected Element, but got {:?}", response),
+            HostResponse::Element(element) => Ok(element),
+            _ => Err(ExecutionError::UnexpectedHostResponse {
+                expected: "Element",
+                actual: response.name(),
+            }),
         }
     }
 }
@@ -312,4 +440,17 @@ impl<A: AdviceProvider> Host for DefaultHost<A> {
     ) -> Result<HostResponse, ExecutionError> {
         self.adv_provider.set_advice(process, &injector)
     }
+
+    /// Inserts the evicted rows into the advice map of the underlying advice provider.
+    fn evict_overflow_rows(&mut self, key: Word, rows: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.adv_provider.insert_into_map(key, rows)
+    }
+
+    /// Reads the evicted rows from the advice map of the underlying advice provider.
+    fn restore_overflow_rows(&mut self, key: Word) -> Result<Vec<Felt>, ExecutionError> {
+        self.adv_provider
+            .get_mapped_values(&key.into())
+            .map(|rows| rows.to_vec())
+            .ok_or(ExecutionError::AdviceMapKeyNotFound(key))
+    }
 }
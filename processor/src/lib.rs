@@ -13,22 +13,23 @@ use miden_air::trace::{
     CHIPLETS_WIDTH, DECODER_TRACE_WIDTH, MIN_TRACE_LEN, RANGE_CHECK_TRACE_WIDTH, STACK_TRACE_WIDTH,
     SYS_TRACE_WIDTH,
 };
-pub use miden_air::{ExecutionOptions, ExecutionOptionsError};
+pub use miden_air::{CancellationToken, ExecutionOptions, ExecutionOptionsError};
 pub use vm_core::{
-    chiplets::hasher::Digest, crypto::merkle::SMT_DEPTH, errors::InputError,
-    utils::DeserializationError, AdviceInjector, AssemblyOp, Felt, Kernel, Operation, Program,
-    ProgramInfo, QuadExtension, StackInputs, StackOutputs, Word, EMPTY_WORD, ONE, ZERO,
+    chiplets::hasher::Digest, code_blocks::CodeBlock, crypto::merkle::SMT_DEPTH,
+    errors::InputError, utils::DeserializationError, AdviceInjector, AssemblyOp, CodeBlockTable,
+    Felt, Kernel, Operation, Program, ProgramInfo, QuadExtension, StackInputs, StackOutputs, Word,
+    EMPTY_WORD, ONE, ZERO,
 };
 use vm_core::{
-    code_blocks::{
-        Call, CodeBlock, Dyn, Join, Loop, OpBatch, Span, Split, OP_BATCH_SIZE, OP_GROUP_SIZE,
-    },
-    CodeBlockTable, Decorator, DecoratorIterator, FieldElement, StackTopState,
+    code_blocks::{Call, Dyn, Join, Loop, OpBatch, Span, Split, OP_BATCH_SIZE, OP_GROUP_SIZE},
+    crypto::hash::Rpo256,
+    Decorator, DecoratorIterator, FieldElement, StackTopState,
 };
 
 pub use winter_prover::matrix::ColMatrix;
 
 mod operations;
+use operations::LocalFrame;
 
 mod system;
 use system::System;
@@ -36,6 +37,10 @@ pub use system::{ContextId, FMP_MIN, SYSCALL_FMP_MIN};
 
 mod decoder;
 use decoder::Decoder;
+pub use decoder::{
+    DecoderRow, OpBatchSummary, OpGroupMismatch, OpGroupMismatchKind, OpGroupTableEntry,
+    OpGroupTableReport,
+};
 
 mod stack;
 use stack::Stack;
@@ -46,26 +51,36 @@ use range::RangeChecker;
 mod host;
 pub use host::{
     advice::{
-        AdviceExtractor, AdviceInputs, AdviceMap, AdviceProvider, AdviceSource, MemAdviceProvider,
-        RecAdviceProvider,
+        AdviceExtractor, AdviceInputs, AdviceMap, AdviceProvider, AdviceSource, AdviceStream,
+        MemAdviceProvider, RecAdviceProvider, StreamingAdviceProvider,
     },
-    DefaultHost, Host, HostResponse,
+    AssertionAction, DefaultHost, Host, HostResponse,
 };
 
 mod chiplets;
 use chiplets::Chiplets;
+pub use chiplets::MemoryAccessRecord;
 
 mod trace;
 use trace::TraceFragment;
-pub use trace::{ChipletsLengths, ExecutionTrace, TraceLenSummary};
+pub use trace::{BlockTrace, ChipletsLengths, ExecutionTrace, TraceLenSummary};
 
 mod errors;
-pub use errors::{ExecutionError, Ext2InttError};
+pub use errors::{ErrorContext, ExecutionError, Ext2InttError};
 
 pub mod utils;
 
 mod debug;
-pub use debug::{AsmOpInfo, VmState, VmStateIterator};
+pub use debug::{AsmOpInfo, ExecutionOutcome, HaltedState, VmState, VmStateIterator};
+
+mod snapshot;
+pub use snapshot::ProcessStateSnapshot;
+
+mod memory_inputs;
+pub use memory_inputs::MemoryInputs;
+
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzz;
 
 // RE-EXPORTS
 // ================================================================================================
@@ -135,6 +150,179 @@ where
     Ok(trace)
 }
 
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, aborting execution once the provided `token` is cancelled.
+///
+/// The token is checked at code block boundaries, and thus, can be used to abort execution of a
+/// program from another thread. Once the token is cancelled, [ExecutionError::Cancelled] is
+/// returned.
+#[tracing::instrument("execute_program", skip_all)]
+pub fn execute_with_token<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ExecutionOptions,
+    token: CancellationToken,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    H: Host,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, host, options)
+        .with_cancellation_token(token);
+    let stack_outputs = process.execute(program)?;
+    let trace = ExecutionTrace::new(process, stack_outputs);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, together with a snapshot of the state of the VM at the end of the execution.
+///
+/// Unlike the execution trace, the snapshot contains the final memory of all execution contexts
+/// accessed by the program. Since the memory is copied in full, this is more expensive than
+/// [execute()] for programs which use a lot of memory.
+#[tracing::instrument("execute_program", skip_all)]
+pub fn execute_with_snapshot<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ExecutionOptions,
+) -> Result<(ExecutionTrace, ProcessStateSnapshot), ExecutionError>
+where
+    H: Host,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, host, options);
+    let stack_outputs = process.execute(program)?;
+    let snapshot = process.snapshot();
+    let trace = ExecutionTrace::new(process, stack_outputs);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok((trace, snapshot))
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, with the memory initialized to the provided memory inputs.
+///
+/// If memory commitment is enabled via [ExecutionOptions], the returned trace exposes a
+/// commitment to the memory inputs (see [ExecutionTrace::memory_commitment()]).
+#[tracing::instrument("execute_program", skip_all)]
+pub fn execute_with_memory<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    memory_inputs: MemoryInputs,
+    host: H,
+    options: ExecutionOptions,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    H: Host,
+{
+    let mut process = Process::new_with_memory(
+        program.kernel().clone(),
+        stack_inputs,
+        memory_inputs,
+        host,
+        options,
+    )?;
+    let stack_outputs = process.execute(program)?;
+    let trace = ExecutionTrace::new(process, stack_outputs);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
+/// Executes the provided program against the provided inputs without building an execution trace,
+/// and returns the outcome of the execution.
+///
+/// Unlike [execute()], this function does not fail on recoverable errors (see
+/// [ExecutionError::is_recoverable()]), such as failed assertions or advice stack underflows.
+/// Instead, execution is terminated at the cycle at which the error occurred, and the state of the
+/// VM at that cycle is returned via [ExecutionOutcome::Halted]. All other errors are returned as
+/// is.
+///
+/// This is intended for high-throughput fuzzing and batch analysis of programs. Since [Host] is
+/// implemented for mutable references, the same host can be reused across many invocations.
+pub fn execute_recoverable<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ExecutionOptions,
+) -> Result<ExecutionOutcome, ExecutionError>
+where
+    H: Host,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, host, options);
+    match process.execute(program) {
+        Ok(stack_outputs) => Ok(ExecutionOutcome::Completed(stack_outputs)),
+        Err(error) if error.is_recoverable() => {
+            // bring back evicted overflow table rows so that the returned stack state is complete
+            while process.stack.last_evicted_overflow_chunk().is_some() {
+                process.restore_overflow_rows()?;
+            }
+
+            let ctx = process.ctx();
+            Ok(ExecutionOutcome::Halted(HaltedState {
+                clk: process.clk(),
+                ctx,
+                fmp: process.system.fmp(),
+                stack: process.stack.get_state_at(process.stack.current_clk()),
+                memory: process.get_mem_state(ctx),
+                error,
+            }))
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the main trace rows resulting from executing the provided code block, starting from
+/// the stack and memory of the provided process state.
+///
+/// This is a low-level API intended for testing individual operations, constraints, and chiplets:
+/// the block is executed as if it were the root of a program, and thus, there is no need to
+/// assemble a complete program to construct a specific scenario. Blocks referenced from the
+/// executed block by their hashes (e.g., targets of `dyn` blocks) are looked up in `cb_table`.
+///
+/// The stack is initialized with the stack of the provided state. Memory of all execution contexts
+/// of the state is written into the memory chiplet at clock cycle 0; these writes are thus present
+/// in the memory chiplet trace, but are not requested from the chiplets bus. The clock cycle, the
+/// execution context, and the free memory pointer of the state are ignored, and the block is
+/// always executed in the root context starting at clock cycle 0.
+///
+/// # Errors
+/// Returns an error if the stack of the provided state contains more than [StackInputs::MAX_LEN]
+/// elements, if the memory of the state cannot be written, or if the block fails to execute.
+pub fn execute_block<H, S>(
+    block: &CodeBlock,
+    cb_table: &CodeBlockTable,
+    kernel: Kernel,
+    state: &S,
+    host: H,
+    options: ExecutionOptions,
+) -> Result<BlockTrace, ExecutionError>
+where
+    H: Host,
+    S: ProcessState + ?Sized,
+{
+    let mut stack = state.get_stack_state();
+    stack.reverse();
+    let stack_inputs = StackInputs::new(stack).map_err(ExecutionError::InvalidStackState)?;
+
+    let mut process = Process::new(kernel, stack_inputs, host, options);
+    for ctx in state.get_mem_contexts() {
+        for (addr, word) in state.get_mem_state(ctx) {
+            process.chiplets.write_mem(ctx, addr as u32, word)?;
+        }
+    }
+
+    process.execute_code_block(block, cb_table)?;
+
+    // bring back all evicted overflow table rows so that they can be included in the outputs
+    while process.stack.last_evicted_overflow_chunk().is_some() {
+        process.restore_overflow_rows()?;
+    }
+
+    assert_eq!(block.hash(), process.decoder.program_hash().into(), "inconsistent program hash");
+    let stack_outputs = process.stack.build_stack_outputs();
+    Ok(BlockTrace::new(process, stack_outputs))
+}
+
 /// Returns an iterator which allows callers to step through the execution and inspect VM state at
 /// each execution step.
 pub fn execute_iter<H>(program: &Program, stack_inputs: StackInputs, host: H) -> VmStateIterator
@@ -169,6 +357,13 @@ where
     host: RefCell<H>,
     max_cycles: u32,
     enable_tracing: bool,
+    overflow_eviction_threshold: Option<usize>,
+    cycle_interval: Option<u32>,
+    cancellation_token: Option<CancellationToken>,
+    consumed_advice: Option<Vec<Felt>>,
+    local_frames: Option<Vec<LocalFrame>>,
+    expected_program_hash: Option<Digest>,
+    memory_commitment: Option<Digest>,
 }
 
 impl<H> Process<H>
@@ -187,6 +382,39 @@ where
         Self::initialize(kernel, stack_inputs, host, execution_options)
     }
 
+    /// Creates a new process with the provided inputs, and with the memory initialized to the
+    /// provided memory inputs.
+    ///
+    /// The words of the memory inputs are written into the memory chiplet at clock cycle 0.
+    ///
+    /// # Errors
+    /// Returns an error if a word of the memory inputs could not be written into memory.
+    pub fn new_with_memory(
+        kernel: Kernel,
+        stack_inputs: StackInputs,
+        memory_inputs: MemoryInputs,
+        host: H,
+        execution_options: ExecutionOptions,
+    ) -> Result<Self, ExecutionError> {
+        let mut process = Self::initialize(kernel, stack_inputs, host, execution_options);
+        for (ctx, addr, word) in memory_inputs.iter() {
+            process.chiplets.write_mem(ctx, addr, word)?;
+        }
+        if let Some(memory_commitment) = process.memory_commitment.as_mut() {
+            *memory_commitment = memory_inputs.commitment();
+        }
+        Ok(process)
+    }
+
+    /// Sets the token which can be used to cancel execution of this process from another thread.
+    ///
+    /// The token is checked at code block boundaries; once it is cancelled, execution is aborted
+    /// with [ExecutionError::Cancelled].
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
     /// Creates a new process with provided inputs and debug options enabled.
     pub fn new_debug(kernel: Kernel, stack_inputs: StackInputs, host: H) -> Self {
         Self::initialize(
@@ -204,15 +432,32 @@ where
         execution_options: ExecutionOptions,
     ) -> Self {
         let in_debug_mode = execution_options.enable_debugging();
+        // the full state of the overflow table is recorded at every cycle in debug mode, and
+        // thus, evicting rows from it would not reduce memory usage
+        let overflow_eviction_threshold = execution_options
+            .overflow_eviction_threshold()
+            .filter(|_| !in_debug_mode)
+            .map(|threshold| threshold as usize);
+
         Self {
             system: System::new(execution_options.expected_cycles() as usize),
             decoder: Decoder::new(in_debug_mode),
             stack: Stack::new(&stack, execution_options.expected_cycles() as usize, in_debug_mode),
             range: RangeChecker::new(),
-            chiplets: Chiplets::new(kernel),
+            chiplets: Chiplets::new(kernel, execution_options.skip_unused_chiplets()),
             host: RefCell::new(host),
             max_cycles: execution_options.max_cycles(),
             enable_tracing: execution_options.enable_tracing(),
+            overflow_eviction_threshold,
+            cycle_interval: execution_options.cycle_interval(),
+            cancellation_token: None,
+            consumed_advice: execution_options.enable_advice_commitment().then(Vec::new),
+            local_frames: execution_options.enable_local_canaries().then(Vec::new),
+            expected_program_hash: execution_options.expected_program_hash(),
+            // the memory is empty unless memory inputs are written via Process::new_with_memory()
+            memory_commitment: execution_options
+                .enable_memory_commitment()
+                .then(|| MemoryInputs::default().commitment()),
         }
     }
 
@@ -222,8 +467,22 @@ where
     /// Executes the provided [Program] in this process.
     pub fn execute(&mut self, program: &Program) -> Result<StackOutputs, ExecutionError> {
         assert_eq!(self.system.clk(), 0, "a program has already been executed in this process");
+        if let Some(expected) = self.expected_program_hash {
+            if expected != program.hash() {
+                return Err(ExecutionError::ProgramHashMismatch {
+                    expected,
+                    actual: program.hash(),
+                });
+            }
+        }
+
         self.execute_code_block(program.root(), program.cb_table())?;
 
+        // bring back all evicted overflow table rows so that they can be included in the outputs
+        while self.stack.last_evicted_overflow_chunk().is_some() {
+            self.restore_overflow_rows()?;
+        }
+
         Ok(self.stack.build_stack_outputs())
     }
 
@@ -239,6 +498,10 @@ where
         block: &CodeBlock,
         cb_table: &CodeBlockTable,
     ) -> Result<(), ExecutionError> {
+        if self.cancellation_token.as_ref().is_some_and(|token| token.is_cancelled()) {
+            return Err(ExecutionError::Cancelled);
+        }
+
         match block {
             CodeBlock::Join(block) => self.execute_join_block(block, cb_table),
             CodeBlock::Split(block) => self.execute_split_block(block, cb_table),
@@ -330,6 +593,11 @@ where
         block: &Call,
         cb_table: &CodeBlockTable,
     ) -> Result<(), ExecutionError> {
+        // neither calls nor syscalls can be made from within a syscall
+        if self.system.in_syscall() {
+            return Err(ExecutionError::CallInSyscall(block.fn_hash()));
+        }
+
         // if this is a syscall, make sure the call target exists in the kernel
         if block.is_syscall() {
             self.chiplets.access_kernel_proc(block.fn_hash())?;
@@ -381,7 +649,7 @@ where
         let mut decorators = block.decorator_iter();
 
         // execute the first operation batch
-        self.execute_op_batch(&block.op_batches()[0], &mut decorators, op_offset)?;
+        self.execute_op_batch(block, &block.op_batches()[0], &mut decorators, op_offset)?;
         op_offset += block.op_batches()[0].ops().len();
 
         // if the span contains more operation batches, execute them. each additional batch is
@@ -390,7 +658,7 @@ where
         for op_batch in block.op_batches().iter().skip(1) {
             self.respan(op_batch);
             self.execute_op(Operation::Noop)?;
-            self.execute_op_batch(op_batch, &mut decorators, op_offset)?;
+            self.execute_op_batch(block, op_batch, &mut decorators, op_offset)?;
             op_offset += op_batch.ops().len();
         }
 
@@ -401,7 +669,8 @@ where
         // are executed after SPAN block is closed to make sure the VM clock cycle advances beyond
         // the last clock cycle of the SPAN block ops.
         for decorator in decorators {
-            self.execute_decorator(decorator)?;
+            self.execute_decorator(decorator)
+                .map_err(|err| err.with_context(|| ErrorContext::new(block, op_offset)))?;
         }
 
         Ok(())
@@ -413,9 +682,13 @@ where
     ///   executed after it.
     /// - If the number of groups in a batch is not a power of 2, NOOPs are executed (one per
     ///   group) to bring it up to the next power of two (e.g., 3 -> 4, 5 -> 8).
+    ///
+    /// Errors resulting from executing an operation (or a decorator attached to it) are annotated
+    /// with the location of the operation within the provided span `block`.
     #[inline(always)]
     fn execute_op_batch(
         &mut self,
+        block: &Span,
         batch: &OpBatch,
         decorators: &mut DecoratorIterator,
         op_offset: usize,
@@ -432,13 +705,15 @@ where
 
         // execute operations in the batch one by one
         for (i, &op) in batch.ops().iter().enumerate() {
+            let error_context = || ErrorContext::new(block, i + op_offset);
             while let Some(decorator) = decorators.next_filtered(i + op_offset) {
-                self.execute_decorator(decorator)?;
+                self.execute_decorator(decorator)
+                    .map_err(|err| err.with_context(error_context))?;
             }
 
             // decode and execute the operation
             self.decoder.execute_user_op(op, op_idx);
-            self.execute_op(op)?;
+            self.execute_op(op).map_err(|err| err.with_context(error_context))?;
 
             // if the operation carries an immediate value, the value is stored at the next group
             // pointer; so, we advance the pointer to the following group
@@ -499,6 +774,9 @@ where
         match decorator {
             Decorator::Advice(injector) => {
                 self.host.borrow_mut().set_advice(self, *injector)?;
+                if let AdviceInjector::MapValueToStack { key_offset, .. } = injector {
+                    self.record_advice_map_read(*key_offset);
+                }
             }
             Decorator::Debug(options) => {
                 self.host.borrow_mut().on_debug(self, options)?;
@@ -509,8 +787,12 @@ where
                 }
             }
             Decorator::Event(id) => {
-                self.host.borrow_mut().on_event(self, *id)?;
+                self.host
+                    .borrow_mut()
+                    .on_event(self, *id)
+                    .map_err(|err| err.into_event_error(*id, self.system.clk()))?;
             }
+            Decorator::MemProtect => self.protect_memory()?,
             Decorator::Trace(id) => {
                 if self.enable_tracing {
                     self.host.borrow_mut().on_trace(self, *id)?;
@@ -527,6 +809,41 @@ where
         self.chiplets.kernel()
     }
 
+    /// Returns an RPO hash of all values consumed from the advice stack so far (in the order in
+    /// which they were consumed), or None if advice commitment is not enabled for this process.
+    pub fn advice_commitment(&self) -> Option<Digest> {
+        self.consumed_advice.as_ref().map(|values| Rpo256::hash_elements(values))
+    }
+
+    /// Returns an RPO hash of the memory inputs this process was created with, or None if memory
+    /// commitment is not enabled for this process.
+    pub fn memory_commitment(&self) -> Option<Digest> {
+        self.memory_commitment
+    }
+
+    /// Records the specified values as consumed from the advice stack if advice commitment is
+    /// enabled for this process.
+    fn record_consumed_advice(&mut self, values: &[Felt]) {
+        if let Some(consumed_advice) = self.consumed_advice.as_mut() {
+            consumed_advice.extend_from_slice(values);
+        }
+    }
+
+    /// Records the key of the advice map entry whose values were just moved onto the advice stack
+    /// if advice commitment is enabled for this process; the key is read from the operand stack
+    /// starting at the specified offset.
+    fn record_advice_map_read(&mut self, key_offset: usize) {
+        if self.consumed_advice.is_some() {
+            let key = [
+                self.get_stack_item(key_offset + 3),
+                self.get_stack_item(key_offset + 2),
+                self.get_stack_item(key_offset + 1),
+                self.get_stack_item(key_offset),
+            ];
+            self.record_consumed_advice(&key);
+        }
+    }
+
     pub fn into_parts(self) -> (System, Decoder, Stack, RangeChecker, Chiplets, H) {
         (
             self.system,
@@ -582,6 +899,34 @@ pub trait ProcessState {
     /// The state is returned as a vector of (address, value) tuples, and includes addresses which
     /// have been accessed at least once.
     fn get_mem_state(&self, ctx: ContextId) -> Vec<(u64, Word)>;
+
+    /// Returns IDs of all execution contexts whose memory has been accessed so far, sorted in
+    /// ascending order.
+    fn get_mem_contexts(&self) -> Vec<ContextId>;
+
+    /// Returns addresses in the specified execution context which have been written to so far,
+    /// sorted in ascending order.
+    ///
+    /// Together with [ProcessState::get_mem_contexts()] and [ProcessState::get_mem_value()], this
+    /// can be used to dump the entire memory of the process without knowing the accessed
+    /// addresses in advance.
+    fn get_mem_written_addresses(&self, ctx: ContextId) -> Vec<u32>;
+
+    /// Returns all accesses to the specified context/address recorded by the memory chiplet so
+    /// far, sorted by clock cycle, or an empty vector if the address hasn't been accessed.
+    ///
+    /// Each access contains the clock cycle at which it happened, as well as the values stored at
+    /// the address before and after the access. A [ProcessStateSnapshot] does not retain the
+    /// history of memory accesses, and thus, always returns an empty vector.
+    fn get_mem_accesses(&self, ctx: ContextId, addr: u32) -> Vec<MemoryAccessRecord>;
+
+    /// Returns an immutable snapshot of the current state of the process.
+    ///
+    /// The snapshot owns all of its data, and thus can be sent to another thread for inspection
+    /// or serialization while the execution of the process continues.
+    fn snapshot(&self) -> ProcessStateSnapshot {
+        ProcessStateSnapshot::new(self)
+    }
 }
 
 impl<H: Host> ProcessState for Process<H> {
@@ -616,6 +961,18 @@ impl<H: Host> ProcessState for Process<H> {
     fn get_mem_state(&self, ctx: ContextId) -> Vec<(u64, Word)> {
         self.chiplets.get_mem_state_at(ctx, self.system.clk())
     }
+
+    fn get_mem_contexts(&self) -> Vec<ContextId> {
+        self.chiplets.get_mem_contexts()
+    }
+
+    fn get_mem_written_addresses(&self, ctx: ContextId) -> Vec<u32> {
+        self.chiplets.get_mem_written_addresses(ctx)
+    }
+
+    fn get_mem_accesses(&self, ctx: ContextId, addr: u32) -> Vec<MemoryAccessRecord> {
+        self.chiplets.get_mem_accesses(ctx, addr)
+    }
 }
 
 // INTERNALS
@@ -634,4 +991,11 @@ where
     pub host: RefCell<H>,
     pub max_cycles: u32,
     pub enable_tracing: bool,
+    pub overflow_eviction_threshold: Option<usize>,
+    pub cycle_interval: Option<u32>,
+    pub cancellation_token: Option<CancellationToken>,
+    pub consumed_advice: Option<Vec<Felt>>,
+    pub(crate) local_frames: Option<Vec<LocalFrame>>,
+    pub expected_program_hash: Option<Digest>,
+    pub memory_commitment: Option<Digest>,
 }
@@ -0,0 +1,83 @@
+use super::{ContextId, Digest, Felt, Word, ZERO};
+use alloc::{collections::BTreeMap, vec::Vec};
+use vm_core::crypto::hash::Rpo256;
+
+// MEMORY INPUTS
+// ================================================================================================
+
+/// Initial state of the VM memory, i.e., words which are stored in memory before the first cycle
+/// of a program is executed.
+///
+/// Words can be placed into the memory of the root context as well as into the memory of any
+/// other execution context. Since the ID of a context created by a `call` is the clock cycle of
+/// the call plus one, placing words into the memory of a context other than the root context is
+/// useful only if the cycle at which the context is created is known in advance.
+///
+/// The words are written into the memory chiplet at clock cycle 0, and thus, are present in the
+/// memory chiplet trace. Same as for [crate::execute_block()], these writes are not requested from
+/// the chiplets bus.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryInputs {
+    words: BTreeMap<(ContextId, u32), Word>,
+}
+
+impl MemoryInputs {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns [MemoryInputs] with all memory initialized to zeros.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Places the provided words at consecutive addresses of the specified context, starting at
+    /// `start_addr`, and returns the updated inputs.
+    ///
+    /// Words previously placed at the same addresses are overwritten.
+    ///
+    /// # Panics
+    /// Panics if the words do not fit into the memory, i.e., if the address of the last word is
+    /// greater than [u32::MAX].
+    pub fn with_words<I>(mut self, ctx: ContextId, start_addr: u32, words: I) -> Self
+    where
+        I: IntoIterator<Item = Word>,
+    {
+        for (i, word) in words.into_iter().enumerate() {
+            let addr = u32::try_from(i)
+                .ok()
+                .and_then(|i| start_addr.checked_add(i))
+                .expect("memory address is greater than u32::MAX");
+            self.words.insert((ctx, addr), word);
+        }
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if no words have been placed into memory.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns an iterator over (context, address, word) tuples of the inputs, sorted by context
+    /// and then by address.
+    pub fn iter(&self) -> impl Iterator<Item = (ContextId, u32, Word)> + '_ {
+        self.words.iter().map(|(&(ctx, addr), &word)| (ctx, addr, word))
+    }
+
+    /// Returns an RPO hash of the inputs.
+    ///
+    /// The hash is computed over the sequence of words in which every (context, address, word)
+    /// tuple of the inputs is represented by two words: `[ctx, addr, 0, 0]` followed by the word
+    /// itself. The tuples are sorted by context and then by address.
+    pub fn commitment(&self) -> Digest {
+        let elements: Vec<Felt> = self
+            .iter()
+            .flat_map(|(ctx, addr, word)| {
+                [Felt::from(ctx), Felt::from(addr), ZERO, ZERO].into_iter().chain(word)
+            })
+            .collect();
+        Rpo256::hash_elements(&elements)
+    }
+}
@@ -201,18 +201,12 @@ mod tests {
         // --- setup memory -----------------------------------------------------------------------
         let ctx = ContextId::root();
         let tztgz = rand_array::<Felt, 4>();
-        process.chiplets.write_mem(
-            ctx,
-            inputs[2].as_int().try_into().expect("Shouldn't fail by construction"),
-            tztgz,
-        );
+        let addr = inputs[2].as_int().try_into().expect("Shouldn't fail by construction");
+        process.chiplets.write_mem(ctx, addr, tztgz).unwrap();
 
         let a = rand_array::<Felt, 4>();
-        process.chiplets.write_mem(
-            ctx,
-            inputs[1].as_int().try_into().expect("Shouldn't fail by construction"),
-            a,
-        );
+        let addr = inputs[1].as_int().try_into().expect("Shouldn't fail by construction");
+        process.chiplets.write_mem(ctx, addr, a).unwrap();
 
         // --- execute RCOMB1 operation -----------------------------------------------------------
         process.execute_op(Operation::RCombBase).unwrap();
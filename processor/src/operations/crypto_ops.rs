@@ -1,6 +1,6 @@
-use super::{ExecutionError, Host, Operation, Process};
+use super::{ExecutionError, Felt, Host, Operation, Process};
 use crate::crypto::MerklePath;
-use vm_core::AdviceInjector;
+use vm_core::{AdviceInjector, ZERO};
 
 // CRYPTOGRAPHIC OPERATIONS
 // ================================================================================================
@@ -64,18 +64,19 @@ where
     /// - The specified depth is either zero or greater than the depth of the Merkle tree
     ///   identified by the specified root.
     /// - Path to the node at the specified depth and index is not known to the advice provider.
-    ///
-    /// # Panics
-    /// Panics if the computed root does not match the root provided via the stack.
+    /// - The path provided by the host is not a valid path for the specified depth and index.
+    /// - The computed root does not match the root provided via the stack.
     pub(super) fn op_mpverify(&mut self) -> Result<(), ExecutionError> {
         // read node value, depth, index and root value from the stack
         let node = [self.stack.get(3), self.stack.get(2), self.stack.get(1), self.stack.get(0)];
+        let depth = self.stack.get(4);
         let index = self.stack.get(5);
         let root = [self.stack.get(9), self.stack.get(8), self.stack.get(7), self.stack.get(6)];
 
         // get a Merkle path from the advice provider for the specified root and node index.
         // the path is expected to be of the specified depth.
         let path = self.host.borrow_mut().get_adv_merkle_path(self)?;
+        validate_merkle_path(&path, depth, index)?;
 
         // use hasher to compute the Merkle root of the path
         let (addr, computed_root) = self.chiplets.build_merkle_root(node, &path, index);
@@ -129,9 +130,9 @@ where
     /// - The specified depth is either zero or greater than the depth of the Merkle tree
     ///   identified by the specified root.
     /// - Path to the node at the specified depth and index is not known to the advice provider.
-    ///
-    /// # Panics
-    /// Panics if the computed old root does not match the input root provided via the stack.
+    /// - The path provided by the host is not a valid path for the specified depth and index.
+    /// - The computed old root does not match the input root provided via the stack (i.e., the
+    ///   old value of the node is not the value currently in the tree at the specified index).
     pub(super) fn op_mrupdate(&mut self) -> Result<(), ExecutionError> {
         // read old node value, depth, index, tree root and new node values from the stack
         let old_node = [self.stack.get(3), self.stack.get(2), self.stack.get(1), self.stack.get(0)];
@@ -149,16 +150,22 @@ where
             .host
             .borrow_mut()
             .set_advice(self, AdviceInjector::UpdateMerkleNode)?
-            .into();
-
-        assert_eq!(path.len(), depth.as_int() as usize);
+            .try_into()?;
+        validate_merkle_path(&path, depth, index)?;
 
         let merkle_tree_update = self.chiplets.update_merkle_root(old_node, new_node, &path, index);
 
-        // Asserts the computed old root of the Merkle path from the advice provider is consistent
-        // with the input root provided via the stack. This will panic only if the advice provider
-        // returns a Merkle path inconsistent with the specified root.
-        assert_eq!(old_root, merkle_tree_update.get_old_root(), "inconsistent Merkle tree root");
+        // make sure the computed old root of the Merkle path from the advice provider is
+        // consistent with the input root provided via the stack. this fails if the old value of
+        // the node is not the value currently in the tree at the specified index, or if the
+        // advice provider returned a Merkle path inconsistent with the specified root.
+        if old_root != merkle_tree_update.get_old_root() {
+            return Err(ExecutionError::MerklePathVerificationFailed {
+                value: old_node,
+                index,
+                root: old_root.into(),
+            });
+        }
 
         // save address(r) of the hasher trace from when the computation starts in the decoder
         // helper registers.
@@ -175,13 +182,43 @@ where
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Makes sure the Merkle path provided by the host can be used to compute the root of a tree for
+/// a node at the specified depth and index.
+///
+/// # Errors
+/// Returns an error if:
+/// - The depth is zero.
+/// - The length of the path does not match the depth.
+/// - The index is out of bounds for the specified depth.
+fn validate_merkle_path(path: &MerklePath, depth: Felt, index: Felt) -> Result<(), ExecutionError> {
+    if depth == ZERO {
+        return Err(ExecutionError::InvalidTreeDepth { depth });
+    }
+    if path.len() as u64 != depth.as_int() {
+        return Err(ExecutionError::InvalidMerklePathLength {
+            depth,
+            path_len: path.len(),
+        });
+    }
+    if index.as_int().checked_shr(path.len() as u32).unwrap_or(0) != 0 {
+        return Err(ExecutionError::InvalidTreeNodeIndex {
+            depth,
+            value: index,
+        });
+    }
+    Ok(())
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
     use super::{
-        super::{Felt, Operation},
+        super::{ExecutionError, Felt, Operation},
         Process,
     };
     use crate::{AdviceInputs, StackInputs, Word, ZERO};
@@ -331,6 +368,47 @@ mod tests {
         assert!(process.host.borrow().advice_provider().has_merkle_root(new_tree.root()));
     }
 
+    #[test]
+    fn op_mrupdate_wrong_old_value() {
+        let leaves = init_leaves(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+
+        // the old value on the stack is not the value of the leaf at the specified index
+        let leaf_index = 5usize;
+        let old_leaf = leaves[leaf_index - 1];
+        let new_leaf = init_node(9);
+
+        let stack_inputs = [
+            new_leaf[0].as_int(),
+            new_leaf[1].as_int(),
+            new_leaf[2].as_int(),
+            new_leaf[3].as_int(),
+            tree.root()[0].as_int(),
+            tree.root()[1].as_int(),
+            tree.root()[2].as_int(),
+            tree.root()[3].as_int(),
+            leaf_index as u64,
+            tree.depth() as u64,
+            old_leaf[0].as_int(),
+            old_leaf[1].as_int(),
+            old_leaf[2].as_int(),
+            old_leaf[3].as_int(),
+        ];
+
+        let store = MerkleStore::from(&tree);
+        let advice_inputs = AdviceInputs::default().with_merkle_store(store);
+        let stack_inputs = StackInputs::try_from_ints(stack_inputs).unwrap();
+        let mut process =
+            Process::new_dummy_with_inputs_and_decoder_helpers(stack_inputs, advice_inputs);
+
+        let expected = ExecutionError::MerklePathVerificationFailed {
+            value: old_leaf,
+            index: Felt::new(leaf_index as u64),
+            root: tree.root(),
+        };
+        assert_eq!(Err(expected), process.execute_op(Operation::MrUpdate));
+    }
+
     #[test]
     fn op_mrupdate_merge_subtree() {
         // init 3 trees, `a` and `b` to be the initial trees, and `c` to be the merged product of
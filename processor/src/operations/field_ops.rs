@@ -47,7 +47,10 @@ where
     pub(super) fn op_inv(&mut self) -> Result<(), ExecutionError> {
         let a = self.stack.get(0);
         if a == ZERO {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
+            return Err(ExecutionError::DivideByZero {
+                clk: self.system.clk(),
+                context: None,
+            });
         }
 
         self.stack.set(0, a.inv());
@@ -1,5 +1,5 @@
 use super::{super::QuadFelt, ExecutionError, Felt, Host, Operation, Process};
-use vm_core::{ExtensionOf, FieldElement, StarkField, ONE, ZERO};
+use vm_core::{FieldElement, StarkField, ONE, ZERO};
 
 // CONSTANTS
 // ================================================================================================
@@ -212,7 +212,13 @@ fn get_domain_segment_flags(domain_segment: usize) -> [Felt; 4] {
 }
 
 /// Computes 2 evaluation points needed for [fold4] function.
-fn compute_evaluation_points(alpha: QuadFelt, x_inv: Felt) -> (QuadFelt, QuadFelt) {
+///
+/// This and the folding functions below work over any extension of the base field, even though
+/// the VM currently folds only evaluations in the quadratic extension.
+fn compute_evaluation_points<E>(alpha: E, x_inv: Felt) -> (E, E)
+where
+    E: FieldElement<BaseField = Felt>,
+{
     let ev = alpha.mul_base(x_inv);
     let es = ev.square();
     (ev, es)
@@ -222,7 +228,10 @@ fn compute_evaluation_points(alpha: QuadFelt, x_inv: Felt) -> (QuadFelt, QuadFel
 /// verifier challenge alpha as follows:
 /// - ev = alpha / x
 /// - es = (alpha / x)^2
-fn fold4(values: [QuadFelt; 4], ev: QuadFelt, es: QuadFelt) -> (QuadFelt, QuadFelt, QuadFelt) {
+fn fold4<E>(values: [E; 4], ev: E, es: E) -> (E, E, E)
+where
+    E: FieldElement<BaseField = Felt>,
+{
     let tmp0 = fold2(values[0], values[2], ev);
     let tmp1 = fold2(values[1], values[3], ev.mul_base(TAU_INV));
     let folded_value = fold2(tmp0, tmp1, es);
@@ -231,7 +240,10 @@ fn fold4(values: [QuadFelt; 4], ev: QuadFelt, es: QuadFelt) -> (QuadFelt, QuadFe
 
 /// Performs folding by a factor of 2. ep is a value computed based on x and verifier challenge
 /// alpha.
-fn fold2(f_x: QuadFelt, f_neg_x: QuadFelt, ep: QuadFelt) -> QuadFelt {
+fn fold2<E>(f_x: E, f_neg_x: E, ep: E) -> E
+where
+    E: FieldElement<BaseField = Felt>,
+{
     (f_x + f_neg_x + ((f_x - f_neg_x) * ep)).mul_base(TWO_INV)
 }
 
@@ -240,24 +252,30 @@ fn fold2(f_x: QuadFelt, f_neg_x: QuadFelt, ep: QuadFelt) -> QuadFelt {
 
 #[cfg(test)]
 mod tests {
-    use super::{
-        ExtensionOf, Felt, FieldElement, Operation, Process, QuadFelt, StarkField, TWO, TWO_INV,
-    };
+    use super::{Felt, FieldElement, Operation, Process, QuadFelt, StarkField, TWO, TWO_INV};
     use alloc::vec::Vec;
     use test_utils::rand::{rand_array, rand_value, rand_vector};
     use vm_core::StackInputs;
-    use winter_prover::math::{fft, get_power_series_with_offset};
-    use winter_utils::transpose_slice;
+    use winter_prover::math::{fft, fields::CubeExtension, get_power_series_with_offset};
+    use winter_utils::{transpose_slice, Randomizable};
 
     #[test]
     fn fold4() {
+        fold4_matches_winterfell::<QuadFelt>();
+        fold4_matches_winterfell::<CubeExtension<Felt>>();
+    }
+
+    fn fold4_matches_winterfell<E>()
+    where
+        E: FieldElement<BaseField = Felt> + Randomizable,
+    {
         let blowup = 4_usize;
 
         // generate random alpha
-        let alpha: QuadFelt = rand_value();
+        let alpha: E = rand_value();
 
         // generate degree 7 polynomial f(x)
-        let poly: Vec<QuadFelt> = rand_vector(8);
+        let poly: Vec<E> = rand_vector(8);
 
         // evaluate the polynomial over domain of 32 elements
         let offset = Felt::GENERATOR;
@@ -265,7 +283,7 @@ mod tests {
         let evaluations = fft::evaluate_poly_with_offset(&poly, &twiddles, offset, blowup);
 
         // fold the evaluations using FRI folding procedure from Winterfell
-        let transposed_evaluations = transpose_slice::<QuadFelt, 4>(&evaluations);
+        let transposed_evaluations = transpose_slice::<E, 4>(&evaluations);
         let folded_evaluations =
             winter_fri::folding::apply_drp(&transposed_evaluations, offset, alpha);
 
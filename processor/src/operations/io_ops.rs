@@ -133,7 +133,7 @@ where
         let word = [self.stack.get(4), self.stack.get(3), self.stack.get(2), self.stack.get(1)];
 
         // write the word to memory and get the previous word
-        self.chiplets.write_mem(ctx, addr, word);
+        self.chiplets.write_mem(ctx, addr, word)?;
 
         // reverse the order of the memory word & update the stack state
         for (i, &value) in word.iter().rev().enumerate() {
@@ -164,7 +164,7 @@ where
         let value = self.stack.get(1);
 
         // write the value to the memory and get the previous word
-        let mut old_word = self.chiplets.write_mem_element(ctx, addr, value);
+        let mut old_word = self.chiplets.write_mem_element(ctx, addr, value)?;
         // put the retrieved word into stack order
         old_word.reverse();
 
@@ -194,9 +194,10 @@ where
 
         // pop two words from the advice stack
         let words = self.host.borrow_mut().pop_adv_stack_dword(self)?;
+        self.record_consumed_advice(&[words[0], words[1]].concat());
 
         // write the words memory
-        self.chiplets.write_mem_double(ctx, addr, words);
+        self.chiplets.write_mem_double(ctx, addr, words)?;
 
         // replace the elements on the stack with the word elements (in stack order)
         for (i, &adv_value) in words.iter().flat_map(|word| word.iter()).rev().enumerate() {
@@ -218,6 +219,30 @@ where
         Ok(())
     }
 
+    /// Marks memory addresses in the range [a, b) of the current execution context as read-only,
+    /// where a and b are the first and second elements on the stack respectively.
+    ///
+    /// This is not an operation, and thus, it does not affect the state of the stack; it is
+    /// executed as a part of the `MemProtect` decorator.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Either of the addresses is greater than u32::MAX.
+    /// - The start address is greater than the end address.
+    pub(crate) fn protect_memory(&mut self) -> Result<(), ExecutionError> {
+        let start_addr = Self::get_valid_address(self.stack.get(0))?;
+        let end_addr = Self::get_valid_address(self.stack.get(1))?;
+        if start_addr > end_addr {
+            return Err(ExecutionError::InvalidMemoryRange {
+                start_addr: start_addr as u64,
+                end_addr: end_addr as u64,
+            });
+        }
+
+        self.chiplets.protect_mem(self.system.ctx(), start_addr, end_addr);
+        Ok(())
+    }
+
     // ADVICE INPUTS
     // --------------------------------------------------------------------------------------------
 
@@ -227,6 +252,7 @@ where
     /// Returns an error if the advice stack is empty.
     pub(super) fn op_advpop(&mut self) -> Result<(), ExecutionError> {
         let value = self.host.borrow_mut().pop_adv_stack(self)?;
+        self.record_consumed_advice(&[value]);
         self.stack.set(0, value);
         self.stack.shift_right(0);
         Ok(())
@@ -239,6 +265,7 @@ where
     /// Returns an error if the advice stack contains fewer than four elements.
     pub(super) fn op_advpopw(&mut self) -> Result<(), ExecutionError> {
         let word: Word = self.host.borrow_mut().pop_adv_stack_word(self)?;
+        self.record_consumed_advice(&word);
 
         self.stack.set(0, word[3]);
         self.stack.set(1, word[2]);
@@ -259,7 +286,10 @@ where
     fn get_valid_address(addr: Felt) -> Result<u32, ExecutionError> {
         let addr = addr.as_int();
         if addr > u32::MAX as u64 {
-            return Err(ExecutionError::MemoryAddressOutOfBounds(addr));
+            return Err(ExecutionError::MemoryAddressOutOfBounds {
+                addr,
+                context: None,
+            });
         }
         Ok(addr as u32)
     }
@@ -12,6 +12,8 @@ mod sys_ops;
 mod u32_ops;
 mod utils;
 
+pub(crate) use sys_ops::LocalFrame;
+
 #[cfg(test)]
 use super::Kernel;
 
@@ -162,9 +164,47 @@ where
         self.system.advance_clock(self.max_cycles)?;
         self.stack.advance_clock();
         self.chiplets.advance_clock();
+        self.manage_overflow_table()?;
+
+        if let Some(interval) = self.cycle_interval {
+            if self.system.clk() % interval == 0 {
+                self.host.borrow_mut().on_cycle_interval(self)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evicts the deepest half of the overflow table rows held in memory into the host if their
+    /// number exceeds the eviction threshold, and restores the most recently evicted rows from the
+    /// host once no rows are held in memory.
+    ///
+    /// Since an operation can remove at most one row from the overflow table, this guarantees
+    /// that the row at the top of the overflow table is always held in memory.
+    fn manage_overflow_table(&mut self) -> Result<(), ExecutionError> {
+        let Some(threshold) = self.overflow_eviction_threshold else {
+            return Ok(());
+        };
+
+        let num_rows = self.stack.num_overflow_rows_in_memory();
+        if num_rows > threshold {
+            let (key, rows) = self.stack.evict_overflow_rows(threshold / 2);
+            self.host.borrow_mut().evict_overflow_rows(key, rows)?;
+        } else if num_rows == 0 && self.stack.last_evicted_overflow_chunk().is_some() {
+            self.restore_overflow_rows()?;
+        }
+
         Ok(())
     }
 
+    /// Requests the most recently evicted chunk of overflow table rows from the host and puts it
+    /// back at the bottom of the overflow table.
+    pub(super) fn restore_overflow_rows(&mut self) -> Result<(), ExecutionError> {
+        let key = self.stack.last_evicted_overflow_chunk().expect("no evicted overflow rows");
+        let rows = self.host.borrow_mut().restore_overflow_rows(key)?;
+        self.stack.restore_overflow_rows(&rows)
+    }
+
     /// Makes sure there is enough memory allocated for the trace to accommodate a new clock cycle.
     fn ensure_trace_capacity(&mut self) {
         self.system.ensure_trace_capacity();
@@ -1,11 +1,25 @@
 use super::{
     super::{
         system::{FMP_MAX, FMP_MIN},
-        ONE,
+        AssertionAction, ContextId, Word, ONE,
     },
     ExecutionError, Felt, Host, Process,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+/// Value written into the canary words surrounding frames of procedure locals.
+const LOCAL_CANARY: Word = [
+    Felt::new(0xdead_beef),
+    Felt::new(0xcafe_f00d),
+    Felt::new(0xdead_beef),
+    Felt::new(0xcafe_f00d),
+];
+
+/// Maximum number of locals a single procedure can declare.
+const MAX_FRAME_SIZE: u64 = u16::MAX as u64;
+
 // SYSTEM OPERATIONS
 // ================================================================================================
 
@@ -15,11 +29,21 @@ where
 {
     /// Pops a value off the stack and asserts that it is equal to ONE.
     ///
+    /// In debug mode, the host may choose to ignore the failed assertion, in which case the value
+    /// is popped off the stack regardless of whether it is equal to ONE.
+    ///
     /// # Errors
     /// Returns an error if the popped value is not ONE.
     pub(super) fn op_assert(&mut self, err_code: u32) -> Result<(), ExecutionError> {
         if self.stack.get(0) != ONE {
-            return Err(self.host.borrow_mut().on_assert_failed(self, err_code));
+            let action = if self.decoder.in_debug_mode() {
+                self.host.borrow_mut().on_assert_failed_in_debug(self, err_code)
+            } else {
+                AssertionAction::Abort
+            };
+            if action == AssertionAction::Abort {
+                return Err(self.host.borrow_mut().on_assert_failed(self, err_code));
+            }
         }
         self.stack.shift_left(1);
         Ok(())
@@ -42,17 +66,34 @@ where
 
     /// Pops an element off the stack and adds it to the current value of `fmp` register.
     ///
+    /// If local canaries are enabled, an allocation of a frame of procedure locals additionally
+    /// moves the `fmp` register by one word to make room for a canary word below the frame, and
+    /// the release of the frame moves it back (see [Self::allocate_local_frame()]).
+    ///
     /// # Errors
-    /// Returns an error if the new value of `fmp` register is greater than or equal to 3 * 2^30.
+    /// Returns an error if:
+    /// - The new value of `fmp` register is greater than or equal to 3 * 2^30.
+    /// - Local canaries are enabled and a canary word around the released frame was overwritten.
     pub(super) fn op_fmpupdate(&mut self) -> Result<(), ExecutionError> {
         let offset = self.stack.get(0);
         let fmp = self.system.fmp();
 
-        let new_fmp = fmp + offset;
+        let frame_update = self.get_local_frame_update(offset);
+        let new_fmp = match frame_update {
+            Some(LocalFrameUpdate::Allocate) => fmp + offset + ONE,
+            Some(LocalFrameUpdate::Release) => fmp + offset - ONE,
+            None => fmp + offset,
+        };
         if new_fmp.as_int() < FMP_MIN || new_fmp.as_int() > FMP_MAX {
             return Err(ExecutionError::InvalidFmpValue(fmp, new_fmp));
         }
 
+        match frame_update {
+            Some(LocalFrameUpdate::Allocate) => self.allocate_local_frame(fmp, new_fmp)?,
+            Some(LocalFrameUpdate::Release) => self.release_local_frame()?,
+            None => (),
+        }
+
         self.system.set_fmp(new_fmp);
         self.stack.shift_left(1);
 
@@ -110,6 +151,117 @@ where
     }
 }
 
+// LOCAL CANARIES
+// ================================================================================================
+
+/// A frame of procedure locals surrounded by canary words.
+///
+/// With local canaries enabled, frames of procedure locals are laid out as follows:
+///
+///   [caller locals] [lower canary] [locals of the frame] [upper canary]
+///
+/// The upper canary of a frame is the lower canary of the frame of a procedure invoked from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LocalFrame {
+    ctx: ContextId,
+    /// Number of locals in the frame.
+    size: u64,
+    /// Address of the canary word below the frame.
+    lower_canary: u32,
+    /// Address of the canary word above the frame.
+    upper_canary: u32,
+}
+
+/// Describes how an `FMPUPDATE` operation affects frames of procedure locals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LocalFrameUpdate {
+    Allocate,
+    Release,
+}
+
+impl<H> Process<H>
+where
+    H: Host,
+{
+    /// Returns the effect of an `FMPUPDATE` operation with the specified offset on frames of
+    /// procedure locals, or None if local canaries are disabled.
+    ///
+    /// A positive offset allocates a new frame. A negative offset releases the innermost frame of
+    /// the current context, but only if its magnitude matches the size of the frame; other
+    /// updates of the `fmp` register are not treated as frame updates.
+    fn get_local_frame_update(&self, offset: Felt) -> Option<LocalFrameUpdate> {
+        let frames = self.local_frames.as_ref()?;
+        if (1..=MAX_FRAME_SIZE).contains(&offset.as_int()) {
+            return Some(LocalFrameUpdate::Allocate);
+        }
+
+        let ctx = self.system.ctx();
+        let size = (-offset).as_int();
+        frames
+            .last()
+            .filter(|frame| frame.ctx == ctx && frame.size == size)
+            .map(|_| LocalFrameUpdate::Release)
+    }
+
+    /// Records the allocation of a frame of locals spanning from `fmp + 2` to `new_fmp` and writes
+    /// canary words at `fmp + 1` and `new_fmp + 1`.
+    ///
+    /// If the lower canary is the upper canary of the enclosing frame, it is checked rather than
+    /// overwritten, so that a corrupted canary is detected before any nested frame is allocated.
+    fn allocate_local_frame(&mut self, fmp: Felt, new_fmp: Felt) -> Result<(), ExecutionError> {
+        let ctx = self.system.ctx();
+        let frame = LocalFrame {
+            ctx,
+            size: new_fmp.as_int() - fmp.as_int() - 1,
+            lower_canary: fmp.as_int() as u32 + 1,
+            upper_canary: new_fmp.as_int() as u32 + 1,
+        };
+
+        let frames = self.local_frames.as_ref().expect("local canaries are disabled");
+        match frames.last() {
+            Some(parent) if parent.ctx == ctx && parent.upper_canary == frame.lower_canary => {
+                self.check_local_canary(ctx, frame.lower_canary)?
+            }
+            _ => self.chiplets.write_mem(ctx, frame.lower_canary, LOCAL_CANARY)?,
+        }
+        self.chiplets.write_mem(ctx, frame.upper_canary, LOCAL_CANARY)?;
+
+        self.local_frames.as_mut().expect("local canaries are disabled").push(frame);
+        Ok(())
+    }
+
+    /// Checks the canary words around the innermost frame of locals and removes the frame.
+    fn release_local_frame(&mut self) -> Result<(), ExecutionError> {
+        let frame = self
+            .local_frames
+            .as_mut()
+            .and_then(|frames| frames.pop())
+            .expect("no frame of locals to release");
+
+        self.check_local_canary(frame.ctx, frame.lower_canary)?;
+        self.check_local_canary(frame.ctx, frame.upper_canary)
+    }
+
+    /// Returns an error if the canary word at the specified address has been overwritten.
+    fn check_local_canary(&self, ctx: ContextId, addr: u32) -> Result<(), ExecutionError> {
+        if self.chiplets.get_mem_value(ctx, addr) == Some(LOCAL_CANARY) {
+            return Ok(());
+        }
+
+        // the canary was written when the frame was allocated, so the address has been written to
+        let write_clk = self
+            .chiplets
+            .get_mem_last_write_clk(ctx, addr)
+            .expect("canary word was never written");
+        Err(ExecutionError::LocalCanaryOverwritten {
+            addr,
+            clk: self.system.clk(),
+            write_clk,
+            context: None,
+        })
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -146,7 +146,10 @@ where
         let a = self.stack.get(1).as_int();
 
         if b == 0 {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
+            return Err(ExecutionError::DivideByZero {
+                clk: self.system.clk(),
+                context: None,
+            });
         }
 
         let q = a / b;
@@ -0,0 +1,181 @@
+use super::{ContextId, DeserializationError, Felt, MemoryAccessRecord, ProcessState, Word};
+use alloc::{collections::BTreeMap, vec::Vec};
+use vm_core::{
+    utils::{ByteReader, ByteWriter, Deserializable, Serializable},
+    WORD_SIZE,
+};
+
+// PROCESS STATE SNAPSHOT
+// ================================================================================================
+
+/// An immutable copy of the state of a process at a given clock cycle.
+///
+/// A snapshot owns all of its data and thus is not tied to the lifetime of the process it was
+/// taken from. This means that a snapshot taken from within a [Host](crate::Host) callback (e.g.,
+/// on every cycle interval) can be sent to another thread for inspection or serialization while
+/// the execution of the program continues.
+///
+/// The snapshot contains the clock cycle, the execution context, the free memory pointer, the
+/// entire stack (including the overflow table), and the memory of all execution contexts which
+/// have been accessed so far. Since the memory is copied in full, taking a snapshot may be
+/// expensive for programs which use a lot of memory.
+///
+/// The snapshot itself implements [ProcessState], and thus can be inspected in the same way as
+/// the live process.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProcessStateSnapshot {
+    clk: u32,
+    ctx: ContextId,
+    fmp: u64,
+    stack: Vec<Felt>,
+    memory: BTreeMap<ContextId, ContextMemory>,
+}
+
+/// Memory of a single execution context.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct ContextMemory {
+    /// (address, value) tuples sorted by address.
+    values: Vec<(u64, Word)>,
+    /// Addresses which have been written to, sorted in ascending order.
+    written: Vec<u32>,
+}
+
+impl ProcessStateSnapshot {
+    /// Returns a snapshot of the current state of the provided process.
+    pub fn new<S: ProcessState + ?Sized>(process: &S) -> Self {
+        let memory = process
+            .get_mem_contexts()
+            .into_iter()
+            .map(|ctx| {
+                let mut values = process.get_mem_state(ctx);
+                values.sort_by_key(|(addr, _)| *addr);
+                let written = process.get_mem_written_addresses(ctx);
+                (ctx, ContextMemory { values, written })
+            })
+            .collect();
+
+        Self {
+            clk: process.clk(),
+            ctx: process.ctx(),
+            fmp: process.fmp(),
+            stack: process.get_stack_state(),
+            memory,
+        }
+    }
+}
+
+impl ProcessState for ProcessStateSnapshot {
+    fn clk(&self) -> u32 {
+        self.clk
+    }
+
+    fn ctx(&self) -> ContextId {
+        self.ctx
+    }
+
+    fn fmp(&self) -> u64 {
+        self.fmp
+    }
+
+    fn get_stack_item(&self, pos: usize) -> Felt {
+        self.stack[pos]
+    }
+
+    fn get_stack_word(&self, word_idx: usize) -> Word {
+        let offset = word_idx * WORD_SIZE;
+        [
+            self.get_stack_item(offset + 3),
+            self.get_stack_item(offset + 2),
+            self.get_stack_item(offset + 1),
+            self.get_stack_item(offset),
+        ]
+    }
+
+    fn get_stack_state(&self) -> Vec<Felt> {
+        self.stack.clone()
+    }
+
+    fn get_mem_value(&self, ctx: ContextId, addr: u32) -> Option<Word> {
+        let values = &self.memory.get(&ctx)?.values;
+        values
+            .binary_search_by_key(&(addr as u64), |(addr, _)| *addr)
+            .ok()
+            .map(|idx| values[idx].1)
+    }
+
+    fn get_mem_state(&self, ctx: ContextId) -> Vec<(u64, Word)> {
+        self.memory.get(&ctx).map(|mem| mem.values.clone()).unwrap_or_default()
+    }
+
+    fn get_mem_contexts(&self) -> Vec<ContextId> {
+        self.memory.keys().copied().collect()
+    }
+
+    fn get_mem_written_addresses(&self, ctx: ContextId) -> Vec<u32> {
+        self.memory.get(&ctx).map(|mem| mem.written.clone()).unwrap_or_default()
+    }
+
+    fn get_mem_accesses(&self, _ctx: ContextId, _addr: u32) -> Vec<MemoryAccessRecord> {
+        // the history of memory accesses is not copied into snapshots
+        Vec::new()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProcessStateSnapshot {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.clk);
+        target.write_u32(self.ctx.into());
+        target.write_u64(self.fmp);
+        target.write_usize(self.stack.len());
+        target.write_many(&self.stack);
+
+        target.write_usize(self.memory.len());
+        for (ctx, memory) in self.memory.iter() {
+            target.write_u32((*ctx).into());
+            target.write_usize(memory.values.len());
+            for (addr, value) in memory.values.iter() {
+                target.write_u64(*addr);
+                target.write_many(value);
+            }
+            target.write_usize(memory.written.len());
+            memory.written.iter().for_each(|addr| target.write_u32(*addr));
+        }
+    }
+}
+
+impl Deserializable for ProcessStateSnapshot {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let clk = source.read_u32()?;
+        let ctx = ContextId::from(source.read_u32()?);
+        let fmp = source.read_u64()?;
+        let stack_len = source.read_usize()?;
+        let stack = source.read_many::<Felt>(stack_len)?;
+
+        let mut memory = BTreeMap::new();
+        let num_contexts = source.read_usize()?;
+        for _ in 0..num_contexts {
+            let ctx = ContextId::from(source.read_u32()?);
+            let num_values = source.read_usize()?;
+            let mut values = Vec::with_capacity(num_values);
+            for _ in 0..num_values {
+                let addr = source.read_u64()?;
+                let value = [source.read()?, source.read()?, source.read()?, source.read()?];
+                values.push((addr, value));
+            }
+            let num_written = source.read_usize()?;
+            let written = (0..num_written).map(|_| source.read_u32()).collect::<Result<_, _>>()?;
+            memory.insert(ctx, ContextMemory { values, written });
+        }
+
+        Ok(Self {
+            clk,
+            ctx,
+            fmp,
+            stack,
+            memory,
+        })
+    }
+}
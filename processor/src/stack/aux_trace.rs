@@ -9,10 +9,8 @@ use miden_air::trace::main_trace::MainTrace;
 /// Describes how to construct execution traces of stack-related auxiliary trace segment columns
 /// (used in multiset checks).
 pub struct AuxTraceBuilder {
-    /// A list of all rows that were added to and then removed from the overflow table.
-    pub(super) overflow_table_rows: Vec<OverflowTableRow>,
-    /// The number of rows in the overflow table when execution begins.
-    pub(super) num_init_rows: usize,
+    /// A list of rows which were in the overflow table when execution began.
+    pub(super) init_rows: Vec<OverflowTableRow>,
 }
 
 impl AuxTraceBuilder {
@@ -32,7 +30,7 @@ impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for AuxTraceBuilder
     /// Initializes the overflow stack auxiliary column.
     fn init_responses(&self, _main_trace: &MainTrace, alphas: &[E]) -> E {
         let mut initial_column_value = E::ONE;
-        for row in self.overflow_table_rows.iter().take(self.num_init_rows) {
+        for row in self.init_rows.iter() {
             let value = (*row).to_value(alphas);
             initial_column_value *= value;
         }
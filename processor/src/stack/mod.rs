@@ -1,4 +1,6 @@
-use super::{Felt, FieldElement, StackInputs, StackOutputs, ONE, STACK_TRACE_WIDTH, ZERO};
+use super::{
+    ExecutionError, Felt, FieldElement, StackInputs, StackOutputs, ONE, STACK_TRACE_WIDTH, ZERO,
+};
 use alloc::vec::Vec;
 use core::cmp;
 use vm_core::{stack::STACK_TOP_SIZE, Word, WORD_SIZE};
@@ -137,6 +139,14 @@ impl Stack {
         result
     }
 
+    /// Returns depth of the stack in the execution context active at the specified clock cycle.
+    ///
+    /// Items of the overflow table which belong to the calling contexts are not included in the
+    /// depth.
+    pub fn get_depth_at(&self, clk: u32) -> usize {
+        self.trace.get_depth_at(clk)
+    }
+
     /// Returns [StackOutputs] consisting of all values on the stack and all addresses in the
     /// overflow table that are required to rebuild the rows in the overflow table.
     pub fn build_stack_outputs(&self) -> StackOutputs {
@@ -273,6 +283,35 @@ impl Stack {
         self.overflow.set_last_row_addr(next_overflow_addr);
     }
 
+    // OVERFLOW TABLE EVICTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of rows in the overflow table which are currently held in memory.
+    pub fn num_overflow_rows_in_memory(&self) -> usize {
+        self.overflow.num_rows_in_memory()
+    }
+
+    /// Returns the commitment to the most recently evicted chunk of overflow table rows, or None
+    /// if no rows are currently evicted from the overflow table.
+    pub fn last_evicted_overflow_chunk(&self) -> Option<Word> {
+        self.overflow.last_evicted_chunk()
+    }
+
+    /// Removes the specified number of rows from the bottom of the overflow table and returns
+    /// them together with a commitment to them.
+    pub fn evict_overflow_rows(&mut self, num_rows: usize) -> (Word, Vec<Felt>) {
+        self.overflow.evict_rows(num_rows)
+    }
+
+    /// Puts the most recently evicted chunk of rows back at the bottom of the overflow table.
+    ///
+    /// # Errors
+    /// Returns an error if the provided values do not match the commitment to the most recently
+    /// evicted chunk.
+    pub fn restore_overflow_rows(&mut self, values: &[Felt]) -> Result<(), ExecutionError> {
+        self.overflow.restore_rows(values)
+    }
+
     // TRACE GENERATION
     // --------------------------------------------------------------------------------------------
 
@@ -1,7 +1,7 @@
-use super::{AuxTraceBuilder, Felt, FieldElement, ZERO};
+use super::{AuxTraceBuilder, ExecutionError, Felt, FieldElement, Word, ZERO};
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
-use vm_core::{utils::uninit_vector, StarkField};
+use vm_core::{crypto::hash::Rpo256, utils::uninit_vector, StarkField};
 
 // OVERFLOW TABLE
 // ================================================================================================
@@ -12,12 +12,20 @@ use vm_core::{utils::uninit_vector, StarkField};
 ///
 /// When `trace_enabled` is set to true, we also record all changes to the table so that we can
 /// reconstruct the overflow table at any clock cycle. This can be used for debugging purposes.
+///
+/// To bound memory usage during deep recursion, rows at the bottom of the table can be evicted
+/// in chunks via [OverflowTable::evict_rows()] and later brought back via
+/// [OverflowTable::restore_rows()]. Eviction does not affect the execution trace, but while rows
+/// are evicted, they are not included in the values returned by [OverflowTable::append_into()].
 pub struct OverflowTable {
-    /// A list of all rows that were added to and then removed from the overflow table.
-    all_rows: Vec<OverflowTableRow>,
-    /// A list of indices into the `all_rows` vector which describes the rows currently in the
-    /// overflow table.
-    active_rows: Vec<usize>,
+    /// A list of rows currently in the overflow table and held in memory, with the row at the top
+    /// of the table in the last position.
+    active_rows: Vec<OverflowTableRow>,
+    /// A list of rows which were in the overflow table when execution began.
+    init_rows: Vec<OverflowTableRow>,
+    /// Commitments to and sizes of the chunks of rows evicted from the bottom of the table. The
+    /// last entry describes the chunk located directly below the rows held in memory.
+    evicted_chunks: Vec<(Word, usize)>,
     /// A map which records the full state of the overflow table at every cycle during which an
     /// update happened. This map is populated only when `trace_enabled` = true.
     trace: BTreeMap<u64, Vec<Felt>>,
@@ -25,8 +33,6 @@ pub struct OverflowTable {
     /// whenever an update happens. This is set to true only when executing programs for debug
     /// purposes.
     trace_enabled: bool,
-    /// The number of rows in the overflow table when execution begins.
-    num_init_rows: usize,
     /// Holds the address (the clock cycle) of the row at to top of the overflow table. When
     /// entering new execution context, this value is set to ZERO, and thus, will differ from the
     /// row address actually at the top of the table.
@@ -39,11 +45,11 @@ impl OverflowTable {
     /// Returns a new [OverflowTable]. The returned table is empty.
     pub fn new(enable_trace: bool) -> Self {
         Self {
-            all_rows: Vec::new(),
             active_rows: Vec::new(),
+            init_rows: Vec::new(),
+            evicted_chunks: Vec::new(),
             trace: BTreeMap::new(),
             trace_enabled: enable_trace,
-            num_init_rows: 0,
             last_row_addr: ZERO,
         }
     }
@@ -56,13 +62,13 @@ impl OverflowTable {
     /// by one. Thus, the first item in the list will become the deepest item in the stack.
     pub fn new_with_inputs(enable_trace: bool, init_values: &[Felt]) -> Self {
         let mut overflow_table = Self::new(enable_trace);
-        overflow_table.num_init_rows = init_values.len();
 
         let mut clk = Felt::MODULUS - init_values.len() as u64;
         for &val in init_values.iter().rev() {
             overflow_table.push(val, Felt::new(clk));
             clk += 1;
         }
+        overflow_table.init_rows = overflow_table.active_rows.clone();
 
         overflow_table
     }
@@ -80,11 +86,9 @@ impl OverflowTable {
         // and thus, no operation can shift the stack to the right at clk = 0.
         debug_assert_ne!(clk, ZERO, "cannot add value to overflow at clk=0");
 
-        // create and record the new row, and also put it at the top of the overflow table
-        let row_idx = self.all_rows.len() as u32;
+        // create the new row and put it at the top of the overflow table
         let new_row = OverflowTableRow::new(clk, value, self.last_row_addr);
-        self.all_rows.push(new_row);
-        self.active_rows.push(row_idx as usize);
+        self.active_rows.push(new_row);
 
         // set the last row address to the address of the newly added row
         self.last_row_addr = clk;
@@ -104,9 +108,8 @@ impl OverflowTable {
             "overflow table is empty in the current context"
         );
 
-        // remove the top entry from the table and determine which table row corresponds to it
-        let last_row_idx = self.active_rows.pop().expect("overflow table is empty");
-        let last_row = &self.all_rows[last_row_idx];
+        // remove the top row from the table
+        let last_row = self.active_rows.pop().expect("overflow table is empty");
 
         // get the value from the last row and also update the last row address to point to the
         // row currently at the top of the table. note that this is context specific. that is,
@@ -134,12 +137,63 @@ impl OverflowTable {
         if last_row_addr != ZERO {
             // if we are not setting the last row address to ZERO, we can set it only to the
             // address of the row actually at the top of the table.
-            let last_row_idx = *self.active_rows.last().expect("overflow table is empty");
-            assert_eq!(self.all_rows[last_row_idx].clk, last_row_addr);
+            let last_row = self.active_rows.last().expect("overflow table is empty");
+            assert_eq!(last_row.clk, last_row_addr);
         }
         self.last_row_addr = last_row_addr;
     }
 
+    /// Removes the specified number of rows from the bottom of the overflow table and returns them
+    /// together with a commitment to them. The rows are returned as a flat list of (clk, val,
+    /// prev) triplets starting with the deepest row.
+    ///
+    /// # Panics
+    /// Panics if the number of rows held in memory is not greater than `num_rows`, since the row
+    /// at the top of the table must always remain in memory.
+    pub fn evict_rows(&mut self, num_rows: usize) -> (Word, Vec<Felt>) {
+        assert!(
+            num_rows < self.active_rows.len(),
+            "cannot evict the top row of the overflow table"
+        );
+
+        let mut values = Vec::with_capacity(num_rows * OverflowTableRow::NUM_ELEMENTS);
+        for row in self.active_rows.drain(..num_rows) {
+            values.extend_from_slice(&[row.clk, row.val, row.prev]);
+        }
+
+        let key: Word = Rpo256::hash_elements(&values).into();
+        self.evicted_chunks.push((key, num_rows));
+
+        (key, values)
+    }
+
+    /// Puts the most recently evicted chunk of rows back at the bottom of the overflow table.
+    ///
+    /// `values` are expected to be in the same format as returned by [OverflowTable::evict_rows()].
+    ///
+    /// # Errors
+    /// Returns an error if the provided values do not match the commitment to the most recently
+    /// evicted chunk.
+    ///
+    /// # Panics
+    /// Panics if no rows have been evicted from the table.
+    pub fn restore_rows(&mut self, values: &[Felt]) -> Result<(), ExecutionError> {
+        let (key, num_rows) = self.evicted_chunks.pop().expect("no rows were evicted");
+        if values.len() != num_rows * OverflowTableRow::NUM_ELEMENTS
+            || Word::from(Rpo256::hash_elements(values)) != key
+        {
+            self.evicted_chunks.push((key, num_rows));
+            return Err(ExecutionError::OverflowTableRestoreFailed(key));
+        }
+
+        let restored = values
+            .chunks(OverflowTableRow::NUM_ELEMENTS)
+            .map(|row| OverflowTableRow::new(row[0], row[1], row[2]));
+        self.active_rows.splice(0..0, restored);
+
+        Ok(())
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -151,20 +205,36 @@ impl OverflowTable {
         self.last_row_addr
     }
 
+    /// Returns the number of rows in the overflow table which are currently held in memory.
+    pub fn num_rows_in_memory(&self) -> usize {
+        self.active_rows.len()
+    }
+
+    /// Returns the commitment to the most recently evicted chunk of rows, or None if no rows are
+    /// currently evicted from the table.
+    pub fn last_evicted_chunk(&self) -> Option<Word> {
+        self.evicted_chunks.last().map(|(key, _)| *key)
+    }
+
     /// Appends the values from the overflow table to the end of the provided vector.
+    ///
+    /// Values of the rows which are currently evicted from the table are not included.
     pub fn append_into(&self, target: &mut Vec<Felt>) {
-        for &idx in self.active_rows.iter().rev() {
-            target.push(self.all_rows[idx].val);
+        for row in self.active_rows.iter().rev() {
+            target.push(row.val);
         }
     }
 
     /// Appends the state of the overflow table at the specified clock cycle to the provided vector.
     ///
+    /// The state at a given clock cycle reflects all updates made by operations executed at prior
+    /// clock cycles.
+    ///
     /// # Panics
     /// Panics when this overflow table was not initialized with `enable_trace` set to true.
     pub fn append_state_into(&self, target: &mut Vec<Felt>, clk: u64) {
         assert!(self.trace_enabled, "overflow trace not enabled");
-        if let Some(x) = self.trace.range(0..=clk).last() {
+        if let Some(x) = self.trace.range(0..clk).last() {
             for item in x.1.iter().rev() {
                 target.push(*item);
             }
@@ -182,10 +252,10 @@ impl OverflowTable {
 
         let mut addrs = unsafe { uninit_vector(self.active_rows.len() + 1) };
         // add the previous address of the first row in the overflow table.
-        addrs[0] = self.all_rows[self.active_rows[0]].prev;
+        addrs[0] = self.active_rows[0].prev;
         // add the address for all the rows in the overflow table.
-        for (i, &row_idx) in self.active_rows.iter().enumerate() {
-            addrs[i + 1] = self.all_rows[row_idx].clk;
+        for (i, row) in self.active_rows.iter().enumerate() {
+            addrs[i + 1] = row.clk;
         }
 
         addrs
@@ -198,8 +268,7 @@ impl OverflowTable {
     /// the auxiliary trace column describing the state of the overflow table at every cycle.
     pub fn into_aux_builder(self) -> AuxTraceBuilder {
         AuxTraceBuilder {
-            num_init_rows: self.num_init_rows,
-            overflow_table_rows: self.all_rows,
+            init_rows: self.init_rows,
         }
     }
 
@@ -209,7 +278,7 @@ impl OverflowTable {
     /// Saves a copy of the current table state into the trace at the specified clock cycle.
     fn save_current_state(&mut self, clk: u64) {
         debug_assert!(self.trace_enabled, "overflow table trace not enabled");
-        let current_state = self.active_rows.iter().map(|&idx| self.all_rows[idx].val).collect();
+        let current_state = self.active_rows.iter().map(|row| row.val).collect();
         self.trace.insert(clk, current_state);
     }
 
@@ -217,12 +286,12 @@ impl OverflowTable {
     // --------------------------------------------------------------------------------------------
 
     #[cfg(test)]
-    pub fn all_rows(&self) -> &[OverflowTableRow] {
-        &self.all_rows
+    pub fn init_rows(&self) -> &[OverflowTableRow] {
+        &self.init_rows
     }
 
     #[cfg(test)]
-    pub fn active_rows(&self) -> &[usize] {
+    pub fn active_rows(&self) -> &[OverflowTableRow] {
         &self.active_rows
     }
 }
@@ -235,7 +304,7 @@ impl OverflowTable {
 /// - The clock cycle at which the stack item was pushed into the overflow table.
 /// - The clock cycle of the value which was at the top of the overflow table when this value
 ///   was pushed onto it.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OverflowTableRow {
     val: Felt,
     clk: Felt,
@@ -243,6 +312,9 @@ pub struct OverflowTableRow {
 }
 
 impl OverflowTableRow {
+    /// Number of field elements needed to describe a single row.
+    pub const NUM_ELEMENTS: usize = 3;
+
     pub fn new(clk: Felt, val: Felt, prev: Felt) -> Self {
         Self { val, clk, prev }
     }
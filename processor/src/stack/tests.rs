@@ -57,7 +57,6 @@ fn initialize_overflow() {
         OverflowTableRow::new(Felt::new(init_addr + 1), Felt::new(2), Felt::new(init_addr)),
         OverflowTableRow::new(Felt::new(init_addr + 2), Felt::new(3), Felt::new(init_addr + 1)),
     ];
-    let expected_overflow_active_rows = vec![0, 1, 2];
 
     // Check the stack state.
     assert_eq!(stack.trace_state(), expected_stack);
@@ -66,8 +65,8 @@ fn initialize_overflow() {
     assert_eq!(stack.helpers_state(), expected_helpers);
 
     // Check the overflow table state.
-    assert_eq!(stack.overflow.active_rows(), expected_overflow_active_rows);
-    assert_eq!(stack.overflow.all_rows(), expected_overflow_rows);
+    assert_eq!(stack.overflow.active_rows(), expected_overflow_rows);
+    assert_eq!(stack.overflow.init_rows(), expected_overflow_rows);
 }
 
 // SHIFT LEFT TEST
@@ -274,6 +273,42 @@ fn start_restore_context() {
     assert_eq!(stack.helpers_state(), build_helpers_partial(0, 0));
 }
 
+// OVERFLOW TABLE EVICTION TEST
+// ================================================================================================
+
+#[test]
+fn evict_restore_overflow_rows() {
+    let stack_inputs = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19];
+    let stack = StackInputs::try_from_ints(stack_inputs).unwrap();
+    let mut stack = Stack::new(&stack, 4, false);
+
+    let init_addr = Felt::MODULUS - 3;
+    let expected_overflow_rows = vec![
+        OverflowTableRow::new(Felt::new(init_addr), ONE, ZERO),
+        OverflowTableRow::new(Felt::new(init_addr + 1), Felt::new(2), Felt::new(init_addr)),
+        OverflowTableRow::new(Felt::new(init_addr + 2), Felt::new(3), Felt::new(init_addr + 1)),
+    ];
+
+    // evict the two deepest rows
+    let (key, rows) = stack.evict_overflow_rows(2);
+    assert_eq!(stack.num_overflow_rows_in_memory(), 1);
+    assert_eq!(stack.last_evicted_overflow_chunk(), Some(key));
+    assert_eq!(stack.overflow.active_rows(), &expected_overflow_rows[2..]);
+
+    // restoring rows which do not match the commitment should fail
+    let mut tampered_rows = rows.clone();
+    tampered_rows[1] += ONE;
+    assert!(stack.restore_overflow_rows(&tampered_rows).is_err());
+    assert!(stack.restore_overflow_rows(&rows[..3]).is_err());
+    assert_eq!(stack.last_evicted_overflow_chunk(), Some(key));
+
+    // restore the evicted rows
+    stack.restore_overflow_rows(&rows).unwrap();
+    assert_eq!(stack.num_overflow_rows_in_memory(), 3);
+    assert_eq!(stack.last_evicted_overflow_chunk(), None);
+    assert_eq!(stack.overflow.active_rows(), expected_overflow_rows);
+}
+
 // TRACE GENERATION
 // ================================================================================================
 
@@ -171,6 +171,11 @@ impl StackTrace {
         }
     }
 
+    /// Returns the depth of the stack at the specified clock cycle.
+    pub fn get_depth_at(&self, clk: u32) -> usize {
+        self.helpers[0][clk as usize].as_int() as usize
+    }
+
     /// Combines all columns of the trace (stack + helpers) into a single array of vectors.
     pub fn into_array(self) -> [Vec<Felt>; STACK_TRACE_WIDTH] {
         let mut trace = Vec::with_capacity(STACK_TRACE_WIDTH);
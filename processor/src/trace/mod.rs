@@ -1,9 +1,10 @@
 use super::{
-    chiplets::AuxTraceBuilder as ChipletsAuxTraceBuilder, crypto::RpoRandomCoin,
-    decoder::AuxTraceBuilder as DecoderAuxTraceBuilder,
+    chiplets::AuxTraceBuilder as ChipletsAuxTraceBuilder,
+    crypto::RpoRandomCoin,
+    decoder::{AuxTraceBuilder as DecoderAuxTraceBuilder, OpGroupTableReport},
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
-    stack::AuxTraceBuilder as StackAuxTraceBuilder, ColMatrix, Digest, Felt, FieldElement, Host,
-    Process, StackTopState,
+    stack::AuxTraceBuilder as StackAuxTraceBuilder,
+    ColMatrix, Digest, Felt, FieldElement, Host, Process, StackTopState,
 };
 use alloc::vec::Vec;
 use miden_air::trace::{
@@ -39,6 +40,53 @@ pub struct AuxTraceBuilders {
     pub(crate) chiplets: ChipletsAuxTraceBuilder,
 }
 
+impl AuxTraceBuilders {
+    /// Builds auxiliary columns of all VM components and returns them in the order in which they
+    /// appear in the auxiliary trace segment: decoder, stack, range checker, and chiplets.
+    #[cfg(not(feature = "concurrent"))]
+    fn build_aux_columns<E: FieldElement<BaseField = Felt>>(
+        &self,
+        main_trace: &MainTrace,
+        rand_elements: &[E],
+    ) -> Vec<Vec<E>> {
+        let decoder = self.decoder.build_aux_columns(main_trace, rand_elements);
+        let stack = self.stack.build_aux_columns(main_trace, rand_elements);
+        let range = self.range.build_aux_columns(main_trace, rand_elements);
+        let chiplets = self.chiplets.build_aux_columns(main_trace, rand_elements);
+
+        decoder.into_iter().chain(stack).chain(range).chain(chiplets).collect()
+    }
+
+    /// Builds auxiliary columns of all VM components and returns them in the order in which they
+    /// appear in the auxiliary trace segment: decoder, stack, range checker, and chiplets.
+    ///
+    /// Columns of different components are independent of each other, and thus, are built in
+    /// multiple threads. The order of the returned columns does not depend on thread scheduling.
+    #[cfg(feature = "concurrent")]
+    fn build_aux_columns<E: FieldElement<BaseField = Felt>>(
+        &self,
+        main_trace: &MainTrace,
+        rand_elements: &[E],
+    ) -> Vec<Vec<E>> {
+        let ((decoder, stack), (range, chiplets)) = rayon::join(
+            || {
+                rayon::join(
+                    || self.decoder.build_aux_columns(main_trace, rand_elements),
+                    || self.stack.build_aux_columns(main_trace, rand_elements),
+                )
+            },
+            || {
+                rayon::join(
+                    || self.range.build_aux_columns(main_trace, rand_elements),
+                    || self.chiplets.build_aux_columns(main_trace, rand_elements),
+                )
+            },
+        );
+
+        decoder.into_iter().chain(stack).chain(range).chain(chiplets).collect()
+    }
+}
+
 /// Execution trace which is generated when a program is executed on the VM.
 ///
 /// The trace consists of the following components:
@@ -53,6 +101,8 @@ pub struct ExecutionTrace {
     aux_trace_builders: AuxTraceBuilders,
     program_info: ProgramInfo,
     stack_outputs: StackOutputs,
+    advice_commitment: Option<Digest>,
+    memory_commitment: Option<Digest>,
     trace_len_summary: TraceLenSummary,
 }
 
@@ -80,6 +130,8 @@ impl ExecutionTrace {
         // create a new program info instance with the underlying kernel
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash.into(), kernel);
+        let advice_commitment = process.advice_commitment();
+        let memory_commitment = process.memory_commitment();
         let (main_trace, aux_trace_hints, trace_len_summary) = finalize_trace(process, rng);
 
         Self {
@@ -89,10 +141,25 @@ impl ExecutionTrace {
             main_trace,
             program_info,
             stack_outputs,
+            advice_commitment,
+            memory_commitment,
             trace_len_summary,
         }
     }
 
+    /// Builds an execution trace for the provided process, which must have already executed a
+    /// program resulting in the specified stack outputs.
+    ///
+    /// This allows building of the execution trace to be measured separately from execution of
+    /// the program.
+    #[cfg(feature = "internals")]
+    pub fn from_process<H>(process: Process<H>, stack_outputs: StackOutputs) -> Self
+    where
+        H: Host,
+    {
+        Self::new(process, stack_outputs)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -111,6 +178,29 @@ impl ExecutionTrace {
         &self.stack_outputs
     }
 
+    /// Returns an RPO hash of all values consumed from the advice stack and of the keys of all
+    /// advice map entries read during the program execution (in the order in which they were
+    /// consumed), or None if advice commitment was not enabled via
+    /// [ExecutionOptions](crate::ExecutionOptions).
+    ///
+    /// The commitment is computed by the processor only, and is not a part of proofs generated
+    /// from this trace: the AIR constraints do not tie the consumed advice to the trace, so a
+    /// verifier could not rely on it. It is intended for diagnostics, e.g., to tell whether two
+    /// executions consumed the same advice.
+    pub fn advice_commitment(&self) -> Option<Digest> {
+        self.advice_commitment
+    }
+
+    /// Returns an RPO hash of the memory inputs the program was executed with, or None if memory
+    /// commitment was not enabled via [ExecutionOptions](crate::ExecutionOptions).
+    ///
+    /// Same as the advice commitment, the commitment is not a part of proofs generated from this
+    /// trace: the initial memory writes are not requested from the chiplets bus, and thus, the AIR
+    /// constraints do not enforce that the memory was initialized to the committed words.
+    pub fn memory_commitment(&self) -> Option<Digest> {
+        self.memory_commitment
+    }
+
     /// Returns the initial state of the top 16 stack registers.
     pub fn init_stack_state(&self) -> StackTopState {
         let mut result = [ZERO; STACK_TOP_SIZE];
@@ -149,6 +239,16 @@ impl ExecutionTrace {
         &self.trace_len_summary
     }
 
+    /// Returns a report describing the contents of the decoder's op group table over this trace.
+    ///
+    /// This is intended for debugging traces for which the op group table column of the auxiliary
+    /// segment is not balanced: the report lists the entries which were removed from the table
+    /// without being inserted (or vice versa), together with the decoder columns at the rows at
+    /// which this happened.
+    pub fn op_group_table_report(&self) -> OpGroupTableReport {
+        OpGroupTableReport::new(&self.main_trace)
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -213,35 +313,9 @@ impl Trace for ExecutionTrace {
             return None;
         }
 
-        // TODO: build auxiliary columns in multiple threads
-
-        // add decoder's running product columns
-        let decoder_aux_columns = self
-            .aux_trace_builders
-            .decoder
-            .build_aux_columns(&self.main_trace, rand_elements);
-
-        // add stack's running product columns
-        let stack_aux_columns =
-            self.aux_trace_builders.stack.build_aux_columns(&self.main_trace, rand_elements);
-
-        // add the range checker's running product columns
-        let range_aux_columns =
-            self.aux_trace_builders.range.build_aux_columns(&self.main_trace, rand_elements);
-
-        // add the running product columns for the chiplets
-        let chiplets = self
-            .aux_trace_builders
-            .chiplets
-            .build_aux_columns(&self.main_trace, rand_elements);
-
-        // combine all auxiliary columns into a single vector
-        let mut aux_columns = decoder_aux_columns
-            .into_iter()
-            .chain(stack_aux_columns)
-            .chain(range_aux_columns)
-            .chain(chiplets)
-            .collect::<Vec<_>>();
+        // build auxiliary columns of all components and combine them into a single vector
+        let mut aux_columns =
+            self.aux_trace_builders.build_aux_columns(&self.main_trace, rand_elements);
 
         // inject random values into the last rows of the trace
         let mut rng = RpoRandomCoin::new(self.program_hash().into());
@@ -261,6 +335,83 @@ impl Trace for ExecutionTrace {
     }
 }
 
+// BLOCK TRACE
+// ================================================================================================
+
+/// Rows of the main execution trace resulting from executing a single code block via
+/// [execute_block()](crate::execute_block).
+///
+/// Unlike an [ExecutionTrace], the rows are neither padded to the next power of two nor followed
+/// by random rows, and no auxiliary trace builders are retained. The rows cover all executed
+/// cycles (including the row holding the final state of the VM), as well as all rows of the range
+/// checker and the chiplets.
+pub struct BlockTrace {
+    columns: Vec<Vec<Felt>>,
+    stack_outputs: StackOutputs,
+    trace_len_summary: TraceLenSummary,
+}
+
+impl BlockTrace {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Builds the trace rows for the provided process.
+    pub(super) fn new<H>(process: Process<H>, stack_outputs: StackOutputs) -> Self
+    where
+        H: Host,
+    {
+        let rng = RpoRandomCoin::new(process.decoder.program_hash());
+        let (main_trace, _, trace_len_summary) = finalize_trace(process, rng);
+
+        // drop the padding rows and the random rows
+        let num_rows = trace_len_summary.trace_len().max(trace_len_summary.main_trace_len() + 1);
+        let columns = (0..main_trace.num_cols())
+            .map(|col_idx| main_trace.get_column(col_idx)[..num_rows].to_vec())
+            .collect();
+
+        Self {
+            columns,
+            stack_outputs,
+            trace_len_summary,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of rows in this trace.
+    pub fn num_rows(&self) -> usize {
+        self.columns[0].len()
+    }
+
+    /// Returns the column at the specified index; columns are laid out in the same way as the
+    /// columns of the main segment of an [ExecutionTrace].
+    pub fn get_column(&self, col_idx: usize) -> &[Felt] {
+        &self.columns[col_idx]
+    }
+
+    /// Copies the values of the row at the specified index into the provided slice.
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds, or if the length of the slice is not equal to
+    /// the number of columns.
+    pub fn read_row_into(&self, row_idx: usize, row: &mut [Felt]) {
+        assert_eq!(row.len(), self.columns.len(), "invalid row length");
+        for (value, column) in row.iter_mut().zip(self.columns.iter()) {
+            *value = column[row_idx];
+        }
+    }
+
+    /// Returns the state of the stack after the block was executed.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
+
+    /// Returns a summary of the lengths of main, range and chiplet traces.
+    pub fn trace_len_summary(&self) -> &TraceLenSummary {
+        &self.trace_len_summary
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -294,8 +445,9 @@ where
     // Generate number of rows for the range trace.
     let range_table_len = range.get_number_range_checker_rows();
 
-    // Get the trace length required to hold all execution trace steps.
-    let max_len = range_table_len.max(clk as usize).max(chiplets.trace_len());
+    // Get the trace length required to hold all execution trace steps; the decoder trace must
+    // also contain at least one HALT row after the last executed cycle.
+    let max_len = range_table_len.max(clk as usize + 1).max(chiplets.trace_len());
 
     // pad the trace length to the next power of two and ensure that there is space for the
     // rows to hold random values
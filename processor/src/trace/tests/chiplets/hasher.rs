@@ -192,6 +192,22 @@ pub fn b_chip_span_with_respan() {
     }
 }
 
+/// Tests that the `b_chip` bus column is balanced when the decoder executes a `SPAN` block with
+/// many operation batches, and thus, absorbs the batches at arbitrary cycles.
+#[test]
+pub fn b_chip_span_with_multiple_batches() {
+    for num_ops in [73, 200] {
+        let program = CodeBlock::new_span(vec![Operation::Noop; num_ops]);
+        let mut trace = build_trace_from_block(&program, &[]);
+
+        let alphas = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+        let aux_columns = trace.build_aux_segment(&[], &alphas).unwrap();
+        let b_chip = aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET);
+
+        assert_eq!(ONE, b_chip[trace.length() - NUM_RAND_ROWS - 1]);
+    }
+}
+
 /// Tests the generation of the `b_chip` bus column when the hasher performs a merge of two code
 /// blocks requested by the decoder. (This also requires a `SPAN` block.)
 #[test]
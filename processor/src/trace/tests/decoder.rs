@@ -6,10 +6,14 @@ use super::{
     },
     Felt,
 };
-use crate::{decoder::build_op_group, ContextId};
+use crate::{
+    decoder::build_op_group, ColMatrix, ContextId, OpGroupMismatchKind, OpGroupTableReport,
+};
+use alloc::string::ToString;
 use miden_air::trace::{
     decoder::{P1_COL_IDX, P2_COL_IDX, P3_COL_IDX},
-    AUX_TRACE_RAND_ELEMENTS,
+    main_trace::MainTrace,
+    AUX_TRACE_RAND_ELEMENTS, STACK_TRACE_OFFSET, TRACE_WIDTH,
 };
 use test_utils::rand::rand_array;
 use vm_core::{code_blocks::CodeBlock, FieldElement, Operation, Word, ONE, ZERO};
@@ -281,6 +285,27 @@ fn decoder_p1_loop_with_repeat() {
     }
 }
 
+#[test]
+#[allow(clippy::needless_range_loop)]
+fn decoder_p1_span_with_multiple_batches() {
+    // in the first span, the op group at position 5 of the second batch is non-zero; in the
+    // second span, the last batch contains a single operation
+    let mut ops = vec![Operation::Push(ONE); 11];
+    ops.extend([Operation::Drop; 11]);
+    for ops in [ops, vec![Operation::Noop; 73], vec![Operation::Noop; 200]] {
+        let mut trace = build_trace_from_ops(ops, &[]);
+        let alphas = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+        let aux_columns = trace.build_aux_segment(&[], &alphas).unwrap();
+        let p1 = aux_columns.get_column(P1_COL_IDX);
+
+        // the table must be empty after the END operation is executed
+        let end_row = trace.trace_len_summary().main_trace_len();
+        for i in end_row..(p1.len() - NUM_RAND_ROWS) {
+            assert_eq!(ONE, p1[i]);
+        }
+    }
+}
+
 // BLOCK HASH TABLE TESTS
 // ================================================================================================
 
@@ -714,6 +739,41 @@ fn decoder_p3_trace_two_batches() {
     }
 }
 
+#[test]
+fn decoder_p3_report() {
+    let (ops, iv) = build_span_with_respan_ops();
+    let trace = build_trace_from_ops(ops, &[]);
+
+    // all groups of both batches are inserted into and removed from the table
+    let report = trace.op_group_table_report();
+    assert!(report.is_consistent());
+    let batches = report.batches();
+    assert_eq!(batches.len(), 2);
+    assert_eq!((batches[0].row, batches[0].num_inserted, batches[0].num_removed), (0, 7, 7));
+    assert_eq!(batches[0].num_immediates, 7);
+    assert_eq!((batches[1].row, batches[1].num_inserted, batches[1].num_removed), (9, 3, 3));
+    assert_eq!(batches[1].num_immediates, 2);
+
+    // corrupt the value pushed onto the stack by the first PUSH operation; the removed entry
+    // then does not match the inserted one, which is never removed
+    let mut columns = trace.main_trace.get_column_range(0..TRACE_WIDTH);
+    columns[STACK_TRACE_OFFSET][2] += ONE;
+    let main_trace = MainTrace::new(ColMatrix::new(columns));
+    let report = OpGroupTableReport::new(&main_trace);
+    assert!(!report.is_consistent());
+
+    let mismatches = report.mismatches();
+    assert_eq!(mismatches.len(), 2);
+    assert_eq!(mismatches[0].row, 0);
+    assert_eq!(mismatches[0].kind, OpGroupMismatchKind::UnremovedEntry);
+    assert_eq!(mismatches[0].entry.group_value, iv[0]);
+    assert_eq!(mismatches[1].row, 1);
+    assert_eq!(mismatches[1].kind, OpGroupMismatchKind::MissingEntry { is_immediate: true });
+    assert_eq!(mismatches[1].entry.group_value, iv[0] + ONE);
+    assert_eq!(mismatches[1].decoder_rows[0].row, 1);
+    assert!(report.to_string().contains("immediate value"));
+}
+
 // HELPER STRUCTS AND METHODS
 // ================================================================================================
 
@@ -1,8 +1,11 @@
 use super::{
     super::chiplets::init_state_from_words, ExecutionTrace, Felt, FieldElement, Process, Trace,
-    NUM_RAND_ROWS,
+    AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, NUM_RAND_ROWS, STACK_TRACE_OFFSET, TRACE_WIDTH,
+};
+use crate::{
+    execute_block, AdviceInputs, DefaultHost, ExecutionOptions, MemAdviceProvider, ProcessState,
+    StackInputs,
 };
-use crate::{AdviceInputs, DefaultHost, ExecutionOptions, MemAdviceProvider, StackInputs};
 use alloc::vec::Vec;
 use test_utils::rand::rand_array;
 use vm_core::{
@@ -51,3 +54,85 @@ pub fn build_trace_from_ops_with_inputs(
     process.execute_code_block(&program, &CodeBlockTable::default()).unwrap();
     ExecutionTrace::new(process, StackOutputs::default())
 }
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn aux_segment_column_order() {
+    let operations = vec![Operation::Push(ONE), Operation::U32split, Operation::HPerm];
+    let mut trace = build_trace_from_ops(operations, &[1, 2, 3, 4]);
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_segment = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_eq!(AUX_TRACE_WIDTH, aux_segment.num_cols());
+
+    // columns must be laid out in the same order regardless of whether they were built
+    // concurrently or not
+    let builders = &trace.aux_trace_builders;
+    let main_trace = &trace.main_trace;
+    let expected = builders
+        .decoder
+        .build_aux_columns(main_trace, &rand_elements)
+        .into_iter()
+        .chain(builders.stack.build_aux_columns(main_trace, &rand_elements))
+        .chain(builders.range.build_aux_columns(main_trace, &rand_elements))
+        .chain(builders.chiplets.build_aux_columns(main_trace, &rand_elements));
+
+    let num_rows = trace.length() - NUM_RAND_ROWS;
+    for (i, column) in expected.enumerate() {
+        assert_eq!(column[..num_rows], aux_segment.get_column(i)[..num_rows]);
+    }
+}
+
+#[test]
+fn trace_includes_halt_row() {
+    // for some of these, the number of executed cycles is one less than a power of two
+    for num_ops in 56..64 {
+        let mut trace = build_trace_from_ops(vec![Operation::Noop; num_ops], &[]);
+        let main_trace_len = trace.trace_len_summary().main_trace_len();
+        assert!(main_trace_len + NUM_RAND_ROWS < trace.length());
+
+        let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+        assert!(trace.build_aux_segment(&[], &rand_elements).is_some());
+    }
+}
+
+#[test]
+fn execute_block_from_state() {
+    // build a state with a non-empty stack and memory by running a few operations
+    let stack_inputs = StackInputs::try_from_ints([1, 2, 3]).unwrap();
+    let mut process =
+        Process::new(Kernel::default(), stack_inputs, DefaultHost::default(), Default::default());
+    let ops = vec![Operation::Push(Felt::new(7)), Operation::Push(Felt::new(5)), Operation::MStore];
+    process
+        .execute_code_block(&CodeBlock::new_span(ops), &CodeBlockTable::default())
+        .unwrap();
+    let state = process.snapshot();
+
+    // the block must see the stack and memory of the state
+    let block = CodeBlock::new_span(vec![Operation::Push(Felt::new(5)), Operation::MLoad]);
+    let trace = execute_block(
+        &block,
+        &CodeBlockTable::default(),
+        Kernel::default(),
+        &state,
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+
+    let mut first_row = [ZERO; TRACE_WIDTH];
+    trace.read_row_into(0, &mut first_row);
+    let stack_range = STACK_TRACE_OFFSET..STACK_TRACE_OFFSET + 16;
+    assert_eq!(first_row[stack_range], state.get_stack_state()[..16]);
+    let expected = [7, 7, 3, 2, 1].map(Felt::new);
+    assert_eq!(trace.stack_outputs().stack()[..5], expected);
+
+    // the rows cover the executed cycles and the memory chiplet rows, without any padding: the
+    // block executes in 4 cycles (SPAN, PUSH, MLOAD, END), and the memory chiplet holds the
+    // initial write and the read
+    let summary = trace.trace_len_summary();
+    assert_eq!(summary.main_trace_len(), 4);
+    assert_eq!(summary.chiplets_trace_len().memory_chiplet_len(), 2);
+    assert_eq!(trace.num_rows(), summary.trace_len().max(summary.main_trace_len() + 1));
+}
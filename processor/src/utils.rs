@@ -11,7 +11,7 @@ pub use vm_core::utils::*;
 
 /// Returns the number of rows in the provided execution trace assumed to be in column-major form
 /// and contain at least one column.
-pub(crate) fn get_trace_len(trace: &[Vec<Felt>]) -> usize {
+pub(crate) fn get_trace_len<E>(trace: &[Vec<E>]) -> usize {
     trace[0].len()
 }
 
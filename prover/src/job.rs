@@ -0,0 +1,302 @@
+use super::{
+    prove_with_token, CancellationToken, ExecutionError, ExecutionProof, Host, Program,
+    ProvingOptions, StackInputs, StackOutputs,
+};
+use std::{
+    future::Future,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{mpsc, Arc, Condvar, Mutex, OnceLock},
+    task::{Context, Poll, Waker},
+    thread::{self, JoinHandle},
+};
+
+/// The result of a proving job.
+pub type ProvingResult = Result<(StackOutputs, ExecutionProof), ExecutionError>;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+// ASYNC PROVER
+// ================================================================================================
+
+/// Executes and proves the specified `program` on a background worker thread, and returns a
+/// handle to the proving job.
+///
+/// This function behaves the same way as [prove()](crate::prove), but it does not block the calling thread.
+/// Instead, the returned [ProvingHandle] can be awaited (it implements [Future] and does not
+/// depend on any specific async runtime), waited on synchronously, or cancelled.
+///
+/// Jobs submitted via this function are executed on a shared [ProvingPool] with one worker per
+/// available CPU core. To control the number of jobs proven concurrently, create a dedicated
+/// [ProvingPool] and submit jobs to it via [ProvingPool::prove()].
+pub fn prove_async<H>(
+    program: Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ProvingOptions,
+) -> ProvingHandle
+where
+    H: Host + Send + 'static,
+{
+    static DEFAULT_POOL: OnceLock<ProvingPool> = OnceLock::new();
+    DEFAULT_POOL
+        .get_or_init(|| {
+            let num_workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+            ProvingPool::new(num_workers)
+        })
+        .prove(program, stack_inputs, host, options)
+}
+
+// PROVING POOL
+// ================================================================================================
+
+/// A pool of worker threads which execute and prove programs in the background.
+///
+/// Jobs are executed in the order in which they were submitted, and each worker executes one job
+/// at a time. Dropping the pool waits for all submitted jobs to complete.
+pub struct ProvingPool {
+    sender: Option<Mutex<mpsc::Sender<Job>>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl ProvingPool {
+    /// Returns a new pool with the specified number of worker threads.
+    ///
+    /// # Panics
+    /// Panics if `num_workers` is zero.
+    pub fn new(num_workers: usize) -> Self {
+        assert!(num_workers > 0, "proving pool must have at least one worker");
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..num_workers)
+            .map(|i| {
+                let receiver = receiver.clone();
+                thread::Builder::new()
+                    .name(format!("miden-prover-{i}"))
+                    .spawn(move || loop {
+                        // the lock is released before the job is executed
+                        let job = receiver.lock().expect("job queue poisoned").recv();
+                        match job {
+                            Ok(job) => job(),
+                            Err(_) => break,
+                        }
+                    })
+                    .expect("failed to spawn proving worker")
+            })
+            .collect();
+
+        Self {
+            sender: Some(Mutex::new(sender)),
+            workers,
+        }
+    }
+
+    /// Returns the number of worker threads in this pool.
+    pub fn num_workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Submits a job which executes and proves the specified `program` to this pool, and returns
+    /// a handle to the job.
+    ///
+    /// The job is cancelled via a token created for it by the pool, and thus, dropping the handle
+    /// cancels the job.
+    pub fn prove<H>(
+        &self,
+        program: Program,
+        stack_inputs: StackInputs,
+        host: H,
+        options: ProvingOptions,
+    ) -> ProvingHandle
+    where
+        H: Host + Send + 'static,
+    {
+        self.submit(program, stack_inputs, host, options, CancellationToken::new(), true)
+    }
+
+    /// Submits a job which executes and proves the specified `program` to this pool, and returns
+    /// a handle to the job.
+    ///
+    /// The job is cancelled once the provided `token` is cancelled, which allows a single token to
+    /// cancel several jobs. Since the token is owned by the caller, dropping the handle does not
+    /// cancel it.
+    pub fn prove_with_token<H>(
+        &self,
+        program: Program,
+        stack_inputs: StackInputs,
+        host: H,
+        options: ProvingOptions,
+        token: CancellationToken,
+    ) -> ProvingHandle
+    where
+        H: Host + Send + 'static,
+    {
+        self.submit(program, stack_inputs, host, options, token, false)
+    }
+
+    /// Submits the job to the workers of this pool. If `cancel_on_drop` is set, the token is
+    /// cancelled when the returned handle is dropped.
+    fn submit<H>(
+        &self,
+        program: Program,
+        stack_inputs: StackInputs,
+        host: H,
+        options: ProvingOptions,
+        token: CancellationToken,
+        cancel_on_drop: bool,
+    ) -> ProvingHandle
+    where
+        H: Host + Send + 'static,
+    {
+        let state = Arc::new(JobState::default());
+        let job: Job = Box::new({
+            let state = state.clone();
+            let token = token.clone();
+            move || {
+                // skip jobs which were cancelled while waiting in the queue
+                let result = if token.is_cancelled() {
+                    Ok(Err(ExecutionError::Cancelled))
+                } else {
+                    panic::catch_unwind(AssertUnwindSafe(|| {
+                        prove_with_token(&program, stack_inputs, host, options, token)
+                    }))
+                };
+                state.complete(result);
+            }
+        });
+
+        let sender = self.sender.as_ref().expect("proving pool has been shut down");
+        sender
+            .lock()
+            .expect("job queue poisoned")
+            .send(job)
+            .expect("proving workers are gone");
+
+        ProvingHandle {
+            state,
+            token,
+            cancel_on_drop,
+        }
+    }
+}
+
+impl Drop for ProvingPool {
+    fn drop(&mut self) {
+        // closing the channel makes the workers exit once the queue has been drained
+        self.sender.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+// PROVING HANDLE
+// ================================================================================================
+
+/// A handle to a proving job submitted via [prove_async()] or [ProvingPool::prove()].
+///
+/// The handle can be awaited to obtain the result of the job, or waited on synchronously via
+/// [ProvingHandle::wait()]. Cancelling the job makes it complete with [ExecutionError::Cancelled]
+/// as soon as the VM observes the cancellation: at the next code block boundary during execution,
+/// or before STARK proof generation starts.
+///
+/// Dropping the handle cancels the job as well, unless the job was submitted with a token provided
+/// by the caller (see [ProvingPool::prove_with_token()]). Jobs which are still queued or executing
+/// are thus aborted once their results are no longer needed (e.g., because the client requesting
+/// the proof has disconnected).
+///
+/// # Limitations
+/// Cancellation is not observed during STARK proof generation: the prover does not check the
+/// token while building the trace LDE, evaluating constraints, or running FRI. A job cancelled
+/// after proof generation has started keeps its worker busy until the proof is complete, and
+/// completes with the proof rather than with [ExecutionError::Cancelled].
+///
+/// If the job panics, the panic is propagated to the thread which polls or waits on the handle.
+pub struct ProvingHandle {
+    state: Arc<JobState>,
+    token: CancellationToken,
+    cancel_on_drop: bool,
+}
+
+impl ProvingHandle {
+    /// Requests cancellation of the job.
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns the token used to cancel the job.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Returns true if the job has completed (successfully or not).
+    pub fn is_finished(&self) -> bool {
+        self.state.inner.lock().expect("job state poisoned").result.is_some()
+    }
+
+    /// Blocks the current thread until the job completes, and returns its result.
+    pub fn wait(self) -> ProvingResult {
+        let mut inner = self.state.inner.lock().expect("job state poisoned");
+        loop {
+            if let Some(result) = inner.result.take() {
+                return result.unwrap_or_else(|panic| panic::resume_unwind(panic));
+            }
+            inner = self.state.completed.wait(inner).expect("job state poisoned");
+        }
+    }
+}
+
+impl Future for ProvingHandle {
+    type Output = ProvingResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut inner = self.state.inner.lock().expect("job state poisoned");
+        match inner.result.take() {
+            Some(result) => Poll::Ready(result.unwrap_or_else(|panic| panic::resume_unwind(panic))),
+            None => {
+                inner.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for ProvingHandle {
+    fn drop(&mut self) {
+        // tokens provided by the caller may be shared with other jobs, and thus, are cancelled
+        // only explicitly
+        if self.cancel_on_drop {
+            self.token.cancel();
+        }
+    }
+}
+
+// JOB STATE
+// ================================================================================================
+
+/// State of a proving job shared between the worker executing the job and the job handle.
+#[derive(Default)]
+struct JobState {
+    inner: Mutex<JobStateInner>,
+    completed: Condvar,
+}
+
+#[derive(Default)]
+struct JobStateInner {
+    result: Option<thread::Result<ProvingResult>>,
+    waker: Option<Waker>,
+}
+
+impl JobState {
+    /// Records the result of the job and wakes up the handle waiting for it.
+    fn complete(&self, result: thread::Result<ProvingResult>) {
+        let mut inner = self.inner.lock().expect("job state poisoned");
+        inner.result = Some(result);
+        if let Some(waker) = inner.waker.take() {
+            waker.wake();
+        }
+        self.completed.notify_all();
+    }
+}
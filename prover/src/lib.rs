@@ -7,7 +7,6 @@ use processor::{
         Blake3_192, Blake3_256, ElementHasher, RandomCoin, Rpo256, RpoRandomCoin, WinterRandomCoin,
     },
     math::{Felt, FieldElement},
-    ExecutionTrace,
 };
 use tracing::{event, instrument, Level};
 use winter_prover::{
@@ -22,13 +21,21 @@ use {std::time::Instant, winter_prover::Trace};
 #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
 mod gpu;
 
+#[cfg(feature = "std")]
+mod job;
+#[cfg(feature = "std")]
+pub use job::{prove_async, ProvingHandle, ProvingPool, ProvingResult};
+
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions};
+pub use air::{
+    CancellationToken, DeserializationError, ExecutionProof, FieldExtension, HashFunction,
+    ProvingOptions,
+};
 pub use processor::{
-    crypto, math, utils, AdviceInputs, Digest, ExecutionError, Host, InputError, MemAdviceProvider,
-    Program, StackInputs, StackOutputs, Word,
+    crypto, math, utils, AdviceInputs, Digest, ExecutionError, ExecutionTrace, Host, InputError,
+    MemAdviceProvider, Program, StackInputs, StackOutputs, Word,
 };
 pub use winter_prover::StarkProof;
 
@@ -44,21 +51,49 @@ pub use winter_prover::StarkProof;
 ///
 /// # Errors
 /// Returns an error if program execution or STARK proof generation fails for any reason.
-#[instrument("prove_program", skip_all)]
 pub fn prove<H>(
     program: &Program,
     stack_inputs: StackInputs,
     host: H,
     options: ProvingOptions,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    H: Host,
+{
+    prove_with_token(program, stack_inputs, host, options, CancellationToken::new())
+}
+
+/// Executes and proves the specified `program` in the same way as [prove()], aborting the job once
+/// the provided `token` is cancelled.
+///
+/// The token is checked at code block boundaries during execution, and once more before STARK
+/// proof generation starts. STARK proof generation itself cannot be interrupted: a job cancelled
+/// after it started runs to completion.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason, or
+/// [ExecutionError::Cancelled] if the token was cancelled before proof generation started.
+#[instrument("prove_program", skip_all)]
+pub fn prove_with_token<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ProvingOptions,
+    token: CancellationToken,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
 where
     H: Host,
 {
     // execute the program to create an execution trace
     #[cfg(feature = "std")]
     let now = Instant::now();
-    let trace =
-        processor::execute(program, stack_inputs.clone(), host, *options.execution_options())?;
+    let trace = processor::execute_with_token(
+        program,
+        stack_inputs.clone(),
+        host,
+        *options.execution_options(),
+        token.clone(),
+    )?;
     #[cfg(feature = "std")]
     event!(
         Level::INFO,
@@ -69,6 +104,34 @@ where
         now.elapsed().as_millis()
     );
 
+    if token.is_cancelled() {
+        return Err(ExecutionError::Cancelled);
+    }
+
+    let stack_outputs = trace.stack_outputs().clone();
+    let proof = prove_trace(trace, stack_inputs, options)?;
+
+    Ok((stack_outputs, proof))
+}
+
+/// Generates a STARK-based proof of the program execution described by the provided execution
+/// `trace`.
+///
+/// This function performs only the proving part of [prove()], and thus, can be used to generate
+/// proofs for traces which have been produced separately (e.g., via [processor::execute()]).
+///
+/// * `stack_inputs` must be the stack inputs against which the trace was generated.
+/// * `options` defines parameters for STARK proof generation. Execution options contained in
+///   `options` are ignored since the program has already been executed.
+///
+/// # Errors
+/// Returns an error if STARK proof generation fails for any reason.
+#[instrument("prove_trace", skip_all)]
+pub fn prove_trace(
+    trace: ExecutionTrace,
+    stack_inputs: StackInputs,
+    options: ProvingOptions,
+) -> Result<ExecutionProof, ExecutionError> {
     let stack_outputs = trace.stack_outputs().clone();
     let hash_fn = options.hash_fn();
 
@@ -77,30 +140,26 @@ where
         HashFunction::Blake3_192 => ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
             options,
             stack_inputs,
-            stack_outputs.clone(),
+            stack_outputs,
         )
         .prove(trace),
         HashFunction::Blake3_256 => ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
             options,
             stack_inputs,
-            stack_outputs.clone(),
+            stack_outputs,
         )
         .prove(trace),
         HashFunction::Rpo256 => {
-            let prover = ExecutionProver::<Rpo256, RpoRandomCoin>::new(
-                options,
-                stack_inputs,
-                stack_outputs.clone(),
-            );
+            let prover =
+                ExecutionProver::<Rpo256, RpoRandomCoin>::new(options, stack_inputs, stack_outputs);
             #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
             let prover = gpu::MetalRpoExecutionProver(prover);
             prover.prove(trace)
         }
     }
     .map_err(ExecutionError::ProverError)?;
-    let proof = ExecutionProof::new(proof, hash_fn);
 
-    Ok((stack_outputs, proof))
+    Ok(ExecutionProof::new(proof, hash_fn))
 }
 
 // PROVER
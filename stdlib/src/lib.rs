@@ -5,6 +5,8 @@ use assembly::{
     Version,
 };
 
+pub mod math;
+
 // STANDARD LIBRARY
 // ================================================================================================
 
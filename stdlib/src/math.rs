@@ -0,0 +1,178 @@
+// MONTGOMERY CONTEXTS
+// ================================================================================================
+
+/// Returns the Montgomery context of the specified modulus expected by the modular arithmetic
+/// procedures in `std::math::u64` (e.g., `mont_mul` or `mod_exp`).
+///
+/// The context consists of two words which must be stored in memory at consecutive addresses
+/// starting at the address passed to the procedures:
+/// - `[m_hi, m_lo, n_hi, n_lo]`, where n = -m^(-1) mod 2^64.
+/// - `[r2_hi, r2_lo, 0, 0]`, where r2 = 2^128 mod m.
+///
+/// # Panics
+/// Panics if the modulus is even or smaller than 3.
+pub fn u64_montgomery_context(modulus: u64) -> [[u32; 4]; 2] {
+    assert!(modulus % 2 == 1 && modulus > 1, "modulus must be odd and greater than 1");
+
+    // Newton's iteration doubles the number of correct bits of the inverse; m * m = 1 mod 8 for
+    // odd m, so the initial value is correct to 3 bits
+    let mut inv = modulus;
+    for _ in 0..5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inv)));
+    }
+    let n = inv.wrapping_neg();
+
+    let r = (1u128 << 64) % modulus as u128;
+    let r2 = (r * r % modulus as u128) as u64;
+
+    let [m_hi, m_lo] = u64_to_limbs(modulus);
+    let [n_hi, n_lo] = u64_to_limbs(n);
+    let [r2_hi, r2_lo] = u64_to_limbs(r2);
+    [[m_hi, m_lo, n_hi, n_lo], [r2_hi, r2_lo, 0, 0]]
+}
+
+/// Returns the Montgomery context of the specified modulus expected by the modular arithmetic
+/// procedures in `std::math::u256` (e.g., `mont_mul_unsafe` or `mod_exp_unsafe`).
+///
+/// The modulus is provided as eight 32-bit limbs in little-endian order. The context consists of
+/// six words which must be stored in memory at consecutive addresses starting at the address
+/// passed to the procedures. The first two words hold m, the next two words hold
+/// n = -m^(-1) mod 2^256, and the last two words hold 2^512 mod m. Each value is stored with its 4
+/// most significant limbs in the first word, and the most significant limb first.
+///
+/// # Panics
+/// Panics if the modulus is even or smaller than 3.
+pub fn u256_montgomery_context(modulus: [u32; 8]) -> [[u32; 4]; 6] {
+    let one = {
+        let mut one = [0; 8];
+        one[0] = 1;
+        one
+    };
+    assert!(modulus[0] % 2 == 1 && modulus != one, "modulus must be odd and greater than 1");
+
+    // Newton's iteration doubles the number of correct bits of the inverse; m * m = 1 mod 8 for
+    // odd m, so the initial value is correct to 3 bits
+    let mut inv = modulus;
+    for _ in 0..7 {
+        let (correction, _) =
+            u256_sub(&[2, 0, 0, 0, 0, 0, 0, 0], &u256_wrapping_mul(&modulus, &inv));
+        inv = u256_wrapping_mul(&inv, &correction);
+    }
+    let (n, _) = u256_sub(&[0; 8], &inv);
+
+    // compute 2^512 mod m by repeated doubling of 1
+    let mut r2 = one;
+    for _ in 0..512 {
+        let (sum, carry) = u256_add(&r2, &r2);
+        let (diff, borrow) = u256_sub(&sum, &modulus);
+        r2 = if carry || !borrow { diff } else { sum };
+    }
+
+    let [m_hi, m_lo] = u256_to_words(&modulus);
+    let [n_hi, n_lo] = u256_to_words(&n);
+    let [r2_hi, r2_lo] = u256_to_words(&r2);
+    [m_hi, m_lo, n_hi, n_lo, r2_hi, r2_lo]
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn u64_to_limbs(value: u64) -> [u32; 2] {
+    [(value >> 32) as u32, value as u32]
+}
+
+fn u256_to_words(value: &[u32; 8]) -> [[u32; 4]; 2] {
+    [
+        [value[7], value[6], value[5], value[4]],
+        [value[3], value[2], value[1], value[0]],
+    ]
+}
+
+/// Computes a + b and returns the result together with the carry.
+fn u256_add(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], bool) {
+    let mut result = [0; 8];
+    let mut carry = 0;
+    for i in 0..8 {
+        let sum = a[i] as u64 + b[i] as u64 + carry;
+        result[i] = sum as u32;
+        carry = sum >> 32;
+    }
+    (result, carry == 1)
+}
+
+/// Computes a - b and returns the result together with the borrow.
+fn u256_sub(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], bool) {
+    let mut result = [0; 8];
+    let mut borrow = false;
+    for i in 0..8 {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u32);
+        result[i] = diff;
+        borrow = b1 || b2;
+    }
+    (result, borrow)
+}
+
+/// Computes a * b mod 2^256.
+fn u256_wrapping_mul(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let mut result = [0; 8];
+    for i in 0..8 {
+        let mut carry = 0;
+        for j in 0..(8 - i) {
+            let product = a[i] as u64 * b[j] as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+    }
+    result
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{u256_montgomery_context, u256_wrapping_mul, u64_montgomery_context};
+
+    #[test]
+    fn u64_context() {
+        let modulus = 0xffff_ffff_ffff_ffc5; // 2^64 - 59
+        let [[m_hi, m_lo, n_hi, n_lo], [r2_hi, r2_lo, zero0, zero1]] =
+            u64_montgomery_context(modulus);
+        let to_u64 = |hi: u32, lo: u32| ((hi as u64) << 32) | lo as u64;
+
+        assert_eq!(modulus, to_u64(m_hi, m_lo));
+        assert_eq!(u64::MAX, modulus.wrapping_mul(to_u64(n_hi, n_lo)));
+        // 2^64 mod m = 59, so 2^128 mod m = 59^2
+        assert_eq!(59 * 59, to_u64(r2_hi, r2_lo));
+        assert_eq!([0, 0], [zero0, zero1]);
+    }
+
+    #[test]
+    fn u256_context() {
+        // 2^256 - 189
+        let mut modulus = [u32::MAX; 8];
+        modulus[0] -= 188;
+        let context = u256_montgomery_context(modulus);
+
+        let limbs = |hi: [u32; 4], lo: [u32; 4]| {
+            let mut limbs = [0u32; 8];
+            for (i, limb) in hi.iter().chain(lo.iter()).enumerate() {
+                limbs[7 - i] = *limb;
+            }
+            limbs
+        };
+
+        assert_eq!(modulus, limbs(context[0], context[1]));
+        let n = limbs(context[2], context[3]);
+        assert_eq!([u32::MAX; 8], u256_wrapping_mul(&modulus, &n));
+        // 2^256 mod m = 189, so 2^512 mod m = 189^2
+        assert_eq!([189 * 189, 0, 0, 0, 0, 0, 0, 0], limbs(context[4], context[5]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn even_modulus() {
+        u64_montgomery_context(1 << 32);
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::BinaryHeap;
+use test_utils::{rand::rand_vector, Felt};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Address of the heap used in the tests.
+const HEAP_PTR: u32 = 1000;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn test_heap_push_pop() {
+    let entries = [[1, 2, 3, 5], [4, 5, 6, 9], [7, 8, 9, 1], [10, 11, 12, 7], [13, 14, 15, 3]];
+
+    let mut source = String::from("use.std::collections::heap\nbegin\n");
+    for entry in entries.iter() {
+        source.push_str(&push_entry(entry));
+    }
+
+    // entries are popped in the order of their priorities, i.e., their last elements
+    let mut sorted = entries;
+    sorted.sort_by_key(|entry| core::cmp::Reverse(entry[3]));
+    for entry in sorted.iter() {
+        source.push_str(&pop_entry(entry));
+    }
+    source.push_str(&format!("push.{HEAP_PTR} exec.heap::size\nend"));
+
+    build_test!(&source).expect_stack(&[0]);
+}
+
+#[test]
+fn test_heap_peek() {
+    let source = format!(
+        "
+    use.std::collections::heap
+
+    begin
+      {}
+      {}
+      {}
+      push.{HEAP_PTR} exec.heap::peek
+      push.{HEAP_PTR} exec.heap::size
+    end
+    ",
+        push_entry(&[1, 2, 3, 4]),
+        push_entry(&[5, 6, 7, 8]),
+        push_entry(&[9, 10, 11, 6])
+    );
+
+    build_test!(&source).expect_stack(&[3, 8, 7, 6, 5]);
+}
+
+#[test]
+fn test_heap_empty() {
+    let size = format!(
+        "
+    use.std::collections::heap
+
+    begin
+      push.{HEAP_PTR} exec.heap::size
+    end
+    "
+    );
+    build_test!(&size).expect_stack(&[0]);
+
+    // peeking at and popping from an empty heap should fail
+    for procedure in ["peek", "pop"] {
+        let source = format!(
+            "
+    use.std::collections::heap
+
+    begin
+      push.{HEAP_PTR} exec.heap::{procedure}
+    end
+    "
+        );
+        assert!(build_test!(&source).execute().is_err());
+    }
+
+    // popping the last entry should leave the heap empty
+    let source = format!(
+        "
+    use.std::collections::heap
+
+    begin
+      {}
+      {}
+      push.{HEAP_PTR} exec.heap::pop
+    end
+    ",
+        push_entry(&[1, 2, 3, 4]),
+        pop_entry(&[1, 2, 3, 4])
+    );
+    assert!(build_test!(&source).execute().is_err());
+}
+
+#[test]
+fn test_heap_random() {
+    // interleave pushes and pops, and check that the entries are popped in the same order as from
+    // a reference heap; priorities are random field elements, and thus, distinct with high
+    // probability
+    let priorities = rand_vector::<u64>(64);
+    let mut reference = BinaryHeap::new();
+
+    let mut source = String::from("use.std::collections::heap\nbegin\n");
+    for (i, priority) in priorities.iter().enumerate() {
+        let i = i as u64;
+        let entry = [i, i + 1, i + 2, Felt::new(*priority).as_int()];
+        source.push_str(&push_entry(&entry));
+        reference.push((entry[3], entry));
+
+        if i % 3 == 2 {
+            let (_, entry) = reference.pop().unwrap();
+            source.push_str(&pop_entry(&entry));
+        }
+    }
+    while let Some((_, entry)) = reference.pop() {
+        source.push_str(&pop_entry(&entry));
+    }
+    source.push_str(&format!("push.{HEAP_PTR} exec.heap::size\nend"));
+
+    build_test!(&source).expect_stack(&[0]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the code which pushes the specified entry onto the heap.
+fn push_entry(entry: &[u64; 4]) -> String {
+    format!("push.{HEAP_PTR} push.{} exec.heap::push\n", word_to_str(entry))
+}
+
+/// Returns the code which pops an entry from the heap, and asserts that it is the specified entry.
+fn pop_entry(entry: &[u64; 4]) -> String {
+    format!("push.{HEAP_PTR} exec.heap::pop push.{} assert_eqw\n", word_to_str(entry))
+}
+
+fn word_to_str(word: &[u64; 4]) -> String {
+    word.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(".")
+}
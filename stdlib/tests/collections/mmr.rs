@@ -679,6 +679,109 @@ fn test_mmr_large_add_roundtrip() {
     test.expect_stack_and_memory(&expect_stack, mmr_ptr, &expected_memory);
 }
 
+#[test]
+fn test_mmr_bag_peaks() -> Result<(), MerkleError> {
+    let mmr_ptr = 1000;
+
+    for num_leaves in [1_u64, 2, 3, 7, 11, 32] {
+        let leaves = (1..=num_leaves).collect::<Vec<_>>();
+        let (mmr, _) = build_mmr(&leaves)?;
+
+        // build the MMR in memory, and bag its peaks
+        let mut source = String::from("use.std::collections::mmr\nbegin\n");
+        for leaf in leaves.iter() {
+            source.push_str(&format!("push.{mmr_ptr}.{leaf}.0.0.0 exec.mmr::add\n"));
+        }
+        source.push_str(&format!("push.{mmr_ptr} exec.mmr::bag_peaks\nend"));
+
+        let peaks = mmr.peaks(mmr.forest()).unwrap();
+        let expect_stack: Vec<u64> = peaks.hash_peaks().iter().rev().map(|v| v.as_int()).collect();
+        build_test!(&source).expect_stack(&expect_stack);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_mmr_append() -> Result<(), MerkleError> {
+    let mmr_ptr = 1000;
+    let unpacked_ptr = 2000;
+    let leaves = (1..=11).collect::<Vec<_>>();
+    let (mmr, _) = build_mmr(&leaves)?;
+
+    // append the last leaf via `append`, and load the MMR from the advice map using the returned
+    // commitment to make sure the peaks were copied there
+    let mut source = String::from("use.std::collections::mmr\nbegin\n");
+    for leaf in leaves[..leaves.len() - 1].iter() {
+        source.push_str(&format!("push.{mmr_ptr}.{leaf}.0.0.0 exec.mmr::add\n"));
+    }
+    source.push_str(&format!(
+        "push.{mmr_ptr}.11.0.0.0 exec.mmr::append
+        dupw push.{unpacked_ptr} movdn.4 exec.mmr::unpack
+        end"
+    ));
+
+    let peaks = mmr.peaks(mmr.forest()).unwrap();
+    let expect_stack: Vec<u64> = peaks.hash_peaks().iter().rev().map(|v| v.as_int()).collect();
+
+    let mut padded_peaks = peaks.peaks().to_vec();
+    padded_peaks.resize(16, RpoDigest::default());
+    let mut expect_memory = vec![peaks.num_leaves() as u64, 0, 0, 0];
+    expect_memory.extend(digests_to_ints(&padded_peaks));
+
+    build_test!(&source).expect_stack_and_memory(&expect_stack, unpacked_ptr, &expect_memory);
+
+    Ok(())
+}
+
+#[test]
+fn test_mmr_verify() -> Result<(), MerkleError> {
+    let mmr_ptr = 1000;
+
+    // three peaks with 8, 2, and 1 leaves
+    let leaves = (1..=11).collect::<Vec<_>>();
+    let (mmr, store) = build_mmr(&leaves)?;
+    let (hash, advice_map) = mmr_advice_map(&mmr);
+
+    let source = "
+        use.std::collections::mmr
+        begin exec.mmr::verify end
+    ";
+    let build_stack = |leaf: Word, pos: u64, hash: RpoDigest| {
+        let mut stack = vec![mmr_ptr];
+        stack.extend(hash.iter().map(|v| v.as_int()));
+        stack.push(pos);
+        stack.extend(leaf.iter().map(|v| v.as_int()));
+        stack
+    };
+
+    // every leaf of the MMR can be verified against the commitment
+    for (pos, leaf) in init_merkle_leaves(&leaves).into_iter().enumerate() {
+        let stack = build_stack(leaf, pos as u64, hash);
+        let test = build_test!(source, &stack, &[], store.clone(), advice_map.clone());
+        test.expect_stack(&[]);
+    }
+
+    // a different leaf at a valid position fails
+    let stack = build_stack(init_merkle_leaf(2), 0, hash);
+    let test = build_test!(source, &stack, &[], store.clone(), advice_map.clone());
+    assert!(test.execute().is_err());
+
+    // a position outside of the MMR fails
+    let stack = build_stack(init_merkle_leaf(12), 11, hash);
+    let test = build_test!(source, &stack, &[], store.clone(), advice_map.clone());
+    assert!(test.execute().is_err());
+
+    // a commitment to a different MMR fails
+    let (other_mmr, _) = build_mmr(&leaves[..10])?;
+    let other_hash = other_mmr.peaks(other_mmr.forest()).unwrap().hash_peaks();
+    let stack = build_stack(init_merkle_leaf(1), 0, other_hash);
+    let test = build_test!(source, &stack, &[], store, advice_map);
+    assert!(test.execute().is_err());
+
+    Ok(())
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -689,3 +792,48 @@ fn digests_to_elements(digests: &[RpoDigest]) -> Vec<Felt> {
 fn digests_to_ints(digests: &[RpoDigest]) -> Vec<u64> {
     digests.iter().map(|d| Word::from(d)).flatten().map(|v| v.as_int()).collect()
 }
+
+/// Returns an MMR built from leaves with the provided values (see `init_merkle_leaf`), together
+/// with a Merkle store containing the inner nodes of all of its peaks.
+fn build_mmr(values: &[u64]) -> Result<(Mmr, MerkleStore), MerkleError> {
+    let leaves = init_merkle_leaves(values);
+    let mmr = Mmr::from(leaves.iter().map(|leaf| RpoDigest::from(*leaf)));
+
+    // each peak is the root of a Merkle tree built from a power-of-two sized range of the leaves,
+    // starting with the largest tree
+    let mut store = MerkleStore::new();
+    let mut roots = Vec::new();
+    let mut start = 0;
+    for depth in (0..usize::BITS).rev() {
+        let tree_size = 1 << depth;
+        if leaves.len() & tree_size == 0 {
+            continue;
+        }
+        if tree_size == 1 {
+            roots.push(RpoDigest::from(leaves[start]));
+        } else {
+            let tree = MerkleTree::new(&leaves[start..start + tree_size])?;
+            store.extend(tree.inner_nodes());
+            roots.push(tree.root());
+        }
+        start += tree_size;
+    }
+    assert_eq!(roots, mmr.peaks(mmr.forest()).unwrap().peaks());
+
+    Ok((mmr, store))
+}
+
+/// Returns the commitment to the peaks of the provided MMR, and the advice map from which
+/// `mmr::unpack` loads the peaks using the commitment as a key.
+fn mmr_advice_map(mmr: &Mmr) -> (RpoDigest, Vec<(RpoDigest, Vec<Felt>)>) {
+    let peaks = mmr.peaks(mmr.forest()).unwrap();
+    let hash = peaks.hash_peaks();
+
+    let mut hash_data = peaks.peaks().to_vec();
+    hash_data.resize(16, RpoDigest::default());
+
+    let mut map_data = vec![Felt::new(peaks.num_leaves() as u64), ZERO, ZERO, ZERO];
+    map_data.extend(digests_to_elements(&hash_data));
+
+    (hash, vec![(hash, map_data)])
+}
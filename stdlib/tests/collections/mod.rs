@@ -1,7 +1,10 @@
 use test_utils::{
-    crypto::{MerkleStore, RpoDigest, Smt},
+    crypto::{LeafIndex, MerkleStore, RpoDigest, SimpleSmt, Smt},
     Felt, Word, EMPTY_WORD,
 };
 
+mod heap;
 mod mmr;
 mod smt;
+mod smt64;
+mod vec;
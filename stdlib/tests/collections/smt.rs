@@ -18,25 +18,31 @@ const LEAVES: [(RpoDigest, Word); 2] = [
     ),
 ];
 
+/// Keys which all map to the same leaf (i.e., their most significant elements are equal), listed
+/// out of order so that inserting them exercises every position in a multiple-pair leaf.
+const COLLIDING_LEAVES: [(RpoDigest, Word); 4] = [
+    (
+        RpoDigest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(42)]),
+        [Felt::new(1_u64), Felt::new(2_u64), Felt::new(3_u64), Felt::new(4_u64)],
+    ),
+    (
+        RpoDigest::new([Felt::new(105), Felt::new(106), Felt::new(101), Felt::new(42)]),
+        [Felt::new(5_u64), Felt::new(6_u64), Felt::new(7_u64), Felt::new(8_u64)],
+    ),
+    // Only the least significant Felt differs from the first key
+    (
+        RpoDigest::new([Felt::new(100), Felt::new(102), Felt::new(103), Felt::new(42)]),
+        [Felt::new(9_u64), Felt::new(10_u64), Felt::new(11_u64), Felt::new(12_u64)],
+    ),
+    (
+        RpoDigest::new([Felt::new(109), Felt::new(110), Felt::new(111), Felt::new(42)]),
+        [Felt::new(13_u64), Felt::new(14_u64), Felt::new(15_u64), Felt::new(16_u64)],
+    ),
+];
+
 /// Tests `get` on every key present in the SMT, as well as an empty leaf
 #[test]
 fn test_smt_get() {
-    fn expect_value_from_get(key: RpoDigest, value: Word, smt: &Smt) {
-        let source = "
-            use.std::collections::smt
-            begin
-            exec.smt::get
-            end
-        ";
-        let mut initial_stack = Vec::new();
-        append_word_to_vec(&mut initial_stack, smt.root().into());
-        append_word_to_vec(&mut initial_stack, key.into());
-        let expected_output = build_expected_stack(value, smt.root().into());
-
-        let (store, advice_map) = build_advice_inputs(smt);
-        build_test!(source, &initial_stack, &[], store, advice_map).expect_stack(&expected_output);
-    }
-
     let smt = Smt::with_entries(LEAVES).unwrap();
 
     // Get all leaves present in tree
@@ -52,6 +58,27 @@ fn test_smt_get() {
     );
 }
 
+/// Tests `get` on leaves holding one or more key-value pairs, including keys which map to a
+/// non-empty leaf but are not present in it
+#[test]
+fn test_smt_get_colliding_keys() {
+    let absent_key =
+        RpoDigest::new([Felt::new(101), Felt::new(102), Felt::new(104), Felt::new(42)]);
+
+    // single kv-pair leaf
+    let smt = Smt::with_entries(COLLIDING_LEAVES[..1].iter().copied()).unwrap();
+    let (key, value) = COLLIDING_LEAVES[0];
+    expect_value_from_get(key, value, &smt);
+    expect_value_from_get(absent_key, EMPTY_WORD, &smt);
+
+    // multiple kv-pair leaf
+    let smt = Smt::with_entries(COLLIDING_LEAVES).unwrap();
+    for (key, value) in COLLIDING_LEAVES {
+        expect_value_from_get(key, value, &smt);
+    }
+    expect_value_from_get(absent_key, EMPTY_WORD, &smt);
+}
+
 /// Tests inserting and removing key-value pairs to an SMT. We do the insert/removal twice to ensure
 /// that the removal properly updates the advice map/stack.
 #[test]
@@ -96,6 +123,115 @@ fn test_smt_set() {
     assert_insert_and_remove(&mut smt);
 }
 
+/// Tests inserting, updating and removing keys which map to the same leaf, such that the leaf
+/// goes from empty to holding a single pair, to holding multiple pairs, and back to empty
+#[test]
+fn test_smt_set_colliding_keys() {
+    let mut smt = Smt::new();
+    let empty_tree_root = smt.root();
+
+    let source = "
+    use.std::collections::smt
+    begin
+      exec.smt::set
+    end
+    ";
+
+    // insert the keys one-by-one
+    for (key, value) in COLLIDING_LEAVES {
+        let (init_stack, final_stack, store, advice_map) =
+            prepare_insert_or_set(key, value, &mut smt);
+        build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+    }
+
+    // update a key in the middle of the leaf
+    let key = COLLIDING_LEAVES[0].0;
+    let value = [42323_u32.into(); 4];
+    let (init_stack, final_stack, store, advice_map) = prepare_insert_or_set(key, value, &mut smt);
+    build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+
+    // removing a key which is not in the leaf is a no-op
+    let absent_key =
+        RpoDigest::new([Felt::new(101), Felt::new(102), Felt::new(104), Felt::new(42)]);
+    let (init_stack, final_stack, store, advice_map) =
+        prepare_insert_or_set(absent_key, EMPTY_WORD, &mut smt);
+    build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+
+    // remove all keys
+    for (key, _) in COLLIDING_LEAVES {
+        let (init_stack, final_stack, store, advice_map) =
+            prepare_insert_or_set(key, EMPTY_WORD, &mut smt);
+        build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+    }
+
+    assert_eq!(smt.root(), empty_tree_root);
+}
+
+/// Tests that `delete` removes keys from both single and multiple kv-pair leaves
+#[test]
+fn test_smt_delete() {
+    let mut smt = Smt::with_entries(LEAVES.into_iter().chain(COLLIDING_LEAVES)).unwrap();
+
+    let source = "
+    use.std::collections::smt
+    begin
+      exec.smt::delete
+    end
+    ";
+
+    for (key, _) in [LEAVES[0], COLLIDING_LEAVES[2], COLLIDING_LEAVES[0]] {
+        let mut init_stack = Vec::new();
+        append_word_to_vec(&mut init_stack, smt.root().into());
+        append_word_to_vec(&mut init_stack, key.into());
+
+        let (store, advice_map) = build_advice_inputs(&smt);
+        let old_value = smt.insert(key, EMPTY_WORD);
+        let final_stack = build_expected_stack(old_value, smt.root().into());
+
+        build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+    }
+}
+
+/// Tests that `set_many` applies the updates stored in memory in order
+#[test]
+fn test_smt_set_many() {
+    let mut smt = Smt::with_entries(LEAVES).unwrap();
+
+    // insert into a new leaf, into existing leaves, then update and remove a key of the batch
+    let updates = [
+        COLLIDING_LEAVES[0],
+        COLLIDING_LEAVES[1],
+        (LEAVES[1].0, [Felt::new(7_u64); 4]),
+        (COLLIDING_LEAVES[2].0, [Felt::new(8_u64); 4]),
+        (COLLIDING_LEAVES[0].0, EMPTY_WORD),
+    ];
+
+    let mut source = String::from("use.std::collections::smt\nbegin\n");
+    for (i, (key, value)) in updates.iter().enumerate() {
+        for (offset, word) in [(0, Word::from(*key)), (1, *value)] {
+            let elements = word.iter().map(|e| e.as_int().to_string()).collect::<Vec<_>>();
+            source.push_str(&format!(
+                "push.{} mem_storew.{} dropw\n",
+                elements.join("."),
+                1000 + 2 * i + offset
+            ));
+        }
+    }
+    source.push_str(&format!("push.{}.1000 exec.smt::set_many\nend", updates.len()));
+
+    let mut init_stack = Vec::new();
+    append_word_to_vec(&mut init_stack, smt.root().into());
+
+    let (store, advice_map) = build_advice_inputs(&smt);
+    for (key, value) in updates {
+        smt.insert(key, value);
+    }
+    let root: Word = smt.root().into();
+    let final_stack = vec![root[3].as_int(), root[2].as_int(), root[1].as_int(), root[0].as_int()];
+
+    build_test!(&source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+}
+
 /// Tests updating an existing key with a different value
 #[test]
 fn test_smt_set_same_key() {
@@ -135,6 +271,41 @@ fn test_smt_set_empty_value_to_empty_leaf() {
     assert_eq!(smt.root(), empty_tree_root);
 }
 
+/// Tests that `get_or_default` returns the default value only for keys which are not in the tree
+#[test]
+fn test_smt_get_or_default() {
+    let smt =
+        Smt::with_entries(LEAVES.into_iter().chain(COLLIDING_LEAVES[..2].iter().copied())).unwrap();
+    let default = [Felt::new(77_u64); 4];
+
+    let source = "
+    use.std::collections::smt
+    begin
+      exec.smt::get_or_default
+    end
+    ";
+
+    let absent_keys = [
+        RpoDigest::new([42_u32.into(), 42_u32.into(), 42_u32.into(), 42_u32.into()]),
+        COLLIDING_LEAVES[2].0,
+    ];
+    let cases = LEAVES
+        .into_iter()
+        .chain(COLLIDING_LEAVES[..2].iter().copied())
+        .chain(absent_keys.into_iter().map(|key| (key, default)));
+
+    for (key, expected_value) in cases {
+        let mut init_stack = Vec::new();
+        append_word_to_vec(&mut init_stack, smt.root().into());
+        append_word_to_vec(&mut init_stack, key.into());
+        append_word_to_vec(&mut init_stack, default);
+        let final_stack = build_expected_stack(expected_value, smt.root().into());
+
+        let (store, advice_map) = build_advice_inputs(&smt);
+        build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+    }
+}
+
 /// Tests that the advice map is properly updated after a `set` on an empty key
 #[test]
 fn test_set_advice_map_empty_key() {
@@ -253,6 +424,22 @@ fn test_set_empty_key_in_non_empty_leaf() {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+fn expect_value_from_get(key: RpoDigest, value: Word, smt: &Smt) {
+    let source = "
+        use.std::collections::smt
+        begin
+        exec.smt::get
+        end
+    ";
+    let mut initial_stack = Vec::new();
+    append_word_to_vec(&mut initial_stack, smt.root().into());
+    append_word_to_vec(&mut initial_stack, key.into());
+    let expected_output = build_expected_stack(value, smt.root().into());
+
+    let (store, advice_map) = build_advice_inputs(smt);
+    build_test!(source, &initial_stack, &[], store, advice_map).expect_stack(&expected_output);
+}
+
 fn prepare_insert_or_set(
     key: RpoDigest,
     value: Word,
@@ -0,0 +1,155 @@
+use super::*;
+
+// TEST DATA
+// ================================================================================================
+
+/// Keys spread over both levels of the tree, including keys which are not smaller than the field
+/// modulus, and thus, cannot be represented by a single field element.
+const LEAVES: [(u64, Word); 4] = [
+    (0, [Felt::new(1_u64), Felt::new(2_u64), Felt::new(3_u64), Felt::new(4_u64)]),
+    // Only the least significant limb differs from the previous key
+    (7, [Felt::new(5_u64), Felt::new(6_u64), Felt::new(7_u64), Felt::new(8_u64)]),
+    // The most significant limb differs from the previous keys
+    (
+        (3 << 32) | 7,
+        [Felt::new(9_u64), Felt::new(10_u64), Felt::new(11_u64), Felt::new(12_u64)],
+    ),
+    (
+        u64::MAX,
+        [Felt::new(13_u64), Felt::new(14_u64), Felt::new(15_u64), Felt::new(16_u64)],
+    ),
+];
+
+/// Tests `get` on every key present in the tree, as well as on empty leaves
+#[test]
+fn test_smt64_get() {
+    let smt = SimpleSmt::<64>::with_leaves(LEAVES).unwrap();
+
+    for (key, value) in LEAVES {
+        expect_value_from_get(key, value, &smt);
+    }
+
+    // empty leaves in a non-empty and in an empty subtree
+    expect_value_from_get(8, EMPTY_WORD, &smt);
+    expect_value_from_get(u64::MAX - 1, EMPTY_WORD, &smt);
+    expect_value_from_get(42 << 32, EMPTY_WORD, &smt);
+}
+
+/// Tests inserting values one-by-one, and then setting them to [ZERO; 4], which should return the
+/// tree to its prior states
+#[test]
+fn test_smt64_set() {
+    let source = "
+        use.std::collections::smt64
+        begin
+            exec.smt64::set
+        end
+    ";
+
+    let mut smt = SimpleSmt::<64>::new().unwrap();
+    let empty_tree_root = smt.root();
+
+    let mut old_roots = Vec::new();
+    for (key, value) in LEAVES {
+        old_roots.push(smt.root());
+        let (init_stack, final_stack, store) = prepare_set(key, value, &mut smt);
+        build_test!(source, &init_stack, &[], store).expect_stack(&final_stack);
+    }
+
+    // updating a key should return the value it replaces
+    let value = [Felt::new(17_u64), Felt::new(18_u64), Felt::new(19_u64), Felt::new(20_u64)];
+    let (init_stack, final_stack, store) = prepare_set(LEAVES[1].0, value, &mut smt);
+    assert_eq!(build_expected_stack(LEAVES[1].1, smt.root().into()), final_stack);
+    build_test!(source, &init_stack, &[], store).expect_stack(&final_stack);
+    smt.insert(LeafIndex::<64>::new(LEAVES[1].0).unwrap(), LEAVES[1].1);
+
+    for (key, old_value) in LEAVES.iter().rev() {
+        let (init_stack, final_stack, store) = prepare_set(*key, EMPTY_WORD, &mut smt);
+        let expected_final_stack =
+            build_expected_stack(*old_value, old_roots.pop().unwrap().into());
+        assert_eq!(expected_final_stack, final_stack);
+        build_test!(source, &init_stack, &[], store).expect_stack(&final_stack);
+    }
+
+    assert_eq!(smt.root(), empty_tree_root);
+}
+
+/// Tests that keys which are not represented using 32-bit limbs are rejected
+#[test]
+fn test_smt64_invalid_key() {
+    let smt = SimpleSmt::<64>::with_leaves(LEAVES).unwrap();
+    let store = MerkleStore::from(&smt);
+
+    let source = "
+        use.std::collections::smt64
+        begin
+            exec.smt64::get
+        end
+    ";
+    let mut init_stack = Vec::new();
+    append_word_to_vec(&mut init_stack, smt.root().into());
+    init_stack.extend_from_slice(&[1 << 32, 0]);
+    assert!(build_test!(source, &init_stack, &[], store.clone()).execute().is_err());
+
+    let source = "
+        use.std::collections::smt64
+        begin
+            exec.smt64::set
+        end
+    ";
+    append_word_to_vec(&mut init_stack, LEAVES[0].1);
+    assert!(build_test!(source, &init_stack, &[], store).execute().is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn expect_value_from_get(key: u64, value: Word, smt: &SimpleSmt<64>) {
+    let source = "
+        use.std::collections::smt64
+        begin
+            exec.smt64::get
+        end
+    ";
+    let mut initial_stack = Vec::new();
+    append_word_to_vec(&mut initial_stack, smt.root().into());
+    append_key_to_vec(&mut initial_stack, key);
+    let expected_output = build_expected_stack(value, smt.root().into());
+
+    let store = MerkleStore::from(smt);
+    build_test!(source, &initial_stack, &[], store).expect_stack(&expected_output);
+}
+
+fn prepare_set(
+    key: u64,
+    value: Word,
+    smt: &mut SimpleSmt<64>,
+) -> (Vec<u64>, Vec<u64>, MerkleStore) {
+    // set initial state of the stack to be [VALUE, k_hi, k_lo, ROOT, ...]
+    let mut initial_stack = Vec::new();
+    append_word_to_vec(&mut initial_stack, smt.root().into());
+    append_key_to_vec(&mut initial_stack, key);
+    append_word_to_vec(&mut initial_stack, value);
+
+    // build a Merkle store for the test before the tree is updated, and then update the tree
+    let store = MerkleStore::from(&*smt);
+    let old_value = smt.insert(LeafIndex::<64>::new(key).unwrap(), value);
+
+    // after the update, the stack should be [OLD_VALUE, ROOT, ...]
+    let expected_output = build_expected_stack(old_value, smt.root().into());
+
+    (initial_stack, expected_output, store)
+}
+
+fn build_expected_stack(word0: Word, word1: Word) -> Vec<u64> {
+    word0.iter().rev().chain(word1.iter().rev()).map(|e| e.as_int()).collect()
+}
+
+fn append_word_to_vec(target: &mut Vec<u64>, word: Word) {
+    target.extend(word.iter().map(|e| e.as_int()));
+}
+
+fn append_key_to_vec(target: &mut Vec<u64>, key: u64) {
+    target.push(key & 0xffffffff);
+    target.push(key >> 32);
+}
@@ -0,0 +1,173 @@
+use test_utils::build_expected_hash;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Address of the vector used in the tests.
+const VEC_PTR: u32 = 1000;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn test_vec_push_pop() {
+    let elements = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+    let mut source = String::from("use.std::collections::vec\nbegin\n");
+    source.push_str(&new_vec(4));
+    for element in elements.iter() {
+        source.push_str(&push_element(element));
+    }
+    source.push_str(&format!("push.{VEC_PTR} exec.vec::len eq.3 assert\n"));
+
+    // elements are popped in the reverse order
+    for element in elements.iter().rev() {
+        source.push_str(&format!(
+            "push.{VEC_PTR} exec.vec::pop push.{} assert_eqw\n",
+            word_to_str(element)
+        ));
+    }
+    source.push_str(&format!("push.{VEC_PTR} exec.vec::capacity\n"));
+    source.push_str(&format!("push.{VEC_PTR} exec.vec::len\nend"));
+
+    build_test!(&source).expect_stack(&[0, 4]);
+}
+
+#[test]
+fn test_vec_get_set() {
+    let source = format!(
+        "
+    use.std::collections::vec
+
+    begin
+      {}
+      {}
+      {}
+      push.{VEC_PTR} push.1 push.13.14.15.16 exec.vec::set
+      push.{VEC_PTR} push.0 exec.vec::get
+      push.{VEC_PTR} push.1 exec.vec::get
+    end
+    ",
+        new_vec(2),
+        push_element(&[1, 2, 3, 4]),
+        push_element(&[5, 6, 7, 8])
+    );
+
+    build_test!(&source).expect_stack(&[16, 15, 14, 13, 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_vec_bounds() {
+    // pushing onto a full vector should fail
+    let push_full = format!(
+        "
+    use.std::collections::vec
+
+    begin
+      {}
+      {}
+      {}
+    end
+    ",
+        new_vec(1),
+        push_element(&[1, 2, 3, 4]),
+        push_element(&[5, 6, 7, 8])
+    );
+    assert!(build_test!(&push_full).execute().is_err());
+
+    // pushing onto a vector which has not been initialized should fail
+    let push_uninit = format!(
+        "
+    use.std::collections::vec
+
+    begin
+      {}
+    end
+    ",
+        push_element(&[1, 2, 3, 4])
+    );
+    assert!(build_test!(&push_uninit).execute().is_err());
+
+    // popping from an empty vector should fail
+    let pop_empty = format!(
+        "
+    use.std::collections::vec
+
+    begin
+      {}
+      push.{VEC_PTR} exec.vec::pop
+    end
+    ",
+        new_vec(1)
+    );
+    assert!(build_test!(&pop_empty).execute().is_err());
+
+    // accessing an element at an index which is not smaller than the length should fail
+    for access in ["push.1 exec.vec::get", "push.1 push.5.6.7.8 exec.vec::set"] {
+        let source = format!(
+            "
+    use.std::collections::vec
+
+    begin
+      {}
+      {}
+      push.{VEC_PTR} {access}
+    end
+    ",
+            new_vec(4),
+            push_element(&[1, 2, 3, 4])
+        );
+        assert!(build_test!(&source).execute().is_err());
+    }
+}
+
+#[test]
+fn test_vec_hash_contents() {
+    let elements = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+    // the hash of an empty vector is the empty word
+    let source = format!(
+        "
+    use.std::collections::vec
+
+    begin
+      {}
+      push.{VEC_PTR} exec.vec::hash_contents
+    end
+    ",
+        new_vec(4)
+    );
+    build_test!(&source).expect_stack(&[0, 0, 0, 0]);
+
+    // the hash is the sequential hash of the elements, with both even and odd numbers of words
+    for n in 1..=elements.len() {
+        let mut source = String::from("use.std::collections::vec\nbegin\n");
+        source.push_str(&new_vec(4));
+        for element in elements[..n].iter() {
+            source.push_str(&push_element(element));
+        }
+        source.push_str(&format!("push.{VEC_PTR} exec.vec::hash_contents\nend"));
+
+        let values = elements[..n].iter().flatten().copied().collect::<Vec<_>>();
+        let expected: Vec<u64> =
+            build_expected_hash(&values).into_iter().map(|e| e.as_int()).collect();
+        build_test!(&source).expect_stack(&expected);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the code which initializes a vector with the specified capacity.
+fn new_vec(capacity: u32) -> String {
+    format!("push.{VEC_PTR} push.{capacity} exec.vec::new\n")
+}
+
+/// Returns the code which appends the specified element to the vector.
+fn push_element(element: &[u64; 4]) -> String {
+    format!("push.{VEC_PTR} push.{} exec.vec::push\n", word_to_str(element))
+}
+
+fn word_to_str(word: &[u64; 4]) -> String {
+    word.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(".")
+}
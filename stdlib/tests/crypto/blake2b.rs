@@ -0,0 +1,121 @@
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use test_utils::{
+    group_slice_elements,
+    rand::{rand_array, rand_value},
+    Felt, IntoBytes,
+};
+
+type Blake2b256 = Blake2b<U32>;
+
+#[test]
+fn blake2b_hash_memory() {
+    let source = "
+    use.std::crypto::hashes::blake2b
+
+    begin
+        # mem.0 - input data address
+        push.10000 mem_store.0
+
+        # mem.1 - length in bytes
+        mem_store.1
+
+        # mem.2 - length in felts
+        mem_load.1 u32assert u32overflowing_add.3 assertz u32assert u32div.4 mem_store.2
+
+        # Load input data into memory address 10000, 10001, ...
+        mem_load.2 u32assert neq.0
+        while.true
+            mem_load.0 mem_storew dropw
+            mem_load.0 u32assert u32overflowing_add.1 assertz mem_store.0
+            mem_load.2 u32assert u32overflowing_sub.1 assertz dup mem_store.2 u32assert neq.0
+        end
+
+        # Compute hash of memory address 10000, 10001, ...
+        mem_load.1
+        push.10000
+        exec.blake2b::hash_memory
+    end";
+
+    // lengths which fall on a block boundary, and a random one
+    for length in [0, 128, 129, rand_value::<u64>() & 1023] {
+        let ibytes: Vec<u8> = (0..length).map(|_| rand_value::<u8>()).collect();
+        let ipadding: Vec<u8> = vec![0; (4 - (length as usize % 4)) % 4];
+
+        let ifelts = [
+            group_slice_elements::<u8, 4>(&[ibytes.clone(), ipadding].concat())
+                .iter()
+                .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+                .rev()
+                .collect::<Vec<u64>>(),
+            vec![length; 1],
+        ]
+        .concat();
+
+        let obytes = Blake2b256::digest(&ibytes);
+        let ofelts = group_slice_elements::<u8, 4>(&obytes)
+            .iter()
+            .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+            .collect::<Vec<u64>>();
+
+        let test = build_test!(source, &ifelts);
+        test.expect_stack(&ofelts);
+    }
+}
+
+#[test]
+fn blake2b_2_to_1_hash() {
+    let source = "
+    use.std::crypto::hashes::blake2b
+
+    begin
+        exec.blake2b::hash_2to1
+    end";
+
+    let input0 = rand_array::<Felt, 4>().into_bytes();
+    let input1 = rand_array::<Felt, 4>().into_bytes();
+
+    let mut ibytes = [0u8; 64];
+    ibytes[..32].copy_from_slice(&input0);
+    ibytes[32..].copy_from_slice(&input1);
+
+    let ifelts = group_slice_elements::<u8, 4>(&ibytes)
+        .iter()
+        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+        .rev()
+        .collect::<Vec<u64>>();
+
+    let obytes = Blake2b256::digest(ibytes);
+    let ofelts = group_slice_elements::<u8, 4>(&obytes)
+        .iter()
+        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+        .collect::<Vec<u64>>();
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
+
+#[test]
+fn blake2b_1_to_1_hash() {
+    let source = "
+    use.std::crypto::hashes::blake2b
+
+    begin
+        exec.blake2b::hash_1to1
+    end";
+
+    let ibytes = rand_array::<Felt, 4>().into_bytes();
+    let ifelts = group_slice_elements::<u8, 4>(&ibytes)
+        .iter()
+        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+        .rev()
+        .collect::<Vec<u64>>();
+
+    let obytes = Blake2b256::digest(ibytes);
+    let ofelts = group_slice_elements::<u8, 4>(&obytes)
+        .iter()
+        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+        .collect::<Vec<u64>>();
+
+    let test = build_test!(source, &ifelts);
+    test.expect_stack(&ofelts);
+}
@@ -0,0 +1,102 @@
+use crate::math::secp256r1::{
+    base_modulus,
+    group::{affine_mul, generator, AffinePoint},
+    radix, rand_u256, scalar_modulus, to_stack,
+};
+use num_bigint::BigUint;
+use processor::ExecutionError;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Signs the hashed message h with the private key d, returning the signature (r, s).
+fn sign(d: &BigUint, h: &BigUint) -> (BigUint, BigUint) {
+    let n = scalar_modulus();
+    loop {
+        let k = rand_u256() % &n;
+        let Some((x, _)) = affine_mul(&generator(), &k) else {
+            continue;
+        };
+        let r = x % &n;
+        let s = k.modinv(&n).unwrap() * (h + &r * d) % &n;
+        if r != BigUint::from(0u32) && s != BigUint::from(0u32) {
+            return (r, s);
+        }
+    }
+}
+
+/// Builds stack inputs of the ECDSA verification routine, converting all provided values into
+/// Montgomery form.
+fn build_stack(pub_key: &AffinePoint, h: &BigUint, r: &BigUint, s: &BigUint) -> Vec<u64> {
+    let (p, n) = (base_modulus(), scalar_modulus());
+    let (x, y) = pub_key.clone().expect("public key is the point at infinity");
+
+    to_stack(&[
+        &(x * radix() % &p),
+        &(y * radix() % &p),
+        &(radix() % &p),
+        &(h * radix() % &n),
+        &(r * radix() % &n),
+        &(s * radix() % &n),
+    ])
+}
+
+// TESTS
+// ================================================================================================
+
+const SOURCE: &str = "
+    use.std::crypto::dsa::ecdsa::secp256r1
+
+    begin
+        exec.secp256r1::verify
+    end";
+
+/// Signatures with r = 0 or s = 0 are rejected before any point multiplication is done, and thus,
+/// this test is cheap enough to be run by default.
+#[test]
+fn verify_fails_for_zero_signature() {
+    let d = rand_u256() % scalar_modulus();
+    let pub_key = affine_mul(&generator(), &d);
+    let h = rand_u256();
+    let (r, s) = sign(&d, &h);
+    let zero = BigUint::from(0u32);
+
+    let test = build_test!(SOURCE, &build_stack(&pub_key, &h, &zero, &s));
+    assert!(matches!(test.execute(), Err(ExecutionError::FailedAssertion { .. })));
+
+    let test = build_test!(SOURCE, &build_stack(&pub_key, &h, &r, &zero));
+    assert!(matches!(test.execute(), Err(ExecutionError::FailedAssertion { .. })));
+}
+
+/// Verifying a signature takes more than 2^23 cycles, and executing it requires more memory than
+/// is available on most CI runners; thus, this test is by default ignored. If you're interested
+/// in running this test, issue
+///
+/// cargo test --release -p miden-stdlib secp256r1 -- --include-ignored
+///
+/// from root directory of Miden repository.
+#[test]
+#[ignore]
+fn verify() {
+    let d = rand_u256() % scalar_modulus();
+    let pub_key = affine_mul(&generator(), &d);
+    let h = rand_u256();
+    let (r, s) = sign(&d, &h);
+
+    let test = build_test!(SOURCE, &build_stack(&pub_key, &h, &r, &s));
+    assert!(test.execute().is_ok());
+}
+
+/// This test is by default ignored for the same reason as [verify()].
+#[test]
+#[ignore]
+fn verify_fails_for_invalid_signature() {
+    let d = rand_u256() % scalar_modulus();
+    let pub_key = affine_mul(&generator(), &d);
+    let h = rand_u256();
+    let (r, s) = sign(&d, &h);
+
+    // signature of a different message
+    let test = build_test!(SOURCE, &build_stack(&pub_key, &(&h + 1u32), &r, &s));
+    assert!(test.execute().is_err());
+}
@@ -5,11 +5,17 @@ use processor::{
     crypto::RpoRandomCoin, AdviceInputs, DefaultHost, Digest, MemAdviceProvider, StackInputs,
 };
 use test_utils::{
-    crypto::{rpo_falcon512::SecretKey, MerkleStore},
+    crypto::{falcon_batch_advice_stack, rpo_falcon512::SecretKey, MerkleStore},
     rand::rand_vector,
     ProgramInfo, Word,
 };
 
+/// Address of the (PK, MSG) pairs used in the batch verification tests.
+const BATCH_PTR: u64 = 100;
+
+/// Address of the workspace used in the batch verification tests.
+const WORK_PTR: u64 = 10000;
+
 #[test]
 fn falcon_execution() {
     let seed = Word::default();
@@ -22,6 +28,29 @@ fn falcon_execution() {
     test.expect_stack(&[])
 }
 
+#[test]
+fn falcon_batch_execution() {
+    let mut rng = RpoRandomCoin::new(Word::default());
+    let batch = (0..3)
+        .map(|_| {
+            let sk = SecretKey::with_rng(&mut rng);
+            let message: Word = rand_vector::<Felt>(4).try_into().unwrap();
+            (sk, message)
+        })
+        .collect::<Vec<_>>();
+    let adv_stack = falcon_batch_advice_stack(&batch);
+
+    let source =
+        generate_batch_test(&batch, &batch.iter().map(|(_, msg)| *msg).collect::<Vec<_>>());
+    build_test!(&source, &[], &adv_stack).expect_stack(&[]);
+
+    // swapping the messages of two signatures must invalidate the batch
+    let mut messages = batch.iter().map(|(_, msg)| *msg).collect::<Vec<_>>();
+    messages.swap(0, 2);
+    let source = generate_batch_test(&batch, &messages);
+    assert!(build_test!(&source, &[], &adv_stack).execute().is_err());
+}
+
 #[test]
 #[ignore]
 fn falcon_prove_verify() {
@@ -80,3 +109,37 @@ fn generate_test(
 
     (source, op_stack, adv_stack, store, advice_map)
 }
+
+/// Returns the source of a program which writes the public keys of the batch together with the
+/// specified messages into memory, and verifies the batch.
+fn generate_batch_test(batch: &[(SecretKey, Word)], messages: &[Word]) -> String {
+    let store_pairs = batch
+        .iter()
+        .zip(messages)
+        .enumerate()
+        .map(|(i, ((sk, _), msg))| {
+            let pk: Word = sk.public_key().into();
+            let ptr = BATCH_PTR + 2 * i as u64;
+            format!("push.{}.{ptr} mem_storew dropw\n", to_imm(&pk))
+                + &format!("push.{}.{} mem_storew dropw\n", to_imm(msg), ptr + 1)
+        })
+        .collect::<String>();
+
+    format!(
+        "
+    use.std::crypto::dsa::rpo_falcon512
+
+    begin
+        {store_pairs}
+        push.{WORK_PTR}.{BATCH_PTR}.{}
+        exec.rpo_falcon512::verify_batch
+    end
+    ",
+        batch.len()
+    )
+}
+
+/// Returns the elements of the word as an immediate value of the push instruction.
+fn to_imm(word: &Word) -> String {
+    word.iter().map(|e| e.as_int().to_string()).collect::<Vec<_>>().join(".")
+}
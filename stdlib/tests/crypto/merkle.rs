@@ -0,0 +1,169 @@
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use test_utils::{group_slice_elements, rand::rand_array, Felt, IntoBytes};
+
+// TREE HELPERS
+// ================================================================================================
+
+const DEPTH: usize = 3;
+
+/// Returns all levels of a binary Merkle tree built from the provided leaf nodes, starting with the
+/// leaves and ending with the root.
+fn build_tree<D: Digest>(leaves: Vec<[u8; 32]>) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves];
+    while levels.last().unwrap().len() > 1 {
+        let parents = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| {
+                let mut hasher = D::new();
+                hasher.update(pair[0]);
+                hasher.update(pair[1]);
+                hasher.finalize().as_slice().try_into().unwrap()
+            })
+            .collect();
+        levels.push(parents);
+    }
+    levels
+}
+
+/// Builds the stack and advice inputs for proving that the leaf at `index` is in the tree, using
+/// `to_words` to convert nodes into the representation expected by the hash procedures.
+fn build_inputs(
+    levels: &[Vec<[u8; 32]>],
+    leaf: [u8; 32],
+    index: usize,
+    root: [u8; 32],
+    to_words: fn(&[u8; 32]) -> [u64; 8],
+) -> (Vec<u64>, Vec<u64>) {
+    let mut stack = vec![DEPTH as u64, index as u64];
+    stack.extend_from_slice(&to_words(&leaf));
+    stack.extend_from_slice(&to_words(&root));
+    stack.reverse();
+
+    let mut advice_stack = Vec::new();
+    let mut position = index;
+    for level in &levels[..DEPTH] {
+        advice_stack.extend(to_words(&level[position ^ 1]).iter().rev());
+        position >>= 1;
+    }
+
+    (stack, advice_stack)
+}
+
+fn random_leaves() -> Vec<[u8; 32]> {
+    (0..1 << DEPTH).map(|_| rand_array::<Felt, 4>().into_bytes()).collect()
+}
+
+/// Represents a node as eight big endian 32-bit words, as expected by SHA256 procedures.
+fn sha256_words(node: &[u8; 32]) -> [u64; 8] {
+    let mut words = [0; 8];
+    for (word, bytes) in words.iter_mut().zip(group_slice_elements::<u8, 4>(node)) {
+        *word = u32::from_be_bytes(*bytes) as u64;
+    }
+    words
+}
+
+/// Represents a node as eight 32-bit words, where each pair holds the higher and lower 32 bits of a
+/// little endian 64-bit lane, as expected by Keccak256 procedures.
+fn keccak256_words(node: &[u8; 32]) -> [u64; 8] {
+    let mut words = [0; 8];
+    for (i, bytes) in group_slice_elements::<u8, 8>(node).iter().enumerate() {
+        let lane = u64::from_le_bytes(*bytes);
+        words[2 * i] = lane >> 32;
+        words[2 * i + 1] = lane as u32 as u64;
+    }
+    words
+}
+
+// SHA256 TREES
+// ================================================================================================
+
+#[test]
+fn verify_sha256() {
+    let source = "
+    use.std::crypto::merkle
+
+    begin
+        exec.merkle::verify_sha256
+    end";
+
+    let leaves = random_leaves();
+    let levels = build_tree::<Sha256>(leaves.clone());
+    let root = levels[DEPTH][0];
+
+    for (index, &leaf) in leaves.iter().enumerate() {
+        let (stack, advice_stack) = build_inputs(&levels, leaf, index, root, sha256_words);
+        let test = build_test!(source, &stack, &advice_stack);
+        test.expect_stack(&[]);
+    }
+
+    // a leaf at a wrong position must fail
+    let (stack, advice_stack) = build_inputs(&levels, leaves[1], 0, root, sha256_words);
+    let test = build_test!(source, &stack, &advice_stack);
+    assert!(test.execute().is_err());
+
+    // an index outside of the tree must fail
+    let (mut stack, advice_stack) = build_inputs(&levels, leaves[2], 2, root, sha256_words);
+    let index_pos = stack.len() - 2;
+    stack[index_pos] += 1 << DEPTH;
+    let test = build_test!(source, &stack, &advice_stack);
+    assert!(test.execute().is_err());
+}
+
+#[test]
+fn verify_sha256_hashed_leaf() {
+    let source = "
+    use.std::crypto::merkle
+
+    begin
+        exec.merkle::verify_sha256_hashed_leaf
+    end";
+
+    let values = random_leaves();
+    let leaves = values
+        .iter()
+        .map(|value| Sha256::digest(value).as_slice().try_into().unwrap())
+        .collect();
+    let levels = build_tree::<Sha256>(leaves);
+    let root = levels[DEPTH][0];
+
+    let index = 5;
+    let (stack, advice_stack) = build_inputs(&levels, values[index], index, root, sha256_words);
+    let test = build_test!(source, &stack, &advice_stack);
+    test.expect_stack(&[]);
+
+    // the leaf node itself is not a valid leaf value
+    let (stack, advice_stack) = build_inputs(&levels, levels[0][index], index, root, sha256_words);
+    let test = build_test!(source, &stack, &advice_stack);
+    assert!(test.execute().is_err());
+}
+
+// KECCAK256 TREES
+// ================================================================================================
+
+#[test]
+fn verify_keccak256() {
+    let source = "
+    use.std::crypto::merkle
+
+    begin
+        exec.merkle::verify_keccak256
+    end";
+
+    let leaves = random_leaves();
+    let levels = build_tree::<Keccak256>(leaves.clone());
+    let root = levels[DEPTH][0];
+
+    let index = 6;
+    let (stack, advice_stack) = build_inputs(&levels, leaves[index], index, root, keccak256_words);
+    let test = build_test!(source, &stack, &advice_stack);
+    test.expect_stack(&[]);
+
+    // a wrong root must fail
+    let (stack, advice_stack) =
+        build_inputs(&levels, leaves[index], index, levels[1][0], keccak256_words);
+    let test = build_test!(source, &stack, &advice_stack);
+    assert!(test.execute().is_err());
+}
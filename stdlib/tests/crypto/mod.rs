@@ -1,11 +1,15 @@
 #[cfg(feature = "std")]
 mod falcon;
 
+mod blake2b;
 mod blake3;
 mod ecdsa_secp256k1;
+mod ecdsa_secp256r1;
 mod elgamal;
 mod fri;
 mod keccak256;
+mod merkle;
 mod native;
+mod schnorr_secp256k1;
 mod sha256;
 mod stark;
@@ -19,6 +19,7 @@ fn test_invalid_end_addr() {
             clk: 18,
             err_code: 0,
             err_msg: None,
+            context: None,
         },
     ));
 
@@ -38,6 +39,7 @@ fn test_invalid_end_addr() {
             clk: 18,
             err_code: 0,
             err_msg: None,
+            context: None,
         },
     ));
 }
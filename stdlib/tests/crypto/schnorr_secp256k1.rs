@@ -0,0 +1,141 @@
+use sha2::{Digest, Sha256};
+use test_utils::{
+    group_slice_elements,
+    rand::{rand_array, rand_vector},
+    test_case,
+};
+
+// TAGGED HASHES
+// ================================================================================================
+
+#[test]
+fn tagged_hash() {
+    let source = "
+    use.std::crypto::dsa::schnorr_secp256k1
+
+    begin
+        exec.schnorr_secp256k1::tagged_hash
+    end";
+
+    let tag: Vec<u8> = rand_vector(16);
+    let message: [u8; 96] = rand_array();
+    let tag_hash = Sha256::digest(&tag);
+
+    let mut stack = to_words(&tag_hash);
+    stack.extend(to_words(&message));
+    stack.reverse();
+
+    let expected = to_words(
+        &Sha256::new()
+            .chain_update(tag_hash)
+            .chain_update(tag_hash)
+            .chain_update(message)
+            .finalize(),
+    );
+
+    let test = build_test!(source, &stack);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn challenge() {
+    let source = "
+    use.std::crypto::dsa::schnorr_secp256k1
+
+    begin
+        exec.schnorr_secp256k1::challenge
+    end";
+
+    let message: [u8; 96] = rand_array();
+    let tag_hash = Sha256::digest(b"BIP0340/challenge");
+
+    let mut stack = to_words(&message);
+    stack.reverse();
+
+    let expected = to_words(
+        &Sha256::new()
+            .chain_update(tag_hash)
+            .chain_update(tag_hash)
+            .chain_update(message)
+            .finalize(),
+    );
+
+    let test = build_test!(source, &stack);
+    test.expect_stack(&expected);
+}
+
+// SIGNATURE VERIFICATION
+// ================================================================================================
+
+/// Because this test is pretty expensive, it's by default ignored. If you're interested in
+/// running this test, issue
+///
+/// cargo test --release -p miden-stdlib schnorr -- --include-ignored
+///
+/// from root directory of Miden repository.
+///
+/// The first test case is taken from https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+#[test_case("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9", "0000000000000000000000000000000000000000000000000000000000000000", "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0", true ; "0")]
+#[test_case("68CCCCAA8AA159BC49BC17525B2087428999CEAA902885D4D61405EDDE231F76", "2B902F8911E81818F8C99D5D5D9831957504D90E945DE2E8F54EE781CC75F636", "22B1D7E0354BEE5ABE1C70C3062889A7E41818BD98C0873E378EA54FF054B36CA3654E8CA11A4A95E1D4190D9E455A1DEE5BA34039CF5A38AA07211958175F44", true ; "1")]
+#[test_case("A4A4375D7BDF447AA85219D6943D300EFDFE71D99AC8EED7297852B090CC520C", "B5769FA0F1483F95A90D9DF2F130D60FCF04BD93F50AE69514DA8C659CE2B10C", "3E021594333B70CEE364DB7753A07F33294A4C864B963EDC419B79DAD9583AB6882AB7C9E0EACBB3EEE6298CFC672084F835966462CCFEA1A47A839270A0013F", true ; "2")]
+#[test_case("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9", "0000000000000000000000000000000000000000000000000000000000000000", "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C1", false ; "3")]
+#[ignore]
+fn verify(pub_key: &str, message: &str, signature: &str, is_valid: bool) {
+    let test = build_verify_test(pub_key, message, signature);
+    assert_eq!(test.execute_process().is_ok(), is_valid);
+}
+
+/// Public key is not a valid x -coordinate of a secp256k1 point.
+#[test]
+fn verify_fails_on_invalid_pub_key() {
+    let test = build_verify_test(
+        "0000000000000000000000000000000000000000000000000000000000000005",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+    );
+    assert!(test.execute().is_err());
+}
+
+/// r is not smaller than the base field prime p, and s is not smaller than the group order n.
+#[test_case("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0" ; "r equal to p")]
+#[test_case("E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141" ; "s equal to n")]
+fn verify_fails_on_out_of_range_signature(signature: &str) {
+    let test = build_verify_test(
+        "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        signature,
+    );
+    assert!(test.execute().is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_verify_test(pub_key: &str, message: &str, signature: &str) -> test_utils::Test {
+    let source = "
+    use.std::crypto::dsa::schnorr_secp256k1
+
+    begin
+        exec.schnorr_secp256k1::verify
+    end";
+
+    let mut stack = [pub_key, message, signature].map(hex_to_words).concat();
+    stack.reverse();
+
+    build_test!(source, &stack)
+}
+
+/// Packs bytes into 32 -bit words, maintaining big endian byte order.
+fn to_words(bytes: &[u8]) -> Vec<u64> {
+    group_slice_elements::<u8, 4>(bytes)
+        .iter()
+        .map(|&bytes| u32::from_be_bytes(bytes) as u64)
+        .collect()
+}
+
+fn hex_to_words(hex: &str) -> Vec<u64> {
+    (0..hex.len())
+        .step_by(8)
+        .map(|i| u32::from_str_radix(&hex[i..i + 8], 16).unwrap() as u64)
+        .collect()
+}
@@ -10,12 +10,13 @@ use test_utils::{
 
 // Note: Changes to MidenVM may cause this test to fail when some of the assumptions documented
 // in `stdlib/asm/crypto/stark/verifier.masm` are violated.
+//
+// These tests check real proofs with `std::crypto::stark::verify`, which is a partial verifier:
+// the AIR constraints are not evaluated over the OOD frame, and thus, a passing test does not
+// mean that the proof was fully verified inside the VM.
 #[test]
-#[ignore]
-fn stark_verifier_e2f4() {
+fn stark_verifier_partial_e2f4() {
     // An example MASM program to be verified inside Miden VM
-    // Note that output stack-overflow is not yet supported because of the way we handle public inputs
-    // in the STARK verifier is not yet general enough. Thus the output stack should be of size exactly 16.
     let example_source = "begin
             repeat.32
                 swap dup.1 add
@@ -25,6 +26,25 @@ fn stark_verifier_e2f4() {
     stack_inputs[15] = 0;
     stack_inputs[14] = 1;
 
+    verify_recursively(example_source, stack_inputs);
+}
+
+#[test]
+fn stark_verifier_partial_e2f4_stack_overflow() {
+    // An example MASM program which takes fewer than 16 inputs and leaves more than 16 outputs on
+    // the stack, so that the number of public inputs is not a multiple of 8
+    let example_source = "begin
+            repeat.32
+                swap dup.1 add
+            end
+            push.1 push.2
+        end";
+    let stack_inputs = vec![1_u64, 0];
+
+    verify_recursively(example_source, stack_inputs);
+}
+
+fn verify_recursively(example_source: &str, stack_inputs: Vec<u64>) {
     let VerifierData {
         initial_stack,
         tape,
@@ -32,11 +52,11 @@ fn stark_verifier_e2f4() {
         advice_map,
     } = generate_recursive_verifier_data(example_source, stack_inputs).unwrap();
 
-    // Verify inside Miden VM
+    // Partially verify inside Miden VM (commitments, proof-of-work and FRI)
     let source = "
-        use.std::crypto::stark::verifier
+        use.std::crypto::stark
         begin
-            exec.verifier::verify
+            exec.stark::verify
         end
         ";
 
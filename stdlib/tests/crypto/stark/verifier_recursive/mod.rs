@@ -11,6 +11,11 @@ mod channel;
 use channel::VerifierChannel;
 
 pub const BLOWUP_FACTOR: usize = 8;
+
+/// Widths of the main and the auxiliary trace segments for which `std::crypto::stark` is written.
+pub const MAIN_TRACE_WIDTH: usize = 70;
+pub const AUX_TRACE_WIDTH: usize = 7;
+
 pub type QuadExt = QuadExtension<Felt>;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -25,12 +30,28 @@ pub fn generate_advice_inputs(
     proof: StarkProof,
     pub_inputs: <ProcessorAir as Air>::PublicInputs,
 ) -> Result<VerifierData, VerifierError> {
+    // the recursive verifier supports only the trace layout of ProcessorAir
+    let trace_info = proof.get_trace_info();
+    let layout = trace_info.layout();
+    assert!(
+        layout.main_trace_width() == MAIN_TRACE_WIDTH
+            && layout.num_aux_segments() == 1
+            && layout.aux_trace_width() == AUX_TRACE_WIDTH,
+        "the recursive verifier does not support a trace layout with {} main and {} auxiliary \
+        columns in {} auxiliary segments",
+        layout.main_trace_width(),
+        layout.aux_trace_width(),
+        layout.num_aux_segments()
+    );
+
     //// build a seed for the public coin; the initial seed is the hash of public inputs and proof
     //// context, but as the protocol progresses, the coin will be reseeded with the info received
     //// from the prover
     let mut public_coin_seed = proof.context.to_elements();
+    let pub_inputs_elements = pub_inputs.to_elements();
     let trace_len: Felt = public_coin_seed[7];
     let initial_stack = vec![
+        pub_inputs_elements.len() as u64,
         public_coin_seed[4].as_int(),
         (public_coin_seed[5].as_int() as usize).ilog2() as u64,
         public_coin_seed[6].as_int(),
@@ -38,9 +59,15 @@ pub fn generate_advice_inputs(
     ];
 
     let mut tape = vec![];
-    public_coin_seed.append(&mut pub_inputs.to_elements());
-
-    let pub_inputs_int: Vec<u64> = pub_inputs.to_elements().iter().map(|a| a.as_int()).collect();
+    public_coin_seed.extend_from_slice(&pub_inputs_elements);
+
+    // the public inputs are followed by the padding applied by the hash function when their number
+    // is not a multiple of the rate width i.e., a 1 followed by as many 0s as needed
+    let mut pub_inputs_int: Vec<u64> = pub_inputs_elements.iter().map(|a| a.as_int()).collect();
+    if pub_inputs_int.len() % 8 != 0 {
+        pub_inputs_int.push(1);
+        pub_inputs_int.resize(pub_inputs_int.len().next_multiple_of(8), 0);
+    }
     tape.extend_from_slice(&pub_inputs_int[..]);
 
     // create AIR instance for the computation specified in the proof
@@ -116,10 +143,19 @@ pub fn generate_advice_inputs(
     // the LDE domain from the public coin.
     // This is needed in order to construct Partial Merkle Trees
     let pow_nonce = channel.read_pow_nonce();
-    let query_positions = public_coin
+    if public_coin.check_leading_zeros(pow_nonce) < air.options().grinding_factor() {
+        return Err(VerifierError::QuerySeedProofOfWorkVerificationFailed);
+    }
+    tape.push(pow_nonce);
+    let mut query_positions = public_coin
         .draw_integers(air.options().num_queries(), air.lde_domain_size(), pow_nonce)
         .map_err(|_| VerifierError::RandomCoinError)?;
 
+    // remove any potential duplicates from the positions as the prover will send openings only
+    // for unique queries
+    query_positions.sort_unstable();
+    query_positions.dedup();
+
     // read advice maps and Merkle paths related to trace and constraint composition polynomial evaluations
     let (mut advice_map, mut partial_trees_traces) =
         channel.read_queried_trace_states(&query_positions)?;
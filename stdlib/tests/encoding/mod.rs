@@ -0,0 +1,153 @@
+use processor::ExecutionError;
+use test_utils::rand::rand_value;
+
+const PTR: u64 = 1000;
+
+// DECODING U32
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn decode_u32() {
+    let values = [0, 1, 127, 128, 300, 16383, 16384, u32::MAX, rand_value::<u32>()];
+    for value in values {
+        let bytes = encode(value as u64);
+        let source = build_source("decode_u32", &bytes);
+
+        let test = build_test!(source, &[]);
+        test.expect_stack(&[value as u64, PTR + bytes.len() as u64]);
+    }
+}
+
+#[test]
+fn decode_u32_sequence() {
+    let mut bytes = encode(624485);
+    bytes.extend(encode(5));
+    let source = format!(
+        "
+        use.std::encoding::leb128
+        begin
+            {}
+            push.{PTR} exec.leb128::decode_u32
+            swap exec.leb128::decode_u32
+        end",
+        store_bytes(&bytes)
+    );
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[5, PTR + bytes.len() as u64, 624485]);
+}
+
+#[test]
+fn decode_u32_invalid() {
+    let encodings: [&[u64]; 6] = [
+        // not canonical
+        &[0x80, 0x00],
+        &[0xff, 0xff, 0x80, 0x00],
+        // longer than 5 bytes
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x01],
+        // greater than u32::MAX
+        &[0xff, 0xff, 0xff, 0xff, 0x1f],
+        &[0x80, 0x80, 0x80, 0x80, 0x10],
+        // not a byte
+        &[0x100],
+    ];
+
+    for bytes in encodings {
+        let source = build_source("decode_u32", bytes);
+        let test = build_test!(source, &[]);
+        assert!(test.execute().is_err(), "{bytes:x?} was not rejected");
+    }
+
+    let source = build_source("decode_u32", &[0x80, 0x00]);
+    let test = build_test!(source, &[]);
+    assert!(matches!(test.execute(), Err(ExecutionError::FailedAssertion { .. })));
+}
+
+// DECODING U64
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn decode_u64() {
+    let values = [
+        0,
+        1,
+        127,
+        128,
+        u32::MAX as u64,
+        (1 << 35) - 1,
+        1 << 35,
+        1 << 63,
+        u64::MAX,
+        rand_value::<u64>(),
+    ];
+    for value in values {
+        let bytes = encode(value);
+        let source = build_source("decode_u64", &bytes);
+
+        let test = build_test!(source, &[]);
+        test.expect_stack(&[value >> 32, value as u32 as u64, PTR + bytes.len() as u64]);
+    }
+}
+
+#[test]
+fn decode_u64_invalid() {
+    let encodings: [&[u64]; 6] = [
+        // not canonical
+        &[0x80, 0x00],
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x00],
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00],
+        // longer than 10 bytes
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01],
+        // greater than u64::MAX
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02],
+        // not a byte
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x100],
+    ];
+
+    for bytes in encodings {
+        let source = build_source("decode_u64", bytes);
+        let test = build_test!(source, &[]);
+        assert!(test.execute().is_err(), "{bytes:x?} was not rejected");
+    }
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Returns the canonical LEB128 encoding of the provided value.
+fn encode(mut value: u64) -> Vec<u64> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = value & 0x7f;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// Returns the source of a program which stores the provided bytes in memory starting at [PTR],
+/// and then executes the specified procedure of the `std::encoding::leb128` module.
+fn build_source(procedure: &str, bytes: &[u64]) -> String {
+    format!(
+        "
+        use.std::encoding::leb128
+        begin
+            {}
+            push.{PTR} exec.leb128::{procedure}
+        end",
+        store_bytes(bytes)
+    )
+}
+
+/// Returns instructions which store the provided bytes in memory starting at [PTR].
+fn store_bytes(bytes: &[u64]) -> String {
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| format!("push.{byte}.{} mem_store", PTR + i as u64))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
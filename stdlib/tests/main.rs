@@ -10,6 +10,7 @@ macro_rules! build_test {
 
 mod collections;
 mod crypto;
+mod encoding;
 mod math;
 mod mem;
 mod sys;
@@ -0,0 +1,138 @@
+use num_bigint::BigUint;
+use processor::{ContextId, ProcessState};
+use test_utils::{rand::rand_value, Test};
+
+// CONSTANTS
+// ================================================================================================
+
+const A_PTR: u32 = 1000;
+const B_PTR: u32 = 2000;
+const C_PTR: u32 = 3000;
+
+// ADDITION AND SUBTRACTION
+// ================================================================================================
+
+#[test]
+fn add() {
+    for n in [0, 1, 8, 64] {
+        let (a, b) = (rand_bigint(n), rand_bigint(n));
+        let sum = &a + &b;
+        let carry = (sum.clone() >> (32 * n)) == BigUint::from(1u32);
+        test_op("add", n, &a, &b, &[carry as u64], &(sum % modulus(n)), n);
+    }
+
+    // the carry is propagated through all limbs
+    let max = modulus(8) - 1u32;
+    test_op("add", 8, &max, &BigUint::from(1u32), &[1], &BigUint::from(0u32), 8);
+}
+
+#[test]
+fn sub() {
+    for n in [0, 1, 8, 64] {
+        let (a, b) = (rand_bigint(n), rand_bigint(n));
+        let borrow = a < b;
+        let diff = (&a + modulus(n) - &b) % modulus(n);
+        test_op("sub", n, &a, &b, &[borrow as u64], &diff, n);
+    }
+
+    // the borrow is propagated through all limbs
+    let max = modulus(8) - 1u32;
+    test_op("sub", 8, &BigUint::from(0u32), &BigUint::from(1u32), &[1], &max, 8);
+}
+
+// MULTIPLICATION
+// ================================================================================================
+
+#[test]
+fn mul() {
+    // schoolbook multiplication is used below 26 limbs, and the Karatsuba method from 26 limbs on
+    for n in [0, 1, 8, 25, 26, 33, 64] {
+        let (a, b) = (rand_bigint(n), rand_bigint(n));
+        test_op("mul", n, &a, &b, &[], &(&a * &b), 2 * n);
+
+        // the intermediate sums of the Karatsuba method overflow
+        let max = modulus(n) - 1u32;
+        test_op("mul", n, &max, &max, &[], &(&max * &max), 2 * n);
+    }
+}
+
+// COMPARISONS
+// ================================================================================================
+
+#[test]
+fn comparisons() {
+    let n = 8;
+    let a = rand_bigint(n);
+    let larger = [&a + 1u32, &a + (BigUint::from(1u32) << (32 * (n - 1)))];
+    for (x, y) in [(&a, &a), (&a, &larger[0]), (&a, &larger[1]), (&larger[0], &a)] {
+        for (procedure, expected) in
+            [("eq", x == y), ("lt", x < y), ("lte", x <= y), ("gt", x > y), ("gte", x >= y)]
+        {
+            // comparisons do not write their result to memory, and thus leave c_ptr on the stack
+            let source = build_source(procedure, n, x, y);
+            build_test!(&source).expect_stack(&[expected as u64, C_PTR as u64]);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the specified procedure with the operands a and b of n limbs, and checks the stack
+/// and the result of c_len limbs written to memory against the expected values.
+fn test_op(
+    procedure: &str,
+    n: usize,
+    a: &BigUint,
+    b: &BigUint,
+    expected_stack: &[u64],
+    expected: &BigUint,
+    c_len: usize,
+) {
+    let source = build_source(procedure, n, a, b);
+    let test: Test = build_test!(&source);
+    test.expect_stack(expected_stack);
+
+    let process = test.execute_process().unwrap();
+    let result = (0..c_len).rev().fold(BigUint::from(0u32), |acc, i| {
+        let word = process.get_mem_value(ContextId::root(), C_PTR + i as u32).unwrap_or_default();
+        (acc << 32) + word[0].as_int()
+    });
+    assert_eq!(&result, expected, "{procedure} of {n} limbs");
+}
+
+/// Returns a program which writes a and b of n limbs into memory, and calls the specified
+/// procedure of `std::math::bigint` on them.
+fn build_source(procedure: &str, n: usize, a: &BigUint, b: &BigUint) -> String {
+    let mut stores = String::new();
+    for (ptr, value) in [(A_PTR, a), (B_PTR, b)] {
+        for (i, limb) in to_limbs(value, n).into_iter().enumerate() {
+            stores.push_str(&format!("push.{limb}.{} mem_store\n", ptr + i as u32));
+        }
+    }
+
+    format!(
+        "
+        use.std::math::bigint
+        begin
+            {stores}
+            push.{C_PTR}.{B_PTR}.{A_PTR}.{n}
+            exec.bigint::{procedure}
+        end"
+    )
+}
+
+fn to_limbs(value: &BigUint, n: usize) -> Vec<u32> {
+    let mut limbs = value.to_u32_digits();
+    limbs.resize(n, 0);
+    limbs
+}
+
+fn modulus(n: usize) -> BigUint {
+    BigUint::from(1u32) << (32 * n)
+}
+
+fn rand_bigint(n: usize) -> BigUint {
+    let limbs = (0..n).map(|_| rand_value::<u64>() as u32).collect::<Vec<_>>();
+    BigUint::from_slice(&limbs)
+}
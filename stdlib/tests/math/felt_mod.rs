@@ -0,0 +1,38 @@
+use processor::ExecutionError;
+use test_utils::{rand::rand_value, Felt, FieldElement, StarkField, TestError, ZERO};
+
+// EXPONENTIATION
+// ================================================================================================
+
+#[test]
+fn pow_u64() {
+    let source = "
+        use.std::math::felt
+        begin
+            exec.felt::pow_u64
+        end";
+
+    let b = rand_value::<u64>() % Felt::MODULUS;
+    let e: u64 = rand_value();
+    for e in [0, 1, u32::MAX as u64, 1 << 32, e, u64::MAX] {
+        let expected = Felt::new(b).exp(e);
+        let test = build_test!(source, &[b, e & (u32::MAX as u64), e >> 32]);
+        test.expect_stack(&[expected.as_int()]);
+    }
+}
+
+#[test]
+fn pow_u64_fail() {
+    let source = "
+        use.std::math::felt
+        begin
+            exec.felt::pow_u64
+        end";
+
+    let e_hi = u32::MAX as u64 + 1;
+    let test = build_test!(source, &[5, 0, e_hi]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::NotU32Value(
+        Felt::new(e_hi),
+        ZERO,
+    )));
+}
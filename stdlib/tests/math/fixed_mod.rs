@@ -0,0 +1,237 @@
+use num_bigint::BigUint;
+use test_utils::rand::rand_value;
+
+// CONSTANTS
+// ================================================================================================
+
+const ROUND_DOWN: u64 = 0;
+const ROUND_UP: u64 = 1;
+const ROUND_NEAREST: u64 = 2;
+
+const ROUNDING_MODES: [u64; 3] = [ROUND_DOWN, ROUND_UP, ROUND_NEAREST];
+
+/// The fixed-point representation of 1.
+const ONE: u128 = 1 << 64;
+
+// CONVERSIONS
+// ================================================================================================
+
+#[test]
+fn from_u64() {
+    let a = rand_value::<u64>();
+
+    let source = "
+        use.std::math::fixed
+        begin
+            exec.fixed::from_u64
+        end";
+
+    build_test!(source, &[a & 0xffffffff, a >> 32]).expect_stack(&to_stack(a as u128 * ONE));
+}
+
+#[test]
+fn to_u64() {
+    let source = "
+        use.std::math::fixed
+        begin
+            exec.fixed::to_u64
+        end";
+
+    let a = rand_u128() >> 1;
+    for (mode, expected) in [
+        (ROUND_DOWN, a >> 64),
+        (ROUND_UP, (a + ONE - 1) >> 64),
+        (ROUND_NEAREST, (a + ONE / 2) >> 64),
+    ] {
+        let mut operands = to_operands(&[a]);
+        operands.push(mode);
+        let expected = expected as u64;
+        build_test!(source, &operands).expect_stack(&[expected >> 32, expected & 0xffffffff]);
+    }
+
+    // values which round to 2^64 do not fit into 64 bits
+    let mut operands = to_operands(&[u128::MAX]);
+    operands.push(ROUND_UP);
+    assert!(build_test!(source, &operands).execute().is_err());
+}
+
+// ADDITION AND SUBTRACTION
+// ================================================================================================
+
+#[test]
+fn add() {
+    let source = "
+        use.std::math::fixed
+        begin
+            exec.fixed::add
+        end";
+
+    let a = rand_u128() >> 1;
+    let b = rand_u128() >> 1;
+    build_test!(source, &to_operands(&[a, b])).expect_stack(&to_stack(a + b));
+
+    // the sum must fit into 128 bits
+    let operands = to_operands(&[u128::MAX, 1]);
+    assert!(build_test!(source, &operands).execute().is_err());
+}
+
+#[test]
+fn sub() {
+    let source = "
+        use.std::math::fixed
+        begin
+            exec.fixed::sub
+        end";
+
+    let a = rand_u128();
+    let b = rand_u128() % a;
+    build_test!(source, &to_operands(&[a, b])).expect_stack(&to_stack(a - b));
+
+    // the difference must not be negative
+    let operands = to_operands(&[b, a]);
+    assert!(build_test!(source, &operands).execute().is_err());
+}
+
+// MULTIPLICATION AND DIVISION
+// ================================================================================================
+
+#[test]
+fn mul() {
+    let source = "
+        use.std::math::fixed
+        begin
+            exec.fixed::mul
+        end";
+
+    let a = rand_u128() >> 32;
+    let b = rand_u128() >> 32;
+    let product = BigUint::from(a) * BigUint::from(b);
+    for mode in ROUNDING_MODES {
+        let expected = round(&product, &(BigUint::from(1u32) << 64), mode);
+        test_op(source, &[a, b], mode, expected);
+    }
+
+    // 1.5 * 0.5 = 0.75 is exact under every rounding mode
+    for mode in ROUNDING_MODES {
+        test_op(source, &[3 * ONE / 2, ONE / 2], mode, 3 * ONE / 4);
+    }
+
+    // the product must fit into 128 bits
+    let mut operands = to_operands(&[u128::MAX, 2 * ONE]);
+    operands.push(ROUND_DOWN);
+    assert!(build_test!(source, &operands).execute().is_err());
+
+    // the rounding mode must be valid
+    let mut operands = to_operands(&[ONE, ONE]);
+    operands.push(3);
+    assert!(build_test!(source, &operands).execute().is_err());
+}
+
+#[test]
+fn div() {
+    let source = "
+        use.std::math::fixed
+        begin
+            exec.fixed::div
+        end";
+
+    let a = rand_u128() >> 32;
+    let b = rand_u128() >> 32;
+    let dividend = BigUint::from(a) << 64;
+    for mode in ROUNDING_MODES {
+        let expected = round(&dividend, &BigUint::from(b), mode);
+        test_op(source, &[a, b], mode, expected);
+    }
+
+    // 1 / 3 = 0x0.5555...; rounding up and to the nearest value differ from rounding down by 1
+    test_op(source, &[ONE, 3 * ONE], ROUND_DOWN, ONE / 3);
+    test_op(source, &[ONE, 3 * ONE], ROUND_UP, ONE / 3 + 1);
+    test_op(source, &[ONE, 3 * ONE], ROUND_NEAREST, ONE / 3);
+
+    // division by zero must fail
+    let mut operands = to_operands(&[ONE, 0]);
+    operands.push(ROUND_DOWN);
+    assert!(build_test!(source, &operands).execute().is_err());
+
+    // the quotient must fit into 128 bits
+    let mut operands = to_operands(&[u128::MAX, ONE / 2]);
+    operands.push(ROUND_DOWN);
+    assert!(build_test!(source, &operands).execute().is_err());
+}
+
+// SQUARE ROOT
+// ================================================================================================
+
+#[test]
+fn sqrt() {
+    let source = "
+        use.std::math::fixed
+        begin
+            exec.fixed::sqrt
+        end";
+
+    for a in [rand_u128(), rand_u128() >> 64, u128::MAX] {
+        let n: BigUint = BigUint::from(a) << 64;
+        let s = n.sqrt();
+        let r = &n - &s * &s;
+        for (mode, expected) in [
+            (ROUND_DOWN, s.clone()),
+            (ROUND_UP, if r == BigUint::from(0u32) { s.clone() } else { &s + 1u32 }),
+            (ROUND_NEAREST, if r > s { &s + 1u32 } else { s.clone() }),
+        ] {
+            test_op(source, &[a], mode, to_u128(&expected));
+        }
+    }
+
+    // the square roots of 0 and 4 are exact under every rounding mode
+    for mode in ROUNDING_MODES {
+        test_op(source, &[0], mode, 0);
+        test_op(source, &[4 * ONE], mode, 2 * ONE);
+    }
+
+    // the rounding mode must be valid
+    let mut operands = to_operands(&[ONE]);
+    operands.push(3);
+    assert!(build_test!(source, &operands).execute().is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the specified source with the operands provided from the deepest to the topmost one
+/// followed by the rounding mode, and checks the result against the expected value.
+fn test_op(source: &str, operands: &[u128], mode: u64, expected: u128) {
+    let mut operands = to_operands(operands);
+    operands.push(mode);
+    build_test!(source, &operands).expect_stack(&to_stack(expected));
+}
+
+/// Returns n / d rounded according to the specified rounding mode.
+fn round(n: &BigUint, d: &BigUint, mode: u64) -> u128 {
+    let (q, r) = (n / d, n % d);
+    let round_up = match mode {
+        ROUND_DOWN => false,
+        ROUND_UP => r != BigUint::from(0u32),
+        ROUND_NEAREST => r * 2u32 >= *d,
+        _ => unreachable!(),
+    };
+    to_u128(&(q + round_up as u32))
+}
+
+fn rand_u128() -> u128 {
+    ((rand_value::<u64>() as u128) << 64) | rand_value::<u64>() as u128
+}
+
+fn to_u128(value: &BigUint) -> u128 {
+    value.iter_u64_digits().rev().fold(0, |acc, digit| (acc << 64) | digit as u128)
+}
+
+/// Returns the limbs of the specified values as stack inputs, with the last value at the top.
+fn to_operands(values: &[u128]) -> Vec<u64> {
+    values.iter().flat_map(|&value| to_stack(value).into_iter().rev()).collect()
+}
+
+/// Returns the limbs of the specified value in the order they appear on the stack.
+fn to_stack(value: u128) -> Vec<u64> {
+    (0..4).rev().map(|i| ((value >> (32 * i)) as u64) & 0xffffffff).collect()
+}
@@ -0,0 +1,182 @@
+use test_utils::rand::rand_value;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Values at the boundaries of the signed and unsigned 32-bit and 64-bit ranges.
+const BOUNDARY_VALUES: [i64; 12] = [
+    0,
+    1,
+    -1,
+    2,
+    -2,
+    i64::MIN,
+    i64::MIN + 1,
+    i64::MAX,
+    i64::MAX - 1,
+    1 << 32,
+    -(1 << 32),
+    -7,
+];
+
+// CONVERSIONS AND SIGN
+// ================================================================================================
+
+#[test]
+fn sign_extend() {
+    for a in [0u32, 1, i32::MAX as u32, 1 << 31, u32::MAX, rand_value::<u64>() as u32] {
+        let expected = a as i32 as i64;
+        let test = build_test!(&source("sign_extend"), &[a as u64]);
+        test.expect_stack(&to_stack(expected));
+    }
+
+    // the input must be a u32 value
+    let test = build_test!(&source("sign_extend"), &[1 << 32]);
+    assert!(test.execute().is_err());
+}
+
+#[test]
+fn is_neg() {
+    for a in operands() {
+        test_unary_op("is_neg", a, &[(a < 0) as u64]);
+    }
+}
+
+#[test]
+fn neg() {
+    for a in operands() {
+        test_unary_op("neg", a, &to_stack(a.wrapping_neg()));
+    }
+}
+
+#[test]
+fn abs() {
+    // the absolute value is returned as an unsigned value, and thus, abs(i64::MIN) = 2^63
+    for a in operands() {
+        let abs = a.unsigned_abs();
+        test_unary_op("abs", a, &[abs >> 32, abs as u32 as u64]);
+    }
+}
+
+// ARITHMETIC OPERATIONS
+// ================================================================================================
+
+#[test]
+fn add() {
+    for (a, b) in operand_pairs() {
+        test_binary_op("wrapping_add", a, b, &to_stack(a.wrapping_add(b)));
+
+        let (c, overflow) = a.overflowing_add(b);
+        test_binary_op("overflowing_add", a, b, &with_flag(overflow, c));
+    }
+}
+
+#[test]
+fn sub() {
+    for (a, b) in operand_pairs() {
+        test_binary_op("wrapping_sub", a, b, &to_stack(a.wrapping_sub(b)));
+
+        let (c, overflow) = a.overflowing_sub(b);
+        test_binary_op("overflowing_sub", a, b, &with_flag(overflow, c));
+    }
+}
+
+#[test]
+fn mul() {
+    for (a, b) in operand_pairs() {
+        test_binary_op("wrapping_mul", a, b, &to_stack(a.wrapping_mul(b)));
+
+        let (c, overflow) = a.overflowing_mul(b);
+        test_binary_op("overflowing_mul", a, b, &with_flag(overflow, c));
+    }
+}
+
+#[test]
+fn divmod() {
+    // the quotient is rounded towards zero, and i64::MIN / -1 wraps around to i64::MIN
+    for (a, b) in operand_pairs().filter(|(_, b)| *b != 0) {
+        let (q, r) = (a.wrapping_div(b), a.wrapping_rem(b));
+        test_binary_op("div", a, b, &to_stack(q));
+        test_binary_op("mod", a, b, &to_stack(r));
+
+        let mut expected = to_stack(r).to_vec();
+        expected.extend_from_slice(&to_stack(q));
+        test_binary_op("divmod", a, b, &expected);
+    }
+
+    // division by zero should fail
+    for procedure in ["div", "mod", "divmod"] {
+        let test = build_test!(&source(procedure), &to_inputs(&[rand_value::<u64>() as i64, 0]));
+        assert!(test.execute().is_err());
+    }
+}
+
+// COMPARISON OPERATIONS
+// ================================================================================================
+
+#[test]
+fn comparisons() {
+    for (a, b) in operand_pairs() {
+        test_binary_op("lt", a, b, &[(a < b) as u64]);
+        test_binary_op("lte", a, b, &[(a <= b) as u64]);
+        test_binary_op("gt", a, b, &[(a > b) as u64]);
+        test_binary_op("gte", a, b, &[(a >= b) as u64]);
+        test_binary_op("eq", a, b, &[(a == b) as u64]);
+        test_binary_op("neq", a, b, &[(a != b) as u64]);
+        test_binary_op("min", a, b, &to_stack(a.min(b)));
+        test_binary_op("max", a, b, &to_stack(a.max(b)));
+    }
+
+    for a in operands() {
+        test_unary_op("eqz", a, &[(a == 0) as u64]);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn test_unary_op(procedure: &str, a: i64, expected: &[u64]) {
+    let test = build_test!(&source(procedure), &to_inputs(&[a]));
+    test.expect_stack(expected);
+}
+
+fn test_binary_op(procedure: &str, a: i64, b: i64, expected: &[u64]) {
+    let test = build_test!(&source(procedure), &to_inputs(&[a, b]));
+    test.expect_stack(expected);
+}
+
+fn source(procedure: &str) -> String {
+    format!(
+        "
+        use.std::math::i64
+        begin
+            exec.i64::{procedure}
+        end"
+    )
+}
+
+/// Returns the boundary values followed by a few random values.
+fn operands() -> impl Iterator<Item = i64> {
+    let random = [rand_value::<u64>() as i64, rand_value::<u32>() as i32 as i64];
+    BOUNDARY_VALUES.into_iter().chain(random)
+}
+
+fn operand_pairs() -> impl Iterator<Item = (i64, i64)> {
+    operands().flat_map(|a| operands().map(move |b| (a, b)))
+}
+
+/// Returns the stack inputs for the specified values, such that the first value is deepest in
+/// the stack.
+fn to_inputs(values: &[i64]) -> Vec<u64> {
+    values.iter().flat_map(|&v| [v as u64 as u32 as u64, v as u64 >> 32]).collect()
+}
+
+/// Returns the limbs of the specified value as they appear on the stack, starting from the top.
+fn to_stack(value: i64) -> [u64; 2] {
+    [value as u64 >> 32, value as u64 as u32 as u64]
+}
+
+fn with_flag(flag: bool, value: i64) -> [u64; 3] {
+    let [hi, lo] = to_stack(value);
+    [flag as u64, hi, lo]
+}
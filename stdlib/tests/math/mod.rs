@@ -1,4 +1,98 @@
+use num_bigint::BigUint;
+use processor::{
+    AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ExecutionError, Host,
+    HostResponse, MemAdviceProvider, ProcessState,
+};
+use test_utils::{ExecutionOptions, Felt};
+
+mod bigint_mod;
 pub mod ecgfp5;
+mod felt_mod;
+mod fixed_mod;
+mod i64_mod;
+mod modarith_mod;
 mod secp256k1;
+pub mod secp256r1;
 mod u256_mod;
 mod u64_mod;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that `inv` of the specified field module (e.g., `secp256k1::base_field`) fails when the
+/// host provides the non-canonical representative `inverse + modulus` of the inverse, even though
+/// such an inverse passes the `a * a^-1 = 1` check.
+pub fn expect_non_canonical_inverse_rejected(
+    module: &str,
+    injector: AdviceInjector,
+    modulus: &BigUint,
+) {
+    let name = module.rsplit("::").next().unwrap();
+    let source = format!(
+        "
+        use.std::math::{module}
+
+        begin
+            exec.{name}::inv
+        end"
+    );
+
+    // R^2 mod p is the Montgomery form of R, whose inverse in Montgomery form is 1; thus, the
+    // non-canonical inverse 1 + p still fits into 256 bits
+    let a = (BigUint::from(1u32) << 512u32) % modulus;
+    let mut stack = a.to_u32_digits().into_iter().map(u64::from).collect::<Vec<_>>();
+    stack.resize(8, 0);
+    stack.reverse();
+
+    let test = build_test!(&source, &stack);
+    let program = test.compile().expect("failed to compile test source");
+    let host = NonCanonicalInverseHost {
+        adv_provider: MemAdviceProvider::from(test.advice_inputs.clone()),
+        injector,
+        modulus: modulus.clone(),
+    };
+    let result =
+        processor::execute(&program, test.stack_inputs.clone(), host, ExecutionOptions::default());
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })), "{module}");
+}
+
+/// Host which adds the modulus to the inverse pushed onto the advice stack by the injector.
+struct NonCanonicalInverseHost {
+    adv_provider: MemAdviceProvider,
+    injector: AdviceInjector,
+    modulus: BigUint,
+}
+
+impl Host for NonCanonicalInverseHost {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.adv_provider.get_advice(process, &extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        let response = self.adv_provider.set_advice(process, &injector)?;
+        if injector == self.injector {
+            // the inverse is pushed onto the advice stack with its most significant limb on top
+            let mut limbs = Vec::with_capacity(8);
+            for _ in 0..8 {
+                limbs.push(self.adv_provider.pop_stack(process)?.as_int() as u32);
+            }
+            limbs.reverse();
+
+            let inverse = BigUint::from_slice(&limbs) + &self.modulus;
+            let limbs = inverse.to_u32_digits();
+            assert_eq!(limbs.len(), 8, "non-canonical inverse does not fit into 256 bits");
+            for limb in limbs {
+                self.adv_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+            }
+        }
+        Ok(response)
+    }
+}
@@ -0,0 +1,191 @@
+use num_bigint::BigUint;
+use processor::ExecutionError;
+use test_utils::rand::rand_vector;
+
+// CONTEXT INITIALIZATION
+// ================================================================================================
+
+#[test]
+fn init_context() {
+    for modulus in moduli() {
+        let source = format!(
+            "
+            use.std::math::modarith
+            begin
+                {}
+                padw push.1005 mem_loadw
+                padw push.1004 mem_loadw
+                padw push.1003 mem_loadw
+                padw push.1002 mem_loadw
+            end",
+            compute_context(&modulus)
+        );
+
+        // the in-VM context must match the one computed on the host
+        let context = miden_stdlib::math::u256_montgomery_context(to_u32_limbs(&modulus));
+        let expected = context[2..].iter().flatten().map(|&v| v as u64).collect::<Vec<_>>();
+        build_test!(&source, &[]).expect_stack(&expected);
+
+        // the modulus must be stored as well
+        let source = format!(
+            "
+            use.std::math::modarith
+            begin
+                {}
+                padw push.1001 mem_loadw
+                padw push.1000 mem_loadw
+            end",
+            compute_context(&modulus)
+        );
+        let expected = to_limbs(&modulus).into_iter().rev().collect::<Vec<_>>();
+        build_test!(&source, &[]).expect_stack(&expected);
+    }
+}
+
+#[test]
+fn init_context_invalid_modulus() {
+    let one = BigUint::from(1u32);
+    for modulus in [BigUint::from(0u32), one.clone(), BigUint::from(2u32), &one << 255] {
+        let source = format!(
+            "
+            use.std::math::modarith
+            begin
+                {}
+            end",
+            compute_context(&modulus)
+        );
+
+        let test = build_test!(&source, &[]);
+        assert!(matches!(test.execute(), Err(ExecutionError::FailedAssertion { .. })));
+    }
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn mod_mul_and_exp() {
+    for modulus in moduli() {
+        let a = rand_u256() % &modulus;
+        let b = rand_u256() % &modulus;
+        test_mod_op("mod_mul", &modulus, &[&a, &b], &(&a * &b % &modulus));
+
+        let e = BigUint::from(65537u32);
+        test_mod_op("mod_exp", &modulus, &[&a, &e], &a.modpow(&e, &modulus));
+    }
+}
+
+#[test]
+fn mont_mul() {
+    let r = BigUint::from(1u32) << 256;
+    for modulus in moduli() {
+        let a = rand_u256() % &modulus;
+        let b = rand_u256() % &modulus;
+
+        // multiplying a in Montgomery form by b cancels out the Montgomery factor
+        let a_mont = &a * &r % &modulus;
+        test_mod_op("mont_mul", &modulus, &[&a_mont, &b], &(&a * &b % &modulus));
+    }
+}
+
+// INVERSION
+// ================================================================================================
+
+#[test]
+fn mod_inv() {
+    let one = BigUint::from(1u32);
+
+    // for prime moduli, the inverse is computed via Fermat's little theorem
+    for modulus in [(&one << 255) - 19u32, (&one << 256) - 189u32] {
+        let exp = &modulus - 2u32;
+        for a in [one.clone(), &modulus - 1u32, rand_u256() % &modulus] {
+            test_mod_op("mod_inv", &modulus, &[&a], &a.modpow(&exp, &modulus));
+        }
+    }
+
+    // 7 * 13 = 91 = 6 * 15 + 1
+    let modulus = BigUint::from(15u32);
+    test_mod_op("mod_inv", &modulus, &[&BigUint::from(7u32)], &BigUint::from(13u32));
+}
+
+#[test]
+fn mod_inv_no_inverse() {
+    // 2^256 - 1 is divisible by 3
+    let one = BigUint::from(1u32);
+    let modulus = (&one << 256) - &one;
+    for a in [BigUint::from(0u32), BigUint::from(3u32)] {
+        let source = format!(
+            "
+            use.std::math::modarith
+            begin
+                {}
+                push.1000
+                exec.modarith::mod_inv
+            end",
+            compute_context(&modulus)
+        );
+
+        let test = build_test!(&source, &to_limbs(&a));
+        assert!(matches!(test.execute(), Err(ExecutionError::FailedAssertion { .. })));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a set of odd moduli used to test modular arithmetic.
+fn moduli() -> Vec<BigUint> {
+    let one = BigUint::from(1u32);
+    vec![
+        BigUint::from(3u32),
+        (&one << 128) + BigUint::from(51u32),
+        (&one << 255) - BigUint::from(19u32),
+        (&one << 256) - &one,
+        rand_u256() | &one,
+    ]
+}
+
+/// Returns the instructions which compute the context of the specified modulus at address 1000.
+fn compute_context(modulus: &BigUint) -> String {
+    let limbs = to_limbs(modulus).iter().map(|v| v.to_string()).collect::<Vec<_>>().join(".");
+    format!("push.{limbs}.1000 exec.modarith::init_context")
+}
+
+/// Executes the specified procedure with the operands provided from the deepest to the topmost
+/// one, using the context computed in the VM, and checks the result against the expected value.
+fn test_mod_op(procedure: &str, modulus: &BigUint, operands: &[&BigUint], expected: &BigUint) {
+    let source = format!(
+        "
+        use.std::math::modarith
+        begin
+            {}
+            push.1000
+            exec.modarith::{procedure}
+        end",
+        compute_context(modulus)
+    );
+
+    let operands = operands.iter().flat_map(|&value| to_limbs(value)).collect::<Vec<_>>();
+    let result = to_limbs(expected).into_iter().rev().collect::<Vec<_>>();
+    build_test!(&source, &operands).expect_stack(&result);
+}
+
+fn rand_u256() -> BigUint {
+    let limbs = rand_vector::<u64>(8).iter().map(|&v| v as u32).collect::<Vec<_>>();
+    BigUint::new(limbs)
+}
+
+/// Returns the 8 limbs of the specified value, with the least significant limb first.
+fn to_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+fn to_u32_limbs(value: &BigUint) -> [u32; 8] {
+    let mut limbs = [0; 8];
+    for (limb, &digit) in limbs.iter_mut().zip(value.to_u32_digits().iter()) {
+        *limb = digit;
+    }
+    limbs
+}
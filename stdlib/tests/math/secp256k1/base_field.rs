@@ -1,4 +1,7 @@
+use super::super::expect_non_canonical_inverse_rejected;
 use core::ops::{Add, Mul, Neg, Sub};
+use num_bigint::BigUint;
+use processor::AdviceInjector;
 use test_utils::rand::rand_array;
 
 /// Secp256k1 base field element, kept in Montgomery form
@@ -516,3 +519,53 @@ fn test_secp256k1_base_field_inv() {
     let test = build_test!(source, &stack);
     test.expect_stack(&elm1.limbs.map(|v| v as u64));
 }
+
+#[test]
+fn test_secp256k1_base_field_inv_zero() {
+    let source = "
+    use.std::math::secp256k1::base_field
+
+    begin
+        exec.base_field::inv
+    end";
+
+    let test = build_test!(source, &[0u64; 8]);
+    test.expect_stack(&[0u64; 8]);
+}
+
+#[test]
+fn test_secp256k1_base_field_inv_non_canonical() {
+    let p = BigUint::parse_bytes(
+        b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        16,
+    )
+    .unwrap();
+    expect_non_canonical_inverse_rejected(
+        "secp256k1::base_field",
+        AdviceInjector::Secp256k1BaseInv,
+        &p,
+    );
+}
+
+#[test]
+fn test_secp256k1_base_field_inv_cycles() {
+    // with the inverse provided by the host, inversion costs about as much as a multiplication
+    // (which checks the inverse) plus a comparison against the modulus, i.e. about 1.7k cycles
+    let cycles = |procedure: &str| {
+        let source = format!(
+            "
+            use.std::math::secp256k1::base_field
+
+            begin
+                exec.base_field::{procedure}
+            end"
+        );
+
+        let stack = rand_array::<u32, 16>().map(|v| v as u64);
+        let test = build_test!(&source, &stack);
+        test.execute().unwrap().trace_len_summary().main_trace_len()
+    };
+
+    let (inv_cycles, mul_cycles) = (cycles("inv"), cycles("mul"));
+    assert!(inv_cycles < mul_cycles + 300, "inv takes {inv_cycles} cycles, mul {mul_cycles}");
+}
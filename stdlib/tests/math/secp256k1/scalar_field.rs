@@ -1,4 +1,7 @@
+use super::super::expect_non_canonical_inverse_rejected;
 use core::ops::Mul;
+use num_bigint::BigUint;
+use processor::AdviceInjector;
 use test_utils::rand::rand_array;
 
 /// Secp256k1 scalar field element, kept in Montgomery form
@@ -307,3 +310,17 @@ fn test_secp256k1_scalar_field_inv() {
     let test = build_test!(source, &stack);
     test.expect_stack(&elm2.limbs.map(|v| v as u64));
 }
+
+#[test]
+fn test_secp256k1_scalar_field_inv_non_canonical() {
+    let n = BigUint::parse_bytes(
+        b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        16,
+    )
+    .unwrap();
+    expect_non_canonical_inverse_rejected(
+        "secp256k1::scalar_field",
+        AdviceInjector::Secp256k1ScalarInv,
+        &n,
+    );
+}
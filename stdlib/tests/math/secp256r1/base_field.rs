@@ -0,0 +1,112 @@
+use super::{
+    super::expect_non_canonical_inverse_rejected, base_modulus, from_stack, radix, rand_u256,
+    to_stack,
+};
+use num_bigint::BigUint;
+use processor::AdviceInjector;
+
+/// Executes the specified procedure of the secp256r1 base field module on the provided operands
+/// and returns the resulting 256-bit number.
+fn exec_base_field(procedure: &str, operands: &[&BigUint]) -> BigUint {
+    let source = format!(
+        "
+    use.std::math::secp256r1::base_field
+
+    begin
+        exec.base_field::{procedure}
+    end"
+    );
+
+    let test = build_test!(source, &to_stack(operands));
+    from_stack(&test.get_last_stack_state(), 0)
+}
+
+#[test]
+fn test_secp256r1_base_field_mul() {
+    let p = base_modulus();
+    let r_inv = radix().modinv(&p).unwrap();
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = exec_base_field("mul", &[&a, &b]);
+    assert_eq!(c % &p, a * b * r_inv % &p);
+
+    // operands which are not reduced and have the largest possible value
+    let a = radix() - 1u32;
+    let c = exec_base_field("mul", &[&a, &a]);
+    assert_eq!(c % &p, &a * &a * radix().modinv(&p).unwrap() % &p);
+}
+
+#[test]
+fn test_secp256r1_base_field_add() {
+    let p = base_modulus();
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = exec_base_field("add", &[&a, &b]);
+    assert_eq!(c % &p, (a + b) % &p);
+
+    // sum of operands which are not reduced requires two corrections
+    let a = radix() - 1u32;
+    let c = exec_base_field("add", &[&a, &a]);
+    assert_eq!(c % &p, (&a + &a) % &p);
+}
+
+#[test]
+fn test_secp256r1_base_field_sub() {
+    let p = base_modulus();
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = exec_base_field("sub", &[&a, &b]);
+    assert_eq!(c % &p, (a + &p - b % &p) % &p);
+
+    // difference of operands which are not reduced requires two corrections
+    let a = BigUint::from(0u32);
+    let b = radix() - 1u32;
+    let c = exec_base_field("sub", &[&a, &b]);
+    assert_eq!(c % &p, (&p - b % &p) % &p);
+}
+
+#[test]
+fn test_secp256r1_base_field_neg() {
+    let p = base_modulus();
+
+    let a = rand_u256();
+    let c = exec_base_field("neg", &[&a]);
+    assert_eq!((c + a) % &p, BigUint::from(0u32));
+}
+
+#[test]
+fn test_secp256r1_base_field_mont_round_trip() {
+    let p = base_modulus();
+
+    let a = rand_u256() % &p;
+    let a_mont = exec_base_field("to_mont", &[&a]);
+    assert_eq!(a_mont % &p, &a * radix() % &p);
+
+    let a_mont = &a * radix() % &p;
+    assert_eq!(exec_base_field("from_mont", &[&a_mont]), a);
+}
+
+#[test]
+fn test_secp256r1_base_field_inv() {
+    let p = base_modulus();
+
+    let a = rand_u256();
+    let b = exec_base_field("inv", &[&a]);
+    // ( a * R^-1 ) * ( b * R^-1 ) = 1, for a, b in Montgomery form
+    assert_eq!(a * b % &p, radix() * radix() % &p);
+
+    let zero = BigUint::from(0u32);
+    assert_eq!(exec_base_field("inv", &[&zero]), zero);
+}
+
+#[test]
+fn test_secp256r1_base_field_inv_non_canonical() {
+    expect_non_canonical_inverse_rejected(
+        "secp256r1::base_field",
+        AdviceInjector::Secp256r1BaseInv,
+        &base_modulus(),
+    );
+}
@@ -0,0 +1,258 @@
+use super::{base_modulus, from_stack, radix, rand_u256, scalar_modulus, to_limbs};
+use num_bigint::BigUint;
+
+// REFERENCE IMPLEMENTATION
+// ================================================================================================
+
+/// A point on secp256r1 curve in affine coordinates, where `None` is the point at infinity.
+pub type AffinePoint = Option<(BigUint, BigUint)>;
+
+/// Returns the generator of secp256r1 group.
+pub fn generator() -> AffinePoint {
+    let x = b"6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+    let y = b"4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+    Some((BigUint::parse_bytes(x, 16).unwrap(), BigUint::parse_bytes(y, 16).unwrap()))
+}
+
+/// Adds two points using the affine addition law of short Weierstrass curves with a = -3.
+fn affine_add(a: &AffinePoint, b: &AffinePoint) -> AffinePoint {
+    let p = base_modulus();
+    let ((x1, y1), (x2, y2)) = match (a, b) {
+        (None, _) => return b.clone(),
+        (_, None) => return a.clone(),
+        (Some(a), Some(b)) => (a, b),
+    };
+
+    let lambda = if x1 == x2 {
+        if (y1 + y2) % &p == BigUint::from(0u32) {
+            return None;
+        }
+        // lambda = (3 * x^2 - 3) / (2 * y)
+        let num = (BigUint::from(3u32) * x1 * x1 + &p - 3u32) % &p;
+        num * (BigUint::from(2u32) * y1).modinv(&p).unwrap() % &p
+    } else {
+        // lambda = (y2 - y1) / (x2 - x1)
+        let num = (y2 + &p - y1) % &p;
+        num * ((x2 + &p - x1) % &p).modinv(&p).unwrap() % &p
+    };
+
+    let x3 = (&lambda * &lambda + BigUint::from(2u32) * &p - x1 - x2) % &p;
+    let y3 = (lambda * ((x1 + &p - &x3) % &p) + &p - y1) % &p;
+    Some((x3, y3))
+}
+
+/// Multiplies a point by a scalar using double-and-add algorithm.
+pub fn affine_mul(point: &AffinePoint, scalar: &BigUint) -> AffinePoint {
+    let mut result = None;
+    let mut base = point.clone();
+    for i in 0..scalar.bits() {
+        if scalar.bit(i) {
+            result = affine_add(&result, &base);
+        }
+        base = affine_add(&base, &base);
+    }
+    result
+}
+
+/// Returns a random point of secp256r1 group, along with its discrete logarithm.
+fn rand_point() -> (AffinePoint, BigUint) {
+    let k = rand_u256() % scalar_modulus();
+    (affine_mul(&generator(), &k), k)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns instructions which write the provided point into memory, as a projective point with
+/// coordinates in Montgomery form, starting at the specified address.
+fn store_point(point: &AffinePoint, addr: u32) -> String {
+    let p = base_modulus();
+    let (x, y) = point.clone().expect("point is at infinity");
+    let coordinates = [x * radix() % &p, y * radix() % &p, radix() % &p];
+
+    let mut source = String::new();
+    for (i, coordinate) in coordinates.iter().enumerate() {
+        let limbs = to_limbs(coordinate);
+        for (j, word) in limbs.chunks(4).enumerate() {
+            source.push_str(&format!(
+                "push.{}.{}.{}.{} mem_storew.{} dropw\n",
+                word[3],
+                word[2],
+                word[1],
+                word[0],
+                addr + 2 * i as u32 + j as u32
+            ));
+        }
+    }
+    source
+}
+
+/// Returns instructions which read the projective point stored in memory at the specified
+/// address and push the affine coordinates of the point onto the stack, in radix-2^32 form.
+///
+/// The resulting stack looks like [y0, ..., y7, x0, ..., x7, ...].
+fn load_affine_point(addr: u32) -> String {
+    format!(
+        "
+        push.0.0.0.0.0.0.0.0 mem_loadw.{z1} swapw mem_loadw.{z0}
+        exec.base_field::inv
+        mem_storew.1000 swapw mem_storew.1001 swapw
+
+        push.0.0.0.0.0.0.0.0 mem_loadw.{x1} swapw mem_loadw.{x0}
+        exec.base_field::mul
+        exec.base_field::from_mont
+
+        push.0.0.0.0.0.0.0.0 mem_loadw.1001 swapw mem_loadw.1000
+        push.0.0.0.0.0.0.0.0 mem_loadw.{y1} swapw mem_loadw.{y0}
+        exec.base_field::mul
+        exec.base_field::from_mont
+        ",
+        x0 = addr,
+        x1 = addr + 1,
+        y0 = addr + 2,
+        y1 = addr + 3,
+        z0 = addr + 4,
+        z1 = addr + 5,
+    )
+}
+
+/// Executes the provided program and returns the affine point left on the stack.
+fn exec_point_program(source: &str) -> AffinePoint {
+    let test = build_test!(source, &[]);
+    let stack = test.get_last_stack_state();
+    Some((from_stack(&stack, 8), from_stack(&stack, 0)))
+}
+
+/// Returns instructions which push the provided scalar onto the stack, in radix-2^32 form.
+fn push_scalar(scalar: &BigUint) -> String {
+    let limbs = to_limbs(scalar).iter().rev().map(|v| v.to_string()).collect::<Vec<_>>();
+    format!("push.{}", limbs.join("."))
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn test_secp256r1_point_doubling() {
+    let (point, _) = rand_point();
+
+    let source = format!(
+        "
+    use.std::math::secp256r1::base_field
+    use.std::math::secp256r1::group
+
+    begin
+        {}
+        push.11.10.9.8.7.6
+        push.5.4.3.2.1.0
+        exec.group::double
+        dropw
+        drop
+        drop
+
+        {}
+    end",
+        store_point(&point, 0),
+        load_affine_point(6)
+    );
+
+    assert_eq!(exec_point_program(&source), affine_add(&point, &point));
+}
+
+#[test]
+fn test_secp256r1_point_addition() {
+    let (point0, _) = rand_point();
+    let (point1, _) = rand_point();
+
+    let source = format!(
+        "
+    use.std::math::secp256r1::base_field
+    use.std::math::secp256r1::group
+
+    begin
+        {}
+        {}
+        push.17.16.15.14.13.12
+        push.11.10.9.8.7.6
+        push.5.4.3.2.1.0
+        exec.group::add
+        dropw
+        drop
+        drop
+
+        {}
+    end",
+        store_point(&point0, 0),
+        store_point(&point1, 6),
+        load_affine_point(12)
+    );
+
+    assert_eq!(exec_point_program(&source), affine_add(&point0, &point1));
+
+    // adding a point to itself must also work, as the addition formula is complete
+    let source = source.replace(&store_point(&point1, 6), &store_point(&point0, 6));
+    assert_eq!(exec_point_program(&source), affine_add(&point0, &point0));
+}
+
+/// Because this test is pretty expensive, it's by default ignored. If you're interested in
+/// running this test, issue
+///
+/// cargo test --release -p miden-stdlib secp256r1 -- --include-ignored
+///
+/// from root directory of Miden repository.
+#[test]
+#[ignore]
+fn test_secp256r1_point_multiplication() {
+    let (point, _) = rand_point();
+    let scalar = rand_u256();
+
+    let source = format!(
+        "
+    use.std::math::secp256r1::base_field
+    use.std::math::secp256r1::group
+
+    begin
+        {}
+        push.11.10.9.8.7.6
+        {}
+        push.5.4.3.2.1.0
+        exec.group::mul
+        dropw
+        drop
+        drop
+
+        {}
+    end",
+        store_point(&point, 0),
+        push_scalar(&scalar),
+        load_affine_point(6)
+    );
+
+    assert_eq!(exec_point_program(&source), affine_mul(&point, &scalar));
+}
+
+#[test]
+fn test_secp256r1_generator_multiplication() {
+    let scalar = rand_u256();
+
+    let source = format!(
+        "
+    use.std::math::secp256r1::base_field
+    use.std::math::secp256r1::group
+
+    begin
+        push.5.4.3.2.1.0
+        {}
+        exec.group::gen_mul
+        dropw
+        drop
+        drop
+
+        {}
+    end",
+        push_scalar(&scalar),
+        load_affine_point(0)
+    );
+
+    assert_eq!(exec_point_program(&source), affine_mul(&generator(), &scalar));
+}
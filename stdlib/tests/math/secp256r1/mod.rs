@@ -0,0 +1,57 @@
+use num_bigint::BigUint;
+use test_utils::{rand::rand_array, Felt};
+
+mod base_field;
+pub mod group;
+mod scalar_field;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the prime modulus of the secp256r1 base field.
+pub fn base_modulus() -> BigUint {
+    BigUint::parse_bytes(b"ffffffff00000001000000000000000000000000ffffffffffffffffffffffff", 16)
+        .unwrap()
+}
+
+/// Returns the prime modulus of the secp256r1 scalar field ( i.e. order of the group ).
+pub fn scalar_modulus() -> BigUint {
+    BigUint::parse_bytes(b"ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551", 16)
+        .unwrap()
+}
+
+/// Returns the Montgomery radix R = 2^256.
+pub fn radix() -> BigUint {
+    BigUint::from(1u32) << 256
+}
+
+/// Returns a random 256-bit number, which is not necessarily reduced modulo any of the moduli.
+pub fn rand_u256() -> BigUint {
+    BigUint::from_slice(&rand_array::<u32, 8>())
+}
+
+/// Returns the eight 32-bit limbs of the provided number, least significant limb first.
+pub fn to_limbs(value: &BigUint) -> [u64; 8] {
+    let mut limbs = [0u64; 8];
+    for (limb, digit) in limbs.iter_mut().zip(value.to_u32_digits()) {
+        *limb = digit as u64;
+    }
+    limbs
+}
+
+/// Builds stack inputs such that the first provided number is on the top of the stack, with its
+/// least significant limb first.
+pub fn to_stack(values: &[&BigUint]) -> Vec<u64> {
+    let mut stack = values.iter().flat_map(|value| to_limbs(value)).collect::<Vec<_>>();
+    stack.reverse();
+    stack
+}
+
+/// Reads a 256-bit number from eight stack elements, starting at the specified position.
+pub fn from_stack(stack: &[Felt], offset: usize) -> BigUint {
+    let limbs = stack[offset..offset + 8]
+        .iter()
+        .map(|value| u32::try_from(value.as_int()).expect("limb is not a u32 value"))
+        .collect::<Vec<_>>();
+    BigUint::from_slice(&limbs)
+}
@@ -0,0 +1,112 @@
+use super::{
+    super::expect_non_canonical_inverse_rejected, from_stack, radix, rand_u256, scalar_modulus,
+    to_stack,
+};
+use num_bigint::BigUint;
+use processor::AdviceInjector;
+
+/// Executes the specified procedure of the secp256r1 scalar field module on the provided operands
+/// and returns the resulting 256-bit number.
+fn exec_scalar_field(procedure: &str, operands: &[&BigUint]) -> BigUint {
+    let source = format!(
+        "
+    use.std::math::secp256r1::scalar_field
+
+    begin
+        exec.scalar_field::{procedure}
+    end"
+    );
+
+    let test = build_test!(source, &to_stack(operands));
+    from_stack(&test.get_last_stack_state(), 0)
+}
+
+#[test]
+fn test_secp256r1_scalar_field_mul() {
+    let n = scalar_modulus();
+    let r_inv = radix().modinv(&n).unwrap();
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = exec_scalar_field("mul", &[&a, &b]);
+    assert_eq!(c % &n, a * b * &r_inv % &n);
+
+    // operands which are not reduced and have the largest possible value
+    let a = radix() - 1u32;
+    let c = exec_scalar_field("mul", &[&a, &a]);
+    assert_eq!(c % &n, &a * &a * r_inv % &n);
+}
+
+#[test]
+fn test_secp256r1_scalar_field_add() {
+    let n = scalar_modulus();
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = exec_scalar_field("add", &[&a, &b]);
+    assert_eq!(c % &n, (a + b) % &n);
+
+    // sum of operands which are not reduced requires two corrections
+    let a = radix() - 1u32;
+    let c = exec_scalar_field("add", &[&a, &a]);
+    assert_eq!(c % &n, (&a + &a) % &n);
+}
+
+#[test]
+fn test_secp256r1_scalar_field_sub() {
+    let n = scalar_modulus();
+
+    let a = rand_u256();
+    let b = rand_u256();
+    let c = exec_scalar_field("sub", &[&a, &b]);
+    assert_eq!(c % &n, (a + &n - b % &n) % &n);
+
+    // difference of operands which are not reduced requires two corrections
+    let a = BigUint::from(0u32);
+    let b = radix() - 1u32;
+    let c = exec_scalar_field("sub", &[&a, &b]);
+    assert_eq!(c % &n, (&n - b % &n) % &n);
+}
+
+#[test]
+fn test_secp256r1_scalar_field_neg() {
+    let n = scalar_modulus();
+
+    let a = rand_u256();
+    let c = exec_scalar_field("neg", &[&a]);
+    assert_eq!((c + a) % &n, BigUint::from(0u32));
+}
+
+#[test]
+fn test_secp256r1_scalar_field_mont_round_trip() {
+    let n = scalar_modulus();
+
+    let a = rand_u256() % &n;
+    let a_mont = exec_scalar_field("to_mont", &[&a]);
+    assert_eq!(a_mont % &n, &a * radix() % &n);
+
+    let a_mont = &a * radix() % &n;
+    assert_eq!(exec_scalar_field("from_mont", &[&a_mont]), a);
+}
+
+#[test]
+fn test_secp256r1_scalar_field_inv() {
+    let n = scalar_modulus();
+
+    let a = rand_u256();
+    let b = exec_scalar_field("inv", &[&a]);
+    // ( a * R^-1 ) * ( b * R^-1 ) = 1, for a, b in Montgomery form
+    assert_eq!(a * b % &n, radix() * radix() % &n);
+
+    let zero = BigUint::from(0u32);
+    assert_eq!(exec_scalar_field("inv", &[&zero]), zero);
+}
+
+#[test]
+fn test_secp256r1_scalar_field_inv_non_canonical() {
+    expect_non_canonical_inverse_rejected(
+        "secp256r1::scalar_field",
+        AdviceInjector::Secp256r1ScalarInv,
+        &scalar_modulus(),
+    );
+}
@@ -1,5 +1,30 @@
 use num_bigint::BigUint;
-use test_utils::rand::rand_vector;
+use processor::ExecutionError;
+use test_utils::rand::{rand_value, rand_vector};
+
+// ADDITION
+// ================================================================================================
+
+#[test]
+fn overflowing_add_unsafe() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_add_unsafe
+        end";
+
+    let modulus = BigUint::from(1u32) << 256;
+    for (a, b) in edge_case_pairs() {
+        let sum = &a + &b;
+        let overflow = (sum >= modulus) as u64;
+        let result = [overflow]
+            .into_iter()
+            .chain(to_limbs(&(sum % &modulus)).into_iter().rev())
+            .collect::<Vec<_>>();
+
+        build_test!(source, &to_operands(&a, &b)).expect_stack(&result);
+    }
+}
 
 // MULTIPLICATION
 // ================================================================================================
@@ -32,6 +57,215 @@ fn mul_unsafe() {
     build_test!(source, &operands).expect_stack(&result);
 }
 
+#[test]
+fn overflowing_mul_unsafe() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::overflowing_mul_unsafe
+        end";
+
+    for (a, b) in edge_case_pairs() {
+        let mut result = (&a * &b).to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+        result.resize(16, 0);
+        result.reverse();
+
+        build_test!(source, &to_operands(&a, &b)).expect_stack(&result);
+    }
+}
+
+// COMPARISONS
+// ================================================================================================
+
+#[test]
+fn comparisons() {
+    for (a, b) in edge_case_pairs() {
+        test_cmp("lt_unsafe", &a, &b, a < b);
+        test_cmp("lte_unsafe", &a, &b, a <= b);
+        test_cmp("gt_unsafe", &a, &b, a > b);
+        test_cmp("gte_unsafe", &a, &b, a >= b);
+    }
+}
+
+// DIVISION
+// ================================================================================================
+
+#[test]
+fn divmod_unsafe() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::divmod_unsafe
+        end";
+
+    for (a, b) in edge_case_pairs().into_iter().filter(|(_, b)| *b != BigUint::from(0u32)) {
+        // the remainder is expected to be at the top of the stack, followed by the quotient
+        let result = to_limbs(&(&a % &b))
+            .into_iter()
+            .rev()
+            .chain(to_limbs(&(&a / &b)).into_iter().rev())
+            .collect::<Vec<_>>();
+
+        build_test!(source, &to_operands(&a, &b)).expect_stack(&result);
+    }
+}
+
+#[test]
+fn div_and_mod_unsafe() {
+    for (a, b) in edge_case_pairs().into_iter().filter(|(_, b)| *b != BigUint::from(0u32)) {
+        test_div("div_unsafe", &a, &b, &(&a / &b));
+        test_div("mod_unsafe", &a, &b, &(&a % &b));
+    }
+}
+
+#[test]
+fn div_unsafe_by_zero() {
+    let a = rand_u256();
+    let zero = BigUint::from(0u32);
+    for procedure in ["divmod_unsafe", "div_unsafe", "mod_unsafe"] {
+        let source = format!(
+            "
+            use.std::math::u256
+            begin
+                exec.u256::{procedure}
+            end"
+        );
+
+        let test = build_test!(&source, &to_operands(&a, &zero));
+        assert!(matches!(test.execute(), Err(ExecutionError::DivideByZero { .. })));
+    }
+}
+
+// SHIFTS
+// ================================================================================================
+
+#[test]
+fn shl_and_shr_unsafe() {
+    let modulus = BigUint::from(1u32) << 256;
+    let shifts = [0, 1, 31, 32, 33, 64, 128, 224, 255, rand_value::<u32>() % 256];
+    for a in edge_cases() {
+        for n in shifts {
+            test_shift("shl_unsafe", &a, n, &((&a << n) % &modulus));
+            test_shift("shr_unsafe", &a, n, &(&a >> n));
+        }
+    }
+}
+
+#[test]
+fn shl_and_shr_unsafe_out_of_range() {
+    let a = rand_u256();
+    for procedure in ["shl_unsafe", "shr_unsafe"] {
+        let source = format!(
+            "
+            use.std::math::u256
+            begin
+                exec.u256::{procedure}
+            end"
+        );
+
+        for n in [256, u32::MAX as u64, u32::MAX as u64 + 1] {
+            let operands = to_limbs(&a).into_iter().chain([n]).collect::<Vec<_>>();
+            assert!(build_test!(&source, &operands).execute().is_err());
+        }
+    }
+}
+
+// EXPONENTIATION
+// ================================================================================================
+
+#[test]
+fn pow_unsafe() {
+    let a = rand_u256();
+    let e = rand_u256();
+    for e in [BigUint::from(0u32), BigUint::from(1u32), BigUint::from(17u32), e] {
+        test_pow("pow_unsafe", &a, &e);
+        test_pow("pow_ct_unsafe", &a, &e);
+    }
+}
+
+#[test]
+fn pow_ct_unsafe_cycle_count() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::pow_ct_unsafe
+        end";
+
+    // the number of cycles must not depend on the exponent
+    let a = rand_u256();
+    let cycles = [BigUint::from(0u32), rand_u256()].map(|e| {
+        let trace = build_test!(source, &to_operands(&a, &e)).execute().unwrap();
+        trace.trace_len_summary().main_trace_len()
+    });
+    assert_eq!(cycles[0], cycles[1]);
+}
+
+// MODULAR ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn mont_mul_unsafe() {
+    let r = BigUint::from(1u32) << 256;
+    for modulus in moduli() {
+        let a = rand_u256() % &modulus;
+        let b = rand_u256() % &modulus;
+
+        // multiplying a in Montgomery form by b cancels out the Montgomery factor
+        let a_mont = &a * &r % &modulus;
+        test_mod_op("mont_mul_unsafe", &modulus, &[&a_mont, &b], &(&a * &b % &modulus));
+    }
+}
+
+#[test]
+fn mod_add_and_sub_unsafe() {
+    let zero = BigUint::from(0u32);
+    let one = BigUint::from(1u32);
+    for modulus in moduli() {
+        let max = &modulus - &one;
+        let rand = rand_u256() % &modulus;
+        for (a, b) in [(&zero, &zero), (&max, &max), (&zero, &max), (&max, &one), (&rand, &max)] {
+            test_mod_op("mod_add_unsafe", &modulus, &[a, b], &((a + b) % &modulus));
+            test_mod_op("mod_sub_unsafe", &modulus, &[a, b], &((a + &modulus - b) % &modulus));
+        }
+    }
+}
+
+#[test]
+fn montgomery_form_conversion_unsafe() {
+    let r = BigUint::from(1u32) << 256;
+    for modulus in moduli() {
+        // values converted into Montgomery form are not required to be smaller than the modulus
+        for a in [BigUint::from(0u32), &modulus - 1u32, &r - 1u32, rand_u256()] {
+            let a_mont = &a * &r % &modulus;
+            test_mod_op("to_mont_unsafe", &modulus, &[&a], &a_mont);
+            test_mod_op("from_mont_unsafe", &modulus, &[&a_mont], &(&a % &modulus));
+        }
+    }
+}
+
+#[test]
+fn mod_mul_unsafe() {
+    for modulus in moduli() {
+        let max = &modulus - 1u32;
+        let a = rand_u256() % &modulus;
+        let b = rand_u256() % &modulus;
+        for (a, b) in [(&max, &max), (&a, &b)] {
+            test_mod_op("mod_mul_unsafe", &modulus, &[a, b], &(a * b % &modulus));
+        }
+    }
+}
+
+#[test]
+fn mod_exp_unsafe() {
+    for modulus in moduli() {
+        let a = rand_u256();
+        for e in [0u32, 1, 2, 65537, rand_value::<u32>()] {
+            let e = BigUint::from(e);
+            test_mod_op("mod_exp_unsafe", &modulus, &[&a, &e], &a.modpow(&e, &modulus));
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -39,3 +273,142 @@ fn rand_u256() -> BigUint {
     let limbs = rand_vector::<u64>(8).iter().map(|&v| v as u32).collect::<Vec<_>>();
     BigUint::new(limbs)
 }
+
+/// Returns a set of values which exercise the corner cases of the 256-bit arithmetic, as well as
+/// a few random values.
+fn edge_cases() -> Vec<BigUint> {
+    let one = BigUint::from(1u32);
+    let max = (&one << 256) - &one;
+    vec![
+        BigUint::from(0u32),
+        one.clone(),
+        BigUint::from(2u32),
+        BigUint::from(u32::MAX),
+        &one << 32,
+        (&one << 128) + BigUint::from(7u32),
+        &one << 255,
+        &max - &one,
+        max,
+        rand_u256(),
+        rand_u256() >> 100,
+    ]
+}
+
+/// Returns all pairs of the values returned by [edge_cases].
+fn edge_case_pairs() -> Vec<(BigUint, BigUint)> {
+    let values = edge_cases();
+    values
+        .iter()
+        .flat_map(|a| values.iter().map(move |b| (a.clone(), b.clone())))
+        .collect()
+}
+
+fn test_cmp(procedure: &str, a: &BigUint, b: &BigUint, expected: bool) {
+    let source = format!(
+        "
+        use.std::math::u256
+        begin
+            exec.u256::{procedure}
+        end"
+    );
+
+    build_test!(&source, &to_operands(a, b)).expect_stack(&[expected as u64]);
+}
+
+fn test_div(procedure: &str, a: &BigUint, b: &BigUint, expected: &BigUint) {
+    let source = format!(
+        "
+        use.std::math::u256
+        begin
+            exec.u256::{procedure}
+        end"
+    );
+
+    let result = to_limbs(expected).into_iter().rev().collect::<Vec<_>>();
+    build_test!(&source, &to_operands(a, b)).expect_stack(&result);
+}
+
+fn test_shift(procedure: &str, a: &BigUint, n: u32, expected: &BigUint) {
+    let source = format!(
+        "
+        use.std::math::u256
+        begin
+            exec.u256::{procedure}
+        end"
+    );
+
+    let operands = to_limbs(a).into_iter().chain([n as u64]).collect::<Vec<_>>();
+    let result = to_limbs(expected).into_iter().rev().collect::<Vec<_>>();
+    build_test!(&source, &operands).expect_stack(&result);
+}
+
+fn test_pow(procedure: &str, a: &BigUint, e: &BigUint) {
+    let source = format!(
+        "
+        use.std::math::u256
+        begin
+            exec.u256::{procedure}
+        end"
+    );
+
+    let modulus = BigUint::from(1u32) << 256;
+    let result = to_limbs(&a.modpow(e, &modulus)).into_iter().rev().collect::<Vec<_>>();
+
+    build_test!(&source, &to_operands(a, e)).expect_stack(&result);
+}
+
+/// Returns the limbs of a followed by the limbs of e, with the least significant limbs first.
+fn to_operands(a: &BigUint, e: &BigUint) -> Vec<u64> {
+    to_limbs(a).into_iter().chain(to_limbs(e)).collect()
+}
+
+/// Returns the 8 limbs of the specified value, with the least significant limb first.
+fn to_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+/// Returns a set of odd moduli used to test modular arithmetic.
+fn moduli() -> Vec<BigUint> {
+    let one = BigUint::from(1u32);
+    vec![
+        BigUint::from(3u32),
+        (&one << 128) + BigUint::from(51u32),
+        (&one << 255) - BigUint::from(19u32),
+        (&one << 256) - BigUint::from(189u32),
+        (&one << 256) - &one,
+        rand_u256() | &one,
+    ]
+}
+
+/// Executes the specified modular arithmetic procedure with the operands provided from the deepest
+/// to the topmost one, and checks the result against the expected value.
+fn test_mod_op(procedure: &str, modulus: &BigUint, operands: &[&BigUint], expected: &BigUint) {
+    let mut limbs = [0; 8];
+    limbs.copy_from_slice(&to_limbs(modulus).iter().map(|&v| v as u32).collect::<Vec<_>>());
+    let context = miden_stdlib::math::u256_montgomery_context(limbs);
+
+    let store_context = context
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            let word = word.iter().rev().map(|v| v.to_string()).collect::<Vec<_>>().join(".");
+            format!("push.{word}.{} mem_storew dropw", 1000 + i)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let source = format!(
+        "
+        use.std::math::u256
+        begin
+            {store_context}
+            push.1000
+            exec.u256::{procedure}
+        end"
+    );
+
+    let operands = operands.iter().flat_map(|&value| to_limbs(value)).collect::<Vec<_>>();
+    let result = to_limbs(expected).into_iter().rev().collect::<Vec<_>>();
+    build_test!(&source, &operands).expect_stack(&result);
+}
@@ -863,6 +863,77 @@ fn cto() {
     build_test!(source, &[255, 255]).expect_stack(&[8]);
 }
 
+// MODULAR ARITHMETIC
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn mont_mul() {
+    for modulus in moduli() {
+        let a = rand_value::<u64>() % modulus;
+        let b = rand_value::<u64>() % modulus;
+
+        // multiplying a in Montgomery form by b cancels out the Montgomery factor
+        let a_mont = mul_mod(a, ((1u128 << 64) % modulus as u128) as u64, modulus);
+        test_mod_op("mont_mul", modulus, &[a_mont, b], mul_mod(a, b, modulus));
+    }
+}
+
+#[test]
+fn mod_add_and_sub() {
+    for modulus in moduli() {
+        for (a, b) in [(0, 0), (modulus - 1, modulus - 1), (0, modulus - 1), (modulus - 1, 1)]
+            .into_iter()
+            .chain([(rand_value::<u64>() % modulus, rand_value::<u64>() % modulus)])
+        {
+            let sum = ((a as u128 + b as u128) % modulus as u128) as u64;
+            test_mod_op("mod_add", modulus, &[a, b], sum);
+
+            let diff = ((a as u128 + modulus as u128 - b as u128) % modulus as u128) as u64;
+            test_mod_op("mod_sub", modulus, &[a, b], diff);
+        }
+    }
+}
+
+#[test]
+fn montgomery_form_conversion() {
+    for modulus in moduli() {
+        let r = ((1u128 << 64) % modulus as u128) as u64;
+
+        // values converted into Montgomery form are not required to be smaller than the modulus
+        for a in [0, 1, modulus - 1, u64::MAX, rand_value::<u64>()] {
+            let a_mont = mul_mod(a % modulus, r, modulus);
+            test_mod_op("to_mont", modulus, &[a], a_mont);
+            test_mod_op("from_mont", modulus, &[a_mont], a % modulus);
+        }
+    }
+}
+
+#[test]
+fn mod_mul() {
+    for modulus in moduli() {
+        for (a, b) in [(0, modulus - 1), (1, modulus - 1), (modulus - 1, modulus - 1)]
+            .into_iter()
+            .chain([(rand_value::<u64>() % modulus, rand_value::<u64>() % modulus)])
+        {
+            test_mod_op("mod_mul", modulus, &[a, b], mul_mod(a, b, modulus));
+        }
+    }
+}
+
+#[test]
+fn mod_exp() {
+    for modulus in moduli() {
+        let a = rand_value::<u64>();
+        for e in [0, 1, 2, 65537, u64::MAX, rand_value::<u64>()] {
+            test_mod_op("mod_exp", modulus, &[a, e], pow_mod(a, e, modulus));
+        }
+    }
+
+    // Fermat's little theorem
+    let p = 0xffff_ffff_0000_0001;
+    test_mod_op("mod_exp", p, &[rand_value::<u64>() % (p - 1) + 1, p - 1], 1);
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -1112,3 +1183,65 @@ fn split_u128(value: u128) -> (u64, u64, u64, u64) {
         value as u32 as u64,
     )
 }
+
+/// Returns a set of odd moduli used to test modular arithmetic.
+fn moduli() -> Vec<u64> {
+    vec![
+        3,
+        0xffff_ffff,
+        0x1_0000_0001,
+        0xffff_ffff_0000_0001,
+        u64::MAX,
+        rand_value::<u64>() | 1,
+    ]
+}
+
+/// Executes the specified modular arithmetic procedure with the operands provided from the deepest
+/// to the topmost one, and checks the result against the expected value.
+fn test_mod_op(procedure: &str, modulus: u64, operands: &[u64], expected: u64) {
+    let context = miden_stdlib::math::u64_montgomery_context(modulus);
+    let source = format!(
+        "
+        use.std::math::u64
+        begin
+            push.{}.1001 mem_storew dropw
+            push.{}.1000 mem_storew dropw
+            push.1000
+            exec.u64::{procedure}
+        end",
+        word_to_str(context[1]),
+        word_to_str(context[0]),
+    );
+
+    let operands = operands
+        .iter()
+        .flat_map(|&value| {
+            let (hi, lo) = split_u64(value);
+            [lo, hi]
+        })
+        .collect::<Vec<_>>();
+    let (c1, c0) = split_u64(expected);
+    build_test!(&source, &operands).expect_stack(&[c1, c0]);
+}
+
+/// Returns the elements of the word in the order which places the first one on top of the stack.
+fn word_to_str(word: [u32; 4]) -> String {
+    word.iter().rev().map(|v| v.to_string()).collect::<Vec<_>>().join(".")
+}
+
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+fn pow_mod(a: u64, mut e: u64, modulus: u64) -> u64 {
+    let mut base = a % modulus;
+    let mut result = 1 % modulus;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        e >>= 1;
+    }
+    result
+}
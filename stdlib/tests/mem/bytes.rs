@@ -0,0 +1,301 @@
+use test_utils::{build_expected_hash, rand::rand_value};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Address of the unpacked source array used in the tests.
+const SRC_PTR: u64 = 1000;
+
+/// Address of the packed array used in the tests.
+const PACKED_PTR: u64 = 2000;
+
+/// Address of the unpacked destination array used in the tests.
+const DST_PTR: u64 = 3000;
+
+// ELEMENTS
+// ================================================================================================
+
+#[test]
+fn test_bytes_to_u32() {
+    let bytes = rand_bytes(4);
+    let value = pack_element(&bytes);
+
+    let source = "
+    use.std::mem::bytes
+
+    begin
+        exec.bytes::bytes_to_u32
+    end
+    ";
+    let stack = bytes.iter().rev().copied().collect::<Vec<_>>();
+    build_test!(source, &stack).expect_stack(&[value]);
+
+    let source = "
+    use.std::mem::bytes
+
+    begin
+        exec.bytes::u32_to_bytes
+    end
+    ";
+    build_test!(source, &[value]).expect_stack(&bytes);
+
+    // values which are not bytes should be rejected
+    let source = "
+    use.std::mem::bytes
+
+    begin
+        push.1.2.256.3 exec.bytes::bytes_to_u32
+    end
+    ";
+    assert!(build_test!(source).execute().is_err());
+}
+
+#[test]
+fn test_bytes_to_u56() {
+    let bytes = rand_bytes(7);
+    let value = pack_element(&bytes);
+
+    let source = "
+    use.std::mem::bytes
+
+    begin
+        exec.bytes::bytes_to_u56
+    end
+    ";
+    let stack = bytes.iter().rev().copied().collect::<Vec<_>>();
+    build_test!(source, &stack).expect_stack(&[value]);
+
+    let source = "
+    use.std::mem::bytes
+
+    begin
+        exec.bytes::u56_to_bytes
+    end
+    ";
+    build_test!(source, &[value]).expect_stack(&bytes);
+
+    // values which do not fit into 56 bits should be rejected
+    let source = "
+    use.std::mem::bytes
+
+    begin
+        exec.bytes::u56_to_bytes
+    end
+    ";
+    assert!(build_test!(source, &[1 << 56]).execute().is_err());
+}
+
+// BYTE ARRAYS
+// ================================================================================================
+
+#[test]
+fn test_pack_unpack() {
+    for n in [0, 1, 16, 37] {
+        let bytes = rand_bytes(n);
+
+        // the packed array should consist of the length prefix followed by the packed words
+        let source = format!(
+            "
+    use.std::mem::bytes
+
+    begin
+        {}
+        push.{PACKED_PTR}.{SRC_PTR}.{n} exec.bytes::pack
+    end
+    ",
+            store_bytes(SRC_PTR, &bytes)
+        );
+        let mut expected_mem = vec![n as u64, 0, 0, 0];
+        expected_mem.extend(pack_elements(&bytes, 4));
+        build_test!(&source).expect_stack_and_memory(&[], PACKED_PTR as u32, &expected_mem);
+
+        // unpacking should restore the original bytes
+        let source = format!(
+            "
+    use.std::mem::bytes
+
+    begin
+        {}
+        push.{PACKED_PTR}.{SRC_PTR}.{n} exec.bytes::pack
+        push.{DST_PTR}.{PACKED_PTR} exec.bytes::unpack
+        {}
+    end
+    ",
+            store_bytes(SRC_PTR, &bytes),
+            assert_bytes(DST_PTR, &bytes)
+        );
+        build_test!(&source).expect_stack(&[n as u64]);
+    }
+}
+
+#[test]
+fn test_pack_unpack_invalid() {
+    // packing values which are not bytes should fail
+    let source = format!(
+        "
+    use.std::mem::bytes
+
+    begin
+        {}
+        push.{PACKED_PTR}.{SRC_PTR}.3 exec.bytes::pack
+    end
+    ",
+        store_bytes(SRC_PTR, &[1, 256, 3])
+    );
+    assert!(build_test!(&source).execute().is_err());
+
+    // unpacking an array with non-zero unused bytes should fail
+    let source = format!(
+        "
+    use.std::mem::bytes
+
+    begin
+        push.3.0.0.0.{PACKED_PTR} mem_storew dropw
+        push.{}.0.0.0.{} mem_storew dropw
+        push.{DST_PTR}.{PACKED_PTR} exec.bytes::unpack
+    end
+    ",
+        pack_element(&[1, 2, 3, 4]),
+        PACKED_PTR + 1
+    );
+    assert!(build_test!(&source).execute().is_err());
+}
+
+#[test]
+fn test_pack_unpack_u56() {
+    for n in [1, 28, 45] {
+        let bytes = rand_bytes(n);
+
+        let source = format!(
+            "
+    use.std::mem::bytes
+
+    begin
+        {}
+        push.{PACKED_PTR}.{SRC_PTR}.{n} exec.bytes::pack_u56
+    end
+    ",
+            store_bytes(SRC_PTR, &bytes)
+        );
+        let expected_mem = pack_elements(&bytes, 7);
+        build_test!(&source).expect_stack_and_memory(&[], PACKED_PTR as u32, &expected_mem);
+
+        let source = format!(
+            "
+    use.std::mem::bytes
+
+    begin
+        {}
+        push.{PACKED_PTR}.{SRC_PTR}.{n} exec.bytes::pack_u56
+        push.{DST_PTR}.{PACKED_PTR}.{n} exec.bytes::unpack_u56
+        {}
+    end
+    ",
+            store_bytes(SRC_PTR, &bytes),
+            assert_bytes(DST_PTR, &bytes)
+        );
+        build_test!(&source).expect_stack(&[]);
+    }
+}
+
+#[test]
+fn test_copy_eq_len() {
+    let bytes = rand_bytes(21);
+    let mut other = bytes.clone();
+    other[20] ^= 1;
+
+    // copy the packed array, and compare it with itself, its copy, a different array of the same
+    // length, and an array of a different length
+    let source = format!(
+        "
+    use.std::mem::bytes
+
+    begin
+        {}
+        push.{PACKED_PTR}.{SRC_PTR}.21 exec.bytes::pack
+        {}
+        push.{}.{SRC_PTR}.21 exec.bytes::pack
+        push.{}.{SRC_PTR}.20 exec.bytes::pack
+
+        push.{DST_PTR}.{PACKED_PTR} exec.bytes::copy
+        push.{DST_PTR} exec.bytes::len
+
+        push.{}.{PACKED_PTR} exec.bytes::eq
+        push.{}.{PACKED_PTR} exec.bytes::eq
+        push.{DST_PTR}.{PACKED_PTR} exec.bytes::eq
+        push.{PACKED_PTR}.{PACKED_PTR} exec.bytes::eq
+    end
+    ",
+        store_bytes(SRC_PTR, &bytes),
+        store_bytes(SRC_PTR, &other),
+        PACKED_PTR + 10,
+        PACKED_PTR + 20,
+        PACKED_PTR + 20,
+        PACKED_PTR + 10,
+    );
+    build_test!(&source).expect_stack(&[1, 1, 0, 0, 21]);
+}
+
+#[test]
+fn test_hash() {
+    for n in [0, 5, 16, 40] {
+        let bytes = rand_bytes(n);
+
+        let source = format!(
+            "
+    use.std::mem::bytes
+
+    begin
+        {}
+        push.{PACKED_PTR}.{SRC_PTR}.{n} exec.bytes::pack
+        push.{PACKED_PTR} exec.bytes::hash
+    end
+    ",
+            store_bytes(SRC_PTR, &bytes)
+        );
+
+        let mut elements = vec![n as u64, 0, 0, 0];
+        elements.extend(pack_elements(&bytes, 4));
+        let expected =
+            build_expected_hash(&elements).iter().map(|e| e.as_int()).collect::<Vec<_>>();
+        build_test!(&source).expect_stack(&expected);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn rand_bytes(n: usize) -> Vec<u64> {
+    (0..n).map(|_| rand_value::<u64>() & 0xff).collect()
+}
+
+/// Packs the specified bytes into a single element in little-endian order.
+fn pack_element(bytes: &[u64]) -> u64 {
+    bytes.iter().rev().fold(0, |acc, &b| (acc << 8) | b)
+}
+
+/// Packs the specified bytes into elements of `bytes_per_element` bytes each, and pads the result
+/// with zeros to a multiple of 4 elements.
+fn pack_elements(bytes: &[u64], bytes_per_element: usize) -> Vec<u64> {
+    let mut elements = bytes.chunks(bytes_per_element).map(pack_element).collect::<Vec<_>>();
+    elements.resize(elements.len().next_multiple_of(4), 0);
+    elements
+}
+
+/// Returns the code which writes the specified bytes into memory, one byte per address.
+fn store_bytes(ptr: u64, bytes: &[u64]) -> String {
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(i, b)| format!("push.{b}.{} mem_store\n", ptr + i as u64))
+        .collect()
+}
+
+/// Returns the code which asserts that the memory holds the specified bytes, one byte per address.
+fn assert_bytes(ptr: u64, bytes: &[u64]) -> String {
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(i, b)| format!("push.{} mem_load push.{b} assert_eq\n", ptr + i as u64))
+        .collect()
+}
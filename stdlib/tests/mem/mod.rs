@@ -1,3 +1,5 @@
+mod bytes;
+
 use processor::{ContextId, DefaultHost, ProcessState};
 use test_utils::{
     build_expected_hash, build_expected_perm, stack_to_ints, ExecutionOptions, Process,
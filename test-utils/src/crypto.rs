@@ -34,3 +34,30 @@ pub fn init_merkle_leaves(values: &[u64]) -> Vec<Word> {
 pub fn init_merkle_leaf(value: u64) -> Word {
     [Felt::new(value), ZERO, ZERO, ZERO]
 }
+
+/// Signs each message with the corresponding secret key, and returns the values which
+/// `std::crypto::dsa::rpo_falcon512::verify_batch` expects on the advice stack, with the top of
+/// the stack first.
+///
+/// For every signature, the values are laid out in the same way as the values produced by the
+/// `adv.push_sig.rpo_falcon512` decorator: the nonce, the expanded public key `h`, the signature
+/// polynomial `s2`, and the product of `h` and `s2` in the ring of polynomials with coefficients
+/// in the Miden field. The values of the first signature are at the top of the stack.
+#[cfg(feature = "std")]
+pub fn falcon_batch_advice_stack(batch: &[(rpo_falcon512::SecretKey, Word)]) -> Vec<u64> {
+    use rpo_falcon512::Polynomial;
+
+    let mut result = Vec::new();
+    for (sk, msg) in batch {
+        let sig = sk.sign(*msg);
+        let s2 = sig.sig_poly();
+        let h = sk.compute_pub_key_poly().0;
+        let pi = Polynomial::mul_modulo_p(&h, s2);
+
+        result.extend(sig.nonce().to_elements().iter().map(|a| a.as_int()));
+        result.extend(h.coefficients.iter().map(|a| a.value() as u64));
+        result.extend(s2.coefficients.iter().map(|a| a.value() as u64));
+        result.extend(pi.iter().copied());
+    }
+    result
+}
@@ -17,12 +17,13 @@ use winter_verifier::verify as verify_proof;
 // EXPORTS
 // ================================================================================================
 
+pub use vm_core::utils::ct_eq;
 pub use vm_core::{chiplets::hasher::Digest, Kernel, ProgramInfo, StackInputs, StackOutputs, Word};
 pub use winter_verifier::{AcceptableOptions, VerifierError};
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{BusArgument, BusArguments, BusRelation, ExecutionProof};
 
 // VERIFIER
 // ================================================================================================
@@ -49,11 +50,22 @@ pub use air::ExecutionProof;
 /// - 128-bit security level, non-recursive context (RPO hash function).
 /// - 128-bit security level, recursive context (RPO hash function).
 ///
+/// Bus relations of the VM are being migrated from auxiliary trace columns to the GKR-based
+/// virtual bus. The verifier checks every relation using the argument recorded for it in the
+/// proof (see [BusArguments]); currently, only relations enforced via auxiliary trace columns can
+/// be verified.
+///
+/// Callers which compare program hashes or stack outputs against expected values (e.g., an
+/// allow-list of programs) should use [ct_eq] rather than `==` to avoid leaking timing
+/// information.
+///
 /// # Errors
 /// Returns an error if:
 /// - The provided proof does not prove a correct execution of the program.
 /// - The the protocol parameters used to generate the proof is not in the set of acceptable
 ///   parameters.
+/// - A bus relation of the proof is enforced via an argument which is not supported by this
+///   verifier.
 #[tracing::instrument("verify_program", skip_all)]
 pub fn verify(
     program_info: ProgramInfo,
@@ -64,6 +76,12 @@ pub fn verify(
     // get security level of the proof
     let security_level = proof.security_level();
 
+    // relations enforced via auxiliary columns are checked by the AIR constraints; verification
+    // of the virtual bus is not supported yet
+    if let Some(relation) = proof.bus_arguments().virtual_bus_relations().next() {
+        return Err(VerificationError::UnsupportedBusArgument(relation));
+    }
+
     // build public inputs and try to verify the proof
     let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
     let (hash_fn, proof) = proof.into_parts();
@@ -98,6 +116,7 @@ pub enum VerificationError {
     VerifierError(VerifierError),
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
+    UnsupportedBusArgument(BusRelation),
 }
 
 impl fmt::Display for VerificationError {
@@ -107,6 +126,9 @@ impl fmt::Display for VerificationError {
             VerifierError(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            UnsupportedBusArgument(relation) => {
+                write!(f, "the virtual bus argument used for the {relation} is not supported")
+            }
         }
     }
 }